@@ -0,0 +1,193 @@
+use crate::vector3::Vector3;
+
+/// An axis-aligned bounding box
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Aabb {
+    pub min: Vector3,
+    pub max: Vector3,
+}
+
+impl Aabb {
+    /// Creates a box from the provided corners
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Aabb;
+    /// use vex::Vector3;
+    ///
+    /// let actual = Aabb::make(Vector3::new(), Vector3::one());
+    /// assert_eq!(actual.min, Vector3::new());
+    /// assert_eq!(actual.max, Vector3::one());
+    /// ```
+    #[inline]
+    pub const fn make(min: Vector3, max: Vector3) -> Aabb {
+        Aabb { min, max }
+    }
+
+    /// Creates the smallest box containing all of the provided points
+    ///
+    /// Returns `None` if there are no points.
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Aabb;
+    /// use vex::Vector3;
+    ///
+    /// let points = [Vector3::make(1.0, -2.0, 3.0), Vector3::make(-1.0, 2.0, 0.0)];
+    /// let actual = Aabb::from_points(&points).unwrap();
+    /// let expected = Aabb::make(Vector3::make(-1.0, -2.0, 0.0), Vector3::make(1.0, 2.0, 3.0));
+    /// assert_eq!(actual, expected);
+    /// ```
+    #[inline]
+    pub fn from_points(points: &[Vector3]) -> Option<Aabb> {
+        let first = points.first()?;
+        let mut aabb = Aabb::make(*first, *first);
+        for p in &points[1..] {
+            aabb.min = Vector3::min(&aabb.min, p);
+            aabb.max = Vector3::max(&aabb.max, p);
+        }
+
+        Some(aabb)
+    }
+
+    /// Gets the center of the box
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Aabb;
+    /// use vex::Vector3;
+    ///
+    /// let actual = Aabb::make(Vector3::new(), Vector3::make(2.0, 4.0, 6.0)).center();
+    /// assert_eq!(actual, Vector3::make(1.0, 2.0, 3.0));
+    /// ```
+    #[inline]
+    pub fn center(&self) -> Vector3 {
+        (self.min + self.max) * 0.5
+    }
+
+    /// Gets the half-size of the box along each axis
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Aabb;
+    /// use vex::Vector3;
+    ///
+    /// let actual = Aabb::make(Vector3::new(), Vector3::make(2.0, 4.0, 6.0)).extents();
+    /// assert_eq!(actual, Vector3::make(1.0, 2.0, 3.0));
+    /// ```
+    #[inline]
+    pub fn extents(&self) -> Vector3 {
+        (self.max - self.min) * 0.5
+    }
+
+    /// Gets the eight corners of the box
+    ///
+    /// Bit 0 of the index selects the x extreme, bit 1 the y extreme, and bit 2 the z extreme.
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Aabb;
+    /// use vex::Vector3;
+    ///
+    /// let corners = Aabb::make(Vector3::new(), Vector3::one()).corners();
+    /// assert_eq!(corners[0], Vector3::make(0.0, 0.0, 0.0));
+    /// assert_eq!(corners[3], Vector3::make(1.0, 1.0, 0.0));
+    /// assert_eq!(corners[7], Vector3::make(1.0, 1.0, 1.0));
+    /// ```
+    #[inline]
+    pub fn corners(&self) -> [Vector3; 8] {
+        let mut corners = [self.min; 8];
+        for (i, corner) in corners.iter_mut().enumerate() {
+            if i & 1 != 0 {
+                corner.x = self.max.x;
+            }
+
+            if i & 2 != 0 {
+                corner.y = self.max.y;
+            }
+
+            if i & 4 != 0 {
+                corner.z = self.max.z;
+            }
+        }
+
+        corners
+    }
+
+    /// Determines whether or not a point lies inside the box (inclusive)
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Aabb;
+    /// use vex::Vector3;
+    ///
+    /// let aabb = Aabb::make(Vector3::new(), Vector3::one());
+    /// assert!(aabb.contains_point(&Vector3::make(0.5, 1.0, 0.0)));
+    /// assert!(!aabb.contains_point(&Vector3::make(0.5, 1.5, 0.0)));
+    /// ```
+    #[inline]
+    pub fn contains_point(&self, point: &Vector3) -> bool {
+        point.x >= self.min.x
+            && point.x <= self.max.x
+            && point.y >= self.min.y
+            && point.y <= self.max.y
+            && point.z >= self.min.z
+            && point.z <= self.max.z
+    }
+
+    /// Gets the surface area of the box
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Aabb;
+    /// use vex::Vector3;
+    ///
+    /// let actual = Aabb::make(Vector3::new(), Vector3::make(1.0, 2.0, 3.0)).surface_area();
+    /// assert_eq!(actual, 22.0);
+    /// ```
+    #[inline]
+    pub fn surface_area(&self) -> f32 {
+        let size = self.max - self.min;
+        2.0 * (size.x * size.y + size.y * size.z + size.z * size.x)
+    }
+
+    /// Determines whether or not two boxes overlap (inclusive)
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Aabb;
+    /// use vex::Vector3;
+    ///
+    /// let a = Aabb::make(Vector3::new(), Vector3::one());
+    /// let b = Aabb::make(Vector3::one(), Vector3::make(2.0, 2.0, 2.0));
+    /// let c = Aabb::make(Vector3::make(1.5, 0.0, 0.0), Vector3::make(2.0, 1.0, 1.0));
+    /// assert!(a.intersects(&b));
+    /// assert!(!a.intersects(&c));
+    /// ```
+    #[inline]
+    pub fn intersects(&self, other: &Aabb) -> bool {
+        self.min.x <= other.max.x
+            && self.max.x >= other.min.x
+            && self.min.y <= other.max.y
+            && self.max.y >= other.min.y
+            && self.min.z <= other.max.z
+            && self.max.z >= other.min.z
+    }
+
+    /// Find the smallest box containing two boxes
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Aabb;
+    /// use vex::Vector3;
+    ///
+    /// let a = Aabb::make(Vector3::new(), Vector3::one());
+    /// let b = Aabb::make(Vector3::make(2.0, -1.0, 0.5), Vector3::make(3.0, 0.0, 0.75));
+    /// let actual = Aabb::union(&a, &b);
+    /// assert_eq!(actual, Aabb::make(Vector3::make(0.0, -1.0, 0.0), Vector3::make(3.0, 1.0, 1.0)));
+    /// ```
+    #[inline]
+    pub fn union(a: &Aabb, b: &Aabb) -> Aabb {
+        Aabb::make(Vector3::min(&a.min, &b.min), Vector3::max(&a.max, &b.max))
+    }
+}
@@ -0,0 +1,68 @@
+use approx::{AbsDiffEq, RelativeEq, UlpsEq};
+
+use crate::matrix2::Matrix2;
+use crate::matrix3::Matrix3;
+use crate::matrix4::Matrix4;
+use crate::quaternion::Quaternion;
+use crate::vector2::Vector2;
+use crate::vector3::Vector3;
+use crate::vector4::Vector4;
+
+// each type is compared component by component, using `f32`'s own tolerances
+macro_rules! impl_approx {
+    ($t:ty, |$v:ident| $components:expr) => {
+        impl AbsDiffEq for $t {
+            type Epsilon = f32;
+
+            #[inline]
+            fn default_epsilon() -> f32 {
+                f32::default_epsilon()
+            }
+
+            #[inline]
+            fn abs_diff_eq(&self, other: &$t, epsilon: f32) -> bool {
+                let lhs = { let $v = self; $components };
+                let rhs = { let $v = other; $components };
+                lhs.iter().zip(rhs.iter()).all(|(a, b)| a.abs_diff_eq(b, epsilon))
+            }
+        }
+
+        impl RelativeEq for $t {
+            #[inline]
+            fn default_max_relative() -> f32 {
+                f32::default_max_relative()
+            }
+
+            #[inline]
+            fn relative_eq(&self, other: &$t, epsilon: f32, max_relative: f32) -> bool {
+                let lhs = { let $v = self; $components };
+                let rhs = { let $v = other; $components };
+                lhs.iter()
+                    .zip(rhs.iter())
+                    .all(|(a, b)| a.relative_eq(b, epsilon, max_relative))
+            }
+        }
+
+        impl UlpsEq for $t {
+            #[inline]
+            fn default_max_ulps() -> u32 {
+                f32::default_max_ulps()
+            }
+
+            #[inline]
+            fn ulps_eq(&self, other: &$t, epsilon: f32, max_ulps: u32) -> bool {
+                let lhs = { let $v = self; $components };
+                let rhs = { let $v = other; $components };
+                lhs.iter().zip(rhs.iter()).all(|(a, b)| a.ulps_eq(b, epsilon, max_ulps))
+            }
+        }
+    };
+}
+
+impl_approx!(Vector2, |v| [v.x, v.y]);
+impl_approx!(Vector3, |v| [v.x, v.y, v.z]);
+impl_approx!(Vector4, |v| [v.x, v.y, v.z, v.w]);
+impl_approx!(Quaternion, |v| [v.x, v.y, v.z, v.w]);
+impl_approx!(Matrix2, |v| v.m);
+impl_approx!(Matrix3, |v| v.m);
+impl_approx!(Matrix4, |v| v.m);
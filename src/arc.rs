@@ -0,0 +1,90 @@
+#[cfg(not(feature = "std"))]
+use crate::math::Float;
+use crate::vector2::Vector2;
+
+use alloc::vec::Vec;
+use core::f32::consts::{FRAC_PI_2, PI};
+
+// the most segments an arc is split into, however tight its tolerance is compared to its radius
+const MAX_SEGMENTS: usize = 1 << 16;
+
+/// Flatten a circular arc into points along it, with as few segments as keep it within
+/// `max_error` of the true arc
+///
+/// The arc sweeps counter-clockwise from `start_angle` to `end_angle` when `end_angle` is larger,
+/// and clockwise when it's smaller, so sweeps aren't wrapped into `[0, 2 * PI)` and a sweep from
+/// `PI * 1.5` to `PI * 2.5` crosses angle 0. Sweeps of more than a full turn are cut to one turn.
+///
+/// The first and last points are computed directly from the start and end angles, so they land
+/// exactly where adjoining segments would expect them, and a full circle ends exactly where it
+/// starts. Each segment spans at most a quarter turn, so even coarse tolerances keep the arc's
+/// shape. Arcs are never split into more than 65536 segments, so tolerances far below the
+/// radius's own float precision are met as closely as that allows.
+///
+/// Panics if `max_error` isn't positive.
+///
+/// # Examples
+/// ```
+/// use vex::Vector2;
+/// use vex::arc_points;
+///
+/// use std::f32::consts::PI;
+///
+/// let center = Vector2::make(1.0, 2.0);
+/// let points = arc_points(&center, 2.0, 0.0, PI / 2.0, 0.01);
+/// assert_eq!(points[0], Vector2::make(3.0, 2.0));
+/// assert!((points[points.len() - 1] - Vector2::make(1.0, 4.0)).mag() < 0.000001);
+///
+/// // the middle of every segment stays within the tolerance
+/// for pair in points.windows(2) {
+///     let middle = (pair[0] + pair[1]) * 0.5;
+///     assert!(2.0 - (middle - center).mag() <= 0.01);
+/// }
+///
+/// // clockwise when the end angle is smaller
+/// let points = arc_points(&Vector2::new(), 1.0, 0.0, -PI / 2.0, 0.1);
+/// assert!(points[1].y < 0.0);
+///
+/// // a full circle closes exactly
+/// let points = arc_points(&Vector2::new(), 1.0, 0.3, 0.3 + 2.0 * PI, 0.001);
+/// assert_eq!(points[0], points[points.len() - 1]);
+///
+/// // tolerances far below the radius's precision still give a bounded number of points
+/// let points = arc_points(&Vector2::new(), 1.0e6, 0.0, PI, 1.0e-6);
+/// assert!(points.len() <= 65537);
+/// ```
+pub fn arc_points(
+    center: &Vector2,
+    radius: f32,
+    start_angle: f32,
+    end_angle: f32,
+    max_error: f32,
+) -> Vec<Vector2> {
+    assert!(max_error > 0.0, "arc_points needs a positive max_error, not {}", max_error);
+
+    let sweep = (end_angle - start_angle).clamp(-2.0 * PI, 2.0 * PI);
+    let full = sweep.abs() >= 2.0 * PI;
+
+    // a chord spanning `step` radians strays `radius * (1 - cos(step / 2))` from the arc, which is
+    // `2 * radius * sin(step / 4)^2`, solved that way so tiny tolerances don't cancel out
+    let step = if max_error >= radius {
+        FRAC_PI_2
+    } else {
+        (4.0 * (max_error / (2.0 * radius)).sqrt().asin()).min(FRAC_PI_2)
+    };
+
+    let segments = ((sweep.abs() / step).ceil() as usize).clamp(1, MAX_SEGMENTS);
+    let point = |angle: f32| {
+        let (sin, cos) = angle.sin_cos();
+        Vector2::make(center.x + radius * cos, center.y + radius * sin)
+    };
+
+    let mut points = Vec::with_capacity(segments + 1);
+    for i in 0..segments {
+        points.push(point(start_angle + sweep * i as f32 / segments as f32));
+    }
+
+    let last = if full { points[0] } else { point(end_angle) };
+    points.push(last);
+    points
+}
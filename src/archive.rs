@@ -0,0 +1,177 @@
+//! Conversions from the archived types generated for the `rkyv` feature
+//!
+//! Archived values store their elements as little-endian `f32`s with 4-byte alignment, whatever
+//! the target, so `Vector4` and `Matrix4` lose their 16-byte alignment once archived. These
+//! conversions copy an archived value back into its native type without going through rkyv's
+//! deserializer.
+
+use crate::matrix2::{ArchivedMatrix2, Matrix2};
+use crate::matrix3::{ArchivedMatrix3, Matrix3};
+use crate::matrix4::{ArchivedMatrix4, Matrix4};
+use crate::quaternion::{ArchivedQuaternion, Quaternion};
+use crate::vector2::{ArchivedVector2, Vector2};
+use crate::vector3::{ArchivedVector3, Vector3};
+use crate::vector4::{ArchivedVector4, Vector4};
+
+impl From<&ArchivedVector2> for Vector2 {
+    /// Copies an archived vector into a native one
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::ArchivedVector2;
+    /// use vex::Vector2;
+    ///
+    /// let v = Vector2::make(1.0, 2.0);
+    /// let bytes = rkyv::to_bytes::<rkyv::rancor::Error>(&v).unwrap();
+    /// let archived = rkyv::access::<ArchivedVector2, rkyv::rancor::Error>(&bytes).unwrap();
+    /// assert_eq!(*archived, v);
+    /// assert_eq!(Vector2::from(archived), v);
+    /// ```
+    #[inline]
+    fn from(archived: &ArchivedVector2) -> Vector2 {
+        Vector2::make(archived.x.to_native(), archived.y.to_native())
+    }
+}
+
+impl From<&ArchivedVector3> for Vector3 {
+    /// Copies an archived vector into a native one
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::ArchivedVector3;
+    /// use vex::Vector3;
+    ///
+    /// let v = Vector3::make(1.0, 2.0, 3.0);
+    /// let bytes = rkyv::to_bytes::<rkyv::rancor::Error>(&v).unwrap();
+    /// let archived = rkyv::access::<ArchivedVector3, rkyv::rancor::Error>(&bytes).unwrap();
+    /// assert_eq!(*archived, v);
+    /// assert_eq!(Vector3::from(archived), v);
+    /// ```
+    #[inline]
+    fn from(archived: &ArchivedVector3) -> Vector3 {
+        Vector3::make(
+            archived.x.to_native(),
+            archived.y.to_native(),
+            archived.z.to_native(),
+        )
+    }
+}
+
+impl From<&ArchivedVector4> for Vector4 {
+    /// Copies an archived vector into a native one
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::ArchivedVector4;
+    /// use vex::Vector4;
+    ///
+    /// let v = Vector4::make(1.0, 2.0, 3.0, 4.0);
+    /// let bytes = rkyv::to_bytes::<rkyv::rancor::Error>(&v).unwrap();
+    /// let archived = rkyv::access::<ArchivedVector4, rkyv::rancor::Error>(&bytes).unwrap();
+    /// assert_eq!(*archived, v);
+    /// assert_eq!(Vector4::from(archived), v);
+    /// ```
+    #[inline]
+    fn from(archived: &ArchivedVector4) -> Vector4 {
+        Vector4::make(
+            archived.x.to_native(),
+            archived.y.to_native(),
+            archived.z.to_native(),
+            archived.w.to_native(),
+        )
+    }
+}
+
+impl From<&ArchivedQuaternion> for Quaternion {
+    /// Copies an archived quaternion into a native one
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::ArchivedQuaternion;
+    /// use vex::Quaternion;
+    ///
+    /// let q = Quaternion::make(1.0, 2.0, 3.0, 4.0);
+    /// let bytes = rkyv::to_bytes::<rkyv::rancor::Error>(&q).unwrap();
+    /// let archived = rkyv::access::<ArchivedQuaternion, rkyv::rancor::Error>(&bytes).unwrap();
+    /// assert_eq!(*archived, q);
+    /// assert_eq!(Quaternion::from(archived), q);
+    /// ```
+    #[inline]
+    fn from(archived: &ArchivedQuaternion) -> Quaternion {
+        Quaternion::make(
+            archived.x.to_native(),
+            archived.y.to_native(),
+            archived.z.to_native(),
+            archived.w.to_native(),
+        )
+    }
+}
+
+impl From<&ArchivedMatrix2> for Matrix2 {
+    /// Copies an archived matrix into a native one
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::ArchivedMatrix2;
+    /// use vex::Matrix2;
+    ///
+    /// let m = Matrix2::make(1.0, 2.0, 3.0, 4.0);
+    /// let bytes = rkyv::to_bytes::<rkyv::rancor::Error>(&m).unwrap();
+    /// let archived = rkyv::access::<ArchivedMatrix2, rkyv::rancor::Error>(&bytes).unwrap();
+    /// assert_eq!(*archived, m);
+    /// assert_eq!(Matrix2::from(archived), m);
+    /// ```
+    #[inline]
+    fn from(archived: &ArchivedMatrix2) -> Matrix2 {
+        Matrix2 {
+            m: archived.m.map(|v| v.to_native()),
+        }
+    }
+}
+
+impl From<&ArchivedMatrix3> for Matrix3 {
+    /// Copies an archived matrix into a native one
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::ArchivedMatrix3;
+    /// use vex::Matrix3;
+    ///
+    /// let m = Matrix3::make(1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0);
+    /// let bytes = rkyv::to_bytes::<rkyv::rancor::Error>(&m).unwrap();
+    /// let archived = rkyv::access::<ArchivedMatrix3, rkyv::rancor::Error>(&bytes).unwrap();
+    /// assert_eq!(*archived, m);
+    /// assert_eq!(Matrix3::from(archived), m);
+    /// ```
+    #[inline]
+    fn from(archived: &ArchivedMatrix3) -> Matrix3 {
+        Matrix3 {
+            m: archived.m.map(|v| v.to_native()),
+        }
+    }
+}
+
+impl From<&ArchivedMatrix4> for Matrix4 {
+    /// Copies an archived matrix into a native one
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::ArchivedMatrix4;
+    /// use vex::Matrix4;
+    ///
+    /// let m = Matrix4::translate(1.0, 2.0, 3.0) * Matrix4::rotate_x(0.5);
+    /// let bytes = rkyv::to_bytes::<rkyv::rancor::Error>(&m).unwrap();
+    /// let archived = rkyv::access::<ArchivedMatrix4, rkyv::rancor::Error>(&bytes).unwrap();
+    /// assert_eq!(*archived, m);
+    /// assert_eq!(Matrix4::from(archived), m);
+    ///
+    /// // corrupt or truncated archives are rejected rather than read
+    /// assert!(rkyv::access::<ArchivedMatrix4, rkyv::rancor::Error>(&bytes[..32]).is_err());
+    /// ```
+    #[inline]
+    fn from(archived: &ArchivedMatrix4) -> Matrix4 {
+        Matrix4 {
+            m: archived.m.map(|v| v.to_native()),
+        }
+    }
+}
@@ -0,0 +1,362 @@
+use crate::common::Matrix;
+use crate::matrix3::Matrix3;
+use crate::vector3::Vector3;
+
+use core::ops::{Add, AddAssign, Mul, MulAssign, Sub, SubAssign};
+
+/// A 6x6 matrix made of four 3x3 blocks
+///
+/// The blocks are laid out as `[b11, b12; b21, b22]`, which suits pose covariances where the
+/// first three rows and columns are position and the last three are orientation.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct BlockMatrix6 {
+    pub b11: Matrix3,
+    pub b12: Matrix3,
+    pub b21: Matrix3,
+    pub b22: Matrix3,
+}
+
+impl BlockMatrix6 {
+    /// Creates a matrix set to its identity
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::BlockMatrix6;
+    /// use vex::Matrix3;
+    ///
+    /// let actual = BlockMatrix6::new();
+    /// assert_eq!(actual.b11, Matrix3::new());
+    /// assert_eq!(actual.b12, Matrix3::new() * 0.0);
+    /// ```
+    #[inline]
+    pub const fn new() -> BlockMatrix6 {
+        BlockMatrix6 {
+            b11: Matrix3::new(),
+            b12: Matrix3::ZERO,
+            b21: Matrix3::ZERO,
+            b22: Matrix3::new(),
+        }
+    }
+
+    /// Creates a matrix from the provided blocks
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::BlockMatrix6;
+    /// use vex::Matrix3;
+    ///
+    /// let a = Matrix3::new() * 2.0;
+    /// let actual = BlockMatrix6::make(a, Matrix3::new(), Matrix3::new(), a);
+    /// assert_eq!(actual.b22, a);
+    /// ```
+    #[inline]
+    pub const fn make(b11: Matrix3, b12: Matrix3, b21: Matrix3, b22: Matrix3) -> BlockMatrix6 {
+        BlockMatrix6 { b11, b12, b21, b22 }
+    }
+
+    /// Creates a block-diagonal matrix
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::BlockMatrix6;
+    /// use vex::Matrix3;
+    ///
+    /// let actual = BlockMatrix6::diagonal(Matrix3::new() * 2.0, Matrix3::new() * 3.0);
+    /// assert_eq!(actual.b21, Matrix3::new() * 0.0);
+    /// assert_eq!(actual.b22, Matrix3::new() * 3.0);
+    /// ```
+    #[inline]
+    pub const fn diagonal(b11: Matrix3, b22: Matrix3) -> BlockMatrix6 {
+        BlockMatrix6::make(b11, Matrix3::ZERO, Matrix3::ZERO, b22)
+    }
+
+    /// Gets the element at a row and column, both in `0..6`
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::BlockMatrix6;
+    /// use vex::Matrix3;
+    ///
+    /// let b12 = Matrix3::make(1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0);
+    /// let actual = BlockMatrix6::make(Matrix3::new(), b12, Matrix3::new(), Matrix3::new());
+    /// assert_eq!(actual.get(1, 3), 2.0);
+    /// assert_eq!(actual.get(5, 5), 1.0);
+    /// ```
+    #[inline]
+    pub fn get(&self, row: usize, column: usize) -> f32 {
+        assert!(row < 6 && column < 6, "index out of range");
+        let block = match (row / 3, column / 3) {
+            (0, 0) => &self.b11,
+            (0, _) => &self.b12,
+            (_, 0) => &self.b21,
+            _ => &self.b22,
+        };
+
+        block.m[(column % 3) * 3 + row % 3]
+    }
+
+    /// Transposes the matrix's elements
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::BlockMatrix6;
+    /// use vex::Matrix3;
+    ///
+    /// let b12 = Matrix3::make(1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0);
+    /// let mut actual = BlockMatrix6::make(Matrix3::new(), b12, Matrix3::new(), Matrix3::new());
+    /// actual.transpose();
+    /// assert_eq!(actual.b21, b12.transposed());
+    /// assert_eq!(actual.b12, Matrix3::new());
+    /// ```
+    #[inline]
+    pub fn transpose(&mut self) {
+        let b12 = self.b12;
+        self.b11.transpose();
+        self.b12 = self.b21.transposed();
+        self.b21 = b12.transposed();
+        self.b22.transpose();
+    }
+
+    /// Find the transpose of the matrix
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::BlockMatrix6;
+    /// use vex::Matrix3;
+    ///
+    /// let b21 = Matrix3::make(1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0);
+    /// let actual = BlockMatrix6::make(Matrix3::new(), Matrix3::new(), b21, Matrix3::new()).transposed();
+    /// assert_eq!(actual.b12, b21.transposed());
+    /// ```
+    #[inline]
+    pub fn transposed(&self) -> BlockMatrix6 {
+        let mut result = *self;
+        result.transpose();
+        result
+    }
+
+    /// Find the inverse of the matrix using the Schur complement, or `None` if it's singular
+    ///
+    /// The complement is taken with respect to `b11` when it's invertible, and `b22` otherwise.
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::BlockMatrix6;
+    /// use vex::Matrix3;
+    ///
+    /// let b11 = Matrix3::make(4.0, 1.0, 0.0, 1.0, 3.0, 0.0, 0.0, 0.0, 2.0);
+    /// let b12 = Matrix3::make(0.5, 0.0, 0.0, 0.0, 0.5, 0.0, 0.0, 0.0, 0.5);
+    /// let m = BlockMatrix6::make(b11, b12, b12.transposed(), Matrix3::new() * 2.0);
+    /// let inverse = m.try_inverse().unwrap();
+    ///
+    /// let product = m * inverse;
+    /// for i in 0..6 {
+    ///     for j in 0..6 {
+    ///         let expected = if i == j { 1.0 } else { 0.0 };
+    ///         assert!((product.get(i, j) - expected).abs() < 0.0001);
+    ///     }
+    /// }
+    /// ```
+    pub fn try_inverse(&self) -> Option<BlockMatrix6> {
+        if let Some(a_inv) = self.b11.try_inverse() {
+            // S = D - C * A^-1 * B
+            let ca = self.b21 * a_inv;
+            let ab = a_inv * self.b12;
+            let s_inv = (self.b22 - ca * self.b12).try_inverse()?;
+
+            let ab_s = ab * s_inv;
+            return Some(BlockMatrix6::make(a_inv + ab_s * ca, -ab_s, -(s_inv * ca), s_inv));
+        }
+
+        if let Some(d_inv) = self.b22.try_inverse() {
+            // S = A - B * D^-1 * C
+            let bd = self.b12 * d_inv;
+            let dc = d_inv * self.b21;
+            let s_inv = (self.b11 - bd * self.b21).try_inverse()?;
+
+            let dc_s = dc * s_inv;
+            return Some(BlockMatrix6::make(s_inv, -(s_inv * bd), -dc_s, d_inv + dc_s * bd));
+        }
+
+        None
+    }
+
+    /// Find the product of the matrix and a 6-vector split into two halves
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::BlockMatrix6;
+    /// use vex::Matrix3;
+    /// use vex::Vector3;
+    ///
+    /// let m = BlockMatrix6::make(Matrix3::new(), Matrix3::new(), Matrix3::new() * 0.0, Matrix3::new() * 2.0);
+    /// let (top, bottom) = m.transform(&Vector3::make(1.0, 2.0, 3.0), &Vector3::make(1.0, 1.0, 1.0));
+    /// assert_eq!(top, Vector3::make(2.0, 3.0, 4.0));
+    /// assert_eq!(bottom, Vector3::make(2.0, 2.0, 2.0));
+    /// ```
+    #[inline]
+    pub fn transform(&self, top: &Vector3, bottom: &Vector3) -> (Vector3, Vector3) {
+        (
+            self.b11.transform_point(top) + self.b12.transform_point(bottom),
+            self.b21.transform_point(top) + self.b22.transform_point(bottom),
+        )
+    }
+}
+
+impl Default for BlockMatrix6 {
+    #[inline]
+    fn default() -> BlockMatrix6 {
+        BlockMatrix6::new()
+    }
+}
+
+impl Add<BlockMatrix6> for BlockMatrix6 {
+    type Output = BlockMatrix6;
+
+    /// Add two matrices
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::BlockMatrix6;
+    /// use vex::Matrix3;
+    ///
+    /// let actual = BlockMatrix6::new() + BlockMatrix6::new();
+    /// assert_eq!(actual, BlockMatrix6::diagonal(Matrix3::new() * 2.0, Matrix3::new() * 2.0));
+    /// ```
+    #[inline]
+    fn add(self, _rhs: BlockMatrix6) -> BlockMatrix6 {
+        BlockMatrix6::make(
+            self.b11 + _rhs.b11,
+            self.b12 + _rhs.b12,
+            self.b21 + _rhs.b21,
+            self.b22 + _rhs.b22,
+        )
+    }
+}
+
+impl AddAssign<BlockMatrix6> for BlockMatrix6 {
+    /// Increment a matrix by another matrix
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::BlockMatrix6;
+    /// use vex::Matrix3;
+    ///
+    /// let mut actual = BlockMatrix6::new();
+    /// actual += BlockMatrix6::new();
+    /// assert_eq!(actual, BlockMatrix6::diagonal(Matrix3::new() * 2.0, Matrix3::new() * 2.0));
+    /// ```
+    #[inline]
+    fn add_assign(&mut self, _rhs: BlockMatrix6) {
+        *self = *self + _rhs;
+    }
+}
+
+impl Sub<BlockMatrix6> for BlockMatrix6 {
+    type Output = BlockMatrix6;
+
+    /// Subtract two matrices
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::BlockMatrix6;
+    /// use vex::Matrix3;
+    ///
+    /// let actual = BlockMatrix6::new() - BlockMatrix6::new();
+    /// assert_eq!(actual, BlockMatrix6::diagonal(Matrix3::new() * 0.0, Matrix3::new() * 0.0));
+    /// ```
+    #[inline]
+    fn sub(self, _rhs: BlockMatrix6) -> BlockMatrix6 {
+        BlockMatrix6::make(
+            self.b11 - _rhs.b11,
+            self.b12 - _rhs.b12,
+            self.b21 - _rhs.b21,
+            self.b22 - _rhs.b22,
+        )
+    }
+}
+
+impl SubAssign<BlockMatrix6> for BlockMatrix6 {
+    /// Decrement a matrix by another matrix
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::BlockMatrix6;
+    /// use vex::Matrix3;
+    ///
+    /// let mut actual = BlockMatrix6::new();
+    /// actual -= BlockMatrix6::new();
+    /// assert_eq!(actual, BlockMatrix6::diagonal(Matrix3::new() * 0.0, Matrix3::new() * 0.0));
+    /// ```
+    #[inline]
+    fn sub_assign(&mut self, _rhs: BlockMatrix6) {
+        *self = *self - _rhs;
+    }
+}
+
+impl Mul<f32> for BlockMatrix6 {
+    type Output = BlockMatrix6;
+
+    /// Multiply a matrix by a scalar value
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::BlockMatrix6;
+    /// use vex::Matrix3;
+    ///
+    /// let actual = BlockMatrix6::new() * 3.0;
+    /// assert_eq!(actual, BlockMatrix6::diagonal(Matrix3::new() * 3.0, Matrix3::new() * 3.0));
+    /// ```
+    #[inline]
+    fn mul(self, _rhs: f32) -> BlockMatrix6 {
+        BlockMatrix6::make(self.b11 * _rhs, self.b12 * _rhs, self.b21 * _rhs, self.b22 * _rhs)
+    }
+}
+
+impl Mul<BlockMatrix6> for BlockMatrix6 {
+    type Output = BlockMatrix6;
+
+    /// Multiply two matrices
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::BlockMatrix6;
+    /// use vex::Matrix3;
+    ///
+    /// let a = Matrix3::make(1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0);
+    /// let m = BlockMatrix6::make(a, a, Matrix3::new(), Matrix3::new());
+    /// let actual = m * BlockMatrix6::new();
+    /// assert_eq!(actual, m);
+    ///
+    /// let actual = m * m;
+    /// assert_eq!(actual.b11, a * a + a);
+    /// assert_eq!(actual.b22, a + Matrix3::new());
+    /// ```
+    #[inline]
+    fn mul(self, _rhs: BlockMatrix6) -> BlockMatrix6 {
+        BlockMatrix6::make(
+            self.b11 * _rhs.b11 + self.b12 * _rhs.b21,
+            self.b11 * _rhs.b12 + self.b12 * _rhs.b22,
+            self.b21 * _rhs.b11 + self.b22 * _rhs.b21,
+            self.b21 * _rhs.b12 + self.b22 * _rhs.b22,
+        )
+    }
+}
+
+impl MulAssign<BlockMatrix6> for BlockMatrix6 {
+    /// Multiply a matrix by another matrix
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::BlockMatrix6;
+    /// use vex::Matrix3;
+    ///
+    /// let mut actual = BlockMatrix6::new() * 2.0;
+    /// actual *= BlockMatrix6::new() * 2.0;
+    /// assert_eq!(actual, BlockMatrix6::new() * 4.0);
+    /// ```
+    #[inline]
+    fn mul_assign(&mut self, _rhs: BlockMatrix6) {
+        *self = *self * _rhs;
+    }
+}
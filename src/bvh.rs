@@ -0,0 +1,500 @@
+use crate::aabb::Aabb;
+use crate::vector3::Vector3;
+
+use alloc::vec::Vec;
+
+#[derive(Copy, Clone, Debug)]
+enum Content {
+    Leaf(usize),
+    Branch(usize, usize),
+}
+
+#[derive(Copy, Clone, Debug)]
+struct Node {
+    aabb: Aabb,
+    parent: Option<usize>,
+    content: Content,
+}
+
+/// A bounding volume hierarchy over axis-aligned boxes, for finding which items overlap a region
+///
+/// Items are identified by the id they're given when the hierarchy is built or when they're
+/// inserted. `build` orders items along a Morton curve through their centers so nearby items
+/// share subtrees, and moving items can be kept up to date with `refit` every frame, which only
+/// recomputes bounds. Items can also be inserted and removed one at a time, which keeps the rest
+/// of the tree as it is.
+#[derive(Clone, Debug, Default)]
+pub struct Bvh {
+    nodes: Vec<Node>,
+    free_nodes: Vec<usize>,
+    leaves: Vec<Option<usize>>,
+    free_ids: Vec<usize>,
+    root: Option<usize>,
+    count: usize,
+}
+
+impl Bvh {
+    /// Creates a hierarchy with no items
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Bvh;
+    ///
+    /// let actual = Bvh::new();
+    /// assert!(actual.is_empty());
+    /// assert_eq!(actual.bounds(), None);
+    /// ```
+    #[inline]
+    pub fn new() -> Bvh {
+        Bvh::default()
+    }
+
+    /// Creates a hierarchy over boxes, ordered along a Morton curve through their centers
+    ///
+    /// Each item's id is its index in `aabbs`.
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Aabb;
+    /// use vex::Bvh;
+    /// use vex::Vector3;
+    ///
+    /// let aabbs: Vec<Aabb> = (0..8)
+    ///     .map(|i| {
+    ///         let min = Vector3::make(i as f32 * 2.0, 0.0, 0.0);
+    ///         Aabb::make(min, min + Vector3::one())
+    ///     })
+    ///     .collect();
+    ///
+    /// let bvh = Bvh::build(&aabbs);
+    /// assert_eq!(bvh.len(), 8);
+    /// assert_eq!(bvh.bounds(), Some(Aabb::make(Vector3::new(), Vector3::make(15.0, 1.0, 1.0))));
+    /// ```
+    pub fn build(aabbs: &[Aabb]) -> Bvh {
+        let mut bvh = Bvh::new();
+        if aabbs.is_empty() {
+            return bvh;
+        }
+
+        let centers: Vec<Vector3> = aabbs.iter().map(|aabb| aabb.center()).collect();
+        let bounds = Aabb::from_points(&centers).unwrap();
+        let size = bounds.max - bounds.min;
+        let scale = size.map(|x| if x > 0.0 { 1023.0 / x } else { 0.0 });
+
+        let mut order: Vec<(u32, usize)> = centers
+            .iter()
+            .enumerate()
+            .map(|(i, center)| {
+                let cell = (*center - bounds.min) * scale;
+                (morton_code(cell.x as u32, cell.y as u32, cell.z as u32), i)
+            })
+            .collect();
+        order.sort_unstable();
+
+        bvh.nodes.reserve(aabbs.len() * 2 - 1);
+        bvh.leaves = alloc::vec![None; aabbs.len()];
+        bvh.count = aabbs.len();
+        bvh.root = Some(bvh.build_range(aabbs, &order, None));
+        bvh
+    }
+
+    /// Gets the number of items
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Aabb;
+    /// use vex::Bvh;
+    /// use vex::Vector3;
+    ///
+    /// let mut bvh = Bvh::new();
+    /// bvh.insert(Aabb::make(Vector3::new(), Vector3::one()));
+    /// assert_eq!(bvh.len(), 1);
+    /// ```
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.count
+    }
+
+    /// Determines whether or not there are no items
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Aabb;
+    /// use vex::Bvh;
+    /// use vex::Vector3;
+    ///
+    /// let mut bvh = Bvh::new();
+    /// let id = bvh.insert(Aabb::make(Vector3::new(), Vector3::one()));
+    /// assert!(!bvh.is_empty());
+    ///
+    /// bvh.remove(id);
+    /// assert!(bvh.is_empty());
+    /// ```
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.count == 0
+    }
+
+    /// Gets the box containing every item, or `None` if there are no items
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Aabb;
+    /// use vex::Bvh;
+    /// use vex::Vector3;
+    ///
+    /// let a = Aabb::make(Vector3::new(), Vector3::one());
+    /// let b = Aabb::make(Vector3::make(-2.0, 0.0, 0.0), Vector3::make(-1.0, 3.0, 1.0));
+    /// let actual = Bvh::build(&[a, b]).bounds();
+    /// assert_eq!(actual, Some(Aabb::union(&a, &b)));
+    /// ```
+    #[inline]
+    pub fn bounds(&self) -> Option<Aabb> {
+        self.root.map(|root| self.nodes[root].aabb)
+    }
+
+    /// Gets the box of an item, or `None` if there's no item with that id
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Aabb;
+    /// use vex::Bvh;
+    /// use vex::Vector3;
+    ///
+    /// let a = Aabb::make(Vector3::new(), Vector3::one());
+    /// let bvh = Bvh::build(&[a]);
+    /// assert_eq!(bvh.get(0), Some(a));
+    /// assert_eq!(bvh.get(1), None);
+    /// ```
+    #[inline]
+    pub fn get(&self, id: usize) -> Option<Aabb> {
+        let node = (*self.leaves.get(id)?)?;
+        Some(self.nodes[node].aabb)
+    }
+
+    /// Adds an item, returning its id
+    ///
+    /// The item joins the subtree whose bounds grow the least by taking it in. Ids of removed
+    /// items are handed out again before new ones.
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Aabb;
+    /// use vex::Bvh;
+    /// use vex::Vector3;
+    ///
+    /// let mut bvh = Bvh::build(&[Aabb::make(Vector3::new(), Vector3::one())]);
+    /// let id = bvh.insert(Aabb::make(Vector3::make(4.0, 0.0, 0.0), Vector3::make(5.0, 1.0, 1.0)));
+    /// assert_eq!(id, 1);
+    ///
+    /// let query = Aabb::make(Vector3::make(4.5, 0.5, 0.5), Vector3::make(6.0, 2.0, 2.0));
+    /// assert_eq!(bvh.query_aabb(&query), vec![1]);
+    /// ```
+    pub fn insert(&mut self, aabb: Aabb) -> usize {
+        let id = match self.free_ids.pop() {
+            Some(id) => id,
+            None => {
+                self.leaves.push(None);
+                self.leaves.len() - 1
+            }
+        };
+
+        let leaf = self.allocate(Node {
+            aabb,
+            parent: None,
+            content: Content::Leaf(id),
+        });
+        self.leaves[id] = Some(leaf);
+        self.count += 1;
+
+        let root = match self.root {
+            Some(root) => root,
+            None => {
+                self.root = Some(leaf);
+                return id;
+            }
+        };
+
+        // walk down to the cheapest sibling, pricing each step by how much it grows the bounds
+        let mut sibling = root;
+        while let Content::Branch(left, right) = self.nodes[sibling].content {
+            let area = self.nodes[sibling].aabb.surface_area();
+            let combined = Aabb::union(&self.nodes[sibling].aabb, &aabb).surface_area();
+            let cost = 2.0 * combined;
+            let inherited = 2.0 * (combined - area);
+
+            let left_cost = self.descend_cost(left, &aabb) + inherited;
+            let right_cost = self.descend_cost(right, &aabb) + inherited;
+            if cost < left_cost && cost < right_cost {
+                break;
+            }
+
+            sibling = if left_cost < right_cost { left } else { right };
+        }
+
+        let old_parent = self.nodes[sibling].parent;
+        let parent = self.allocate(Node {
+            aabb: Aabb::union(&self.nodes[sibling].aabb, &aabb),
+            parent: old_parent,
+            content: Content::Branch(sibling, leaf),
+        });
+        self.nodes[sibling].parent = Some(parent);
+        self.nodes[leaf].parent = Some(parent);
+
+        match old_parent {
+            Some(old_parent) => {
+                self.replace_child(old_parent, sibling, parent);
+                self.refit_ancestors(old_parent);
+            }
+            None => self.root = Some(parent),
+        }
+
+        id
+    }
+
+    /// Removes an item, returning whether or not it was there
+    ///
+    /// Its sibling takes the place of their shared parent, and the bounds above are shrunk.
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Aabb;
+    /// use vex::Bvh;
+    /// use vex::Vector3;
+    ///
+    /// let a = Aabb::make(Vector3::new(), Vector3::one());
+    /// let b = Aabb::make(Vector3::make(4.0, 0.0, 0.0), Vector3::make(5.0, 1.0, 1.0));
+    /// let mut bvh = Bvh::build(&[a, b]);
+    /// assert!(bvh.remove(1));
+    /// assert!(!bvh.remove(1));
+    /// assert_eq!(bvh.bounds(), Some(a));
+    ///
+    /// // the removed id is reused
+    /// assert_eq!(bvh.insert(b), 1);
+    /// ```
+    pub fn remove(&mut self, id: usize) -> bool {
+        let leaf = match self.leaves.get(id) {
+            Some(&Some(leaf)) => leaf,
+            _ => return false,
+        };
+
+        self.leaves[id] = None;
+        self.free_ids.push(id);
+        self.free_nodes.push(leaf);
+        self.count -= 1;
+
+        let parent = match self.nodes[leaf].parent {
+            Some(parent) => parent,
+            None => {
+                self.root = None;
+                return true;
+            }
+        };
+
+        let sibling = match self.nodes[parent].content {
+            Content::Branch(left, right) if left == leaf => right,
+            Content::Branch(left, _) => left,
+            Content::Leaf(_) => unreachable!(),
+        };
+
+        let grandparent = self.nodes[parent].parent;
+        self.nodes[sibling].parent = grandparent;
+        self.free_nodes.push(parent);
+        match grandparent {
+            Some(grandparent) => {
+                self.replace_child(grandparent, parent, sibling);
+                self.refit_ancestors(grandparent);
+            }
+            None => self.root = Some(sibling),
+        }
+
+        true
+    }
+
+    /// Updates every item's box and recomputes the bounds above them, without restructuring
+    ///
+    /// `new_aabbs` is indexed by item id, and entries for ids that aren't in use are ignored. This
+    /// is much cheaper than rebuilding, but the tree gets less efficient to query as items move
+    /// far from where they were built or inserted.
+    ///
+    /// Panics if `new_aabbs` is too short to hold every item's id.
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Aabb;
+    /// use vex::Bvh;
+    /// use vex::Vector3;
+    ///
+    /// let mut aabbs = vec![
+    ///     Aabb::make(Vector3::new(), Vector3::one()),
+    ///     Aabb::make(Vector3::make(4.0, 0.0, 0.0), Vector3::make(5.0, 1.0, 1.0)),
+    /// ];
+    /// let mut bvh = Bvh::build(&aabbs);
+    ///
+    /// aabbs[1].min.y += 10.0;
+    /// aabbs[1].max.y += 10.0;
+    /// bvh.refit(&aabbs);
+    /// assert_eq!(bvh.get(1), Some(aabbs[1]));
+    /// assert_eq!(bvh.bounds(), Some(Aabb::union(&aabbs[0], &aabbs[1])));
+    ///
+    /// let query = Aabb::make(Vector3::make(4.0, 10.0, 0.0), Vector3::make(5.0, 11.0, 1.0));
+    /// assert_eq!(bvh.query_aabb(&query), vec![1]);
+    /// ```
+    pub fn refit(&mut self, new_aabbs: &[Aabb]) {
+        let root = match self.root {
+            Some(root) => root,
+            None => return,
+        };
+
+        // parents come before their children in preorder, so the reverse visits children first
+        let mut order = Vec::with_capacity(self.nodes.len());
+        let mut stack = alloc::vec![root];
+        while let Some(i) = stack.pop() {
+            order.push(i);
+            if let Content::Branch(left, right) = self.nodes[i].content {
+                stack.push(left);
+                stack.push(right);
+            }
+        }
+
+        for &i in order.iter().rev() {
+            self.nodes[i].aabb = match self.nodes[i].content {
+                Content::Leaf(id) => new_aabbs[id],
+                Content::Branch(left, right) => {
+                    Aabb::union(&self.nodes[left].aabb, &self.nodes[right].aabb)
+                }
+            };
+        }
+    }
+
+    /// Find the ids of every item whose box overlaps a box (inclusive)
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Aabb;
+    /// use vex::Bvh;
+    /// use vex::Vector3;
+    ///
+    /// let aabbs: Vec<Aabb> = (0..16)
+    ///     .map(|i| {
+    ///         let min = Vector3::make((i % 4) as f32 * 2.0, (i / 4) as f32 * 2.0, 0.0);
+    ///         Aabb::make(min, min + Vector3::one())
+    ///     })
+    ///     .collect();
+    /// let bvh = Bvh::build(&aabbs);
+    ///
+    /// let query = Aabb::make(Vector3::make(0.5, 0.5, 0.0), Vector3::make(2.5, 0.5, 0.0));
+    /// let mut actual = bvh.query_aabb(&query);
+    /// actual.sort();
+    /// assert_eq!(actual, vec![0, 1]);
+    /// ```
+    pub fn query_aabb(&self, aabb: &Aabb) -> Vec<usize> {
+        let mut ids = Vec::new();
+        let mut stack: Vec<usize> = self.root.into_iter().collect();
+        while let Some(i) = stack.pop() {
+            let node = &self.nodes[i];
+            if !node.aabb.intersects(aabb) {
+                continue;
+            }
+
+            match node.content {
+                Content::Leaf(id) => ids.push(id),
+                Content::Branch(left, right) => {
+                    stack.push(left);
+                    stack.push(right);
+                }
+            }
+        }
+
+        ids
+    }
+
+    // builds the subtree over a run of items sorted by Morton code, split at its middle
+    fn build_range(
+        &mut self,
+        aabbs: &[Aabb],
+        order: &[(u32, usize)],
+        parent: Option<usize>,
+    ) -> usize {
+        if let [(_, id)] = *order {
+            let leaf = self.allocate(Node {
+                aabb: aabbs[id],
+                parent,
+                content: Content::Leaf(id),
+            });
+            self.leaves[id] = Some(leaf);
+            return leaf;
+        }
+
+        // the children are filled in once they exist
+        let node = self.allocate(Node {
+            aabb: aabbs[order[0].1],
+            parent,
+            content: Content::Leaf(order[0].1),
+        });
+
+        let (low, high) = order.split_at(order.len() / 2);
+        let left = self.build_range(aabbs, low, Some(node));
+        let right = self.build_range(aabbs, high, Some(node));
+        self.nodes[node].aabb = Aabb::union(&self.nodes[left].aabb, &self.nodes[right].aabb);
+        self.nodes[node].content = Content::Branch(left, right);
+        node
+    }
+
+    fn allocate(&mut self, node: Node) -> usize {
+        match self.free_nodes.pop() {
+            Some(i) => {
+                self.nodes[i] = node;
+                i
+            }
+            None => {
+                self.nodes.push(node);
+                self.nodes.len() - 1
+            }
+        }
+    }
+
+    // how much the bounds grow if the box goes under a node
+    fn descend_cost(&self, i: usize, aabb: &Aabb) -> f32 {
+        let node = &self.nodes[i];
+        let combined = Aabb::union(&node.aabb, aabb).surface_area();
+        match node.content {
+            Content::Leaf(_) => combined,
+            Content::Branch(..) => combined - node.aabb.surface_area(),
+        }
+    }
+
+    fn replace_child(&mut self, parent: usize, old: usize, new: usize) {
+        if let Content::Branch(ref mut left, ref mut right) = self.nodes[parent].content {
+            if *left == old {
+                *left = new;
+            } else {
+                *right = new;
+            }
+        }
+    }
+
+    fn refit_ancestors(&mut self, start: usize) {
+        let mut current = Some(start);
+        while let Some(i) = current {
+            if let Content::Branch(left, right) = self.nodes[i].content {
+                self.nodes[i].aabb = Aabb::union(&self.nodes[left].aabb, &self.nodes[right].aabb);
+            }
+
+            current = self.nodes[i].parent;
+        }
+    }
+}
+
+// interleaves the low 10 bits of each coordinate, x in the lowest bit
+fn morton_code(x: u32, y: u32, z: u32) -> u32 {
+    spread_bits(x.min(1023)) | (spread_bits(y.min(1023)) << 1) | (spread_bits(z.min(1023)) << 2)
+}
+
+// spaces the low 10 bits of a value two bits apart
+fn spread_bits(v: u32) -> u32 {
+    let v = (v | (v << 16)) & 0x0300_00ff;
+    let v = (v | (v << 8)) & 0x0300_f00f;
+    let v = (v | (v << 4)) & 0x030c_30c3;
+    (v | (v << 2)) & 0x0924_9249
+}
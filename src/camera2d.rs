@@ -0,0 +1,194 @@
+#[cfg(not(feature = "std"))]
+use crate::math::Float;
+use crate::common::Matrix;
+use crate::matrix3::Matrix3;
+use crate::matrix4::Matrix4;
+use crate::rect::Rect;
+use crate::vector2::Vector2;
+
+/// A 2D camera looking at a world with y up, through a viewport measured in pixels
+///
+/// `position` is the world point shown at the center of the viewport, `zoom` is the number of
+/// pixels per world unit, and `rotation` turns the camera counter-clockwise in radians, which
+/// turns the world clockwise on screen. Screen coordinates have their origin at the top-left
+/// corner of the viewport with y down, like window and mouse coordinates.
+///
+/// With `pixel_snap` set, the world is moved onto whole pixels so sprites drawn at whole world
+/// positions with an integer zoom stay crisp instead of shimmering as the camera pans.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Camera2D {
+    pub position: Vector2,
+    pub zoom: f32,
+    pub rotation: f32,
+    pub viewport: Vector2,
+    pub pixel_snap: bool,
+}
+
+impl Camera2D {
+    /// Creates a camera from the provided view and viewport size, without pixel snapping
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Camera2D;
+    /// use vex::Vector2;
+    ///
+    /// let actual = Camera2D::make(Vector2::make(1.0, 2.0), 4.0, 0.0, Vector2::make(800.0, 600.0));
+    /// assert_eq!(actual.position, Vector2::make(1.0, 2.0));
+    /// assert_eq!(actual.zoom, 4.0);
+    /// assert!(!actual.pixel_snap);
+    /// ```
+    #[inline]
+    pub const fn make(position: Vector2, zoom: f32, rotation: f32, viewport: Vector2) -> Camera2D {
+        Camera2D {
+            position,
+            zoom,
+            rotation,
+            viewport,
+            pixel_snap: false,
+        }
+    }
+
+    /// Gets the matrix taking world positions to screen positions in pixels
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Camera2D;
+    /// use vex::Matrix;
+    /// use vex::Vector2;
+    ///
+    /// let camera = Camera2D::make(Vector2::make(10.0, 10.0), 2.0, 0.0, Vector2::make(800.0, 600.0));
+    /// let m = camera.view_matrix();
+    /// assert_eq!(m.transform_point(&Vector2::make(10.0, 10.0)), Vector2::make(400.0, 300.0));
+    /// assert_eq!(m.transform_point(&Vector2::make(11.0, 11.0)), Vector2::make(402.0, 298.0));
+    ///
+    /// let mut camera = Camera2D::make(Vector2::make(0.3, 0.0), 1.0, 0.0, Vector2::make(800.0, 600.0));
+    /// assert!((camera.world_to_screen(&Vector2::new()).x - 399.7).abs() < 0.0001);
+    /// camera.pixel_snap = true;
+    /// assert_eq!(camera.world_to_screen(&Vector2::new()), Vector2::make(400.0, 300.0));
+    /// ```
+    pub fn view_matrix(&self) -> Matrix3 {
+        let center = self.viewport * 0.5;
+        let mut m = Matrix3::translate_2d(center.x, center.y)
+            * Matrix3::scale_2d(self.zoom, -self.zoom)
+            * Matrix3::rotate_2d(-self.rotation)
+            * Matrix3::translate_2d(-self.position.x, -self.position.y);
+
+        if self.pixel_snap {
+            m.set_m13(m.m13().round());
+            m.set_m23(m.m23().round());
+        }
+
+        m
+    }
+
+    /// Gets the matrix taking world positions to OpenGL clip space, for rendering through the
+    /// camera
+    ///
+    /// Depth passes through unchanged.
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Camera2D;
+    /// use vex::Matrix;
+    /// use vex::Vector2;
+    /// use vex::Vector3;
+    ///
+    /// let camera = Camera2D::make(Vector2::make(5.0, 0.0), 10.0, 0.0, Vector2::make(200.0, 100.0));
+    /// let m = camera.view_projection();
+    /// let actual = m.transform_point(&Vector3::make(5.0, 0.0, 0.5));
+    /// assert!((actual - Vector3::make(0.0, 0.0, 0.5)).mag() < 0.000001);
+    /// let actual = m.transform_point(&Vector3::make(15.0, 5.0, 0.0));
+    /// assert!((actual - Vector3::make(1.0, 1.0, 0.0)).mag() < 0.000001);
+    /// ```
+    pub fn view_projection(&self) -> Matrix4 {
+        let to_clip = Matrix3::translate_2d(-1.0, 1.0)
+            * Matrix3::scale_2d(2.0 / self.viewport.x, -2.0 / self.viewport.y);
+        let m = to_clip * self.view_matrix();
+
+        Matrix4::make(
+            m.m11(), m.m21(), 0.0, 0.0,
+            m.m12(), m.m22(), 0.0, 0.0,
+            0.0, 0.0, 1.0, 0.0,
+            m.m13(), m.m23(), 0.0, 1.0,
+        )
+    }
+
+    /// Find the screen position in pixels of a world position
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Camera2D;
+    /// use vex::Vector2;
+    ///
+    /// let rotation = std::f32::consts::FRAC_PI_2;
+    /// let camera = Camera2D::make(Vector2::new(), 1.0, rotation, Vector2::make(100.0, 100.0));
+    ///
+    /// // the camera is turned left, so the world's +y axis points right on screen
+    /// let actual = camera.world_to_screen(&Vector2::make(0.0, 10.0));
+    /// assert!((actual - Vector2::make(60.0, 50.0)).mag() < 0.0001);
+    /// ```
+    #[inline]
+    pub fn world_to_screen(&self, world: &Vector2) -> Vector2 {
+        self.view_matrix().transform_point(world)
+    }
+
+    /// Find the world position under a screen position in pixels
+    ///
+    /// A camera with zero zoom sees only its own position.
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Camera2D;
+    /// use vex::Vector2;
+    ///
+    /// let camera = Camera2D::make(Vector2::make(3.0, -2.0), 8.0, 0.6, Vector2::make(640.0, 480.0));
+    /// let world = Vector2::make(4.5, 1.0);
+    /// let actual = camera.screen_to_world(&camera.world_to_screen(&world));
+    /// assert!((actual - world).mag() < 0.0001);
+    ///
+    /// // the top-left corner of the viewport
+    /// let camera = Camera2D::make(Vector2::new(), 10.0, 0.0, Vector2::make(640.0, 480.0));
+    /// assert_eq!(camera.screen_to_world(&Vector2::new()), Vector2::make(-32.0, 24.0));
+    /// ```
+    #[inline]
+    pub fn screen_to_world(&self, screen: &Vector2) -> Vector2 {
+        self.view_matrix()
+            .try_inverse()
+            .map_or(self.position, |m| m.transform_point(screen))
+    }
+
+    /// Centers the camera on a world rectangle and zooms so all of it fits in the viewport
+    ///
+    /// The rectangle is fit as it appears through the camera's rotation. An empty rectangle only
+    /// recenters the camera.
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Camera2D;
+    /// use vex::Rect;
+    /// use vex::Vector2;
+    ///
+    /// let mut camera = Camera2D::make(Vector2::new(), 1.0, 0.0, Vector2::make(800.0, 600.0));
+    /// camera.zoom_to_fit(&Rect::make(Vector2::make(10.0, 0.0), Vector2::make(30.0, 10.0)));
+    /// assert_eq!(camera.position, Vector2::make(20.0, 5.0));
+    /// assert_eq!(camera.zoom, 40.0);
+    ///
+    /// let corner = camera.world_to_screen(&Vector2::make(10.0, 10.0));
+    /// assert_eq!(corner, Vector2::make(0.0, 100.0));
+    /// ```
+    pub fn zoom_to_fit(&mut self, rect: &Rect) {
+        self.position = rect.center();
+
+        let (sine, cosine) = self.rotation.sin_cos();
+        let (width, height) = (rect.width(), rect.height());
+        let fit_width = cosine.abs() * width + sine.abs() * height;
+        let fit_height = sine.abs() * width + cosine.abs() * height;
+
+        let zoom_x = if fit_width > 0.0 { self.viewport.x / fit_width } else { f32::INFINITY };
+        let zoom_y = if fit_height > 0.0 { self.viewport.y / fit_height } else { f32::INFINITY };
+        let zoom = zoom_x.min(zoom_y);
+        if zoom.is_finite() {
+            self.zoom = zoom;
+        }
+    }
+}
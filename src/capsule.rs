@@ -0,0 +1,270 @@
+use crate::aabb::Aabb;
+use crate::plane::Plane;
+use crate::vector3::Vector3;
+
+/// How two overlapping 3D shapes penetrate each other
+///
+/// Moving the second shape by `normal * depth` (or the first by the opposite) separates them.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Contact3 {
+    /// The unit direction from the first shape towards the second
+    pub normal: Vector3,
+    /// How far the shapes overlap along the normal
+    pub depth: f32,
+}
+
+impl Contact3 {
+    /// Gets the same contact as seen from the second shape
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Contact3;
+    /// use vex::Vector3;
+    ///
+    /// let contact = Contact3 { normal: Vector3::up(), depth: 0.5 };
+    /// let actual = contact.flipped();
+    /// assert_eq!(actual.normal, Vector3::make(0.0, -1.0, 0.0));
+    /// assert_eq!(actual.depth, 0.5);
+    /// ```
+    #[inline]
+    pub fn flipped(&self) -> Contact3 {
+        Contact3 {
+            normal: -self.normal,
+            depth: self.depth,
+        }
+    }
+}
+
+/// A 3D capsule: every point within `radius` of the segment from `a` to `b`
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Capsule {
+    pub a: Vector3,
+    pub b: Vector3,
+    pub radius: f32,
+}
+
+impl Capsule {
+    /// Creates a capsule from the provided segment and radius
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Capsule;
+    /// use vex::Vector3;
+    ///
+    /// let actual = Capsule::make(Vector3::new(), Vector3::make(0.0, 2.0, 0.0), 0.5);
+    /// assert_eq!(actual.b, Vector3::make(0.0, 2.0, 0.0));
+    /// assert_eq!(actual.radius, 0.5);
+    /// ```
+    #[inline]
+    pub const fn make(a: Vector3, b: Vector3, radius: f32) -> Capsule {
+        Capsule { a, b, radius }
+    }
+
+    /// Find the capsule moved by an offset
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Capsule;
+    /// use vex::Vector3;
+    ///
+    /// let capsule = Capsule::make(Vector3::new(), Vector3::make(0.0, 2.0, 0.0), 0.5);
+    /// let actual = capsule.translated(&Vector3::make(1.0, 0.0, 0.0));
+    /// assert_eq!(actual.a, Vector3::make(1.0, 0.0, 0.0));
+    /// assert_eq!(actual.b, Vector3::make(1.0, 2.0, 0.0));
+    /// ```
+    #[inline]
+    pub fn translated(&self, offset: &Vector3) -> Capsule {
+        Capsule::make(self.a + *offset, self.b + *offset, self.radius)
+    }
+
+    /// Find the point on the capsule's segment closest to a point
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Capsule;
+    /// use vex::Vector3;
+    ///
+    /// let capsule = Capsule::make(Vector3::new(), Vector3::make(0.0, 2.0, 0.0), 0.5);
+    /// let actual = capsule.closest_on_segment(&Vector3::make(3.0, 1.0, 0.0));
+    /// assert_eq!(actual, Vector3::make(0.0, 1.0, 0.0));
+    ///
+    /// let actual = capsule.closest_on_segment(&Vector3::make(3.0, 5.0, 0.0));
+    /// assert_eq!(actual, Vector3::make(0.0, 2.0, 0.0));
+    /// ```
+    #[inline]
+    pub fn closest_on_segment(&self, point: &Vector3) -> Vector3 {
+        let d = self.b - self.a;
+        let length_sq = d.mag_sq();
+        if length_sq == 0.0 {
+            return self.a;
+        }
+
+        let t = (Vector3::dot(&(*point - self.a), &d) / length_sq).clamp(0.0, 1.0);
+        self.a + d * t
+    }
+
+    /// Determine whether or not a point is inside the capsule
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Capsule;
+    /// use vex::Vector3;
+    ///
+    /// let capsule = Capsule::make(Vector3::new(), Vector3::make(0.0, 2.0, 0.0), 0.5);
+    /// assert!(capsule.contains_point(&Vector3::make(0.5, 1.0, 0.0)));
+    /// assert!(capsule.contains_point(&Vector3::make(0.0, 2.4, 0.0)));
+    /// assert!(!capsule.contains_point(&Vector3::make(0.4, 2.4, 0.0)));
+    /// ```
+    #[inline]
+    pub fn contains_point(&self, point: &Vector3) -> bool {
+        let closest = self.closest_on_segment(point);
+        (*point - closest).mag_sq() <= self.radius * self.radius
+    }
+
+    /// Find how the capsule overlaps the solid half-space behind a plane
+    ///
+    /// Everything on the negative side of the plane counts as solid, and the plane's normal is
+    /// expected to be normalized.
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Capsule;
+    /// use vex::Plane;
+    /// use vex::Vector3;
+    ///
+    /// let floor = Plane::from_point_normal(&Vector3::new(), &Vector3::up());
+    /// let capsule = Capsule::make(
+    ///     Vector3::make(0.0, 0.25, 0.0),
+    ///     Vector3::make(0.0, 2.0, 0.0),
+    ///     0.5,
+    /// );
+    /// let actual = capsule.overlap_plane(&floor).unwrap();
+    /// assert_eq!(actual.normal, Vector3::make(0.0, -1.0, 0.0));
+    /// assert_eq!(actual.depth, 0.25);
+    ///
+    /// assert_eq!(capsule.translated(&Vector3::make(0.0, 1.0, 0.0)).overlap_plane(&floor), None);
+    /// ```
+    #[inline]
+    pub fn overlap_plane(&self, plane: &Plane) -> Option<Contact3> {
+        let lowest = plane.signed_distance(&self.a).min(plane.signed_distance(&self.b));
+        if lowest > self.radius {
+            return None;
+        }
+
+        Some(Contact3 {
+            normal: -plane.normal,
+            depth: self.radius - lowest,
+        })
+    }
+
+    /// Find how the capsule overlaps an axis-aligned box
+    ///
+    /// When the capsule's segment passes through the box, the contact pushes the capsule out
+    /// through the face it needs to move the least to clear.
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Aabb;
+    /// use vex::Capsule;
+    /// use vex::Vector3;
+    ///
+    /// let aabb = Aabb::make(Vector3::new(), Vector3::one());
+    ///
+    /// // resting on top of the box
+    /// let capsule = Capsule::make(
+    ///     Vector3::make(0.5, 1.25, 0.5),
+    ///     Vector3::make(0.5, 3.0, 0.5),
+    ///     0.5,
+    /// );
+    /// let actual = capsule.overlap_aabb(&aabb).unwrap();
+    /// assert_eq!(actual.normal, Vector3::make(0.0, -1.0, 0.0));
+    /// assert_eq!(actual.depth, 0.25);
+    ///
+    /// // the segment passes through the box, closest to its +x face
+    /// let capsule = Capsule::make(
+    ///     Vector3::make(0.75, -1.0, 0.5),
+    ///     Vector3::make(0.75, 2.0, 0.5),
+    ///     0.5,
+    /// );
+    /// let actual = capsule.overlap_aabb(&aabb).unwrap();
+    /// assert_eq!(actual.normal, Vector3::make(-1.0, 0.0, 0.0));
+    /// assert_eq!(actual.depth, 0.75);
+    ///
+    /// let capsule = Capsule::make(
+    ///     Vector3::make(2.0, 0.0, 0.5),
+    ///     Vector3::make(2.0, 1.0, 0.5),
+    ///     0.5,
+    /// );
+    /// assert_eq!(capsule.overlap_aabb(&aabb), None);
+    /// ```
+    pub fn overlap_aabb(&self, aabb: &Aabb) -> Option<Contact3> {
+        let (p, q) = self.closest_to_aabb(aabb);
+        let offset = q - p;
+        let distance = offset.mag();
+        if distance > self.radius {
+            return None;
+        } else if distance > 0.0 {
+            return Some(Contact3 {
+                normal: offset / distance,
+                depth: self.radius - distance,
+            });
+        }
+
+        // the segment reaches inside, so find the face that's quickest to push it out through
+        let mut best = Contact3 {
+            normal: Vector3::ZERO,
+            depth: f32::INFINITY,
+        };
+
+        for axis in 0..3u32 {
+            let low = self.a[axis].min(self.b[axis]);
+            let high = self.a[axis].max(self.b[axis]);
+            let mut normal = Vector3::ZERO;
+
+            let depth = aabb.max[axis] - low + self.radius;
+            if depth < best.depth {
+                normal[axis] = -1.0;
+                best = Contact3 { normal, depth };
+            }
+
+            let depth = high - aabb.min[axis] + self.radius;
+            if depth < best.depth {
+                normal[axis] = 1.0;
+                best = Contact3 { normal, depth };
+            }
+        }
+
+        Some(best)
+    }
+
+    // the closest points between the segment and the box, found by ternary search since the
+    // distance from the box is convex along the segment
+    fn closest_to_aabb(&self, aabb: &Aabb) -> (Vector3, Vector3) {
+        let d = self.b - self.a;
+        let distance_sq = |t: f32| {
+            let p = self.a + d * t;
+            (p.clamped(&aabb.min, &aabb.max) - p).mag_sq()
+        };
+
+        let (mut low, mut high) = (0.0f32, 1.0f32);
+        for _ in 0..32 {
+            let third = (high - low) / 3.0;
+            if distance_sq(low + third) <= distance_sq(high - third) {
+                high -= third;
+            } else {
+                low += third;
+            }
+        }
+
+        // upright capsules usually rest an end on the box, so prefer the exact ends on ties
+        let mut t = (low + high) * 0.5;
+        for &end in [0.0, 1.0].iter() {
+            if distance_sq(end) <= distance_sq(t) {
+                t = end;
+            }
+        }
+
+        let p = self.a + d * t;
+        (p, p.clamped(&aabb.min, &aabb.max))
+    }
+}
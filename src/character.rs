@@ -0,0 +1,211 @@
+#[cfg(not(feature = "std"))]
+use crate::math::Float;
+use crate::aabb::Aabb;
+use crate::capsule::Capsule;
+use crate::capsule::Contact3;
+use crate::plane::Plane;
+use crate::vector3::Vector3;
+
+/// How many times `resolve_penetrations` revisits the shapes, since pushing out of one can push
+/// into another
+const RESOLVE_ITERATIONS: usize = 4;
+
+/// A static shape a character can stand on or bump into
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum Obstacle {
+    /// The solid half-space behind a plane with a normalized normal
+    Plane(Plane),
+    /// A solid axis-aligned box
+    Aabb(Aabb),
+}
+
+impl Obstacle {
+    /// Find how a capsule overlaps the obstacle
+    ///
+    /// The contact's normal points from the capsule towards the obstacle.
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Aabb;
+    /// use vex::Capsule;
+    /// use vex::Obstacle;
+    /// use vex::Vector3;
+    ///
+    /// let obstacle = Obstacle::Aabb(Aabb::make(Vector3::new(), Vector3::one()));
+    /// let capsule = Capsule::make(
+    ///     Vector3::make(1.25, 0.5, 0.5),
+    ///     Vector3::make(3.0, 0.5, 0.5),
+    ///     0.5,
+    /// );
+    /// let actual = obstacle.overlap_capsule(&capsule).unwrap();
+    /// assert_eq!(actual.normal, Vector3::make(-1.0, 0.0, 0.0));
+    /// assert_eq!(actual.depth, 0.25);
+    /// ```
+    #[inline]
+    pub fn overlap_capsule(&self, capsule: &Capsule) -> Option<Contact3> {
+        match *self {
+            Obstacle::Plane(ref plane) => capsule.overlap_plane(plane),
+            Obstacle::Aabb(ref aabb) => capsule.overlap_aabb(aabb),
+        }
+    }
+}
+
+/// The surface found under a character by `find_ground`
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Ground {
+    /// Where the capsule touches the ground once it's moved by `distance`
+    pub point: Vector3,
+    /// The unit surface normal at the touching point, which always points somewhat upwards
+    pub normal: Vector3,
+    /// How far to move the capsule down to rest on the ground, which is negative when the ground
+    /// is above the capsule's bottom and it needs to step up
+    pub distance: f32,
+}
+
+/// Find the ground a capsule can snap down to or step up onto
+///
+/// Each end of the capsule is dropped straight down onto the obstacles, and the highest surface
+/// reachable by moving it at most `max_snap` down or up is returned. Only surfaces facing
+/// somewhat upwards count as ground, so walls and ceilings are ignored. The ground under a
+/// tilted capsule's middle is missed, which suits character capsules since they stay upright.
+///
+/// Returns `None` when nothing is in reach.
+///
+/// # Examples
+/// ```
+/// use vex::Aabb;
+/// use vex::Capsule;
+/// use vex::Obstacle;
+/// use vex::Plane;
+/// use vex::Vector3;
+/// use vex::find_ground;
+///
+/// let floor = Obstacle::Plane(Plane::from_point_normal(&Vector3::new(), &Vector3::up()));
+/// let step = Obstacle::Aabb(
+///     Aabb::make(Vector3::make(0.0, 0.0, -1.0), Vector3::make(2.0, 0.25, 1.0)),
+/// );
+///
+/// // hovering a little above the floor
+/// let capsule = Capsule::make(Vector3::make(-2.0, 0.6, 0.0), Vector3::make(-2.0, 2.0, 0.0), 0.5);
+/// let actual = find_ground(&capsule, &[floor, step], 0.3).unwrap();
+/// assert!((actual.distance - 0.1).abs() < 0.00001);
+/// assert_eq!(actual.normal, Vector3::up());
+/// assert_eq!(actual.point, Vector3::make(-2.0, 0.0, 0.0));
+///
+/// // the step is within reach, so the capsule steps up onto it
+/// let capsule = capsule.translated(&Vector3::make(3.0, 0.0, 0.0));
+/// let actual = find_ground(&capsule, &[floor, step], 0.3).unwrap();
+/// assert!((actual.distance + 0.15).abs() < 0.00001);
+/// assert_eq!(actual.point, Vector3::make(1.0, 0.25, 0.0));
+///
+/// // the floor is too far below to snap to
+/// assert_eq!(find_ground(&capsule, &[floor], 0.05), None);
+/// ```
+pub fn find_ground(capsule: &Capsule, candidates: &[Obstacle], max_snap: f32) -> Option<Ground> {
+    let mut best: Option<Ground> = None;
+    for candidate in candidates.iter() {
+        for end in [capsule.a, capsule.b].iter() {
+            let ground = match *candidate {
+                Obstacle::Plane(ref plane) => drop_onto_plane(end, capsule.radius, plane),
+                Obstacle::Aabb(ref aabb) => drop_onto_aabb(end, capsule.radius, aabb),
+            };
+
+            if let Some(ground) = ground {
+                let higher = match best {
+                    Some(best) => ground.distance < best.distance,
+                    None => true,
+                };
+
+                if ground.distance.abs() <= max_snap && higher {
+                    best = Some(ground);
+                }
+            }
+        }
+    }
+
+    best
+}
+
+/// Find the offset that pushes a capsule out of every obstacle it overlaps
+///
+/// Obstacles are pushed out of one at a time along their shallowest direction, and the whole set
+/// is revisited a few times since leaving one obstacle can mean entering another. Tight spots
+/// that can't be escaped, like a gap narrower than the capsule, are left partly overlapping.
+///
+/// # Examples
+/// ```
+/// use vex::Aabb;
+/// use vex::Capsule;
+/// use vex::Obstacle;
+/// use vex::Plane;
+/// use vex::Vector3;
+/// use vex::resolve_penetrations;
+///
+/// let floor = Obstacle::Plane(Plane::from_point_normal(&Vector3::new(), &Vector3::up()));
+/// let wall = Obstacle::Aabb(
+///     Aabb::make(Vector3::make(1.0, 0.0, -5.0), Vector3::make(2.0, 3.0, 5.0)),
+/// );
+///
+/// // sunk into the floor and pressed into the wall
+/// let capsule = Capsule::make(Vector3::make(0.75, 0.25, 0.0), Vector3::make(0.75, 2.0, 0.0), 0.5);
+/// let actual = resolve_penetrations(&capsule, &[floor, wall]);
+/// assert!((actual - Vector3::make(-0.25, 0.25, 0.0)).mag() < 0.00001);
+///
+/// // already clear
+/// let capsule = capsule.translated(&actual);
+/// assert_eq!(resolve_penetrations(&capsule, &[floor, wall]), Vector3::ZERO);
+/// ```
+pub fn resolve_penetrations(capsule: &Capsule, shapes: &[Obstacle]) -> Vector3 {
+    let mut offset = Vector3::ZERO;
+    for _ in 0..RESOLVE_ITERATIONS {
+        let mut moved = false;
+        for shape in shapes.iter() {
+            if let Some(contact) = shape.overlap_capsule(&capsule.translated(&offset)) {
+                if contact.depth > 0.0 {
+                    offset -= contact.normal * contact.depth;
+                    moved = true;
+                }
+            }
+        }
+
+        if !moved {
+            break;
+        }
+    }
+
+    offset
+}
+
+// drops a sphere straight down onto a plane's surface
+fn drop_onto_plane(center: &Vector3, radius: f32, plane: &Plane) -> Option<Ground> {
+    if plane.normal.y <= 0.0 {
+        return None;
+    }
+
+    let distance = (plane.signed_distance(center) - radius) / plane.normal.y;
+    let rest = *center - Vector3::up() * distance;
+    Some(Ground {
+        point: rest - plane.normal * radius,
+        normal: plane.normal,
+        distance,
+    })
+}
+
+// drops a sphere straight down onto the top of a box, which it lands on either flat or on an edge
+fn drop_onto_aabb(center: &Vector3, radius: f32, aabb: &Aabb) -> Option<Ground> {
+    let x = center.x.clamp(aabb.min.x, aabb.max.x);
+    let z = center.z.clamp(aabb.min.z, aabb.max.z);
+    let (dx, dz) = (center.x - x, center.z - z);
+    let reach_sq = radius * radius - (dx * dx + dz * dz);
+    if reach_sq <= 0.0 {
+        return None;
+    }
+
+    let rise = reach_sq.sqrt();
+    let point = Vector3::make(x, aabb.max.y, z);
+    Some(Ground {
+        point,
+        normal: Vector3::make(dx, rise, dz) / radius,
+        distance: center.y - (aabb.max.y + rise),
+    })
+}
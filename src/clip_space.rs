@@ -0,0 +1,71 @@
+/// The range that visible depths land in after the perspective divide
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum DepthRange {
+    /// The near plane maps to -1 and the far plane to 1, as in OpenGL
+    NegativeOneToOne,
+    /// The near plane maps to 0 and the far plane to 1, as in Vulkan, Direct3D, Metal, and WebGPU
+    ZeroToOne,
+    /// The near plane maps to 1 and the far plane to 0, for reverse-Z depth buffers
+    OneToZero,
+}
+
+/// The clip space conventions a graphics API expects from projection matrices
+///
+/// Every projection is right-handed, looking down -z in view space. Projections built for a
+/// convention only differ in how they map depth, and whether they flip y for APIs whose clip
+/// space y points down.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct ClipSpace {
+    pub depth: DepthRange,
+    pub flip_y: bool,
+}
+
+impl ClipSpace {
+    /// OpenGL's clip space, with depth in `[-1, 1]` and y up
+    pub const OPENGL: ClipSpace = ClipSpace {
+        depth: DepthRange::NegativeOneToOne,
+        flip_y: false,
+    };
+
+    /// Vulkan's clip space, with depth in `[0, 1]` and y down
+    pub const VULKAN: ClipSpace = ClipSpace {
+        depth: DepthRange::ZeroToOne,
+        flip_y: true,
+    };
+
+    /// The clip space of Direct3D, Metal, and WebGPU, with depth in `[0, 1]` and y up
+    pub const DIRECT3D: ClipSpace = ClipSpace {
+        depth: DepthRange::ZeroToOne,
+        flip_y: false,
+    };
+
+    /// Creates a convention from the provided depth range and y direction
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::ClipSpace;
+    /// use vex::DepthRange;
+    ///
+    /// let actual = ClipSpace::make(DepthRange::ZeroToOne, true);
+    /// assert_eq!(actual, ClipSpace::VULKAN);
+    /// ```
+    #[inline]
+    pub const fn make(depth: DepthRange, flip_y: bool) -> ClipSpace {
+        ClipSpace { depth, flip_y }
+    }
+}
+
+impl Default for ClipSpace {
+    /// Gets OpenGL's clip space, which the plain projection constructors target
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::ClipSpace;
+    ///
+    /// assert_eq!(ClipSpace::default(), ClipSpace::OPENGL);
+    /// ```
+    #[inline]
+    fn default() -> ClipSpace {
+        ClipSpace::OPENGL
+    }
+}
@@ -0,0 +1,225 @@
+use crate::aabb::Aabb;
+#[cfg(not(feature = "std"))]
+use crate::math::Float;
+use crate::matrix4::Matrix4;
+use crate::vector3::Vector3;
+
+/// Find the exponential depth slice containing a view-space depth
+///
+/// Slice boundaries are spaced so that `near * (far / near) ^ (i / slices)` is the start of slice
+/// `i`, which keeps froxels roughly cubic. Returns `None` for depths outside `[near, far)`, and
+/// when there are no slices.
+///
+/// # Examples
+/// ```
+/// use vex::froxel_slice;
+///
+/// assert_eq!(froxel_slice(1.0, 4, 1.0, 16.0), Some(0));
+/// assert_eq!(froxel_slice(3.0, 4, 1.0, 16.0), Some(1));
+/// assert_eq!(froxel_slice(15.0, 4, 1.0, 16.0), Some(3));
+/// assert_eq!(froxel_slice(16.0, 4, 1.0, 16.0), None);
+/// assert_eq!(froxel_slice(3.0, 0, 1.0, 16.0), None);
+/// ```
+#[inline]
+pub fn froxel_slice(depth: f32, slices: u32, near: f32, far: f32) -> Option<u32> {
+    if slices == 0 || !(near..far).contains(&depth) {
+        return None;
+    }
+
+    let slice = ((depth / near).ln() / (far / near).ln() * slices as f32) as u32;
+    Some(slice.min(slices - 1))
+}
+
+/// Find the view-space depth at which an exponential depth slice starts
+///
+/// This is the inverse of [`froxel_slice`](fn.froxel_slice.html), and passing `slices` returns
+/// `far`.
+///
+/// # Examples
+/// ```
+/// use vex::froxel_slice_depth;
+///
+/// assert_eq!(froxel_slice_depth(0, 4, 1.0, 16.0), 1.0);
+/// assert_eq!(froxel_slice_depth(2, 4, 1.0, 16.0), 4.0);
+/// assert_eq!(froxel_slice_depth(4, 4, 1.0, 16.0), 16.0);
+/// ```
+#[inline]
+pub fn froxel_slice_depth(slice: u32, slices: u32, near: f32, far: f32) -> f32 {
+    near * (far / near).powf(slice as f32 / slices as f32)
+}
+
+/// Find the froxel containing a view-space position, in a grid over a perspective projection
+///
+/// This is shorthand for [`FroxelGrid::index`](struct.FroxelGrid.html#method.index) when the grid
+/// isn't kept around. The projection places the x and y tiles, and `near` and `far` bound the
+/// depth slices. Returns `None` if the position lies outside the grid, or any of its dimensions
+/// are zero.
+///
+/// # Examples
+/// ```
+/// use vex::Matrix4;
+/// use vex::Vector3;
+/// use vex::froxel_index;
+///
+/// let projection = Matrix4::perspective(90.0, 1.0, 1.0, 16.0);
+/// let actual = froxel_index(&Vector3::make(-2.0, 0.5, -3.0), [4, 4, 4], 1.0, 16.0, &projection);
+/// assert_eq!(actual, Some([0, 2, 1]));
+/// assert_eq!(froxel_index(&Vector3::make(-2.0, 0.5, -3.0), [0, 4, 4], 1.0, 16.0, &projection), None);
+/// ```
+#[inline]
+pub fn froxel_index(
+    view_pos: &Vector3,
+    grid_dims: [u32; 3],
+    near: f32,
+    far: f32,
+    projection: &Matrix4,
+) -> Option<[u32; 3]> {
+    FroxelGrid::make(grid_dims, near, far, *projection).index(view_pos)
+}
+
+/// A frustum-aligned voxel grid for clustered shading
+///
+/// Tiles are evenly spaced in NDC along x and y and exponentially spaced in depth. View space
+/// looks down -z, so view-space depth is `-z`.
+#[derive(Copy, Clone, Debug)]
+pub struct FroxelGrid {
+    pub dims: [u32; 3],
+    pub near: f32,
+    pub far: f32,
+    pub projection: Matrix4,
+}
+
+impl FroxelGrid {
+    /// Creates a grid with the provided dimensions over a perspective projection
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::FroxelGrid;
+    /// use vex::Matrix4;
+    ///
+    /// let grid = FroxelGrid::make([16, 8, 24], 0.1, 100.0, Matrix4::perspective(90.0, 2.0, 0.1, 100.0));
+    /// assert_eq!(grid.count(), 16 * 8 * 24);
+    /// ```
+    #[inline]
+    pub const fn make(dims: [u32; 3], near: f32, far: f32, projection: Matrix4) -> FroxelGrid {
+        FroxelGrid {
+            dims,
+            near,
+            far,
+            projection,
+        }
+    }
+
+    /// Gets the total number of froxels in the grid
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::FroxelGrid;
+    /// use vex::Matrix4;
+    ///
+    /// let grid = FroxelGrid::make([4, 4, 4], 1.0, 16.0, Matrix4::perspective(90.0, 1.0, 1.0, 16.0));
+    /// assert_eq!(grid.count(), 64);
+    /// ```
+    #[inline]
+    pub fn count(&self) -> usize {
+        self.dims[0] as usize * self.dims[1] as usize * self.dims[2] as usize
+    }
+
+    /// Find the froxel containing a view-space position
+    ///
+    /// Returns `None` if the position lies outside the frustum covered by the grid, or any of its
+    /// dimensions are zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::FroxelGrid;
+    /// use vex::Matrix4;
+    /// use vex::Vector3;
+    ///
+    /// let grid = FroxelGrid::make([4, 4, 4], 1.0, 16.0, Matrix4::perspective(90.0, 1.0, 1.0, 16.0));
+    /// assert_eq!(grid.index(&Vector3::make(-2.0, 0.5, -3.0)), Some([0, 2, 1]));
+    /// assert_eq!(grid.index(&Vector3::make(0.0, 0.0, 2.0)), None);
+    /// ```
+    pub fn index(&self, view_pos: &Vector3) -> Option<[u32; 3]> {
+        let depth = -view_pos.z;
+        let z = froxel_slice(depth, self.dims[2], self.near, self.far)?;
+        let m = &self.projection;
+        let ndc_x = (m.m11() * view_pos.x + m.m13() * view_pos.z) / depth;
+        let ndc_y = (m.m22() * view_pos.y + m.m23() * view_pos.z) / depth;
+        let x = tile(ndc_x, self.dims[0])?;
+        let y = tile(ndc_y, self.dims[1])?;
+        Some([x, y, z])
+    }
+
+    /// Find the flattened index of the froxel containing a view-space position
+    ///
+    /// Froxels are ordered by x, then y, then depth slice.
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::FroxelGrid;
+    /// use vex::Matrix4;
+    /// use vex::Vector3;
+    ///
+    /// let grid = FroxelGrid::make([4, 4, 4], 1.0, 16.0, Matrix4::perspective(90.0, 1.0, 1.0, 16.0));
+    /// assert_eq!(grid.linear_index(&Vector3::make(-2.0, 0.5, -3.0)), Some(24));
+    /// ```
+    #[inline]
+    pub fn linear_index(&self, view_pos: &Vector3) -> Option<usize> {
+        let [x, y, z] = self.index(view_pos)?;
+        let (w, h) = (self.dims[0] as usize, self.dims[1] as usize);
+        Some(x as usize + w * (y as usize + h * z as usize))
+    }
+
+    /// Find the view-space bounds of a froxel
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Aabb;
+    /// use vex::FroxelGrid;
+    /// use vex::Matrix4;
+    /// use vex::Vector3;
+    ///
+    /// let grid = FroxelGrid::make([4, 4, 4], 1.0, 16.0, Matrix4::perspective(90.0, 1.0, 1.0, 16.0));
+    /// let bounds = grid.bounds([0, 2, 1]);
+    /// assert!(bounds.contains_point(&Vector3::make(-2.0, 0.5, -3.0)));
+    /// assert!((bounds.min - Vector3::make(-4.0, 0.0, -4.0)).mag() < 0.0001);
+    /// assert!((bounds.max - Vector3::make(-1.0, 2.0, -2.0)).mag() < 0.0001);
+    /// ```
+    pub fn bounds(&self, index: [u32; 3]) -> Aabb {
+        let near = froxel_slice_depth(index[2], self.dims[2], self.near, self.far);
+        let far = froxel_slice_depth(index[2] + 1, self.dims[2], self.near, self.far);
+        let m = &self.projection;
+        let x0 = index[0] as f32 / self.dims[0] as f32 * 2.0 - 1.0;
+        let x1 = (index[0] + 1) as f32 / self.dims[0] as f32 * 2.0 - 1.0;
+        let y0 = index[1] as f32 / self.dims[1] as f32 * 2.0 - 1.0;
+        let y1 = (index[1] + 1) as f32 / self.dims[1] as f32 * 2.0 - 1.0;
+
+        let mut points = [Vector3::new(); 8];
+        let mut i = 0;
+        for &depth in &[near, far] {
+            for &ndc_x in &[x0, x1] {
+                for &ndc_y in &[y0, y1] {
+                    points[i] = Vector3::make(
+                        depth * (ndc_x + m.m13()) / m.m11(),
+                        depth * (ndc_y + m.m23()) / m.m22(),
+                        -depth,
+                    );
+                    i += 1;
+                }
+            }
+        }
+
+        Aabb::from_points(&points).unwrap()
+    }
+}
+
+#[inline]
+fn tile(ndc: f32, count: u32) -> Option<u32> {
+    if count == 0 || !(-1.0..=1.0).contains(&ndc) {
+        return None;
+    }
+
+    let tile = ((ndc * 0.5 + 0.5) * count as f32) as u32;
+    Some(tile.min(count - 1))
+}
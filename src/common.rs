@@ -1,3 +1,8 @@
+#[cfg(not(feature = "std"))]
+use crate::math::Float;
+
+use core::ops::{Add, Mul, Neg, Sub};
+
 #[inline]
 pub fn is_valid(x: f32) -> bool {
     !(x.is_nan() || x.is_infinite())
@@ -72,6 +77,154 @@ pub fn sign(x: f32) -> f32 {
     }
 }
 
+/// Find a minimum of two values that blends smoothly where they're within `k` of each other
+///
+/// This is the quadratic polynomial smooth minimum. Where the values differ by `k` or more it is
+/// exactly their minimum, and where they're equal it is `k / 4` below it. The blend region being
+/// bounded makes it the usual choice for combining signed distance fields. A `k` of zero or less
+/// gives the plain minimum.
+///
+/// # Examples
+/// ```
+/// use vex::smooth_min;
+///
+/// assert_eq!(smooth_min(1.0, 3.0, 1.0), 1.0);
+/// assert_eq!(smooth_min(2.0, 2.0, 1.0), 1.75);
+/// assert!(smooth_min(2.0, 2.5, 1.0) < 2.0);
+/// assert_eq!(smooth_min(2.0, 2.5, 0.0), 2.0);
+/// ```
+#[inline]
+pub fn smooth_min(a: f32, b: f32, k: f32) -> f32 {
+    if k <= 0.0 {
+        return a.min(b);
+    }
+
+    let h = (k - (a - b).abs()).max(0.0) / k;
+    a.min(b) - h * h * k * 0.25
+}
+
+/// Find a minimum of two values that blends smoothly everywhere, with sharpness `k`
+///
+/// This is the exponential smooth minimum, `-k * ln(e^(-a / k) + e^(-b / k))`. Unlike
+/// [`smooth_min`](fn.smooth_min.html) it is smooth to every order, but it never quite reaches the
+/// minimum: it is `k * ln(2)` below it where the values are equal, and slightly below it however
+/// far apart they are. It also generalizes to more than two values by nesting. A `k` of zero or
+/// less gives the plain minimum.
+///
+/// # Examples
+/// ```
+/// use vex::smooth_min_exp;
+///
+/// let actual = smooth_min_exp(2.0, 2.0, 1.0);
+/// assert!((actual - (2.0 - 2.0f32.ln())).abs() < 0.000001);
+/// assert!((smooth_min_exp(1.0, 30.0, 1.0) - 1.0).abs() < 0.000001);
+/// assert_eq!(smooth_min_exp(2.0, 2.5, 0.0), 2.0);
+/// ```
+#[inline]
+pub fn smooth_min_exp(a: f32, b: f32, k: f32) -> f32 {
+    if k <= 0.0 {
+        return a.min(b);
+    }
+
+    // offset by the minimum so the exponentials can't overflow
+    let m = a.min(b);
+    m - k * ((-(a - m) / k).exp() + (-(b - m) / k).exp()).ln()
+}
+
+/// Find a maximum of two values that blends smoothly where they're within `k` of each other
+///
+/// This mirrors [`smooth_min`](fn.smooth_min.html), sitting `k / 4` above the maximum where the
+/// values are equal.
+///
+/// # Examples
+/// ```
+/// use vex::smooth_max;
+///
+/// assert_eq!(smooth_max(1.0, 3.0, 1.0), 3.0);
+/// assert_eq!(smooth_max(2.0, 2.0, 1.0), 2.25);
+/// ```
+#[inline]
+pub fn smooth_max(a: f32, b: f32, k: f32) -> f32 {
+    -smooth_min(-a, -b, k)
+}
+
+/// Find the exponential smooth maximum of two values, with sharpness `k`
+///
+/// This mirrors [`smooth_min_exp`](fn.smooth_min_exp.html).
+///
+/// # Examples
+/// ```
+/// use vex::smooth_max_exp;
+///
+/// let actual = smooth_max_exp(2.0, 2.0, 1.0);
+/// assert!((actual - (2.0 + 2.0f32.ln())).abs() < 0.000001);
+/// ```
+#[inline]
+pub fn smooth_max_exp(a: f32, b: f32, k: f32) -> f32 {
+    -smooth_min_exp(-a, -b, k)
+}
+
+/// Find an absolute value with the corner at zero rounded off, `sqrt(x^2 + k^2)`
+///
+/// The result is always above `|x|`, by `k` at zero and by less the further `x` is from it.
+///
+/// # Examples
+/// ```
+/// use vex::soft_abs;
+///
+/// assert_eq!(soft_abs(0.0, 0.5), 0.5);
+/// assert_eq!(soft_abs(-3.0, 4.0), 5.0);
+/// assert_eq!(soft_abs(-3.0, 0.0), 3.0);
+/// ```
+#[inline]
+pub fn soft_abs(x: f32, k: f32) -> f32 {
+    (x * x + k * k).sqrt()
+}
+
 pub trait Matrix<T> {
     fn transform_point(&self, point: &T) -> T;
 }
+
+/// Operations shared by the vector types, so code can be written once for any of them
+pub trait Vector:
+    Copy
+    + PartialEq
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<f32, Output = Self>
+    + Neg<Output = Self>
+{
+    /// The vector with every component set to 0
+    fn zero() -> Self;
+
+    /// Find the dot product between two vectors
+    fn dot(a: &Self, b: &Self) -> f32;
+
+    /// Determines if every component is within `epsilon` of the other vector's
+    fn approx_eq(&self, other: &Self, epsilon: f32) -> bool;
+}
+
+/// Operations shared by the square matrix types, so code can be written once for any of them
+pub trait SquareMatrix: Copy + PartialEq + Mul<Output = Self> {
+    /// The identity matrix
+    fn identity() -> Self;
+
+    /// Find the determinant of the matrix
+    fn determinant(&self) -> f32;
+
+    /// Find the transpose of the matrix
+    fn transposed(&self) -> Self;
+
+    /// Find the inverse of the matrix, or `None` if it isn't invertible
+    fn try_inverse(&self) -> Option<Self>;
+
+    /// Determines if every element is within `epsilon` of the other matrix's
+    fn approx_eq(&self, other: &Self, epsilon: f32) -> bool;
+}
+
+/// The blend factor for `mix`, either one scalar shared by every component or a value of the same
+/// type holding a factor per component
+pub trait MixFactor<T> {
+    /// Spreads the factor out to one value per component
+    fn spread(self) -> T;
+}
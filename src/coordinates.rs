@@ -0,0 +1,165 @@
+#[cfg(not(feature = "std"))]
+use crate::math::Float;
+use crate::vector2::Vector2;
+use crate::vector3::Vector3;
+
+/// Find the point at a distance and angle from the origin in 2D
+///
+/// The angle is counter-clockwise from +x.
+///
+/// # Examples
+/// ```
+/// use vex::Vector2;
+/// use vex::polar_to_cartesian;
+///
+/// use std::f32::consts::PI;
+///
+/// let actual = polar_to_cartesian(2.0, PI / 2.0);
+/// assert!((actual - Vector2::make(0.0, 2.0)).mag() < 0.000001);
+/// ```
+#[inline]
+pub fn polar_to_cartesian(radius: f32, angle: f32) -> Vector2 {
+    let (sin, cos) = angle.sin_cos();
+    Vector2::make(radius * cos, radius * sin)
+}
+
+/// Find the distance and angle of a 2D point from the origin
+///
+/// The angle is counter-clockwise from +x, in `[-PI, PI]`, and is 0 for the origin.
+///
+/// # Examples
+/// ```
+/// use vex::Vector2;
+/// use vex::cartesian_to_polar;
+///
+/// use std::f32::consts::PI;
+///
+/// let (radius, angle) = cartesian_to_polar(&Vector2::make(0.0, -3.0));
+/// assert_eq!(radius, 3.0);
+/// assert_eq!(angle, -PI / 2.0);
+/// ```
+#[inline]
+pub fn cartesian_to_polar(point: &Vector2) -> (f32, f32) {
+    (point.mag(), point.y.atan2(point.x))
+}
+
+/// Find the point at a distance and pair of angles from the origin
+///
+/// `theta` is the angle down from +y and `phi` is the angle around y from +z towards +x, so an
+/// orbit camera can keep `theta` inside `(0, PI)` to stay off the poles.
+///
+/// # Examples
+/// ```
+/// use vex::Vector3;
+/// use vex::spherical_to_cartesian;
+///
+/// use std::f32::consts::PI;
+///
+/// let actual = spherical_to_cartesian(2.0, PI / 2.0, PI / 2.0);
+/// assert!((actual - Vector3::make(2.0, 0.0, 0.0)).mag() < 0.000001);
+///
+/// let actual = spherical_to_cartesian(2.0, 0.0, 1.0);
+/// assert!((actual - Vector3::make(0.0, 2.0, 0.0)).mag() < 0.000001);
+/// ```
+#[inline]
+pub fn spherical_to_cartesian(radius: f32, theta: f32, phi: f32) -> Vector3 {
+    let (sin_theta, cos_theta) = theta.sin_cos();
+    let (sin_phi, cos_phi) = phi.sin_cos();
+    Vector3::make(
+        radius * sin_theta * sin_phi,
+        radius * cos_theta,
+        radius * sin_theta * cos_phi,
+    )
+}
+
+/// Find the distance and pair of angles of a point from the origin
+///
+/// Returns `(radius, theta, phi)` with `theta` in `[0, PI]` and `phi` in `[-PI, PI]`. Both angles
+/// are 0 for the origin, and `phi` is 0 on the y axis.
+///
+/// # Examples
+/// ```
+/// use vex::Vector3;
+/// use vex::cartesian_to_spherical;
+/// use vex::spherical_to_cartesian;
+///
+/// let p = Vector3::make(1.0, -2.0, 3.0);
+/// let (radius, theta, phi) = cartesian_to_spherical(&p);
+/// assert_eq!(radius, p.mag());
+/// assert!((spherical_to_cartesian(radius, theta, phi) - p).mag() < 0.00001);
+///
+/// assert_eq!(cartesian_to_spherical(&Vector3::new()), (0.0, 0.0, 0.0));
+/// ```
+#[inline]
+pub fn cartesian_to_spherical(point: &Vector3) -> (f32, f32, f32) {
+    let radius = point.mag();
+    if radius == 0.0 {
+        return (0.0, 0.0, 0.0);
+    }
+
+    let theta = (point.y / radius).clamp(-1.0, 1.0).acos();
+    (radius, theta, point.x.atan2(point.z))
+}
+
+/// Find the unit direction a camera faces after turning by `yaw` and tilting by `pitch`
+///
+/// With both at 0 the direction is [`Vector3::forward`](struct.Vector3.html#method.forward).
+/// Positive yaw turns counter-clockwise around +y, towards -x, and positive pitch looks up.
+///
+/// # Examples
+/// ```
+/// use vex::Vector3;
+/// use vex::direction_from_yaw_pitch;
+///
+/// use std::f32::consts::PI;
+///
+/// assert_eq!(direction_from_yaw_pitch(0.0, 0.0), Vector3::forward());
+///
+/// let actual = direction_from_yaw_pitch(PI / 2.0, 0.0);
+/// assert!((actual - Vector3::make(-1.0, 0.0, 0.0)).mag() < 0.000001);
+///
+/// let actual = direction_from_yaw_pitch(1.0, PI / 2.0);
+/// assert!((actual - Vector3::up()).mag() < 0.000001);
+/// ```
+#[inline]
+pub fn direction_from_yaw_pitch(yaw: f32, pitch: f32) -> Vector3 {
+    let (sin_yaw, cos_yaw) = yaw.sin_cos();
+    let (sin_pitch, cos_pitch) = pitch.sin_cos();
+    Vector3::make(-sin_yaw * cos_pitch, sin_pitch, -cos_yaw * cos_pitch)
+}
+
+/// Find the yaw and pitch that face a direction
+///
+/// Returns `(yaw, pitch)` with `yaw` in `[-PI, PI]` and `pitch` in `[-PI / 2, PI / 2]`, undoing
+/// [`direction_from_yaw_pitch`](fn.direction_from_yaw_pitch.html). The direction doesn't need
+/// to be normalized. Straight up or down has a yaw of 0, and the zero vector faces forward.
+///
+/// # Examples
+/// ```
+/// use vex::Vector3;
+/// use vex::direction_from_yaw_pitch;
+/// use vex::yaw_pitch_from_direction;
+///
+/// let (yaw, pitch) = yaw_pitch_from_direction(&Vector3::make(-2.0, 1.0, 3.0));
+/// let actual = direction_from_yaw_pitch(yaw, pitch);
+/// assert!((actual - Vector3::make(-2.0, 1.0, 3.0).normalized()).mag() < 0.00001);
+///
+/// assert_eq!(yaw_pitch_from_direction(&Vector3::forward()), (0.0, 0.0));
+/// ```
+#[inline]
+pub fn yaw_pitch_from_direction(direction: &Vector3) -> (f32, f32) {
+    let length = direction.mag();
+    if length == 0.0 {
+        return (0.0, 0.0);
+    }
+
+    let pitch = (direction.y / length).clamp(-1.0, 1.0).asin();
+    let horizontal = direction.x * direction.x + direction.z * direction.z;
+    let yaw = if horizontal == 0.0 {
+        0.0
+    } else {
+        (-direction.x).atan2(-direction.z)
+    };
+
+    (yaw, pitch)
+}
@@ -0,0 +1,154 @@
+#[cfg(not(feature = "std"))]
+use crate::math::Float;
+use crate::common::Vector;
+use crate::vector2::Vector2;
+use crate::vector3::Vector3;
+
+use alloc::vec::Vec;
+
+/// Estimate the curvature of a polyline at one of its points
+///
+/// This is the reciprocal of the radius of the circle through the point and its two neighbors, so
+/// it doesn't depend on how evenly the points are spaced. The first and last points use the
+/// circle through their nearest three points. Polylines with fewer than three points, and
+/// collinear or repeated points, have zero curvature.
+///
+/// # Examples
+/// ```
+/// use vex::Vector2;
+/// use vex::curvature_at;
+///
+/// // points on a circle of radius 2
+/// let points: Vec<Vector2> = (0..8)
+///     .map(|i| {
+///         let angle = i as f32 * 0.3;
+///         Vector2::make(2.0 * angle.cos(), 2.0 * angle.sin())
+///     })
+///     .collect();
+///
+/// assert!((curvature_at(&points, 3) - 0.5).abs() < 0.0001);
+/// assert!((curvature_at(&points, 0) - 0.5).abs() < 0.0001);
+///
+/// let line = [Vector2::make(0.0, 0.0), Vector2::make(1.0, 1.0), Vector2::make(3.0, 3.0)];
+/// assert_eq!(curvature_at(&line, 1), 0.0);
+/// ```
+pub fn curvature_at<V: Vector>(points: &[V], i: usize) -> f32 {
+    if points.len() < 3 {
+        return 0.0;
+    }
+
+    let i = i.clamp(1, points.len() - 2);
+    let u = points[i] - points[i - 1];
+    let v = points[i + 1] - points[i];
+    let w = points[i + 1] - points[i - 1];
+
+    // twice the triangle's area, from the Gram determinant so it works in any dimension
+    let (uu, vv, uv) = (V::dot(&u, &u), V::dot(&v, &v), V::dot(&u, &v));
+    let double_area = (uu * vv - uv * uv).max(0.0).sqrt();
+    let lengths = (uu * vv * V::dot(&w, &w)).sqrt();
+    if lengths == 0.0 {
+        return 0.0;
+    }
+
+    2.0 * double_area / lengths
+}
+
+/// Estimate the torsion of a 3D polyline at one of its points
+///
+/// Torsion measures how fast the curve twists out of its plane of curvature, and is positive for
+/// a right-handed helix. It's estimated with central differences over the point and two
+/// neighbors on each side, assuming the points are evenly spaced along the curve's parameter.
+/// Points within two of either end use the nearest five points. Polylines with fewer than five
+/// points, and straight stretches, have zero torsion.
+///
+/// # Examples
+/// ```
+/// use vex::Vector3;
+/// use vex::torsion_at;
+///
+/// // a helix with radius 1 that rises 1 per radian has torsion 1 / 2
+/// let points: Vec<Vector3> = (0..10)
+///     .map(|i| {
+///         let t = i as f32 * 0.1;
+///         Vector3::make(t.cos(), t.sin(), t)
+///     })
+///     .collect();
+///
+/// assert!((torsion_at(&points, 5) - 0.5).abs() < 0.001);
+///
+/// // a flat curve doesn't twist
+/// let flat: Vec<Vector3> = points.iter().map(|p| Vector3::make(p.x, p.y, 0.0)).collect();
+/// assert_eq!(torsion_at(&flat, 5), 0.0);
+/// ```
+pub fn torsion_at(points: &[Vector3], i: usize) -> f32 {
+    if points.len() < 5 {
+        return 0.0;
+    }
+
+    let i = i.clamp(2, points.len() - 3);
+    let (a, b, c, d, e) = (points[i - 2], points[i - 1], points[i], points[i + 1], points[i + 2]);
+    let first = (d - b) * 0.5;
+    let second = d - c * 2.0 + b;
+    let third = (e - d * 2.0 + b * 2.0 - a) * 0.5;
+
+    let normal = Vector3::cross(&first, &second);
+    let mag_sq = normal.mag_sq();
+    if mag_sq == 0.0 {
+        return 0.0;
+    }
+
+    Vector3::dot(&normal, &third) / mag_sq
+}
+
+/// Find where a 2D polyline switches from turning one way to turning the other
+///
+/// Each inflection is placed halfway between the last point turning one way and the first point
+/// turning the other, so straight stretches between opposite bends put it in their middle. Points
+/// that don't turn at all are skipped.
+///
+/// # Examples
+/// ```
+/// use vex::Vector2;
+/// use vex::inflection_points;
+///
+/// // an S bend, turning left and then right
+/// let points = [
+///     Vector2::make(0.0, 0.0),
+///     Vector2::make(1.0, 0.0),
+///     Vector2::make(2.0, 1.0),
+///     Vector2::make(3.0, 1.0),
+///     Vector2::make(4.0, 0.0),
+/// ];
+/// assert_eq!(inflection_points(&points), vec![Vector2::make(1.5, 0.5)]);
+///
+/// // a straight stretch between the bends
+/// let points = [
+///     Vector2::make(0.0, 0.0),
+///     Vector2::make(1.0, 0.0),
+///     Vector2::make(2.0, 1.0),
+///     Vector2::make(3.0, 2.0),
+///     Vector2::make(4.0, 2.0),
+/// ];
+/// assert_eq!(inflection_points(&points), vec![Vector2::make(2.0, 1.0)]);
+/// ```
+pub fn inflection_points(points: &[Vector2]) -> Vec<Vector2> {
+    let mut inflections = Vec::new();
+    let mut previous: Option<(usize, bool)> = None;
+    for i in 1..points.len().saturating_sub(1) {
+        let turn = Vector2::cross(&(points[i] - points[i - 1]), &(points[i + 1] - points[i]));
+        if turn == 0.0 {
+            continue;
+        }
+
+        let left = turn > 0.0;
+        if let Some((j, was_left)) = previous {
+            if was_left != left {
+                inflections.push((points[j] + points[i]) * 0.5);
+            }
+        }
+
+        previous = Some((i, left));
+    }
+
+    inflections
+}
@@ -0,0 +1,106 @@
+use crate::ivector2::IVector2;
+use crate::matrix4::Matrix4;
+
+use alloc::vec::Vec;
+
+/// Generates an `n` by `n` Bayer index matrix in row-major order
+///
+/// Each entry is the order in which that cell turns on, from `0` to `n * n - 1`. `n` must be a
+/// power of two.
+///
+/// # Examples
+/// ```
+/// use vex::bayer_matrix;
+///
+/// assert_eq!(bayer_matrix(2), vec![0, 2, 3, 1]);
+///
+/// let actual = bayer_matrix(4);
+/// let expected = vec![
+///     0, 8, 2, 10,
+///     12, 4, 14, 6,
+///     3, 11, 1, 9,
+///     15, 7, 13, 5,
+/// ];
+/// assert_eq!(actual, expected);
+/// ```
+pub fn bayer_matrix(n: u32) -> Vec<u32> {
+    assert!(n.is_power_of_two(), "bayer matrix size must be a power of two");
+
+    let mut values = Vec::with_capacity((n * n) as usize);
+    for y in 0..n {
+        for x in 0..n {
+            values.push(bayer_value(x, y, n));
+        }
+    }
+
+    values
+}
+
+/// Generates the 4x4 Bayer threshold matrix
+///
+/// Entries are normalized to `(index + 0.5) / 16` so the thresholds are centered in `(0, 1)`. Row
+/// `y` and column `x` of the matrix hold the threshold for pixel `<x, y>`.
+///
+/// # Examples
+/// ```
+/// use vex::bayer_matrix4;
+///
+/// let m = bayer_matrix4();
+/// assert_eq!(m.m11(), 0.5 / 16.0);
+/// assert_eq!(m.m12(), 8.5 / 16.0);
+/// assert_eq!(m.m21(), 12.5 / 16.0);
+/// assert_eq!(m.m44(), 5.5 / 16.0);
+/// ```
+pub fn bayer_matrix4() -> Matrix4 {
+    let mut m = Matrix4::new();
+    for x in 0..4 {
+        for y in 0..4 {
+            m.m[(x * 4 + y) as usize] = (bayer_value(x, y, 4) as f32 + 0.5) / 16.0;
+        }
+    }
+
+    m
+}
+
+/// Find the ordered dithering threshold for a pixel
+///
+/// The pattern tiles every `n` pixels, including for negative coordinates, and thresholds are
+/// normalized like [`bayer_matrix4`](fn.bayer_matrix4.html). A value should be drawn if it is
+/// greater than the threshold. `n` must be a power of two.
+///
+/// # Examples
+/// ```
+/// use vex::IVector2;
+/// use vex::ordered_dither_threshold;
+///
+/// assert_eq!(ordered_dither_threshold(IVector2::make(1, 0), 4), 8.5 / 16.0);
+/// assert_eq!(ordered_dither_threshold(IVector2::make(5, 4), 4), 8.5 / 16.0);
+/// assert_eq!(ordered_dither_threshold(IVector2::make(-3, -4), 4), 8.5 / 16.0);
+/// assert_eq!(ordered_dither_threshold(IVector2::make(1, 1), 2), 1.5 / 4.0);
+/// ```
+#[inline]
+pub fn ordered_dither_threshold(pixel: IVector2, n: u32) -> f32 {
+    assert!(n.is_power_of_two(), "bayer matrix size must be a power of two");
+
+    let mask = n as i32 - 1;
+    let value = bayer_value((pixel.x & mask) as u32, (pixel.y & mask) as u32, n);
+    (value as f32 + 0.5) / (n * n) as f32
+}
+
+/// Each bit level of the coordinates picks a quadrant of the 2x2 base pattern, with the lowest
+/// bits being the most significant
+#[inline]
+fn bayer_value(x: u32, y: u32, n: u32) -> u32 {
+    const QUADRANTS: [[u32; 2]; 2] = [[0, 2], [3, 1]];
+
+    let mut value = 0;
+    let mut bit = 0;
+    while (1 << bit) < n {
+        let qx = ((x >> bit) & 1) as usize;
+        let qy = ((y >> bit) & 1) as usize;
+        value = value * 4 + QUADRANTS[qy][qx];
+        bit += 1;
+    }
+
+    value
+}
@@ -0,0 +1,91 @@
+use crate::plane::Plane;
+use crate::ray::Ray;
+use crate::vector3::Vector3;
+
+/// Gets how far a cursor ray moved along an axis, for dragging translation handles
+///
+/// Each ray is matched to the point on the axis line it passes closest to, and the result is the
+/// distance between those points along `axis_dir`, in world units. A ray that runs parallel to the
+/// axis has no closest point, so no movement is reported for it.
+///
+/// # Examples
+/// ```
+/// use vex::Ray;
+/// use vex::Vector3;
+/// use vex::drag_along_axis;
+///
+/// // a camera above the x axis looking down as the cursor moves right
+/// let eye = Vector3::make(0.0, 0.0, 5.0);
+/// let prev = Ray::make(eye, Vector3::make(1.0, 0.0, -5.0));
+/// let curr = Ray::make(eye, Vector3::make(3.0, 0.0, -5.0));
+/// let actual = drag_along_axis(&prev, &curr, &Vector3::new(), &Vector3::make(2.0, 0.0, 0.0));
+/// assert!((actual - 2.0).abs() < 0.0001);
+///
+/// // looking straight down the axis
+/// let along = Ray::make(eye, Vector3::make(0.0, 0.0, -1.0));
+/// let actual = drag_along_axis(&prev, &along, &Vector3::new(), &Vector3::make(0.0, 0.0, 1.0));
+/// assert_eq!(actual, 0.0);
+/// ```
+pub fn drag_along_axis(
+    ray_prev: &Ray,
+    ray_curr: &Ray,
+    axis_origin: &Vector3,
+    axis_dir: &Vector3,
+) -> f32 {
+    let axis = axis_dir.normalized();
+    match (
+        closest_on_axis(ray_prev, axis_origin, &axis),
+        closest_on_axis(ray_curr, axis_origin, &axis),
+    ) {
+        (Some(prev), Some(curr)) => curr - prev,
+        _ => 0.0,
+    }
+}
+
+/// Gets how far a cursor ray moved across a plane, for dragging handles constrained to it
+///
+/// The result is the offset from where the previous ray hits the plane to where the current one
+/// does. If either ray runs parallel to the plane or points away from it, there is nothing to
+/// follow and the zero vector is returned.
+///
+/// # Examples
+/// ```
+/// use vex::Plane;
+/// use vex::Ray;
+/// use vex::Vector3;
+/// use vex::drag_on_plane;
+///
+/// // dragging across the ground from a camera above it
+/// let ground = Plane::from_point_normal(&Vector3::new(), &Vector3::up());
+/// let eye = Vector3::make(0.0, 4.0, 0.0);
+/// let prev = Ray::make(eye, Vector3::make(1.0, -2.0, 0.0));
+/// let curr = Ray::make(eye, Vector3::make(1.0, -1.0, 1.0));
+/// let actual = drag_on_plane(&prev, &curr, &ground);
+/// assert!((actual - Vector3::make(2.0, 0.0, 4.0)).mag() < 0.0001);
+///
+/// // the cursor moved above the horizon
+/// let sky = Ray::make(eye, Vector3::make(0.0, 1.0, 1.0));
+/// assert_eq!(drag_on_plane(&prev, &sky, &ground), Vector3::new());
+/// ```
+pub fn drag_on_plane(ray_prev: &Ray, ray_curr: &Ray, plane: &Plane) -> Vector3 {
+    match (plane.intersect_ray(ray_prev), plane.intersect_ray(ray_curr)) {
+        (Some(prev), Some(curr)) => ray_curr.point_at(curr) - ray_prev.point_at(prev),
+        _ => Vector3::new(),
+    }
+}
+
+/// The distance along a unit axis to the point on it nearest the ray's line
+#[inline]
+fn closest_on_axis(ray: &Ray, axis_origin: &Vector3, axis: &Vector3) -> Option<f32> {
+    let b = Vector3::dot(axis, &ray.direction);
+    let c = ray.direction.mag_sq();
+    let denom = c - b * b;
+    if denom <= c * 0.000001 {
+        return None;
+    }
+
+    let offset = *axis_origin - ray.origin;
+    let d = Vector3::dot(axis, &offset);
+    let e = Vector3::dot(&ray.direction, &offset);
+    Some((b * e - c * d) / denom)
+}
@@ -0,0 +1,262 @@
+use crate::matrix3::Matrix3;
+use crate::matrix4::Matrix4;
+use crate::vector3::Vector3;
+
+/// Measurements of how far a rotation has drifted from being orthonormal
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct DriftReport {
+    /// The number of compositions recorded so far
+    pub steps: u32,
+    /// The largest element of `transpose(R) * R - I`
+    pub orthogonality_error: f32,
+    /// How far the determinant is from 1
+    pub determinant_error: f32,
+}
+
+impl DriftReport {
+    /// Gets the larger of the two errors
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::DriftReport;
+    ///
+    /// let report = DriftReport { steps: 1, orthogonality_error: 0.5, determinant_error: 0.25 };
+    /// assert_eq!(report.max_error(), 0.5);
+    /// ```
+    #[inline]
+    pub fn max_error(&self) -> f32 {
+        self.orthogonality_error.max(self.determinant_error)
+    }
+}
+
+/// Tracks numerical drift of a rotation that's built up over many repeated compositions
+///
+/// Record the matrix after every composition, and renormalize it once
+/// [`needs_renormalization`](#method.needs_renormalization) reports true, or on the cadence
+/// suggested by [`suggested_interval`](#method.suggested_interval). Only the rotation part of a
+/// `Matrix4` is measured, so it should carry no scale.
+#[derive(Clone, Debug, PartialEq)]
+pub struct DriftMonitor {
+    tolerance: f32,
+    latest: DriftReport,
+    worst: f32,
+}
+
+impl DriftMonitor {
+    /// Creates a monitor that flags drift beyond the provided tolerance
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::DriftMonitor;
+    ///
+    /// let monitor = DriftMonitor::make(0.0001);
+    /// assert_eq!(monitor.latest().steps, 0);
+    /// ```
+    #[inline]
+    pub fn make(tolerance: f32) -> DriftMonitor {
+        DriftMonitor {
+            tolerance,
+            latest: DriftReport::default(),
+            worst: 0.0,
+        }
+    }
+
+    /// Records the current state of a rotation matrix
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::DriftMonitor;
+    /// use vex::Matrix3;
+    ///
+    /// let mut monitor = DriftMonitor::make(0.0001);
+    /// let report = monitor.record_matrix3(&(Matrix3::new() * 1.01));
+    /// assert_eq!(report.steps, 1);
+    /// assert!(report.orthogonality_error > 0.02);
+    /// assert!(monitor.needs_renormalization());
+    /// ```
+    pub fn record_matrix3(&mut self, m: &Matrix3) -> DriftReport {
+        let report = DriftReport {
+            steps: self.latest.steps + 1,
+            orthogonality_error: orthogonality_error(m),
+            determinant_error: (m.determinant() - 1.0).abs(),
+        };
+
+        self.worst = self.worst.max(report.max_error());
+        self.latest = report;
+        report
+    }
+
+    /// Records the current state of the rotation part (upper 3x3) of a matrix
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::DriftMonitor;
+    /// use vex::Matrix4;
+    ///
+    /// let mut monitor = DriftMonitor::make(0.0001);
+    /// let report = monitor.record_matrix4(&Matrix4::translate(1.0, 2.0, 3.0));
+    /// assert_eq!(report.max_error(), 0.0);
+    /// assert!(!monitor.needs_renormalization());
+    /// ```
+    #[inline]
+    pub fn record_matrix4(&mut self, m: &Matrix4) -> DriftReport {
+        self.record_matrix3(&Matrix3::make(
+            m.m11(),
+            m.m21(),
+            m.m31(),
+            m.m12(),
+            m.m22(),
+            m.m32(),
+            m.m13(),
+            m.m23(),
+            m.m33(),
+        ))
+    }
+
+    /// Gets the most recent report
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::DriftMonitor;
+    /// use vex::Matrix3;
+    ///
+    /// let mut monitor = DriftMonitor::make(0.0001);
+    /// monitor.record_matrix3(&Matrix3::new());
+    /// monitor.record_matrix3(&Matrix3::new());
+    /// assert_eq!(monitor.latest().steps, 2);
+    /// ```
+    #[inline]
+    pub fn latest(&self) -> DriftReport {
+        self.latest
+    }
+
+    /// Gets the largest error seen since the monitor was created or last reset
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::DriftMonitor;
+    /// use vex::Matrix3;
+    ///
+    /// let mut monitor = DriftMonitor::make(0.0001);
+    /// monitor.record_matrix3(&(Matrix3::new() * 2.0));
+    /// monitor.record_matrix3(&Matrix3::new());
+    /// assert_eq!(monitor.worst_error(), 7.0);
+    /// ```
+    #[inline]
+    pub fn worst_error(&self) -> f32 {
+        self.worst
+    }
+
+    /// Determines whether or not the latest error exceeds the tolerance
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::DriftMonitor;
+    /// use vex::Matrix3;
+    ///
+    /// let mut monitor = DriftMonitor::make(0.1);
+    /// monitor.record_matrix3(&(Matrix3::new() * 1.01));
+    /// assert!(!monitor.needs_renormalization());
+    /// ```
+    #[inline]
+    pub fn needs_renormalization(&self) -> bool {
+        self.latest.max_error() > self.tolerance
+    }
+
+    /// Find how many compositions can run between renormalizations while staying within tolerance
+    ///
+    /// Assumes the error grows linearly with the number of compositions, which holds for the
+    /// rounding drift of repeated floating-point products. Returns `None` until some drift has
+    /// been measured.
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::DriftMonitor;
+    /// use vex::Matrix3;
+    ///
+    /// let mut monitor = DriftMonitor::make(0.001);
+    /// assert_eq!(monitor.suggested_interval(), None);
+    /// for _ in 0..10 {
+    ///     monitor.record_matrix3(&Matrix3::new());
+    /// }
+    ///
+    /// // the determinant is about 1.0003, so the error grew by roughly 0.0003 over 11 steps
+    /// monitor.record_matrix3(&(Matrix3::new() * 1.0001));
+    /// let interval = monitor.suggested_interval().unwrap();
+    /// assert!(interval > 30 && interval < 40);
+    /// ```
+    pub fn suggested_interval(&self) -> Option<u32> {
+        let error = self.latest.max_error();
+        if self.latest.steps == 0 || error <= 0.0 {
+            return None;
+        }
+
+        let rate = error / self.latest.steps as f32;
+        Some(((self.tolerance / rate) as u32).max(1))
+    }
+
+    /// Clears the recorded history, typically right after renormalizing
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::DriftMonitor;
+    /// use vex::Matrix3;
+    ///
+    /// let mut monitor = DriftMonitor::make(0.0001);
+    /// monitor.record_matrix3(&(Matrix3::new() * 2.0));
+    /// monitor.reset();
+    /// assert_eq!(monitor.latest().steps, 0);
+    /// assert_eq!(monitor.worst_error(), 0.0);
+    /// ```
+    #[inline]
+    pub fn reset(&mut self) {
+        self.latest = DriftReport::default();
+        self.worst = 0.0;
+    }
+}
+
+/// Find the largest element of `transpose(m) * m - I`, which is zero for an orthonormal matrix
+///
+/// # Examples
+/// ```
+/// use vex::Matrix3;
+/// use vex::orthogonality_error;
+///
+/// assert_eq!(orthogonality_error(&Matrix3::new()), 0.0);
+/// assert_eq!(orthogonality_error(&(Matrix3::new() * 2.0)), 3.0);
+/// ```
+pub fn orthogonality_error(m: &Matrix3) -> f32 {
+    let columns = [m.col(0), m.col(1), m.col(2)];
+    let mut error = 0.0f32;
+    for i in 0..3 {
+        for j in i..3 {
+            let expected = if i == j { 1.0 } else { 0.0 };
+            error = error.max((Vector3::dot(&columns[i], &columns[j]) - expected).abs());
+        }
+    }
+
+    error
+}
+
+/// Find the nearest rotation matrix using Gram-Schmidt on the columns
+///
+/// This is [`Matrix3::orthonormalized`](struct.Matrix3.html#method.orthonormalized) with the last
+/// column rebuilt from the other two, so a drifted matrix can't turn into a reflection. The first
+/// column keeps its direction, so it's best to order the columns by importance.
+///
+/// # Examples
+/// ```
+/// use vex::Matrix3;
+/// use vex::orthogonality_error;
+/// use vex::renormalize_rotation;
+///
+/// let m = Matrix3::make(1.01, 0.02, 0.0, -0.01, 0.99, 0.0, 0.0, 0.0, 1.0);
+/// let actual = renormalize_rotation(&m);
+/// assert!(orthogonality_error(&actual) < 0.000001);
+/// ```
+pub fn renormalize_rotation(m: &Matrix3) -> Matrix3 {
+    let mut result = m.orthonormalized();
+    let z = Vector3::cross(&result.col(0), &result.col(1));
+    result.set_col(2, &z);
+    result
+}
@@ -0,0 +1,394 @@
+#[cfg(not(feature = "std"))]
+use crate::math::Float;
+use crate::matrix4::Matrix4;
+use crate::quaternion::Quaternion;
+use crate::vector3::Vector3;
+
+use core::ops::Mul;
+
+/// A rigid transform, a rotation followed by a translation, stored as a dual quaternion
+///
+/// `real` holds the rotation, and `dual` holds the translation scaled into the quaternion algebra.
+/// Unlike matrices, dual quaternions blend without shearing or shrinking, which makes them the
+/// usual choice for skinning.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct DualQuaternion {
+    pub real: Quaternion,
+    pub dual: Quaternion,
+}
+
+impl DualQuaternion {
+    /// The identity transform
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::DualQuaternion;
+    ///
+    /// assert_eq!(DualQuaternion::IDENTITY, DualQuaternion::new());
+    /// ```
+    pub const IDENTITY: DualQuaternion = DualQuaternion {
+        real: Quaternion::IDENTITY,
+        dual: Quaternion {
+            x: 0.0,
+            y: 0.0,
+            z: 0.0,
+            w: 0.0,
+        },
+    };
+
+    /// Creates an identity transform
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::DualQuaternion;
+    /// use vex::Quaternion;
+    ///
+    /// let actual = DualQuaternion::new();
+    /// assert_eq!(actual.real, Quaternion::new());
+    /// assert_eq!(actual.dual, Quaternion::make(0.0, 0.0, 0.0, 0.0));
+    /// ```
+    #[inline]
+    pub const fn new() -> DualQuaternion {
+        DualQuaternion::IDENTITY
+    }
+
+    /// Creates a dual quaternion from the provided real and dual parts
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::DualQuaternion;
+    /// use vex::Quaternion;
+    ///
+    /// let real = Quaternion::make(0.0, 0.0, 0.0, 1.0);
+    /// let dual = Quaternion::make(1.0, 2.0, 3.0, 0.0);
+    /// let actual = DualQuaternion::make(real, dual);
+    /// assert_eq!(actual.real, real);
+    /// assert_eq!(actual.dual, dual);
+    /// ```
+    #[inline]
+    pub const fn make(real: Quaternion, dual: Quaternion) -> DualQuaternion {
+        DualQuaternion { real, dual }
+    }
+
+    /// Creates a transform that rotates and then translates
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::DualQuaternion;
+    /// use vex::Quaternion;
+    /// use vex::Vector3;
+    ///
+    /// let rotation = Quaternion::from_axis_angle(&Vector3::up(), 0.5);
+    /// let translation = Vector3::make(1.0, 2.0, 3.0);
+    /// let actual = DualQuaternion::from_rotation_translation(&rotation, &translation);
+    /// assert_eq!(actual.rotation(), rotation);
+    /// assert!((actual.translation() - translation).mag() < 0.0001);
+    /// ```
+    #[inline]
+    pub fn from_rotation_translation(
+        rotation: &Quaternion,
+        translation: &Vector3,
+    ) -> DualQuaternion {
+        let t = Quaternion::make(translation.x, translation.y, translation.z, 0.0);
+        DualQuaternion::make(*rotation, t * *rotation * 0.5)
+    }
+
+    /// Gets the rotation of the transform
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::DualQuaternion;
+    /// use vex::Quaternion;
+    /// use vex::Vector3;
+    ///
+    /// let rotation = Quaternion::from_axis_angle(&Vector3::right(), 1.0);
+    /// let actual = DualQuaternion::from_rotation_translation(&rotation, &Vector3::one());
+    /// assert_eq!(actual.rotation(), rotation);
+    /// ```
+    #[inline]
+    pub fn rotation(&self) -> Quaternion {
+        self.real
+    }
+
+    /// Gets the translation of the transform
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::DualQuaternion;
+    /// use vex::Quaternion;
+    /// use vex::Vector3;
+    ///
+    /// let translation = Vector3::make(-1.0, 0.5, 4.0);
+    /// let actual = DualQuaternion::from_rotation_translation(&Quaternion::new(), &translation);
+    /// assert_eq!(actual.translation(), translation);
+    /// ```
+    #[inline]
+    pub fn translation(&self) -> Vector3 {
+        let t = self.dual * self.real.conjugated() * 2.0;
+        Vector3::make(t.x, t.y, t.z)
+    }
+
+    /// Find the dual quaternion scaled to represent a rigid transform
+    ///
+    /// The real part is scaled to unit length, and the dual part is made perpendicular to it. A
+    /// dual quaternion with a zero real part is returned unchanged.
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::DualQuaternion;
+    /// use vex::Quaternion;
+    /// use vex::Vector3;
+    ///
+    /// let rotation = Quaternion::from_axis_angle(&Vector3::up(), 0.5);
+    /// let dq = DualQuaternion::from_rotation_translation(&rotation, &Vector3::make(1.0, 2.0, 3.0));
+    /// let scaled = DualQuaternion::make(dq.real * 3.0, dq.dual * 3.0);
+    /// let actual = scaled.normalized();
+    /// assert!((actual.real - dq.real).mag() < 0.0001);
+    /// assert!((actual.dual - dq.dual).mag() < 0.0001);
+    /// ```
+    #[inline]
+    pub fn normalized(&self) -> DualQuaternion {
+        let mag = self.real.mag();
+        if mag == 0.0 {
+            return *self;
+        }
+
+        let real = self.real * (1.0 / mag);
+        let dual = self.dual * (1.0 / mag);
+        DualQuaternion::make(real, dual - real * Quaternion::dot(&real, &dual))
+    }
+
+    /// Find the conjugate, which is the inverse of a normalized dual quaternion
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::DualQuaternion;
+    /// use vex::Quaternion;
+    /// use vex::Vector3;
+    ///
+    /// let rotation = Quaternion::from_axis_angle(&Vector3::make(1.0, 1.0, 0.0), 2.0);
+    /// let dq = DualQuaternion::from_rotation_translation(&rotation, &Vector3::make(1.0, 2.0, 3.0));
+    /// let p = Vector3::make(-2.0, 0.5, 1.0);
+    /// let actual = dq.conjugated().transform_point(&dq.transform_point(&p));
+    /// assert!((actual - p).mag() < 0.0001);
+    /// ```
+    #[inline]
+    pub fn conjugated(&self) -> DualQuaternion {
+        DualQuaternion::make(self.real.conjugated(), self.dual.conjugated())
+    }
+
+    /// Find the resulting point given a point and a normalized dual quaternion
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::DualQuaternion;
+    /// use vex::Quaternion;
+    /// use vex::Vector3;
+    ///
+    /// let rotation = Quaternion::from_axis_angle(&Vector3::make(0.0, 0.0, 1.0), std::f32::consts::FRAC_PI_2);
+    /// let dq = DualQuaternion::from_rotation_translation(&rotation, &Vector3::make(0.0, 0.0, 5.0));
+    /// let actual = dq.transform_point(&Vector3::right());
+    /// assert!((actual - Vector3::make(0.0, 1.0, 5.0)).mag() < 0.0001);
+    /// ```
+    #[inline]
+    pub fn transform_point(&self, point: &Vector3) -> Vector3 {
+        self.real.rotate(point) + self.translation()
+    }
+
+    /// Interpolates between two normalized transforms along the screw motion joining them
+    ///
+    /// Screw linear interpolation moves at a constant rate with the rotation and translation
+    /// coupled, taking the shorter way around.
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::DualQuaternion;
+    /// use vex::Quaternion;
+    /// use vex::Vector3;
+    ///
+    /// let axis = Vector3::make(0.0, 0.0, 1.0);
+    /// let a = DualQuaternion::new();
+    /// let b = DualQuaternion::from_rotation_translation(
+    ///     &Quaternion::from_axis_angle(&axis, std::f32::consts::FRAC_PI_2),
+    ///     &Vector3::make(0.0, 0.0, 4.0),
+    /// );
+    ///
+    /// // a quarter of the way around and along the screw
+    /// let actual = DualQuaternion::sclerp(&a, &b, 0.25);
+    /// let expected = Quaternion::from_axis_angle(&axis, std::f32::consts::FRAC_PI_8);
+    /// assert!((actual.rotation() - expected).mag() < 0.0001);
+    /// assert!((actual.translation() - Vector3::make(0.0, 0.0, 1.0)).mag() < 0.0001);
+    ///
+    /// assert!((DualQuaternion::sclerp(&a, &b, 1.0).translation() - b.translation()).mag() < 0.0001);
+    /// ```
+    pub fn sclerp(a: &DualQuaternion, b: &DualQuaternion, t: f32) -> DualQuaternion {
+        let mut b = *b;
+        if Quaternion::dot(&a.real, &b.real) < 0.0 {
+            b = DualQuaternion::make(-b.real, -b.dual);
+        }
+
+        let difference = a.conjugated() * b;
+        *a * difference.powf(t)
+    }
+
+    /// Blends normalized transforms by weight, as in dual quaternion skinning
+    ///
+    /// Each transform is flipped onto the same side as the first before its weighted sum is
+    /// normalized. An empty set of transforms blends to the identity.
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::DualQuaternion;
+    /// use vex::Quaternion;
+    /// use vex::Vector3;
+    ///
+    /// let axis = Vector3::make(0.0, 0.0, 1.0);
+    /// let a = DualQuaternion::new();
+    /// let b = DualQuaternion::from_rotation_translation(
+    ///     &Quaternion::from_axis_angle(&axis, std::f32::consts::FRAC_PI_2),
+    ///     &Vector3::new(),
+    /// );
+    ///
+    /// let actual = DualQuaternion::blend(&[(a, 0.5), (b, 0.5)]);
+    /// let expected = Quaternion::from_axis_angle(&axis, std::f32::consts::FRAC_PI_4);
+    /// assert!((actual.rotation() - expected).mag() < 0.0001);
+    /// assert_eq!(DualQuaternion::blend(&[]), DualQuaternion::new());
+    /// ```
+    pub fn blend(transforms: &[(DualQuaternion, f32)]) -> DualQuaternion {
+        let first = match transforms.first() {
+            Some((first, _)) => first.real,
+            None => return DualQuaternion::new(),
+        };
+
+        let zero = Quaternion::make(0.0, 0.0, 0.0, 0.0);
+        let mut sum = DualQuaternion::make(zero, zero);
+        for (dq, weight) in transforms.iter() {
+            let weight = if Quaternion::dot(&first, &dq.real) < 0.0 { -*weight } else { *weight };
+            sum.real += dq.real * weight;
+            sum.dual += dq.dual * weight;
+        }
+
+        sum.normalized()
+    }
+
+    /// Raises a normalized transform to a power by scaling its screw motion
+    fn powf(&self, t: f32) -> DualQuaternion {
+        let sine = Vector3::make(self.real.x, self.real.y, self.real.z).mag();
+
+        // without a rotation the screw is a plain translation
+        if sine < 0.0001 {
+            let rotation = Quaternion::nlerp(&Quaternion::new(), &self.real, t);
+            return DualQuaternion::from_rotation_translation(&rotation, &(self.translation() * t));
+        }
+
+        let axis = Vector3::make(self.real.x, self.real.y, self.real.z) * (1.0 / sine);
+        let angle = 2.0 * sine.atan2(self.real.w);
+        let pitch = -2.0 * self.dual.w / sine;
+        let dual = Vector3::make(self.dual.x, self.dual.y, self.dual.z);
+        let moment = (dual - axis * (pitch * 0.5 * self.real.w)) * (1.0 / sine);
+
+        let (sine, cosine) = (angle * t * 0.5).sin_cos();
+        let pitch = pitch * t * 0.5;
+        let real = axis * sine;
+        let dual = moment * sine + axis * (pitch * cosine);
+        DualQuaternion::make(
+            Quaternion::make(real.x, real.y, real.z, cosine),
+            Quaternion::make(dual.x, dual.y, dual.z, -pitch * sine),
+        )
+    }
+}
+
+impl Default for DualQuaternion {
+    /// Creates an identity transform
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::DualQuaternion;
+    ///
+    /// assert_eq!(DualQuaternion::default(), DualQuaternion::new());
+    /// ```
+    #[inline]
+    fn default() -> DualQuaternion {
+        DualQuaternion::new()
+    }
+}
+
+impl From<Matrix4> for DualQuaternion {
+    /// Creates a dual quaternion from the rotation and translation of a rigid transform matrix
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::DualQuaternion;
+    /// use vex::Matrix4;
+    /// use vex::Vector3;
+    ///
+    /// let m = Matrix4::translate(1.0, 2.0, 3.0) * Matrix4::rotate_y(0.75);
+    /// let actual = DualQuaternion::from(m);
+    /// assert!((actual.translation() - Vector3::make(1.0, 2.0, 3.0)).mag() < 0.0001);
+    /// assert!(Matrix4::from(actual).approx_eq(&m, 0.0001));
+    /// ```
+    #[inline]
+    fn from(m: Matrix4) -> DualQuaternion {
+        let rotation = Quaternion::from(m).normalized();
+        let translation = Vector3::make(m.m14(), m.m24(), m.m34());
+        DualQuaternion::from_rotation_translation(&rotation, &translation)
+    }
+}
+
+impl From<DualQuaternion> for Matrix4 {
+    /// Creates a rigid transform matrix from a normalized dual quaternion
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::DualQuaternion;
+    /// use vex::Matrix;
+    /// use vex::Matrix4;
+    /// use vex::Quaternion;
+    /// use vex::Vector3;
+    ///
+    /// let rotation = Quaternion::from_axis_angle(&Vector3::make(1.0, -1.0, 2.0), 1.2);
+    /// let dq = DualQuaternion::from_rotation_translation(&rotation, &Vector3::make(4.0, 5.0, 6.0));
+    /// let p = Vector3::make(1.0, 2.0, 3.0);
+    /// let actual = Matrix4::from(dq).transform_point(&p);
+    /// assert!((actual - dq.transform_point(&p)).mag() < 0.0001);
+    /// ```
+    #[inline]
+    fn from(dq: DualQuaternion) -> Matrix4 {
+        let t = dq.translation();
+        Matrix4::translate(t.x, t.y, t.z) * Matrix4::from(dq.real)
+    }
+}
+
+impl Mul<DualQuaternion> for DualQuaternion {
+    type Output = DualQuaternion;
+
+    /// Combine two transforms, producing the transform of `_rhs` followed by `self`
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::DualQuaternion;
+    /// use vex::Quaternion;
+    /// use vex::Vector3;
+    ///
+    /// let a = DualQuaternion::from_rotation_translation(
+    ///     &Quaternion::from_axis_angle(&Vector3::up(), 1.0),
+    ///     &Vector3::make(1.0, 0.0, 0.0),
+    /// );
+    /// let b = DualQuaternion::from_rotation_translation(
+    ///     &Quaternion::from_axis_angle(&Vector3::right(), 0.5),
+    ///     &Vector3::make(0.0, 2.0, 0.0),
+    /// );
+    ///
+    /// let p = Vector3::make(1.0, 2.0, 3.0);
+    /// let actual = (a * b).transform_point(&p);
+    /// let expected = a.transform_point(&b.transform_point(&p));
+    /// assert!((actual - expected).mag() < 0.0001);
+    /// ```
+    #[inline]
+    fn mul(self, _rhs: DualQuaternion) -> DualQuaternion {
+        DualQuaternion::make(
+            self.real * _rhs.real,
+            self.real * _rhs.dual + self.dual * _rhs.real,
+        )
+    }
+}
@@ -0,0 +1,200 @@
+/// How close two floats must be to count as equal
+///
+/// Two values are equal when any one of the tolerances holds: their difference is within
+/// `absolute`, their difference is within `relative` times the larger magnitude, or they are
+/// within `ulps` representable floats of each other. A tolerance of zero never holds. The right
+/// tolerances depend on the scale of the data: a CAD model measured in millimeters and terrain
+/// measured in kilometers call for different absolute tolerances, while the relative and ulps
+/// tolerances scale with the values themselves.
+///
+/// The default is a good fit for values around unit magnitude, and a policy can be tuned per call
+/// with the `with_*` methods.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Epsilon {
+    pub absolute: f32,
+    pub relative: f32,
+    pub ulps: u32,
+}
+
+impl Epsilon {
+    /// The default policy, with an absolute tolerance of `0.000001`, a relative tolerance of
+    /// `f32::EPSILON`, and 4 ulps
+    pub const DEFAULT: Epsilon = Epsilon {
+        absolute: 0.000001,
+        relative: f32::EPSILON,
+        ulps: 4,
+    };
+
+    /// Creates a policy from the provided tolerances
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Epsilon;
+    ///
+    /// let actual = Epsilon::make(0.001, 0.0, 0);
+    /// assert_eq!(actual.absolute, 0.001);
+    /// assert_eq!(actual.relative, 0.0);
+    /// assert_eq!(actual.ulps, 0);
+    /// ```
+    #[inline]
+    pub const fn make(absolute: f32, relative: f32, ulps: u32) -> Epsilon {
+        Epsilon {
+            absolute,
+            relative,
+            ulps,
+        }
+    }
+
+    /// Find the policy with a different absolute tolerance
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Epsilon;
+    ///
+    /// let actual = Epsilon::DEFAULT.with_absolute(0.5);
+    /// assert_eq!(actual.absolute, 0.5);
+    /// assert_eq!(actual.ulps, Epsilon::DEFAULT.ulps);
+    /// ```
+    #[inline]
+    pub fn with_absolute(&self, absolute: f32) -> Epsilon {
+        Epsilon { absolute, ..*self }
+    }
+
+    /// Find the policy with a different relative tolerance
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Epsilon;
+    ///
+    /// let actual = Epsilon::DEFAULT.with_relative(0.01);
+    /// assert_eq!(actual.relative, 0.01);
+    /// assert_eq!(actual.absolute, Epsilon::DEFAULT.absolute);
+    /// ```
+    #[inline]
+    pub fn with_relative(&self, relative: f32) -> Epsilon {
+        Epsilon { relative, ..*self }
+    }
+
+    /// Find the policy with a different ulps tolerance
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Epsilon;
+    ///
+    /// let actual = Epsilon::DEFAULT.with_ulps(16);
+    /// assert_eq!(actual.ulps, 16);
+    /// assert_eq!(actual.relative, Epsilon::DEFAULT.relative);
+    /// ```
+    #[inline]
+    pub fn with_ulps(&self, ulps: u32) -> Epsilon {
+        Epsilon { ulps, ..*self }
+    }
+
+    /// Determine whether or not two values are equal under the policy
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Epsilon;
+    ///
+    /// let epsilon = Epsilon::DEFAULT;
+    /// assert!(epsilon.approx_eq(1.0, 1.0000005));
+    /// assert!(!epsilon.approx_eq(1.0, 1.00001));
+    ///
+    /// // far from unit magnitude the relative tolerance takes over
+    /// assert!(epsilon.approx_eq(1000000.0, 1000000.06));
+    /// assert!(!Epsilon::make(0.000001, 0.0, 0).approx_eq(1000000.0, 1000000.06));
+    /// ```
+    #[inline]
+    pub fn approx_eq(&self, a: f32, b: f32) -> bool {
+        if a == b {
+            return true;
+        }
+
+        let difference = (a - b).abs();
+        if difference <= self.absolute {
+            return true;
+        }
+
+        if difference <= self.relative * a.abs().max(b.abs()) {
+            return true;
+        }
+
+        // floats of the same sign are ordered like their bits
+        if a.is_sign_negative() != b.is_sign_negative() || !a.is_finite() || !b.is_finite() {
+            return false;
+        }
+
+        a.to_bits().abs_diff(b.to_bits()) <= self.ulps
+    }
+
+    /// Determine whether or not a value is within the absolute tolerance of zero
+    ///
+    /// Only the absolute tolerance applies, since nothing is relatively close to zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Epsilon;
+    ///
+    /// assert!(Epsilon::DEFAULT.is_zero(-0.0000005));
+    /// assert!(!Epsilon::DEFAULT.is_zero(0.001));
+    /// assert!(Epsilon::DEFAULT.with_absolute(0.01).is_zero(0.001));
+    /// ```
+    #[inline]
+    pub fn is_zero(&self, value: f32) -> bool {
+        value.abs() <= self.absolute
+    }
+
+    /// Determine whether or not every pair of elements is equal under the policy
+    #[inline]
+    pub(crate) fn all_approx_eq(&self, a: &[f32], b: &[f32]) -> bool {
+        a.iter().zip(b.iter()).all(|(a, b)| self.approx_eq(*a, *b))
+    }
+
+    /// Find the number of linearly independent columns of a column-major `n` by `n` matrix
+    ///
+    /// Uses Gaussian elimination with partial pivoting, counting a pivot as zero when it's within
+    /// the absolute tolerance. The elements are overwritten with the eliminated matrix.
+    pub(crate) fn rank(&self, m: &mut [f32], n: usize) -> u32 {
+        let mut rank = 0;
+        for col in 0..n {
+            let pivot = (rank..n)
+                .max_by(|&a, &b| m[col * n + a].abs().total_cmp(&m[col * n + b].abs()))
+                .filter(|&row| !self.is_zero(m[col * n + row]));
+
+            let pivot = match pivot {
+                Some(pivot) => pivot,
+                None => continue,
+            };
+
+            for c in col..n {
+                m.swap(c * n + rank, c * n + pivot);
+            }
+
+            for row in rank + 1..n {
+                let factor = m[col * n + row] / m[col * n + rank];
+                for c in col..n {
+                    m[c * n + row] -= factor * m[c * n + rank];
+                }
+            }
+
+            rank += 1;
+        }
+
+        rank as u32
+    }
+}
+
+impl Default for Epsilon {
+    /// Creates the default policy
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Epsilon;
+    ///
+    /// assert_eq!(Epsilon::default(), Epsilon::DEFAULT);
+    /// ```
+    #[inline]
+    fn default() -> Epsilon {
+        Epsilon::DEFAULT
+    }
+}
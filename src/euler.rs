@@ -0,0 +1,318 @@
+#[cfg(not(feature = "std"))]
+use crate::math::Float;
+use crate::matrix3::Matrix3;
+use crate::matrix4::Matrix4;
+use crate::vector3::Vector3;
+
+use core::f32::consts::PI;
+
+/// The order in which Euler angle rotations are applied
+///
+/// `XYZ` rotates about the x-axis first, then the y-axis, then the z-axis, so the resulting
+/// matrix is `Rz * Ry * Rx`. Each rotation is about the fixed world axes.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum EulerOrder {
+    XYZ,
+    XZY,
+    YXZ,
+    YZX,
+    ZXY,
+    ZYX,
+}
+
+impl EulerOrder {
+    /// Gets the axes in the order they're applied, and whether that order is an even permutation
+    #[inline]
+    fn axes(self) -> ([usize; 3], bool) {
+        match self {
+            EulerOrder::XYZ => ([0, 1, 2], true),
+            EulerOrder::XZY => ([0, 2, 1], false),
+            EulerOrder::YXZ => ([1, 0, 2], false),
+            EulerOrder::YZX => ([1, 2, 0], true),
+            EulerOrder::ZXY => ([2, 0, 1], true),
+            EulerOrder::ZYX => ([2, 1, 0], false),
+        }
+    }
+}
+
+/// Euler angles (in radians) with an order, for accumulating rotations like a camera's
+///
+/// Each axis can be limited to a range, which accumulated rotations are clamped to, like a
+/// camera's pitch. Axes without limits wrap around into `[-pi, pi)` so they never lose precision
+/// by growing without bound, like a camera's yaw.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct EulerAngles {
+    pub x: f32,
+    pub y: f32,
+    pub z: f32,
+    pub order: EulerOrder,
+    /// The `(min, max)` range of each axis, or `None` if it wraps around
+    pub limits: [Option<(f32, f32)>; 3],
+}
+
+impl EulerAngles {
+    /// Creates angles with no rotation and no limits
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::EulerAngles;
+    /// use vex::EulerOrder;
+    ///
+    /// let actual = EulerAngles::new(EulerOrder::YXZ);
+    /// assert_eq!((actual.x, actual.y, actual.z), (0.0, 0.0, 0.0));
+    /// assert_eq!(actual.limits, [None, None, None]);
+    /// ```
+    #[inline]
+    pub const fn new(order: EulerOrder) -> EulerAngles {
+        EulerAngles::make(0.0, 0.0, 0.0, order)
+    }
+
+    /// Creates angles with no limits
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::EulerAngles;
+    /// use vex::EulerOrder;
+    ///
+    /// let actual = EulerAngles::make(0.1, 0.2, 0.3, EulerOrder::ZYX);
+    /// assert_eq!((actual.x, actual.y, actual.z), (0.1, 0.2, 0.3));
+    /// assert_eq!(actual.order, EulerOrder::ZYX);
+    /// ```
+    #[inline]
+    pub const fn make(x: f32, y: f32, z: f32, order: EulerOrder) -> EulerAngles {
+        EulerAngles {
+            x,
+            y,
+            z,
+            order,
+            limits: [None; 3],
+        }
+    }
+
+    /// Find the angles that reproduce a rotation matrix's rotation in the provided order
+    ///
+    /// See [`Matrix3::to_euler`](struct.Matrix3.html#method.to_euler) for how the angles are
+    /// chosen.
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::EulerAngles;
+    /// use vex::EulerOrder;
+    /// use vex::Matrix3;
+    ///
+    /// let m = Matrix3::from_euler(0.1, -0.2, 0.3, EulerOrder::YXZ);
+    /// let actual = EulerAngles::from_matrix3(&m, EulerOrder::YXZ);
+    /// assert!(actual.to_matrix3().approx_eq(&m, 0.000001));
+    /// ```
+    #[inline]
+    pub fn from_matrix3(m: &Matrix3, order: EulerOrder) -> EulerAngles {
+        let angles = m.to_euler(order);
+        EulerAngles::make(angles.x, angles.y, angles.z, order)
+    }
+
+    /// Limits an axis to a range, clamping its current angle into it
+    ///
+    /// `axis` is 0, 1, or 2 for x, y, or z.
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::EulerAngles;
+    /// use vex::EulerOrder;
+    ///
+    /// let mut actual = EulerAngles::make(2.0, 0.0, 0.0, EulerOrder::YXZ);
+    /// actual.set_limits(0, -1.5, 1.5);
+    /// assert_eq!(actual.limits[0], Some((-1.5, 1.5)));
+    /// assert_eq!(actual.x, 1.5);
+    /// ```
+    #[inline]
+    pub fn set_limits(&mut self, axis: usize, min: f32, max: f32) {
+        self.limits[axis] = Some((min, max));
+        self.accumulate_axis(axis, 0.0);
+    }
+
+    /// Removes the limits on an axis, so it wraps around instead
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::EulerAngles;
+    /// use vex::EulerOrder;
+    ///
+    /// let mut actual = EulerAngles::new(EulerOrder::YXZ);
+    /// actual.set_limits(1, -1.0, 1.0);
+    /// actual.clear_limits(1);
+    /// assert_eq!(actual.limits[1], None);
+    /// ```
+    #[inline]
+    pub fn clear_limits(&mut self, axis: usize) {
+        self.limits[axis] = None;
+    }
+
+    /// Adds a rotation (in radians) about each axis, clamping limited axes and wrapping the rest
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::EulerAngles;
+    /// use vex::EulerOrder;
+    /// use vex::Vector3;
+    ///
+    /// // a first-person camera pitches about x, then yaws about y, and stops just short of straight up
+    /// let mut camera = EulerAngles::new(EulerOrder::ZXY);
+    /// camera.set_limits(0, -1.55, 1.55);
+    ///
+    /// camera.accumulate(&Vector3::make(1.0, 2.0, 0.0));
+    /// camera.accumulate(&Vector3::make(1.0, 2.0, 0.0));
+    /// assert_eq!(camera.x, 1.55);
+    /// assert!((camera.y - (4.0 - 2.0 * std::f32::consts::PI)).abs() < 0.00001);
+    ///
+    /// camera.accumulate(&Vector3::make(-0.55, 0.0, 0.0));
+    /// assert!((camera.x - 1.0).abs() < 0.00001);
+    /// ```
+    #[inline]
+    pub fn accumulate(&mut self, delta: &Vector3) {
+        self.accumulate_axis(0, delta.x);
+        self.accumulate_axis(1, delta.y);
+        self.accumulate_axis(2, delta.z);
+    }
+
+    /// Creates a rotation matrix from the angles
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::EulerAngles;
+    /// use vex::EulerOrder;
+    /// use vex::Matrix3;
+    ///
+    /// let actual = EulerAngles::make(0.1, 0.2, 0.3, EulerOrder::XZY).to_matrix3();
+    /// assert_eq!(actual, Matrix3::from_euler(0.1, 0.2, 0.3, EulerOrder::XZY));
+    /// ```
+    #[inline]
+    pub fn to_matrix3(&self) -> Matrix3 {
+        Matrix3::from_euler(self.x, self.y, self.z, self.order)
+    }
+
+    /// Creates a rotation matrix from the angles
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::EulerAngles;
+    /// use vex::EulerOrder;
+    /// use vex::Matrix4;
+    ///
+    /// let actual = EulerAngles::make(0.1, 0.2, 0.3, EulerOrder::XZY).to_matrix4();
+    /// assert_eq!(actual, Matrix4::from_euler(0.1, 0.2, 0.3, EulerOrder::XZY));
+    /// ```
+    #[inline]
+    pub fn to_matrix4(&self) -> Matrix4 {
+        Matrix4::from_euler(self.x, self.y, self.z, self.order)
+    }
+
+    /// Gets the angles as a vector of x, y, and z
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::EulerAngles;
+    /// use vex::EulerOrder;
+    /// use vex::Vector3;
+    ///
+    /// let actual = EulerAngles::make(0.1, 0.2, 0.3, EulerOrder::XYZ).to_vector3();
+    /// assert_eq!(actual, Vector3::make(0.1, 0.2, 0.3));
+    /// ```
+    #[inline]
+    pub fn to_vector3(&self) -> Vector3 {
+        Vector3::make(self.x, self.y, self.z)
+    }
+
+    #[inline]
+    fn accumulate_axis(&mut self, axis: usize, delta: f32) {
+        let angle = match axis {
+            0 => &mut self.x,
+            1 => &mut self.y,
+            _ => &mut self.z,
+        };
+
+        *angle = match self.limits[axis] {
+            Some((min, max)) => (*angle + delta).clamp(min, max),
+            None => wrap_angle(*angle + delta),
+        };
+    }
+}
+
+/// Wraps an angle (in radians) into `[-pi, pi)`
+///
+/// # Examples
+/// ```
+/// use vex::wrap_angle;
+///
+/// let pi = std::f32::consts::PI;
+/// assert_eq!(wrap_angle(0.5), 0.5);
+/// assert!((wrap_angle(pi + 0.5) - (0.5 - pi)).abs() < 0.00001);
+/// assert!((wrap_angle(-pi - 0.5) - (pi - 0.5)).abs() < 0.00001);
+/// assert!((wrap_angle(7.0 * pi) + pi).abs() < 0.00001);
+/// ```
+#[inline]
+pub fn wrap_angle(angle: f32) -> f32 {
+    let turn = 2.0 * PI;
+    let mut wrapped = (angle + PI) % turn;
+    if wrapped < 0.0 {
+        wrapped += turn;
+    }
+
+    wrapped - PI
+}
+
+/// Builds a rotation as rows of a 3x3 matrix from angles about x, y, and z (in radians)
+pub(crate) fn euler_to_rows(angles: &Vector3, order: EulerOrder) -> [[f32; 3]; 3] {
+    let (axes, _) = order.axes();
+    let values = [angles.x, angles.y, angles.z];
+    let mut result = [[1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0]];
+    for &axis in axes.iter() {
+        result = mul_rows(&axis_rotation(axis, values[axis]), &result);
+    }
+
+    result
+}
+
+/// Extracts angles about x, y, and z (in radians) from the rows of a 3x3 rotation matrix
+///
+/// The middle rotation is kept within `[-pi/2, pi/2]`. At gimbal lock the last rotation is
+/// reported as zero and folded into the first.
+pub(crate) fn rows_to_euler(m: &[[f32; 3]; 3], order: EulerOrder) -> Vector3 {
+    let ([i, j, k], even) = order.axes();
+    let s = if even { 1.0 } else { -1.0 };
+
+    let sine = (-s * m[k][i]).clamp(-1.0, 1.0);
+    let b = sine.asin();
+    let (a, c) = if sine.abs() < 0.9999999 {
+        ((s * m[k][j]).atan2(m[k][k]), (s * m[j][i]).atan2(m[i][i]))
+    } else {
+        ((-s * m[j][k]).atan2(m[j][j]), 0.0)
+    };
+
+    let mut result = [0.0; 3];
+    result[i] = a;
+    result[j] = b;
+    result[k] = c;
+    Vector3::make(result[0], result[1], result[2])
+}
+
+#[inline]
+fn axis_rotation(axis: usize, angle: f32) -> [[f32; 3]; 3] {
+    let (sine, cosine) = angle.sin_cos();
+    match axis {
+        0 => [[1.0, 0.0, 0.0], [0.0, cosine, -sine], [0.0, sine, cosine]],
+        1 => [[cosine, 0.0, sine], [0.0, 1.0, 0.0], [-sine, 0.0, cosine]],
+        _ => [[cosine, -sine, 0.0], [sine, cosine, 0.0], [0.0, 0.0, 1.0]],
+    }
+}
+
+#[inline]
+fn mul_rows(a: &[[f32; 3]; 3], b: &[[f32; 3]; 3]) -> [[f32; 3]; 3] {
+    let mut result = [[0.0; 3]; 3];
+    for (r, row) in result.iter_mut().enumerate() {
+        for (c, value) in row.iter_mut().enumerate() {
+            *value = a[r][0] * b[0][c] + a[r][1] * b[1][c] + a[r][2] * b[2][c];
+        }
+    }
+
+    result
+}
@@ -0,0 +1,252 @@
+//! Faster, less exact kernels for the `fast-math` feature
+//!
+//! Rust doesn't let code opt into relaxed floating-point semantics on stable, so these kernels get
+//! their speed from what stays legal: an approximate reciprocal square root in place of a square
+//! root and a division, and sums regrouped into shorter chains of dependent additions. Each one
+//! documents how far its results can stray from the exact kernel it stands in for.
+//!
+//! # Denormals
+//!
+//! Arithmetic on denormal (subnormal) floats is many times slower than on normal floats on most
+//! x86 CPUs, which shows up in long-running feedback loops like audio filters and decaying
+//! particle velocities, whose values shrink towards zero without reaching it. The crate never
+//! changes the floating-point environment itself, since the flush-to-zero (FTZ) and
+//! denormals-are-zero (DAZ) flags are per-thread state that would leak into the caller's code.
+//! Workloads that want them should either:
+//!
+//! - Set FTZ and DAZ once on the threads that run them (`MXCSR` bits 15 and 6 on x86, `FPCR` bit
+//!   24 on aarch64), which affects every float operation on that thread
+//! - Flush the state that feeds back into the loop with [`flush_denormal`](fn.flush_denormal.html)
+//!   or [`Vector3::flushed`](struct.Vector3.html#method.flushed) once per iteration, which keeps
+//!   the rest of the program IEEE compliant
+
+use crate::matrix4::Matrix4;
+use crate::vector2::Vector2;
+use crate::vector3::Vector3;
+use crate::vector4::Vector4;
+
+/// The largest relative error of [`rsqrt_fast`](fn.rsqrt_fast.html) over every normal input
+pub const RSQRT_FAST_MAX_ERROR: f32 = 0.000005;
+
+/// Find zero in place of a denormal float, keeping its sign
+///
+/// Normal floats, infinities, and NaNs are returned unchanged.
+///
+/// # Examples
+/// ```
+/// use vex::flush_denormal;
+///
+/// assert_eq!(flush_denormal(1.0e-40), 0.0);
+/// assert!(flush_denormal(-1.0e-40).is_sign_negative());
+/// assert_eq!(flush_denormal(f32::MIN_POSITIVE), f32::MIN_POSITIVE);
+/// assert_eq!(flush_denormal(2.5), 2.5);
+/// ```
+#[inline]
+pub fn flush_denormal(x: f32) -> f32 {
+    if x.is_subnormal() {
+        0.0f32.copysign(x)
+    } else {
+        x
+    }
+}
+
+/// Approximate the reciprocal square root of a positive float
+///
+/// This refines the classic bit-level estimate with two Newton-Raphson steps, so the relative
+/// error stays under [`RSQRT_FAST_MAX_ERROR`](constant.RSQRT_FAST_MAX_ERROR.html) for every
+/// normal input. Denormal inputs are less accurate, and zero, negative, infinite, and NaN inputs
+/// give meaningless results.
+///
+/// # Examples
+/// ```
+/// use vex::rsqrt_fast;
+/// use vex::RSQRT_FAST_MAX_ERROR;
+///
+/// let mut x = 1.0e-30f32;
+/// while x < 1.0e30 {
+///     let exact = 1.0 / (x as f64).sqrt();
+///     let error = ((rsqrt_fast(x) as f64 - exact) / exact).abs();
+///     assert!(error < RSQRT_FAST_MAX_ERROR as f64);
+///     x *= 1.37;
+/// }
+/// ```
+#[inline]
+pub fn rsqrt_fast(x: f32) -> f32 {
+    let mut y = f32::from_bits(0x5f37_59df - (x.to_bits() >> 1));
+    let half = 0.5 * x;
+    y *= 1.5 - half * y * y;
+    y * (1.5 - half * y * y)
+}
+
+impl Vector2 {
+    /// Find the normalized vector using an approximate reciprocal square root
+    ///
+    /// The length of the result is within `RSQRT_FAST_MAX_ERROR` of 1, plus rounding. Vectors too
+    /// short to normalize are returned as zero, matching `normalized`.
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Vector2;
+    ///
+    /// let actual = Vector2::make(3.0, -4.0).normalized_fast();
+    /// assert!((actual - Vector2::make(0.6, -0.8)).mag() < 0.00001);
+    /// assert_eq!(Vector2::new().normalized_fast(), Vector2::new());
+    /// ```
+    #[inline]
+    pub fn normalized_fast(&self) -> Vector2 {
+        let mag_sq = self.mag_sq();
+        if mag_sq <= f32::EPSILON * f32::EPSILON {
+            return Vector2::ZERO;
+        }
+
+        *self * rsqrt_fast(mag_sq)
+    }
+
+    /// Find the vector with denormal components replaced by zero
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Vector2;
+    ///
+    /// let actual = Vector2::make(1.0e-40, 2.0).flushed();
+    /// assert_eq!(actual, Vector2::make(0.0, 2.0));
+    /// ```
+    #[inline]
+    pub fn flushed(&self) -> Vector2 {
+        self.map(flush_denormal)
+    }
+}
+
+impl Vector3 {
+    /// Find the normalized vector using an approximate reciprocal square root
+    ///
+    /// The length of the result is within `RSQRT_FAST_MAX_ERROR` of 1, plus rounding. Vectors too
+    /// short to normalize are returned as zero, matching `normalized`.
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Vector3;
+    ///
+    /// let v = Vector3::make(1.0, -2.0, 3.0);
+    /// assert!((v.normalized_fast() - v.normalized()).mag() < 0.00001);
+    /// assert!((v.normalized_fast().mag() - 1.0).abs() < 0.00001);
+    /// assert_eq!(Vector3::new().normalized_fast(), Vector3::new());
+    /// ```
+    #[inline]
+    pub fn normalized_fast(&self) -> Vector3 {
+        let mag_sq = self.mag_sq();
+        if mag_sq <= f32::EPSILON * f32::EPSILON {
+            return Vector3::ZERO;
+        }
+
+        *self * rsqrt_fast(mag_sq)
+    }
+
+    /// Find the vector with denormal components replaced by zero
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Vector3;
+    ///
+    /// // a decaying velocity stops instead of lingering in denormals
+    /// let mut velocity = Vector3::make(1.0, 0.0, 0.0);
+    /// for _ in 0..200 {
+    ///     velocity = (velocity * 0.5).flushed();
+    /// }
+    /// assert_eq!(velocity, Vector3::ZERO);
+    /// ```
+    #[inline]
+    pub fn flushed(&self) -> Vector3 {
+        self.map(flush_denormal)
+    }
+}
+
+impl Vector4 {
+    /// Find the normalized vector using an approximate reciprocal square root
+    ///
+    /// The length of the result is within `RSQRT_FAST_MAX_ERROR` of 1, plus rounding. Vectors too
+    /// short to normalize are returned as zero, matching `normalized`.
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Vector4;
+    ///
+    /// let v = Vector4::make(1.0, -2.0, 3.0, 0.5);
+    /// assert!((v.normalized_fast() - v.normalized()).mag() < 0.00001);
+    /// assert_eq!(Vector4::new().normalized_fast(), Vector4::new());
+    /// ```
+    #[inline]
+    pub fn normalized_fast(&self) -> Vector4 {
+        let mag_sq = self.mag_sq();
+        if mag_sq <= f32::EPSILON * f32::EPSILON {
+            return Vector4::ZERO;
+        }
+
+        *self * rsqrt_fast(mag_sq)
+    }
+
+    /// Find the vector with denormal components replaced by zero
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Vector4;
+    ///
+    /// let actual = Vector4::make(1.0e-40, 2.0, -1.0e-41, 0.0).flushed();
+    /// assert_eq!(actual, Vector4::make(0.0, 2.0, 0.0, 0.0));
+    /// ```
+    #[inline]
+    pub fn flushed(&self) -> Vector4 {
+        self.map(flush_denormal)
+    }
+}
+
+impl Matrix4 {
+    /// Multiply two matrices, summing each element's products in pairs
+    ///
+    /// The `*` operator adds each element's four products one after another, while this adds
+    /// them as two independent pairs, so each element only waits on two additions in a row
+    /// instead of three. Every element stays within `2 * f32::EPSILON` times the sum of the
+    /// absolute values of its four products of the exact result, the same bound as `*`, but the
+    /// two can differ in the last few bits.
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Matrix4;
+    ///
+    /// let a = Matrix4::make(
+    ///     1.5, -2.25, 3.1, 0.0, 4.7, 5.3, -6.9, 0.0, 7.7, 8.2, 9.9, 0.0, 0.3, -1.1, 2.6, 1.0,
+    /// );
+    /// let b = Matrix4::make(
+    ///     0.1, 0.7, -0.3, 0.0, -1.9, 2.4, 0.6, 0.0, 3.3, -0.8, 1.7, 0.0, 5.0, 6.0, -7.0, 1.0,
+    /// );
+    /// let actual = Matrix4::mul_fast(&a, &b);
+    /// assert!(actual.approx_eq(&(a * b), 0.0001));
+    ///
+    /// // every element is within the documented bound of the exact product
+    /// for col in 0..4 {
+    ///     for row in 0..4 {
+    ///         let products: Vec<f64> = (0..4)
+    ///             .map(|k| a.m[k * 4 + row] as f64 * b.m[col * 4 + k] as f64)
+    ///             .collect();
+    ///         let exact: f64 = products.iter().sum();
+    ///         let scale: f64 = products.iter().map(|p| p.abs()).sum();
+    ///         let error = (actual.m[col * 4 + row] as f64 - exact).abs();
+    ///         assert!(error <= 2.0 * f32::EPSILON as f64 * scale);
+    ///     }
+    /// }
+    /// ```
+    #[inline]
+    pub fn mul_fast(a: &Matrix4, b: &Matrix4) -> Matrix4 {
+        let mut m = [0.0; 16];
+        for col in 0..4 {
+            let c = &b.m[col * 4..col * 4 + 4];
+            for row in 0..4 {
+                let low = a.m[row] * c[0] + a.m[4 + row] * c[1];
+                let high = a.m[8 + row] * c[2] + a.m[12 + row] * c[3];
+                m[col * 4 + row] = low + high;
+            }
+        }
+
+        Matrix4 { m }
+    }
+}
@@ -0,0 +1,79 @@
+use crate::vector3::Vector3;
+
+/// Find the curl of a vector field at a point using central differences
+///
+/// `epsilon` is the step taken along each axis, so smaller values are more accurate until
+/// floating point cancellation takes over.
+///
+/// # Examples
+/// ```
+/// use vex::Vector3;
+/// use vex::curl;
+///
+/// // a field rotating counter-clockwise about the z-axis
+/// let field = |p: Vector3| Vector3::make(-p.y, p.x, 0.0);
+/// let actual = curl(field, &Vector3::make(1.0, 2.0, 3.0), 0.001);
+/// assert!((actual - Vector3::make(0.0, 0.0, 2.0)).mag() < 0.001);
+/// ```
+pub fn curl(field: impl Fn(Vector3) -> Vector3, p: &Vector3, epsilon: f32) -> Vector3 {
+    let [dx, dy, dz] = partials(&field, p, epsilon);
+    Vector3::make(dy.z - dz.y, dz.x - dx.z, dx.y - dy.x)
+}
+
+/// Find the divergence of a vector field at a point using central differences
+///
+/// # Examples
+/// ```
+/// use vex::Vector3;
+/// use vex::divergence;
+///
+/// let field = |p: Vector3| p;
+/// let actual = divergence(field, &Vector3::make(1.0, 2.0, 3.0), 0.001);
+/// assert!((actual - 3.0).abs() < 0.001);
+/// ```
+pub fn divergence(field: impl Fn(Vector3) -> Vector3, p: &Vector3, epsilon: f32) -> f32 {
+    let [dx, dy, dz] = partials(&field, p, epsilon);
+    dx.x + dy.y + dz.z
+}
+
+/// Find the gradient of a scalar field at a point using central differences
+///
+/// # Examples
+/// ```
+/// use vex::Vector3;
+/// use vex::gradient;
+///
+/// let field = |p: Vector3| p.x * p.x + p.y;
+/// let actual = gradient(field, &Vector3::make(2.0, 5.0, 1.0), 0.001);
+/// assert!((actual - Vector3::make(4.0, 1.0, 0.0)).mag() < 0.01);
+/// ```
+pub fn gradient(field: impl Fn(Vector3) -> f32, p: &Vector3, epsilon: f32) -> Vector3 {
+    let mut result = Vector3::new();
+    for axis in 0..3 {
+        let (forward, backward) = offsets(p, axis, epsilon);
+        result[axis] = (field(forward) - field(backward)) / (2.0 * epsilon);
+    }
+
+    result
+}
+
+/// Partial derivatives of a vector field along x, y, and z
+#[inline]
+fn partials(field: &impl Fn(Vector3) -> Vector3, p: &Vector3, epsilon: f32) -> [Vector3; 3] {
+    let mut result = [Vector3::new(); 3];
+    for (axis, partial) in (0..3).zip(result.iter_mut()) {
+        let (forward, backward) = offsets(p, axis, epsilon);
+        *partial = (field(forward) - field(backward)) / (2.0 * epsilon);
+    }
+
+    result
+}
+
+#[inline]
+fn offsets(p: &Vector3, axis: u32, epsilon: f32) -> (Vector3, Vector3) {
+    let mut forward = *p;
+    let mut backward = *p;
+    forward[axis] += epsilon;
+    backward[axis] -= epsilon;
+    (forward, backward)
+}
@@ -0,0 +1,329 @@
+#[cfg(not(feature = "std"))]
+use crate::math::Float;
+use crate::matrix3::Matrix3;
+use crate::matrix4::Matrix4;
+use crate::vector2::Vector2;
+use crate::vector3::Vector3;
+
+/// Find the rotation and translation that best maps `src` onto `dst` in the least-squares sense
+///
+/// Points are matched by index, so both slices must have the same length. An empty point set
+/// produces the identity.
+///
+/// # Panics
+///
+/// Panics if `src` and `dst` have different lengths.
+///
+/// # Examples
+/// ```
+/// use vex::Matrix;
+/// use vex::Vector2;
+/// use vex::fit_rigid_2d;
+///
+/// let src = [Vector2::make(0.0, 0.0), Vector2::make(1.0, 0.0), Vector2::make(0.0, 2.0)];
+/// let dst = [Vector2::make(3.0, 1.0), Vector2::make(3.0, 2.0), Vector2::make(1.0, 1.0)];
+/// let m = fit_rigid_2d(&src, &dst);
+/// for (s, d) in src.iter().zip(dst.iter()) {
+///     assert!((m.transform_point(s) - *d).mag() < 0.0001);
+/// }
+/// ```
+#[inline]
+pub fn fit_rigid_2d(src: &[Vector2], dst: &[Vector2]) -> Matrix3 {
+    fit_2d(src, dst, false)
+}
+
+/// Find the rotation, uniform scale, and translation that best maps `src` onto `dst`
+///
+/// This is the Umeyama extension of [`fit_rigid_2d`](fn.fit_rigid_2d.html).
+///
+/// # Panics
+///
+/// Panics if `src` and `dst` have different lengths.
+///
+/// # Examples
+/// ```
+/// use vex::Matrix;
+/// use vex::Vector2;
+/// use vex::fit_similarity_2d;
+///
+/// let src = [Vector2::make(0.0, 0.0), Vector2::make(1.0, 0.0), Vector2::make(0.0, 1.0)];
+/// let dst = [Vector2::make(1.0, 1.0), Vector2::make(3.0, 1.0), Vector2::make(1.0, 3.0)];
+/// let m = fit_similarity_2d(&src, &dst);
+/// for (s, d) in src.iter().zip(dst.iter()) {
+///     assert!((m.transform_point(s) - *d).mag() < 0.0001);
+/// }
+/// ```
+#[inline]
+pub fn fit_similarity_2d(src: &[Vector2], dst: &[Vector2]) -> Matrix3 {
+    fit_2d(src, dst, true)
+}
+
+/// Find the rotation and translation that best maps `src` onto `dst` in the least-squares sense
+///
+/// Uses Horn's closed-form quaternion solution of the Kabsch problem, so the result is always a
+/// proper rotation, even for degenerate or mirrored inputs. Points are matched by index, so both
+/// slices must have the same length. An empty point set produces the identity.
+///
+/// # Panics
+///
+/// Panics if `src` and `dst` have different lengths.
+///
+/// # Examples
+/// ```
+/// use vex::Matrix;
+/// use vex::Vector3;
+/// use vex::fit_rigid_3d;
+///
+/// let src = [
+///     Vector3::make(0.0, 0.0, 0.0),
+///     Vector3::make(1.0, 0.0, 0.0),
+///     Vector3::make(0.0, 2.0, 0.0),
+///     Vector3::make(0.0, 0.0, 3.0),
+/// ];
+///
+/// // rotated 90 degrees about z, then translated
+/// let dst = [
+///     Vector3::make(1.0, 2.0, 3.0),
+///     Vector3::make(1.0, 3.0, 3.0),
+///     Vector3::make(-1.0, 2.0, 3.0),
+///     Vector3::make(1.0, 2.0, 6.0),
+/// ];
+///
+/// let m = fit_rigid_3d(&src, &dst);
+/// for (s, d) in src.iter().zip(dst.iter()) {
+///     assert!((m.transform_point(s) - *d).mag() < 0.0001);
+/// }
+/// ```
+#[inline]
+pub fn fit_rigid_3d(src: &[Vector3], dst: &[Vector3]) -> Matrix4 {
+    fit_3d(src, dst, false)
+}
+
+/// Find the rotation, uniform scale, and translation that best maps `src` onto `dst`
+///
+/// This is the Umeyama extension of [`fit_rigid_3d`](fn.fit_rigid_3d.html).
+///
+/// # Panics
+///
+/// Panics if `src` and `dst` have different lengths.
+///
+/// # Examples
+/// ```
+/// use vex::Matrix;
+/// use vex::Vector3;
+/// use vex::fit_similarity_3d;
+///
+/// let src = [
+///     Vector3::make(0.0, 0.0, 0.0),
+///     Vector3::make(1.0, 0.0, 0.0),
+///     Vector3::make(0.0, 1.0, 0.0),
+///     Vector3::make(0.0, 0.0, 1.0),
+/// ];
+///
+/// let dst = [
+///     Vector3::make(0.0, 0.0, 1.0),
+///     Vector3::make(0.0, 0.0, 3.0),
+///     Vector3::make(0.0, 2.0, 1.0),
+///     Vector3::make(-2.0, 0.0, 1.0),
+/// ];
+///
+/// let m = fit_similarity_3d(&src, &dst);
+/// for (s, d) in src.iter().zip(dst.iter()) {
+///     assert!((m.transform_point(s) - *d).mag() < 0.0001);
+/// }
+/// ```
+#[inline]
+pub fn fit_similarity_3d(src: &[Vector3], dst: &[Vector3]) -> Matrix4 {
+    fit_3d(src, dst, true)
+}
+
+fn fit_2d(src: &[Vector2], dst: &[Vector2], with_scale: bool) -> Matrix3 {
+    assert_eq!(src.len(), dst.len(), "point sets must have the same length");
+    if src.is_empty() {
+        return Matrix3::new();
+    }
+
+    let count = src.len() as f32;
+    let src_center = src.iter().fold(Vector2::new(), |acc, p| acc + *p) / count;
+    let dst_center = dst.iter().fold(Vector2::new(), |acc, p| acc + *p) / count;
+
+    let mut dot = 0.0;
+    let mut cross = 0.0;
+    let mut variance = 0.0;
+    for (s, d) in src.iter().zip(dst.iter()) {
+        let a = *s - src_center;
+        let b = *d - dst_center;
+        dot += Vector2::dot(&a, &b);
+        cross += Vector2::cross(&a, &b);
+        variance += a.mag_sq();
+    }
+
+    let angle = cross.atan2(dot);
+    let cosine = angle.cos();
+    let sine = angle.sin();
+    let scale = if with_scale && variance > 0.0 {
+        (cosine * dot + sine * cross) / variance
+    } else {
+        1.0
+    };
+
+    let tx = dst_center.x - scale * (cosine * src_center.x - sine * src_center.y);
+    let ty = dst_center.y - scale * (sine * src_center.x + cosine * src_center.y);
+    Matrix3::make(
+        scale * cosine,
+        scale * sine,
+        0.0,
+        -scale * sine,
+        scale * cosine,
+        0.0,
+        tx,
+        ty,
+        1.0,
+    )
+}
+
+fn fit_3d(src: &[Vector3], dst: &[Vector3], with_scale: bool) -> Matrix4 {
+    assert_eq!(src.len(), dst.len(), "point sets must have the same length");
+    if src.is_empty() {
+        return Matrix4::new();
+    }
+
+    let count = src.len() as f32;
+    let src_center = src.iter().fold(Vector3::new(), |acc, p| acc + *p) / count;
+    let dst_center = dst.iter().fold(Vector3::new(), |acc, p| acc + *p) / count;
+
+    // accumulate the cross-covariance s[i][j] = sum(a[i] * b[j])
+    let mut s = [[0.0f64; 3]; 3];
+    let mut variance = 0.0f64;
+    for (p, q) in src.iter().zip(dst.iter()) {
+        let a = *p - src_center;
+        let b = *q - dst_center;
+        let a = [a.x as f64, a.y as f64, a.z as f64];
+        let b = [b.x as f64, b.y as f64, b.z as f64];
+        for i in 0..3 {
+            for j in 0..3 {
+                s[i][j] += a[i] * b[j];
+            }
+        }
+
+        variance += a[0] * a[0] + a[1] * a[1] + a[2] * a[2];
+    }
+
+    let (sxx, sxy, sxz) = (s[0][0], s[0][1], s[0][2]);
+    let (syx, syy, syz) = (s[1][0], s[1][1], s[1][2]);
+    let (szx, szy, szz) = (s[2][0], s[2][1], s[2][2]);
+    let n = [
+        [sxx + syy + szz, syz - szy, szx - sxz, sxy - syx],
+        [syz - szy, sxx - syy - szz, sxy + syx, szx + sxz],
+        [szx - sxz, sxy + syx, -sxx + syy - szz, syz + szy],
+        [sxy - syx, szx + sxz, syz + szy, -sxx - syy + szz],
+    ];
+
+    // the optimal rotation is the eigenvector of the largest eigenvalue
+    let (values, vectors) = jacobi_eigen_4(n);
+    let mut best = 0;
+    for i in 1..4 {
+        if values[i] > values[best] {
+            best = i;
+        }
+    }
+
+    let w = vectors[0][best];
+    let x = vectors[1][best];
+    let y = vectors[2][best];
+    let z = vectors[3][best];
+    let r = [
+        [1.0 - 2.0 * (y * y + z * z), 2.0 * (x * y - w * z), 2.0 * (x * z + w * y)],
+        [2.0 * (x * y + w * z), 1.0 - 2.0 * (x * x + z * z), 2.0 * (y * z - w * x)],
+        [2.0 * (x * z - w * y), 2.0 * (y * z + w * x), 1.0 - 2.0 * (x * x + y * y)],
+    ];
+
+    let scale = if with_scale && variance > 0.0 {
+        let mut trace = 0.0;
+        for i in 0..3 {
+            for j in 0..3 {
+                trace += r[i][j] * s[j][i];
+            }
+        }
+
+        (trace / variance) as f32
+    } else {
+        1.0
+    };
+
+    let r = [
+        [r[0][0] as f32 * scale, r[0][1] as f32 * scale, r[0][2] as f32 * scale],
+        [r[1][0] as f32 * scale, r[1][1] as f32 * scale, r[1][2] as f32 * scale],
+        [r[2][0] as f32 * scale, r[2][1] as f32 * scale, r[2][2] as f32 * scale],
+    ];
+
+    let c = src_center;
+    let tx = dst_center.x - (r[0][0] * c.x + r[0][1] * c.y + r[0][2] * c.z);
+    let ty = dst_center.y - (r[1][0] * c.x + r[1][1] * c.y + r[1][2] * c.z);
+    let tz = dst_center.z - (r[2][0] * c.x + r[2][1] * c.y + r[2][2] * c.z);
+    Matrix4::make(
+        r[0][0], r[1][0], r[2][0], 0.0,
+        r[0][1], r[1][1], r[2][1], 0.0,
+        r[0][2], r[1][2], r[2][2], 0.0,
+        tx, ty, tz, 1.0,
+    )
+}
+
+/// Cyclic Jacobi eigen decomposition of a symmetric 4x4 matrix, returning the eigenvalues and
+/// the eigenvectors as columns
+#[allow(clippy::needless_range_loop)]
+fn jacobi_eigen_4(mut a: [[f64; 4]; 4]) -> ([f64; 4], [[f64; 4]; 4]) {
+    let mut v = [[0.0f64; 4]; 4];
+    for (i, row) in v.iter_mut().enumerate() {
+        row[i] = 1.0;
+    }
+
+    for _ in 0..32 {
+        let mut off = 0.0;
+        for p in 0..3 {
+            for q in (p + 1)..4 {
+                off += a[p][q] * a[p][q];
+            }
+        }
+
+        if off < 1e-24 {
+            break;
+        }
+
+        for p in 0..3 {
+            for q in (p + 1)..4 {
+                if a[p][q] == 0.0 {
+                    continue;
+                }
+
+                let theta = (a[q][q] - a[p][p]) / (2.0 * a[p][q]);
+                let t = theta.signum() / (theta.abs() + (theta * theta + 1.0).sqrt());
+                let c = 1.0 / (t * t + 1.0).sqrt();
+                let s = t * c;
+
+                for k in 0..4 {
+                    let akp = a[k][p];
+                    let akq = a[k][q];
+                    a[k][p] = c * akp - s * akq;
+                    a[k][q] = s * akp + c * akq;
+                }
+
+                for k in 0..4 {
+                    let apk = a[p][k];
+                    let aqk = a[q][k];
+                    a[p][k] = c * apk - s * aqk;
+                    a[q][k] = s * apk + c * aqk;
+                }
+
+                for row in v.iter_mut() {
+                    let vkp = row[p];
+                    let vkq = row[q];
+                    row[p] = c * vkp - s * vkq;
+                    row[q] = s * vkp + c * vkq;
+                }
+            }
+        }
+    }
+
+    ([a[0][0], a[1][1], a[2][2], a[3][3]], v)
+}
@@ -0,0 +1,129 @@
+//! Shared `Display` and `Debug` layouts for vectors and matrices
+//!
+//! Vectors print as `<x, y, z>` and matrices as a list of rows, `[[m11, m12], [m21, m22]]`, with
+//! the formatter's flags applied to every component. The alternate flag lays matrices out as a
+//! grid, one row per line, with each column padded to its widest element.
+
+use core::fmt::{self, Alignment, Display, Formatter, Write};
+
+// counts the characters written through it, so elements can be measured without allocating
+struct Counter(usize);
+
+impl Write for Counter {
+    #[inline]
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.0 += s.chars().count();
+        Ok(())
+    }
+}
+
+// the flags that change how a value itself is written, as opposed to how it's padded
+#[derive(Copy, Clone)]
+struct Spec {
+    sign_plus: bool,
+    precision: Option<usize>,
+}
+
+impl Spec {
+    fn of(f: &Formatter) -> Spec {
+        Spec {
+            sign_plus: f.sign_plus(),
+            precision: f.precision(),
+        }
+    }
+}
+
+// writes a value with the formatter's sign and precision, leaving out its padding
+fn write_unpadded<W: Write, T: Display>(w: &mut W, spec: Spec, x: &T) -> fmt::Result {
+    match (spec.sign_plus, spec.precision) {
+        (false, None) => write!(w, "{}", x),
+        (false, Some(p)) => write!(w, "{:.*}", p, x),
+        (true, None) => write!(w, "{:+}", x),
+        (true, Some(p)) => write!(w, "{:+.*}", p, x),
+    }
+}
+
+fn unpadded_len<T: Display>(spec: Spec, x: &T) -> usize {
+    let mut counter = Counter(0);
+    let _ = write_unpadded(&mut counter, spec, x);
+    counter.0
+}
+
+fn write_fill(f: &mut Formatter, count: usize) -> fmt::Result {
+    for _ in 0..count {
+        f.write_char(f.fill())?;
+    }
+
+    Ok(())
+}
+
+// writes each component with the formatter's flags, separated by commas
+fn write_components<T: Display>(f: &mut Formatter, components: &[T]) -> fmt::Result {
+    for (i, component) in components.iter().enumerate() {
+        if i > 0 {
+            f.write_str(", ")?;
+        }
+
+        Display::fmt(component, f)?;
+    }
+
+    Ok(())
+}
+
+/// Writes a vector's components as `<x, y, z>`, applying the formatter's flags to each one
+pub(crate) fn write_vector<T: Display>(f: &mut Formatter, components: &[T]) -> fmt::Result {
+    f.write_char('<')?;
+    write_components(f, components)?;
+    f.write_char('>')
+}
+
+/// Writes a matrix's rows as nested lists, or as an aligned grid with the alternate flag
+pub(crate) fn write_matrix<const N: usize>(f: &mut Formatter, rows: &[[f32; N]; N]) -> fmt::Result {
+    if !f.alternate() {
+        f.write_char('[')?;
+        for (i, row) in rows.iter().enumerate() {
+            if i > 0 {
+                f.write_str(", ")?;
+            }
+
+            f.write_char('[')?;
+            write_components(f, row)?;
+            f.write_char(']')?;
+        }
+
+        return f.write_char(']');
+    }
+
+    let spec = Spec::of(f);
+    let mut widths = [f.width().unwrap_or(0); N];
+    for row in rows {
+        for (width, x) in widths.iter_mut().zip(row) {
+            *width = (*width).max(unpadded_len(spec, x));
+        }
+    }
+
+    f.write_str("[\n")?;
+    for (i, row) in rows.iter().enumerate() {
+        f.write_str("    [")?;
+        for (j, x) in row.iter().enumerate() {
+            if j > 0 {
+                f.write_str(", ")?;
+            }
+
+            let padding = widths[j] - unpadded_len(spec, x);
+            let (before, after) = match f.align() {
+                Some(Alignment::Left) => (0, padding),
+                Some(Alignment::Center) => (padding / 2, padding - padding / 2),
+                _ => (padding, 0),
+            };
+
+            write_fill(f, before)?;
+            write_unpadded(f, spec, x)?;
+            write_fill(f, after)?;
+        }
+
+        f.write_str(if i + 1 < N { "],\n" } else { "]\n" })?;
+    }
+
+    f.write_char(']')
+}
@@ -0,0 +1,202 @@
+use crate::aabb::Aabb;
+use crate::clip_space::{ClipSpace, DepthRange};
+use crate::matrix4::Matrix4;
+use crate::plane::Plane;
+use crate::vector3::Vector3;
+
+/// The volume visible through a camera, bounded by six planes facing inwards
+///
+/// The planes are ordered left, right, bottom, top, near, far.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Frustum {
+    pub planes: [Plane; 6],
+}
+
+impl Frustum {
+    /// Creates a frustum from the provided planes, which should face inwards
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Frustum;
+    /// use vex::Plane;
+    /// use vex::Vector3;
+    ///
+    /// let plane = Plane::make(Vector3::up(), 1.0);
+    /// let actual = Frustum::make([plane; 6]);
+    /// assert_eq!(actual.planes[4], plane);
+    /// ```
+    #[inline]
+    pub const fn make(planes: [Plane; 6]) -> Frustum {
+        Frustum { planes }
+    }
+
+    /// Extracts the frustum of a combined projection and view matrix
+    ///
+    /// The planes are read straight from the rows of the matrix, in world space when given a
+    /// projection times a view matrix, and are normalized so distances to them are in world units.
+    /// The matrix is expected to map into OpenGL's clip volume, where depth runs from `-w` to `w`,
+    /// as [`perspective`](struct.Matrix4.html#method.perspective) and
+    /// [`ortho`](struct.Matrix4.html#method.ortho) do. Use
+    /// [`from_matrix_clip`](#method.from_matrix_clip) for other clip spaces.
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Frustum;
+    /// use vex::Matrix4;
+    /// use vex::Vector3;
+    ///
+    /// let projection = Matrix4::perspective(90.0, 1.0, 1.0, 100.0);
+    /// let view = Matrix4::look_at_rh(Vector3::new(), Vector3::make(0.0, 0.0, -1.0), Vector3::up());
+    /// let frustum = Frustum::from_matrix(&(projection * view));
+    ///
+    /// let near = frustum.planes[4];
+    /// assert!((near.normal - Vector3::make(0.0, 0.0, -1.0)).mag() < 0.0001);
+    /// assert!((near.d + 1.0).abs() < 0.0001);
+    ///
+    /// let far = frustum.planes[5];
+    /// assert!((far.signed_distance(&Vector3::make(0.0, 0.0, -100.0))).abs() < 0.001);
+    /// ```
+    #[inline]
+    pub fn from_matrix(view_proj: &Matrix4) -> Frustum {
+        Frustum::from_matrix_clip(view_proj, &ClipSpace::OPENGL)
+    }
+
+    /// Extracts the frustum of a combined projection and view matrix built for a clip space
+    ///
+    /// The near and far planes are picked from the clip space's depth range, and the bottom and
+    /// top planes are swapped back when it flips y, so the planes keep their usual order. An
+    /// infinite projection has no far plane, so everything is in front of it.
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::ClipSpace;
+    /// use vex::DepthRange;
+    /// use vex::Frustum;
+    /// use vex::Matrix4;
+    /// use vex::Vector3;
+    ///
+    /// let projection = Matrix4::perspective_clip(90.0, 1.0, 1.0, 100.0, &ClipSpace::VULKAN);
+    /// let frustum = Frustum::from_matrix_clip(&projection, &ClipSpace::VULKAN);
+    /// assert!((frustum.planes[4].signed_distance(&Vector3::make(0.0, 0.0, -1.0))).abs() < 0.0001);
+    /// assert!(frustum.planes[2].signed_distance(&Vector3::make(0.0, -5.0, -10.0)) > 0.0);
+    /// assert!(frustum.contains_point(&Vector3::make(0.0, 0.0, -50.0)));
+    ///
+    /// // reverse-Z, with no far plane
+    /// let reverse_z = ClipSpace::make(DepthRange::OneToZero, false);
+    /// let projection = Matrix4::perspective_reverse_z(90.0, 1.0, 1.0);
+    /// let frustum = Frustum::from_matrix_clip(&projection, &reverse_z);
+    /// assert!((frustum.planes[4].signed_distance(&Vector3::make(0.0, 0.0, -1.0))).abs() < 0.0001);
+    /// assert!(frustum.contains_point(&Vector3::make(0.0, 0.0, -1000000.0)));
+    /// assert!(!frustum.contains_point(&Vector3::make(0.0, 0.0, -0.5)));
+    /// ```
+    pub fn from_matrix_clip(view_proj: &Matrix4, clip: &ClipSpace) -> Frustum {
+        let m = &view_proj.m;
+        let row = |i: usize| [m[i], m[i + 4], m[i + 8], m[i + 12]];
+        let (x, y, z, w) = (row(0), row(1), row(2), row(3));
+
+        // the plane where `a * w + b * r` is zero, facing the side where it's positive
+        let plane = |a: f32, b: f32, r: [f32; 4]| {
+            let normal = Vector3::make(
+                a * w[0] + b * r[0],
+                a * w[1] + b * r[1],
+                a * w[2] + b * r[2],
+            );
+            Plane::make(normal, a * w[3] + b * r[3]).normalized()
+        };
+
+        let y_sign = if clip.flip_y { -1.0 } else { 1.0 };
+        let (near, far) = match clip.depth {
+            DepthRange::NegativeOneToOne => (plane(1.0, 1.0, z), plane(1.0, -1.0, z)),
+            DepthRange::ZeroToOne => (plane(0.0, 1.0, z), plane(1.0, -1.0, z)),
+            DepthRange::OneToZero => (plane(1.0, -1.0, z), plane(0.0, 1.0, z)),
+        };
+
+        Frustum::make([
+            plane(1.0, 1.0, x),
+            plane(1.0, -1.0, x),
+            plane(1.0, y_sign, y),
+            plane(1.0, -y_sign, y),
+            near,
+            far,
+        ])
+    }
+
+    /// Determine whether or not a point is inside the frustum
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Frustum;
+    /// use vex::Matrix4;
+    /// use vex::Vector3;
+    ///
+    /// let frustum = Frustum::from_matrix(&Matrix4::perspective(90.0, 1.0, 1.0, 100.0));
+    /// assert!(frustum.contains_point(&Vector3::make(0.0, 0.0, -10.0)));
+    /// assert!(frustum.contains_point(&Vector3::make(9.0, -9.0, -10.0)));
+    /// assert!(!frustum.contains_point(&Vector3::make(11.0, 0.0, -10.0)));
+    /// assert!(!frustum.contains_point(&Vector3::make(0.0, 0.0, 10.0)));
+    /// assert!(!frustum.contains_point(&Vector3::make(0.0, 0.0, -101.0)));
+    /// ```
+    #[inline]
+    pub fn contains_point(&self, point: &Vector3) -> bool {
+        self.planes.iter().all(|plane| plane.signed_distance(point) >= 0.0)
+    }
+
+    /// Determine whether or not a sphere is at least partly inside the frustum
+    ///
+    /// Like most culling tests this is conservative: a large sphere just outside a corner of the
+    /// frustum can be reported as intersecting it, but a sphere inside is never missed.
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Frustum;
+    /// use vex::Matrix4;
+    /// use vex::Vector3;
+    ///
+    /// let frustum = Frustum::from_matrix(&Matrix4::perspective(90.0, 1.0, 1.0, 100.0));
+    /// assert!(frustum.intersects_sphere(&Vector3::make(0.0, 0.0, -10.0), 1.0));
+    /// assert!(frustum.intersects_sphere(&Vector3::make(11.0, 0.0, -10.0), 1.0));
+    /// assert!(!frustum.intersects_sphere(&Vector3::make(12.0, 0.0, -10.0), 1.0));
+    /// assert!(!frustum.intersects_sphere(&Vector3::make(0.0, 0.0, 2.0), 1.0));
+    /// ```
+    #[inline]
+    pub fn intersects_sphere(&self, center: &Vector3, radius: f32) -> bool {
+        self.planes.iter().all(|plane| plane.signed_distance(center) >= -radius)
+    }
+
+    /// Determine whether or not a box is at least partly inside the frustum
+    ///
+    /// Each plane is tested against the corner of the box furthest along its normal. Like
+    /// [`intersects_sphere`](#method.intersects_sphere), this is conservative near the frustum's
+    /// edges.
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Aabb;
+    /// use vex::Frustum;
+    /// use vex::Matrix4;
+    /// use vex::Vector3;
+    ///
+    /// let frustum = Frustum::from_matrix(&Matrix4::perspective(90.0, 1.0, 1.0, 100.0));
+    /// let inside = Aabb::make(Vector3::make(-1.0, -1.0, -11.0), Vector3::make(1.0, 1.0, -9.0));
+    /// assert!(frustum.intersects_aabb(&inside));
+    ///
+    /// let straddling = Aabb::make(Vector3::make(9.0, -1.0, -11.0), Vector3::make(20.0, 1.0, -9.0));
+    /// assert!(frustum.intersects_aabb(&straddling));
+    ///
+    /// let behind = Aabb::make(Vector3::make(-1.0, -1.0, 1.0), Vector3::make(1.0, 1.0, 2.0));
+    /// assert!(!frustum.intersects_aabb(&behind));
+    /// ```
+    #[inline]
+    pub fn intersects_aabb(&self, aabb: &Aabb) -> bool {
+        self.planes.iter().all(|plane| {
+            let n = plane.normal;
+            let corner = Vector3::make(
+                if n.x >= 0.0 { aabb.max.x } else { aabb.min.x },
+                if n.y >= 0.0 { aabb.max.y } else { aabb.min.y },
+                if n.z >= 0.0 { aabb.max.z } else { aabb.min.z },
+            );
+
+            plane.signed_distance(&corner) >= 0.0
+        })
+    }
+}
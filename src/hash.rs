@@ -0,0 +1,140 @@
+use core::hash::{Hash, Hasher};
+
+#[cfg(not(feature = "std"))]
+use crate::math::Float;
+use crate::vector3::Vector3;
+
+const FNV_OFFSET: u64 = 0xcbf29ce484222325;
+const FNV_PRIME: u64 = 0x100000001b3;
+
+/// Find a content hash of a buffer of vectors that's stable across platforms and releases
+///
+/// Components are hashed as their little-endian bytes using 64-bit FNV-1a, after folding -0.0
+/// into 0.0 and every NaN into a single canonical NaN, so equal geometry always produces the same
+/// hash regardless of the host's endianness.
+///
+/// # Examples
+/// ```
+/// use vex::Vector3;
+/// use vex::hash_buffer;
+///
+/// let a = [Vector3::make(1.0, 2.0, 3.0), Vector3::make(0.0, 0.0, 0.0)];
+/// let b = [Vector3::make(1.0, 2.0, 3.0), Vector3::make(-0.0, 0.0, 0.0)];
+/// let c = [Vector3::make(1.0, 2.0, 3.0), Vector3::make(0.0, 0.0, 1.0)];
+/// assert_eq!(hash_buffer(&a), hash_buffer(&b));
+/// assert_ne!(hash_buffer(&a), hash_buffer(&c));
+/// assert_eq!(hash_buffer(&[]), 0xcbf29ce484222325);
+/// ```
+pub fn hash_buffer(points: &[Vector3]) -> u64 {
+    let mut hash = FNV_OFFSET;
+    for p in points {
+        for &value in &[p.x, p.y, p.z] {
+            hash = fnv1a(hash, &canonical(value).to_bits().to_le_bytes());
+        }
+    }
+
+    hash
+}
+
+/// Find a content hash of a buffer of vectors after snapping them to a grid
+///
+/// Each component is rounded to the nearest multiple of `step` and the grid coordinate is hashed,
+/// so buffers that differ only by noise smaller than half a step hash the same. Non-finite
+/// components are hashed as they are by [`hash_buffer`](fn.hash_buffer.html).
+///
+/// # Examples
+/// ```
+/// use vex::Vector3;
+/// use vex::hash_buffer_quantized;
+///
+/// let a = [Vector3::make(1.0, 2.0, 3.0)];
+/// let b = [Vector3::make(1.0001, 1.9999, 3.0)];
+/// let c = [Vector3::make(1.1, 2.0, 3.0)];
+/// assert_eq!(hash_buffer_quantized(&a, 0.001), hash_buffer_quantized(&b, 0.001));
+/// assert_ne!(hash_buffer_quantized(&a, 0.001), hash_buffer_quantized(&c, 0.001));
+/// ```
+pub fn hash_buffer_quantized(points: &[Vector3], step: f32) -> u64 {
+    assert!(step > 0.0, "quantization step must be positive");
+
+    let mut hash = FNV_OFFSET;
+    for p in points {
+        for &value in &[p.x, p.y, p.z] {
+            let bits = if value.is_finite() {
+                ((value as f64 / step as f64).round() as i64) as u64
+            } else {
+                canonical(value).to_bits() as u64
+            };
+
+            hash = fnv1a(hash, &bits.to_le_bytes());
+        }
+    }
+
+    hash
+}
+
+/// A wrapper that lets vector, matrix, and quaternion types be used as `HashMap` or `HashSet` keys
+///
+/// Values compare and hash by the bits of their components, after folding -0.0 into 0.0 and every
+/// NaN into a single canonical NaN, so unlike the wrapped type's `PartialEq`, NaN components equal
+/// each other.
+///
+/// # Examples
+/// ```
+/// use std::collections::HashSet;
+/// use vex::Hashable;
+/// use vex::Vector3;
+///
+/// let mut set = HashSet::new();
+/// set.insert(Hashable(Vector3::make(1.0, 0.0, 2.0)));
+/// assert!(set.contains(&Hashable(Vector3::make(1.0, -0.0, 2.0))));
+/// assert!(!set.contains(&Hashable(Vector3::make(1.0, 0.0, 3.0))));
+///
+/// let nan = Vector3::make(f32::NAN, 0.0, 0.0);
+/// assert_eq!(Hashable(nan), Hashable(nan));
+/// ```
+#[derive(Copy, Clone, Debug, Default)]
+pub struct Hashable<T>(pub T);
+
+impl<T: AsRef<[f32]>> PartialEq for Hashable<T> {
+    #[inline]
+    fn eq(&self, other: &Hashable<T>) -> bool {
+        let a = self.0.as_ref();
+        let b = other.0.as_ref();
+        a.len() == b.len()
+            && a.iter()
+                .zip(b.iter())
+                .all(|(x, y)| canonical(*x).to_bits() == canonical(*y).to_bits())
+    }
+}
+
+impl<T: AsRef<[f32]>> Eq for Hashable<T> {}
+
+impl<T: AsRef<[f32]>> Hash for Hashable<T> {
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        for value in self.0.as_ref() {
+            canonical(*value).to_bits().hash(state);
+        }
+    }
+}
+
+#[inline]
+fn canonical(value: f32) -> f32 {
+    if value.is_nan() {
+        f32::NAN
+    } else if value == 0.0 {
+        0.0
+    } else {
+        value
+    }
+}
+
+#[inline]
+fn fnv1a(mut hash: u64, bytes: &[u8]) -> u64 {
+    for &byte in bytes {
+        hash ^= byte as u64;
+        hash = hash.wrapping_mul(FNV_PRIME);
+    }
+
+    hash
+}
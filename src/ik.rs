@@ -0,0 +1,157 @@
+use crate::vector3::Vector3;
+
+/// Bends a chain of joints so its end reaches towards a target, using FABRIK
+///
+/// `joints` runs from the root to the end of the chain, and `lengths[i]` is the length of the bone
+/// between `joints[i]` and `joints[i + 1]`. The root stays where it is. A target out of reach
+/// straightens the chain towards it. Otherwise the chain is solved for up to `iterations` passes,
+/// stopping early once the end is within `tolerance` of the target.
+///
+/// When a `pole` is provided, each joint between the root and the end is swung around the line
+/// through its neighbors to face the pole, which keeps knees and elbows bending the same way.
+///
+/// Returns the distance from the end of the chain to the target.
+///
+/// # Examples
+/// ```
+/// use vex::Vector3;
+/// use vex::fabrik;
+///
+/// let mut joints = [
+///     Vector3::new(),
+///     Vector3::make(0.0, 1.0, 0.0),
+///     Vector3::make(0.0, 2.0, 0.0),
+///     Vector3::make(0.0, 3.0, 0.0),
+/// ];
+/// let lengths = [1.0, 1.0, 1.0];
+/// let target = Vector3::make(1.5, 1.5, 0.0);
+/// let error = fabrik(&mut joints, &lengths, &target, None, 20, 0.001);
+/// assert!(error <= 0.001);
+/// assert!((joints[3] - target).mag() <= 0.001);
+/// assert_eq!(joints[0], Vector3::new());
+///
+/// for i in 0..3 {
+///     assert!((Vector3::distance(&joints[i], &joints[i + 1]) - 1.0).abs() < 0.0001);
+/// }
+///
+/// // out of reach, so the chain points straight at the target
+/// let error = fabrik(&mut joints, &lengths, &Vector3::make(5.0, 0.0, 0.0), None, 20, 0.001);
+/// assert!((error - 2.0).abs() < 0.0001);
+/// assert!((joints[3] - Vector3::make(3.0, 0.0, 0.0)).mag() < 0.0001);
+/// ```
+///
+/// ```
+/// use vex::Vector3;
+/// use vex::fabrik;
+///
+/// // a leg bending its knee towards +z
+/// let mut joints = [Vector3::new(), Vector3::make(0.0, -1.0, 0.0), Vector3::make(0.0, -2.0, 0.0)];
+/// let target = Vector3::make(0.0, -1.5, 0.0);
+/// let pole = Vector3::make(0.0, -1.0, 1.0);
+/// fabrik(&mut joints, &[1.0, 1.0], &target, Some(&pole), 20, 0.001);
+/// assert!((joints[2] - target).mag() <= 0.001);
+/// assert!(joints[1].z > 0.5);
+/// assert!((Vector3::distance(&joints[0], &joints[1]) - 1.0).abs() < 0.0001);
+/// ```
+pub fn fabrik(
+    joints: &mut [Vector3],
+    lengths: &[f32],
+    target: &Vector3,
+    pole: Option<&Vector3>,
+    iterations: u32,
+    tolerance: f32,
+) -> f32 {
+    assert_eq!(lengths.len() + 1, joints.len(), "a chain needs one length per bone");
+
+    let count = joints.len();
+    if count < 2 {
+        return joints.first().map_or(0.0, |root| Vector3::distance(root, target));
+    }
+
+    let root = joints[0];
+    let reach: f32 = lengths.iter().sum();
+    if Vector3::distance(&root, target) >= reach {
+        for i in 0..count - 1 {
+            let direction = direction(&joints[i], target);
+            joints[i + 1] = joints[i] + direction * lengths[i];
+        }
+
+        return Vector3::distance(&joints[count - 1], target);
+    }
+
+    // a straight chain has no side to fold towards, so start it bent slightly towards the pole
+    let solved = Vector3::distance(&joints[count - 1], target) <= tolerance;
+    if let (Some(pole), false) = (pole, solved) {
+        for i in 1..count - 1 {
+            let bend = 0.01 * lengths[i - 1].min(lengths[i]);
+            joints[i] = face_pole(&joints[i - 1], &joints[i], &joints[i + 1], pole, bend);
+        }
+    }
+
+    for _ in 0..iterations {
+        if Vector3::distance(&joints[count - 1], target) <= tolerance {
+            break;
+        }
+
+        // backwards from the target to the root
+        joints[count - 1] = *target;
+        for i in (0..count - 1).rev() {
+            let direction = direction(&joints[i + 1], &joints[i]);
+            joints[i] = joints[i + 1] + direction * lengths[i];
+        }
+
+        // forwards from the root back out to the end
+        joints[0] = root;
+        for i in 0..count - 1 {
+            let direction = direction(&joints[i], &joints[i + 1]);
+            joints[i + 1] = joints[i] + direction * lengths[i];
+        }
+
+        if let Some(pole) = pole {
+            for i in 1..count - 1 {
+                joints[i] = face_pole(&joints[i - 1], &joints[i], &joints[i + 1], pole, 0.0);
+            }
+        }
+    }
+
+    Vector3::distance(&joints[count - 1], target)
+}
+
+/// The unit direction from one point to another, falling back to up when they coincide
+#[inline]
+fn direction(from: &Vector3, to: &Vector3) -> Vector3 {
+    let direction = (*to - *from).normalized();
+    if direction == Vector3::new() {
+        Vector3::up()
+    } else {
+        direction
+    }
+}
+
+/// Swings a joint around the line through its neighbors so it points at the pole, which keeps
+/// both of its bone lengths unless the joint is pushed out to `min_radius` from the line
+#[inline]
+fn face_pole(
+    prev: &Vector3,
+    joint: &Vector3,
+    next: &Vector3,
+    pole: &Vector3,
+    min_radius: f32,
+) -> Vector3 {
+    let axis = (*next - *prev).normalized();
+    if axis == Vector3::new() {
+        return *joint;
+    }
+
+    let offset = *joint - *prev;
+    let center = *prev + axis * Vector3::dot(&offset, &axis);
+    let radius = Vector3::distance(joint, &center).max(min_radius);
+
+    let to_pole = *pole - *prev;
+    let toward = (to_pole - axis * Vector3::dot(&to_pole, &axis)).normalized();
+    if toward == Vector3::new() {
+        return *joint;
+    }
+
+    center + toward * radius
+}
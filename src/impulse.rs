@@ -0,0 +1,106 @@
+use crate::vector2::Vector2;
+use crate::vector3::Vector3;
+
+/// Find the impulses that resolve a 2D collision with restitution and Coulomb friction
+///
+/// `v_rel` is the velocity of body b relative to body a at the contact point, and `normal` is the
+/// unit contact normal pointing from a to b. Returns the impulses for a and b, which change their
+/// velocities by `impulse * inv_mass`. Bodies that are already separating get zero impulses, and
+/// a zero inverse mass makes a body immovable.
+///
+/// # Examples
+/// ```
+/// use vex::Vector2;
+/// use vex::resolve_collision_2d;
+///
+/// // equal masses colliding head on swap velocities when perfectly elastic
+/// let (va, vb) = (Vector2::make(1.0, 0.0), Vector2::make(-1.0, 0.0));
+/// let normal = Vector2::make(1.0, 0.0);
+/// let (ja, jb) = resolve_collision_2d(&(vb - va), &normal, 1.0, 0.0, 1.0, 1.0);
+/// assert_eq!(va + ja, Vector2::make(-1.0, 0.0));
+/// assert_eq!(vb + jb, Vector2::make(1.0, 0.0));
+///
+/// // friction is limited by the normal impulse
+/// let (_, jb) = resolve_collision_2d(&Vector2::make(-1.0, 2.0), &normal, 0.0, 0.5, 1.0, 1.0);
+/// assert_eq!(jb, Vector2::make(0.5, -0.25));
+///
+/// // separating bodies are left alone
+/// let (_, jb) = resolve_collision_2d(&Vector2::make(1.0, 0.0), &normal, 1.0, 0.5, 1.0, 1.0);
+/// assert_eq!(jb, Vector2::new());
+/// ```
+pub fn resolve_collision_2d(
+    v_rel: &Vector2,
+    normal: &Vector2,
+    restitution: f32,
+    friction: f32,
+    inv_mass_a: f32,
+    inv_mass_b: f32,
+) -> (Vector2, Vector2) {
+    let inv_mass = inv_mass_a + inv_mass_b;
+    let normal_speed = Vector2::dot(v_rel, normal);
+    if normal_speed >= 0.0 || inv_mass <= 0.0 {
+        return (Vector2::new(), Vector2::new());
+    }
+
+    let j = -(1.0 + restitution) * normal_speed / inv_mass;
+    let mut impulse = *normal * j;
+
+    let tangent = *v_rel - *normal * normal_speed;
+    let tangent_speed = tangent.mag();
+    if tangent_speed > 0.0 {
+        let jt = (tangent_speed / inv_mass).min(friction * j);
+        impulse -= tangent * (jt / tangent_speed);
+    }
+
+    (-impulse, impulse)
+}
+
+/// Find the impulses that resolve a 3D collision with restitution and Coulomb friction
+///
+/// `v_rel` is the velocity of body b relative to body a at the contact point, and `normal` is the
+/// unit contact normal pointing from a to b. Returns the impulses for a and b, which change their
+/// velocities by `impulse * inv_mass`. Bodies that are already separating get zero impulses, and
+/// a zero inverse mass makes a body immovable.
+///
+/// # Examples
+/// ```
+/// use vex::Vector3;
+/// use vex::resolve_collision_3d;
+///
+/// // a ball dropped onto static ground (zero inverse mass) bounces with half its speed
+/// let v = Vector3::make(2.0, -4.0, 0.0);
+/// let normal = Vector3::make(0.0, -1.0, 0.0);
+/// let (ja, jb) = resolve_collision_3d(&(Vector3::new() - v), &normal, 0.5, 0.0, 1.0, 0.0);
+/// assert_eq!(v + ja, Vector3::make(2.0, 2.0, 0.0));
+/// assert_eq!(jb, Vector3::make(0.0, -6.0, 0.0));
+///
+/// // with enough friction the sliding stops as well
+/// let (ja, _) = resolve_collision_3d(&(Vector3::new() - v), &normal, 0.5, 1.0, 1.0, 0.0);
+/// assert_eq!(v + ja, Vector3::make(0.0, 2.0, 0.0));
+/// ```
+pub fn resolve_collision_3d(
+    v_rel: &Vector3,
+    normal: &Vector3,
+    restitution: f32,
+    friction: f32,
+    inv_mass_a: f32,
+    inv_mass_b: f32,
+) -> (Vector3, Vector3) {
+    let inv_mass = inv_mass_a + inv_mass_b;
+    let normal_speed = Vector3::dot(v_rel, normal);
+    if normal_speed >= 0.0 || inv_mass <= 0.0 {
+        return (Vector3::new(), Vector3::new());
+    }
+
+    let j = -(1.0 + restitution) * normal_speed / inv_mass;
+    let mut impulse = *normal * j;
+
+    let tangent = *v_rel - *normal * normal_speed;
+    let tangent_speed = tangent.mag();
+    if tangent_speed > 0.0 {
+        let jt = (tangent_speed / inv_mass).min(friction * j);
+        impulse -= tangent * (jt / tangent_speed);
+    }
+
+    (-impulse, impulse)
+}
@@ -0,0 +1,229 @@
+use crate::vector2::Vector2;
+
+/// Find the area of a simple polygon
+///
+/// The vertices can be wound either way, and the result is always positive. Polygons with fewer
+/// than 3 vertices have no area.
+///
+/// # Examples
+/// ```
+/// use vex::Vector2;
+/// use vex::polygon_area;
+///
+/// let square = [
+///     Vector2::make(0.0, 0.0),
+///     Vector2::make(2.0, 0.0),
+///     Vector2::make(2.0, 2.0),
+///     Vector2::make(0.0, 2.0),
+/// ];
+/// assert_eq!(polygon_area(&square), 4.0);
+///
+/// let clockwise = [square[3], square[2], square[1], square[0]];
+/// assert_eq!(polygon_area(&clockwise), 4.0);
+/// assert_eq!(polygon_area(&square[..2]), 0.0);
+/// ```
+pub fn polygon_area(points: &[Vector2]) -> f32 {
+    signed_area(points).abs()
+}
+
+/// Find the center of mass of a simple polygon with uniform density
+///
+/// Returns `None` if the polygon has no area.
+///
+/// # Examples
+/// ```
+/// use vex::Vector2;
+/// use vex::polygon_center_of_mass;
+///
+/// let triangle = [
+///     Vector2::make(0.0, 0.0),
+///     Vector2::make(3.0, 0.0),
+///     Vector2::make(0.0, 3.0),
+/// ];
+/// assert_eq!(polygon_center_of_mass(&triangle), Some(Vector2::make(1.0, 1.0)));
+///
+/// // the centroid of the vertices would be pulled towards the extra vertex
+/// let square = [
+///     Vector2::make(0.0, 0.0),
+///     Vector2::make(1.0, 0.0),
+///     Vector2::make(2.0, 0.0),
+///     Vector2::make(2.0, 2.0),
+///     Vector2::make(0.0, 2.0),
+/// ];
+/// assert_eq!(polygon_center_of_mass(&square), Some(Vector2::make(1.0, 1.0)));
+/// assert_eq!(polygon_center_of_mass(&square[..2]), None);
+/// ```
+pub fn polygon_center_of_mass(points: &[Vector2]) -> Option<Vector2> {
+    let area = signed_area(points);
+    if area == 0.0 {
+        return None;
+    }
+
+    // measure from the first vertex to keep large coordinates from losing precision
+    let origin = points[0];
+    let mut sum = Vector2::new();
+    for (a, b) in edges(points, &origin) {
+        sum += (a + b) * Vector2::cross(&a, &b);
+    }
+
+    Some(origin + sum / (6.0 * area))
+}
+
+/// Find the moment of inertia of a simple polygon with uniform density about its center of mass
+///
+/// Returns 0 if the polygon has no area.
+///
+/// # Examples
+/// ```
+/// use vex::Vector2;
+/// use vex::box_inertia;
+/// use vex::polygon_inertia;
+///
+/// let rect = [
+///     Vector2::make(10.0, 10.0),
+///     Vector2::make(14.0, 10.0),
+///     Vector2::make(14.0, 12.0),
+///     Vector2::make(10.0, 12.0),
+/// ];
+/// let actual = polygon_inertia(&rect, 3.0);
+/// assert!((actual - box_inertia(4.0, 2.0, 3.0)).abs() < 0.0001);
+/// assert_eq!(polygon_inertia(&rect[..2], 3.0), 0.0);
+/// ```
+pub fn polygon_inertia(points: &[Vector2], mass: f32) -> f32 {
+    let area = signed_area(points);
+    if area == 0.0 {
+        return 0.0;
+    }
+
+    let origin = points[0];
+    let mut centroid = Vector2::new();
+    let mut second_moment = 0.0;
+    for (a, b) in edges(points, &origin) {
+        let cross = Vector2::cross(&a, &b);
+        centroid += (a + b) * cross;
+        let spread = Vector2::dot(&a, &a) + Vector2::dot(&a, &b) + Vector2::dot(&b, &b);
+        second_moment += cross * spread;
+    }
+
+    // the sums are about the first vertex, so shift them to the center of mass
+    let centroid = centroid / (6.0 * area);
+    mass * (second_moment / (12.0 * area) - centroid.mag_sq())
+}
+
+/// Find the moment of inertia of a solid disc about its center
+///
+/// # Examples
+/// ```
+/// use vex::circle_inertia;
+///
+/// assert_eq!(circle_inertia(2.0, 3.0), 6.0);
+/// ```
+#[inline]
+pub fn circle_inertia(radius: f32, mass: f32) -> f32 {
+    0.5 * mass * radius * radius
+}
+
+/// Find the moment of inertia of a ring, such as a wheel with a hub cut out, about its center
+///
+/// An `inner_radius` of 0 is a solid disc, and an `inner_radius` equal to `outer_radius` is a thin
+/// hoop.
+///
+/// # Examples
+/// ```
+/// use vex::circle_inertia;
+/// use vex::ring_inertia;
+///
+/// assert_eq!(ring_inertia(0.0, 2.0, 3.0), circle_inertia(2.0, 3.0));
+/// assert_eq!(ring_inertia(2.0, 2.0, 3.0), 12.0);
+/// assert_eq!(ring_inertia(1.0, 3.0, 2.0), 10.0);
+/// ```
+#[inline]
+pub fn ring_inertia(inner_radius: f32, outer_radius: f32, mass: f32) -> f32 {
+    0.5 * mass * (inner_radius * inner_radius + outer_radius * outer_radius)
+}
+
+/// Find the moment of inertia of a solid box about its center
+///
+/// # Examples
+/// ```
+/// use vex::box_inertia;
+///
+/// assert_eq!(box_inertia(4.0, 2.0, 3.0), 5.0);
+/// ```
+#[inline]
+pub fn box_inertia(width: f32, height: f32, mass: f32) -> f32 {
+    mass * (width * width + height * height) / 12.0
+}
+
+/// Moves a moment of inertia from a body's center of mass to a parallel axis `offset` away
+///
+/// # Examples
+/// ```
+/// use vex::Vector2;
+/// use vex::circle_inertia;
+/// use vex::parallel_axis;
+///
+/// // a disc rolling on its edge pivots about the contact point
+/// let actual = parallel_axis(circle_inertia(2.0, 3.0), 3.0, &Vector2::make(0.0, 2.0));
+/// assert_eq!(actual, 18.0);
+/// ```
+#[inline]
+pub fn parallel_axis(inertia: f32, mass: f32, offset: &Vector2) -> f32 {
+    inertia + mass * offset.mag_sq()
+}
+
+/// Find the center of mass of a set of point masses
+///
+/// Returns `None` if the total mass is 0.
+///
+/// # Examples
+/// ```
+/// use vex::Vector2;
+/// use vex::center_of_mass;
+///
+/// let bodies = [
+///     (Vector2::make(0.0, 0.0), 3.0),
+///     (Vector2::make(4.0, 0.0), 1.0),
+///     (Vector2::make(0.0, 8.0), 0.0),
+/// ];
+/// assert_eq!(center_of_mass(&bodies), Some(Vector2::make(1.0, 0.0)));
+/// assert_eq!(center_of_mass(&[]), None);
+/// ```
+pub fn center_of_mass(bodies: &[(Vector2, f32)]) -> Option<Vector2> {
+    let mut total = 0.0;
+    let mut sum = Vector2::new();
+    for (position, mass) in bodies.iter() {
+        total += mass;
+        sum += *position * *mass;
+    }
+
+    if total == 0.0 {
+        None
+    } else {
+        Some(sum / total)
+    }
+}
+
+/// Signed area of the polygon, positive when it is wound counter-clockwise
+#[inline]
+fn signed_area(points: &[Vector2]) -> f32 {
+    if points.len() < 3 {
+        return 0.0;
+    }
+
+    let origin = points[0];
+    let twice_area: f32 = edges(points, &origin).map(|(a, b)| Vector2::cross(&a, &b)).sum();
+    twice_area / 2.0
+}
+
+/// Each edge of the polygon relative to `origin`, including the closing edge
+#[inline]
+fn edges<'a>(
+    points: &'a [Vector2],
+    origin: &'a Vector2,
+) -> impl Iterator<Item = (Vector2, Vector2)> + 'a {
+    points
+        .iter()
+        .zip(points.iter().cycle().skip(1))
+        .map(move |(a, b)| (*a - *origin, *b - *origin))
+}
@@ -0,0 +1,307 @@
+//! Generic access to the components of math types, for editor UIs
+//!
+//! Components are read and written as `f64`, which holds every `f32`, `i32`, and `u32` exactly,
+//! so a property panel can edit any type through `&mut dyn Inspect` without losing precision.
+//! The `egui` feature adds [`DragComponents`](struct.DragComponents.html), a widget that draws a
+//! drag value for each component.
+
+#[cfg(not(feature = "std"))]
+use crate::math::Float;
+use crate::ivector2::IVector2;
+use crate::ivector3::IVector3;
+use crate::matrix2::Matrix2;
+use crate::matrix3::Matrix3;
+use crate::matrix4::Matrix4;
+use crate::quaternion::Quaternion;
+use crate::uvector2::UVector2;
+use crate::uvector3::UVector3;
+use crate::vector2::Vector2;
+use crate::vector3::Vector3;
+use crate::vector4::Vector4;
+
+/// The range of values an editor should allow for a component
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Hint {
+    pub min: f64,
+    pub max: f64,
+}
+
+impl Hint {
+    /// Allows any value
+    pub const UNBOUNDED: Hint = Hint {
+        min: f64::NEG_INFINITY,
+        max: f64::INFINITY,
+    };
+
+    /// Creates a hint from the provided bounds
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Hint;
+    ///
+    /// let actual = Hint::make(-1.0, 1.0);
+    /// assert_eq!(actual.min, -1.0);
+    /// assert_eq!(actual.max, 1.0);
+    /// ```
+    #[inline]
+    pub const fn make(min: f64, max: f64) -> Hint {
+        Hint { min, max }
+    }
+}
+
+impl Default for Hint {
+    /// Gets a hint that allows any value
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Hint;
+    ///
+    /// assert_eq!(Hint::default(), Hint::UNBOUNDED);
+    /// ```
+    #[inline]
+    fn default() -> Hint {
+        Hint::UNBOUNDED
+    }
+}
+
+/// Reads and writes a value's components by index, with names and range hints for each
+///
+/// Components are indexed in the order the value stores them, so matrices list their elements
+/// column by column. Reading or writing past the last component panics.
+///
+/// # Examples
+/// ```
+/// use vex::Inspect;
+/// use vex::Vector3;
+///
+/// fn describe(value: &dyn Inspect) -> String {
+///     let fields = value.fields();
+///     let parts: Vec<String> = (0..fields.len())
+///         .map(|i| format!("{}={}", fields[i], value.component(i)))
+///         .collect();
+///     parts.join(" ")
+/// }
+///
+/// assert_eq!(describe(&Vector3::make(1.0, 2.0, 3.0)), "x=1 y=2 z=3");
+/// ```
+pub trait Inspect {
+    /// Gets the name of each component, in index order
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Inspect;
+    /// use vex::Matrix2;
+    /// use vex::Vector2;
+    ///
+    /// assert_eq!(Vector2::new().fields(), ["x", "y"]);
+    /// assert_eq!(Matrix2::new().fields(), ["m11", "m21", "m12", "m22"]);
+    /// ```
+    fn fields(&self) -> &'static [&'static str];
+
+    /// Gets a component as an `f64`
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Inspect;
+    /// use vex::Matrix3;
+    ///
+    /// let m = Matrix3::make(1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0);
+    /// assert_eq!(m.component(3), 4.0);
+    /// assert_eq!(m.component(3), m.m12() as f64);
+    /// ```
+    fn component(&self, index: usize) -> f64;
+
+    /// Sets a component from an `f64`, rounding and saturating it for integer types
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::IVector2;
+    /// use vex::Inspect;
+    /// use vex::UVector2;
+    ///
+    /// let mut v = IVector2::new();
+    /// v.set_component(0, 2.6);
+    /// v.set_component(1, -1.0e12);
+    /// assert_eq!(v, IVector2::make(3, i32::MIN));
+    ///
+    /// let mut v = UVector2::new();
+    /// v.set_component(1, -4.0);
+    /// assert_eq!(v, UVector2::make(0, 0));
+    /// ```
+    fn set_component(&mut self, index: usize, value: f64);
+
+    /// Gets the range of values an editor should allow for a component
+    ///
+    /// Float vectors and matrices are unbounded, integer vectors span their component type, and
+    /// quaternion components stay within `[-1, 1]`.
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Hint;
+    /// use vex::Inspect;
+    /// use vex::Quaternion;
+    /// use vex::UVector3;
+    /// use vex::Vector3;
+    ///
+    /// assert_eq!(Vector3::new().hint(0), Hint::UNBOUNDED);
+    /// assert_eq!(Quaternion::new().hint(3), Hint::make(-1.0, 1.0));
+    /// assert_eq!(UVector3::new().hint(2), Hint::make(0.0, u32::MAX as f64));
+    /// ```
+    #[inline]
+    fn hint(&self, _index: usize) -> Hint {
+        Hint::UNBOUNDED
+    }
+}
+
+// implements `Inspect` for a type with named fields of a primitive type
+macro_rules! inspect_fields {
+    ($t:ident, $c:ident, [$($field:ident),+]) => {
+        impl Inspect for $t {
+            #[inline]
+            fn fields(&self) -> &'static [&'static str] {
+                &[$(stringify!($field)),+]
+            }
+
+            #[inline]
+            fn component(&self, index: usize) -> f64 {
+                let components = [$(self.$field),+];
+                components[index] as f64
+            }
+
+            #[inline]
+            fn set_component(&mut self, index: usize, value: f64) {
+                let components = [$(&mut self.$field),+];
+                *components[index] = inspect_fields!(@cast $c, value);
+            }
+
+            #[inline]
+            fn hint(&self, _index: usize) -> Hint {
+                inspect_fields!(@hint $t, $c)
+            }
+        }
+    };
+    (@cast f32, $value:expr) => { $value as f32 };
+    (@cast $c:ident, $value:expr) => { $value.round() as $c };
+    (@hint Quaternion, $c:ident) => { Hint::make(-1.0, 1.0) };
+    (@hint $t:ident, f32) => { Hint::UNBOUNDED };
+    (@hint $t:ident, $c:ident) => { Hint::make($c::MIN as f64, $c::MAX as f64) };
+}
+
+inspect_fields!(Vector2, f32, [x, y]);
+inspect_fields!(Vector3, f32, [x, y, z]);
+inspect_fields!(Vector4, f32, [x, y, z, w]);
+inspect_fields!(Quaternion, f32, [x, y, z, w]);
+inspect_fields!(IVector2, i32, [x, y]);
+inspect_fields!(IVector3, i32, [x, y, z]);
+inspect_fields!(UVector2, u32, [x, y]);
+inspect_fields!(UVector3, u32, [x, y, z]);
+
+// implements `Inspect` for a matrix, naming its elements in column-major order
+macro_rules! inspect_matrix {
+    ($t:ident, [$($field:literal),+]) => {
+        impl Inspect for $t {
+            #[inline]
+            fn fields(&self) -> &'static [&'static str] {
+                &[$($field),+]
+            }
+
+            #[inline]
+            fn component(&self, index: usize) -> f64 {
+                self.m[index] as f64
+            }
+
+            #[inline]
+            fn set_component(&mut self, index: usize, value: f64) {
+                self.m[index] = value as f32;
+            }
+        }
+    };
+}
+
+inspect_matrix!(Matrix2, ["m11", "m21", "m12", "m22"]);
+inspect_matrix!(Matrix3, ["m11", "m21", "m31", "m12", "m22", "m32", "m13", "m23", "m33"]);
+inspect_matrix!(
+    Matrix4,
+    [
+        "m11", "m21", "m31", "m41", "m12", "m22", "m32", "m42", "m13", "m23", "m33", "m43",
+        "m14", "m24", "m34", "m44"
+    ]
+);
+
+/// An `egui` widget that draws a drag value for each component of an inspectable value
+///
+/// The components are laid out in a row that wraps when it runs out of room, each labeled with
+/// its field name and clamped to its hint. The response covers the whole row, and reports a
+/// change when any component changed.
+///
+/// # Examples
+/// ```
+/// extern crate egui;
+///
+/// use vex::DragComponents;
+/// use vex::Vector3;
+///
+/// let mut position = Vector3::make(1.0, 2.0, 3.0);
+/// let ctx = egui::Context::default();
+/// let _ = ctx.run_ui(Default::default(), |ui| {
+///     let response = ui.add(DragComponents::new(&mut position));
+///     assert!(!response.changed());
+/// });
+/// ```
+#[cfg(feature = "egui")]
+pub struct DragComponents<'a> {
+    value: &'a mut dyn Inspect,
+}
+
+#[cfg(feature = "egui")]
+impl<'a> DragComponents<'a> {
+    /// Creates a widget that edits the provided value
+    ///
+    /// # Examples
+    /// ```
+    /// extern crate egui;
+    ///
+    /// use vex::DragComponents;
+    /// use vex::Matrix4;
+    ///
+    /// let mut transform = Matrix4::IDENTITY;
+    /// let ctx = egui::Context::default();
+    /// let _ = ctx.run_ui(Default::default(), |ui| {
+    ///     ui.add(DragComponents::new(&mut transform));
+    /// });
+    /// ```
+    #[inline]
+    pub fn new(value: &'a mut dyn Inspect) -> DragComponents<'a> {
+        DragComponents { value }
+    }
+}
+
+#[cfg(feature = "egui")]
+impl egui::Widget for DragComponents<'_> {
+    fn ui(self, ui: &mut egui::Ui) -> egui::Response {
+        let value = self.value;
+        let mut changed = false;
+        let mut response = ui
+            .horizontal_wrapped(|ui| {
+                for (i, field) in value.fields().iter().enumerate() {
+                    let hint = value.hint(i);
+                    let mut x = value.component(i);
+                    let drag = egui::DragValue::new(&mut x)
+                        .range(hint.min..=hint.max)
+                        .prefix(format!("{}: ", field));
+
+                    if ui.add(drag).changed() {
+                        value.set_component(i, x);
+                        changed = true;
+                    }
+                }
+            })
+            .response;
+
+        if changed {
+            response.mark_changed();
+        }
+
+        response
+    }
+}
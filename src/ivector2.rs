@@ -0,0 +1,706 @@
+use crate::format;
+#[cfg(not(feature = "std"))]
+use crate::math::Float;
+use crate::vector2::Vector2;
+
+use core::fmt;
+use core::fmt::{Display, Formatter};
+
+use core::ops::{
+    Add,
+    AddAssign,
+    Div,
+    DivAssign,
+    Index,
+    IndexMut,
+    Mul,
+    MulAssign,
+    Neg,
+    Sub,
+    SubAssign,
+};
+
+#[repr(C)]
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct IVector2 {
+    pub x: i32,
+    pub y: i32,
+}
+
+impl IVector2 {
+    /// The vector <0, 0>
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::IVector2;
+    ///
+    /// assert_eq!(IVector2::ZERO, IVector2::new());
+    /// ```
+    pub const ZERO: IVector2 = IVector2 { x: 0, y: 0 };
+
+    /// The vector <1, 1>
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::IVector2;
+    ///
+    /// assert_eq!(IVector2::ONE, IVector2::make(1, 1));
+    /// ```
+    pub const ONE: IVector2 = IVector2 { x: 1, y: 1 };
+
+    /// A unit vector <1, 0> along the x-axis
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::IVector2;
+    ///
+    /// assert_eq!(IVector2::X, IVector2::make(1, 0));
+    /// ```
+    pub const X: IVector2 = IVector2 { x: 1, y: 0 };
+
+    /// A unit vector <0, 1> along the y-axis
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::IVector2;
+    ///
+    /// assert_eq!(IVector2::Y, IVector2::make(0, 1));
+    /// ```
+    pub const Y: IVector2 = IVector2 { x: 0, y: 1 };
+
+    /// Creates a vector <0, 0>
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::IVector2;
+    ///
+    /// let actual = IVector2::new();
+    /// let expected = IVector2 { x: 0, y: 0 };
+    /// assert_eq!(actual, expected);
+    /// ```
+    #[inline]
+    pub const fn new() -> IVector2 {
+        IVector2 { x: 0, y: 0 }
+    }
+
+    /// Creates a vector from the provided values
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::IVector2;
+    ///
+    /// let actual = IVector2::make(1, 2);
+    /// let expected = IVector2 { x: 1, y: 2 };
+    /// assert_eq!(actual, expected);
+    /// ```
+    #[inline]
+    pub const fn make(x: i32, y: i32) -> IVector2 {
+        IVector2 { x, y }
+    }
+
+    /// Find the dot product between two vectors
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::IVector2;
+    ///
+    /// let a = IVector2::make(1, 2);
+    /// let b = IVector2::make(3, 5);
+    /// assert_eq!(IVector2::dot(&a, &b), 13);
+    /// ```
+    #[inline]
+    pub fn dot(a: &IVector2, b: &IVector2) -> i32 {
+        a.x * b.x + a.y * b.y
+    }
+
+    /// Find the minimum (smallest) set of values
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::IVector2;
+    ///
+    /// let a = IVector2::make(1, 2);
+    /// let b = IVector2::make(4, -2);
+    /// assert_eq!(IVector2::min(&a, &b), IVector2::make(1, -2));
+    /// ```
+    #[inline]
+    pub fn min(a: &IVector2, b: &IVector2) -> IVector2 {
+        IVector2::make(a.x.min(b.x), a.y.min(b.y))
+    }
+
+    /// Find the maximum (largest) set of values
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::IVector2;
+    ///
+    /// let a = IVector2::make(1, 2);
+    /// let b = IVector2::make(4, -2);
+    /// assert_eq!(IVector2::max(&a, &b), IVector2::make(4, 2));
+    /// ```
+    #[inline]
+    pub fn max(a: &IVector2, b: &IVector2) -> IVector2 {
+        IVector2::make(a.x.max(b.x), a.y.max(b.y))
+    }
+
+    /// Find the vector with each component clamped between two vectors
+    ///
+    /// Panics if any component of `a` is greater than the same component of `b`.
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::IVector2;
+    ///
+    /// let a = IVector2::make(0, 0);
+    /// let b = IVector2::make(2, 2);
+    /// let actual = IVector2::make(-5, 1).clamped(&a, &b);
+    /// assert_eq!(actual, IVector2::make(0, 1));
+    /// ```
+    #[inline]
+    pub fn clamped(&self, a: &IVector2, b: &IVector2) -> IVector2 {
+        IVector2::make(
+            self.x.clamp(a.x, b.x),
+            self.y.clamp(a.y, b.y),
+        )
+    }
+
+    /// Find the vector with the absolute value of each component
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::IVector2;
+    ///
+    /// let actual = IVector2::make(-1, 2).abs_value();
+    /// assert_eq!(actual, IVector2::make(1, 2));
+    /// ```
+    #[inline]
+    pub fn abs_value(&self) -> IVector2 {
+        IVector2::make(self.x.abs(), self.y.abs())
+    }
+
+    /// Gets the smallest component
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::IVector2;
+    ///
+    /// assert_eq!(IVector2::make(3, 5).min_element(), 3);
+    /// ```
+    #[inline]
+    pub fn min_element(&self) -> i32 {
+        self.x.min(self.y)
+    }
+
+    /// Gets the largest component
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::IVector2;
+    ///
+    /// assert_eq!(IVector2::make(3, 5).max_element(), 5);
+    /// ```
+    #[inline]
+    pub fn max_element(&self) -> i32 {
+        self.x.max(self.y)
+    }
+
+    /// Gets the sum of the components
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::IVector2;
+    ///
+    /// assert_eq!(IVector2::make(3, 5).sum(), 8);
+    /// ```
+    #[inline]
+    pub fn sum(&self) -> i32 {
+        self.x + self.y
+    }
+
+    /// Gets the product of the components
+    ///
+    /// Useful for counting the cells in a grid or the invocations in a dispatch of this size.
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::IVector2;
+    ///
+    /// assert_eq!(IVector2::make(3, 5).product(), 15);
+    /// ```
+    #[inline]
+    pub fn product(&self) -> i32 {
+        self.x * self.y
+    }
+
+    /// Creates a vector from a floating point vector with each component rounded down
+    ///
+    /// Components outside the range of `i32` saturate to its limits, and NaN becomes 0.
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::IVector2;
+    /// use vex::Vector2;
+    ///
+    /// let actual = IVector2::floor(&Vector2::make(1.5, -0.5));
+    /// assert_eq!(actual, IVector2::make(1, -1));
+    /// ```
+    #[inline]
+    pub fn floor(v: &Vector2) -> IVector2 {
+        IVector2::make(v.x.floor() as i32, v.y.floor() as i32)
+    }
+
+    /// Creates a vector from a floating point vector with each component rounded up
+    ///
+    /// Components outside the range of `i32` saturate to its limits, and NaN becomes 0.
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::IVector2;
+    /// use vex::Vector2;
+    ///
+    /// let actual = IVector2::ceil(&Vector2::make(1.5, -0.5));
+    /// assert_eq!(actual, IVector2::make(2, 0));
+    /// ```
+    #[inline]
+    pub fn ceil(v: &Vector2) -> IVector2 {
+        IVector2::make(v.x.ceil() as i32, v.y.ceil() as i32)
+    }
+
+    /// Creates a vector from a floating point vector with each component rounded to the nearest integer, with halves rounded away from zero
+    ///
+    /// Components outside the range of `i32` saturate to its limits, and NaN becomes 0.
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::IVector2;
+    /// use vex::Vector2;
+    ///
+    /// let actual = IVector2::round(&Vector2::make(1.5, -0.5));
+    /// assert_eq!(actual, IVector2::make(2, -1));
+    /// ```
+    #[inline]
+    pub fn round(v: &Vector2) -> IVector2 {
+        IVector2::make(v.x.round() as i32, v.y.round() as i32)
+    }
+
+    /// Converts the vector to a floating point vector
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::IVector2;
+    /// use vex::Vector2;
+    ///
+    /// let actual = IVector2::make(1, 2).as_f32();
+    /// assert_eq!(actual, Vector2::make(1.0, 2.0));
+    /// ```
+    #[inline]
+    pub fn as_f32(&self) -> Vector2 {
+        Vector2::make(self.x as f32, self.y as f32)
+    }
+}
+
+impl From<[i32; 2]> for IVector2 {
+    /// Creates a vector from an array of its components
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::IVector2;
+    ///
+    /// let actual = IVector2::from([1, 2]);
+    /// assert_eq!(actual, IVector2::make(1, 2));
+    /// ```
+    #[inline]
+    fn from(values: [i32; 2]) -> IVector2 {
+        IVector2::make(values[0], values[1])
+    }
+}
+
+impl From<IVector2> for [i32; 2] {
+    /// Creates an array from the components of a vector
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::IVector2;
+    ///
+    /// let actual: [i32; 2] = IVector2::make(1, 2).into();
+    /// assert_eq!(actual, [1, 2]);
+    /// ```
+    #[inline]
+    fn from(v: IVector2) -> [i32; 2] {
+        [v.x, v.y]
+    }
+}
+
+impl Index<u32> for IVector2 {
+    type Output = i32;
+
+    /// Looks up a component by index
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::IVector2;
+    ///
+    /// let v = IVector2::make(1, 2);
+    /// assert_eq!(v[1], 2);
+    /// ```
+    #[inline]
+    fn index(&self, index: u32) -> &i32 {
+        match index {
+            0 => &self.x,
+            1 => &self.y,
+            _ => panic!("Invalid index for IVector2: {}", index),
+        }
+    }
+}
+
+impl IndexMut<u32> for IVector2 {
+    /// Mutate a component by index
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::IVector2;
+    ///
+    /// let mut v = IVector2::make(1, 2);
+    /// v[1] = 8;
+    /// assert_eq!(v[1], 8);
+    /// ```
+    #[inline]
+    fn index_mut(&mut self, index: u32) -> &mut i32 {
+        match index {
+            0 => &mut self.x,
+            1 => &mut self.y,
+            _ => panic!("Invalid index for IVector2: {}", index),
+        }
+    }
+}
+
+impl Neg for IVector2 {
+    type Output = IVector2;
+
+    /// Negates all components in a vector
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::IVector2;
+    ///
+    /// let actual = -IVector2::make(1, 2);
+    /// assert_eq!(actual, IVector2::make(-1, -2));
+    /// ```
+    #[inline]
+    fn neg(self) -> IVector2 {
+        IVector2::make(-self.x, -self.y)
+    }
+}
+
+impl Add<i32> for IVector2 {
+    type Output = IVector2;
+
+    /// Add a scalar to a vector
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::IVector2;
+    ///
+    /// let actual = IVector2::make(1, 2) + 2;
+    /// assert_eq!(actual, IVector2::make(3, 4));
+    /// ```
+    #[inline]
+    fn add(self, _rhs: i32) -> IVector2 {
+        IVector2::make(self.x + _rhs, self.y + _rhs)
+    }
+}
+
+impl AddAssign<i32> for IVector2 {
+    /// Increment a vector by a scalar
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::IVector2;
+    ///
+    /// let mut actual = IVector2::make(1, 2);
+    /// actual += 2;
+    /// assert_eq!(actual, IVector2::make(3, 4));
+    /// ```
+    #[inline]
+    fn add_assign(&mut self, _rhs: i32) {
+        self.x += _rhs;
+        self.y += _rhs;
+    }
+}
+
+impl Add<IVector2> for IVector2 {
+    type Output = IVector2;
+
+    /// Add two vectors
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::IVector2;
+    ///
+    /// let actual = IVector2::make(1, 2) + IVector2::make(3, 5);
+    /// assert_eq!(actual, IVector2::make(4, 7));
+    /// ```
+    #[inline]
+    fn add(self, _rhs: IVector2) -> IVector2 {
+        IVector2::make(self.x + _rhs.x, self.y + _rhs.y)
+    }
+}
+
+impl AddAssign<IVector2> for IVector2 {
+    /// Increment a vector by another vector
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::IVector2;
+    ///
+    /// let mut actual = IVector2::make(1, 2);
+    /// actual += IVector2::make(3, 5);
+    /// assert_eq!(actual, IVector2::make(4, 7));
+    /// ```
+    #[inline]
+    fn add_assign(&mut self, _rhs: IVector2) {
+        self.x += _rhs.x;
+        self.y += _rhs.y;
+    }
+}
+
+impl Sub<i32> for IVector2 {
+    type Output = IVector2;
+
+    /// Subtract a scalar from a vector
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::IVector2;
+    ///
+    /// let actual = IVector2::make(1, 2) - 2;
+    /// assert_eq!(actual, IVector2::make(-1, 0));
+    /// ```
+    #[inline]
+    fn sub(self, _rhs: i32) -> IVector2 {
+        IVector2::make(self.x - _rhs, self.y - _rhs)
+    }
+}
+
+impl SubAssign<i32> for IVector2 {
+    /// Decrement a vector by a scalar
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::IVector2;
+    ///
+    /// let mut actual = IVector2::make(1, 2);
+    /// actual -= 2;
+    /// assert_eq!(actual, IVector2::make(-1, 0));
+    /// ```
+    #[inline]
+    fn sub_assign(&mut self, _rhs: i32) {
+        self.x -= _rhs;
+        self.y -= _rhs;
+    }
+}
+
+impl Sub<IVector2> for IVector2 {
+    type Output = IVector2;
+
+    /// Subtract two vectors
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::IVector2;
+    ///
+    /// let actual = IVector2::make(1, 2) - IVector2::make(3, 5);
+    /// assert_eq!(actual, IVector2::make(-2, -3));
+    /// ```
+    #[inline]
+    fn sub(self, _rhs: IVector2) -> IVector2 {
+        IVector2::make(self.x - _rhs.x, self.y - _rhs.y)
+    }
+}
+
+impl SubAssign<IVector2> for IVector2 {
+    /// Decrement a vector by another vector
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::IVector2;
+    ///
+    /// let mut actual = IVector2::make(1, 2);
+    /// actual -= IVector2::make(3, 5);
+    /// assert_eq!(actual, IVector2::make(-2, -3));
+    /// ```
+    #[inline]
+    fn sub_assign(&mut self, _rhs: IVector2) {
+        self.x -= _rhs.x;
+        self.y -= _rhs.y;
+    }
+}
+
+impl Mul<i32> for IVector2 {
+    type Output = IVector2;
+
+    /// Multiply a vector by a scalar
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::IVector2;
+    ///
+    /// let actual = IVector2::make(1, 2) * 2;
+    /// assert_eq!(actual, IVector2::make(2, 4));
+    /// ```
+    #[inline]
+    fn mul(self, _rhs: i32) -> IVector2 {
+        IVector2::make(self.x * _rhs, self.y * _rhs)
+    }
+}
+
+impl MulAssign<i32> for IVector2 {
+    /// Multiply a vector by a scalar
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::IVector2;
+    ///
+    /// let mut actual = IVector2::make(1, 2);
+    /// actual *= 2;
+    /// assert_eq!(actual, IVector2::make(2, 4));
+    /// ```
+    #[inline]
+    fn mul_assign(&mut self, _rhs: i32) {
+        self.x *= _rhs;
+        self.y *= _rhs;
+    }
+}
+
+impl Mul<IVector2> for IVector2 {
+    type Output = IVector2;
+
+    /// Multiply two vectors component-wise
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::IVector2;
+    ///
+    /// let actual = IVector2::make(1, 2) * IVector2::make(3, 5);
+    /// assert_eq!(actual, IVector2::make(3, 10));
+    /// ```
+    #[inline]
+    fn mul(self, _rhs: IVector2) -> IVector2 {
+        IVector2::make(self.x * _rhs.x, self.y * _rhs.y)
+    }
+}
+
+impl MulAssign<IVector2> for IVector2 {
+    /// Multiply a vector by another vector component-wise
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::IVector2;
+    ///
+    /// let mut actual = IVector2::make(1, 2);
+    /// actual *= IVector2::make(3, 5);
+    /// assert_eq!(actual, IVector2::make(3, 10));
+    /// ```
+    #[inline]
+    fn mul_assign(&mut self, _rhs: IVector2) {
+        self.x *= _rhs.x;
+        self.y *= _rhs.y;
+    }
+}
+
+impl Div<i32> for IVector2 {
+    type Output = IVector2;
+
+    /// Divide a vector by a scalar
+    ///
+    /// Division by zero panics, and division rounds towards zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::IVector2;
+    ///
+    /// let actual = IVector2::make(6, 10) / 2;
+    /// assert_eq!(actual, IVector2::make(3, 5));
+    /// ```
+    #[inline]
+    fn div(self, _rhs: i32) -> IVector2 {
+        IVector2::make(self.x / _rhs, self.y / _rhs)
+    }
+}
+
+impl DivAssign<i32> for IVector2 {
+    /// Divide a vector by a scalar
+    ///
+    /// Division by zero panics, and division rounds towards zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::IVector2;
+    ///
+    /// let mut actual = IVector2::make(6, 10);
+    /// actual /= 2;
+    /// assert_eq!(actual, IVector2::make(3, 5));
+    /// ```
+    #[inline]
+    fn div_assign(&mut self, _rhs: i32) {
+        self.x /= _rhs;
+        self.y /= _rhs;
+    }
+}
+
+impl Div<IVector2> for IVector2 {
+    type Output = IVector2;
+
+    /// Divide two vectors component-wise
+    ///
+    /// Division by zero panics, and division rounds towards zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::IVector2;
+    ///
+    /// let actual = IVector2::make(6, 10) / IVector2::make(3, 5);
+    /// assert_eq!(actual, IVector2::make(2, 2));
+    /// ```
+    #[inline]
+    fn div(self, _rhs: IVector2) -> IVector2 {
+        IVector2::make(self.x / _rhs.x, self.y / _rhs.y)
+    }
+}
+
+impl DivAssign<IVector2> for IVector2 {
+    /// Divide a vector by another vector component-wise
+    ///
+    /// Division by zero panics, and division rounds towards zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::IVector2;
+    ///
+    /// let mut actual = IVector2::make(6, 10);
+    /// actual /= IVector2::make(3, 5);
+    /// assert_eq!(actual, IVector2::make(2, 2));
+    /// ```
+    #[inline]
+    fn div_assign(&mut self, _rhs: IVector2) {
+        self.x /= _rhs.x;
+        self.y /= _rhs.y;
+    }
+}
+
+impl Display for IVector2 {
+    /// Formats as `<x, y>`, with the formatter's flags applied to each component
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::IVector2;
+    ///
+    /// let v = IVector2::make(-1, 20);
+    /// assert_eq!(format!("{}", v), "<-1, 20>");
+    /// assert_eq!(format!("{:3}", v), "< -1,  20>");
+    /// ```
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        format::write_vector(f, &[self.x, self.y])
+    }
+}
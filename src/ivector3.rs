@@ -0,0 +1,728 @@
+use crate::format;
+#[cfg(not(feature = "std"))]
+use crate::math::Float;
+use crate::vector3::Vector3;
+
+use core::fmt;
+use core::fmt::{Display, Formatter};
+
+use core::ops::{
+    Add,
+    AddAssign,
+    Div,
+    DivAssign,
+    Index,
+    IndexMut,
+    Mul,
+    MulAssign,
+    Neg,
+    Sub,
+    SubAssign,
+};
+
+#[repr(C)]
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct IVector3 {
+    pub x: i32,
+    pub y: i32,
+    pub z: i32,
+}
+
+impl IVector3 {
+    /// The vector <0, 0, 0>
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::IVector3;
+    ///
+    /// assert_eq!(IVector3::ZERO, IVector3::new());
+    /// ```
+    pub const ZERO: IVector3 = IVector3 { x: 0, y: 0, z: 0 };
+
+    /// The vector <1, 1, 1>
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::IVector3;
+    ///
+    /// assert_eq!(IVector3::ONE, IVector3::make(1, 1, 1));
+    /// ```
+    pub const ONE: IVector3 = IVector3 { x: 1, y: 1, z: 1 };
+
+    /// A unit vector <1, 0, 0> along the x-axis
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::IVector3;
+    ///
+    /// assert_eq!(IVector3::X, IVector3::make(1, 0, 0));
+    /// ```
+    pub const X: IVector3 = IVector3 { x: 1, y: 0, z: 0 };
+
+    /// A unit vector <0, 1, 0> along the y-axis
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::IVector3;
+    ///
+    /// assert_eq!(IVector3::Y, IVector3::make(0, 1, 0));
+    /// ```
+    pub const Y: IVector3 = IVector3 { x: 0, y: 1, z: 0 };
+
+    /// A unit vector <0, 0, 1> along the z-axis
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::IVector3;
+    ///
+    /// assert_eq!(IVector3::Z, IVector3::make(0, 0, 1));
+    /// ```
+    pub const Z: IVector3 = IVector3 { x: 0, y: 0, z: 1 };
+
+    /// Creates a vector <0, 0, 0>
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::IVector3;
+    ///
+    /// let actual = IVector3::new();
+    /// let expected = IVector3 { x: 0, y: 0, z: 0 };
+    /// assert_eq!(actual, expected);
+    /// ```
+    #[inline]
+    pub const fn new() -> IVector3 {
+        IVector3 { x: 0, y: 0, z: 0 }
+    }
+
+    /// Creates a vector from the provided values
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::IVector3;
+    ///
+    /// let actual = IVector3::make(1, 2, 3);
+    /// let expected = IVector3 { x: 1, y: 2, z: 3 };
+    /// assert_eq!(actual, expected);
+    /// ```
+    #[inline]
+    pub const fn make(x: i32, y: i32, z: i32) -> IVector3 {
+        IVector3 { x, y, z }
+    }
+
+    /// Find the dot product between two vectors
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::IVector3;
+    ///
+    /// let a = IVector3::make(1, 2, 3);
+    /// let b = IVector3::make(3, 5, 4);
+    /// assert_eq!(IVector3::dot(&a, &b), 25);
+    /// ```
+    #[inline]
+    pub fn dot(a: &IVector3, b: &IVector3) -> i32 {
+        a.x * b.x + a.y * b.y + a.z * b.z
+    }
+
+    /// Find the minimum (smallest) set of values
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::IVector3;
+    ///
+    /// let a = IVector3::make(1, 2, 3);
+    /// let b = IVector3::make(4, -2, 6);
+    /// assert_eq!(IVector3::min(&a, &b), IVector3::make(1, -2, 3));
+    /// ```
+    #[inline]
+    pub fn min(a: &IVector3, b: &IVector3) -> IVector3 {
+        IVector3::make(a.x.min(b.x), a.y.min(b.y), a.z.min(b.z))
+    }
+
+    /// Find the maximum (largest) set of values
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::IVector3;
+    ///
+    /// let a = IVector3::make(1, 2, 3);
+    /// let b = IVector3::make(4, -2, 6);
+    /// assert_eq!(IVector3::max(&a, &b), IVector3::make(4, 2, 6));
+    /// ```
+    #[inline]
+    pub fn max(a: &IVector3, b: &IVector3) -> IVector3 {
+        IVector3::make(a.x.max(b.x), a.y.max(b.y), a.z.max(b.z))
+    }
+
+    /// Find the vector with each component clamped between two vectors
+    ///
+    /// Panics if any component of `a` is greater than the same component of `b`.
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::IVector3;
+    ///
+    /// let a = IVector3::make(0, 0, 0);
+    /// let b = IVector3::make(2, 2, 2);
+    /// let actual = IVector3::make(-5, 1, 3).clamped(&a, &b);
+    /// assert_eq!(actual, IVector3::make(0, 1, 2));
+    /// ```
+    #[inline]
+    pub fn clamped(&self, a: &IVector3, b: &IVector3) -> IVector3 {
+        IVector3::make(
+            self.x.clamp(a.x, b.x),
+            self.y.clamp(a.y, b.y),
+            self.z.clamp(a.z, b.z),
+        )
+    }
+
+    /// Find the vector with the absolute value of each component
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::IVector3;
+    ///
+    /// let actual = IVector3::make(-1, 2, -3).abs_value();
+    /// assert_eq!(actual, IVector3::make(1, 2, 3));
+    /// ```
+    #[inline]
+    pub fn abs_value(&self) -> IVector3 {
+        IVector3::make(self.x.abs(), self.y.abs(), self.z.abs())
+    }
+
+    /// Gets the smallest component
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::IVector3;
+    ///
+    /// assert_eq!(IVector3::make(3, 5, 4).min_element(), 3);
+    /// ```
+    #[inline]
+    pub fn min_element(&self) -> i32 {
+        self.x.min(self.y).min(self.z)
+    }
+
+    /// Gets the largest component
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::IVector3;
+    ///
+    /// assert_eq!(IVector3::make(3, 5, 4).max_element(), 5);
+    /// ```
+    #[inline]
+    pub fn max_element(&self) -> i32 {
+        self.x.max(self.y).max(self.z)
+    }
+
+    /// Gets the sum of the components
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::IVector3;
+    ///
+    /// assert_eq!(IVector3::make(3, 5, 4).sum(), 12);
+    /// ```
+    #[inline]
+    pub fn sum(&self) -> i32 {
+        self.x + self.y + self.z
+    }
+
+    /// Gets the product of the components
+    ///
+    /// Useful for counting the cells in a grid or the invocations in a dispatch of this size.
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::IVector3;
+    ///
+    /// assert_eq!(IVector3::make(3, 5, 4).product(), 60);
+    /// ```
+    #[inline]
+    pub fn product(&self) -> i32 {
+        self.x * self.y * self.z
+    }
+
+    /// Creates a vector from a floating point vector with each component rounded down
+    ///
+    /// Components outside the range of `i32` saturate to its limits, and NaN becomes 0.
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::IVector3;
+    /// use vex::Vector3;
+    ///
+    /// let actual = IVector3::floor(&Vector3::make(1.5, -0.5, 2.7));
+    /// assert_eq!(actual, IVector3::make(1, -1, 2));
+    /// ```
+    #[inline]
+    pub fn floor(v: &Vector3) -> IVector3 {
+        IVector3::make(v.x.floor() as i32, v.y.floor() as i32, v.z.floor() as i32)
+    }
+
+    /// Creates a vector from a floating point vector with each component rounded up
+    ///
+    /// Components outside the range of `i32` saturate to its limits, and NaN becomes 0.
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::IVector3;
+    /// use vex::Vector3;
+    ///
+    /// let actual = IVector3::ceil(&Vector3::make(1.5, -0.5, 2.7));
+    /// assert_eq!(actual, IVector3::make(2, 0, 3));
+    /// ```
+    #[inline]
+    pub fn ceil(v: &Vector3) -> IVector3 {
+        IVector3::make(v.x.ceil() as i32, v.y.ceil() as i32, v.z.ceil() as i32)
+    }
+
+    /// Creates a vector from a floating point vector with each component rounded to the nearest integer, with halves rounded away from zero
+    ///
+    /// Components outside the range of `i32` saturate to its limits, and NaN becomes 0.
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::IVector3;
+    /// use vex::Vector3;
+    ///
+    /// let actual = IVector3::round(&Vector3::make(1.5, -0.5, 2.7));
+    /// assert_eq!(actual, IVector3::make(2, -1, 3));
+    /// ```
+    #[inline]
+    pub fn round(v: &Vector3) -> IVector3 {
+        IVector3::make(v.x.round() as i32, v.y.round() as i32, v.z.round() as i32)
+    }
+
+    /// Converts the vector to a floating point vector
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::IVector3;
+    /// use vex::Vector3;
+    ///
+    /// let actual = IVector3::make(1, 2, 3).as_f32();
+    /// assert_eq!(actual, Vector3::make(1.0, 2.0, 3.0));
+    /// ```
+    #[inline]
+    pub fn as_f32(&self) -> Vector3 {
+        Vector3::make(self.x as f32, self.y as f32, self.z as f32)
+    }
+}
+
+impl From<[i32; 3]> for IVector3 {
+    /// Creates a vector from an array of its components
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::IVector3;
+    ///
+    /// let actual = IVector3::from([1, 2, 3]);
+    /// assert_eq!(actual, IVector3::make(1, 2, 3));
+    /// ```
+    #[inline]
+    fn from(values: [i32; 3]) -> IVector3 {
+        IVector3::make(values[0], values[1], values[2])
+    }
+}
+
+impl From<IVector3> for [i32; 3] {
+    /// Creates an array from the components of a vector
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::IVector3;
+    ///
+    /// let actual: [i32; 3] = IVector3::make(1, 2, 3).into();
+    /// assert_eq!(actual, [1, 2, 3]);
+    /// ```
+    #[inline]
+    fn from(v: IVector3) -> [i32; 3] {
+        [v.x, v.y, v.z]
+    }
+}
+
+impl Index<u32> for IVector3 {
+    type Output = i32;
+
+    /// Looks up a component by index
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::IVector3;
+    ///
+    /// let v = IVector3::make(1, 2, 3);
+    /// assert_eq!(v[2], 3);
+    /// ```
+    #[inline]
+    fn index(&self, index: u32) -> &i32 {
+        match index {
+            0 => &self.x,
+            1 => &self.y,
+            2 => &self.z,
+            _ => panic!("Invalid index for IVector3: {}", index),
+        }
+    }
+}
+
+impl IndexMut<u32> for IVector3 {
+    /// Mutate a component by index
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::IVector3;
+    ///
+    /// let mut v = IVector3::make(1, 2, 3);
+    /// v[2] = 8;
+    /// assert_eq!(v[2], 8);
+    /// ```
+    #[inline]
+    fn index_mut(&mut self, index: u32) -> &mut i32 {
+        match index {
+            0 => &mut self.x,
+            1 => &mut self.y,
+            2 => &mut self.z,
+            _ => panic!("Invalid index for IVector3: {}", index),
+        }
+    }
+}
+
+impl Neg for IVector3 {
+    type Output = IVector3;
+
+    /// Negates all components in a vector
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::IVector3;
+    ///
+    /// let actual = -IVector3::make(1, 2, 3);
+    /// assert_eq!(actual, IVector3::make(-1, -2, -3));
+    /// ```
+    #[inline]
+    fn neg(self) -> IVector3 {
+        IVector3::make(-self.x, -self.y, -self.z)
+    }
+}
+
+impl Add<i32> for IVector3 {
+    type Output = IVector3;
+
+    /// Add a scalar to a vector
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::IVector3;
+    ///
+    /// let actual = IVector3::make(1, 2, 3) + 2;
+    /// assert_eq!(actual, IVector3::make(3, 4, 5));
+    /// ```
+    #[inline]
+    fn add(self, _rhs: i32) -> IVector3 {
+        IVector3::make(self.x + _rhs, self.y + _rhs, self.z + _rhs)
+    }
+}
+
+impl AddAssign<i32> for IVector3 {
+    /// Increment a vector by a scalar
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::IVector3;
+    ///
+    /// let mut actual = IVector3::make(1, 2, 3);
+    /// actual += 2;
+    /// assert_eq!(actual, IVector3::make(3, 4, 5));
+    /// ```
+    #[inline]
+    fn add_assign(&mut self, _rhs: i32) {
+        self.x += _rhs;
+        self.y += _rhs;
+        self.z += _rhs;
+    }
+}
+
+impl Add<IVector3> for IVector3 {
+    type Output = IVector3;
+
+    /// Add two vectors
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::IVector3;
+    ///
+    /// let actual = IVector3::make(1, 2, 3) + IVector3::make(3, 5, 4);
+    /// assert_eq!(actual, IVector3::make(4, 7, 7));
+    /// ```
+    #[inline]
+    fn add(self, _rhs: IVector3) -> IVector3 {
+        IVector3::make(self.x + _rhs.x, self.y + _rhs.y, self.z + _rhs.z)
+    }
+}
+
+impl AddAssign<IVector3> for IVector3 {
+    /// Increment a vector by another vector
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::IVector3;
+    ///
+    /// let mut actual = IVector3::make(1, 2, 3);
+    /// actual += IVector3::make(3, 5, 4);
+    /// assert_eq!(actual, IVector3::make(4, 7, 7));
+    /// ```
+    #[inline]
+    fn add_assign(&mut self, _rhs: IVector3) {
+        self.x += _rhs.x;
+        self.y += _rhs.y;
+        self.z += _rhs.z;
+    }
+}
+
+impl Sub<i32> for IVector3 {
+    type Output = IVector3;
+
+    /// Subtract a scalar from a vector
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::IVector3;
+    ///
+    /// let actual = IVector3::make(1, 2, 3) - 2;
+    /// assert_eq!(actual, IVector3::make(-1, 0, 1));
+    /// ```
+    #[inline]
+    fn sub(self, _rhs: i32) -> IVector3 {
+        IVector3::make(self.x - _rhs, self.y - _rhs, self.z - _rhs)
+    }
+}
+
+impl SubAssign<i32> for IVector3 {
+    /// Decrement a vector by a scalar
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::IVector3;
+    ///
+    /// let mut actual = IVector3::make(1, 2, 3);
+    /// actual -= 2;
+    /// assert_eq!(actual, IVector3::make(-1, 0, 1));
+    /// ```
+    #[inline]
+    fn sub_assign(&mut self, _rhs: i32) {
+        self.x -= _rhs;
+        self.y -= _rhs;
+        self.z -= _rhs;
+    }
+}
+
+impl Sub<IVector3> for IVector3 {
+    type Output = IVector3;
+
+    /// Subtract two vectors
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::IVector3;
+    ///
+    /// let actual = IVector3::make(1, 2, 3) - IVector3::make(3, 5, 4);
+    /// assert_eq!(actual, IVector3::make(-2, -3, -1));
+    /// ```
+    #[inline]
+    fn sub(self, _rhs: IVector3) -> IVector3 {
+        IVector3::make(self.x - _rhs.x, self.y - _rhs.y, self.z - _rhs.z)
+    }
+}
+
+impl SubAssign<IVector3> for IVector3 {
+    /// Decrement a vector by another vector
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::IVector3;
+    ///
+    /// let mut actual = IVector3::make(1, 2, 3);
+    /// actual -= IVector3::make(3, 5, 4);
+    /// assert_eq!(actual, IVector3::make(-2, -3, -1));
+    /// ```
+    #[inline]
+    fn sub_assign(&mut self, _rhs: IVector3) {
+        self.x -= _rhs.x;
+        self.y -= _rhs.y;
+        self.z -= _rhs.z;
+    }
+}
+
+impl Mul<i32> for IVector3 {
+    type Output = IVector3;
+
+    /// Multiply a vector by a scalar
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::IVector3;
+    ///
+    /// let actual = IVector3::make(1, 2, 3) * 2;
+    /// assert_eq!(actual, IVector3::make(2, 4, 6));
+    /// ```
+    #[inline]
+    fn mul(self, _rhs: i32) -> IVector3 {
+        IVector3::make(self.x * _rhs, self.y * _rhs, self.z * _rhs)
+    }
+}
+
+impl MulAssign<i32> for IVector3 {
+    /// Multiply a vector by a scalar
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::IVector3;
+    ///
+    /// let mut actual = IVector3::make(1, 2, 3);
+    /// actual *= 2;
+    /// assert_eq!(actual, IVector3::make(2, 4, 6));
+    /// ```
+    #[inline]
+    fn mul_assign(&mut self, _rhs: i32) {
+        self.x *= _rhs;
+        self.y *= _rhs;
+        self.z *= _rhs;
+    }
+}
+
+impl Mul<IVector3> for IVector3 {
+    type Output = IVector3;
+
+    /// Multiply two vectors component-wise
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::IVector3;
+    ///
+    /// let actual = IVector3::make(1, 2, 3) * IVector3::make(3, 5, 4);
+    /// assert_eq!(actual, IVector3::make(3, 10, 12));
+    /// ```
+    #[inline]
+    fn mul(self, _rhs: IVector3) -> IVector3 {
+        IVector3::make(self.x * _rhs.x, self.y * _rhs.y, self.z * _rhs.z)
+    }
+}
+
+impl MulAssign<IVector3> for IVector3 {
+    /// Multiply a vector by another vector component-wise
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::IVector3;
+    ///
+    /// let mut actual = IVector3::make(1, 2, 3);
+    /// actual *= IVector3::make(3, 5, 4);
+    /// assert_eq!(actual, IVector3::make(3, 10, 12));
+    /// ```
+    #[inline]
+    fn mul_assign(&mut self, _rhs: IVector3) {
+        self.x *= _rhs.x;
+        self.y *= _rhs.y;
+        self.z *= _rhs.z;
+    }
+}
+
+impl Div<i32> for IVector3 {
+    type Output = IVector3;
+
+    /// Divide a vector by a scalar
+    ///
+    /// Division by zero panics, and division rounds towards zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::IVector3;
+    ///
+    /// let actual = IVector3::make(6, 10, 8) / 2;
+    /// assert_eq!(actual, IVector3::make(3, 5, 4));
+    /// ```
+    #[inline]
+    fn div(self, _rhs: i32) -> IVector3 {
+        IVector3::make(self.x / _rhs, self.y / _rhs, self.z / _rhs)
+    }
+}
+
+impl DivAssign<i32> for IVector3 {
+    /// Divide a vector by a scalar
+    ///
+    /// Division by zero panics, and division rounds towards zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::IVector3;
+    ///
+    /// let mut actual = IVector3::make(6, 10, 8);
+    /// actual /= 2;
+    /// assert_eq!(actual, IVector3::make(3, 5, 4));
+    /// ```
+    #[inline]
+    fn div_assign(&mut self, _rhs: i32) {
+        self.x /= _rhs;
+        self.y /= _rhs;
+        self.z /= _rhs;
+    }
+}
+
+impl Div<IVector3> for IVector3 {
+    type Output = IVector3;
+
+    /// Divide two vectors component-wise
+    ///
+    /// Division by zero panics, and division rounds towards zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::IVector3;
+    ///
+    /// let actual = IVector3::make(6, 10, 9) / IVector3::make(3, 5, 3);
+    /// assert_eq!(actual, IVector3::make(2, 2, 3));
+    /// ```
+    #[inline]
+    fn div(self, _rhs: IVector3) -> IVector3 {
+        IVector3::make(self.x / _rhs.x, self.y / _rhs.y, self.z / _rhs.z)
+    }
+}
+
+impl DivAssign<IVector3> for IVector3 {
+    /// Divide a vector by another vector component-wise
+    ///
+    /// Division by zero panics, and division rounds towards zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::IVector3;
+    ///
+    /// let mut actual = IVector3::make(6, 10, 9);
+    /// actual /= IVector3::make(3, 5, 3);
+    /// assert_eq!(actual, IVector3::make(2, 2, 3));
+    /// ```
+    #[inline]
+    fn div_assign(&mut self, _rhs: IVector3) {
+        self.x /= _rhs.x;
+        self.y /= _rhs.y;
+        self.z /= _rhs.z;
+    }
+}
+
+impl Display for IVector3 {
+    /// Formats as `<x, y, z>`, with the formatter's flags applied to each component
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::IVector3;
+    ///
+    /// let v = IVector3::make(-1, 20, 3);
+    /// assert_eq!(format!("{}", v), "<-1, 20, 3>");
+    /// assert_eq!(format!("{:+}", v), "<-1, +20, +3>");
+    /// ```
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        format::write_vector(f, &[self.x, self.y, self.z])
+    }
+}
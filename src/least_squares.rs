@@ -0,0 +1,254 @@
+use crate::common::Matrix;
+use crate::matrix3::Matrix3;
+use crate::matrix4::Matrix4;
+use crate::vector3::Vector3;
+use crate::vector4::Vector4;
+
+/// Accumulates the normal equations `A^T A x = A^T b` of a 3-unknown least squares problem
+///
+/// Rows of `A` and their entries in `b` are added one at a time, so a fit can be built up while
+/// streaming observations without storing them. Both halves of the Gram matrix `A^T A` get the
+/// same products, so it stays exactly symmetric.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct LeastSquares3 {
+    /// The Gram matrix `A^T A`
+    pub ata: Matrix3,
+    /// The projected right-hand side `A^T b`
+    pub atb: Vector3,
+}
+
+impl LeastSquares3 {
+    /// Creates an accumulator with no rows
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::LeastSquares3;
+    /// use vex::Vector3;
+    ///
+    /// let actual = LeastSquares3::new();
+    /// assert_eq!(actual.ata.m, [0.0; 9]);
+    /// assert_eq!(actual.atb, Vector3::ZERO);
+    /// ```
+    #[inline]
+    pub const fn new() -> LeastSquares3 {
+        LeastSquares3 {
+            ata: Matrix3 { m: [0.0; 9] },
+            atb: Vector3::ZERO,
+        }
+    }
+
+    /// Adds the row `row . x = b` to the system
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::LeastSquares3;
+    /// use vex::Matrix3;
+    /// use vex::Vector3;
+    ///
+    /// let mut actual = LeastSquares3::new();
+    /// actual.add_row(&Vector3::make(1.0, 2.0, 3.0), 2.0);
+    /// assert_eq!(actual.ata, Matrix3::make(1.0, 2.0, 3.0, 2.0, 4.0, 6.0, 3.0, 6.0, 9.0));
+    /// assert_eq!(actual.atb, Vector3::make(2.0, 4.0, 6.0));
+    /// ```
+    #[inline]
+    pub fn add_row(&mut self, row: &Vector3, b: f32) {
+        self.add_weighted_row(row, b, 1.0);
+    }
+
+    /// Adds the row `row . x = b` to the system, scaling its influence on the fit by `weight`
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::LeastSquares3;
+    /// use vex::Vector3;
+    ///
+    /// let mut a = LeastSquares3::new();
+    /// a.add_weighted_row(&Vector3::make(1.0, 2.0, 3.0), 2.0, 2.0);
+    ///
+    /// let mut b = LeastSquares3::new();
+    /// b.add_row(&Vector3::make(1.0, 2.0, 3.0), 2.0);
+    /// b.add_row(&Vector3::make(1.0, 2.0, 3.0), 2.0);
+    /// assert_eq!(a, b);
+    /// ```
+    pub fn add_weighted_row(&mut self, row: &Vector3, b: f32, weight: f32) {
+        let r: [f32; 3] = (*row).into();
+        for i in 0..3 {
+            for j in i..3 {
+                let product = weight * r[i] * r[j];
+                self.ata.m[i * 3 + j] += product;
+                if i != j {
+                    self.ata.m[j * 3 + i] += product;
+                }
+            }
+
+            self.atb[i as u32] += weight * r[i] * b;
+        }
+    }
+
+    /// Solves the normal equations for the `x` that minimizes the squared error of every row
+    ///
+    /// Returns `None` when the rows don't constrain every unknown. Forming `A^T A` squares the
+    /// condition number of `A`, so nearly dependent rows lose precision.
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::LeastSquares3;
+    /// use vex::Vector3;
+    ///
+    /// // fit z = 2x - y + 3 to points on that plane
+    /// let mut system = LeastSquares3::new();
+    /// for &(x, y) in [(0.0, 0.0), (1.0, 0.0), (0.0, 1.0), (2.0, 3.0)].iter() {
+    ///     system.add_row(&Vector3::make(x, y, 1.0), 2.0 * x - y + 3.0);
+    /// }
+    ///
+    /// let actual = system.solve_normal_equations().unwrap();
+    /// assert!(actual.approx_eq(&Vector3::make(2.0, -1.0, 3.0), 0.0001));
+    ///
+    /// // a single row can't pin down three unknowns
+    /// let mut system = LeastSquares3::new();
+    /// system.add_row(&Vector3::make(1.0, 0.0, 0.0), 1.0);
+    /// assert_eq!(system.solve_normal_equations(), None);
+    /// ```
+    #[inline]
+    pub fn solve_normal_equations(&self) -> Option<Vector3> {
+        self.ata.try_inverse().map(|inverse| inverse.transform_point(&self.atb))
+    }
+}
+
+/// Accumulates the normal equations `A^T A x = A^T b` of a 4-unknown least squares problem
+///
+/// Rows of `A` and their entries in `b` are added one at a time, so a fit can be built up while
+/// streaming observations without storing them. Both halves of the Gram matrix `A^T A` get the
+/// same products, so it stays exactly symmetric.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct LeastSquares4 {
+    /// The Gram matrix `A^T A`
+    pub ata: Matrix4,
+    /// The projected right-hand side `A^T b`
+    pub atb: Vector4,
+}
+
+impl LeastSquares4 {
+    /// Creates an accumulator with no rows
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::LeastSquares4;
+    /// use vex::Vector4;
+    ///
+    /// let actual = LeastSquares4::new();
+    /// assert_eq!(actual.ata.m, [0.0; 16]);
+    /// assert_eq!(actual.atb, Vector4::ZERO);
+    /// ```
+    #[inline]
+    pub const fn new() -> LeastSquares4 {
+        LeastSquares4 {
+            ata: Matrix4 { m: [0.0; 16] },
+            atb: Vector4::ZERO,
+        }
+    }
+
+    /// Adds the row `row . x = b` to the system
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::LeastSquares4;
+    /// use vex::Vector4;
+    ///
+    /// let mut actual = LeastSquares4::new();
+    /// actual.add_row(&Vector4::make(1.0, 2.0, 0.0, 1.0), 3.0);
+    /// assert_eq!(actual.ata.m12(), 2.0);
+    /// assert_eq!(actual.ata.m21(), 2.0);
+    /// assert_eq!(actual.ata.m44(), 1.0);
+    /// assert_eq!(actual.atb, Vector4::make(3.0, 6.0, 0.0, 3.0));
+    /// ```
+    #[inline]
+    pub fn add_row(&mut self, row: &Vector4, b: f32) {
+        self.add_weighted_row(row, b, 1.0);
+    }
+
+    /// Adds the row `row . x = b` to the system, scaling its influence on the fit by `weight`
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::LeastSquares4;
+    /// use vex::Vector4;
+    ///
+    /// let mut a = LeastSquares4::new();
+    /// a.add_weighted_row(&Vector4::make(1.0, 2.0, 3.0, 4.0), 2.0, 2.0);
+    ///
+    /// let mut b = LeastSquares4::new();
+    /// b.add_row(&Vector4::make(1.0, 2.0, 3.0, 4.0), 2.0);
+    /// b.add_row(&Vector4::make(1.0, 2.0, 3.0, 4.0), 2.0);
+    /// assert_eq!(a, b);
+    /// ```
+    pub fn add_weighted_row(&mut self, row: &Vector4, b: f32, weight: f32) {
+        let r: [f32; 4] = (*row).into();
+        for i in 0..4 {
+            for j in i..4 {
+                let product = weight * r[i] * r[j];
+                self.ata.m[i * 4 + j] += product;
+                if i != j {
+                    self.ata.m[j * 4 + i] += product;
+                }
+            }
+
+            self.atb[i as u32] += weight * r[i] * b;
+        }
+    }
+
+    /// Solves the normal equations for the `x` that minimizes the squared error of every row
+    ///
+    /// Returns `None` when the rows don't constrain every unknown. Forming `A^T A` squares the
+    /// condition number of `A`, so nearly dependent rows lose precision.
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::LeastSquares4;
+    /// use vex::Vector4;
+    ///
+    /// // fit w = x + 2y - z + 1 to points on that hyperplane
+    /// let mut system = LeastSquares4::new();
+    /// let points = [(0.0, 0.0, 0.0), (1.0, 0.0, 0.0), (0.0, 1.0, 0.0), (0.0, 0.0, 1.0), (1.0, 2.0, 3.0)];
+    /// for &(x, y, z) in points.iter() {
+    ///     system.add_row(&Vector4::make(x, y, z, 1.0), x + 2.0 * y - z + 1.0);
+    /// }
+    ///
+    /// let actual = system.solve_normal_equations().unwrap();
+    /// assert!(actual.approx_eq(&Vector4::make(1.0, 2.0, -1.0, 1.0), 0.0001));
+    /// ```
+    #[inline]
+    pub fn solve_normal_equations(&self) -> Option<Vector4> {
+        self.ata.try_inverse().map(|inverse| inverse.transform_point(&self.atb))
+    }
+}
+
+impl Default for LeastSquares3 {
+    /// Creates an accumulator with no rows
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::LeastSquares3;
+    ///
+    /// assert_eq!(LeastSquares3::default(), LeastSquares3::new());
+    /// ```
+    #[inline]
+    fn default() -> LeastSquares3 {
+        LeastSquares3::new()
+    }
+}
+
+impl Default for LeastSquares4 {
+    /// Creates an accumulator with no rows
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::LeastSquares4;
+    ///
+    /// assert_eq!(LeastSquares4::default(), LeastSquares4::new());
+    /// ```
+    #[inline]
+    fn default() -> LeastSquares4 {
+        LeastSquares4::new()
+    }
+}
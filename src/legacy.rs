@@ -0,0 +1,245 @@
+// the names from before the `Vector*`/`Matrix*` renames, kept so older code still compiles
+
+use crate::matrix2::Matrix2;
+use crate::matrix3::Matrix3;
+use crate::matrix4::Matrix4;
+use crate::quaternion::Quaternion;
+use crate::vector2::Vector2;
+use crate::vector3::Vector3;
+use crate::vector4::Vector4;
+
+/// The old name for [`Vector2`](struct.Vector2.html)
+#[deprecated(since = "2.2.0", note = "use `Vector2` instead")]
+pub type Vec2 = Vector2;
+
+/// The old name for [`Vector3`](struct.Vector3.html)
+#[deprecated(since = "2.2.0", note = "use `Vector3` instead")]
+pub type Vec3 = Vector3;
+
+/// The old name for [`Vector4`](struct.Vector4.html)
+#[deprecated(since = "2.2.0", note = "use `Vector4` instead")]
+pub type Vec4 = Vector4;
+
+/// The old name for [`Matrix2`](struct.Matrix2.html)
+#[deprecated(since = "2.2.0", note = "use `Matrix2` instead")]
+pub type Mat2 = Matrix2;
+
+/// The old name for [`Matrix3`](struct.Matrix3.html)
+#[deprecated(since = "2.2.0", note = "use `Matrix3` instead")]
+pub type Mat3 = Matrix3;
+
+/// The old name for [`Matrix4`](struct.Matrix4.html)
+#[deprecated(since = "2.2.0", note = "use `Matrix4` instead")]
+pub type Mat4 = Matrix4;
+
+impl Vector2 {
+    /// The old name for [`Vector2::make`](struct.Vector2.html#method.make)
+    ///
+    /// # Examples
+    /// ```
+    /// #![allow(deprecated)]
+    /// use vex::Vec2;
+    /// use vex::Vector2;
+    ///
+    /// assert_eq!(Vec2::construct(1.0, 2.0), Vector2::make(1.0, 2.0));
+    /// ```
+    #[inline]
+    #[deprecated(since = "2.2.0", note = "use `Vector2::make` instead")]
+    pub fn construct(x: f32, y: f32) -> Vector2 {
+        Vector2::make(x, y)
+    }
+
+    /// The old name for [`Vector2::norm`](struct.Vector2.html#method.norm)
+    ///
+    /// # Examples
+    /// ```
+    /// #![allow(deprecated)]
+    /// use vex::Vector2;
+    ///
+    /// let mut actual = Vector2::make(3.0, 4.0);
+    /// assert_eq!(actual.normalize(), 5.0);
+    /// assert_eq!(actual, Vector2::make(0.6, 0.8));
+    /// ```
+    #[inline]
+    #[deprecated(since = "2.2.0", note = "use `Vector2::norm` instead")]
+    pub fn normalize(&mut self) -> f32 {
+        self.norm()
+    }
+}
+
+impl Vector3 {
+    /// The old name for [`Vector3::make`](struct.Vector3.html#method.make)
+    ///
+    /// # Examples
+    /// ```
+    /// #![allow(deprecated)]
+    /// use vex::Vec3;
+    /// use vex::Vector3;
+    ///
+    /// assert_eq!(Vec3::construct(1.0, 2.0, 3.0), Vector3::make(1.0, 2.0, 3.0));
+    /// ```
+    #[inline]
+    #[deprecated(since = "2.2.0", note = "use `Vector3::make` instead")]
+    pub fn construct(x: f32, y: f32, z: f32) -> Vector3 {
+        Vector3::make(x, y, z)
+    }
+
+    /// The old name for [`Vector3::norm`](struct.Vector3.html#method.norm)
+    ///
+    /// # Examples
+    /// ```
+    /// #![allow(deprecated)]
+    /// use vex::Vector3;
+    ///
+    /// let mut actual = Vector3::make(0.0, 3.0, 4.0);
+    /// assert_eq!(actual.normalize(), 5.0);
+    /// assert_eq!(actual, Vector3::make(0.0, 0.6, 0.8));
+    /// ```
+    #[inline]
+    #[deprecated(since = "2.2.0", note = "use `Vector3::norm` instead")]
+    pub fn normalize(&mut self) -> f32 {
+        self.norm()
+    }
+}
+
+impl Vector4 {
+    /// The old name for [`Vector4::make`](struct.Vector4.html#method.make)
+    ///
+    /// # Examples
+    /// ```
+    /// #![allow(deprecated)]
+    /// use vex::Vec4;
+    /// use vex::Vector4;
+    ///
+    /// assert_eq!(Vec4::construct(1.0, 2.0, 3.0, 4.0), Vector4::make(1.0, 2.0, 3.0, 4.0));
+    /// ```
+    #[inline]
+    #[deprecated(since = "2.2.0", note = "use `Vector4::make` instead")]
+    pub fn construct(x: f32, y: f32, z: f32, w: f32) -> Vector4 {
+        Vector4::make(x, y, z, w)
+    }
+
+    /// The old name for [`Vector4::norm`](struct.Vector4.html#method.norm)
+    ///
+    /// # Examples
+    /// ```
+    /// #![allow(deprecated)]
+    /// use vex::Vector4;
+    ///
+    /// let mut actual = Vector4::make(0.0, 0.0, 3.0, 4.0);
+    /// assert_eq!(actual.normalize(), 5.0);
+    /// assert_eq!(actual, Vector4::make(0.0, 0.0, 0.6, 0.8));
+    /// ```
+    #[inline]
+    #[deprecated(since = "2.2.0", note = "use `Vector4::norm` instead")]
+    pub fn normalize(&mut self) -> f32 {
+        self.norm()
+    }
+}
+
+impl Quaternion {
+    /// The old name for [`Quaternion::make`](struct.Quaternion.html#method.make)
+    ///
+    /// # Examples
+    /// ```
+    /// #![allow(deprecated)]
+    /// use vex::Quaternion;
+    ///
+    /// let actual = Quaternion::construct(1.0, 2.0, 3.0, 4.0);
+    /// assert_eq!(actual, Quaternion::make(1.0, 2.0, 3.0, 4.0));
+    /// ```
+    #[inline]
+    #[deprecated(since = "2.2.0", note = "use `Quaternion::make` instead")]
+    pub fn construct(x: f32, y: f32, z: f32, w: f32) -> Quaternion {
+        Quaternion::make(x, y, z, w)
+    }
+}
+
+impl Matrix2 {
+    /// The old name for [`Matrix2::make`](struct.Matrix2.html#method.make)
+    ///
+    /// # Examples
+    /// ```
+    /// #![allow(deprecated)]
+    /// use vex::Mat2;
+    /// use vex::Matrix2;
+    ///
+    /// let actual = Mat2::construct(1.0, 2.0, 3.0, 4.0);
+    /// assert_eq!(actual, Matrix2::make(1.0, 2.0, 3.0, 4.0));
+    /// ```
+    #[inline]
+    #[deprecated(since = "2.2.0", note = "use `Matrix2::make` instead")]
+    pub fn construct(m11: f32, m21: f32, m12: f32, m22: f32) -> Matrix2 {
+        Matrix2::make(m11, m21, m12, m22)
+    }
+}
+
+impl Matrix3 {
+    /// The old name for [`Matrix3::make`](struct.Matrix3.html#method.make)
+    ///
+    /// # Examples
+    /// ```
+    /// #![allow(deprecated)]
+    /// use vex::Mat3;
+    /// use vex::Matrix3;
+    ///
+    /// let actual = Mat3::construct(1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0);
+    /// assert_eq!(actual, Matrix3::make(1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0));
+    /// ```
+    #[inline]
+    #[allow(clippy::too_many_arguments)]
+    #[deprecated(since = "2.2.0", note = "use `Matrix3::make` instead")]
+    pub fn construct(
+        m11: f32,
+        m21: f32,
+        m31: f32,
+        m12: f32,
+        m22: f32,
+        m32: f32,
+        m13: f32,
+        m23: f32,
+        m33: f32,
+    ) -> Matrix3 {
+        Matrix3::make(m11, m21, m31, m12, m22, m32, m13, m23, m33)
+    }
+}
+
+impl Matrix4 {
+    /// The old name for [`Matrix4::make`](struct.Matrix4.html#method.make)
+    ///
+    /// # Examples
+    /// ```
+    /// #![allow(deprecated)]
+    /// use vex::Mat4;
+    /// use vex::Matrix4;
+    ///
+    /// let values = [1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0, 10.0, 11.0, 12.0, 13.0, 14.0, 15.0, 16.0];
+    /// let actual = Mat4::construct(1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0, 10.0, 11.0, 12.0, 13.0, 14.0, 15.0, 16.0);
+    /// assert_eq!(actual.m, values);
+    /// ```
+    #[inline]
+    #[allow(clippy::too_many_arguments)]
+    #[deprecated(since = "2.2.0", note = "use `Matrix4::make` instead")]
+    pub fn construct(
+        m11: f32,
+        m21: f32,
+        m31: f32,
+        m41: f32,
+        m12: f32,
+        m22: f32,
+        m32: f32,
+        m42: f32,
+        m13: f32,
+        m23: f32,
+        m33: f32,
+        m43: f32,
+        m14: f32,
+        m24: f32,
+        m34: f32,
+        m44: f32,
+    ) -> Matrix4 {
+        Matrix4::make(
+            m11, m21, m31, m41, m12, m22, m32, m42, m13, m23, m33, m43, m14, m24, m34, m44,
+        )
+    }
+}
@@ -1,15 +1,178 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[cfg(not(any(feature = "std", feature = "libm")))]
+compile_error!("vex needs either the `std` feature or the `libm` feature for float math");
+
+#[cfg_attr(not(feature = "std"), macro_use)]
+extern crate alloc;
+#[cfg(feature = "std")]
+extern crate core;
+#[cfg(feature = "approx")]
+extern crate approx;
+#[cfg(feature = "egui")]
+extern crate egui;
+#[cfg(feature = "libm")]
+extern crate libm;
+#[cfg(feature = "rand")]
+extern crate rand;
+#[cfg(feature = "rkyv")]
+extern crate rkyv;
+
+mod aabb;
+#[cfg(feature = "approx")]
+mod approx_eq;
+mod arc;
+#[cfg(feature = "rkyv")]
+mod archive;
+mod block;
+mod bvh;
+mod camera2d;
+mod capsule;
+mod character;
+mod clip_space;
+mod cluster;
 mod common;
+mod coordinates;
+mod curvature;
+mod dither;
+mod drag;
+mod drift;
+mod dual_quaternion;
+mod epsilon;
+mod euler;
+#[cfg(feature = "fast-math")]
+mod fast;
+mod field;
+mod fitting;
+mod format;
+mod frustum;
+mod hash;
+mod ik;
+mod impulse;
+mod inertia;
+mod inspect;
+mod ivector2;
+mod ivector3;
+mod least_squares;
+mod legacy;
+#[cfg(not(feature = "std"))]
+mod math;
 mod matrix2;
 mod matrix3;
 mod matrix4;
+mod monotone_cubic;
+mod non_zero_scale;
+mod normalized;
+mod packing;
+mod parse;
+mod path;
+mod plane;
+mod points;
+mod predicates;
+pub mod prelude;
+mod quantize;
+mod quaternion;
+#[cfg(feature = "rand")]
+mod random;
+mod raster;
+mod ray;
+mod rect;
+mod rot2;
+mod sampling;
+mod screen;
+mod sequence;
+mod shape2;
+#[cfg(feature = "simd")]
+mod simd;
+mod solid_angle;
+mod spread;
+mod spring;
+mod storage;
+#[cfg(feature = "std")]
+mod stream;
+mod swizzle;
+#[cfg(feature = "test-utils")]
+pub mod test_utils;
+mod toi;
+mod transform;
+mod transform_pool;
+mod unit_vector3;
+mod uv;
+mod uvector2;
+mod uvector3;
 mod vector2;
 mod vector3;
 mod vector4;
 
+pub use aabb::*;
+pub use arc::*;
+pub use block::*;
+pub use bvh::*;
+pub use camera2d::*;
+pub use capsule::*;
+pub use character::*;
+pub use clip_space::*;
+pub use cluster::*;
 pub use common::*;
+pub use coordinates::*;
+pub use curvature::*;
+pub use dither::*;
+pub use drag::*;
+pub use drift::*;
+pub use dual_quaternion::*;
+pub use epsilon::*;
+pub use euler::*;
+#[cfg(feature = "fast-math")]
+pub use fast::*;
+pub use field::*;
+pub use fitting::*;
+pub use frustum::*;
+pub use hash::*;
+pub use ik::*;
+pub use impulse::*;
+pub use inertia::*;
+pub use inspect::*;
+pub use ivector2::*;
+pub use ivector3::*;
+pub use least_squares::*;
+pub use legacy::*;
 pub use matrix2::*;
 pub use matrix3::*;
 pub use matrix4::*;
+pub use monotone_cubic::*;
+pub use non_zero_scale::*;
+pub use normalized::*;
+pub use packing::*;
+pub use parse::*;
+pub use path::*;
+pub use plane::*;
+pub use points::*;
+pub use predicates::*;
+pub use quantize::*;
+pub use quaternion::*;
+#[cfg(feature = "rand")]
+pub use random::*;
+pub use raster::*;
+pub use ray::*;
+pub use rect::*;
+pub use rot2::*;
+pub use sampling::*;
+pub use screen::*;
+pub use sequence::*;
+pub use shape2::*;
+pub use solid_angle::*;
+pub use spread::*;
+pub use spring::*;
+pub use storage::*;
+#[cfg(feature = "std")]
+pub use stream::*;
+pub use toi::*;
+pub use transform::*;
+pub use transform_pool::*;
+pub use unit_vector3::*;
+pub use uv::*;
+pub use uvector2::*;
+pub use uvector3::*;
 pub use vector2::*;
 pub use vector3::*;
 pub use vector4::*;
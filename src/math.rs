@@ -0,0 +1,95 @@
+// without `std`, the inherent float methods that need the platform's math library aren't
+// available, so this trait provides them from `libm` under the same names
+pub(crate) trait Float: Sized {
+    fn acos(self) -> Self;
+    fn asin(self) -> Self;
+    fn atan2(self, other: Self) -> Self;
+    fn ceil(self) -> Self;
+    fn cos(self) -> Self;
+    fn exp(self) -> Self;
+    fn floor(self) -> Self;
+    fn ln(self) -> Self;
+    fn powf(self, n: Self) -> Self;
+    fn round(self) -> Self;
+    fn sin(self) -> Self;
+    fn sin_cos(self) -> (Self, Self);
+    fn sqrt(self) -> Self;
+}
+
+macro_rules! impl_float {
+    ($t:ty, $acos:ident, $asin:ident, $atan2:ident, $ceil:ident, $cos:ident, $exp:ident,
+     $floor:ident, $ln:ident, $powf:ident, $round:ident, $sin:ident, $sin_cos:ident,
+     $sqrt:ident) => {
+        impl Float for $t {
+            #[inline]
+            fn acos(self) -> $t {
+                libm::$acos(self)
+            }
+
+            #[inline]
+            fn asin(self) -> $t {
+                libm::$asin(self)
+            }
+
+            #[inline]
+            fn atan2(self, other: $t) -> $t {
+                libm::$atan2(self, other)
+            }
+
+            #[inline]
+            fn ceil(self) -> $t {
+                libm::$ceil(self)
+            }
+
+            #[inline]
+            fn cos(self) -> $t {
+                libm::$cos(self)
+            }
+
+            #[inline]
+            fn exp(self) -> $t {
+                libm::$exp(self)
+            }
+
+            #[inline]
+            fn floor(self) -> $t {
+                libm::$floor(self)
+            }
+
+            #[inline]
+            fn ln(self) -> $t {
+                libm::$ln(self)
+            }
+
+            #[inline]
+            fn powf(self, n: $t) -> $t {
+                libm::$powf(self, n)
+            }
+
+            #[inline]
+            fn round(self) -> $t {
+                libm::$round(self)
+            }
+
+            #[inline]
+            fn sin(self) -> $t {
+                libm::$sin(self)
+            }
+
+            #[inline]
+            fn sin_cos(self) -> ($t, $t) {
+                libm::$sin_cos(self)
+            }
+
+            #[inline]
+            fn sqrt(self) -> $t {
+                libm::$sqrt(self)
+            }
+        }
+    };
+}
+
+impl_float!(
+    f32, acosf, asinf, atan2f, ceilf, cosf, expf, floorf, logf, powf, roundf, sinf, sincosf, sqrtf
+);
+impl_float!(f64, acos, asin, atan2, ceil, cos, exp, floor, log, pow, round, sin, sincos, sqrt);
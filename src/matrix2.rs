@@ -1,11 +1,21 @@
 use crate::common;
+use crate::epsilon::Epsilon;
+use crate::format;
+#[cfg(not(feature = "std"))]
+use crate::math::Float;
+use crate::parse::{self, ParseError};
 use crate::vector2::Vector2;
 
-use std::cmp;
-use std::fmt;
-use std::fmt::{Display, Formatter};
+use core::array;
+use core::cmp;
+use core::fmt;
+use core::fmt::{Debug, Display, Formatter};
+use core::slice;
+use core::str::FromStr;
 
-use std::ops::{
+use core::ops::{
+    Index,
+    IndexMut,
     Neg,
     Add,
     AddAssign,
@@ -17,13 +27,45 @@ use std::ops::{
     DivAssign,
 };
 
-#[repr(C, packed)]
-#[derive(Copy, Clone, Debug)]
+#[repr(C)]
+#[derive(Copy, Clone)]
+#[cfg_attr(feature = "rkyv", derive(rkyv::Archive, rkyv::Deserialize, rkyv::Serialize))]
+#[cfg_attr(feature = "rkyv", rkyv(derive(Debug), compare(PartialEq)))]
 pub struct Matrix2 {
     pub m: [f32; 4],
 }
 
 impl Matrix2 {
+    /// The identity matrix
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Matrix2;
+    /// 
+    /// assert_eq!(Matrix2::IDENTITY, Matrix2::new());
+    /// ```
+    pub const IDENTITY: Matrix2 = Matrix2 {
+        m: [
+            1.0, 0.0,
+            0.0, 1.0,
+        ],
+    };
+
+    /// A matrix with every element set to zero
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Matrix2;
+    ///
+    /// assert_eq!(Matrix2::ZERO, Matrix2::IDENTITY * 0.0);
+    /// ```
+    pub const ZERO: Matrix2 = Matrix2 {
+        m: [
+            0.0, 0.0,
+            0.0, 0.0,
+        ],
+    };
+
     /// Creates a matrix set to its identity
     ///
     /// # Examples
@@ -37,7 +79,7 @@ impl Matrix2 {
     /// ]);
     /// ```
     #[inline]
-    pub fn new() -> Matrix2 {
+    pub const fn new() -> Matrix2 {
         Matrix2 {
             m: [
                 1.0, 0.0,
@@ -57,12 +99,46 @@ impl Matrix2 {
     /// assert_eq!(actual.m, expected);
     /// ```
     #[inline]
-    pub fn make(m11: f32, m21: f32, m12: f32, m22: f32) -> Matrix2 {
+    pub const fn make(m11: f32, m21: f32, m12: f32, m22: f32) -> Matrix2 {
         Matrix2 {
             m: [m11, m21, m12, m22],
         }
     }
 
+    /// Creates a matrix that rotates counter-clockwise by `angle` radians
+    ///
+    /// # Examples
+    /// ```
+    /// use std::f32::consts::PI;
+    /// use vex::Matrix;
+    /// use vex::Matrix2;
+    /// use vex::Vector2;
+    /// 
+    /// let actual = Matrix2::rotate(PI / 2.0).transform_point(&Vector2::make(1.0, 2.0));
+    /// let expected = Vector2::make(-2.0, 1.0);
+    /// assert!((actual - expected).mag() < 0.000001);
+    /// ```
+    #[inline]
+    pub fn rotate(angle: f32) -> Matrix2 {
+        let (sine, cosine) = angle.sin_cos();
+        Matrix2::make(cosine, sine, -sine, cosine)
+    }
+
+    /// Creates a scale matrix
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Matrix2;
+    /// 
+    /// let actual = Matrix2::scale(2.0, 3.0);
+    /// let expected = [2.0, 0.0, 0.0, 3.0];
+    /// assert_eq!(actual.m, expected);
+    /// ```
+    #[inline]
+    pub fn scale(x: f32, y: f32) -> Matrix2 {
+        Matrix2::make(x, 0.0, 0.0, y)
+    }
+
     /// Gets the value for the m11 element
     ///
     /// # Examples
@@ -91,198 +167,807 @@ impl Matrix2 {
         self.m[1]
     }
 
-    /// Gets the value for the m12 element
+    /// Gets the value for the m12 element
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Matrix2;
+    /// 
+    /// let actual = Matrix2::make(1.0, 2.0, 3.0, 4.0);
+    /// assert_eq!(actual.m12(), 3.0);
+    /// ```
+    #[inline]
+    pub fn m12(&self) -> f32 {
+        self.m[2]
+    }
+
+    /// Gets the value for the m22 element
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Matrix2;
+    /// 
+    /// let actual = Matrix2::make(1.0, 2.0, 3.0, 4.0);
+    /// assert_eq!(actual.m22(), 4.0);
+    /// ```
+    #[inline]
+    pub fn m22(&self) -> f32 {
+        self.m[3]
+    }
+
+    /// Sets the value for the m11 element
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Matrix2;
+    /// 
+    /// let mut actual = Matrix2::make(0.0, 0.0, 0.0, 0.0);
+    /// actual.set_m11(1.0);
+    /// let expected = [1.0, 0.0, 0.0, 0.0];
+    /// assert_eq!(actual.m, expected);
+    /// ```
+    #[inline]
+    pub fn set_m11(&mut self, v: f32) {
+        self.m[0] = v;
+    }
+
+    /// Sets the value for the m21 element
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Matrix2;
+    /// 
+    /// let mut actual = Matrix2::make(0.0, 0.0, 0.0, 0.0);
+    /// actual.set_m21(1.0);
+    /// let expected = [0.0, 1.0, 0.0, 0.0];
+    /// assert_eq!(actual.m, expected);
+    /// ```
+    #[inline]
+    pub fn set_m21(&mut self, v: f32) {
+        self.m[1] = v;
+    }
+
+    /// Sets the value for the m12 element
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Matrix2;
+    /// 
+    /// let mut actual = Matrix2::make(0.0, 0.0, 0.0, 0.0);
+    /// actual.set_m12(1.0);
+    /// let expected = [0.0, 0.0, 1.0, 0.0];
+    /// assert_eq!(actual.m, expected);
+    /// ```
+    #[inline]
+    pub fn set_m12(&mut self, v: f32) {
+        self.m[2] = v;
+    }
+
+    /// Sets the value for the m22 element
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Matrix2;
+    /// 
+    /// let mut actual = Matrix2::make(0.0, 0.0, 0.0, 0.0);
+    /// actual.set_m22(1.0);
+    /// let expected = [0.0, 0.0, 0.0, 1.0];
+    /// assert_eq!(actual.m, expected);
+    /// ```
+    #[inline]
+    pub fn set_m22(&mut self, v: f32) {
+        self.m[3] = v;
+    }
+
+    /// Sets the internal contents of the matrix
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Matrix2;
+    /// 
+    /// let mut actual = Matrix2::new();
+    /// actual.set(1.0, 2.0, 3.0, 4.0);
+    /// let expected = Matrix2::make(1.0, 2.0, 3.0, 4.0);
+    /// assert_eq!(actual, expected);
+    /// ```
+    #[inline]
+    pub fn set(&mut self, m11: f32, m21: f32, m12: f32, m22: f32) {
+        self.set_m11(m11);
+        self.set_m21(m21);
+        self.set_m12(m12);
+        self.set_m22(m22);
+    }
+
+    /// Gets a row of the matrix, counting from 0
+    ///
+    /// Panics if `i` is out of range.
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Matrix2;
+    /// use vex::Vector2;
+    /// 
+    /// let m = Matrix2::make(1.0, 2.0, 3.0, 4.0);
+    /// assert_eq!(m.row(0), Vector2::make(1.0, 3.0));
+    /// ```
+    #[inline]
+    pub fn row(&self, i: usize) -> Vector2 {
+        assert!(i < 2, "Invalid row for Matrix2: {}", i);
+        Vector2::make(self.m[i], self.m[2 + i])
+    }
+
+    /// Gets a column of the matrix, counting from 0
+    ///
+    /// Panics if `i` is out of range.
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Matrix2;
+    /// use vex::Vector2;
+    /// 
+    /// let m = Matrix2::make(1.0, 2.0, 3.0, 4.0);
+    /// assert_eq!(m.col(0), Vector2::make(1.0, 2.0));
+    /// ```
+    #[inline]
+    pub fn col(&self, i: usize) -> Vector2 {
+        assert!(i < 2, "Invalid column for Matrix2: {}", i);
+        Vector2::make(self.m[i * 2], self.m[i * 2 + 1])
+    }
+
+    /// Sets a row of the matrix, counting from 0
+    ///
+    /// Panics if `i` is out of range.
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Matrix2;
+    /// use vex::Vector2;
+    /// 
+    /// let mut actual = Matrix2::new();
+    /// actual.set_row(1, &Vector2::make(1.0, 2.0));
+    /// assert_eq!(actual.row(1), Vector2::make(1.0, 2.0));
+    /// ```
+    #[inline]
+    pub fn set_row(&mut self, i: usize, v: &Vector2) {
+        assert!(i < 2, "Invalid row for Matrix2: {}", i);
+        self.m[i] = v.x;
+        self.m[2 + i] = v.y;
+    }
+
+    /// Sets a column of the matrix, counting from 0
+    ///
+    /// Panics if `i` is out of range.
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Matrix2;
+    /// use vex::Vector2;
+    /// 
+    /// let mut actual = Matrix2::new();
+    /// actual.set_col(1, &Vector2::make(1.0, 2.0));
+    /// assert_eq!(actual.col(1), Vector2::make(1.0, 2.0));
+    /// ```
+    #[inline]
+    pub fn set_col(&mut self, i: usize, v: &Vector2) {
+        assert!(i < 2, "Invalid column for Matrix2: {}", i);
+        self.m[i * 2] = v.x;
+        self.m[i * 2 + 1] = v.y;
+    }
+
+    /// Iterates over the rows of the matrix from first to last
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Matrix2;
+    /// use vex::Vector2;
+    /// 
+    /// let m = Matrix2::make(1.0, 2.0, 3.0, 4.0);
+    /// let rows: Vec<Vector2> = m.rows().collect();
+    /// assert_eq!(rows.len(), 2);
+    /// assert_eq!(rows[1], Vector2::make(2.0, 4.0));
+    /// ```
+    #[inline]
+    pub fn rows(&self) -> impl Iterator<Item = Vector2> + '_ {
+        (0..2).map(move |i| self.row(i))
+    }
+
+    /// Iterates over the columns of the matrix from first to last
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Matrix2;
+    /// use vex::Vector2;
+    /// 
+    /// let m = Matrix2::make(1.0, 2.0, 3.0, 4.0);
+    /// let cols: Vec<Vector2> = m.cols().collect();
+    /// assert_eq!(cols.len(), 2);
+    /// assert_eq!(cols[1], Vector2::make(3.0, 4.0));
+    /// ```
+    #[inline]
+    pub fn cols(&self) -> impl Iterator<Item = Vector2> + '_ {
+        (0..2).map(move |i| self.col(i))
+    }
+
+    /// Iterates over the elements of the matrix in column-major order
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Matrix2;
+    /// 
+    /// let m = Matrix2::make(1.0, 2.0, 3.0, 4.0);
+    /// assert_eq!(m.iter().sum::<f32>(), 10.0);
+    /// ```
+    #[inline]
+    pub fn iter(&self) -> slice::Iter<'_, f32> {
+        self.m.iter()
+    }
+
+    /// Iterates mutably over the elements of the matrix in column-major order
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Matrix2;
+    /// 
+    /// let mut actual = Matrix2::new();
+    /// for element in actual.iter_mut() {
+    ///     *element += 1.0;
+    /// }
+    ///
+    /// assert_eq!(actual.m[0], 2.0);
+    /// assert_eq!(actual.m[1], 1.0);
+    /// ```
+    #[inline]
+    pub fn iter_mut(&mut self) -> slice::IterMut<'_, f32> {
+        self.m.iter_mut()
+    }
+
+    /// Transposes the matrix's elements
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Matrix2;
+    /// 
+    /// let mut actual = Matrix2::make(1.0, 2.0, 3.0, 4.0);
+    /// actual.transpose();
+    /// let expected = Matrix2::make(1.0, 3.0, 2.0, 4.0);
+    /// assert_eq!(actual, expected);
+    /// ```
+    #[inline]
+    pub fn transpose(&mut self) {
+        self.m.swap(1, 2);
+    }
+
+    /// Find the matrix's determinant
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Matrix2;
+    /// 
+    /// let actual = Matrix2::make(1.0, 2.0, 3.0, 4.0).determinant();
+    /// assert_eq!(actual, -2.0);
+    /// ```
+    #[inline]
+    pub fn determinant(&self) -> f32 {
+        self.m11() * self.m22() - self.m12() * self.m21()
+    }
+
+    /// Find the sum of the elements along the main diagonal
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Matrix2;
+    /// 
+    /// let actual = Matrix2::make(1.0, 2.0, 3.0, 4.0).trace();
+    /// assert_eq!(actual, 5.0);
+    /// ```
+    #[inline]
+    pub fn trace(&self) -> f32 {
+        self.m[0] + self.m[3]
+    }
+
+    /// Find the inverse of the matrix, or `None` if it's singular
+    ///
+    /// Only a determinant of exactly zero counts as singular, so a matrix that is merely close to
+    /// singular still inverts, to a result with huge elements that amplifies any rounding error.
+    /// Use [`try_inverse_with`](#method.try_inverse_with) to reject those as well.
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Matrix2;
+    ///
+    /// let actual = Matrix2::make(1.0, 2.0, 3.0, 4.0).try_inverse();
+    /// let expected = Matrix2::make(-2.0, 1.0, 1.5, -0.5);
+    /// assert_eq!(actual, Some(expected));
+    /// assert_eq!(Matrix2::make(1.0, 2.0, 2.0, 4.0).try_inverse(), None);
+    /// ```
+    #[inline]
+    pub fn try_inverse(&self) -> Option<Matrix2> {
+        let det = self.determinant();
+        if det == 0.0 {
+            return None;
+        }
+
+        let inv_det = 1.0 / det;
+        let m11 = self.m22() * inv_det;
+        let m21 = -self.m21() * inv_det;
+        let m12 = -self.m12() * inv_det;
+        let m22 = self.m11() * inv_det;
+        Some(Matrix2::make(m11, m21, m12, m22))
+    }
+
+    /// Find the inverse of the matrix, or `None` if it's singular or too close to singular under
+    /// an epsilon policy
+    ///
+    /// The determinant alone can't say how close a matrix is to singular, since it scales with the
+    /// elements: a uniform scale by 0.01 inverts exactly, despite its tiny determinant. Instead,
+    /// the determinant is divided by the product of the column lengths, which measures closeness
+    /// to singular independently of scale, from 0 for singular matrices up to 1 for orthogonal
+    /// ones. The matrix is rejected when that measure is within `epsilon.absolute` of zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Epsilon;
+    /// use vex::Matrix2;
+    ///
+    /// let m = Matrix2::make(1.0, 1.0, 1.0, 1.0000001);
+    /// assert!(m.try_inverse().is_some());
+    /// assert_eq!(m.try_inverse_with(&Epsilon::DEFAULT), None);
+    ///
+    /// let m = Matrix2::new() * 0.001;
+    /// assert_eq!(m.try_inverse_with(&Epsilon::DEFAULT), m.try_inverse());
+    /// ```
+    #[inline]
+    pub fn try_inverse_with(&self, epsilon: &Epsilon) -> Option<Matrix2> {
+        let volume: f32 = self.cols().map(|col| col.mag()).product();
+        if volume == 0.0 || epsilon.is_zero(self.determinant() / volume) {
+            return None;
+        }
+
+        self.try_inverse()
+    }
+
+    /// Inverses the matrix, returning false and leaving it unchanged if it's singular
+    ///
+    /// # Examples
+    /// ```
+    /// #![allow(deprecated)]
+    /// use vex::Matrix2;
+    /// 
+    /// let mut actual = Matrix2::make(1.0, 2.0, 3.0, 4.0);
+    /// actual.inverse();
+    /// let expected = Matrix2::make(-2.0, 1.0, 1.5, -0.5);
+    /// assert_eq!(actual, expected);
+    /// ```
+    #[deprecated(since = "2.2.0", note = "use `try_inverse` instead")]
+    #[inline]
+    pub fn inverse(&mut self) -> bool {
+        match self.try_inverse() {
+            Some(inverse) => {
+                *self = inverse;
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Negates the matrix's elements
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Matrix2;
+    /// 
+    /// let mut actual = Matrix2::make(1.0, 2.0, 3.0, 4.0);
+    /// actual.negate();
+    /// let expected = Matrix2::make(-1.0, -2.0, -3.0, -4.0);
+    /// assert_eq!(actual, expected);
+    /// ```
+    #[inline]
+    pub fn negate(&mut self) {
+        for value in self.m.iter_mut() {
+            *value = -*value;
+        }
+    }
+
+    /// Find the matrix with its elements negated
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Matrix2;
+    /// 
+    /// let actual = Matrix2::make(1.0, 2.0, 3.0, 4.0).negated();
+    /// let expected = Matrix2::make(-1.0, -2.0, -3.0, -4.0);
+    /// assert_eq!(actual, expected);
+    /// ```
+    #[inline]
+    pub fn negated(&self) -> Matrix2 {
+        -*self
+    }
+
+    /// Transposes the matrix's elements in place
+    ///
+    /// This is the same as `transpose`, named to pair with the value-returning variants.
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Matrix2;
+    /// 
+    /// let mut actual = Matrix2::make(1.0, 2.0, 3.0, 4.0);
+    /// actual.transpose_in_place();
+    /// let mut expected = Matrix2::make(1.0, 2.0, 3.0, 4.0);
+    /// expected.transpose();
+    /// assert_eq!(actual, expected);
+    /// ```
+    #[inline]
+    pub fn transpose_in_place(&mut self) {
+        self.transpose();
+    }
+
+    /// Find the transpose of the matrix
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Matrix2;
+    /// 
+    /// let actual = Matrix2::make(1.0, 2.0, 3.0, 4.0).transposed();
+    /// let expected = Matrix2::make(1.0, 3.0, 2.0, 4.0);
+    /// assert_eq!(actual, expected);
+    /// ```
+    #[inline]
+    pub fn transposed(&self) -> Matrix2 {
+        let mut result = *self;
+        result.transpose();
+        result
+    }
+
+    /// Makes the columns orthonormal using Gram-Schmidt
+    ///
+    /// The first column keeps its direction. A zero or dependent column is replaced by one
+    /// perpendicular to the columns before it.
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Epsilon;
+    /// use vex::Matrix2;
+    /// 
+    /// let mut actual = Matrix2::make(1.01, 0.02, -0.01, 0.99);
+    /// actual.orthonormalize();
+    /// assert!(actual.is_orthogonal(&Epsilon::DEFAULT));
+    /// ```
+    pub fn orthonormalize(&mut self) {
+        let mut x = self.col(0).normalized();
+        if x == Vector2::new() {
+            x = Vector2::make(1.0, 0.0);
+        }
+
+        let y = self.col(1);
+        let mut y = (y - x * Vector2::dot(&x, &y)).normalized();
+        if y == Vector2::new() {
+            y = Vector2::make(-x.y, x.x);
+        }
+
+        self.set_col(0, &x);
+        self.set_col(1, &y);
+    }
+
+    /// Find the matrix with its columns made orthonormal using Gram-Schmidt
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Epsilon;
+    /// use vex::Matrix2;
+    /// 
+    /// let actual = Matrix2::new().orthonormalized();
+    /// assert!(actual.is_identity(&Epsilon::DEFAULT));
+    /// ```
+    #[inline]
+    pub fn orthonormalized(&self) -> Matrix2 {
+        let mut result = *self;
+        result.orthonormalize();
+        result
+    }
+
+    /// Find the matrix with a function applied to each element
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Matrix2;
+    /// 
+    /// let actual = Matrix2::make(1.0, -2.0, 3.0, -4.0).map(f32::abs);
+    /// assert_eq!(actual.m, [1.0, 2.0, 3.0, 4.0]);
+    /// ```
+    #[inline]
+    pub fn map<F: Fn(f32) -> f32>(&self, f: F) -> Matrix2 {
+        Matrix2 { m: self.m.map(f) }
+    }
+
+    /// Linearly interpolate between two matrices with either a scalar factor or one per element
+    ///
+    /// Matches GLSL's `mix`, giving `a * (1 - t) + b * t` for each element, so a factor of 1
+    /// returns exactly `b`.
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Matrix2;
+    /// 
+    /// let a = Matrix2::make(0.0, 10.0, -4.0, 1.0);
+    /// let b = Matrix2::make(4.0, 20.0, 4.0, 3.0);
+    /// assert_eq!(Matrix2::mix(&a, &b, 0.5), Matrix2::make(2.0, 15.0, 0.0, 2.0));
+    ///
+    /// let t = Matrix2::make(0.0, 1.0, 0.25, 0.5);
+    /// assert_eq!(Matrix2::mix(&a, &b, t), Matrix2::make(0.0, 20.0, -2.0, 2.0));
+    /// ```
+    #[inline]
+    pub fn mix<T: common::MixFactor<Matrix2>>(a: &Matrix2, b: &Matrix2, t: T) -> Matrix2 {
+        let t = t.spread();
+        let mut m = [0.0; 4];
+        for (i, element) in m.iter_mut().enumerate() {
+            *element = a.m[i] * (1.0 - t.m[i]) + b.m[i] * t.m[i];
+        }
+
+        Matrix2 { m }
+    }
+
+    /// Determine whether or not all elements of the matrices are within `epsilon` of each other
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Matrix2;
+    /// 
+    /// let a = Matrix2::make(1.0, 2.0, 3.0, 4.0);
+    /// assert!(a.approx_eq(&Matrix2::make(1.0000001, 2.0, 3.0, 4.0), 0.000001));
+    /// assert!(!a.approx_eq(&Matrix2::make(1.1, 2.0, 3.0, 4.0), 0.000001));
+    /// ```
+    #[inline]
+    pub fn approx_eq(&self, other: &Matrix2, epsilon: f32) -> bool {
+        for i in 0..4 {
+            if (self.m[i] - other.m[i]).abs() > epsilon {
+                return false;
+            }
+        }
+
+        true
+    }
+
+    /// Determine whether or not all elements of the matrices are equal under an epsilon policy
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Epsilon;
+    /// use vex::Matrix2;
+    /// 
+    /// let a = Matrix2::make(1000000.0, 2.0, 3.0, 4.0);
+    /// assert!(a.approx_eq_with(&Matrix2::make(1000000.06, 2.0, 3.0, 4.0), &Epsilon::DEFAULT));
+    /// assert!(!a.approx_eq_with(&Matrix2::make(1000000.0, 2.1, 3.0, 4.0), &Epsilon::DEFAULT));
+    /// ```
+    #[inline]
+    pub fn approx_eq_with(&self, other: &Matrix2, epsilon: &Epsilon) -> bool {
+        let a: [f32; 4] = (*self).into();
+        let b: [f32; 4] = (*other).into();
+        epsilon.all_approx_eq(&a, &b)
+    }
+
+    /// Determine whether or not the matrix is too close to singular to invert under an epsilon
+    /// policy
     ///
     /// # Examples
     /// ```
+    /// use vex::Epsilon;
     /// use vex::Matrix2;
     /// 
-    /// let actual = Matrix2::make(1.0, 2.0, 3.0, 4.0);
-    /// assert_eq!(actual.m12(), 3.0);
+    /// assert!(Matrix2::make(1.0, 2.0, 2.0, 4.0000005).is_singular(&Epsilon::DEFAULT));
+    /// assert!(!Matrix2::make(1.0, 2.0, 2.0, 4.1).is_singular(&Epsilon::DEFAULT));
+    /// assert!(Matrix2::make(1.0, 2.0, 2.0, 4.1).is_singular(&Epsilon::DEFAULT.with_absolute(0.1)));
     /// ```
     #[inline]
-    pub fn m12(&self) -> f32 {
-        self.m[2]
+    pub fn is_singular(&self, epsilon: &Epsilon) -> bool {
+        epsilon.is_zero(self.determinant())
     }
 
-    /// Gets the value for the m22 element
+    /// Find the number of linearly independent columns under an epsilon policy
+    ///
+    /// Pivots within the absolute tolerance count as zero, so nearly dependent columns lower the
+    /// rank.
     ///
     /// # Examples
     /// ```
+    /// use vex::Epsilon;
     /// use vex::Matrix2;
     /// 
-    /// let actual = Matrix2::make(1.0, 2.0, 3.0, 4.0);
-    /// assert_eq!(actual.m22(), 4.0);
+    /// assert_eq!(Matrix2::make(1.0, 2.0, 3.0, 4.0).rank(&Epsilon::DEFAULT), 2);
+    /// assert_eq!(Matrix2::make(1.0, 2.0, 2.0, 4.0).rank(&Epsilon::DEFAULT), 1);
+    /// assert_eq!(Matrix2::make(0.0, 0.0, 0.0, 0.0).rank(&Epsilon::DEFAULT), 0);
     /// ```
     #[inline]
-    pub fn m22(&self) -> f32 {
-        self.m[3]
+    pub fn rank(&self, epsilon: &Epsilon) -> u32 {
+        let mut m = self.m;
+        epsilon.rank(&mut m, 2)
     }
 
-    /// Sets the value for the m11 element
+    /// Determine whether or not the matrix is the identity under an epsilon policy
     ///
     /// # Examples
     /// ```
+    /// use vex::Epsilon;
     /// use vex::Matrix2;
     /// 
-    /// let mut actual = Matrix2::make(0.0, 0.0, 0.0, 0.0);
-    /// actual.set_m11(1.0);
-    /// let expected = [1.0, 0.0, 0.0, 0.0];
-    /// assert_eq!(actual.m, expected);
+    /// let mut m = Matrix2::new();
+    /// assert!(m.is_identity(&Epsilon::DEFAULT));
+    /// m.m[1] = 0.001;
+    /// assert!(!m.is_identity(&Epsilon::DEFAULT));
+    /// assert!(m.is_identity(&Epsilon::DEFAULT.with_absolute(0.01)));
     /// ```
     #[inline]
-    pub fn set_m11(&mut self, v: f32) {
-        self.m[0] = v;
+    pub fn is_identity(&self, epsilon: &Epsilon) -> bool {
+        self.approx_eq_with(&Matrix2::IDENTITY, epsilon)
     }
 
-    /// Sets the value for the m21 element
+    /// Determine whether or not the columns are orthonormal under an epsilon policy
+    ///
+    /// Orthonormal matrices are pure rotations or reflections, so their inverse is their transpose.
     ///
     /// # Examples
     /// ```
+    /// use vex::Epsilon;
     /// use vex::Matrix2;
     /// 
-    /// let mut actual = Matrix2::make(0.0, 0.0, 0.0, 0.0);
-    /// actual.set_m21(1.0);
-    /// let expected = [0.0, 1.0, 0.0, 0.0];
-    /// assert_eq!(actual.m, expected);
-    /// ```
-    #[inline]
-    pub fn set_m21(&mut self, v: f32) {
-        self.m[1] = v;
+    /// assert!(Matrix2::rotate(0.5).is_orthogonal(&Epsilon::DEFAULT));
+    /// assert!(!Matrix2::scale(2.0, 1.0).is_orthogonal(&Epsilon::DEFAULT));
+    /// ```
+    pub fn is_orthogonal(&self, epsilon: &Epsilon) -> bool {
+        let columns = [self.col(0), self.col(1)];
+        for i in 0..2 {
+            for j in i..2 {
+                let expected = if i == j { 1.0 } else { 0.0 };
+                if !epsilon.approx_eq(Vector2::dot(&columns[i], &columns[j]), expected) {
+                    return false;
+                }
+            }
+        }
+
+        true
     }
 
-    /// Sets the value for the m12 element
+    /// Determine whether or not all elements of the matrix are valid
     ///
     /// # Examples
     /// ```
     /// use vex::Matrix2;
     /// 
-    /// let mut actual = Matrix2::make(0.0, 0.0, 0.0, 0.0);
-    /// actual.set_m12(1.0);
-    /// let expected = [0.0, 0.0, 1.0, 0.0];
-    /// assert_eq!(actual.m, expected);
+    /// let actual = Matrix2::make(1.0, 2.0, 3.0, 4.0);
+    /// assert!(actual.is_valid());
     /// ```
     #[inline]
-    pub fn set_m12(&mut self, v: f32) {
-        self.m[2] = v;
+    pub fn is_valid(&self) -> bool {
+        for i in 0..4 {
+            if !common::is_valid(self.m[i]) {
+                return false;
+            }
+        }
+
+        true
     }
+}
 
-    /// Sets the value for the m22 element
+impl Default for Matrix2 {
+    /// Creates an identity matrix
     ///
     /// # Examples
     /// ```
     /// use vex::Matrix2;
-    /// 
-    /// let mut actual = Matrix2::make(0.0, 0.0, 0.0, 0.0);
-    /// actual.set_m22(1.0);
-    /// let expected = [0.0, 0.0, 0.0, 1.0];
-    /// assert_eq!(actual.m, expected);
+    ///
+    /// assert_eq!(Matrix2::default(), Matrix2::new());
     /// ```
     #[inline]
-    pub fn set_m22(&mut self, v: f32) {
-        self.m[3] = v;
+    fn default() -> Matrix2 {
+        Matrix2::new()
     }
+}
 
-    /// Sets the internal contents of the matrix
+impl From<[f32; 4]> for Matrix2 {
+    /// Creates a Matrix2 from an array of elements in column-major order
     ///
     /// # Examples
     /// ```
     /// use vex::Matrix2;
     /// 
-    /// let mut actual = Matrix2::new();
-    /// actual.set(1.0, 2.0, 3.0, 4.0);
+    /// let actual = Matrix2::from([1.0, 2.0, 3.0, 4.0]);
     /// let expected = Matrix2::make(1.0, 2.0, 3.0, 4.0);
     /// assert_eq!(actual, expected);
     /// ```
     #[inline]
-    pub fn set(&mut self, m11: f32, m21: f32, m12: f32, m22: f32) {
-        self.set_m11(m11);
-        self.set_m21(m21);
-        self.set_m12(m12);
-        self.set_m22(m22);
+    fn from(item: [f32; 4]) -> Matrix2 {
+        Matrix2 { m: item }
     }
+}
 
-    /// Transposes the matrix's elements
+impl From<Matrix2> for [f32; 4] {
+    /// Creates an array from the elements of a Matrix2 in column-major order
     ///
     /// # Examples
     /// ```
     /// use vex::Matrix2;
     /// 
-    /// let mut actual = Matrix2::make(1.0, 2.0, 3.0, 4.0);
-    /// actual.transpose();
-    /// let expected = Matrix2::make(1.0, 3.0, 2.0, 4.0);
+    /// let actual: [f32; 4] = Matrix2::make(1.0, 2.0, 3.0, 4.0).into();
+    /// let expected = [1.0, 2.0, 3.0, 4.0];
     /// assert_eq!(actual, expected);
     /// ```
     #[inline]
-    pub fn transpose(&mut self) {
-        let temp = self.m[1];
-        self.m[1] = self.m[2];
-        self.m[2] = temp;
+    fn from(item: Matrix2) -> [f32; 4] {
+        item.m
     }
+}
 
-    /// Find the matrix's determinant
+impl AsRef<[f32]> for Matrix2 {
+    /// Gets the elements as a slice in column-major order
     ///
     /// # Examples
     /// ```
     /// use vex::Matrix2;
     /// 
-    /// let actual = Matrix2::make(1.0, 2.0, 3.0, 4.0).determinant();
-    /// assert_eq!(actual, -2.0);
+    /// let v = Matrix2::make(1.0, 2.0, 3.0, 4.0);
+    /// let actual: &[f32] = v.as_ref();
+    /// assert_eq!(actual, &[1.0, 2.0, 3.0, 4.0][..]);
     /// ```
     #[inline]
-    pub fn determinant(&self) -> f32 {
-        self.m11() * self.m22() - self.m12() * self.m21()
+    fn as_ref(&self) -> &[f32] {
+        &self.m
     }
+}
 
-    /// Inverses the matrix
+impl AsMut<[f32]> for Matrix2 {
+    /// Gets the elements as a mutable slice in column-major order
     ///
     /// # Examples
     /// ```
     /// use vex::Matrix2;
     /// 
-    /// let mut actual = Matrix2::make(1.0, 2.0, 3.0, 4.0);
-    /// actual.inverse();
-    /// let expected = Matrix2::make(-2.0, 1.0, 1.5, -0.5);
-    /// assert_eq!(actual, expected);
+    /// let mut v = Matrix2::make(1.0, 2.0, 3.0, 4.0);
+    /// v.as_mut()[0] = 5.0;
+    /// assert_eq!(v.as_ref()[0], 5.0);
     /// ```
     #[inline]
-    pub fn inverse(&mut self) -> bool {
-        let det = self.determinant();
-        if det == 0.0 {
-            return false;
-        }
+    fn as_mut(&mut self) -> &mut [f32] {
+        &mut self.m
+    }
+}
 
-        let inv_det = 1.0 / det;
-        let m11 = self.m22() * inv_det;
-        let m21 = -self.m21() * inv_det;
-        let m12 = -self.m12() * inv_det;
-        let m22 = self.m11() * inv_det;
+impl Index<(usize, usize)> for Matrix2 {
+    type Output = f32;
 
-        self.set_m11(m11);
-        self.set_m21(m21);
-        self.set_m12(m12);
-        self.set_m22(m22);
-        true
+    /// Looks up an element by its row and column, counting from 0
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Matrix2;
+    /// 
+    /// let m = Matrix2::make(1.0, 2.0, 3.0, 4.0);
+    /// assert_eq!(m[(1, 0)], 2.0);
+    /// assert_eq!(m[(0, 1)], 3.0);
+    /// ```
+    #[inline]
+    fn index(&self, index: (usize, usize)) -> &f32 {
+        let (row, col) = index;
+        assert!(row < 2 && col < 2, "Invalid index for Matrix2: {:?}", index);
+        &self.m[col * 2 + row]
     }
+}
 
-    /// Determine whether or not all elements of the matrix are valid
+impl IndexMut<(usize, usize)> for Matrix2 {
+    /// Mutate an element by its row and column, counting from 0
     ///
     /// # Examples
     /// ```
     /// use vex::Matrix2;
     /// 
-    /// let actual = Matrix2::make(1.0, 2.0, 3.0, 4.0);
-    /// assert!(actual.is_valid());
+    /// let mut m = Matrix2::new();
+    /// m[(0, 1)] = 5.0;
+    /// assert_eq!(m.m12(), 5.0);
     /// ```
     #[inline]
-    pub fn is_valid(&self) -> bool {
-        for i in 0..4 {
-            if !common::is_valid(self.m[i]) {
-                return false;
-            }
-        }
-
-        true
+    fn index_mut(&mut self, index: (usize, usize)) -> &mut f32 {
+        let (row, col) = index;
+        assert!(row < 2 && col < 2, "Invalid index for Matrix2: {:?}", index);
+        &mut self.m[col * 2 + row]
     }
 }
 
@@ -303,10 +988,8 @@ impl Neg for Matrix2 {
     fn neg(self) -> Matrix2 {
         let mut m = [0.0; 4];
 
-        unsafe {
-            for (i, elem) in self.m.iter().enumerate() {
-                m[i] = -*elem;
-            }
+        for (i, elem) in self.m.iter().enumerate() {
+            m[i] = -*elem;
         }
 
         Matrix2 { m }
@@ -330,10 +1013,33 @@ impl Add<f32> for Matrix2 {
     fn add(self, _rhs: f32) -> Matrix2 {
         let mut mat = Matrix2::new();
 
-        unsafe {
-            for (i, elem) in self.m.iter().enumerate() {
-                mat.m[i] = *elem + _rhs;
-            }
+        for (i, elem) in self.m.iter().enumerate() {
+            mat.m[i] = *elem + _rhs;
+        }
+
+        mat
+    }
+}
+
+impl Add<Matrix2> for f32 {
+    type Output = Matrix2;
+
+    /// Find the resulting matrix by adding a matrix's elements to a scalar
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Matrix2;
+    /// 
+    /// let actual = 1.0 + Matrix2::make(1.0, 2.0, 3.0, 4.0);
+    /// let expected = Matrix2::make(2.0, 3.0, 4.0, 5.0);
+    /// assert_eq!(actual, expected);
+    /// ```
+    #[inline]
+    fn add(self, _rhs: Matrix2) -> Matrix2 {
+        let mut mat = Matrix2::new();
+
+        for (i, elem) in _rhs.m.iter().enumerate() {
+            mat.m[i] = self + *elem;
         }
 
         mat
@@ -359,10 +1065,8 @@ impl Add<Matrix2> for Matrix2 {
     fn add(self, _rhs: Matrix2) -> Matrix2 {
         let mut mat = Matrix2::new();
 
-        unsafe {
-            for (i, elem) in self.m.iter().enumerate() {
-                mat.m[i] = *elem + _rhs.m[i];
-            }
+        for (i, elem) in self.m.iter().enumerate() {
+            mat.m[i] = *elem + _rhs.m[i];
         }
 
         mat
@@ -383,10 +1087,8 @@ impl AddAssign<f32> for Matrix2 {
     /// ```
     #[inline]
     fn add_assign(&mut self, _rhs: f32) {
-        unsafe {
-            for elem in self.m.iter_mut() {
-                *elem += _rhs;
-            }
+        for elem in self.m.iter_mut() {
+            *elem += _rhs;
         }
     }
 }
@@ -405,10 +1107,8 @@ impl AddAssign<Matrix2> for Matrix2 {
     /// ```
     #[inline]
     fn add_assign(&mut self, _rhs: Matrix2) {
-        unsafe {
-            for (i, elem) in self.m.iter_mut().enumerate() {
-                *elem += _rhs.m[i];
-            }
+        for (i, elem) in self.m.iter_mut().enumerate() {
+            *elem += _rhs.m[i];
         }
     }
 }
@@ -430,10 +1130,33 @@ impl Sub<f32> for Matrix2 {
     fn sub(self, _rhs: f32) -> Matrix2 {
         let mut mat = Matrix2::new();
 
-        unsafe {
-            for (i, elem) in self.m.iter().enumerate() {
-                mat.m[i] = *elem - _rhs;
-            }
+        for (i, elem) in self.m.iter().enumerate() {
+            mat.m[i] = *elem - _rhs;
+        }
+
+        mat
+    }
+}
+
+impl Sub<Matrix2> for f32 {
+    type Output = Matrix2;
+
+    /// Find the resulting matrix by subtracting a matrix's elements from a scalar
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Matrix2;
+    /// 
+    /// let actual = 10.0 - Matrix2::make(1.0, 2.0, 3.0, 4.0);
+    /// let expected = Matrix2::make(9.0, 8.0, 7.0, 6.0);
+    /// assert_eq!(actual, expected);
+    /// ```
+    #[inline]
+    fn sub(self, _rhs: Matrix2) -> Matrix2 {
+        let mut mat = Matrix2::new();
+
+        for (i, elem) in _rhs.m.iter().enumerate() {
+            mat.m[i] = self - *elem;
         }
 
         mat
@@ -459,10 +1182,8 @@ impl Sub<Matrix2> for Matrix2 {
     fn sub(self, _rhs: Matrix2) -> Matrix2 {
         let mut mat = Matrix2::new();
 
-        unsafe {
-            for (i, elem) in self.m.iter().enumerate() {
-                mat.m[i] = *elem - _rhs.m[i];
-            }
+        for (i, elem) in self.m.iter().enumerate() {
+            mat.m[i] = *elem - _rhs.m[i];
         }
 
         mat
@@ -483,10 +1204,8 @@ impl SubAssign<f32> for Matrix2 {
     /// ```
     #[inline]
     fn sub_assign(&mut self, _rhs: f32) {
-        unsafe {
-            for elem in self.m.iter_mut() {
-                *elem -= _rhs;
-            }
+        for elem in self.m.iter_mut() {
+            *elem -= _rhs;
         }
     }
 }
@@ -504,10 +1223,8 @@ impl SubAssign<Matrix2> for Matrix2 {
     /// ```
     #[inline]
     fn sub_assign(&mut self, _rhs: Matrix2) {
-        unsafe {
-            for (i, elem) in self.m.iter_mut().enumerate() {
-                *elem -= _rhs.m[i];
-            }
+        for (i, elem) in self.m.iter_mut().enumerate() {
+            *elem -= _rhs.m[i];
         }
     }
 }
@@ -529,10 +1246,33 @@ impl Mul<f32> for Matrix2 {
     fn mul(self, _rhs: f32) -> Matrix2 {
         let mut mat = Matrix2::new();
 
-        unsafe {
-            for (i, elem) in self.m.iter().enumerate() {
-                mat.m[i] = *elem * _rhs;
-            }
+        for (i, elem) in self.m.iter().enumerate() {
+            mat.m[i] = *elem * _rhs;
+        }
+
+        mat
+    }
+}
+
+impl Mul<Matrix2> for f32 {
+    type Output = Matrix2;
+
+    /// Find the resulting matrix by multiplying a scalar by a matrix's elements
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Matrix2;
+    /// 
+    /// let actual = 2.0 * Matrix2::make(1.0, 2.0, 3.0, 4.0);
+    /// let expected = Matrix2::make(2.0, 4.0, 6.0, 8.0);
+    /// assert_eq!(actual, expected);
+    /// ```
+    #[inline]
+    fn mul(self, _rhs: Matrix2) -> Matrix2 {
+        let mut mat = Matrix2::new();
+
+        for (i, elem) in _rhs.m.iter().enumerate() {
+            mat.m[i] = self * *elem;
         }
 
         mat
@@ -578,10 +1318,8 @@ impl MulAssign<f32> for Matrix2 {
     /// ```
     #[inline]
     fn mul_assign(&mut self, _rhs: f32) {
-        unsafe {
-            for elem in self.m.iter_mut() {
-                *elem *= _rhs;
-            }
+        for elem in self.m.iter_mut() {
+            *elem *= _rhs;
         }
     }
 }
@@ -622,10 +1360,8 @@ impl Div<f32> for Matrix2 {
     fn div(self, _rhs: f32) -> Matrix2 {
         let mut mat = Matrix2::new();
 
-        unsafe {
-            for (i, elem) in self.m.iter().enumerate() {
-                mat.m[i] = *elem / _rhs;
-            }
+        for (i, elem) in self.m.iter().enumerate() {
+            mat.m[i] = *elem / _rhs;
         }
 
         mat
@@ -646,10 +1382,8 @@ impl DivAssign<f32> for Matrix2 {
     /// ```
     #[inline]
     fn div_assign(&mut self, _rhs: f32) {
-        unsafe {
-            for elem in self.m.iter_mut() {
-                *elem /= _rhs;
-            }
+        for elem in self.m.iter_mut() {
+            *elem /= _rhs;
         }
     }
 }
@@ -665,11 +1399,9 @@ impl cmp::PartialEq for Matrix2 {
     /// ```
     #[inline]
     fn eq(&self, _rhs: &Matrix2) -> bool {
-        unsafe {
-            for (i, elem) in self.m.iter().enumerate() {
-                if *elem != _rhs.m[i] {
-                    return false;
-                }
+        for (i, elem) in self.m.iter().enumerate() {
+            if *elem != _rhs.m[i] {
+                return false;
             }
         }
 
@@ -678,16 +1410,70 @@ impl cmp::PartialEq for Matrix2 {
 }
 
 impl Display for Matrix2 {
+    /// Formats the matrix as a list of its rows, with the formatter's flags applied to each element
+    ///
+    /// The alternate flag lays the rows out as a grid instead, one per line, with each column
+    /// padded to its widest element.
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Matrix2;
+    ///
+    /// let m = Matrix2::make(1.0, 2.0, -3.5, 40.0);
+    /// assert_eq!(format!("{}", m), "[[1, -3.5], [2, 40]]");
+    /// assert_eq!(format!("{:.1}", m), "[[1.0, -3.5], [2.0, 40.0]]");
+    /// assert_eq!(format!("{:#}", m), "[\n    [1, -3.5],\n    [2,   40]\n]");
+    /// ```
     #[inline]
     fn fmt(&self, f: &mut Formatter) -> fmt::Result {
-        write!(
-            f,
-            "[\n  {}, {}\n  {}, {}\n]",
-            self.m11(),
-            self.m12(),
-            self.m21(),
-            self.m22()
-        )
+        let rows: [[f32; 2]; 2] = array::from_fn(|r| array::from_fn(|c| self.m[c * 2 + r]));
+        format::write_matrix(f, &rows)
+    }
+}
+
+impl Debug for Matrix2 {
+    /// Formats the matrix's elements grouped by column, in the order they're stored
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Matrix2;
+    ///
+    /// let m = Matrix2::make(1.0, 2.0, -3.5, 40.0);
+    /// assert_eq!(format!("{:?}", m), "Matrix2 { cols: [[1.0, 2.0], [-3.5, 40.0]] }");
+    /// ```
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let cols: [[f32; 2]; 2] = array::from_fn(|c| array::from_fn(|r| self.m[c * 2 + r]));
+        f.debug_struct("Matrix2").field("cols", &cols).finish()
+    }
+}
+
+impl FromStr for Matrix2 {
+    type Err = ParseError;
+
+    /// Parses a matrix written as a list of its rows, as `Display` writes it
+    ///
+    /// Whitespace between elements and rows is ignored, so alternate grid output parses too.
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Matrix2;
+    /// use vex::ParseError;
+    ///
+    /// let m = Matrix2::make(1.0, 2.0, -3.5, 40.0);
+    /// assert_eq!("[[1, -3.5], [2, 40]]".parse::<Matrix2>(), Ok(m));
+    /// assert_eq!(format!("{:#}", m).parse::<Matrix2>(), Ok(m));
+    /// assert_eq!(format!("{:8.3}", m).parse::<Matrix2>(), Ok(m));
+    ///
+    /// let actual = "[[1, 2]]".parse::<Matrix2>();
+    /// assert_eq!(actual, Err(ParseError::Rows { expected: 2, found: 1 }));
+    /// ```
+    #[inline]
+    fn from_str(s: &str) -> Result<Matrix2, ParseError> {
+        let rows: [[f32; 2]; 2] = parse::parse_matrix(s)?;
+        Ok(Matrix2 {
+            m: array::from_fn(|i| rows[i % 2][i / 2]),
+        })
     }
 }
 
@@ -714,3 +1500,44 @@ impl common::Matrix<Vector2> for Matrix2 {
         )
     }
 }
+
+impl common::SquareMatrix for Matrix2 {
+    #[inline]
+    fn identity() -> Matrix2 {
+        Matrix2::new()
+    }
+
+    #[inline]
+    fn determinant(&self) -> f32 {
+        Matrix2::determinant(self)
+    }
+
+    #[inline]
+    fn transposed(&self) -> Matrix2 {
+        Matrix2::transposed(self)
+    }
+
+    #[inline]
+    fn try_inverse(&self) -> Option<Matrix2> {
+        Matrix2::try_inverse(self)
+    }
+
+    #[inline]
+    fn approx_eq(&self, other: &Matrix2, epsilon: f32) -> bool {
+        Matrix2::approx_eq(self, other, epsilon)
+    }
+}
+
+impl common::MixFactor<Matrix2> for f32 {
+    #[inline]
+    fn spread(self) -> Matrix2 {
+        Matrix2 { m: [self; 4] }
+    }
+}
+
+impl common::MixFactor<Matrix2> for Matrix2 {
+    #[inline]
+    fn spread(self) -> Matrix2 {
+        self
+    }
+}
@@ -1,13 +1,25 @@
 use crate::common;
+use crate::epsilon::Epsilon;
+use crate::euler::{self, EulerOrder};
+use crate::format;
+#[cfg(not(feature = "std"))]
+use crate::math::Float;
 use crate::matrix2::Matrix2;
+use crate::parse::{self, ParseError};
+use crate::unit_vector3::UnitVector3;
 use crate::vector2::Vector2;
 use crate::vector3::Vector3;
 
-use std::cmp;
-use std::fmt;
-use std::fmt::{Display, Formatter};
+use core::array;
+use core::cmp;
+use core::fmt;
+use core::fmt::{Debug, Display, Formatter};
+use core::slice;
+use core::str::FromStr;
 
-use std::ops::{
+use core::ops::{
+    Index,
+    IndexMut,
     Neg,
     Add,
     AddAssign,
@@ -20,13 +32,47 @@ use std::ops::{
 };
 
 
-#[repr(C, packed)]
-#[derive(Copy, Clone, Debug)]
+#[repr(C)]
+#[derive(Copy, Clone)]
+#[cfg_attr(feature = "rkyv", derive(rkyv::Archive, rkyv::Deserialize, rkyv::Serialize))]
+#[cfg_attr(feature = "rkyv", rkyv(derive(Debug), compare(PartialEq)))]
 pub struct Matrix3 {
     pub m: [f32; 9],
 }
 
 impl Matrix3 {
+    /// The identity matrix
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Matrix3;
+    /// 
+    /// assert_eq!(Matrix3::IDENTITY, Matrix3::new());
+    /// ```
+    pub const IDENTITY: Matrix3 = Matrix3 {
+        m: [
+            1.0, 0.0, 0.0,
+            0.0, 1.0, 0.0,
+            0.0, 0.0, 1.0,
+        ],
+    };
+
+    /// A matrix with every element set to zero
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Matrix3;
+    ///
+    /// assert_eq!(Matrix3::ZERO, Matrix3::IDENTITY * 0.0);
+    /// ```
+    pub const ZERO: Matrix3 = Matrix3 {
+        m: [
+            0.0, 0.0, 0.0,
+            0.0, 0.0, 0.0,
+            0.0, 0.0, 0.0,
+        ],
+    };
+
     /// Creates a matrix set to its identity
     ///
     /// # Examples
@@ -43,7 +89,7 @@ impl Matrix3 {
     /// });
     /// ```
     #[inline]
-    pub fn new() -> Matrix3 {
+    pub const fn new() -> Matrix3 {
         Matrix3 {
             m: [
                 1.0, 0.0, 0.0,
@@ -64,7 +110,8 @@ impl Matrix3 {
     /// assert_eq!(actual.m, expected);
     /// ```
     #[inline]
-    pub fn make(
+    #[allow(clippy::too_many_arguments)]
+    pub const fn make(
         m11: f32,
         m21: f32,
         m31: f32,
@@ -80,6 +127,370 @@ impl Matrix3 {
         }
     }
 
+    /// Creates a 2D translation matrix
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Matrix3;
+    /// 
+    /// let actual = Matrix3::translate_2d(1.0, 2.0);
+    /// let expected = [
+    ///     1.0, 0.0, 0.0, // column 1
+    ///     0.0, 1.0, 0.0, // column 2
+    ///     1.0, 2.0, 1.0, // column 3
+    /// ];
+    /// assert_eq!(actual.m, expected);
+    /// ```
+    #[inline]
+    pub fn translate_2d(x: f32, y: f32) -> Matrix3 {
+        Matrix3::make(1.0, 0.0, 0.0, 0.0, 1.0, 0.0, x, y, 1.0)
+    }
+
+    /// Creates a 2D matrix that rotates counter-clockwise by `angle` radians
+    ///
+    /// # Examples
+    /// ```
+    /// use std::f32::consts::PI;
+    /// use vex::Matrix;
+    /// use vex::Matrix3;
+    /// use vex::Vector2;
+    /// 
+    /// let actual = Matrix3::rotate_2d(PI / 2.0).transform_point(&Vector2::make(1.0, 2.0));
+    /// let expected = Vector2::make(-2.0, 1.0);
+    /// assert!((actual - expected).mag() < 0.000001);
+    /// ```
+    #[inline]
+    pub fn rotate_2d(angle: f32) -> Matrix3 {
+        let (sine, cosine) = angle.sin_cos();
+        Matrix3::make(cosine, sine, 0.0, -sine, cosine, 0.0, 0.0, 0.0, 1.0)
+    }
+
+    /// Creates a 2D scale matrix
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Matrix3;
+    /// 
+    /// let actual = Matrix3::scale_2d(2.0, 3.0);
+    /// let expected = [
+    ///     2.0, 0.0, 0.0, // column 1
+    ///     0.0, 3.0, 0.0, // column 2
+    ///     0.0, 0.0, 1.0, // column 3
+    /// ];
+    /// assert_eq!(actual.m, expected);
+    /// ```
+    #[inline]
+    pub fn scale_2d(x: f32, y: f32) -> Matrix3 {
+        Matrix3::make(x, 0.0, 0.0, 0.0, y, 0.0, 0.0, 0.0, 1.0)
+    }
+
+    /// Creates a 2D matrix that scales, then rotates counter-clockwise by `angle` radians, then
+    /// translates
+    ///
+    /// This is the usual sprite transform, and equals
+    /// `translate_2d(..) * rotate_2d(angle) * scale_2d(..)`.
+    ///
+    /// # Examples
+    /// ```
+    /// use std::f32::consts::PI;
+    /// use vex::Matrix;
+    /// use vex::Matrix3;
+    /// use vex::Vector2;
+    /// 
+    /// let translation = Vector2::make(5.0, 6.0);
+    /// let scale = Vector2::make(2.0, 3.0);
+    /// let actual = Matrix3::from_transform_2d(&translation, PI / 2.0, &scale);
+    /// let expected = Matrix3::translate_2d(5.0, 6.0) * Matrix3::rotate_2d(PI / 2.0) * Matrix3::scale_2d(2.0, 3.0);
+    /// assert!(actual.approx_eq(&expected, 0.000001));
+    ///
+    /// let point = actual.transform_point(&Vector2::make(1.0, 1.0));
+    /// assert!((point - Vector2::make(2.0, 8.0)).mag() < 0.000001);
+    /// ```
+    #[inline]
+    pub fn from_transform_2d(translation: &Vector2, angle: f32, scale: &Vector2) -> Matrix3 {
+        let (sine, cosine) = angle.sin_cos();
+        Matrix3::make(
+            cosine * scale.x,
+            sine * scale.x,
+            0.0,
+            -sine * scale.y,
+            cosine * scale.y,
+            0.0,
+            translation.x,
+            translation.y,
+            1.0,
+        )
+    }
+
+    /// Creates a rotation matrix from Euler angles (in radians) applied in the provided order
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::EulerOrder;
+    /// use vex::Matrix;
+    /// use vex::Matrix3;
+    /// use vex::Vector3;
+    ///
+    /// let half_pi = std::f32::consts::PI / 2.0;
+    /// let m = Matrix3::from_euler(half_pi, 0.0, half_pi, EulerOrder::XYZ);
+    /// let actual = m.transform_point(&Vector3::up());
+    /// assert!((actual - Vector3::make(0.0, 0.0, 1.0)).mag() < 0.0001);
+    ///
+    /// let m = Matrix3::from_euler(half_pi, 0.0, half_pi, EulerOrder::ZYX);
+    /// let actual = m.transform_point(&Vector3::up());
+    /// assert!((actual - Vector3::make(-1.0, 0.0, 0.0)).mag() < 0.0001);
+    /// ```
+    #[inline]
+    pub fn from_euler(x: f32, y: f32, z: f32, order: EulerOrder) -> Matrix3 {
+        let r = euler::euler_to_rows(&Vector3::make(x, y, z), order);
+        Matrix3::make(
+            r[0][0], r[1][0], r[2][0],
+            r[0][1], r[1][1], r[2][1],
+            r[0][2], r[1][2], r[2][2],
+        )
+    }
+
+    /// Find the Euler angles (in radians) that reproduce the matrix's rotation in the provided order
+    ///
+    /// The matrix must be a pure rotation. The second rotation applied is kept
+    /// within `[-pi/2, pi/2]`, and at gimbal lock the third rotation is reported as zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::EulerOrder;
+    /// use vex::Matrix3;
+    /// use vex::Vector3;
+    ///
+    /// let m = Matrix3::from_euler(0.1, -0.2, 0.3, EulerOrder::YXZ);
+    /// let actual = m.to_euler(EulerOrder::YXZ);
+    /// assert!((actual - Vector3::make(0.1, -0.2, 0.3)).mag() < 0.0001);
+    /// ```
+    #[inline]
+    pub fn to_euler(&self, order: EulerOrder) -> Vector3 {
+        let rows = [
+            [self.m[0], self.m[3], self.m[6]],
+            [self.m[1], self.m[4], self.m[7]],
+            [self.m[2], self.m[5], self.m[8]],
+        ];
+        euler::rows_to_euler(&rows, order)
+    }
+
+    /// Creates a rotation matrix about an arbitrary axis, with the angle in radians
+    ///
+    /// The axis doesn't need to be normalized, and a zero axis gives the identity. Rotations are
+    /// counter-clockwise when looking down the axis towards the origin.
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Matrix;
+    /// use vex::Matrix3;
+    /// use vex::Vector3;
+    ///
+    /// let half_pi = std::f32::consts::PI / 2.0;
+    /// let m = Matrix3::from_axis_angle(Vector3::make(0.0, 0.0, 2.0), half_pi);
+    /// assert!((m.transform_point(&Vector3::right()) - Vector3::up()).mag() < 0.0001);
+    ///
+    /// let m = Matrix3::from_axis_angle(Vector3::right(), half_pi);
+    /// let actual = m.transform_point(&Vector3::up());
+    /// assert!((actual - Vector3::make(0.0, 0.0, 1.0)).mag() < 0.0001);
+    ///
+    /// // a third of a turn about the diagonal cycles the axes
+    /// let m = Matrix3::from_axis_angle(Vector3::one(), 2.0 * std::f32::consts::PI / 3.0);
+    /// let actual = m.transform_point(&Vector3::right());
+    /// assert!((actual - Vector3::up()).mag() < 0.0001);
+    ///
+    /// assert_eq!(Matrix3::from_axis_angle(Vector3::new(), 1.0), Matrix3::new());
+    /// ```
+    #[inline]
+    pub fn from_axis_angle(axis: Vector3, angle: f32) -> Matrix3 {
+        let length = axis.mag();
+        if length == 0.0 {
+            return Matrix3::new();
+        }
+
+        Matrix3::from_rotation_vector(axis * (angle / length))
+    }
+
+    /// Creates a rotation matrix about a unit axis, with the angle in radians
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Matrix;
+    /// use vex::Matrix3;
+    /// use vex::UnitVector3;
+    /// use vex::Vector3;
+    ///
+    /// let half_pi = std::f32::consts::PI / 2.0;
+    /// let m = Matrix3::from_unit_axis_angle(UnitVector3::Z, half_pi);
+    /// assert!((m.transform_point(&Vector3::right()) - Vector3::up()).mag() < 0.0001);
+    /// ```
+    #[inline]
+    pub fn from_unit_axis_angle(axis: UnitVector3, angle: f32) -> Matrix3 {
+        Matrix3::from_rotation_vector(axis.get() * angle)
+    }
+
+    /// Creates a rotation matrix from a rotation vector, whose direction is the axis and whose
+    /// length is the angle (in radians)
+    ///
+    /// This is the exponential map from so(3), computed with Rodrigues' formula. Small angles use
+    /// a Taylor expansion, so the result stays accurate all the way down to the zero vector.
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Matrix;
+    /// use vex::Matrix3;
+    /// use vex::Vector3;
+    ///
+    /// let m = Matrix3::from_rotation_vector(Vector3::make(0.0, 0.0, std::f32::consts::PI / 2.0));
+    /// let actual = m.transform_point(&Vector3::right());
+    /// assert!((actual - Vector3::up()).mag() < 0.0001);
+    /// assert_eq!(Matrix3::from_rotation_vector(Vector3::new()), Matrix3::new());
+    /// ```
+    pub fn from_rotation_vector(v: Vector3) -> Matrix3 {
+        let theta_sq = v.mag_sq();
+        let theta = theta_sq.sqrt();
+        let (a, b) = if theta < 1e-4 {
+            (1.0 - theta_sq / 6.0, 0.5 - theta_sq / 24.0)
+        } else {
+            (theta.sin() / theta, (1.0 - theta.cos()) / theta_sq)
+        };
+
+        // I + a * K + b * K^2, where K is the skew-symmetric matrix of v
+        let (x, y, z) = (v.x, v.y, v.z);
+        Matrix3::make(
+            1.0 - b * (y * y + z * z),
+            a * z + b * x * y,
+            -a * y + b * x * z,
+            -a * z + b * x * y,
+            1.0 - b * (x * x + z * z),
+            a * x + b * y * z,
+            a * y + b * x * z,
+            -a * x + b * y * z,
+            1.0 - b * (x * x + y * y),
+        )
+    }
+
+    /// Find the rotation vector of a rotation matrix, whose direction is the axis and whose length
+    /// is the angle (in radians)
+    ///
+    /// This is the logarithmic map to so(3), and the inverse of
+    /// [`from_rotation_vector`](#method.from_rotation_vector). The angle is within `[0, pi]`, and
+    /// rotations of exactly pi may report either of the two equivalent axes.
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Matrix3;
+    /// use vex::Vector3;
+    ///
+    /// let expected = Vector3::make(0.3, -1.2, 0.8);
+    /// let actual = Matrix3::from_rotation_vector(expected).to_rotation_vector();
+    /// assert!((actual - expected).mag() < 0.0001);
+    ///
+    /// let expected = Vector3::make(0.0, std::f32::consts::PI, 0.0);
+    /// let actual = Matrix3::from_rotation_vector(expected).to_rotation_vector();
+    /// assert!((actual - expected).mag() < 0.001 || (actual + expected).mag() < 0.001);
+    /// ```
+    pub fn to_rotation_vector(&self) -> Vector3 {
+        // twice the sine of the angle times the axis
+        let vee = Vector3::make(
+            self.m32() - self.m23(),
+            self.m13() - self.m31(),
+            self.m21() - self.m12(),
+        );
+
+        // atan2 stays well conditioned near 0 and pi, where acos of the trace doesn't
+        let trace = self.m11() + self.m22() + self.m33();
+        let cosine = ((trace - 1.0) * 0.5).clamp(-1.0, 1.0);
+        let theta = (vee.mag() * 0.5).atan2(cosine);
+
+        if theta < 1e-4 {
+            return vee * (0.5 + theta * theta / 12.0);
+        }
+
+        if theta < core::f32::consts::PI - 1e-3 {
+            return vee * (theta / (2.0 * theta.sin()));
+        }
+
+        // near pi the sine vanishes, so recover the axis from the symmetric part instead
+        let diagonal = [self.m11(), self.m22(), self.m33()];
+        let k = if diagonal[0] >= diagonal[1] && diagonal[0] >= diagonal[2] {
+            0
+        } else if diagonal[1] >= diagonal[2] {
+            1
+        } else {
+            2
+        };
+
+        let one_minus_cosine = 1.0 - cosine;
+        let mut axis = Vector3::new();
+        axis[k as u32] = ((diagonal[k] - cosine) / one_minus_cosine).max(0.0).sqrt();
+        for j in 0..3 {
+            if j != k {
+                let symmetric = self.m[j * 3 + k] + self.m[k * 3 + j];
+                axis[j as u32] = symmetric / (2.0 * one_minus_cosine * axis[k as u32]);
+            }
+        }
+
+        axis.norm();
+        if Vector3::dot(&axis, &vee) < 0.0 {
+            axis = -axis;
+        }
+
+        axis * theta
+    }
+
+    /// Find the axis-aligned orientation closest to the rotation
+    ///
+    /// The result is whichever of the 24 rotations mapping axes onto axes is closest to the matrix,
+    /// which makes this a quantizer for rotations in level editors and grid-based building.
+    /// Scale in the matrix doesn't affect the result, but shear can.
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::EulerOrder;
+    /// use vex::Matrix3;
+    ///
+    /// let m = Matrix3::from_euler(0.1, 1.4, -0.2, EulerOrder::XYZ);
+    /// let expected = Matrix3::from_euler(0.0, std::f32::consts::FRAC_PI_2, 0.0, EulerOrder::XYZ);
+    /// assert!(m.snap_to_nearest_90_degrees().approx_eq(&expected, 0.000001));
+    ///
+    /// let actual = Matrix3::new().snap_to_nearest_90_degrees();
+    /// assert_eq!(actual, Matrix3::new());
+    /// ```
+    pub fn snap_to_nearest_90_degrees(&self) -> Matrix3 {
+        const PERMUTATIONS: [([usize; 3], f32); 6] = [
+            ([0, 1, 2], 1.0),
+            ([1, 2, 0], 1.0),
+            ([2, 0, 1], 1.0),
+            ([0, 2, 1], -1.0),
+            ([2, 1, 0], -1.0),
+            ([1, 0, 2], -1.0),
+        ];
+
+        // column j of the result is the signed unit axis rows[j]
+        let mut best = (f32::NEG_INFINITY, [0; 3], [1.0; 3]);
+        for (rows, parity) in PERMUTATIONS.iter() {
+            for flips in 0..8 {
+                let signs = [0, 1, 2].map(|j| if flips & (1 << j) == 0 { 1.0 } else { -1.0 });
+                if parity * signs[0] * signs[1] * signs[2] < 0.0 {
+                    continue;
+                }
+
+                let score: f32 = (0..3).map(|j| signs[j] * self.m[j * 3 + rows[j]]).sum();
+                if score > best.0 {
+                    best = (score, *rows, signs);
+                }
+            }
+        }
+
+        let mut result = Matrix3 { m: [0.0; 9] };
+        for j in 0..3 {
+            result.m[j * 3 + best.1[j]] = best.2[j];
+        }
+
+        result
+    }
+
     /// Gets the value for the m11 element
     ///
     /// # Examples
@@ -318,184 +729,913 @@ impl Matrix3 {
         self.m[6] = v;
     }
 
-    /// Sets the value for the m11 element
+    /// Sets the value for the m11 element
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Matrix3;
+    /// 
+    /// let mut actual = Matrix3::make(0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0);
+    /// actual.set_m23(1.0);
+    /// let expected = [0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 1.0, 0.0];
+    /// assert_eq!(actual.m, expected);
+    /// ```
+    #[inline]
+    pub fn set_m23(&mut self, v: f32) {
+        self.m[7] = v;
+    }
+
+    /// Sets the value for the m11 element
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Matrix3;
+    /// 
+    /// let mut actual = Matrix3::make(0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0);
+    /// actual.set_m33(1.0);
+    /// let expected = [0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 1.0];
+    /// assert_eq!(actual.m, expected);
+    /// ```
+    #[inline]
+    pub fn set_m33(&mut self, v: f32) {
+        self.m[8] = v;
+    }
+
+    /// Sets the internal contents of the matrix
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Matrix3;
+    /// 
+    /// let mut actual = Matrix3::new();
+    /// actual.set(1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0);
+    /// let expected = Matrix3::make(1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0);
+    /// assert_eq!(actual, expected);
+    /// ```
+    #[inline]
+    #[allow(clippy::too_many_arguments)]
+    pub fn set(
+        &mut self,
+        m11: f32,
+        m21: f32,
+        m31: f32,
+        m12: f32,
+        m22: f32,
+        m32: f32,
+        m13: f32,
+        m23: f32,
+        m33: f32,
+    ) {
+        self.set_m11(m11);
+        self.set_m21(m21);
+        self.set_m31(m31);
+        self.set_m12(m12);
+        self.set_m22(m22);
+        self.set_m32(m32);
+        self.set_m13(m13);
+        self.set_m23(m23);
+        self.set_m33(m33);
+    }
+
+    /// Gets a row of the matrix, counting from 0
+    ///
+    /// Panics if `i` is out of range.
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Matrix3;
+    /// use vex::Vector3;
+    /// 
+    /// let m = Matrix3::make(1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0);
+    /// assert_eq!(m.row(0), Vector3::make(1.0, 4.0, 7.0));
+    /// ```
+    #[inline]
+    pub fn row(&self, i: usize) -> Vector3 {
+        assert!(i < 3, "Invalid row for Matrix3: {}", i);
+        Vector3::make(self.m[i], self.m[3 + i], self.m[6 + i])
+    }
+
+    /// Gets a column of the matrix, counting from 0
+    ///
+    /// Panics if `i` is out of range.
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Matrix3;
+    /// use vex::Vector3;
+    /// 
+    /// let m = Matrix3::make(1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0);
+    /// assert_eq!(m.col(0), Vector3::make(1.0, 2.0, 3.0));
+    /// ```
+    #[inline]
+    pub fn col(&self, i: usize) -> Vector3 {
+        assert!(i < 3, "Invalid column for Matrix3: {}", i);
+        Vector3::make(self.m[i * 3], self.m[i * 3 + 1], self.m[i * 3 + 2])
+    }
+
+    /// Sets a row of the matrix, counting from 0
+    ///
+    /// Panics if `i` is out of range.
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Matrix3;
+    /// use vex::Vector3;
+    /// 
+    /// let mut actual = Matrix3::new();
+    /// actual.set_row(2, &Vector3::make(1.0, 2.0, 3.0));
+    /// assert_eq!(actual.row(2), Vector3::make(1.0, 2.0, 3.0));
+    /// ```
+    #[inline]
+    pub fn set_row(&mut self, i: usize, v: &Vector3) {
+        assert!(i < 3, "Invalid row for Matrix3: {}", i);
+        self.m[i] = v.x;
+        self.m[3 + i] = v.y;
+        self.m[6 + i] = v.z;
+    }
+
+    /// Sets a column of the matrix, counting from 0
+    ///
+    /// Panics if `i` is out of range.
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Matrix3;
+    /// use vex::Vector3;
+    /// 
+    /// let mut actual = Matrix3::new();
+    /// actual.set_col(2, &Vector3::make(1.0, 2.0, 3.0));
+    /// assert_eq!(actual.col(2), Vector3::make(1.0, 2.0, 3.0));
+    /// ```
+    #[inline]
+    pub fn set_col(&mut self, i: usize, v: &Vector3) {
+        assert!(i < 3, "Invalid column for Matrix3: {}", i);
+        self.m[i * 3] = v.x;
+        self.m[i * 3 + 1] = v.y;
+        self.m[i * 3 + 2] = v.z;
+    }
+
+    /// Iterates over the rows of the matrix from first to last
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Matrix3;
+    /// use vex::Vector3;
+    /// 
+    /// let m = Matrix3::make(1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0);
+    /// let rows: Vec<Vector3> = m.rows().collect();
+    /// assert_eq!(rows.len(), 3);
+    /// assert_eq!(rows[2], Vector3::make(3.0, 6.0, 9.0));
+    /// ```
+    #[inline]
+    pub fn rows(&self) -> impl Iterator<Item = Vector3> + '_ {
+        (0..3).map(move |i| self.row(i))
+    }
+
+    /// Iterates over the columns of the matrix from first to last
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Matrix3;
+    /// use vex::Vector3;
+    /// 
+    /// let m = Matrix3::make(1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0);
+    /// let cols: Vec<Vector3> = m.cols().collect();
+    /// assert_eq!(cols.len(), 3);
+    /// assert_eq!(cols[2], Vector3::make(7.0, 8.0, 9.0));
+    /// ```
+    #[inline]
+    pub fn cols(&self) -> impl Iterator<Item = Vector3> + '_ {
+        (0..3).map(move |i| self.col(i))
+    }
+
+    /// Iterates over the elements of the matrix in column-major order
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Matrix3;
+    /// 
+    /// let m = Matrix3::make(1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0);
+    /// assert_eq!(m.iter().sum::<f32>(), 45.0);
+    /// ```
+    #[inline]
+    pub fn iter(&self) -> slice::Iter<'_, f32> {
+        self.m.iter()
+    }
+
+    /// Iterates mutably over the elements of the matrix in column-major order
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Matrix3;
+    /// 
+    /// let mut actual = Matrix3::new();
+    /// for element in actual.iter_mut() {
+    ///     *element += 1.0;
+    /// }
+    ///
+    /// assert_eq!(actual.m[0], 2.0);
+    /// assert_eq!(actual.m[1], 1.0);
+    /// ```
+    #[inline]
+    pub fn iter_mut(&mut self) -> slice::IterMut<'_, f32> {
+        self.m.iter_mut()
+    }
+
+    /// Transposes the matrix's elements
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Matrix3;
+    /// 
+    /// let mut actual = Matrix3::make(1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0);
+    /// actual.transpose();
+    /// let expected = Matrix3::make(1.0, 4.0, 7.0, 2.0, 5.0, 8.0, 3.0, 6.0, 9.0);
+    /// assert_eq!(actual, expected);
+    /// ```
+    #[inline]
+    pub fn transpose(&mut self) {
+        let m = &mut self.m;
+        m.swap(1, 3);
+        m.swap(5, 7);
+        m.swap(2, 6);
+    }
+
+    /// Find the matrix's determinant
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Matrix3;
+    /// 
+    /// let actual = Matrix3::make(1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0).determinant();
+    /// assert_eq!(actual, 0.0);
+    /// ```
+    #[inline]
+    pub fn determinant(&self) -> f32 {
+        self.m11() * (self.m22() * self.m33() - self.m23() * self.m32())
+            - (self.m12() * (self.m21() * self.m33() - self.m23() * self.m31()))
+            + (self.m13() * (self.m21() * self.m32() - self.m22() * self.m31()))
+    }
+
+    /// Find the sum of the elements along the main diagonal
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Matrix3;
+    /// 
+    /// let actual = Matrix3::make(1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0).trace();
+    /// assert_eq!(actual, 15.0);
+    /// ```
+    #[inline]
+    pub fn trace(&self) -> f32 {
+        self.m[0] + self.m[4] + self.m[8]
+    }
+
+    /// Find the inverse of the matrix, or `None` if it's singular
+    ///
+    /// Only a determinant of exactly zero counts as singular, so a matrix that is merely close to
+    /// singular still inverts, to a result with huge elements that amplifies any rounding error.
+    /// Use [`try_inverse_with`](#method.try_inverse_with) to reject those as well.
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Matrix3;
+    ///
+    /// let actual = Matrix3::make(1.0, 0.0, 5.0, 2.0, 1.0, 6.0, 3.0, 4.0, 0.0).try_inverse();
+    /// let expected = Matrix3::make(-24.0, 20.0, -5.0, 18.0, -15.0, 4.0, 5.0, -4.0, 1.0);
+    /// assert_eq!(actual, Some(expected));
+    /// assert_eq!(Matrix3::make(1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0).try_inverse(), None);
+    /// ```
+    #[inline]
+    pub fn try_inverse(&self) -> Option<Matrix3> {
+        let det = self.determinant();
+        if det == 0.0 {
+            return None;
+        }
+
+        let inv_det = 1.0 / det;
+        let mut result = *self;
+        let m11 =
+            Matrix2::make(self.m22(), self.m23(), self.m32(), self.m33()).determinant() * inv_det;
+        let m21 =
+            Matrix2::make(self.m23(), self.m21(), self.m33(), self.m31()).determinant() * inv_det;
+        let m31 =
+            Matrix2::make(self.m21(), self.m22(), self.m31(), self.m32()).determinant() * inv_det;
+        let m12 =
+            Matrix2::make(self.m13(), self.m12(), self.m33(), self.m32()).determinant() * inv_det;
+        let m22 =
+            Matrix2::make(self.m11(), self.m13(), self.m31(), self.m33()).determinant() * inv_det;
+        let m32 =
+            Matrix2::make(self.m12(), self.m11(), self.m32(), self.m31()).determinant() * inv_det;
+        let m13 =
+            Matrix2::make(self.m12(), self.m13(), self.m22(), self.m23()).determinant() * inv_det;
+        let m23 =
+            Matrix2::make(self.m13(), self.m11(), self.m23(), self.m21()).determinant() * inv_det;
+        let m33 =
+            Matrix2::make(self.m11(), self.m12(), self.m21(), self.m22()).determinant() * inv_det;
+
+        result.set_m11(m11);
+        result.set_m21(m21);
+        result.set_m31(m31);
+        result.set_m12(m12);
+        result.set_m22(m22);
+        result.set_m32(m32);
+        result.set_m13(m13);
+        result.set_m23(m23);
+        result.set_m33(m33);
+        Some(result)
+    }
+
+    /// Find the inverse of the matrix, or `None` if it's singular or too close to singular under
+    /// an epsilon policy
+    ///
+    /// The determinant alone can't say how close a matrix is to singular, since it scales with the
+    /// elements: a uniform scale by 0.01 inverts exactly, despite its tiny determinant. Instead,
+    /// the determinant is divided by the product of the column lengths, which measures closeness
+    /// to singular independently of scale, from 0 for singular matrices up to 1 for orthogonal
+    /// ones. The matrix is rejected when that measure is within `epsilon.absolute` of zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Epsilon;
+    /// use vex::Matrix3;
+    ///
+    /// let m = Matrix3::make(1.0, 0.0, 0.0, 0.0, 1.0, 0.0, 1.0, 1.0, 0.0000001);
+    /// assert!(m.try_inverse().is_some());
+    /// assert_eq!(m.try_inverse_with(&Epsilon::DEFAULT), None);
+    ///
+    /// let m = Matrix3::new() * 0.01;
+    /// assert_eq!(m.try_inverse_with(&Epsilon::DEFAULT), m.try_inverse());
+    /// ```
+    #[inline]
+    pub fn try_inverse_with(&self, epsilon: &Epsilon) -> Option<Matrix3> {
+        let volume: f32 = self.cols().map(|col| col.mag()).product();
+        if volume == 0.0 || epsilon.is_zero(self.determinant() / volume) {
+            return None;
+        }
+
+        self.try_inverse()
+    }
+
+    /// Inverses the matrix, returning false and leaving it unchanged if it's singular
+    ///
+    /// # Examples
+    /// ```
+    /// #![allow(deprecated)]
+    /// use vex::Matrix3;
+    /// 
+    /// let mut actual = Matrix3::make(1.0, 0.0, 5.0, 2.0, 1.0, 6.0, 3.0, 4.0, 0.0);
+    /// actual.inverse();
+    /// let expected = Matrix3::make(-24.0, 20.0, -5.0, 18.0, -15.0, 4.0, 5.0, -4.0, 1.0);
+    /// assert_eq!(actual, expected);
+    /// ```
+    #[deprecated(since = "2.2.0", note = "use `try_inverse` instead")]
+    #[inline]
+    pub fn inverse(&mut self) -> bool {
+        match self.try_inverse() {
+            Some(inverse) => {
+                *self = inverse;
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Negates the matrix's elements
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Matrix3;
+    /// 
+    /// let mut actual = Matrix3::make(1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0);
+    /// actual.negate();
+    /// let expected = Matrix3::make(-1.0, -2.0, -3.0, -4.0, -5.0, -6.0, -7.0, -8.0, -9.0);
+    /// assert_eq!(actual, expected);
+    /// ```
+    #[inline]
+    pub fn negate(&mut self) {
+        for value in self.m.iter_mut() {
+            *value = -*value;
+        }
+    }
+
+    /// Find the matrix with its elements negated
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Matrix3;
+    /// 
+    /// let actual = Matrix3::make(1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0).negated();
+    /// let expected = Matrix3::make(-1.0, -2.0, -3.0, -4.0, -5.0, -6.0, -7.0, -8.0, -9.0);
+    /// assert_eq!(actual, expected);
+    /// ```
+    #[inline]
+    pub fn negated(&self) -> Matrix3 {
+        -*self
+    }
+
+    /// Transposes the matrix's elements in place
+    ///
+    /// This is the same as `transpose`, named to pair with the value-returning variants.
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Matrix3;
+    /// 
+    /// let mut actual = Matrix3::make(1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0);
+    /// actual.transpose_in_place();
+    /// let mut expected = Matrix3::make(1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0);
+    /// expected.transpose();
+    /// assert_eq!(actual, expected);
+    /// ```
+    #[inline]
+    pub fn transpose_in_place(&mut self) {
+        self.transpose();
+    }
+
+    /// Find the transpose of the matrix
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Matrix3;
+    /// 
+    /// let actual = Matrix3::make(1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0).transposed();
+    /// let expected = Matrix3::make(1.0, 4.0, 7.0, 2.0, 5.0, 8.0, 3.0, 6.0, 9.0);
+    /// assert_eq!(actual, expected);
+    /// ```
+    #[inline]
+    pub fn transposed(&self) -> Matrix3 {
+        let mut result = *self;
+        result.transpose();
+        result
+    }
+
+    /// Find the matrix that converts coordinates in one basis into coordinates in another, or
+    /// `None` if `to` isn't invertible
+    ///
+    /// Each basis holds its basis vectors as columns, in terms of a shared space, so the result is
+    /// `to^-1 * from`.
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Matrix;
+    /// use vex::Matrix3;
+    /// use vex::Vector3;
+    ///
+    /// let from = Matrix3::make(0.0, 1.0, 0.0, -1.0, 0.0, 0.0, 0.0, 0.0, 1.0);
+    /// let to = Matrix3::make(2.0, 0.0, 0.0, 0.0, 2.0, 0.0, 0.0, 0.0, 1.0);
+    /// let convert = Matrix3::change_of_basis(&from, &to).unwrap();
+    ///
+    /// let actual = convert.transform_point(&Vector3::make(1.0, 2.0, 3.0));
+    /// assert_eq!(actual, Vector3::make(-1.0, 0.5, 3.0));
+    /// assert_eq!(Matrix3::change_of_basis(&from, &(to * 0.0)), None);
+    /// ```
+    #[inline]
+    pub fn change_of_basis(from: &Matrix3, to: &Matrix3) -> Option<Matrix3> {
+        Some(to.try_inverse()? * *from)
+    }
+
+    /// Find `self * cov * transpose(self)`, which propagates a covariance through a linear map
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Matrix3;
+    /// 
+    /// let a = Matrix3::make(1.0, 0.0, 0.0, 2.0, 1.0, 0.0, 0.0, 0.0, 1.0);
+    /// let cov = Matrix3::make(4.0, 1.0, 0.0, 1.0, 2.0, 0.0, 0.0, 0.0, 3.0);
+    /// let actual = a.similarity_transform(&cov);
+    /// let expected = a * cov * a.transposed();
+    /// assert_eq!(actual, expected);
+    ///
+    /// // symmetrize to remove any asymmetry left by rounding
+    /// let actual = a.similarity_transform(&cov).symmetrized();
+    /// assert_eq!(actual, actual.transposed());
+    /// ```
+    #[inline]
+    pub fn similarity_transform(&self, cov: &Matrix3) -> Matrix3 {
+        *self * *cov * self.transposed()
+    }
+
+    /// Find `self * cov * transpose(other)`, which propagates a cross-covariance through two
+    /// linear maps
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Matrix3;
+    /// 
+    /// let a = Matrix3::make(1.0, 0.0, 0.0, 2.0, 1.0, 0.0, 0.0, 0.0, 1.0);
+    /// let b = Matrix3::make(2.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 1.0, 1.0);
+    /// let cov = Matrix3::make(4.0, 1.0, 0.0, 1.0, 2.0, 0.0, 0.0, 0.0, 3.0);
+    /// let actual = a.cross_transform(&cov, &b);
+    /// assert_eq!(actual, a * cov * b.transposed());
+    /// ```
+    #[inline]
+    pub fn cross_transform(&self, cov: &Matrix3, other: &Matrix3) -> Matrix3 {
+        *self * *cov * other.transposed()
+    }
+
+    /// Replaces the matrix with the average of itself and its transpose
+    ///
+    /// Covariance matrices are symmetric in theory, but rounding lets the two halves drift apart
+    /// after repeated propagation. This removes that drift.
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Matrix3;
+    /// 
+    /// let mut actual = Matrix3::make(1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0);
+    /// actual.symmetrize();
+    /// let expected = Matrix3::make(1.0, 3.0, 5.0, 3.0, 5.0, 7.0, 5.0, 7.0, 9.0);
+    /// assert_eq!(actual, expected);
+    /// ```
+    #[inline]
+    pub fn symmetrize(&mut self) {
+        let transposed = self.transposed();
+        for (value, other) in self.m.iter_mut().zip(transposed.m.iter()) {
+            *value = (*value + *other) * 0.5;
+        }
+    }
+
+    /// Find the average of the matrix and its transpose
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Matrix3;
+    /// 
+    /// let actual = Matrix3::make(1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0).symmetrized();
+    /// let expected = Matrix3::make(1.0, 3.0, 5.0, 3.0, 5.0, 7.0, 5.0, 7.0, 9.0);
+    /// assert_eq!(actual, expected);
+    /// ```
+    #[inline]
+    pub fn symmetrized(&self) -> Matrix3 {
+        let mut result = *self;
+        result.symmetrize();
+        result
+    }
+
+    /// Makes the columns orthonormal using Gram-Schmidt
+    ///
+    /// The first column keeps its direction, so order the columns by importance. A zero or
+    /// dependent column is replaced by one perpendicular to the columns before it.
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Epsilon;
+    /// use vex::Matrix3;
+    /// 
+    /// let mut actual = Matrix3::make(1.01, 0.02, 0.0, -0.01, 0.99, 0.03, 0.0, -0.02, 1.02);
+    /// actual.orthonormalize();
+    /// assert!(actual.is_orthogonal(&Epsilon::DEFAULT));
+    /// ```
+    pub fn orthonormalize(&mut self) {
+        let [x, y, z] = [self.col(0), self.col(1), self.col(2)];
+        let mut x = x.normalized();
+        if x == Vector3::new() {
+            x = Vector3::right();
+        }
+
+        let mut y = (y - x * Vector3::dot(&x, &y)).normalized();
+        if y == Vector3::new() {
+            y = Vector3::perpendicular(&x);
+        }
+
+        let mut z = (z - x * Vector3::dot(&x, &z) - y * Vector3::dot(&y, &z)).normalized();
+        if z == Vector3::new() {
+            z = Vector3::cross(&x, &y);
+        }
+
+        self.set_col(0, &x);
+        self.set_col(1, &y);
+        self.set_col(2, &z);
+    }
+
+    /// Find the matrix with its columns made orthonormal using Gram-Schmidt
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Epsilon;
+    /// use vex::Matrix3;
+    /// 
+    /// let actual = Matrix3::new().orthonormalized();
+    /// assert!(actual.is_identity(&Epsilon::DEFAULT));
+    /// ```
+    #[inline]
+    pub fn orthonormalized(&self) -> Matrix3 {
+        let mut result = *self;
+        result.orthonormalize();
+        result
+    }
+
+    /// Find the matrix with a function applied to each element
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Matrix3;
+    /// 
+    /// let actual = Matrix3::make(1.0, -2.0, 3.0, -4.0, 5.0, -6.0, 7.0, -8.0, 9.0).map(f32::abs);
+    /// assert_eq!(actual.m, [1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0]);
+    /// ```
+    #[inline]
+    pub fn map<F: Fn(f32) -> f32>(&self, f: F) -> Matrix3 {
+        Matrix3 { m: self.m.map(f) }
+    }
+
+    /// Linearly interpolate between two matrices with either a scalar factor or one per element
+    ///
+    /// Matches GLSL's `mix`, giving `a * (1 - t) + b * t` for each element, so a factor of 1
+    /// returns exactly `b`.
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Matrix3;
+    /// 
+    /// let a = Matrix3::new();
+    /// let b = Matrix3::scale_2d(3.0, 5.0);
+    /// assert_eq!(Matrix3::mix(&a, &b, 0.5), Matrix3::scale_2d(2.0, 3.0));
+    ///
+    /// let t = Matrix3::make(1.0, 1.0, 1.0, 1.0, 0.0, 1.0, 1.0, 1.0, 1.0);
+    /// assert_eq!(Matrix3::mix(&a, &b, t), Matrix3::make(3.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0));
+    /// ```
+    #[inline]
+    pub fn mix<T: common::MixFactor<Matrix3>>(a: &Matrix3, b: &Matrix3, t: T) -> Matrix3 {
+        let t = t.spread();
+        let mut m = [0.0; 9];
+        for (i, element) in m.iter_mut().enumerate() {
+            *element = a.m[i] * (1.0 - t.m[i]) + b.m[i] * t.m[i];
+        }
+
+        Matrix3 { m }
+    }
+
+    /// Determine whether or not all elements of the matrices are within `epsilon` of each other
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Matrix3;
+    /// 
+    /// let a = Matrix3::new();
+    /// assert!(a.approx_eq(&Matrix3::make(1.0000001, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0), 0.000001));
+    /// assert!(!a.approx_eq(&Matrix3::make(1.1, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0), 0.000001));
+    /// ```
+    #[inline]
+    pub fn approx_eq(&self, other: &Matrix3, epsilon: f32) -> bool {
+        for i in 0..9 {
+            if (self.m[i] - other.m[i]).abs() > epsilon {
+                return false;
+            }
+        }
+
+        true
+    }
+
+    /// Determine whether or not all elements of the matrices are equal under an epsilon policy
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Epsilon;
+    /// use vex::Matrix3;
+    /// 
+    /// let a = Matrix3::make(1000000.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 10.0);
+    /// assert!(a.approx_eq_with(&Matrix3::make(1000000.06, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 10.0), &Epsilon::DEFAULT));
+    /// assert!(!a.approx_eq_with(&Matrix3::make(1000000.0, 2.1, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 10.0), &Epsilon::DEFAULT));
+    /// ```
+    #[inline]
+    pub fn approx_eq_with(&self, other: &Matrix3, epsilon: &Epsilon) -> bool {
+        let a: [f32; 9] = (*self).into();
+        let b: [f32; 9] = (*other).into();
+        epsilon.all_approx_eq(&a, &b)
+    }
+
+    /// Determine whether or not the matrix is too close to singular to invert under an epsilon
+    /// policy
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Epsilon;
+    /// use vex::Matrix3;
+    /// 
+    /// let a = Matrix3::make(1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0);
+    /// let b = Matrix3::make(1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.01);
+    /// assert!(a.is_singular(&Epsilon::DEFAULT));
+    /// assert!(!b.is_singular(&Epsilon::DEFAULT));
+    /// assert!(b.is_singular(&Epsilon::DEFAULT.with_absolute(0.1)));
+    /// ```
+    #[inline]
+    pub fn is_singular(&self, epsilon: &Epsilon) -> bool {
+        epsilon.is_zero(self.determinant())
+    }
+
+    /// Find the number of linearly independent columns under an epsilon policy
+    ///
+    /// Pivots within the absolute tolerance count as zero, so nearly dependent columns lower the
+    /// rank.
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Epsilon;
+    /// use vex::Matrix3;
+    /// 
+    /// let full = Matrix3::make(1.0, 2.0, 0.0, 0.0, 1.0, 3.0, 2.0, 0.0, 1.0);
+    /// assert_eq!(full.rank(&Epsilon::DEFAULT), 3);
+    /// let plane = Matrix3::make(1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0);
+    /// assert_eq!(plane.rank(&Epsilon::DEFAULT), 2);
+    /// let line = Matrix3::make(1.0, 2.0, 3.0, 2.0, 4.0, 6.0, 3.0, 6.0, 9.0);
+    /// assert_eq!(line.rank(&Epsilon::DEFAULT), 1);
+    /// ```
+    #[inline]
+    pub fn rank(&self, epsilon: &Epsilon) -> u32 {
+        let mut m = self.m;
+        epsilon.rank(&mut m, 3)
+    }
+
+    /// Determine whether or not the matrix is the identity under an epsilon policy
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Epsilon;
+    /// use vex::Matrix3;
+    /// 
+    /// let mut m = Matrix3::new();
+    /// assert!(m.is_identity(&Epsilon::DEFAULT));
+    /// m.m[1] = 0.001;
+    /// assert!(!m.is_identity(&Epsilon::DEFAULT));
+    /// assert!(m.is_identity(&Epsilon::DEFAULT.with_absolute(0.01)));
+    /// ```
+    #[inline]
+    pub fn is_identity(&self, epsilon: &Epsilon) -> bool {
+        self.approx_eq_with(&Matrix3::IDENTITY, epsilon)
+    }
+
+    /// Determine whether or not the columns are orthonormal under an epsilon policy
+    ///
+    /// Orthonormal matrices are pure rotations or reflections, so their inverse is their transpose.
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Epsilon;
+    /// use vex::EulerOrder;
+    /// use vex::Matrix3;
+    /// 
+    /// let rotation = Matrix3::from_euler(0.25, -0.5, 1.0, EulerOrder::XYZ);
+    /// assert!(rotation.is_orthogonal(&Epsilon::DEFAULT));
+    /// let shear = Matrix3::make(1.0, 0.1, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0);
+    /// assert!(!shear.is_orthogonal(&Epsilon::DEFAULT));
+    /// ```
+    pub fn is_orthogonal(&self, epsilon: &Epsilon) -> bool {
+        let columns = [self.col(0), self.col(1), self.col(2)];
+        for i in 0..3 {
+            for j in i..3 {
+                let expected = if i == j { 1.0 } else { 0.0 };
+                if !epsilon.approx_eq(Vector3::dot(&columns[i], &columns[j]), expected) {
+                    return false;
+                }
+            }
+        }
+
+        true
+    }
+
+    /// Determine whether or not all elements of the matrix are valid
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Matrix3;
+    /// 
+    /// let actual = Matrix3::make(1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0);
+    /// assert!(actual.is_valid());
+    /// ```
+    #[inline]
+    pub fn is_valid(&self) -> bool {
+        for i in 0..9 {
+            if !common::is_valid(self.m[i]) {
+                return false;
+            }
+        }
+
+        true
+    }
+}
+
+impl Default for Matrix3 {
+    /// Creates an identity matrix
     ///
     /// # Examples
     /// ```
     /// use vex::Matrix3;
-    /// 
-    /// let mut actual = Matrix3::make(0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0);
-    /// actual.set_m23(1.0);
-    /// let expected = [0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 1.0, 0.0];
-    /// assert_eq!(actual.m, expected);
+    ///
+    /// assert_eq!(Matrix3::default(), Matrix3::new());
     /// ```
     #[inline]
-    pub fn set_m23(&mut self, v: f32) {
-        self.m[7] = v;
+    fn default() -> Matrix3 {
+        Matrix3::new()
     }
+}
 
-    /// Sets the value for the m11 element
+impl From<[f32; 9]> for Matrix3 {
+    /// Creates a Matrix3 from an array of elements in column-major order
     ///
     /// # Examples
     /// ```
     /// use vex::Matrix3;
     /// 
-    /// let mut actual = Matrix3::make(0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0);
-    /// actual.set_m33(1.0);
-    /// let expected = [0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 1.0];
-    /// assert_eq!(actual.m, expected);
+    /// let actual = Matrix3::from([1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0]);
+    /// let expected = Matrix3::make(1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0);
+    /// assert_eq!(actual, expected);
     /// ```
     #[inline]
-    pub fn set_m33(&mut self, v: f32) {
-        self.m[8] = v;
+    fn from(item: [f32; 9]) -> Matrix3 {
+        Matrix3 { m: item }
     }
+}
 
-    /// Sets the internal contents of the matrix
+impl From<Matrix3> for [f32; 9] {
+    /// Creates an array from the elements of a Matrix3 in column-major order
     ///
     /// # Examples
     /// ```
     /// use vex::Matrix3;
     /// 
-    /// let mut actual = Matrix3::new();
-    /// actual.set(1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0);
-    /// let expected = Matrix3::make(1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0);
+    /// let actual: [f32; 9] = Matrix3::make(1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0).into();
+    /// let expected = [1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0];
     /// assert_eq!(actual, expected);
     /// ```
     #[inline]
-    pub fn set(
-        &mut self,
-        m11: f32,
-        m21: f32,
-        m31: f32,
-        m12: f32,
-        m22: f32,
-        m32: f32,
-        m13: f32,
-        m23: f32,
-        m33: f32,
-    ) {
-        self.set_m11(m11);
-        self.set_m21(m21);
-        self.set_m31(m31);
-        self.set_m12(m12);
-        self.set_m22(m22);
-        self.set_m32(m32);
-        self.set_m13(m13);
-        self.set_m23(m23);
-        self.set_m33(m33);
+    fn from(item: Matrix3) -> [f32; 9] {
+        item.m
     }
+}
 
-    /// Transposes the matrix's elements
+impl AsRef<[f32]> for Matrix3 {
+    /// Gets the elements as a slice in column-major order
     ///
     /// # Examples
     /// ```
     /// use vex::Matrix3;
     /// 
-    /// let mut actual = Matrix3::make(1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0);
-    /// actual.transpose();
-    /// let expected = Matrix3::make(1.0, 4.0, 7.0, 2.0, 5.0, 8.0, 3.0, 6.0, 9.0);
-    /// assert_eq!(actual, expected);
+    /// let v = Matrix3::make(1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0);
+    /// let actual: &[f32] = v.as_ref();
+    /// assert_eq!(actual, &[1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0][..]);
     /// ```
     #[inline]
-    pub fn transpose(&mut self) {
-        let mut m = self.m;
-
-        let temp = m[1];
-        m[1] = m[3];
-        m[3] = temp;
-        let temp = m[5];
-        m[5] = m[7];
-        m[7] = temp;
-        let temp = m[2];
-        m[2] = m[6];
-        m[6] = temp;
-        self.m = m;
+    fn as_ref(&self) -> &[f32] {
+        &self.m
     }
+}
 
-    /// Find the matrix's determinant
+impl AsMut<[f32]> for Matrix3 {
+    /// Gets the elements as a mutable slice in column-major order
     ///
     /// # Examples
     /// ```
     /// use vex::Matrix3;
     /// 
-    /// let actual = Matrix3::make(1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0).determinant();
-    /// assert_eq!(actual, 0.0);
+    /// let mut v = Matrix3::make(1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0);
+    /// v.as_mut()[0] = 5.0;
+    /// assert_eq!(v.as_ref()[0], 5.0);
     /// ```
     #[inline]
-    pub fn determinant(&self) -> f32 {
-        self.m11() * (self.m22() * self.m33() - self.m23() * self.m32())
-            - (self.m12() * (self.m21() * self.m33() - self.m23() * self.m31()))
-            + (self.m13() * (self.m21() * self.m32() - self.m22() * self.m31()))
+    fn as_mut(&mut self) -> &mut [f32] {
+        &mut self.m
     }
+}
 
-    /// Inverses the matrix
+impl Index<(usize, usize)> for Matrix3 {
+    type Output = f32;
+
+    /// Looks up an element by its row and column, counting from 0
     ///
     /// # Examples
     /// ```
     /// use vex::Matrix3;
     /// 
-    /// let mut actual = Matrix3::make(1.0, 0.0, 5.0, 2.0, 1.0, 6.0, 3.0, 4.0, 0.0);
-    /// actual.inverse();
-    /// let expected = Matrix3::make(-24.0, 20.0, -5.0, 18.0, -15.0, 4.0, 5.0, -4.0, 1.0);
-    /// assert_eq!(actual, expected);
+    /// let m = Matrix3::make(1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0);
+    /// assert_eq!(m[(1, 0)], 2.0);
+    /// assert_eq!(m[(0, 1)], 4.0);
     /// ```
     #[inline]
-    pub fn inverse(&mut self) -> bool {
-        let det = self.determinant();
-        if det == 0.0 {
-            return false;
-        }
-
-        let inv_det = 1.0 / det;
-        let m11 =
-            Matrix2::make(self.m22(), self.m23(), self.m32(), self.m33()).determinant() * inv_det;
-        let m21 =
-            Matrix2::make(self.m23(), self.m21(), self.m33(), self.m31()).determinant() * inv_det;
-        let m31 =
-            Matrix2::make(self.m21(), self.m22(), self.m31(), self.m32()).determinant() * inv_det;
-        let m12 =
-            Matrix2::make(self.m13(), self.m12(), self.m33(), self.m32()).determinant() * inv_det;
-        let m22 =
-            Matrix2::make(self.m11(), self.m13(), self.m31(), self.m33()).determinant() * inv_det;
-        let m32 =
-            Matrix2::make(self.m12(), self.m11(), self.m32(), self.m31()).determinant() * inv_det;
-        let m13 =
-            Matrix2::make(self.m12(), self.m13(), self.m22(), self.m23()).determinant() * inv_det;
-        let m23 =
-            Matrix2::make(self.m13(), self.m11(), self.m23(), self.m21()).determinant() * inv_det;
-        let m33 =
-            Matrix2::make(self.m11(), self.m12(), self.m21(), self.m22()).determinant() * inv_det;
-
-        self.set_m11(m11);
-        self.set_m21(m21);
-        self.set_m31(m31);
-        self.set_m12(m12);
-        self.set_m22(m22);
-        self.set_m32(m32);
-        self.set_m13(m13);
-        self.set_m23(m23);
-        self.set_m33(m33);
-        true
+    fn index(&self, index: (usize, usize)) -> &f32 {
+        let (row, col) = index;
+        assert!(row < 3 && col < 3, "Invalid index for Matrix3: {:?}", index);
+        &self.m[col * 3 + row]
     }
+}
 
-    /// Determine whether or not all elements of the matrix are valid
+impl IndexMut<(usize, usize)> for Matrix3 {
+    /// Mutate an element by its row and column, counting from 0
     ///
     /// # Examples
     /// ```
     /// use vex::Matrix3;
     /// 
-    /// let actual = Matrix3::make(1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0);
-    /// assert!(actual.is_valid());
+    /// let mut m = Matrix3::new();
+    /// m[(0, 1)] = 5.0;
+    /// assert_eq!(m.m12(), 5.0);
     /// ```
     #[inline]
-    pub fn is_valid(&self) -> bool {
-        for i in 0..9 {
-            if !common::is_valid(self.m[i]) {
-                return false;
-            }
-        }
-
-        true
+    fn index_mut(&mut self, index: (usize, usize)) -> &mut f32 {
+        let (row, col) = index;
+        assert!(row < 3 && col < 3, "Invalid index for Matrix3: {:?}", index);
+        &mut self.m[col * 3 + row]
     }
 }
 
@@ -516,10 +1656,8 @@ impl Neg for Matrix3 {
     fn neg(self) -> Matrix3 {
         let mut m = [0.0; 9];
 
-        unsafe {
-            for (i, elem) in self.m.iter().enumerate() {
-                m[i] = -*elem;
-            }
+        for (i, elem) in self.m.iter().enumerate() {
+            m[i] = -*elem;
         }
 
         Matrix3 { m }
@@ -543,10 +1681,33 @@ impl Add<f32> for Matrix3 {
     fn add(self, _rhs: f32) -> Matrix3 {
         let mut mat = Matrix3::new();
 
-        unsafe {
-            for (i, elem) in self.m.iter().enumerate() {
-                mat.m[i] = *elem + _rhs;
-            }
+        for (i, elem) in self.m.iter().enumerate() {
+            mat.m[i] = *elem + _rhs;
+        }
+
+        mat
+    }
+}
+
+impl Add<Matrix3> for f32 {
+    type Output = Matrix3;
+
+    /// Find the resulting matrix by adding a matrix's elements to a scalar
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Matrix3;
+    /// 
+    /// let actual = 1.0 + Matrix3::make(1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0);
+    /// let expected = Matrix3::make(2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0, 10.0);
+    /// assert_eq!(actual, expected);
+    /// ```
+    #[inline]
+    fn add(self, _rhs: Matrix3) -> Matrix3 {
+        let mut mat = Matrix3::new();
+
+        for (i, elem) in _rhs.m.iter().enumerate() {
+            mat.m[i] = self + *elem;
         }
 
         mat
@@ -572,10 +1733,8 @@ impl Add<Matrix3> for Matrix3 {
     fn add(self, _rhs: Matrix3) -> Matrix3 {
         let mut mat = Matrix3::new();
 
-        unsafe {
-            for (i, elem) in self.m.iter().enumerate() {
-                mat.m[i] = *elem + _rhs.m[i];
-            }
+        for (i, elem) in self.m.iter().enumerate() {
+            mat.m[i] = *elem + _rhs.m[i];
         }
 
         mat
@@ -596,10 +1755,8 @@ impl AddAssign<f32> for Matrix3 {
     /// ```
     #[inline]
     fn add_assign(&mut self, _rhs: f32) {
-        unsafe {
-            for elem in self.m.iter_mut() {
-                *elem += _rhs;
-            }
+        for elem in self.m.iter_mut() {
+            *elem += _rhs;
         }
     }
 }
@@ -618,10 +1775,8 @@ impl AddAssign<Matrix3> for Matrix3 {
     /// ```
     #[inline]
     fn add_assign(&mut self, _rhs: Matrix3) {
-        unsafe {
-            for (i, elem) in self.m.iter_mut().enumerate() {
-                *elem += _rhs.m[i];
-            }
+        for (i, elem) in self.m.iter_mut().enumerate() {
+            *elem += _rhs.m[i];
         }
     }
 }
@@ -643,10 +1798,33 @@ impl Sub<f32> for Matrix3 {
     fn sub(self, _rhs: f32) -> Matrix3 {
         let mut mat = Matrix3::new();
 
-        unsafe {
-            for (i, elem) in self.m.iter().enumerate() {
-                mat.m[i] = *elem - _rhs;
-            }
+        for (i, elem) in self.m.iter().enumerate() {
+            mat.m[i] = *elem - _rhs;
+        }
+
+        mat
+    }
+}
+
+impl Sub<Matrix3> for f32 {
+    type Output = Matrix3;
+
+    /// Find the resulting matrix by subtracting a matrix's elements from a scalar
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Matrix3;
+    /// 
+    /// let actual = 10.0 - Matrix3::make(1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0);
+    /// let expected = Matrix3::make(9.0, 8.0, 7.0, 6.0, 5.0, 4.0, 3.0, 2.0, 1.0);
+    /// assert_eq!(actual, expected);
+    /// ```
+    #[inline]
+    fn sub(self, _rhs: Matrix3) -> Matrix3 {
+        let mut mat = Matrix3::new();
+
+        for (i, elem) in _rhs.m.iter().enumerate() {
+            mat.m[i] = self - *elem;
         }
 
         mat
@@ -672,10 +1850,8 @@ impl Sub<Matrix3> for Matrix3 {
     fn sub(self, _rhs: Matrix3) -> Matrix3 {
         let mut mat = Matrix3::new();
 
-        unsafe {
-            for (i, elem) in self.m.iter().enumerate() {
-                mat.m[i] = *elem - _rhs.m[i];
-            }
+        for (i, elem) in self.m.iter().enumerate() {
+            mat.m[i] = *elem - _rhs.m[i];
         }
 
         mat
@@ -696,10 +1872,8 @@ impl SubAssign<f32> for Matrix3 {
     /// ```
     #[inline]
     fn sub_assign(&mut self, _rhs: f32) {
-        unsafe {
-            for elem in self.m.iter_mut() {
-                *elem -= _rhs;
-            }
+        for elem in self.m.iter_mut() {
+            *elem -= _rhs;
         }
     }
 }
@@ -717,10 +1891,8 @@ impl SubAssign<Matrix3> for Matrix3 {
     /// ```
     #[inline]
     fn sub_assign(&mut self, _rhs: Matrix3) {
-        unsafe {
-            for (i, elem) in self.m.iter_mut().enumerate() {
-                *elem -= _rhs.m[i];
-            }
+        for (i, elem) in self.m.iter_mut().enumerate() {
+            *elem -= _rhs.m[i];
         }
     }
 }
@@ -742,10 +1914,33 @@ impl Mul<f32> for Matrix3 {
     fn mul(self, _rhs: f32) -> Matrix3 {
         let mut mat = Matrix3::new();
 
-        unsafe {
-            for (i, elem) in self.m.iter().enumerate() {
-                mat.m[i] = *elem * _rhs;
-            }
+        for (i, elem) in self.m.iter().enumerate() {
+            mat.m[i] = *elem * _rhs;
+        }
+
+        mat
+    }
+}
+
+impl Mul<Matrix3> for f32 {
+    type Output = Matrix3;
+
+    /// Find the resulting matrix by multiplying a scalar by a matrix's elements
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Matrix3;
+    /// 
+    /// let actual = 2.0 * Matrix3::make(1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0);
+    /// let expected = Matrix3::make(2.0, 4.0, 6.0, 8.0, 10.0, 12.0, 14.0, 16.0, 18.0);
+    /// assert_eq!(actual, expected);
+    /// ```
+    #[inline]
+    fn mul(self, _rhs: Matrix3) -> Matrix3 {
+        let mut mat = Matrix3::new();
+
+        for (i, elem) in _rhs.m.iter().enumerate() {
+            mat.m[i] = self * *elem;
         }
 
         mat
@@ -796,10 +1991,8 @@ impl MulAssign<f32> for Matrix3 {
     /// ```
     #[inline]
     fn mul_assign(&mut self, _rhs: f32) {
-        unsafe {
-            for elem in self.m.iter_mut() {
-                *elem *= _rhs;
-            }
+        for elem in self.m.iter_mut() {
+            *elem *= _rhs;
         }
     }
 }
@@ -840,10 +2033,8 @@ impl Div<f32> for Matrix3 {
     fn div(self, _rhs: f32) -> Matrix3 {
         let mut mat = Matrix3::new();
 
-        unsafe {
-            for (i, elem) in self.m.iter().enumerate() {
-                mat.m[i] = *elem / _rhs;
-            }
+        for (i, elem) in self.m.iter().enumerate() {
+            mat.m[i] = *elem / _rhs;
         }
 
         mat
@@ -864,10 +2055,8 @@ impl DivAssign<f32> for Matrix3 {
     /// ```
     #[inline]
     fn div_assign(&mut self, _rhs: f32) {
-        unsafe {
-            for elem in self.m.iter_mut() {
-                *elem /= _rhs;
-            }
+        for elem in self.m.iter_mut() {
+            *elem /= _rhs;
         }
     }
 }
@@ -883,11 +2072,9 @@ impl cmp::PartialEq for Matrix3 {
     /// ```
     #[inline]
     fn eq(&self, _rhs: &Matrix3) -> bool {
-        unsafe {
-            for (i, elem) in self.m.iter().enumerate() {
-                if *elem != _rhs.m[i] {
-                    return false;
-                }
+        for (i, elem) in self.m.iter().enumerate() {
+            if *elem != _rhs.m[i] {
+                return false;
             }
         }
 
@@ -896,21 +2083,75 @@ impl cmp::PartialEq for Matrix3 {
 }
 
 impl Display for Matrix3 {
+    /// Formats the matrix as a list of its rows, with the formatter's flags applied to each element
+    ///
+    /// The alternate flag lays the rows out as a grid instead, one per line, with each column
+    /// padded to its widest element.
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Matrix3;
+    ///
+    /// let m = Matrix3::make(1.0, 0.0, 0.0, 0.0, 1.0, 0.0, 12.5, -4.0, 1.0);
+    /// assert_eq!(format!("{}", m), "[[1, 0, 12.5], [0, 1, -4], [0, 0, 1]]");
+    /// assert_eq!(
+    ///     format!("{:#}", m),
+    ///     "[\n    [1, 0, 12.5],\n    [0, 1,   -4],\n    [0, 0,    1]\n]",
+    /// );
+    /// ```
     #[inline]
     fn fmt(&self, f: &mut Formatter) -> fmt::Result {
-        write!(
-            f,
-            "[\n  {}, {}, {}\n  {}, {}, {}\n  {}, {}, {}\n]",
-            self.m11(),
-            self.m12(),
-            self.m13(),
-            self.m21(),
-            self.m22(),
-            self.m23(),
-            self.m31(),
-            self.m32(),
-            self.m33(),
-        )
+        let rows: [[f32; 3]; 3] = array::from_fn(|r| array::from_fn(|c| self.m[c * 3 + r]));
+        format::write_matrix(f, &rows)
+    }
+}
+
+impl Debug for Matrix3 {
+    /// Formats the matrix's elements grouped by column, in the order they're stored
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Matrix3;
+    ///
+    /// let m = Matrix3::make(1.0, 0.0, 0.0, 0.0, 1.0, 0.0, 12.5, -4.0, 1.0);
+    /// assert_eq!(
+    ///     format!("{:?}", m),
+    ///     "Matrix3 { cols: [[1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [12.5, -4.0, 1.0]] }",
+    /// );
+    /// ```
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let cols: [[f32; 3]; 3] = array::from_fn(|c| array::from_fn(|r| self.m[c * 3 + r]));
+        f.debug_struct("Matrix3").field("cols", &cols).finish()
+    }
+}
+
+impl FromStr for Matrix3 {
+    type Err = ParseError;
+
+    /// Parses a matrix written as a list of its rows, as `Display` writes it
+    ///
+    /// Whitespace between elements and rows is ignored, so alternate grid output parses too.
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Matrix3;
+    /// use vex::ParseError;
+    ///
+    /// let m = Matrix3::make(1.0, 0.0, 0.0, 0.0, 1.0, 0.0, 12.5, -4.0, 1.0);
+    /// assert_eq!("[[1, 0, 12.5], [0, 1, -4], [0, 0, 1]]".parse::<Matrix3>(), Ok(m));
+    /// assert_eq!(format!("{:#}", m).parse::<Matrix3>(), Ok(m));
+    /// assert_eq!(format!("{:8.3}", m).parse::<Matrix3>(), Ok(m));
+    ///
+    /// let actual = "[[1, 2], [1, 2]]".parse::<Matrix3>();
+    /// assert_eq!(actual, Err(ParseError::Count { expected: 3, found: 2 }));
+    /// ```
+    #[inline]
+    fn from_str(s: &str) -> Result<Matrix3, ParseError> {
+        let rows: [[f32; 3]; 3] = parse::parse_matrix(s)?;
+        Ok(Matrix3 {
+            m: array::from_fn(|i| rows[i % 3][i / 3]),
+        })
     }
 }
 
@@ -962,3 +2203,44 @@ impl common::Matrix<Vector3> for Matrix3 {
         )
     }
 }
+
+impl common::SquareMatrix for Matrix3 {
+    #[inline]
+    fn identity() -> Matrix3 {
+        Matrix3::new()
+    }
+
+    #[inline]
+    fn determinant(&self) -> f32 {
+        Matrix3::determinant(self)
+    }
+
+    #[inline]
+    fn transposed(&self) -> Matrix3 {
+        Matrix3::transposed(self)
+    }
+
+    #[inline]
+    fn try_inverse(&self) -> Option<Matrix3> {
+        Matrix3::try_inverse(self)
+    }
+
+    #[inline]
+    fn approx_eq(&self, other: &Matrix3, epsilon: f32) -> bool {
+        Matrix3::approx_eq(self, other, epsilon)
+    }
+}
+
+impl common::MixFactor<Matrix3> for f32 {
+    #[inline]
+    fn spread(self) -> Matrix3 {
+        Matrix3 { m: [self; 9] }
+    }
+}
+
+impl common::MixFactor<Matrix3> for Matrix3 {
+    #[inline]
+    fn spread(self) -> Matrix3 {
+        self
+    }
+}
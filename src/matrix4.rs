@@ -1,13 +1,30 @@
+use crate::clip_space::{ClipSpace, DepthRange};
 use crate::common;
+use crate::epsilon::Epsilon;
+use crate::euler::{self, EulerOrder};
+use crate::format;
+#[cfg(not(feature = "std"))]
+use crate::math::Float;
 use crate::matrix3::Matrix3;
+use crate::non_zero_scale::NonZeroScale;
+use crate::parse::{self, ParseError};
+#[cfg(feature = "simd")]
+use crate::simd;
+use crate::storage::{ColumnMajor, RowMajor};
+use crate::unit_vector3::UnitVector3;
 use crate::vector3::Vector3;
 use crate::vector4::Vector4;
 
-use std::cmp;
-use std::fmt;
-use std::fmt::{Display, Formatter};
+use core::array;
+use core::cmp;
+use core::fmt;
+use core::fmt::{Debug, Display, Formatter};
+use core::slice;
+use core::str::FromStr;
 
-use std::ops::{
+use core::ops::{
+    Index,
+    IndexMut,
     Neg,
     Add,
     AddAssign,
@@ -19,13 +36,59 @@ use std::ops::{
     DivAssign,
 };
 
-#[repr(C, packed)]
-#[derive(Copy, Clone, Debug)]
+/// Aligned to 16 bytes so it can be loaded directly into SIMD registers
+///
+/// # Examples
+/// ```
+/// use std::mem;
+/// use vex::Matrix4;
+///
+/// assert_eq!(mem::align_of::<Matrix4>(), 16);
+/// assert_eq!(mem::size_of::<Matrix4>(), 64);
+/// ```
+#[repr(C, align(16))]
+#[derive(Copy, Clone)]
+#[cfg_attr(feature = "rkyv", derive(rkyv::Archive, rkyv::Deserialize, rkyv::Serialize))]
+#[cfg_attr(feature = "rkyv", rkyv(derive(Debug), compare(PartialEq)))]
 pub struct Matrix4 {
     pub m: [f32; 16],
 }
 
 impl Matrix4 {
+    /// The identity matrix
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Matrix4;
+    /// 
+    /// assert_eq!(Matrix4::IDENTITY, Matrix4::new());
+    /// ```
+    pub const IDENTITY: Matrix4 = Matrix4 {
+        m: [
+            1.0, 0.0, 0.0, 0.0,
+            0.0, 1.0, 0.0, 0.0,
+            0.0, 0.0, 1.0, 0.0,
+            0.0, 0.0, 0.0, 1.0,
+        ],
+    };
+
+    /// A matrix with every element set to zero
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Matrix4;
+    ///
+    /// assert_eq!(Matrix4::ZERO, Matrix4::IDENTITY * 0.0);
+    /// ```
+    pub const ZERO: Matrix4 = Matrix4 {
+        m: [
+            0.0, 0.0, 0.0, 0.0,
+            0.0, 0.0, 0.0, 0.0,
+            0.0, 0.0, 0.0, 0.0,
+            0.0, 0.0, 0.0, 0.0,
+        ],
+    };
+
     /// Creates a matrix set to its identity
     ///
     /// # Examples
@@ -43,7 +106,7 @@ impl Matrix4 {
     /// });
     /// ```
     #[inline]
-    pub fn new() -> Matrix4 {
+    pub const fn new() -> Matrix4 {
         Matrix4 {
             m: [
                 1.0, 0.0, 0.0, 0.0,
@@ -63,9 +126,17 @@ impl Matrix4 {
     /// let actual = Matrix4::make(1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0, 10.0, 11.0, 12.0, 13.0, 14.0, 15.0, 16.0);
     /// let expected = [1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0, 10.0, 11.0, 12.0, 13.0, 14.0, 15.0, 16.0];
     /// assert_eq!(actual.m, expected);
+    ///
+    /// // matrices can be made at compile time, such as for tables of transforms
+    /// const MIRROR_X: Matrix4 = Matrix4::make(
+    ///     -1.0, 0.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 0.0, 1.0,
+    /// );
+    /// static MIRRORS: [Matrix4; 2] = [Matrix4::IDENTITY, MIRROR_X];
+    /// assert_eq!(MIRRORS[1].m11(), -1.0);
     /// ```
     #[inline]
-    pub fn make(
+    #[allow(clippy::too_many_arguments)]
+    pub const fn make(
         m11: f32,
         m21: f32,
         m31: f32,
@@ -90,8 +161,67 @@ impl Matrix4 {
         }
     }
 
+    /// Creates a matrix from elements in an explicit storage order
+    ///
+    /// Accepts either [`ColumnMajor`](struct.ColumnMajor.html) or
+    /// [`RowMajor`](struct.RowMajor.html), so the order of a raw array has to be spelled out at the
+    /// call site.
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::ColumnMajor;
+    /// use vex::Matrix4;
+    /// use vex::RowMajor;
+    ///
+    /// let columns = [1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0, 10.0, 11.0, 12.0, 13.0, 14.0, 15.0, 16.0];
+    /// let rows = [1.0, 5.0, 9.0, 13.0, 2.0, 6.0, 10.0, 14.0, 3.0, 7.0, 11.0, 15.0, 4.0, 8.0, 12.0, 16.0];
+    /// let a = Matrix4::from_storage(ColumnMajor(columns));
+    /// let b = Matrix4::from_storage(RowMajor(rows));
+    /// assert_eq!(a, b);
+    /// assert_eq!(a.m14(), 13.0);
+    /// ```
+    #[inline]
+    pub fn from_storage<T: Into<ColumnMajor>>(storage: T) -> Matrix4 {
+        Matrix4 {
+            m: storage.into().0,
+        }
+    }
+
+    /// Gets the matrix's elements in column-major order
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::ColumnMajor;
+    /// use vex::Matrix4;
+    ///
+    /// let actual = Matrix4::translate(1.0, 2.0, 3.0).to_column_major();
+    /// assert_eq!(&actual.0[12..], &[1.0, 2.0, 3.0, 1.0]);
+    /// ```
+    #[inline]
+    pub fn to_column_major(&self) -> ColumnMajor {
+        ColumnMajor(self.m)
+    }
+
+    /// Gets the matrix's elements in row-major order
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Matrix4;
+    /// use vex::RowMajor;
+    ///
+    /// let actual = Matrix4::translate(1.0, 2.0, 3.0).to_row_major();
+    /// assert_eq!(&actual.0[..4], &[1.0, 0.0, 0.0, 1.0]);
+    /// ```
+    #[inline]
+    pub fn to_row_major(&self) -> RowMajor {
+        RowMajor::from(self.to_column_major())
+    }
+
     /// Creates a orthogonal projection matrix
     ///
+    /// Depth is mapped to the OpenGL clip range, so `-near` lands on -1 and `-far` on 1. Use
+    /// [`ortho_clip`](#method.ortho_clip) for other graphics APIs.
+    ///
     /// # Examples
     /// ```
     /// use vex::Matrix4;
@@ -108,24 +238,122 @@ impl Matrix4 {
     /// ```
     #[inline]
     pub fn ortho(left: f32, right: f32, top: f32, bottom: f32, near: f32, far: f32) -> Matrix4 {
+        Matrix4::ortho_clip(left, right, top, bottom, near, far, &ClipSpace::OPENGL)
+    }
+
+    /// Creates a orthogonal projection matrix for a graphics API's clip space
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::ClipSpace;
+    /// use vex::DepthRange;
+    /// use vex::Matrix4;
+    /// use vex::Matrix;
+    /// use vex::Vector3;
+    ///
+    /// let actual = Matrix4::ortho_clip(-2.0, 2.0, 1.0, -1.0, 1.0, 5.0, &ClipSpace::VULKAN);
+    /// let corner = actual.transform_point(&Vector3::make(2.0, 1.0, -1.0));
+    /// assert_eq!(corner, Vector3::make(1.0, -1.0, 0.0));
+    /// assert_eq!(actual.transform_point(&Vector3::make(0.0, 0.0, -5.0)).z, 1.0);
+    ///
+    /// let actual = Matrix4::ortho_clip(-2.0, 2.0, 1.0, -1.0, 1.0, 5.0, &ClipSpace::OPENGL);
+    /// assert_eq!(actual, Matrix4::ortho(-2.0, 2.0, 1.0, -1.0, 1.0, 5.0));
+    ///
+    /// let reverse_z = ClipSpace::make(DepthRange::OneToZero, false);
+    /// let actual = Matrix4::ortho_clip(-2.0, 2.0, 1.0, -1.0, 1.0, 5.0, &reverse_z);
+    /// assert_eq!(actual.transform_point(&Vector3::make(0.0, 0.0, -1.0)).z, 1.0);
+    /// assert_eq!(actual.transform_point(&Vector3::make(0.0, 0.0, -5.0)).z, 0.0);
+    /// ```
+    pub fn ortho_clip(
+        left: f32,
+        right: f32,
+        top: f32,
+        bottom: f32,
+        near: f32,
+        far: f32,
+        clip: &ClipSpace,
+    ) -> Matrix4 {
         let width = right - left;
         let height = top - bottom;
         let depth = far - near;
+        let y_sign = if clip.flip_y { -1.0 } else { 1.0 };
         let tx = -(right + left) / width;
         let ty = -(top + bottom) / height;
-        let tz = -(far + near) / depth;
-        let mut mat = Matrix4::new();
+        let (sz, tz) = match clip.depth {
+            DepthRange::NegativeOneToOne => (-2.0 / depth, -(far + near) / depth),
+            DepthRange::ZeroToOne => (-1.0 / depth, -near / depth),
+            DepthRange::OneToZero => (1.0 / depth, far / depth),
+        };
 
+        let mut mat = Matrix4::new();
         mat.set_m11(2.0 / width);
-        mat.set_m22(2.0 / height);
-        mat.set_m33(-2.0 / depth);
+        mat.set_m22(y_sign * 2.0 / height);
+        mat.set_m33(sz);
         mat.set_m14(tx);
-        mat.set_m24(ty);
+        mat.set_m24(y_sign * ty);
         mat.set_m34(tz);
         mat
     }
 
-    /// Creates a orthogonal projection matrix
+    /// Creates a orthogonal projection matrix for OpenGL, with depth in `[-1, 1]`
+    ///
+    /// This is the same as [`ortho`](#method.ortho).
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Matrix4;
+    ///
+    /// let actual = Matrix4::ortho_gl(-960.0, 960.0, 540.0, -540.0, -100.0, 100.0);
+    /// assert_eq!(actual, Matrix4::ortho(-960.0, 960.0, 540.0, -540.0, -100.0, 100.0));
+    /// ```
+    #[inline]
+    pub fn ortho_gl(left: f32, right: f32, top: f32, bottom: f32, near: f32, far: f32) -> Matrix4 {
+        Matrix4::ortho_clip(left, right, top, bottom, near, far, &ClipSpace::OPENGL)
+    }
+
+    /// Creates a orthogonal projection matrix with depth in `[0, 1]` and y up, for Direct3D,
+    /// Metal, and WebGPU
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Matrix4;
+    /// use vex::Matrix;
+    /// use vex::Vector3;
+    ///
+    /// let actual = Matrix4::ortho_zo(-2.0, 2.0, 1.0, -1.0, 1.0, 5.0);
+    /// let corner = actual.transform_point(&Vector3::make(2.0, 1.0, -1.0));
+    /// assert_eq!(corner, Vector3::make(1.0, 1.0, 0.0));
+    /// assert_eq!(actual.transform_point(&Vector3::make(0.0, 0.0, -5.0)).z, 1.0);
+    /// ```
+    #[inline]
+    pub fn ortho_zo(left: f32, right: f32, top: f32, bottom: f32, near: f32, far: f32) -> Matrix4 {
+        Matrix4::ortho_clip(left, right, top, bottom, near, far, &ClipSpace::DIRECT3D)
+    }
+
+    /// Creates a orthogonal projection matrix for Vulkan, with depth in `[0, 1]` and y down
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Matrix4;
+    /// use vex::Matrix;
+    /// use vex::Vector3;
+    ///
+    /// let actual = Matrix4::ortho_vk(-2.0, 2.0, 1.0, -1.0, 1.0, 5.0);
+    /// let corner = actual.transform_point(&Vector3::make(2.0, 1.0, -1.0));
+    /// assert_eq!(corner, Vector3::make(1.0, -1.0, 0.0));
+    /// assert_eq!(actual.transform_point(&Vector3::make(0.0, 0.0, -5.0)).z, 1.0);
+    /// ```
+    #[inline]
+    pub fn ortho_vk(left: f32, right: f32, top: f32, bottom: f32, near: f32, far: f32) -> Matrix4 {
+        Matrix4::ortho_clip(left, right, top, bottom, near, far, &ClipSpace::VULKAN)
+    }
+
+    /// Creates a right-handed perspective projection matrix with a vertical field of view (in
+    /// degrees)
+    ///
+    /// Depth is mapped to the OpenGL clip range, so `-near` lands on -1 and `-far` on 1 after the
+    /// perspective divide. This matches `glm::perspective` and glam's `perspective_rh_gl`. Use
+    /// [`perspective_clip`](#method.perspective_clip) for other graphics APIs.
     ///
     /// # Examples
     /// ```
@@ -135,408 +363,1009 @@ impl Matrix4 {
     /// let height = 1080;
     /// let aspect_ratio = width as f32 / height as f32;
     /// let actual = Matrix4::perspective(75.0, aspect_ratio, 1.0, 1000.0);
+    ///
+    /// // glm::perspective(glm::radians(75.0f), 1920.0f / 1080.0f, 1.0f, 1000.0f)
     /// let expected = [
-    ///      0.73306423,  0.0,        0.0,       0.0,      // column 1
-    ///      0.0,         1.3032253,  0.0,       0.0,      // column 2
-    ///      0.0,         0.0,       -1.002002, -2.002002, // column 3
-    ///      0.0,         0.0,        0.0,       0.0       // column 4
+    ///     0.73306427, 0.0,        0.0,       0.0,  // column 1
+    ///     0.0,        1.3032254,  0.0,       0.0,  // column 2
+    ///     0.0,        0.0,       -1.002002, -1.0,  // column 3
+    ///     0.0,        0.0,       -2.002002,  0.0,  // column 4
     /// ];
     ///
-    /// assert_eq!(actual.m, expected);
+    /// for (a, e) in actual.m.iter().zip(expected.iter()) {
+    ///     assert!((a - e).abs() < 0.000001);
+    /// }
     /// ```
     #[inline]
     pub fn perspective(fov: f32, aspect_ratio: f32, near: f32, far: f32) -> Matrix4 {
+        Matrix4::perspective_clip(fov, aspect_ratio, near, far, &ClipSpace::OPENGL)
+    }
+
+    /// Creates a right-handed perspective projection matrix with a vertical field of view (in
+    /// degrees) for a graphics API's clip space
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::ClipSpace;
+    /// use vex::DepthRange;
+    /// use vex::Matrix4;
+    /// use vex::Matrix;
+    /// use vex::Vector4;
+    ///
+    /// let actual = Matrix4::perspective_clip(90.0, 1.0, 1.0, 10.0, &ClipSpace::VULKAN);
+    /// let clip = actual.transform_point(&Vector4::make(1.0, 1.0, -1.0, 1.0));
+    /// assert!((clip.y / clip.w + 1.0).abs() < 0.000001);
+    /// assert_eq!(clip.z / clip.w, 0.0);
+    ///
+    /// let clip = actual.transform_point(&Vector4::make(0.0, 0.0, -10.0, 1.0));
+    /// assert!((clip.z / clip.w - 1.0).abs() < 0.000001);
+    ///
+    /// let actual = Matrix4::perspective_clip(75.0, 1.5, 1.0, 100.0, &ClipSpace::OPENGL);
+    /// assert_eq!(actual, Matrix4::perspective(75.0, 1.5, 1.0, 100.0));
+    ///
+    /// let reverse_z = ClipSpace::make(DepthRange::OneToZero, false);
+    /// let actual = Matrix4::perspective_clip(75.0, 1.5, 1.0, 100.0, &reverse_z);
+    /// assert_eq!(actual, Matrix4::perspective_reverse_z_far(75.0, 1.5, 1.0, 100.0));
+    /// ```
+    pub fn perspective_clip(
+        fov: f32,
+        aspect_ratio: f32,
+        near: f32,
+        far: f32,
+        clip: &ClipSpace,
+    ) -> Matrix4 {
         let radians: f32 = (fov / 2.0).to_radians();
-        let sine = radians.sin();
-        let cotangent = radians.cos() / sine;
+        let cotangent = radians.cos() / radians.sin();
         let depth = far - near;
+        let y_sign = if clip.flip_y { -1.0 } else { 1.0 };
+        let (sz, tz) = match clip.depth {
+            DepthRange::NegativeOneToOne => (-(far + near) / depth, -2.0 * near * far / depth),
+            DepthRange::ZeroToOne => (-far / depth, -near * far / depth),
+            DepthRange::OneToZero => (near / depth, near * far / depth),
+        };
 
-        // setup the projection matrix
         let mut mat = Matrix4::new();
         mat.set_m11(cotangent / aspect_ratio);
-        mat.set_m22(cotangent);
-        mat.set_m33(-(far + near) / depth);
+        mat.set_m22(y_sign * cotangent);
+        mat.set_m33(sz);
         mat.set_m43(-1.0);
-        mat.set_m43(-2.0 * near * far / depth);
+        mat.set_m34(tz);
         mat.set_m44(0.0);
         mat
     }
 
-    /// Creates a look-at matrix
+    /// Creates a right-handed perspective projection matrix for OpenGL, with depth in `[-1, 1]`
+    ///
+    /// This is the same as [`perspective`](#method.perspective).
     ///
     /// # Examples
     /// ```
     /// use vex::Matrix4;
-    /// use vex::Vector3;
-    ///
-    /// let position = Vector3::make(0.0, 1.0, 1.0);
-    /// let target = Vector3::new();
-    /// let actual = Matrix4::look_at(position, target, Vector3::up());
-    /// let expected = [
-    ///   1.0, 0.0,         0.0,        0.0, // column 1
-    ///   0.0, 0.70710677, -0.70710677, 0.0, // column 2
-    ///   0.0, 0.70710677,  0.70710677, 0.0, // column 3
-    ///   0.0, 1.0,         1.0,        1.0, // column 4
-    /// ];
     ///
-    /// assert_eq!(actual.m, expected);
+    /// let actual = Matrix4::perspective_gl(75.0, 1.5, 1.0, 100.0);
+    /// assert_eq!(actual, Matrix4::perspective(75.0, 1.5, 1.0, 100.0));
     /// ```
     #[inline]
-    pub fn look_at(position: Vector3, target: Vector3, up: Vector3) -> Matrix4 {
-        let mut forward = target - position;
-        forward.norm();
-
-        let mut right = Vector3::cross(&forward, &up);
-        right.norm();
-        let up = Vector3::cross(&right, &forward);
-
-        Matrix4::make(
-            right.x, right.y, right.z, 0.0, up.x, up.y, up.z, 0.0, -forward.x, -forward.y,
-            -forward.z, 0.0, position.x, position.y, position.z, 1.0,
-        )
+    pub fn perspective_gl(fov: f32, aspect_ratio: f32, near: f32, far: f32) -> Matrix4 {
+        Matrix4::perspective_clip(fov, aspect_ratio, near, far, &ClipSpace::OPENGL)
     }
 
-    /// Creates a translation matrix
+    /// Creates a right-handed perspective projection matrix with depth in `[0, 1]` and y up, for
+    /// Direct3D, Metal, and WebGPU
+    ///
+    /// This matches glam's `perspective_rh` and `glm::perspectiveRH_ZO`.
     ///
     /// # Examples
     /// ```
     /// use vex::Matrix4;
-    /// use vex::Vector3;
+    /// use vex::Matrix;
+    /// use vex::Vector4;
     ///
-    /// let position = Vector3::make(0.0, 1.0, 1.0);
-    /// let target = Vector3::new();
-    /// let actual = Matrix4::translate(1.0, 2.0, 3.0);
+    /// let actual = Matrix4::perspective_zo(90.0, 2.0, 0.5, 50.0);
+    ///
+    /// // glm::perspectiveRH_ZO(glm::radians(90.0f), 2.0f, 0.5f, 50.0f)
     /// let expected = [
-    ///   1.0, 0.0, 0.0, 0.0, // column 1
-    ///   0.0, 1.0, 0.0, 0.0, // column 2
-    ///   0.0, 0.0, 1.0, 0.0, // column 3
-    ///   1.0, 2.0, 3.0, 1.0, // column 4
+    ///     0.5, 0.0,  0.0,        0.0, // column 1
+    ///     0.0, 1.0,  0.0,        0.0, // column 2
+    ///     0.0, 0.0, -1.010101,  -1.0, // column 3
+    ///     0.0, 0.0, -0.5050505,  0.0, // column 4
     /// ];
     ///
-    /// assert_eq!(actual.m, expected);
+    /// for (a, e) in actual.m.iter().zip(expected.iter()) {
+    ///     assert!((a - e).abs() < 0.000001);
+    /// }
+    ///
+    /// let clip = actual.transform_point(&Vector4::make(0.0, 0.0, -0.5, 1.0));
+    /// assert_eq!(clip.z / clip.w, 0.0);
     /// ```
     #[inline]
-    pub fn translate(x: f32, y: f32, z: f32) -> Matrix4 {
-        let mut mat = Matrix4::new();
-        mat.set_m14(x);
-        mat.set_m24(y);
-        mat.set_m34(z);
-        mat
+    pub fn perspective_zo(fov: f32, aspect_ratio: f32, near: f32, far: f32) -> Matrix4 {
+        Matrix4::perspective_clip(fov, aspect_ratio, near, far, &ClipSpace::DIRECT3D)
     }
 
-    /// Creates an x-rotation matrix
+    /// Creates a right-handed perspective projection matrix for Vulkan, with depth in `[0, 1]`
+    /// and y down
     ///
     /// # Examples
     /// ```
     /// use vex::Matrix4;
-    /// use vex::Vector3;
-    ///
-    /// let actual = Matrix4::rotate_x(1.5707);
-    /// let expected = [
-    ///     1.0,  0.0,           0.0,           0.0,
-    ///     0.0,  0.00009627739, 1.0,           0.0,
-    ///     0.0, -1.0,           0.00009627739, 0.0,
-    ///     0.0,  0.0,           0.0,           1.0,
-    /// ];
     ///
-    /// assert_eq!(actual.m, expected);
+    /// let actual = Matrix4::perspective_vk(90.0, 2.0, 0.5, 50.0);
+    /// let expected = Matrix4::perspective_zo(90.0, 2.0, 0.5, 50.0);
+    /// assert_eq!(actual.m22(), -expected.m22());
+    /// assert_eq!(actual.m33(), expected.m33());
     /// ```
     #[inline]
-    pub fn rotate_x(angle: f32) -> Matrix4 {
-        let mut mat = Matrix4::new();
-        mat.set_m22(angle.cos());
-        mat.set_m32(angle.sin());
-        mat.set_m23(-angle.sin());
-        mat.set_m33(angle.cos());
-        mat
+    pub fn perspective_vk(fov: f32, aspect_ratio: f32, near: f32, far: f32) -> Matrix4 {
+        Matrix4::perspective_clip(fov, aspect_ratio, near, far, &ClipSpace::VULKAN)
     }
 
-    /// Creates an y-rotation matrix
+    /// Creates a right-handed perspective projection matrix with no far plane
+    ///
+    /// This is the limit of [`perspective`](#method.perspective) as `far` approaches infinity, and
+    /// matches `glm::infinitePerspective` and glam's `perspective_infinite_rh` for the OpenGL clip
+    /// range.
     ///
     /// # Examples
     /// ```
     /// use vex::Matrix4;
-    /// use vex::Vector3;
+    /// use vex::Matrix;
+    /// use vex::Vector4;
     ///
-    /// let actual = Matrix4::rotate_y(1.5707);
+    /// let actual = Matrix4::perspective_infinite(90.0, 2.0, 0.5);
+    ///
+    /// // glm::infinitePerspective(glm::radians(90.0f), 2.0f, 0.5f)
     /// let expected = [
-    ///     0.00009627739,  0.0, 1.0,           0.0, // column 1
-    ///     0.0,            1.0, 0.0,           0.0, // column 2
-    ///     0.0,            0.0, 0.00009627739, 0.0, // column 3
-    ///     0.0,            0.0, 0.0,           1.0, // column 4
+    ///     0.5, 0.0,  0.0,  0.0, // column 1
+    ///     0.0, 1.0,  0.0,  0.0, // column 2
+    ///     0.0, 0.0, -1.0, -1.0, // column 3
+    ///     0.0, 0.0, -1.0,  0.0, // column 4
     /// ];
     ///
-    /// assert_eq!(actual.m, expected);
+    /// for (a, e) in actual.m.iter().zip(expected.iter()) {
+    ///     assert!((a - e).abs() < 0.000001);
+    /// }
+    ///
+    /// // the near plane still maps to -1
+    /// let clip = actual.transform_point(&Vector4::make(0.0, 0.0, -0.5, 1.0));
+    /// assert_eq!(clip.z / clip.w, -1.0);
     /// ```
     #[inline]
-    pub fn rotate_y(angle: f32) -> Matrix4 {
+    pub fn perspective_infinite(fov: f32, aspect_ratio: f32, near: f32) -> Matrix4 {
+        let radians: f32 = (fov / 2.0).to_radians();
+        let cotangent = radians.cos() / radians.sin();
+
         let mut mat = Matrix4::new();
-        mat.set_m11(angle.cos());
-        mat.set_m31(-angle.sin());
-        mat.set_m31(angle.sin());
-        mat.set_m33(angle.cos());
+        mat.set_m11(cotangent / aspect_ratio);
+        mat.set_m22(cotangent);
+        mat.set_m33(-1.0);
+        mat.set_m43(-1.0);
+        mat.set_m34(-2.0 * near);
+        mat.set_m44(0.0);
         mat
     }
 
-    /// Creates an z-rotation matrix
+    /// Creates a right-handed reverse-Z perspective projection matrix with no far plane
+    ///
+    /// Depth is mapped to `[0, 1]` backwards, so `-near` lands on 1 and points approach 0 as they
+    /// recede. Paired with a floating-point depth buffer cleared to 0 and a greater-than depth
+    /// test, this spreads precision far more evenly across the view than the standard mapping.
+    /// This matches glam's `perspective_infinite_reverse_rh`.
     ///
     /// # Examples
     /// ```
     /// use vex::Matrix4;
-    /// use vex::Vector3;
+    /// use vex::Matrix;
+    /// use vex::Vector4;
     ///
-    /// let actual = Matrix4::rotate_z(1.5707);
+    /// let actual = Matrix4::perspective_reverse_z(90.0, 2.0, 0.5);
     /// let expected = [
-    ///     0.00009627739, -1.0,           0.0, 0.0, // column 1
-    ///     1.0,            0.00009627739, 0.0, 0.0, // column 2
-    ///     0.0,            0.0,           1.0, 0.0, // column 3
-    ///     0.0,            0.0,           0.0, 1.0, // column 4
+    ///     0.5, 0.0, 0.0,  0.0, // column 1
+    ///     0.0, 1.0, 0.0,  0.0, // column 2
+    ///     0.0, 0.0, 0.0, -1.0, // column 3
+    ///     0.0, 0.0, 0.5,  0.0, // column 4
     /// ];
     ///
-    /// assert_eq!(actual.m, expected);
+    /// for (a, e) in actual.m.iter().zip(expected.iter()) {
+    ///     assert!((a - e).abs() < 0.000001);
+    /// }
+    ///
+    /// let near = actual.transform_point(&Vector4::make(0.0, 0.0, -0.5, 1.0));
+    /// assert_eq!(near.z / near.w, 1.0);
+    ///
+    /// let far = actual.transform_point(&Vector4::make(0.0, 0.0, -1000000.0, 1.0));
+    /// assert!(far.z / far.w > 0.0 && far.z / far.w < 0.000001);
     /// ```
     #[inline]
-    pub fn rotate_z(angle: f32) -> Matrix4 {
+    pub fn perspective_reverse_z(fov: f32, aspect_ratio: f32, near: f32) -> Matrix4 {
+        let radians: f32 = (fov / 2.0).to_radians();
+        let cotangent = radians.cos() / radians.sin();
+
         let mut mat = Matrix4::new();
-        mat.set_m11(angle.cos());
-        mat.set_m21(-angle.sin());
-        mat.set_m12(angle.sin());
-        mat.set_m22(angle.cos());
+        mat.set_m11(cotangent / aspect_ratio);
+        mat.set_m22(cotangent);
+        mat.set_m33(0.0);
+        mat.set_m43(-1.0);
+        mat.set_m34(near);
+        mat.set_m44(0.0);
         mat
     }
 
-    /// Creates a scale matrix
+    /// Creates a right-handed reverse-Z perspective projection matrix with a far plane
+    ///
+    /// Depth is mapped to `[0, 1]` backwards, so `-near` lands on 1 and `-far` on 0. Every depth is
+    /// one minus the depth [`perspective_zo`](#method.perspective_zo) gives the same point. This
+    /// matches glam's `perspective_rh` with `near` and `far` swapped.
     ///
     /// # Examples
     /// ```
     /// use vex::Matrix4;
-    /// 
-    /// let actual = Matrix4::scale(1.0, 2.0, 3.0);
-    /// let expected = [
-    ///     1.0, 0.0, 0.0, 0.0, // column 1
-    ///     0.0, 2.0, 0.0, 0.0, // column 2
-    ///     0.0, 0.0, 3.0, 0.0, // column 3
-    ///     0.0, 0.0, 0.0, 1.0, // column 4
-    /// ];
-    /// assert_eq!(actual.m, expected);
+    /// use vex::Matrix;
+    /// use vex::Vector4;
+    ///
+    /// let actual = Matrix4::perspective_reverse_z_far(75.0, 1.5, 0.5, 100.0);
+    /// let standard = Matrix4::perspective_zo(75.0, 1.5, 0.5, 100.0);
+    /// for &z in [-0.5, -1.0, -10.0, -60.0, -100.0].iter() {
+    ///     let p = Vector4::make(1.0, -2.0, z, 1.0);
+    ///     let reversed = actual.transform_point(&p);
+    ///     let expected = standard.transform_point(&p);
+    ///     assert_eq!(reversed.x, expected.x);
+    ///     assert_eq!(reversed.w, expected.w);
+    ///     assert!((reversed.z / reversed.w - (1.0 - expected.z / expected.w)).abs() < 0.00001);
+    /// }
+    ///
+    /// // approaches the infinite projection as the far plane recedes
+    /// let far = Matrix4::perspective_reverse_z_far(75.0, 1.5, 0.5, 1000000.0);
+    /// assert!(far.approx_eq(&Matrix4::perspective_reverse_z(75.0, 1.5, 0.5), 0.00001));
     /// ```
     #[inline]
-    pub fn scale(x: f32, y: f32, z: f32) -> Matrix4 {
+    pub fn perspective_reverse_z_far(fov: f32, aspect_ratio: f32, near: f32, far: f32) -> Matrix4 {
+        let radians: f32 = (fov / 2.0).to_radians();
+        let cotangent = radians.cos() / radians.sin();
+        let depth = far - near;
+
         let mut mat = Matrix4::new();
-        mat.set_m11(x);
-        mat.set_m22(y);
-        mat.set_m33(z);
+        mat.set_m11(cotangent / aspect_ratio);
+        mat.set_m22(cotangent);
+        mat.set_m33(near / depth);
+        mat.set_m43(-1.0);
+        mat.set_m34(far * near / depth);
+        mat.set_m44(0.0);
         mat
     }
 
-    /// Gets the value for the m11 element
+    /// Creates a right-handed perspective projection matrix from the bounds of the near plane
+    ///
+    /// Unlike [`perspective`](#method.perspective), the view can be off-center. This matches
+    /// `glm::frustum` and the classic `glFrustum`.
     ///
     /// # Examples
     /// ```
     /// use vex::Matrix4;
-    /// 
-    /// let actual = Matrix4::make(1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0, 10.0, 11.0, 12.0, 13.0, 14.0, 15.0, 16.0);
-    /// assert_eq!(actual.m11(), 1.0);
+    ///
+    /// let actual = Matrix4::frustum(-1.0, 3.0, -2.0, 2.0, 1.0, 10.0);
+    ///
+    /// // glm::frustum(-1.0f, 3.0f, -2.0f, 2.0f, 1.0f, 10.0f)
+    /// let expected = [
+    ///     0.5, 0.0,  0.0,        0.0, // column 1
+    ///     0.0, 0.5,  0.0,        0.0, // column 2
+    ///     0.5, 0.0, -1.2222222, -1.0, // column 3
+    ///     0.0, 0.0, -2.2222222,  0.0, // column 4
+    /// ];
+    ///
+    /// for (a, e) in actual.m.iter().zip(expected.iter()) {
+    ///     assert!((a - e).abs() < 0.000001);
+    /// }
+    ///
+    /// let symmetric = Matrix4::frustum(-1.0, 1.0, -1.0, 1.0, 1.0, 10.0);
+    /// let expected = Matrix4::perspective(90.0, 1.0, 1.0, 10.0);
+    /// for (a, e) in symmetric.m.iter().zip(expected.m.iter()) {
+    ///     assert!((a - e).abs() < 0.000001);
+    /// }
     /// ```
     #[inline]
-    pub fn m11(&self) -> f32 {
-        self.m[0]
-    }
-
-    /// Gets the value for the m21 element
+    pub fn frustum(left: f32, right: f32, bottom: f32, top: f32, near: f32, far: f32) -> Matrix4 {
+        let width = right - left;
+        let height = top - bottom;
+        let depth = far - near;
+
+        let mut mat = Matrix4::new();
+        mat.set_m11(2.0 * near / width);
+        mat.set_m22(2.0 * near / height);
+        mat.set_m13((right + left) / width);
+        mat.set_m23((top + bottom) / height);
+        mat.set_m33(-(far + near) / depth);
+        mat.set_m43(-1.0);
+        mat.set_m34(-2.0 * near * far / depth);
+        mat.set_m44(0.0);
+        mat
+    }
+
+    /// Creates a look-at matrix
+    ///
+    /// This places an object at `position` facing `target` (a camera-to-world matrix). Use
+    /// [`look_at_rh`](#method.look_at_rh) or [`look_at_lh`](#method.look_at_lh) for a view matrix.
     ///
     /// # Examples
     /// ```
     /// use vex::Matrix4;
-    /// 
-    /// let actual = Matrix4::make(1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0, 10.0, 11.0, 12.0, 13.0, 14.0, 15.0, 16.0);
-    /// assert_eq!(actual.m21(), 2.0);
+    /// use vex::Vector3;
+    ///
+    /// let position = Vector3::make(0.0, 1.0, 1.0);
+    /// let target = Vector3::new();
+    /// let actual = Matrix4::look_at(position, target, Vector3::up());
+    /// let expected = [
+    ///   1.0, 0.0,         0.0,        0.0, // column 1
+    ///   0.0, 0.70710677, -0.70710677, 0.0, // column 2
+    ///   0.0, 0.70710677,  0.70710677, 0.0, // column 3
+    ///   0.0, 1.0,         1.0,        1.0, // column 4
+    /// ];
+    ///
+    /// assert_eq!(actual.m, expected);
     /// ```
     #[inline]
-    pub fn m21(&self) -> f32 {
-        self.m[1]
+    pub fn look_at(position: Vector3, target: Vector3, up: Vector3) -> Matrix4 {
+        let mut forward = target - position;
+        forward.norm();
+
+        let mut right = Vector3::cross(&forward, &up);
+        right.norm();
+        let up = Vector3::cross(&right, &forward);
+
+        Matrix4::make(
+            right.x, right.y, right.z, 0.0, up.x, up.y, up.z, 0.0, -forward.x, -forward.y,
+            -forward.z, 0.0, position.x, position.y, position.z, 1.0,
+        )
     }
 
-    /// Gets the value for the m31 element
+    /// Creates a look-at matrix, if `target` gives a direction that isn't parallel to `up`
+    ///
+    /// This is [`look_at`](#method.look_at) with the cases that make it produce NaNs ruled out:
+    /// `up` is a unit vector, and a `target` at `position`, or straight along `up`, gives `None`.
     ///
     /// # Examples
     /// ```
     /// use vex::Matrix4;
-    /// 
-    /// let actual = Matrix4::make(1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0, 10.0, 11.0, 12.0, 13.0, 14.0, 15.0, 16.0);
-    /// assert_eq!(actual.m31(), 3.0);
+    /// use vex::UnitVector3;
+    /// use vex::Vector3;
+    ///
+    /// let position = Vector3::make(0.0, 1.0, 1.0);
+    /// let actual = Matrix4::try_look_at(position, Vector3::new(), UnitVector3::Y).unwrap();
+    /// assert_eq!(actual, Matrix4::look_at(position, Vector3::new(), Vector3::up()));
+    ///
+    /// assert!(Matrix4::try_look_at(position, position, UnitVector3::Y).is_none());
+    /// assert!(Matrix4::try_look_at(Vector3::new(), Vector3::up(), UnitVector3::Y).is_none());
     /// ```
     #[inline]
-    pub fn m31(&self) -> f32 {
-        self.m[2]
+    pub fn try_look_at(position: Vector3, target: Vector3, up: UnitVector3) -> Option<Matrix4> {
+        let forward = UnitVector3::try_new(target - position)?;
+        UnitVector3::try_new(Vector3::cross(&forward, &up))?;
+        Some(Matrix4::look_at(position, target, up.get()))
     }
 
-    /// Gets the value for the m41 element
+    /// Creates a right-handed view matrix for a camera at `eye` looking at `target`
+    ///
+    /// Unlike [`look_at`](#method.look_at), which places an object in the world, this is the
+    /// inverse: it moves the world into camera space, looking down -z. This matches `glm::lookAtRH`
+    /// and glam's `look_at_rh`.
     ///
     /// # Examples
     /// ```
+    /// use vex::Matrix;
     /// use vex::Matrix4;
-    /// 
-    /// let actual = Matrix4::make(1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0, 10.0, 11.0, 12.0, 13.0, 14.0, 15.0, 16.0);
-    /// assert_eq!(actual.m41(), 4.0);
+    /// use vex::Vector3;
+    ///
+    /// let eye = Vector3::make(0.0, 1.0, 1.0);
+    /// let actual = Matrix4::look_at_rh(eye, Vector3::new(), Vector3::up());
+    /// let expected = Matrix4::look_at(eye, Vector3::new(), Vector3::up()).try_inverse().unwrap();
+    /// for (a, e) in actual.m.iter().zip(expected.m.iter()) {
+    ///     assert!((a - e).abs() < 0.000001);
+    /// }
+    ///
+    /// // the target ends up straight ahead of the camera
+    /// let actual = actual.transform_point(&Vector3::new());
+    /// assert!((actual - Vector3::make(0.0, 0.0, -(2.0f32).sqrt())).mag() < 0.000001);
     /// ```
     #[inline]
-    pub fn m41(&self) -> f32 {
-        self.m[3]
+    pub fn look_at_rh(eye: Vector3, target: Vector3, up: Vector3) -> Matrix4 {
+        Matrix4::look_to_rh(eye, target - eye, up)
     }
 
-    /// Gets the value for the m12 element
+    /// Creates a left-handed view matrix for a camera at `eye` looking at `target`
+    ///
+    /// The camera looks down +z in view space. This matches `glm::lookAtLH` and glam's
+    /// `look_at_lh`.
     ///
     /// # Examples
     /// ```
+    /// use vex::Matrix;
     /// use vex::Matrix4;
-    /// 
-    /// let actual = Matrix4::make(1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0, 10.0, 11.0, 12.0, 13.0, 14.0, 15.0, 16.0);
-    /// assert_eq!(actual.m12(), 5.0);
+    /// use vex::Vector3;
+    ///
+    /// let eye = Vector3::make(0.0, 1.0, 1.0);
+    /// let view = Matrix4::look_at_lh(eye, Vector3::new(), Vector3::up());
+    /// let actual = view.transform_point(&Vector3::new());
+    /// assert!((actual - Vector3::make(0.0, 0.0, (2.0f32).sqrt())).mag() < 0.000001);
+    ///
+    /// let actual = view.transform_point(&Vector3::make(1.0, 0.0, 0.0));
+    /// assert!(actual.x < 0.0);
     /// ```
     #[inline]
-    pub fn m12(&self) -> f32 {
-        self.m[4]
+    pub fn look_at_lh(eye: Vector3, target: Vector3, up: Vector3) -> Matrix4 {
+        Matrix4::look_to_lh(eye, target - eye, up)
     }
 
-    /// Gets the value for the m22 element
+    /// Creates a right-handed view matrix for a camera at `eye` facing `direction`
     ///
     /// # Examples
     /// ```
     /// use vex::Matrix4;
-    /// 
-    /// let actual = Matrix4::make(1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0, 10.0, 11.0, 12.0, 13.0, 14.0, 15.0, 16.0);
-    /// assert_eq!(actual.m22(), 6.0);
+    /// use vex::Vector3;
+    ///
+    /// let eye = Vector3::make(1.0, 2.0, 3.0);
+    /// let direction = Vector3::make(0.0, -1.0, -1.0);
+    /// let actual = Matrix4::look_to_rh(eye, direction, Vector3::up());
+    /// let expected = Matrix4::look_at_rh(eye, eye + direction, Vector3::up());
+    /// assert_eq!(actual, expected);
     /// ```
     #[inline]
-    pub fn m22(&self) -> f32 {
-        self.m[5]
+    pub fn look_to_rh(eye: Vector3, direction: Vector3, up: Vector3) -> Matrix4 {
+        let mut forward = direction;
+        forward.norm();
+
+        let mut right = Vector3::cross(&forward, &up);
+        right.norm();
+        let up = Vector3::cross(&right, &forward);
+
+        Matrix4::make(
+            right.x, up.x, -forward.x, 0.0,
+            right.y, up.y, -forward.y, 0.0,
+            right.z, up.z, -forward.z, 0.0,
+            -Vector3::dot(&right, &eye), -Vector3::dot(&up, &eye), Vector3::dot(&forward, &eye), 1.0,
+        )
     }
 
-    /// Gets the value for the m32 element
+    /// Creates a left-handed view matrix for a camera at `eye` facing `direction`
     ///
     /// # Examples
     /// ```
     /// use vex::Matrix4;
-    /// 
-    /// let actual = Matrix4::make(1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0, 10.0, 11.0, 12.0, 13.0, 14.0, 15.0, 16.0);
-    /// assert_eq!(actual.m32(), 7.0);
+    /// use vex::Vector3;
+    ///
+    /// let eye = Vector3::make(1.0, 2.0, 3.0);
+    /// let direction = Vector3::make(0.0, -1.0, -1.0);
+    /// let actual = Matrix4::look_to_lh(eye, direction, Vector3::up());
+    /// let expected = Matrix4::look_at_lh(eye, eye + direction, Vector3::up());
+    /// assert_eq!(actual, expected);
     /// ```
     #[inline]
-    pub fn m32(&self) -> f32 {
-        self.m[6]
+    pub fn look_to_lh(eye: Vector3, direction: Vector3, up: Vector3) -> Matrix4 {
+        let mut forward = direction;
+        forward.norm();
+
+        let mut right = Vector3::cross(&up, &forward);
+        right.norm();
+        let up = Vector3::cross(&forward, &right);
+
+        Matrix4::make(
+            right.x, up.x, forward.x, 0.0,
+            right.y, up.y, forward.y, 0.0,
+            right.z, up.z, forward.z, 0.0,
+            -Vector3::dot(&right, &eye), -Vector3::dot(&up, &eye), -Vector3::dot(&forward, &eye), 1.0,
+        )
     }
 
-    /// Gets the value for the m42 element
+    /// Creates a translation matrix
     ///
     /// # Examples
     /// ```
     /// use vex::Matrix4;
-    /// 
-    /// let actual = Matrix4::make(1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0, 10.0, 11.0, 12.0, 13.0, 14.0, 15.0, 16.0);
-    /// assert_eq!(actual.m42(), 8.0);
+    /// use vex::Vector3;
+    ///
+    /// let position = Vector3::make(0.0, 1.0, 1.0);
+    /// let target = Vector3::new();
+    /// let actual = Matrix4::translate(1.0, 2.0, 3.0);
+    /// let expected = [
+    ///   1.0, 0.0, 0.0, 0.0, // column 1
+    ///   0.0, 1.0, 0.0, 0.0, // column 2
+    ///   0.0, 0.0, 1.0, 0.0, // column 3
+    ///   1.0, 2.0, 3.0, 1.0, // column 4
+    /// ];
+    ///
+    /// assert_eq!(actual.m, expected);
     /// ```
     #[inline]
-    pub fn m42(&self) -> f32 {
-        self.m[7]
+    pub fn translate(x: f32, y: f32, z: f32) -> Matrix4 {
+        let mut mat = Matrix4::new();
+        mat.set_m14(x);
+        mat.set_m24(y);
+        mat.set_m34(z);
+        mat
     }
 
-    /// Gets the value for the m13 element
+    /// Creates an x-rotation matrix
     ///
     /// # Examples
     /// ```
     /// use vex::Matrix4;
-    /// 
-    /// let actual = Matrix4::make(1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0, 10.0, 11.0, 12.0, 13.0, 14.0, 15.0, 16.0);
-    /// assert_eq!(actual.m13(), 9.0);
+    /// use vex::Vector3;
+    ///
+    /// let actual = Matrix4::rotate_x(1.5707);
+    /// let expected = [
+    ///     1.0,  0.0,           0.0,           0.0,
+    ///     0.0,  0.00009627739, 1.0,           0.0,
+    ///     0.0, -1.0,           0.00009627739, 0.0,
+    ///     0.0,  0.0,           0.0,           1.0,
+    /// ];
+    ///
+    /// assert_eq!(actual.m, expected);
     /// ```
     #[inline]
-    pub fn m13(&self) -> f32 {
-        self.m[8]
+    pub fn rotate_x(angle: f32) -> Matrix4 {
+        let mut mat = Matrix4::new();
+        mat.set_m22(angle.cos());
+        mat.set_m32(angle.sin());
+        mat.set_m23(-angle.sin());
+        mat.set_m33(angle.cos());
+        mat
     }
 
-    /// Gets the value for the m23 element
+    /// Creates an y-rotation matrix
     ///
     /// # Examples
     /// ```
+    /// use vex::Matrix;
     /// use vex::Matrix4;
-    /// 
-    /// let actual = Matrix4::make(1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0, 10.0, 11.0, 12.0, 13.0, 14.0, 15.0, 16.0);
-    /// assert_eq!(actual.m23(), 10.0);
+    /// use vex::Vector3;
+    ///
+    /// let actual = Matrix4::rotate_y(1.5707);
+    /// let expected = [
+    ///     0.00009627739, 0.0, -1.0,           0.0, // column 1
+    ///     0.0,           1.0,  0.0,           0.0, // column 2
+    ///     1.0,           0.0,  0.00009627739, 0.0, // column 3
+    ///     0.0,           0.0,  0.0,           1.0, // column 4
+    /// ];
+    ///
+    /// assert_eq!(actual.m, expected);
+    ///
+    /// // z rotates towards x
+    /// let actual = actual.transform_point(&Vector3::make(0.0, 0.0, 1.0));
+    /// assert!((actual - Vector3::right()).mag() < 0.001);
     /// ```
     #[inline]
-    pub fn m23(&self) -> f32 {
-        self.m[9]
+    pub fn rotate_y(angle: f32) -> Matrix4 {
+        let mut mat = Matrix4::new();
+        mat.set_m11(angle.cos());
+        mat.set_m31(-angle.sin());
+        mat.set_m13(angle.sin());
+        mat.set_m33(angle.cos());
+        mat
     }
 
-    /// Gets the value for the m33 element
+    /// Creates an z-rotation matrix
     ///
     /// # Examples
     /// ```
+    /// use vex::Matrix;
     /// use vex::Matrix4;
-    /// 
-    /// let actual = Matrix4::make(1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0, 10.0, 11.0, 12.0, 13.0, 14.0, 15.0, 16.0);
-    /// assert_eq!(actual.m33(), 11.0);
+    /// use vex::Vector3;
+    ///
+    /// let actual = Matrix4::rotate_z(1.5707);
+    /// let expected = [
+    ///      0.00009627739, 1.0,           0.0, 0.0, // column 1
+    ///     -1.0,           0.00009627739, 0.0, 0.0, // column 2
+    ///      0.0,           0.0,           1.0, 0.0, // column 3
+    ///      0.0,           0.0,           0.0, 1.0, // column 4
+    /// ];
+    ///
+    /// assert_eq!(actual.m, expected);
+    ///
+    /// // x rotates towards y
+    /// let actual = actual.transform_point(&Vector3::right());
+    /// assert!((actual - Vector3::up()).mag() < 0.001);
+    ///
+    /// // positive angles are counter-clockwise, matching rotate_x and rotate_y
+    /// let mat = Matrix4::rotate_z(std::f32::consts::FRAC_PI_2);
+    /// let actual = mat.transform_point(&Vector3::make(1.0, 0.0, 0.0));
+    /// assert!((actual - Vector3::make(0.0, 1.0, 0.0)).mag() < 0.000001);
     /// ```
     #[inline]
-    pub fn m33(&self) -> f32 {
-        self.m[10]
+    pub fn rotate_z(angle: f32) -> Matrix4 {
+        let mut mat = Matrix4::new();
+        mat.set_m11(angle.cos());
+        mat.set_m21(angle.sin());
+        mat.set_m12(-angle.sin());
+        mat.set_m22(angle.cos());
+        mat
     }
 
-    /// Gets the value for the m43 element
+    /// Creates a rotation matrix from angles (in radians) about the x, y, and z axes
+    ///
+    /// The rotation about x is applied first, then y, then z, so the result is
+    /// `rotate_z(z) * rotate_y(y) * rotate_x(x)`. Use [`from_euler`](#method.from_euler) for other
+    /// orders.
     ///
     /// # Examples
     /// ```
+    /// use vex::Matrix;
     /// use vex::Matrix4;
-    /// 
-    /// let actual = Matrix4::make(1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0, 10.0, 11.0, 12.0, 13.0, 14.0, 15.0, 16.0);
-    /// assert_eq!(actual.m43(), 12.0);
+    /// use vex::Vector3;
+    ///
+    /// let actual = Matrix4::rotate(0.3, -0.7, 1.1);
+    /// let expected = Matrix4::rotate_z(1.1) * Matrix4::rotate_y(-0.7) * Matrix4::rotate_x(0.3);
+    /// assert!(actual.approx_eq(&expected, 0.000001));
+    ///
+    /// let half_pi = std::f32::consts::PI / 2.0;
+    /// let actual = Matrix4::rotate(half_pi, 0.0, half_pi).transform_point(&Vector3::up());
+    /// assert!((actual - Vector3::make(0.0, 0.0, 1.0)).mag() < 0.0001);
     /// ```
     #[inline]
-    pub fn m43(&self) -> f32 {
-        self.m[11]
+    pub fn rotate(x: f32, y: f32, z: f32) -> Matrix4 {
+        Matrix4::from_euler(x, y, z, EulerOrder::XYZ)
     }
 
-    /// Gets the value for the m14 element
+    /// Creates a scale matrix
     ///
     /// # Examples
     /// ```
     /// use vex::Matrix4;
     /// 
-    /// let actual = Matrix4::make(1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0, 10.0, 11.0, 12.0, 13.0, 14.0, 15.0, 16.0);
-    /// assert_eq!(actual.m14(), 13.0);
+    /// let actual = Matrix4::scale(1.0, 2.0, 3.0);
+    /// let expected = [
+    ///     1.0, 0.0, 0.0, 0.0, // column 1
+    ///     0.0, 2.0, 0.0, 0.0, // column 2
+    ///     0.0, 0.0, 3.0, 0.0, // column 3
+    ///     0.0, 0.0, 0.0, 1.0, // column 4
+    /// ];
+    /// assert_eq!(actual.m, expected);
     /// ```
     #[inline]
-    pub fn m14(&self) -> f32 {
-        self.m[12]
+    pub fn scale(x: f32, y: f32, z: f32) -> Matrix4 {
+        let mut mat = Matrix4::new();
+        mat.set_m11(x);
+        mat.set_m22(y);
+        mat.set_m33(z);
+        mat
     }
 
-    /// Gets the value for the m24 element
+    /// Creates a scale matrix that is guaranteed to be invertible
     ///
     /// # Examples
     /// ```
     /// use vex::Matrix4;
-    /// 
-    /// let actual = Matrix4::make(1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0, 10.0, 11.0, 12.0, 13.0, 14.0, 15.0, 16.0);
-    /// assert_eq!(actual.m24(), 14.0);
+    /// use vex::NonZeroScale;
+    /// use vex::Vector3;
+    ///
+    /// let scale = NonZeroScale::try_new(Vector3::make(1.0, 2.0, 4.0)).unwrap();
+    /// let m = Matrix4::from_non_zero_scale(&scale);
+    /// assert_eq!(m, Matrix4::scale(1.0, 2.0, 4.0));
+    /// assert_eq!(m.try_inverse().unwrap(), Matrix4::scale(1.0, 0.5, 0.25));
     /// ```
     #[inline]
-    pub fn m24(&self) -> f32 {
-        self.m[13]
+    pub fn from_non_zero_scale(scale: &NonZeroScale) -> Matrix4 {
+        Matrix4::scale(scale.x, scale.y, scale.z)
     }
 
-    /// Gets the value for the m34 element
+    /// Creates a rotation matrix about an arbitrary axis, with the angle in radians
+    ///
+    /// The axis doesn't need to be normalized, and a zero axis gives the identity. Rotations are
+    /// counter-clockwise when looking down the axis towards the origin.
     ///
     /// # Examples
     /// ```
+    /// use vex::Matrix;
     /// use vex::Matrix4;
-    /// 
-    /// let actual = Matrix4::make(1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0, 10.0, 11.0, 12.0, 13.0, 14.0, 15.0, 16.0);
-    /// assert_eq!(actual.m34(), 15.0);
+    /// use vex::Vector3;
+    ///
+    /// let half_pi = std::f32::consts::PI / 2.0;
+    /// let m = Matrix4::from_axis_angle(Vector3::up(), half_pi);
+    /// let actual = m.transform_point(&Vector3::make(0.0, 0.0, 1.0));
+    /// assert!((actual - Vector3::right()).mag() < 0.0001);
+    ///
+    /// let m = Matrix4::from_axis_angle(Vector3::right(), 0.5);
+    /// assert!(m.approx_eq(&Matrix4::rotate_x(0.5), 0.0001));
+    ///
+    /// let m = Matrix4::from_axis_angle(Vector3::one(), 2.0 * std::f32::consts::PI / 3.0);
+    /// let actual = m.transform_point(&Vector3::up());
+    /// assert!((actual - Vector3::make(0.0, 0.0, 1.0)).mag() < 0.0001);
+    /// assert_eq!(m.m44(), 1.0);
     /// ```
     #[inline]
-    pub fn m34(&self) -> f32 {
-        self.m[14]
+    pub fn from_axis_angle(axis: Vector3, angle: f32) -> Matrix4 {
+        let r = Matrix3::from_axis_angle(axis, angle);
+        Matrix4::make(
+            r.m11(), r.m21(), r.m31(), 0.0,
+            r.m12(), r.m22(), r.m32(), 0.0,
+            r.m13(), r.m23(), r.m33(), 0.0,
+            0.0, 0.0, 0.0, 1.0,
+        )
+    }
+
+    /// Creates a rotation matrix about a unit axis, with the angle in radians
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Matrix;
+    /// use vex::Matrix4;
+    /// use vex::UnitVector3;
+    /// use vex::Vector3;
+    ///
+    /// let m = Matrix4::from_unit_axis_angle(UnitVector3::X, 0.5);
+    /// assert!(m.approx_eq(&Matrix4::rotate_x(0.5), 0.0001));
+    /// ```
+    #[inline]
+    pub fn from_unit_axis_angle(axis: UnitVector3, angle: f32) -> Matrix4 {
+        let r = Matrix3::from_unit_axis_angle(axis, angle);
+        Matrix4::make(
+            r.m11(), r.m21(), r.m31(), 0.0,
+            r.m12(), r.m22(), r.m32(), 0.0,
+            r.m13(), r.m23(), r.m33(), 0.0,
+            0.0, 0.0, 0.0, 1.0,
+        )
+    }
+
+    /// Creates a rotation matrix from Euler angles (in radians) applied in the provided order
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::EulerOrder;
+    /// use vex::Matrix;
+    /// use vex::Matrix4;
+    /// use vex::Vector3;
+    ///
+    /// let half_pi = std::f32::consts::PI / 2.0;
+    /// let m = Matrix4::from_euler(half_pi, 0.0, half_pi, EulerOrder::XYZ);
+    /// let actual = m.transform_point(&Vector3::up());
+    /// assert!((actual - Vector3::make(0.0, 0.0, 1.0)).mag() < 0.0001);
+    ///
+    /// let m = Matrix4::from_euler(half_pi, 0.0, half_pi, EulerOrder::ZYX);
+    /// let actual = m.transform_point(&Vector3::up());
+    /// assert!((actual - Vector3::make(-1.0, 0.0, 0.0)).mag() < 0.0001);
+    /// ```
+    #[inline]
+    pub fn from_euler(x: f32, y: f32, z: f32, order: EulerOrder) -> Matrix4 {
+        let r = euler::euler_to_rows(&Vector3::make(x, y, z), order);
+        Matrix4::make(
+            r[0][0], r[1][0], r[2][0], 0.0,
+            r[0][1], r[1][1], r[2][1], 0.0,
+            r[0][2], r[1][2], r[2][2], 0.0,
+            0.0, 0.0, 0.0, 1.0,
+        )
+    }
+
+    /// Find the Euler angles (in radians) that reproduce the matrix's rotation in the provided order
+    ///
+    /// The matrix must be a pure rotation and translation is ignored. The second rotation applied is kept
+    /// within `[-pi/2, pi/2]`, and at gimbal lock the third rotation is reported as zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::EulerOrder;
+    /// use vex::Matrix4;
+    /// use vex::Vector3;
+    ///
+    /// let m = Matrix4::from_euler(0.1, -0.2, 0.3, EulerOrder::YXZ);
+    /// let actual = m.to_euler(EulerOrder::YXZ);
+    /// assert!((actual - Vector3::make(0.1, -0.2, 0.3)).mag() < 0.0001);
+    /// ```
+    #[inline]
+    pub fn to_euler(&self, order: EulerOrder) -> Vector3 {
+        let rows = [
+            [self.m[0], self.m[4], self.m[8]],
+            [self.m[1], self.m[5], self.m[9]],
+            [self.m[2], self.m[6], self.m[10]],
+        ];
+        euler::rows_to_euler(&rows, order)
+    }
+
+    /// Gets the value for the m11 element
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Matrix4;
+    /// 
+    /// let actual = Matrix4::make(1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0, 10.0, 11.0, 12.0, 13.0, 14.0, 15.0, 16.0);
+    /// assert_eq!(actual.m11(), 1.0);
+    /// ```
+    #[inline]
+    pub fn m11(&self) -> f32 {
+        self.m[0]
+    }
+
+    /// Gets the value for the m21 element
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Matrix4;
+    /// 
+    /// let actual = Matrix4::make(1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0, 10.0, 11.0, 12.0, 13.0, 14.0, 15.0, 16.0);
+    /// assert_eq!(actual.m21(), 2.0);
+    /// ```
+    #[inline]
+    pub fn m21(&self) -> f32 {
+        self.m[1]
+    }
+
+    /// Gets the value for the m31 element
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Matrix4;
+    /// 
+    /// let actual = Matrix4::make(1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0, 10.0, 11.0, 12.0, 13.0, 14.0, 15.0, 16.0);
+    /// assert_eq!(actual.m31(), 3.0);
+    /// ```
+    #[inline]
+    pub fn m31(&self) -> f32 {
+        self.m[2]
+    }
+
+    /// Gets the value for the m41 element
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Matrix4;
+    /// 
+    /// let actual = Matrix4::make(1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0, 10.0, 11.0, 12.0, 13.0, 14.0, 15.0, 16.0);
+    /// assert_eq!(actual.m41(), 4.0);
+    /// ```
+    #[inline]
+    pub fn m41(&self) -> f32 {
+        self.m[3]
+    }
+
+    /// Gets the value for the m12 element
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Matrix4;
+    /// 
+    /// let actual = Matrix4::make(1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0, 10.0, 11.0, 12.0, 13.0, 14.0, 15.0, 16.0);
+    /// assert_eq!(actual.m12(), 5.0);
+    /// ```
+    #[inline]
+    pub fn m12(&self) -> f32 {
+        self.m[4]
+    }
+
+    /// Gets the value for the m22 element
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Matrix4;
+    /// 
+    /// let actual = Matrix4::make(1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0, 10.0, 11.0, 12.0, 13.0, 14.0, 15.0, 16.0);
+    /// assert_eq!(actual.m22(), 6.0);
+    /// ```
+    #[inline]
+    pub fn m22(&self) -> f32 {
+        self.m[5]
+    }
+
+    /// Gets the value for the m32 element
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Matrix4;
+    /// 
+    /// let actual = Matrix4::make(1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0, 10.0, 11.0, 12.0, 13.0, 14.0, 15.0, 16.0);
+    /// assert_eq!(actual.m32(), 7.0);
+    /// ```
+    #[inline]
+    pub fn m32(&self) -> f32 {
+        self.m[6]
+    }
+
+    /// Gets the value for the m42 element
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Matrix4;
+    /// 
+    /// let actual = Matrix4::make(1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0, 10.0, 11.0, 12.0, 13.0, 14.0, 15.0, 16.0);
+    /// assert_eq!(actual.m42(), 8.0);
+    /// ```
+    #[inline]
+    pub fn m42(&self) -> f32 {
+        self.m[7]
+    }
+
+    /// Gets the value for the m13 element
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Matrix4;
+    /// 
+    /// let actual = Matrix4::make(1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0, 10.0, 11.0, 12.0, 13.0, 14.0, 15.0, 16.0);
+    /// assert_eq!(actual.m13(), 9.0);
+    /// ```
+    #[inline]
+    pub fn m13(&self) -> f32 {
+        self.m[8]
+    }
+
+    /// Gets the value for the m23 element
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Matrix4;
+    /// 
+    /// let actual = Matrix4::make(1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0, 10.0, 11.0, 12.0, 13.0, 14.0, 15.0, 16.0);
+    /// assert_eq!(actual.m23(), 10.0);
+    /// ```
+    #[inline]
+    pub fn m23(&self) -> f32 {
+        self.m[9]
+    }
+
+    /// Gets the value for the m33 element
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Matrix4;
+    /// 
+    /// let actual = Matrix4::make(1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0, 10.0, 11.0, 12.0, 13.0, 14.0, 15.0, 16.0);
+    /// assert_eq!(actual.m33(), 11.0);
+    /// ```
+    #[inline]
+    pub fn m33(&self) -> f32 {
+        self.m[10]
+    }
+
+    /// Gets the value for the m43 element
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Matrix4;
+    /// 
+    /// let actual = Matrix4::make(1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0, 10.0, 11.0, 12.0, 13.0, 14.0, 15.0, 16.0);
+    /// assert_eq!(actual.m43(), 12.0);
+    /// ```
+    #[inline]
+    pub fn m43(&self) -> f32 {
+        self.m[11]
+    }
+
+    /// Gets the value for the m14 element
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Matrix4;
+    /// 
+    /// let actual = Matrix4::make(1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0, 10.0, 11.0, 12.0, 13.0, 14.0, 15.0, 16.0);
+    /// assert_eq!(actual.m14(), 13.0);
+    /// ```
+    #[inline]
+    pub fn m14(&self) -> f32 {
+        self.m[12]
+    }
+
+    /// Gets the value for the m24 element
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Matrix4;
+    /// 
+    /// let actual = Matrix4::make(1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0, 10.0, 11.0, 12.0, 13.0, 14.0, 15.0, 16.0);
+    /// assert_eq!(actual.m24(), 14.0);
+    /// ```
+    #[inline]
+    pub fn m24(&self) -> f32 {
+        self.m[13]
+    }
+
+    /// Gets the value for the m34 element
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Matrix4;
+    /// 
+    /// let actual = Matrix4::make(1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0, 10.0, 11.0, 12.0, 13.0, 14.0, 15.0, 16.0);
+    /// assert_eq!(actual.m34(), 15.0);
+    /// ```
+    #[inline]
+    pub fn m34(&self) -> f32 {
+        self.m[14]
     }
 
     /// Gets the value for the m44 element
@@ -821,6 +1650,7 @@ impl Matrix4 {
     /// assert_eq!(actual, expected);
     /// ```
     #[inline]
+    #[allow(clippy::too_many_arguments)]
     pub fn set(
         &mut self,
         m11: f32,
@@ -858,40 +1688,183 @@ impl Matrix4 {
         self.set_m44(m44);
     }
 
-    /// Transposes the matrix's elements
+    /// Gets a row of the matrix, counting from 0
+    ///
+    /// Panics if `i` is out of range.
     ///
     /// # Examples
     /// ```
     /// use vex::Matrix4;
+    /// use vex::Vector4;
     /// 
-    /// let mut actual = Matrix4::make(1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0, 10.0, 11.0, 12.0, 13.0, 14.0, 15.0, 16.0);
-    /// actual.transpose();
-    /// let expected = Matrix4::make(1.0, 5.0, 9.0, 13.0, 2.0, 6.0, 10.0, 14.0, 3.0, 7.0, 11.0, 15.0, 4.0, 8.0, 12.0, 16.0);
-    /// assert_eq!(actual, expected);
+    /// let m = Matrix4::from([
+    ///     1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0, 10.0, 11.0, 12.0, 13.0, 14.0, 15.0, 16.0,
+    /// ]);
+    /// assert_eq!(m.row(0), Vector4::make(1.0, 5.0, 9.0, 13.0));
     /// ```
     #[inline]
-    pub fn transpose(&mut self) {
-        let mut m = self.m;
+    pub fn row(&self, i: usize) -> Vector4 {
+        assert!(i < 4, "Invalid row for Matrix4: {}", i);
+        Vector4::make(self.m[i], self.m[4 + i], self.m[8 + i], self.m[12 + i])
+    }
 
-        let temp = m[1];
-        m[1] = m[4];
-        m[4] = temp;
-        let temp = m[2];
-        m[2] = m[8];
-        m[8] = temp;
-        let temp = m[6];
-        m[6] = m[9];
-        m[9] = temp;
-        let temp = m[7];
-        m[7] = m[13];
-        m[13] = temp;
-        let temp = m[11];
-        m[11] = m[14];
-        m[14] = temp;
-        let temp = m[3];
-        m[3] = m[12];
-        m[12] = temp;
-        self.m = m;
+    /// Gets a column of the matrix, counting from 0
+    ///
+    /// Panics if `i` is out of range.
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Matrix4;
+    /// use vex::Vector4;
+    /// 
+    /// let m = Matrix4::from([
+    ///     1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0, 10.0, 11.0, 12.0, 13.0, 14.0, 15.0, 16.0,
+    /// ]);
+    /// assert_eq!(m.col(0), Vector4::make(1.0, 2.0, 3.0, 4.0));
+    /// ```
+    #[inline]
+    pub fn col(&self, i: usize) -> Vector4 {
+        assert!(i < 4, "Invalid column for Matrix4: {}", i);
+        Vector4::make(self.m[i * 4], self.m[i * 4 + 1], self.m[i * 4 + 2], self.m[i * 4 + 3])
+    }
+
+    /// Sets a row of the matrix, counting from 0
+    ///
+    /// Panics if `i` is out of range.
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Matrix4;
+    /// use vex::Vector4;
+    /// 
+    /// let mut actual = Matrix4::new();
+    /// actual.set_row(3, &Vector4::make(1.0, 2.0, 3.0, 4.0));
+    /// assert_eq!(actual.row(3), Vector4::make(1.0, 2.0, 3.0, 4.0));
+    /// ```
+    #[inline]
+    pub fn set_row(&mut self, i: usize, v: &Vector4) {
+        assert!(i < 4, "Invalid row for Matrix4: {}", i);
+        self.m[i] = v.x;
+        self.m[4 + i] = v.y;
+        self.m[8 + i] = v.z;
+        self.m[12 + i] = v.w;
+    }
+
+    /// Sets a column of the matrix, counting from 0
+    ///
+    /// Panics if `i` is out of range.
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Matrix4;
+    /// use vex::Vector4;
+    /// 
+    /// let mut actual = Matrix4::new();
+    /// actual.set_col(3, &Vector4::make(1.0, 2.0, 3.0, 4.0));
+    /// assert_eq!(actual.col(3), Vector4::make(1.0, 2.0, 3.0, 4.0));
+    /// ```
+    #[inline]
+    pub fn set_col(&mut self, i: usize, v: &Vector4) {
+        assert!(i < 4, "Invalid column for Matrix4: {}", i);
+        self.m[i * 4] = v.x;
+        self.m[i * 4 + 1] = v.y;
+        self.m[i * 4 + 2] = v.z;
+        self.m[i * 4 + 3] = v.w;
+    }
+
+    /// Iterates over the rows of the matrix from first to last
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Matrix4;
+    /// use vex::Vector4;
+    /// 
+    /// let m = Matrix4::from([
+    ///     1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0, 10.0, 11.0, 12.0, 13.0, 14.0, 15.0, 16.0,
+    /// ]);
+    /// let rows: Vec<Vector4> = m.rows().collect();
+    /// assert_eq!(rows.len(), 4);
+    /// assert_eq!(rows[3], Vector4::make(4.0, 8.0, 12.0, 16.0));
+    /// ```
+    #[inline]
+    pub fn rows(&self) -> impl Iterator<Item = Vector4> + '_ {
+        (0..4).map(move |i| self.row(i))
+    }
+
+    /// Iterates over the columns of the matrix from first to last
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Matrix4;
+    /// use vex::Vector4;
+    /// 
+    /// let m = Matrix4::from([
+    ///     1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0, 10.0, 11.0, 12.0, 13.0, 14.0, 15.0, 16.0,
+    /// ]);
+    /// let cols: Vec<Vector4> = m.cols().collect();
+    /// assert_eq!(cols.len(), 4);
+    /// assert_eq!(cols[3], Vector4::make(13.0, 14.0, 15.0, 16.0));
+    /// ```
+    #[inline]
+    pub fn cols(&self) -> impl Iterator<Item = Vector4> + '_ {
+        (0..4).map(move |i| self.col(i))
+    }
+
+    /// Iterates over the elements of the matrix in column-major order
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Matrix4;
+    /// 
+    /// let m = Matrix4::from([
+    ///     1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0, 10.0, 11.0, 12.0, 13.0, 14.0, 15.0, 16.0,
+    /// ]);
+    /// assert_eq!(m.iter().sum::<f32>(), 136.0);
+    /// ```
+    #[inline]
+    pub fn iter(&self) -> slice::Iter<'_, f32> {
+        self.m.iter()
+    }
+
+    /// Iterates mutably over the elements of the matrix in column-major order
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Matrix4;
+    /// 
+    /// let mut actual = Matrix4::new();
+    /// for element in actual.iter_mut() {
+    ///     *element += 1.0;
+    /// }
+    ///
+    /// assert_eq!(actual.m[0], 2.0);
+    /// assert_eq!(actual.m[1], 1.0);
+    /// ```
+    #[inline]
+    pub fn iter_mut(&mut self) -> slice::IterMut<'_, f32> {
+        self.m.iter_mut()
+    }
+
+    /// Transposes the matrix's elements
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Matrix4;
+    /// 
+    /// let mut actual = Matrix4::make(1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0, 10.0, 11.0, 12.0, 13.0, 14.0, 15.0, 16.0);
+    /// actual.transpose();
+    /// let expected = Matrix4::make(1.0, 5.0, 9.0, 13.0, 2.0, 6.0, 10.0, 14.0, 3.0, 7.0, 11.0, 15.0, 4.0, 8.0, 12.0, 16.0);
+    /// assert_eq!(actual, expected);
+    /// ```
+    #[inline]
+    pub fn transpose(&mut self) {
+        let m = &mut self.m;
+        m.swap(1, 4);
+        m.swap(2, 8);
+        m.swap(6, 9);
+        m.swap(7, 13);
+        m.swap(11, 14);
+        m.swap(3, 12);
     }
 
     /// Find the matrix's determinant
@@ -964,25 +1937,44 @@ impl Matrix4 {
         a - b + c - d
     }
 
-    /// Inverses the matrix
+    /// Find the sum of the elements along the main diagonal
     ///
     /// # Examples
     /// ```
     /// use vex::Matrix4;
     /// 
-    /// let mut actual = Matrix4::make(1.0, 0.0, 2.0, 2.0, 0.0, 2.0, 1.0, 0.0, 0.0, 1.0, 0.0, 1.0, 1.0, 2.0, 1.0, 4.0);
-    /// actual.inverse();
+    /// let actual = Matrix4::scale(2.0, 3.0, 4.0).trace();
+    /// assert_eq!(actual, 10.0);
+    /// ```
+    #[inline]
+    pub fn trace(&self) -> f32 {
+        self.m[0] + self.m[5] + self.m[10] + self.m[15]
+    }
+
+    /// Find the inverse of the matrix, or `None` if it's singular
+    ///
+    /// Only a determinant of exactly zero counts as singular, so a matrix that is merely close to
+    /// singular still inverts, to a result with huge elements that amplifies any rounding error.
+    /// Use [`try_inverse_with`](#method.try_inverse_with) to reject those as well.
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Matrix4;
+    ///
+    /// let actual = Matrix4::make(1.0, 0.0, 2.0, 2.0, 0.0, 2.0, 1.0, 0.0, 0.0, 1.0, 0.0, 1.0, 1.0, 2.0, 1.0, 4.0).try_inverse();
     /// let expected = Matrix4::make(-2.0, 1.0, -8.0, 3.0, -0.5, 0.5, -1.0, 0.5, 1.0, 0.0, 2.0, -1.0, 0.5, -0.5, 2.0, -0.5);
-    /// assert_eq!(actual, expected);
+    /// assert_eq!(actual, Some(expected));
+    /// assert_eq!(Matrix4::scale(1.0, 0.0, 1.0).try_inverse(), None);
     /// ```
     #[inline]
-    pub fn inverse(&mut self) -> bool {
+    pub fn try_inverse(&self) -> Option<Matrix4> {
         let det = self.determinant();
         if det == 0.0 {
-            return false;
+            return None;
         }
 
         let inv_det = 1.0 / det;
+        let mut result = *self;
 
         // process the first column
         let pre_m11 = self.m22() * self.m33() * self.m44()
@@ -1062,49 +2054,771 @@ impl Matrix4 {
             - self.m13() * self.m21() * self.m42()
             + self.m13() * self.m41() * self.m22();
 
-        // process the fourth column
-        let pre_m14 = -self.m12() * self.m23() * self.m34()
-            + self.m12() * self.m33() * self.m24()
-            + self.m13() * self.m22() * self.m34()
-            - self.m13() * self.m32() * self.m24()
-            - self.m14() * self.m22() * self.m33()
-            + self.m14() * self.m32() * self.m23();
-        let pre_m24 = self.m11() * self.m23() * self.m34()
-            - self.m11() * self.m33() * self.m24()
-            - self.m13() * self.m21() * self.m34()
-            + self.m13() * self.m31() * self.m24()
-            + self.m14() * self.m21() * self.m33()
-            - self.m14() * self.m31() * self.m23();
-        let pre_m34 = -self.m11() * self.m22() * self.m34()
-            + self.m11() * self.m32() * self.m24()
-            + self.m12() * self.m21() * self.m34()
-            - self.m12() * self.m31() * self.m24()
-            - self.m14() * self.m21() * self.m32()
-            + self.m14() * self.m31() * self.m22();
-        let pre_m44 = self.m11() * self.m22() * self.m33()
-            - self.m11() * self.m32() * self.m23()
-            - self.m12() * self.m21() * self.m33()
-            + self.m12() * self.m31() * self.m23()
-            + self.m13() * self.m21() * self.m32()
-            - self.m13() * self.m31() * self.m22();
+        // process the fourth column
+        let pre_m14 = -self.m12() * self.m23() * self.m34()
+            + self.m12() * self.m33() * self.m24()
+            + self.m13() * self.m22() * self.m34()
+            - self.m13() * self.m32() * self.m24()
+            - self.m14() * self.m22() * self.m33()
+            + self.m14() * self.m32() * self.m23();
+        let pre_m24 = self.m11() * self.m23() * self.m34()
+            - self.m11() * self.m33() * self.m24()
+            - self.m13() * self.m21() * self.m34()
+            + self.m13() * self.m31() * self.m24()
+            + self.m14() * self.m21() * self.m33()
+            - self.m14() * self.m31() * self.m23();
+        let pre_m34 = -self.m11() * self.m22() * self.m34()
+            + self.m11() * self.m32() * self.m24()
+            + self.m12() * self.m21() * self.m34()
+            - self.m12() * self.m31() * self.m24()
+            - self.m14() * self.m21() * self.m32()
+            + self.m14() * self.m31() * self.m22();
+        let pre_m44 = self.m11() * self.m22() * self.m33()
+            - self.m11() * self.m32() * self.m23()
+            - self.m12() * self.m21() * self.m33()
+            + self.m12() * self.m31() * self.m23()
+            + self.m13() * self.m21() * self.m32()
+            - self.m13() * self.m31() * self.m22();
+
+        // set the values
+        result.set_m11(pre_m11 * inv_det);
+        result.set_m21(pre_m21 * inv_det);
+        result.set_m31(pre_m31 * inv_det);
+        result.set_m41(pre_m41 * inv_det);
+        result.set_m12(pre_m12 * inv_det);
+        result.set_m22(pre_m22 * inv_det);
+        result.set_m32(pre_m32 * inv_det);
+        result.set_m42(pre_m42 * inv_det);
+        result.set_m13(pre_m13 * inv_det);
+        result.set_m23(pre_m23 * inv_det);
+        result.set_m33(pre_m33 * inv_det);
+        result.set_m43(pre_m43 * inv_det);
+        result.set_m14(pre_m14 * inv_det);
+        result.set_m24(pre_m24 * inv_det);
+        result.set_m34(pre_m34 * inv_det);
+        result.set_m44(pre_m44 * inv_det);
+        Some(result)
+    }
+
+    /// Find the inverse of the matrix, or `None` if it's singular or too close to singular under
+    /// an epsilon policy
+    ///
+    /// The determinant alone can't say how close a matrix is to singular, since it scales with the
+    /// elements: a uniform scale by 0.01 inverts exactly, despite its tiny determinant. Instead,
+    /// the determinant is divided by the product of the column lengths, which measures closeness
+    /// to singular independently of scale, from 0 for singular matrices up to 1 for orthogonal
+    /// ones. The matrix is rejected when that measure is within `epsilon.absolute` of zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Epsilon;
+    /// use vex::Matrix4;
+    ///
+    /// let m = Matrix4::make(
+    ///     1.0, 0.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 1.0, 1.0, 0.0000001, 0.0, 0.0, 0.0, 0.0, 1.0,
+    /// );
+    /// assert!(m.try_inverse().is_some());
+    /// assert_eq!(m.try_inverse_with(&Epsilon::DEFAULT), None);
+    ///
+    /// let m = Matrix4::new() * 0.01;
+    /// assert_eq!(m.try_inverse_with(&Epsilon::DEFAULT), m.try_inverse());
+    /// ```
+    #[inline]
+    pub fn try_inverse_with(&self, epsilon: &Epsilon) -> Option<Matrix4> {
+        let volume: f32 = self.cols().map(|col| col.mag()).product();
+        if volume == 0.0 || epsilon.is_zero(self.determinant() / volume) {
+            return None;
+        }
+
+        self.try_inverse()
+    }
+
+    /// Inverses the matrix, returning false and leaving it unchanged if it's singular
+    ///
+    /// # Examples
+    /// ```
+    /// #![allow(deprecated)]
+    /// use vex::Matrix4;
+    /// 
+    /// let mut actual = Matrix4::make(1.0, 0.0, 2.0, 2.0, 0.0, 2.0, 1.0, 0.0, 0.0, 1.0, 0.0, 1.0, 1.0, 2.0, 1.0, 4.0);
+    /// actual.inverse();
+    /// let expected = Matrix4::make(-2.0, 1.0, -8.0, 3.0, -0.5, 0.5, -1.0, 0.5, 1.0, 0.0, 2.0, -1.0, 0.5, -0.5, 2.0, -0.5);
+    /// assert_eq!(actual, expected);
+    /// ```
+    #[deprecated(since = "2.2.0", note = "use `try_inverse` instead")]
+    #[inline]
+    pub fn inverse(&mut self) -> bool {
+        match self.try_inverse() {
+            Some(inverse) => {
+                *self = inverse;
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Negates the matrix's elements
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Matrix4;
+    /// 
+    /// let mut actual = Matrix4::make(1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0, 10.0, 11.0, 12.0, 13.0, 14.0, 15.0, 16.0);
+    /// actual.negate();
+    /// let expected = Matrix4::make(-1.0, -2.0, -3.0, -4.0, -5.0, -6.0, -7.0, -8.0, -9.0, -10.0, -11.0, -12.0, -13.0, -14.0, -15.0, -16.0);
+    /// assert_eq!(actual, expected);
+    /// ```
+    #[inline]
+    pub fn negate(&mut self) {
+        for value in self.m.iter_mut() {
+            *value = -*value;
+        }
+    }
+
+    /// Find the matrix with its elements negated
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Matrix4;
+    /// 
+    /// let actual = Matrix4::make(1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0, 10.0, 11.0, 12.0, 13.0, 14.0, 15.0, 16.0).negated();
+    /// let expected = Matrix4::make(-1.0, -2.0, -3.0, -4.0, -5.0, -6.0, -7.0, -8.0, -9.0, -10.0, -11.0, -12.0, -13.0, -14.0, -15.0, -16.0);
+    /// assert_eq!(actual, expected);
+    /// ```
+    #[inline]
+    pub fn negated(&self) -> Matrix4 {
+        -*self
+    }
+
+    /// Transposes the matrix's elements in place
+    ///
+    /// This is the same as `transpose`, named to pair with the value-returning variants.
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Matrix4;
+    /// 
+    /// let mut actual = Matrix4::make(1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0, 10.0, 11.0, 12.0, 13.0, 14.0, 15.0, 16.0);
+    /// actual.transpose_in_place();
+    /// let mut expected = Matrix4::make(1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0, 10.0, 11.0, 12.0, 13.0, 14.0, 15.0, 16.0);
+    /// expected.transpose();
+    /// assert_eq!(actual, expected);
+    /// ```
+    #[inline]
+    pub fn transpose_in_place(&mut self) {
+        self.transpose();
+    }
+
+    /// Find the transpose of the matrix
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Matrix4;
+    /// 
+    /// let actual = Matrix4::make(1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0, 10.0, 11.0, 12.0, 13.0, 14.0, 15.0, 16.0).transposed();
+    /// let expected = Matrix4::make(1.0, 5.0, 9.0, 13.0, 2.0, 6.0, 10.0, 14.0, 3.0, 7.0, 11.0, 15.0, 4.0, 8.0, 12.0, 16.0);
+    /// assert_eq!(actual, expected);
+    /// ```
+    #[inline]
+    pub fn transposed(&self) -> Matrix4 {
+        let mut result = *self;
+        result.transpose();
+        result
+    }
+
+    /// Find the inverse of a matrix that only rotates, scales, shears, and translates, or `None`
+    /// if it isn't invertible
+    ///
+    /// Only the upper-left 3x3 part is inverted, which is much cheaper than a full inverse. Debug
+    /// builds assert that the bottom row is `<0, 0, 0, 1>`.
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Matrix4;
+    /// 
+    /// let m = Matrix4::translate(1.0, 2.0, 3.0) * Matrix4::rotate_x(0.5);
+    /// let m = m * Matrix4::scale(2.0, 4.0, 1.0);
+    /// let actual = m.try_inverse_affine().unwrap();
+    /// assert!(actual.approx_eq(&m.try_inverse().unwrap(), 0.0001));
+    ///
+    /// let actual = Matrix4::translate(1.0, 2.0, 3.0).try_inverse_affine().unwrap();
+    /// assert_eq!(actual, Matrix4::translate(-1.0, -2.0, -3.0));
+    /// assert_eq!(Matrix4::scale(0.0, 1.0, 1.0).try_inverse_affine(), None);
+    /// ```
+    pub fn try_inverse_affine(&self) -> Option<Matrix4> {
+        debug_assert!(
+            self.is_affine(&Epsilon::DEFAULT),
+            "try_inverse_affine called on a matrix that isn't affine: {:?}",
+            self
+        );
+
+        let m = &self.m;
+        let c11 = m[5] * m[10] - m[9] * m[6];
+        let c12 = m[9] * m[2] - m[1] * m[10];
+        let c13 = m[1] * m[6] - m[5] * m[2];
+        let det = m[0] * c11 + m[4] * c12 + m[8] * c13;
+        if det == 0.0 {
+            return None;
+        }
+
+        let inv_det = 1.0 / det;
+        let r = [
+            c11 * inv_det,
+            c12 * inv_det,
+            c13 * inv_det,
+            (m[8] * m[6] - m[4] * m[10]) * inv_det,
+            (m[0] * m[10] - m[8] * m[2]) * inv_det,
+            (m[4] * m[2] - m[0] * m[6]) * inv_det,
+            (m[4] * m[9] - m[8] * m[5]) * inv_det,
+            (m[8] * m[1] - m[0] * m[9]) * inv_det,
+            (m[0] * m[5] - m[4] * m[1]) * inv_det,
+        ];
+
+        let mut result = *self;
+        result.set_inverse_basis(&r);
+        Some(result)
+    }
+
+    /// Find the inverse of a matrix that only rotates and translates
+    ///
+    /// The inverse of a rotation is its transpose, so this is the cheapest inverse there is. Debug
+    /// builds assert that the bottom row is `<0, 0, 0, 1>` and that the basis vectors are
+    /// orthonormal.
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Matrix4;
+    /// 
+    /// let m = Matrix4::translate(1.0, 2.0, 3.0) * Matrix4::rotate(0.2, -0.4, 0.9);
+    /// assert!(m.inverse_rigid().approx_eq(&m.try_inverse().unwrap(), 0.0001));
+    ///
+    /// let actual = (Matrix4::translate(1.0, 0.0, 0.0) * Matrix4::rotate_z(0.5)).inverse_rigid();
+    /// let expected = Matrix4::rotate_z(-0.5) * Matrix4::translate(-1.0, 0.0, 0.0);
+    /// assert!(actual.approx_eq(&expected, 0.0001));
+    /// ```
+    pub fn inverse_rigid(&self) -> Matrix4 {
+        debug_assert!(
+            self.is_affine(&Epsilon::DEFAULT)
+                && self.is_orthogonal(&Epsilon::DEFAULT.with_absolute(0.001)),
+            "inverse_rigid called on a matrix that isn't rigid: {:?}",
+            self
+        );
+
+        let m = &self.m;
+        let r = [m[0], m[4], m[8], m[1], m[5], m[9], m[2], m[6], m[10]];
+        let mut result = *self;
+        result.set_inverse_basis(&r);
+        result
+    }
+
+    /// Find this transform relative to another, or `None` if the other isn't invertible
+    ///
+    /// This is `parent^-1 * self`, which turns a world transform into a local transform under
+    /// `parent`, such as when parenting an object in a scene graph without moving it. Affine
+    /// parents are inverted with the cheap
+    /// [`try_inverse_affine`](#method.try_inverse_affine), and only parents with a projection
+    /// take the full inverse.
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Matrix4;
+    ///
+    /// let parent = Matrix4::translate(1.0, 2.0, 3.0) * Matrix4::rotate_y(0.5);
+    /// let local = Matrix4::translate(0.0, 1.0, 0.0) * Matrix4::scale(2.0, 2.0, 2.0);
+    /// let world = parent * local;
+    /// assert!(world.relative_to(&parent).unwrap().approx_eq(&local, 0.0001));
+    ///
+    /// assert_eq!(world.relative_to(&Matrix4::scale(1.0, 0.0, 1.0)), None);
+    /// ```
+    #[inline]
+    pub fn relative_to(&self, parent: &Matrix4) -> Option<Matrix4> {
+        let inverse = if parent.is_affine(&Epsilon::DEFAULT) {
+            parent.try_inverse_affine()?
+        } else {
+            parent.try_inverse()?
+        };
+
+        Some(inverse * *self)
+    }
+
+    /// Find this transform relative to another that only rotates and translates
+    ///
+    /// This is [`relative_to`](#method.relative_to) using the cheapest inverse there is, with the
+    /// same requirements as [`inverse_rigid`](#method.inverse_rigid).
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Matrix4;
+    ///
+    /// let parent = Matrix4::translate(1.0, 2.0, 3.0) * Matrix4::rotate(0.2, -0.4, 0.9);
+    /// let local = Matrix4::translate(0.0, 1.0, 0.0) * Matrix4::rotate_x(0.3);
+    /// let world = parent * local;
+    /// assert!(world.relative_to_rigid(&parent).approx_eq(&local, 0.0001));
+    /// ```
+    #[inline]
+    pub fn relative_to_rigid(&self, parent: &Matrix4) -> Matrix4 {
+        parent.inverse_rigid() * *self
+    }
+
+    /// Find the matrix that converts coordinates in one frame into coordinates in another, or
+    /// `None` if `to` isn't invertible
+    ///
+    /// Both frames map their own coordinates into a shared space, like world space, so the result
+    /// is `to^-1 * from`, the same as `from.relative_to(to)`.
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Matrix;
+    /// use vex::Matrix4;
+    /// use vex::Vector3;
+    ///
+    /// let from = Matrix4::translate(5.0, 0.0, 0.0);
+    /// let to = Matrix4::translate(0.0, 0.0, -2.0) * Matrix4::scale(2.0, 2.0, 2.0);
+    /// let convert = Matrix4::change_of_basis(&from, &to).unwrap();
+    ///
+    /// let point = Vector3::make(1.0, 2.0, 3.0);
+    /// let world = from.transform_point(&point);
+    /// let actual = convert.transform_point(&point);
+    /// assert!((to.transform_point(&actual) - world).mag() < 0.0001);
+    /// assert!((actual - Vector3::make(3.0, 1.0, 2.5)).mag() < 0.0001);
+    /// ```
+    #[inline]
+    pub fn change_of_basis(from: &Matrix4, to: &Matrix4) -> Option<Matrix4> {
+        from.relative_to(to)
+    }
+
+    /// Determine whether or not the matrix is affine under an epsilon policy
+    ///
+    /// Affine matrices have a bottom row of `<0, 0, 0, 1>`, so they don't apply any perspective.
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Epsilon;
+    /// use vex::Matrix4;
+    /// 
+    /// assert!(Matrix4::translate(1.0, 2.0, 3.0).is_affine(&Epsilon::DEFAULT));
+    /// let perspective = Matrix4::perspective(1.0, 1.5, 0.1, 100.0);
+    /// assert!(!perspective.is_affine(&Epsilon::DEFAULT));
+    /// ```
+    #[inline]
+    pub fn is_affine(&self, epsilon: &Epsilon) -> bool {
+        epsilon.is_zero(self.m[3])
+            && epsilon.is_zero(self.m[7])
+            && epsilon.is_zero(self.m[11])
+            && epsilon.approx_eq(self.m[15], 1.0)
+    }
+
+    // replaces the basis with the inverse basis `r`, given in column-major order, and the
+    // translation with the translation that undoes the current one
+    #[inline]
+    fn set_inverse_basis(&mut self, r: &[f32; 9]) {
+        let (tx, ty, tz) = (self.m[12], self.m[13], self.m[14]);
+        self.m = [
+            r[0], r[1], r[2], 0.0,
+            r[3], r[4], r[5], 0.0,
+            r[6], r[7], r[8], 0.0,
+            -(r[0] * tx + r[3] * ty + r[6] * tz),
+            -(r[1] * tx + r[4] * ty + r[7] * tz),
+            -(r[2] * tx + r[5] * ty + r[8] * tz),
+            1.0,
+        ];
+    }
+
+    /// Find the matrix that transforms normals, which is the inverse-transpose of the upper 3x3
+    ///
+    /// Normals transformed by a model matrix with non-uniform scale or shear stop being
+    /// perpendicular to their surface, and this matrix keeps them perpendicular. Translation has
+    /// no effect on it. The result isn't normalized, so transformed normals need renormalizing.
+    /// Returns `None` if the upper 3x3 is singular.
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Matrix;
+    /// use vex::Matrix3;
+    /// use vex::Matrix4;
+    /// use vex::Vector3;
+    ///
+    /// // rotations and translations leave the rotation untouched
+    /// let m = Matrix4::translate(1.0, 2.0, 3.0) * Matrix4::rotate_x(0.5);
+    /// let actual = m.normal_matrix().unwrap();
+    /// let expected = Matrix3::from_axis_angle(Vector3::right(), 0.5);
+    /// assert!(actual.approx_eq(&expected, 0.000001));
+    ///
+    /// // squashing a 45 degree slope along y makes it shallower, so its normal steepens
+    /// let m = Matrix4::scale(1.0, 0.5, 1.0);
+    /// let normal = Vector3::make(-1.0, 1.0, 0.0).normalized();
+    /// let actual = m.normal_matrix().unwrap().transform_point(&normal).normalized();
+    /// let tangent = m.transform_point(&Vector3::make(1.0, 1.0, 0.0));
+    /// assert!(Vector3::dot(&actual, &tangent).abs() < 0.000001);
+    ///
+    /// assert_eq!(Matrix4::scale(1.0, 0.0, 1.0).normal_matrix(), None);
+    /// ```
+    #[inline]
+    pub fn normal_matrix(&self) -> Option<Matrix3> {
+        let upper = Matrix3::make(
+            self.m11(), self.m21(), self.m31(),
+            self.m12(), self.m22(), self.m32(),
+            self.m13(), self.m23(), self.m33(),
+        );
+        upper.try_inverse().map(|inverse| inverse.transposed())
+    }
+
+    /// Find the resulting direction given a direction and matrix
+    ///
+    /// Directions have no position, so unlike [`transform_point`](trait.Matrix.html) the
+    /// translation is ignored. The result isn't normalized.
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Matrix4;
+    /// use vex::Vector3;
+    ///
+    /// let m = Matrix4::translate(5.0, 6.0, 7.0) * Matrix4::scale(2.0, 3.0, 4.0);
+    /// let actual = m.transform_direction(&Vector3::make(1.0, 1.0, 1.0));
+    /// assert_eq!(actual, Vector3::make(2.0, 3.0, 4.0));
+    /// ```
+    #[inline]
+    pub fn transform_direction(&self, direction: &Vector3) -> Vector3 {
+        Vector3::make(
+            self.m11() * direction.x + self.m12() * direction.y + self.m13() * direction.z,
+            self.m21() * direction.x + self.m22() * direction.y + self.m23() * direction.z,
+            self.m31() * direction.x + self.m32() * direction.y + self.m33() * direction.z,
+        )
+    }
+
+    /// Find the resulting unit normal given a surface normal and matrix
+    ///
+    /// Normals are transformed by the [`normal_matrix`](#method.normal_matrix) so they stay
+    /// perpendicular to their surface under non-uniform scale and shear, then renormalized.
+    /// Returns a zero vector if the matrix flattens space and has no normal matrix.
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Matrix4;
+    /// use vex::Vector3;
+    ///
+    /// let m = Matrix4::translate(5.0, 6.0, 7.0) * Matrix4::scale(1.0, 0.5, 1.0);
+    /// let normal = Vector3::make(-1.0, 1.0, 0.0).normalized();
+    /// let actual = m.transform_normal(&normal);
+    /// let tangent = m.transform_direction(&Vector3::make(1.0, 1.0, 0.0));
+    /// assert!(Vector3::dot(&actual, &tangent).abs() < 0.000001);
+    /// assert!((actual.mag() - 1.0).abs() < 0.000001);
+    ///
+    /// assert_eq!(Matrix4::scale(0.0, 1.0, 1.0).transform_normal(&normal), Vector3::new());
+    /// ```
+    #[inline]
+    pub fn transform_normal(&self, normal: &Vector3) -> Vector3 {
+        match self.normal_matrix() {
+            Some(m) => common::Matrix::transform_point(&m, normal).normalized(),
+            None => Vector3::new(),
+        }
+    }
+
+    /// Find `self * cov * transpose(self)`, which propagates a covariance through a linear map
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Matrix4;
+    /// 
+    /// let a = Matrix4::translate(1.0, 2.0, 3.0) * Matrix4::scale(2.0, 1.0, 1.0);
+    /// let cov = Matrix4::scale(4.0, 2.0, 3.0);
+    /// let actual = a.similarity_transform(&cov);
+    /// let expected = a * cov * a.transposed();
+    /// assert_eq!(actual, expected);
+    ///
+    /// // symmetrize to remove any asymmetry left by rounding
+    /// let actual = a.similarity_transform(&cov).symmetrized();
+    /// assert_eq!(actual, actual.transposed());
+    /// ```
+    #[inline]
+    pub fn similarity_transform(&self, cov: &Matrix4) -> Matrix4 {
+        *self * *cov * self.transposed()
+    }
+
+    /// Find `self * cov * transpose(other)`, which propagates a cross-covariance through two
+    /// linear maps
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Matrix4;
+    /// 
+    /// let a = Matrix4::translate(1.0, 2.0, 3.0) * Matrix4::scale(2.0, 1.0, 1.0);
+    /// let b = Matrix4::scale(1.0, 2.0, 3.0);
+    /// let cov = Matrix4::scale(4.0, 2.0, 3.0);
+    /// let actual = a.cross_transform(&cov, &b);
+    /// assert_eq!(actual, a * cov * b.transposed());
+    /// ```
+    #[inline]
+    pub fn cross_transform(&self, cov: &Matrix4, other: &Matrix4) -> Matrix4 {
+        *self * *cov * other.transposed()
+    }
+
+    /// Replaces the matrix with the average of itself and its transpose
+    ///
+    /// Covariance matrices are symmetric in theory, but rounding lets the two halves drift apart
+    /// after repeated propagation. This removes that drift.
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Matrix4;
+    /// 
+    /// let mut actual = Matrix4::make(1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0, 10.0, 11.0, 12.0, 13.0, 14.0, 15.0, 16.0);
+    /// actual.symmetrize();
+    /// let expected = Matrix4::make(1.0, 3.5, 6.0, 8.5, 3.5, 6.0, 8.5, 11.0, 6.0, 8.5, 11.0, 13.5, 8.5, 11.0, 13.5, 16.0);
+    /// assert_eq!(actual, expected);
+    /// ```
+    #[inline]
+    pub fn symmetrize(&mut self) {
+        let transposed = self.transposed();
+        for (value, other) in self.m.iter_mut().zip(transposed.m.iter()) {
+            *value = (*value + *other) * 0.5;
+        }
+    }
+
+    /// Find the average of the matrix and its transpose
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Matrix4;
+    /// 
+    /// let actual = Matrix4::make(1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0, 10.0, 11.0, 12.0, 13.0, 14.0, 15.0, 16.0).symmetrized();
+    /// let expected = Matrix4::make(1.0, 3.5, 6.0, 8.5, 3.5, 6.0, 8.5, 11.0, 6.0, 8.5, 11.0, 13.5, 8.5, 11.0, 13.5, 16.0);
+    /// assert_eq!(actual, expected);
+    /// ```
+    #[inline]
+    pub fn symmetrized(&self) -> Matrix4 {
+        let mut result = *self;
+        result.symmetrize();
+        result
+    }
+
+    /// Makes the basis vectors orthonormal using Gram-Schmidt
+    ///
+    /// Only the upper-left 3x3 part changes, so translation is kept. The first basis vector keeps
+    /// its direction, and a zero or dependent one is replaced by one perpendicular to those before
+    /// it.
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Epsilon;
+    /// use vex::EulerOrder;
+    /// use vex::Matrix4;
+    /// 
+    /// let mut actual = Matrix4::from_euler(0.25, -0.5, 1.0, EulerOrder::ZYX) * 1.01;
+    /// actual.set_m44(1.0);
+    /// actual.set_m14(5.0);
+    /// actual.orthonormalize();
+    /// assert!(actual.is_orthogonal(&Epsilon::DEFAULT));
+    /// assert_eq!(actual.m14(), 5.0);
+    /// ```
+    pub fn orthonormalize(&mut self) {
+        let x = Vector3::make(self.m[0], self.m[1], self.m[2]);
+        let y = Vector3::make(self.m[4], self.m[5], self.m[6]);
+        let z = Vector3::make(self.m[8], self.m[9], self.m[10]);
+        let mut x = x.normalized();
+        if x == Vector3::new() {
+            x = Vector3::right();
+        }
+
+        let mut y = (y - x * Vector3::dot(&x, &y)).normalized();
+        if y == Vector3::new() {
+            y = Vector3::perpendicular(&x);
+        }
+
+        let mut z = (z - x * Vector3::dot(&x, &z) - y * Vector3::dot(&y, &z)).normalized();
+        if z == Vector3::new() {
+            z = Vector3::cross(&x, &y);
+        }
+
+        for (i, axis) in [x, y, z].iter().enumerate() {
+            self.m[i * 4] = axis.x;
+            self.m[i * 4 + 1] = axis.y;
+            self.m[i * 4 + 2] = axis.z;
+        }
+    }
+
+    /// Find the matrix with its basis vectors made orthonormal using Gram-Schmidt
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Epsilon;
+    /// use vex::Matrix4;
+    /// 
+    /// let actual = Matrix4::new().orthonormalized();
+    /// assert!(actual.is_identity(&Epsilon::DEFAULT));
+    /// ```
+    #[inline]
+    pub fn orthonormalized(&self) -> Matrix4 {
+        let mut result = *self;
+        result.orthonormalize();
+        result
+    }
+
+    /// Find the matrix with a function applied to each element
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Matrix4;
+    /// 
+    /// let actual = Matrix4::scale(-2.0, 3.0, -4.0).map(f32::abs);
+    /// assert_eq!(actual.m, Matrix4::scale(2.0, 3.0, 4.0).m);
+    /// ```
+    #[inline]
+    pub fn map<F: Fn(f32) -> f32>(&self, f: F) -> Matrix4 {
+        Matrix4 { m: self.m.map(f) }
+    }
+
+    /// Linearly interpolate between two matrices with either a scalar factor or one per element
+    ///
+    /// Matches GLSL's `mix`, giving `a * (1 - t) + b * t` for each element, so a factor of 1
+    /// returns exactly `b`.
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Matrix4;
+    /// 
+    /// let a = Matrix4::new();
+    /// let b = Matrix4::translate(4.0, 8.0, -2.0);
+    /// assert_eq!(Matrix4::mix(&a, &b, 0.5), Matrix4::translate(2.0, 4.0, -1.0));
+    ///
+    /// let t = Matrix4::from([0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.5, 0.0]);
+    /// assert_eq!(Matrix4::mix(&a, &b, t), Matrix4::translate(4.0, 0.0, -1.0));
+    /// ```
+    #[inline]
+    pub fn mix<T: common::MixFactor<Matrix4>>(a: &Matrix4, b: &Matrix4, t: T) -> Matrix4 {
+        let t = t.spread();
+        let mut m = [0.0; 16];
+        for (i, element) in m.iter_mut().enumerate() {
+            *element = a.m[i] * (1.0 - t.m[i]) + b.m[i] * t.m[i];
+        }
+
+        Matrix4 { m }
+    }
+
+    /// Determine whether or not all elements of the matrices are within `epsilon` of each other
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Matrix4;
+    /// 
+    /// let a = Matrix4::new();
+    /// assert!(a.approx_eq(&Matrix4::translate(0.0000001, 0.0, 0.0), 0.000001));
+    /// assert!(!a.approx_eq(&Matrix4::translate(0.1, 0.0, 0.0), 0.000001));
+    /// ```
+    #[inline]
+    pub fn approx_eq(&self, other: &Matrix4, epsilon: f32) -> bool {
+        for i in 0..16 {
+            if (self.m[i] - other.m[i]).abs() > epsilon {
+                return false;
+            }
+        }
+
+        true
+    }
+
+    /// Determine whether or not all elements of the matrices are equal under an epsilon policy
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Epsilon;
+    /// use vex::Matrix4;
+    /// 
+    /// let a = Matrix4::translate(1000000.0, 2.0, 3.0);
+    /// assert!(a.approx_eq_with(&Matrix4::translate(1000000.06, 2.0, 3.0), &Epsilon::DEFAULT));
+    /// assert!(!a.approx_eq_with(&Matrix4::translate(1000000.0, 2.1, 3.0), &Epsilon::DEFAULT));
+    /// ```
+    #[inline]
+    pub fn approx_eq_with(&self, other: &Matrix4, epsilon: &Epsilon) -> bool {
+        let a: [f32; 16] = (*self).into();
+        let b: [f32; 16] = (*other).into();
+        epsilon.all_approx_eq(&a, &b)
+    }
+
+    /// Determine whether or not the matrix is too close to singular to invert under an epsilon
+    /// policy
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Epsilon;
+    /// use vex::Matrix4;
+    /// 
+    /// assert!(Matrix4::scale(1.0, 0.0, 1.0).is_singular(&Epsilon::DEFAULT));
+    /// assert!(!Matrix4::scale(1.0, 0.01, 1.0).is_singular(&Epsilon::DEFAULT));
+    /// assert!(Matrix4::scale(1.0, 0.01, 1.0).is_singular(&Epsilon::DEFAULT.with_absolute(0.1)));
+    /// ```
+    #[inline]
+    pub fn is_singular(&self, epsilon: &Epsilon) -> bool {
+        epsilon.is_zero(self.determinant())
+    }
+
+    /// Find the number of linearly independent columns under an epsilon policy
+    ///
+    /// Pivots within the absolute tolerance count as zero, so nearly dependent columns lower the
+    /// rank.
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Epsilon;
+    /// use vex::Matrix4;
+    /// 
+    /// assert_eq!(Matrix4::translate(1.0, 2.0, 3.0).rank(&Epsilon::DEFAULT), 4);
+    /// assert_eq!(Matrix4::scale(1.0, 0.0, 2.0).rank(&Epsilon::DEFAULT), 3);
+    /// ```
+    #[inline]
+    pub fn rank(&self, epsilon: &Epsilon) -> u32 {
+        let mut m = self.m;
+        epsilon.rank(&mut m, 4)
+    }
+
+    /// Determine whether or not the matrix is the identity under an epsilon policy
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Epsilon;
+    /// use vex::Matrix4;
+    /// 
+    /// let mut m = Matrix4::new();
+    /// assert!(m.is_identity(&Epsilon::DEFAULT));
+    /// m.m[1] = 0.001;
+    /// assert!(!m.is_identity(&Epsilon::DEFAULT));
+    /// assert!(m.is_identity(&Epsilon::DEFAULT.with_absolute(0.01)));
+    /// ```
+    #[inline]
+    pub fn is_identity(&self, epsilon: &Epsilon) -> bool {
+        self.approx_eq_with(&Matrix4::IDENTITY, epsilon)
+    }
+
+    /// Determine whether or not the basis vectors are orthonormal under an epsilon policy
+    ///
+    /// Only the upper-left 3x3 part is checked, so translation and the bottom row are ignored,
+    /// which makes this true for any rigid transform.
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Epsilon;
+    /// use vex::EulerOrder;
+    /// use vex::Matrix4;
+    /// 
+    /// let rotation = Matrix4::from_euler(0.25, -0.5, 1.0, EulerOrder::ZYX);
+    /// let rigid = rotation * Matrix4::translate(1.0, 2.0, 3.0);
+    /// assert!(rigid.is_orthogonal(&Epsilon::DEFAULT));
+    /// assert!(!Matrix4::scale(1.0, 2.0, 1.0).is_orthogonal(&Epsilon::DEFAULT));
+    /// ```
+    pub fn is_orthogonal(&self, epsilon: &Epsilon) -> bool {
+        let columns = [
+            Vector3::make(self.m[0], self.m[1], self.m[2]),
+            Vector3::make(self.m[4], self.m[5], self.m[6]),
+            Vector3::make(self.m[8], self.m[9], self.m[10]),
+        ];
+        for i in 0..3 {
+            for j in i..3 {
+                let expected = if i == j { 1.0 } else { 0.0 };
+                if !epsilon.approx_eq(Vector3::dot(&columns[i], &columns[j]), expected) {
+                    return false;
+                }
+            }
+        }
 
-        // set the values
-        self.set_m11(pre_m11 * inv_det);
-        self.set_m21(pre_m21 * inv_det);
-        self.set_m31(pre_m31 * inv_det);
-        self.set_m41(pre_m41 * inv_det);
-        self.set_m12(pre_m12 * inv_det);
-        self.set_m22(pre_m22 * inv_det);
-        self.set_m32(pre_m32 * inv_det);
-        self.set_m42(pre_m42 * inv_det);
-        self.set_m13(pre_m13 * inv_det);
-        self.set_m23(pre_m23 * inv_det);
-        self.set_m33(pre_m33 * inv_det);
-        self.set_m43(pre_m43 * inv_det);
-        self.set_m14(pre_m14 * inv_det);
-        self.set_m24(pre_m24 * inv_det);
-        self.set_m34(pre_m34 * inv_det);
-        self.set_m44(pre_m44 * inv_det);
         true
     }
 
@@ -1129,6 +2843,131 @@ impl Matrix4 {
     }
 }
 
+impl Default for Matrix4 {
+    /// Creates an identity matrix
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Matrix4;
+    ///
+    /// assert_eq!(Matrix4::default(), Matrix4::new());
+    /// ```
+    #[inline]
+    fn default() -> Matrix4 {
+        Matrix4::new()
+    }
+}
+
+impl From<[f32; 16]> for Matrix4 {
+    /// Creates a Matrix4 from an array of elements in column-major order
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Matrix4;
+    /// 
+    /// let actual = Matrix4::from([1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0, 10.0, 11.0, 12.0, 13.0, 14.0, 15.0, 16.0]);
+    /// let expected = Matrix4::make(1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0, 10.0, 11.0, 12.0, 13.0, 14.0, 15.0, 16.0);
+    /// assert_eq!(actual, expected);
+    /// ```
+    #[inline]
+    fn from(item: [f32; 16]) -> Matrix4 {
+        Matrix4 { m: item }
+    }
+}
+
+impl From<Matrix4> for [f32; 16] {
+    /// Creates an array from the elements of a Matrix4 in column-major order
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Matrix4;
+    /// 
+    /// let actual: [f32; 16] = Matrix4::make(1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0, 10.0, 11.0, 12.0, 13.0, 14.0, 15.0, 16.0).into();
+    /// let expected = [1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0, 10.0, 11.0, 12.0, 13.0, 14.0, 15.0, 16.0];
+    /// assert_eq!(actual, expected);
+    /// ```
+    #[inline]
+    fn from(item: Matrix4) -> [f32; 16] {
+        item.m
+    }
+}
+
+impl AsRef<[f32]> for Matrix4 {
+    /// Gets the elements as a slice in column-major order
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Matrix4;
+    /// 
+    /// let v = Matrix4::make(1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0, 10.0, 11.0, 12.0, 13.0, 14.0, 15.0, 16.0);
+    /// let actual: &[f32] = v.as_ref();
+    /// assert_eq!(actual, &[1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0, 10.0, 11.0, 12.0, 13.0, 14.0, 15.0, 16.0][..]);
+    /// ```
+    #[inline]
+    fn as_ref(&self) -> &[f32] {
+        &self.m
+    }
+}
+
+impl AsMut<[f32]> for Matrix4 {
+    /// Gets the elements as a mutable slice in column-major order
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Matrix4;
+    /// 
+    /// let mut v = Matrix4::make(1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0, 10.0, 11.0, 12.0, 13.0, 14.0, 15.0, 16.0);
+    /// v.as_mut()[0] = 5.0;
+    /// assert_eq!(v.as_ref()[0], 5.0);
+    /// ```
+    #[inline]
+    fn as_mut(&mut self) -> &mut [f32] {
+        &mut self.m
+    }
+}
+
+impl Index<(usize, usize)> for Matrix4 {
+    type Output = f32;
+
+    /// Looks up an element by its row and column, counting from 0
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Matrix4;
+    /// 
+    /// let m = Matrix4::from([
+    ///     1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0, 10.0, 11.0, 12.0, 13.0, 14.0, 15.0, 16.0,
+    /// ]);
+    /// assert_eq!(m[(1, 0)], 2.0);
+    /// assert_eq!(m[(0, 1)], 5.0);
+    /// ```
+    #[inline]
+    fn index(&self, index: (usize, usize)) -> &f32 {
+        let (row, col) = index;
+        assert!(row < 4 && col < 4, "Invalid index for Matrix4: {:?}", index);
+        &self.m[col * 4 + row]
+    }
+}
+
+impl IndexMut<(usize, usize)> for Matrix4 {
+    /// Mutate an element by its row and column, counting from 0
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Matrix4;
+    /// 
+    /// let mut m = Matrix4::new();
+    /// m[(0, 1)] = 5.0;
+    /// assert_eq!(m.m12(), 5.0);
+    /// ```
+    #[inline]
+    fn index_mut(&mut self, index: (usize, usize)) -> &mut f32 {
+        let (row, col) = index;
+        assert!(row < 4 && col < 4, "Invalid index for Matrix4: {:?}", index);
+        &mut self.m[col * 4 + row]
+    }
+}
+
 impl Neg for Matrix4 {
     type Output = Matrix4;
 
@@ -1146,10 +2985,8 @@ impl Neg for Matrix4 {
     fn neg(self) -> Matrix4 {
         let mut m = [0.0; 16];
 
-        unsafe {
-            for (i, elem) in self.m.iter().enumerate() {
-                m[i] = -*elem;
-            }
+        for (i, elem) in self.m.iter().enumerate() {
+            m[i] = -*elem;
         }
 
         Matrix4 { m }
@@ -1173,10 +3010,33 @@ impl Add<f32> for Matrix4 {
     fn add(self, _rhs: f32) -> Matrix4 {
         let mut mat = Matrix4::new();
 
-        unsafe {
-            for (i, elem) in self.m.iter().enumerate() {
-                mat.m[i] = *elem + _rhs;
-            }
+        for (i, elem) in self.m.iter().enumerate() {
+            mat.m[i] = *elem + _rhs;
+        }
+
+        mat
+    }
+}
+
+impl Add<Matrix4> for f32 {
+    type Output = Matrix4;
+
+    /// Find the resulting matrix by adding a matrix's elements to a scalar
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Matrix4;
+    /// 
+    /// let actual = 1.0 + Matrix4::translate(1.0, 2.0, 3.0);
+    /// let expected = Matrix4::translate(1.0, 2.0, 3.0) + 1.0;
+    /// assert_eq!(actual, expected);
+    /// ```
+    #[inline]
+    fn add(self, _rhs: Matrix4) -> Matrix4 {
+        let mut mat = Matrix4::new();
+
+        for (i, elem) in _rhs.m.iter().enumerate() {
+            mat.m[i] = self + *elem;
         }
 
         mat
@@ -1202,10 +3062,8 @@ impl Add<Matrix4> for Matrix4 {
     fn add(self, _rhs: Matrix4) -> Matrix4 {
         let mut mat = Matrix4::new();
 
-        unsafe {
-            for (i, elem) in self.m.iter().enumerate() {
-                mat.m[i] = *elem + _rhs.m[i];
-            }
+        for (i, elem) in self.m.iter().enumerate() {
+            mat.m[i] = *elem + _rhs.m[i];
         }
 
         mat
@@ -1226,10 +3084,8 @@ impl AddAssign<f32> for Matrix4 {
     /// ```
     #[inline]
     fn add_assign(&mut self, _rhs: f32) {
-        unsafe {
-            for elem in self.m.iter_mut() {
-                *elem += _rhs;
-            }
+        for elem in self.m.iter_mut() {
+            *elem += _rhs;
         }
     }
 }
@@ -1248,10 +3104,8 @@ impl AddAssign<Matrix4> for Matrix4 {
     /// ```
     #[inline]
     fn add_assign(&mut self, _rhs: Matrix4) {
-        unsafe {
-            for (i, elem) in self.m.iter_mut().enumerate() {
-                *elem += _rhs.m[i];
-            }
+        for (i, elem) in self.m.iter_mut().enumerate() {
+            *elem += _rhs.m[i];
         }
     }
 }
@@ -1273,10 +3127,33 @@ impl Sub<f32> for Matrix4 {
     fn sub(self, _rhs: f32) -> Matrix4 {
         let mut mat = Matrix4::new();
 
-        unsafe {
-            for (i, elem) in self.m.iter().enumerate() {
-                mat.m[i] = *elem - _rhs;
-            }
+        for (i, elem) in self.m.iter().enumerate() {
+            mat.m[i] = *elem - _rhs;
+        }
+
+        mat
+    }
+}
+
+impl Sub<Matrix4> for f32 {
+    type Output = Matrix4;
+
+    /// Find the resulting matrix by subtracting a matrix's elements from a scalar
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Matrix4;
+    /// 
+    /// let actual = 10.0 - Matrix4::translate(1.0, 2.0, 3.0);
+    /// let expected = -Matrix4::translate(1.0, 2.0, 3.0) + 10.0;
+    /// assert_eq!(actual, expected);
+    /// ```
+    #[inline]
+    fn sub(self, _rhs: Matrix4) -> Matrix4 {
+        let mut mat = Matrix4::new();
+
+        for (i, elem) in _rhs.m.iter().enumerate() {
+            mat.m[i] = self - *elem;
         }
 
         mat
@@ -1302,10 +3179,8 @@ impl Sub<Matrix4> for Matrix4 {
     fn sub(self, _rhs: Matrix4) -> Matrix4 {
         let mut mat = Matrix4::new();
 
-        unsafe {
-            for (i, elem) in self.m.iter().enumerate() {
-                mat.m[i] = *elem - _rhs.m[i];
-            }
+        for (i, elem) in self.m.iter().enumerate() {
+            mat.m[i] = *elem - _rhs.m[i];
         }
 
         mat
@@ -1326,10 +3201,8 @@ impl SubAssign<f32> for Matrix4 {
     /// ```
     #[inline]
     fn sub_assign(&mut self, _rhs: f32) {
-        unsafe {
-            for elem in self.m.iter_mut() {
-                *elem -= _rhs;
-            }
+        for elem in self.m.iter_mut() {
+            *elem -= _rhs;
         }
     }
 }
@@ -1347,10 +3220,8 @@ impl SubAssign<Matrix4> for Matrix4 {
     /// ```
     #[inline]
     fn sub_assign(&mut self, _rhs: Matrix4) {
-        unsafe {
-            for (i, elem) in self.m.iter_mut().enumerate() {
-                *elem -= _rhs.m[i];
-            }
+        for (i, elem) in self.m.iter_mut().enumerate() {
+            *elem -= _rhs.m[i];
         }
     }
 }
@@ -1372,10 +3243,33 @@ impl Mul<f32> for Matrix4 {
     fn mul(self, _rhs: f32) -> Matrix4 {
         let mut mat = Matrix4::new();
 
-        unsafe {
-            for (i, elem) in self.m.iter().enumerate() {
-                mat.m[i] = *elem * _rhs;
-            }
+        for (i, elem) in self.m.iter().enumerate() {
+            mat.m[i] = *elem * _rhs;
+        }
+
+        mat
+    }
+}
+
+impl Mul<Matrix4> for f32 {
+    type Output = Matrix4;
+
+    /// Find the resulting matrix by multiplying a scalar by a matrix's elements
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Matrix4;
+    /// 
+    /// let actual = 2.0 * Matrix4::translate(1.0, 2.0, 3.0);
+    /// let expected = Matrix4::translate(1.0, 2.0, 3.0) * 2.0;
+    /// assert_eq!(actual, expected);
+    /// ```
+    #[inline]
+    fn mul(self, _rhs: Matrix4) -> Matrix4 {
+        let mut mat = Matrix4::new();
+
+        for (i, elem) in _rhs.m.iter().enumerate() {
+            mat.m[i] = self * *elem;
         }
 
         mat
@@ -1403,7 +3297,16 @@ impl Mul<Matrix4> for Matrix4 {
     /// assert_eq!(actual, expected);
     /// ```
     #[inline]
+    #[cfg_attr(feature = "simd", allow(unreachable_code))]
     fn mul(self, _rhs: Matrix4) -> Matrix4 {
+        #[cfg(feature = "simd")]
+        {
+            let (a, b) = (self.m, _rhs.m);
+            return Matrix4 {
+                m: simd::mul_matrix4(&a, &b),
+            };
+        }
+
         let m11 = self.m11() * _rhs.m11()
             + self.m12() * _rhs.m21()
             + self.m13() * _rhs.m31()
@@ -1492,10 +3395,8 @@ impl MulAssign<f32> for Matrix4 {
     /// ```
     #[inline]
     fn mul_assign(&mut self, _rhs: f32) {
-        unsafe {
-            for elem in self.m.iter_mut() {
-                *elem *= _rhs;
-            }
+        for elem in self.m.iter_mut() {
+            *elem *= _rhs;
         }
     }
 }
@@ -1541,10 +3442,8 @@ impl Div<f32> for Matrix4 {
     fn div(self, _rhs: f32) -> Matrix4 {
         let mut mat = Matrix4::new();
 
-        unsafe {
-            for (i, elem) in self.m.iter().enumerate() {
-                mat.m[i] = *elem / _rhs;
-            }
+        for (i, elem) in self.m.iter().enumerate() {
+            mat.m[i] = *elem / _rhs;
         }
 
         mat
@@ -1565,10 +3464,8 @@ impl DivAssign<f32> for Matrix4 {
     /// ```
     #[inline]
     fn div_assign(&mut self, _rhs: f32) {
-        unsafe {
-            for elem in self.m.iter_mut() {
-                *elem /= _rhs;
-            }
+        for elem in self.m.iter_mut() {
+            *elem /= _rhs;
         }
     }
 }
@@ -1584,11 +3481,9 @@ impl cmp::PartialEq for Matrix4 {
     /// ```
     #[inline]
     fn eq(&self, _rhs: &Matrix4) -> bool {
-        unsafe {
-            for (i, elem) in self.m.iter().enumerate() {
-                if *elem != _rhs.m[i] {
-                    return false;
-                }
+        for (i, elem) in self.m.iter().enumerate() {
+            if *elem != _rhs.m[i] {
+                return false;
             }
         }
 
@@ -1597,28 +3492,76 @@ impl cmp::PartialEq for Matrix4 {
 }
 
 impl Display for Matrix4 {
+    /// Formats the matrix as a list of its rows, with the formatter's flags applied to each element
+    ///
+    /// The alternate flag lays the rows out as a grid instead, one per line, with each column
+    /// padded to its widest element.
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Matrix4;
+    ///
+    /// let m = Matrix4::translate(10.0, -2.0, 0.5);
+    /// assert_eq!(format!("{}", m), "[[1, 0, 0, 10], [0, 1, 0, -2], [0, 0, 1, 0.5], [0, 0, 0, 1]]");
+    /// assert_eq!(
+    ///     format!("{:#.1}", m),
+    ///     "[\n    [1.0, 0.0, 0.0, 10.0],\n    [0.0, 1.0, 0.0, -2.0],\n    [0.0, 0.0, 1.0,  0.5],\n    [0.0, 0.0, 0.0,  1.0]\n]",
+    /// );
+    /// ```
     #[inline]
     fn fmt(&self, f: &mut Formatter) -> fmt::Result {
-        write!(
-            f,
-            "[\n  {}, {}, {}, {}\n  {}, {}, {}, {}\n  {}, {}, {}, {}\n  {}, {}, {}, {}\n]",
-            self.m11(),
-            self.m12(),
-            self.m13(),
-            self.m14(),
-            self.m21(),
-            self.m22(),
-            self.m23(),
-            self.m24(),
-            self.m31(),
-            self.m32(),
-            self.m33(),
-            self.m34(),
-            self.m41(),
-            self.m42(),
-            self.m43(),
-            self.m44(),
-        )
+        let rows: [[f32; 4]; 4] = array::from_fn(|r| array::from_fn(|c| self.m[c * 4 + r]));
+        format::write_matrix(f, &rows)
+    }
+}
+
+impl Debug for Matrix4 {
+    /// Formats the matrix's elements grouped by column, in the order they're stored
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Matrix4;
+    ///
+    /// let m = Matrix4::translate(10.0, -2.0, 0.5);
+    /// assert_eq!(
+    ///     format!("{:?}", m),
+    ///     "Matrix4 { cols: [[1.0, 0.0, 0.0, 0.0], [0.0, 1.0, 0.0, 0.0], [0.0, 0.0, 1.0, 0.0], [10.0, -2.0, 0.5, 1.0]] }",
+    /// );
+    /// ```
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let cols: [[f32; 4]; 4] = array::from_fn(|c| array::from_fn(|r| self.m[c * 4 + r]));
+        f.debug_struct("Matrix4").field("cols", &cols).finish()
+    }
+}
+
+impl FromStr for Matrix4 {
+    type Err = ParseError;
+
+    /// Parses a matrix written as a list of its rows, as `Display` writes it
+    ///
+    /// Whitespace between elements and rows is ignored, so alternate grid output parses too.
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Matrix4;
+    /// use vex::ParseError;
+    ///
+    /// let m = Matrix4::translate(10.0, -2.0, 0.5);
+    /// let text = "[[1, 0, 0, 10], [0, 1, 0, -2], [0, 0, 1, 0.5], [0, 0, 0, 1]]";
+    /// assert_eq!(text.parse::<Matrix4>(), Ok(m));
+    /// assert_eq!(format!("{:#}", m).parse::<Matrix4>(), Ok(m));
+    /// assert_eq!(format!("{:8.3}", m).parse::<Matrix4>(), Ok(m));
+    ///
+    /// let actual = "[[1, 2], [1, 2], [1, 2]]".parse::<Matrix4>();
+    /// assert_eq!(actual, Err(ParseError::Count { expected: 4, found: 2 }));
+    /// ```
+    #[inline]
+    fn from_str(s: &str) -> Result<Matrix4, ParseError> {
+        let rows: [[f32; 4]; 4] = parse::parse_matrix(s)?;
+        Ok(Matrix4 {
+            m: array::from_fn(|i| rows[i % 4][i / 4]),
+        })
     }
 }
 
@@ -1684,3 +3627,44 @@ impl common::Matrix<Vector4> for Matrix4 {
         )
     }
 }
+
+impl common::SquareMatrix for Matrix4 {
+    #[inline]
+    fn identity() -> Matrix4 {
+        Matrix4::new()
+    }
+
+    #[inline]
+    fn determinant(&self) -> f32 {
+        Matrix4::determinant(self)
+    }
+
+    #[inline]
+    fn transposed(&self) -> Matrix4 {
+        Matrix4::transposed(self)
+    }
+
+    #[inline]
+    fn try_inverse(&self) -> Option<Matrix4> {
+        Matrix4::try_inverse(self)
+    }
+
+    #[inline]
+    fn approx_eq(&self, other: &Matrix4, epsilon: f32) -> bool {
+        Matrix4::approx_eq(self, other, epsilon)
+    }
+}
+
+impl common::MixFactor<Matrix4> for f32 {
+    #[inline]
+    fn spread(self) -> Matrix4 {
+        Matrix4 { m: [self; 16] }
+    }
+}
+
+impl common::MixFactor<Matrix4> for Matrix4 {
+    #[inline]
+    fn spread(self) -> Matrix4 {
+        self
+    }
+}
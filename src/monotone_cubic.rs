@@ -0,0 +1,247 @@
+#[cfg(not(feature = "std"))]
+use crate::math::Float;
+use crate::vector2::Vector2;
+use crate::vector3::Vector3;
+use crate::vector4::Vector4;
+
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+
+/// A value that can be interpolated one component at a time
+pub trait Components: Copy {
+    /// The number of components
+    const COUNT: usize;
+
+    /// Gets a component by index
+    fn component(&self, i: usize) -> f32;
+
+    /// Sets a component by index
+    fn set_component(&mut self, i: usize, value: f32);
+}
+
+impl Components for f32 {
+    const COUNT: usize = 1;
+
+    #[inline]
+    fn component(&self, _i: usize) -> f32 {
+        *self
+    }
+
+    #[inline]
+    fn set_component(&mut self, _i: usize, value: f32) {
+        *self = value;
+    }
+}
+
+macro_rules! impl_components {
+    ($t:ty, $count:expr) => {
+        impl Components for $t {
+            const COUNT: usize = $count;
+
+            #[inline]
+            fn component(&self, i: usize) -> f32 {
+                self[i as u32]
+            }
+
+            #[inline]
+            fn set_component(&mut self, i: usize, value: f32) {
+                self[i as u32] = value;
+            }
+        }
+    };
+}
+
+impl_components!(Vector2, 2);
+impl_components!(Vector3, 3);
+impl_components!(Vector4, 4);
+
+/// A curve through `(time, value)` keyframes that never overshoots them
+///
+/// Uses Fritsch-Carlson monotone cubic interpolation on each component independently. Wherever a
+/// component's keyframes only rise or only fall, the curve between them does too, and a component
+/// that holds still between two keyframes stays flat. Unlike Catmull-Rom, the curve never goes
+/// past the smallest or largest keyframe value.
+#[derive(Clone, Debug, PartialEq)]
+pub struct MonotoneCubic<T: Components> {
+    times: Vec<f32>,
+    values: Vec<T>,
+    tangents: Vec<T>,
+}
+
+impl<T: Components> MonotoneCubic<T> {
+    /// Creates a curve from keyframes sorted by time
+    ///
+    /// Returns `None` if there are no keyframes, or if their times aren't strictly increasing.
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::MonotoneCubic;
+    ///
+    /// assert!(MonotoneCubic::make(&[(0.0, 1.0), (1.0, 2.0)]).is_some());
+    /// assert_eq!(MonotoneCubic::<f32>::make(&[]), None);
+    /// assert_eq!(MonotoneCubic::make(&[(1.0, 1.0), (1.0, 2.0)]), None);
+    /// ```
+    pub fn make(keys: &[(f32, T)]) -> Option<MonotoneCubic<T>> {
+        let unordered = keys
+            .windows(2)
+            .any(|pair| pair[0].0.partial_cmp(&pair[1].0) != Some(Ordering::Less));
+        if keys.is_empty() || unordered {
+            return None;
+        }
+
+        let times: Vec<f32> = keys.iter().map(|key| key.0).collect();
+        let values: Vec<T> = keys.iter().map(|key| key.1).collect();
+        let mut tangents = values.clone();
+        let mut secants = Vec::with_capacity(keys.len().saturating_sub(1));
+        let mut slopes = Vec::with_capacity(keys.len());
+
+        for c in 0..T::COUNT {
+            secants.clear();
+            for k in 0..keys.len() - 1 {
+                let rise = values[k + 1].component(c) - values[k].component(c);
+                secants.push(rise / (times[k + 1] - times[k]));
+            }
+
+            slopes.clear();
+            slopes.extend((0..keys.len()).map(|k| initial_slope(&secants, k)));
+            for (k, &secant) in secants.iter().enumerate() {
+                limit_slopes(&mut slopes, k, secant);
+            }
+
+            for (tangent, &slope) in tangents.iter_mut().zip(slopes.iter()) {
+                tangent.set_component(c, slope);
+            }
+        }
+
+        Some(MonotoneCubic {
+            times,
+            values,
+            tangents,
+        })
+    }
+
+    /// Find the value of the curve at a time
+    ///
+    /// Times before the first keyframe or after the last one hold that keyframe's value.
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::MonotoneCubic;
+    /// use vex::Vector2;
+    ///
+    /// let keys = [(0.0, 0.0), (1.0, 1.0), (2.0, 1.0), (3.0, 4.0)];
+    /// let curve = MonotoneCubic::make(&keys).unwrap();
+    /// assert_eq!(curve.sample(1.0), 1.0);
+    /// assert_eq!(curve.sample(-1.0), 0.0);
+    /// assert_eq!(curve.sample(5.0), 4.0);
+    ///
+    /// // flat between equal keyframes, and never below or above its neighbors
+    /// assert_eq!(curve.sample(1.5), 1.0);
+    /// for i in 0..=30 {
+    ///     let value = curve.sample(i as f32 * 0.1);
+    ///     assert!(value >= 0.0 && value <= 4.0);
+    /// }
+    ///
+    /// let keys = [(0.0, Vector2::make(0.0, 2.0)), (2.0, Vector2::make(4.0, 0.0))];
+    /// let curve = MonotoneCubic::make(&keys).unwrap();
+    /// assert_eq!(curve.sample(1.0), Vector2::make(2.0, 1.0));
+    /// ```
+    pub fn sample(&self, time: f32) -> T {
+        let last = self.times.len() - 1;
+        if time.is_nan() || time <= self.times[0] {
+            return self.values[0];
+        } else if time >= self.times[last] {
+            return self.values[last];
+        }
+
+        // the keyframe starting the segment that contains the time
+        let k = self.times.partition_point(|&t| t <= time) - 1;
+        let h = self.times[k + 1] - self.times[k];
+        let t = (time - self.times[k]) / h;
+        let (t2, t3) = (t * t, t * t * t);
+
+        let h00 = 2.0 * t3 - 3.0 * t2 + 1.0;
+        let h10 = t3 - 2.0 * t2 + t;
+        let h01 = -2.0 * t3 + 3.0 * t2;
+        let h11 = t3 - t2;
+
+        let (a, b) = (self.values[k], self.values[k + 1]);
+        let (ma, mb) = (self.tangents[k], self.tangents[k + 1]);
+        let mut result = a;
+        for c in 0..T::COUNT {
+            let value = h00 * a.component(c)
+                + h10 * h * ma.component(c)
+                + h01 * b.component(c)
+                + h11 * h * mb.component(c);
+            result.set_component(c, value);
+        }
+
+        result
+    }
+
+    /// Gets the time of the first keyframe
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::MonotoneCubic;
+    ///
+    /// let curve = MonotoneCubic::make(&[(0.5, 1.0), (2.0, 3.0)]).unwrap();
+    /// assert_eq!(curve.start(), 0.5);
+    /// ```
+    #[inline]
+    pub fn start(&self) -> f32 {
+        self.times[0]
+    }
+
+    /// Gets the time of the last keyframe
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::MonotoneCubic;
+    ///
+    /// let curve = MonotoneCubic::make(&[(0.5, 1.0), (2.0, 3.0)]).unwrap();
+    /// assert_eq!(curve.end(), 2.0);
+    /// ```
+    #[inline]
+    pub fn end(&self) -> f32 {
+        self.times[self.times.len() - 1]
+    }
+}
+
+// averages the neighboring secants, or flattens the keyframe if it's a peak or a valley
+#[inline]
+fn initial_slope(secants: &[f32], k: usize) -> f32 {
+    if secants.is_empty() {
+        return 0.0;
+    } else if k == 0 {
+        return secants[0];
+    } else if k == secants.len() {
+        return secants[k - 1];
+    }
+
+    let (before, after) = (secants[k - 1], secants[k]);
+    if before * after <= 0.0 {
+        0.0
+    } else {
+        (before + after) * 0.5
+    }
+}
+
+// shrinks the slopes at both ends of segment `k` until its cubic can't overshoot
+#[inline]
+fn limit_slopes(slopes: &mut [f32], k: usize, secant: f32) {
+    if secant == 0.0 {
+        slopes[k] = 0.0;
+        slopes[k + 1] = 0.0;
+        return;
+    }
+
+    let alpha = slopes[k] / secant;
+    let beta = slopes[k + 1] / secant;
+    let length_sq = alpha * alpha + beta * beta;
+    if length_sq > 9.0 {
+        let scale = 3.0 / length_sq.sqrt();
+        slopes[k] = scale * alpha * secant;
+        slopes[k + 1] = scale * beta * secant;
+    }
+}
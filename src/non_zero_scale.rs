@@ -0,0 +1,119 @@
+use crate::vector3::Vector3;
+
+use core::ops::Deref;
+
+/// A per-axis scale that is guaranteed to be invertible
+///
+/// Every component is a normal float, so it's finite, non-zero, and has a finite reciprocal.
+/// Scale matrices built from one never collapse an axis, so they can always be inverted. Derefs
+/// to `Vector3`, so its components and methods can be read directly.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct NonZeroScale(Vector3);
+
+impl NonZeroScale {
+    /// The scale that leaves every axis unchanged
+    pub const ONE: NonZeroScale = NonZeroScale(Vector3::ONE);
+
+    /// Creates a scale from per-axis factors, if every factor is a normal float
+    ///
+    /// Zero, subnormal, infinite, and NaN factors give `None`. Negative factors are allowed, and
+    /// mirror their axis.
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::NonZeroScale;
+    /// use vex::Vector3;
+    ///
+    /// let actual = NonZeroScale::try_new(Vector3::make(2.0, -1.0, 0.5)).unwrap();
+    /// assert_eq!(actual.get(), Vector3::make(2.0, -1.0, 0.5));
+    ///
+    /// assert!(NonZeroScale::try_new(Vector3::make(2.0, 0.0, 0.5)).is_none());
+    /// assert!(NonZeroScale::try_new(Vector3::make(2.0, 1.0e-40, 0.5)).is_none());
+    /// assert!(NonZeroScale::try_new(Vector3::make(f32::NAN, 1.0, 0.5)).is_none());
+    /// ```
+    #[inline]
+    pub fn try_new(v: Vector3) -> Option<NonZeroScale> {
+        if v.x.is_normal() && v.y.is_normal() && v.z.is_normal() {
+            Some(NonZeroScale(v))
+        } else {
+            None
+        }
+    }
+
+    /// Creates a scale with the same factor on every axis, if it's a normal float
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::NonZeroScale;
+    /// use vex::Vector3;
+    ///
+    /// let actual = NonZeroScale::try_uniform(3.0).unwrap();
+    /// assert_eq!(actual.get(), Vector3::make(3.0, 3.0, 3.0));
+    /// assert!(NonZeroScale::try_uniform(0.0).is_none());
+    /// ```
+    #[inline]
+    pub fn try_uniform(s: f32) -> Option<NonZeroScale> {
+        NonZeroScale::try_new(Vector3::make(s, s, s))
+    }
+
+    /// Gets the scale as a plain `Vector3`
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::NonZeroScale;
+    /// use vex::Vector3;
+    ///
+    /// assert_eq!(NonZeroScale::ONE.get(), Vector3::ONE);
+    /// ```
+    #[inline]
+    pub fn get(&self) -> Vector3 {
+        self.0
+    }
+
+    /// Find the scale that undoes this one
+    ///
+    /// The reciprocal of a normal float is always finite and non-zero, though the reciprocals of
+    /// the very largest factors are subnormal, so the result is a plain `Vector3`.
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::NonZeroScale;
+    /// use vex::Vector3;
+    ///
+    /// let scale = NonZeroScale::try_new(Vector3::make(2.0, -4.0, 0.5)).unwrap();
+    /// assert_eq!(scale.recip(), Vector3::make(0.5, -0.25, 2.0));
+    ///
+    /// let huge = NonZeroScale::try_uniform(f32::MAX).unwrap();
+    /// assert!(huge.recip().x > 0.0);
+    /// ```
+    #[inline]
+    pub fn recip(&self) -> Vector3 {
+        Vector3::make(1.0 / self.0.x, 1.0 / self.0.y, 1.0 / self.0.z)
+    }
+}
+
+impl Deref for NonZeroScale {
+    type Target = Vector3;
+
+    #[inline]
+    fn deref(&self) -> &Vector3 {
+        &self.0
+    }
+}
+
+impl From<NonZeroScale> for Vector3 {
+    /// Converts a scale into a plain `Vector3`
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::NonZeroScale;
+    /// use vex::Vector3;
+    ///
+    /// let actual: Vector3 = NonZeroScale::ONE.into();
+    /// assert_eq!(actual, Vector3::ONE);
+    /// ```
+    #[inline]
+    fn from(scale: NonZeroScale) -> Vector3 {
+        scale.0
+    }
+}
@@ -0,0 +1,144 @@
+#[cfg(not(feature = "std"))]
+use crate::math::Float;
+
+/// Encodes a value as a normalized i16, clamping it to `[-1, 1]` and rounding to the nearest step
+///
+/// NaN encodes as zero, matching GPU conversion rules.
+///
+/// # Examples
+/// ```
+/// use vex::encode_snorm16;
+///
+/// assert_eq!(encode_snorm16(1.0), 32767);
+/// assert_eq!(encode_snorm16(-1.0), -32767);
+/// assert_eq!(encode_snorm16(0.5), 16384);
+/// assert_eq!(encode_snorm16(2.0), 32767);
+/// ```
+#[inline]
+pub fn encode_snorm16(x: f32) -> i16 {
+    (x.clamp(-1.0, 1.0) * 32767.0).round() as i16
+}
+
+/// Decodes a normalized i16 back to a value in `[-1, 1]`
+///
+/// Both the minimum and minimum plus one decode to -1, so zero is exactly representable.
+/// This matches how GPUs read `snorm16` vertex attributes.
+///
+/// # Examples
+/// ```
+/// use vex::decode_snorm16;
+///
+/// assert_eq!(decode_snorm16(32767), 1.0);
+/// assert_eq!(decode_snorm16(0), 0.0);
+/// assert_eq!(decode_snorm16(-32767), -1.0);
+/// assert_eq!(decode_snorm16(i16::MIN), -1.0);
+/// ```
+#[inline]
+pub fn decode_snorm16(x: i16) -> f32 {
+    (x as f32 / 32767.0).max(-1.0)
+}
+
+/// Encodes a value as a normalized i8, clamping it to `[-1, 1]` and rounding to the nearest step
+///
+/// NaN encodes as zero, matching GPU conversion rules.
+///
+/// # Examples
+/// ```
+/// use vex::encode_snorm8;
+///
+/// assert_eq!(encode_snorm8(1.0), 127);
+/// assert_eq!(encode_snorm8(-1.0), -127);
+/// assert_eq!(encode_snorm8(0.5), 64);
+/// assert_eq!(encode_snorm8(2.0), 127);
+/// ```
+#[inline]
+pub fn encode_snorm8(x: f32) -> i8 {
+    (x.clamp(-1.0, 1.0) * 127.0).round() as i8
+}
+
+/// Decodes a normalized i8 back to a value in `[-1, 1]`
+///
+/// Both the minimum and minimum plus one decode to -1, so zero is exactly representable.
+/// This matches how GPUs read `snorm8` vertex attributes.
+///
+/// # Examples
+/// ```
+/// use vex::decode_snorm8;
+///
+/// assert_eq!(decode_snorm8(127), 1.0);
+/// assert_eq!(decode_snorm8(0), 0.0);
+/// assert_eq!(decode_snorm8(-127), -1.0);
+/// assert_eq!(decode_snorm8(i8::MIN), -1.0);
+/// ```
+#[inline]
+pub fn decode_snorm8(x: i8) -> f32 {
+    (x as f32 / 127.0).max(-1.0)
+}
+
+/// Encodes a value as a normalized u16, clamping it to `[0, 1]` and rounding to the nearest step
+///
+/// NaN encodes as zero, matching GPU conversion rules.
+///
+/// # Examples
+/// ```
+/// use vex::encode_unorm16;
+///
+/// assert_eq!(encode_unorm16(1.0), 65535);
+/// assert_eq!(encode_unorm16(0.0), 0);
+/// assert_eq!(encode_unorm16(0.5), 32768);
+/// assert_eq!(encode_unorm16(-1.0), 0);
+/// ```
+#[inline]
+pub fn encode_unorm16(x: f32) -> u16 {
+    (x.clamp(0.0, 1.0) * 65535.0).round() as u16
+}
+
+/// Decodes a normalized u16 back to a value in `[0, 1]`
+///
+/// This matches how GPUs read `unorm16` vertex attributes.
+///
+/// # Examples
+/// ```
+/// use vex::decode_unorm16;
+///
+/// assert_eq!(decode_unorm16(65535), 1.0);
+/// assert_eq!(decode_unorm16(0), 0.0);
+/// ```
+#[inline]
+pub fn decode_unorm16(x: u16) -> f32 {
+    x as f32 / 65535.0
+}
+
+/// Encodes a value as a normalized u8, clamping it to `[0, 1]` and rounding to the nearest step
+///
+/// NaN encodes as zero, matching GPU conversion rules.
+///
+/// # Examples
+/// ```
+/// use vex::encode_unorm8;
+///
+/// assert_eq!(encode_unorm8(1.0), 255);
+/// assert_eq!(encode_unorm8(0.0), 0);
+/// assert_eq!(encode_unorm8(0.5), 128);
+/// assert_eq!(encode_unorm8(-1.0), 0);
+/// ```
+#[inline]
+pub fn encode_unorm8(x: f32) -> u8 {
+    (x.clamp(0.0, 1.0) * 255.0).round() as u8
+}
+
+/// Decodes a normalized u8 back to a value in `[0, 1]`
+///
+/// This matches how GPUs read `unorm8` vertex attributes.
+///
+/// # Examples
+/// ```
+/// use vex::decode_unorm8;
+///
+/// assert_eq!(decode_unorm8(255), 1.0);
+/// assert_eq!(decode_unorm8(0), 0.0);
+/// ```
+#[inline]
+pub fn decode_unorm8(x: u8) -> f32 {
+    x as f32 / 255.0
+}
@@ -0,0 +1,260 @@
+use crate::ivector2::IVector2;
+
+use alloc::vec::Vec;
+
+#[derive(Copy, Clone, Debug)]
+struct SkylineNode {
+    x: i32,
+    y: i32,
+    width: i32,
+}
+
+/// Packs rectangles into an atlas using the skyline bottom-left heuristic
+#[derive(Clone, Debug)]
+pub struct RectPacker {
+    size: IVector2,
+    max_size: IVector2,
+    skyline: Vec<SkylineNode>,
+}
+
+impl RectPacker {
+    /// Creates a packer for an atlas of a fixed size
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::IVector2;
+    /// use vex::RectPacker;
+    ///
+    /// let packer = RectPacker::make(IVector2::make(256, 128));
+    /// assert_eq!(packer.size(), IVector2::make(256, 128));
+    /// ```
+    #[inline]
+    pub fn make(size: IVector2) -> RectPacker {
+        RectPacker::make_growable(size, size)
+    }
+
+    /// Creates a packer that doubles the atlas (up to `max_size`) whenever a rectangle doesn't fit
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::IVector2;
+    /// use vex::RectPacker;
+    ///
+    /// let mut packer = RectPacker::make_growable(IVector2::make(16, 16), IVector2::make(64, 64));
+    /// let actual = packer.insert(&IVector2::make(32, 8));
+    /// assert_eq!(actual, Some(IVector2::make(0, 0)));
+    /// assert_eq!(packer.size(), IVector2::make(32, 16));
+    /// ```
+    #[inline]
+    pub fn make_growable(size: IVector2, max_size: IVector2) -> RectPacker {
+        RectPacker {
+            size,
+            max_size: IVector2::make(max_size.x.max(size.x), max_size.y.max(size.y)),
+            skyline: vec![SkylineNode { x: 0, y: 0, width: size.x }],
+        }
+    }
+
+    /// Gets the current size of the atlas
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::IVector2;
+    /// use vex::RectPacker;
+    ///
+    /// let packer = RectPacker::make(IVector2::make(64, 32));
+    /// assert_eq!(packer.size(), IVector2::make(64, 32));
+    /// ```
+    #[inline]
+    pub fn size(&self) -> IVector2 {
+        self.size
+    }
+
+    /// Removes all placed rectangles while keeping the current atlas size
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::IVector2;
+    /// use vex::RectPacker;
+    ///
+    /// let mut packer = RectPacker::make(IVector2::make(8, 8));
+    /// packer.insert(&IVector2::make(8, 8));
+    /// packer.clear();
+    /// assert_eq!(packer.insert(&IVector2::make(8, 8)), Some(IVector2::make(0, 0)));
+    /// ```
+    #[inline]
+    pub fn clear(&mut self) {
+        self.skyline.clear();
+        self.skyline.push(SkylineNode { x: 0, y: 0, width: self.size.x });
+    }
+
+    /// Places a rectangle, returning its bottom-left position in the atlas
+    ///
+    /// Returns `None` if the size isn't positive or the rectangle doesn't fit, even after growing.
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::IVector2;
+    /// use vex::RectPacker;
+    ///
+    /// let mut packer = RectPacker::make(IVector2::make(64, 64));
+    /// assert_eq!(packer.insert(&IVector2::make(32, 16)), Some(IVector2::make(0, 0)));
+    /// assert_eq!(packer.insert(&IVector2::make(32, 8)), Some(IVector2::make(32, 0)));
+    /// assert_eq!(packer.insert(&IVector2::make(40, 8)), Some(IVector2::make(0, 16)));
+    /// assert_eq!(packer.insert(&IVector2::make(128, 8)), None);
+    /// ```
+    pub fn insert(&mut self, size: &IVector2) -> Option<IVector2> {
+        if size.x <= 0 || size.y <= 0 {
+            return None;
+        }
+
+        loop {
+            if let Some((index, position)) = self.find_position(size) {
+                self.place(index, position, size);
+                return Some(position);
+            }
+
+            if !self.grow() {
+                return None;
+            }
+        }
+    }
+
+    fn find_position(&self, size: &IVector2) -> Option<(usize, IVector2)> {
+        let mut best: Option<(usize, IVector2)> = None;
+        let mut best_top = i32::MAX;
+        let mut best_width = i32::MAX;
+
+        for (i, node) in self.skyline.iter().enumerate() {
+            if let Some(y) = self.fit(i, size) {
+                let top = y + size.y;
+                if top < best_top || (top == best_top && node.width < best_width) {
+                    best = Some((i, IVector2::make(node.x, y)));
+                    best_top = top;
+                    best_width = node.width;
+                }
+            }
+        }
+
+        best
+    }
+
+    fn fit(&self, index: usize, size: &IVector2) -> Option<i32> {
+        let x = self.skyline[index].x;
+        if x + size.x > self.size.x {
+            return None;
+        }
+
+        let mut y = 0;
+        let mut remaining = size.x;
+        for node in &self.skyline[index..] {
+            y = y.max(node.y);
+            if y + size.y > self.size.y {
+                return None;
+            }
+
+            remaining -= node.width;
+            if remaining <= 0 {
+                return Some(y);
+            }
+        }
+
+        None
+    }
+
+    fn place(&mut self, index: usize, position: IVector2, size: &IVector2) {
+        let node = SkylineNode {
+            x: position.x,
+            y: position.y + size.y,
+            width: size.x,
+        };
+
+        self.skyline.insert(index, node);
+
+        // shrink or remove the nodes now covered by the new one
+        let right = node.x + node.width;
+        while index + 1 < self.skyline.len() {
+            let next = &mut self.skyline[index + 1];
+            if next.x >= right {
+                break;
+            }
+
+            let overlap = right - next.x;
+            if overlap < next.width {
+                next.x += overlap;
+                next.width -= overlap;
+                break;
+            }
+
+            self.skyline.remove(index + 1);
+        }
+
+        self.merge();
+    }
+
+    fn merge(&mut self) {
+        let mut i = 0;
+        while i + 1 < self.skyline.len() {
+            if self.skyline[i].y == self.skyline[i + 1].y {
+                self.skyline[i].width += self.skyline[i + 1].width;
+                self.skyline.remove(i + 1);
+            } else {
+                i += 1;
+            }
+        }
+    }
+
+    fn grow(&mut self) -> bool {
+        let can_grow_x = self.size.x < self.max_size.x;
+        let can_grow_y = self.size.y < self.max_size.y;
+
+        if can_grow_x && (self.size.x <= self.size.y || !can_grow_y) {
+            let width = (self.size.x * 2).max(1).min(self.max_size.x);
+            self.skyline.push(SkylineNode {
+                x: self.size.x,
+                y: 0,
+                width: width - self.size.x,
+            });
+
+            self.size.x = width;
+            self.merge();
+            true
+        } else if can_grow_y {
+            self.size.y = (self.size.y * 2).max(1).min(self.max_size.y);
+            true
+        } else {
+            false
+        }
+    }
+}
+
+/// Packs a set of rectangles into an atlas, tallest first, growing it up to `max_size` when needed
+///
+/// Returns the final atlas size and the bottom-left position of each rectangle in input order, or
+/// `None` if they couldn't all be placed.
+///
+/// # Examples
+/// ```
+/// use vex::IVector2;
+/// use vex::pack_rects;
+///
+/// let sizes = [IVector2::make(8, 8), IVector2::make(16, 16), IVector2::make(8, 8)];
+/// let (size, positions) = pack_rects(&sizes, IVector2::make(16, 16), IVector2::make(64, 64)).unwrap();
+/// assert_eq!(size, IVector2::make(32, 16));
+/// assert_eq!(positions, vec![IVector2::make(16, 0), IVector2::make(0, 0), IVector2::make(24, 0)]);
+/// ```
+pub fn pack_rects(
+    sizes: &[IVector2],
+    size: IVector2,
+    max_size: IVector2,
+) -> Option<(IVector2, Vec<IVector2>)> {
+    let mut order: Vec<usize> = (0..sizes.len()).collect();
+    order.sort_by(|&a, &b| sizes[b].y.cmp(&sizes[a].y).then(sizes[b].x.cmp(&sizes[a].x)));
+
+    let mut packer = RectPacker::make_growable(size, max_size);
+    let mut positions = vec![IVector2::new(); sizes.len()];
+    for i in order {
+        positions[i] = packer.insert(&sizes[i])?;
+    }
+
+    Some((packer.size(), positions))
+}
@@ -0,0 +1,129 @@
+use core::fmt;
+use core::fmt::{Display, Formatter};
+use core::num::ParseFloatError;
+use core::str::FromStr;
+
+/// The reason text couldn't be parsed as a vector or matrix
+///
+/// Parsing accepts what `Display` writes, including padded and alternate grid output: vectors as
+/// `<x, y, z>`, and matrices as a list of rows, `[[m11, m12], [m21, m22]]`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ParseError {
+    /// A bracket or separator was missing where this character was expected
+    Expected(char),
+    /// There were a different number of components in a vector or matrix row than the type has
+    Count { expected: usize, found: usize },
+    /// There were a different number of rows than the matrix has
+    Rows { expected: usize, found: usize },
+    /// The component at this position, counting from zero in the order written, isn't a number
+    Number { index: usize, source: ParseFloatError },
+}
+
+impl Display for ParseError {
+    /// Describes what went wrong
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::ParseError;
+    ///
+    /// let actual = ParseError::Count { expected: 3, found: 2 };
+    /// assert_eq!(actual.to_string(), "expected 3 components, found 2");
+    /// assert_eq!(ParseError::Expected('<').to_string(), "expected `<`");
+    /// ```
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            ParseError::Expected(c) => write!(f, "expected `{}`", c),
+            ParseError::Count { expected, found } => {
+                write!(f, "expected {} components, found {}", expected, found)
+            }
+            ParseError::Rows { expected, found } => {
+                write!(f, "expected {} rows, found {}", expected, found)
+            }
+            ParseError::Number { index, source } => {
+                write!(f, "component {} isn't a number: {}", index, source)
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ParseError {
+    #[inline]
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            ParseError::Number { source, .. } => Some(source),
+            _ => None,
+        }
+    }
+}
+
+// strips a pair of brackets from around the trimmed text
+fn strip_brackets(s: &str, open: char, close: char) -> Result<&str, ParseError> {
+    let s = s.trim();
+    let s = s.strip_prefix(open).ok_or(ParseError::Expected(open))?;
+    s.strip_suffix(close).ok_or(ParseError::Expected(close))
+}
+
+// parses a list of numbers separated by commas, or by whitespace alone as older versions wrote
+fn parse_components<const N: usize>(s: &str, first: usize) -> Result<[f32; N], ParseError> {
+    let mut components = [0.0; N];
+    let mut found = 0;
+    let mut parse = |text: &str| {
+        if found < N {
+            components[found] = f32::from_str(text.trim()).map_err(|source| {
+                ParseError::Number {
+                    index: first + found,
+                    source,
+                }
+            })?;
+        }
+
+        found += 1;
+        Ok(())
+    };
+
+    if s.contains(',') {
+        s.split(',').try_for_each(&mut parse)?;
+    } else {
+        s.split_whitespace().try_for_each(&mut parse)?;
+    }
+
+    if found != N {
+        return Err(ParseError::Count { expected: N, found });
+    }
+
+    Ok(components)
+}
+
+/// Parses a vector written as `<x, y, z>`
+pub(crate) fn parse_vector<const N: usize>(s: &str) -> Result<[f32; N], ParseError> {
+    parse_components(strip_brackets(s, '<', '>')?, 0)
+}
+
+/// Parses a matrix written as a list of rows, returning its rows
+pub(crate) fn parse_matrix<const N: usize>(s: &str) -> Result<[[f32; N]; N], ParseError> {
+    let mut rest = strip_brackets(s, '[', ']')?.trim_start();
+    let mut rows = [[0.0; N]; N];
+    let mut found = 0;
+    while !rest.is_empty() {
+        if found > 0 {
+            rest = rest.strip_prefix(',').ok_or(ParseError::Expected(','))?.trim_start();
+        }
+
+        rest = rest.strip_prefix('[').ok_or(ParseError::Expected('['))?;
+        let end = rest.find(']').ok_or(ParseError::Expected(']'))?;
+        if found < N {
+            rows[found] = parse_components(&rest[..end], found * N)?;
+        }
+
+        found += 1;
+        rest = rest[end + 1..].trim_start();
+    }
+
+    if found != N {
+        return Err(ParseError::Rows { expected: N, found });
+    }
+
+    Ok(rows)
+}
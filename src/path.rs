@@ -0,0 +1,403 @@
+#[cfg(not(feature = "std"))]
+use crate::math::Float;
+use crate::matrix4::Matrix4;
+use crate::vector3::Vector3;
+
+use alloc::vec::Vec;
+
+/// A point on a waypoint path, as found by [`closest_point_on_path`](fn.closest_point_on_path.html)
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct PathPoint {
+    /// The position on the path
+    pub position: Vector3,
+    /// The index of the segment from `path[segment]` to `path[segment + 1]` containing the point
+    pub segment: usize,
+    /// The distance along the path from the first waypoint
+    pub distance: f32,
+}
+
+/// Find the total length of a waypoint path
+///
+/// # Examples
+/// ```
+/// use vex::Vector3;
+/// use vex::path_length;
+///
+/// let path = [
+///     Vector3::make(0.0, 0.0, 0.0),
+///     Vector3::make(3.0, 0.0, 0.0),
+///     Vector3::make(3.0, 4.0, 0.0),
+/// ];
+/// assert_eq!(path_length(&path), 7.0);
+/// assert_eq!(path_length(&[]), 0.0);
+/// ```
+#[inline]
+pub fn path_length(path: &[Vector3]) -> f32 {
+    path.windows(2).map(|w| (w[1] - w[0]).mag()).sum()
+}
+
+/// Find the point on a waypoint path closest to the provided point
+///
+/// If several segments are equally close, the one earliest along the path wins. A path with a
+/// single waypoint always returns that waypoint, and an empty path returns `None`.
+///
+/// # Examples
+/// ```
+/// use vex::Vector3;
+/// use vex::closest_point_on_path;
+///
+/// let path = [
+///     Vector3::make(0.0, 0.0, 0.0),
+///     Vector3::make(3.0, 0.0, 0.0),
+///     Vector3::make(3.0, 4.0, 0.0),
+/// ];
+/// let actual = closest_point_on_path(&path, &Vector3::make(5.0, 2.0, 1.0)).unwrap();
+/// assert_eq!(actual.position, Vector3::make(3.0, 2.0, 0.0));
+/// assert_eq!(actual.segment, 1);
+/// assert_eq!(actual.distance, 5.0);
+///
+/// assert_eq!(closest_point_on_path(&[], &Vector3::new()), None);
+/// ```
+pub fn closest_point_on_path(path: &[Vector3], point: &Vector3) -> Option<PathPoint> {
+    let first = *path.first()?;
+    let mut best = PathPoint {
+        position: first,
+        segment: 0,
+        distance: 0.0,
+    };
+    let mut best_dist_sq = (*point - first).mag_sq();
+    let mut travelled = 0.0;
+
+    for (i, w) in path.windows(2).enumerate() {
+        let d = w[1] - w[0];
+        let length_sq = d.mag_sq();
+        let t = if length_sq > 0.0 {
+            (Vector3::dot(&(*point - w[0]), &d) / length_sq).clamp(0.0, 1.0)
+        } else {
+            0.0
+        };
+
+        let position = w[0] + d * t;
+        let dist_sq = (*point - position).mag_sq();
+        let length = length_sq.sqrt();
+        if dist_sq < best_dist_sq {
+            best_dist_sq = dist_sq;
+            best = PathPoint {
+                position,
+                segment: i,
+                distance: travelled + length * t,
+            };
+        }
+
+        travelled += length;
+    }
+
+    Some(best)
+}
+
+/// Find the distance along a waypoint path of the path's closest point to the provided point
+///
+/// # Examples
+/// ```
+/// use vex::Vector3;
+/// use vex::path_distance;
+///
+/// let path = [
+///     Vector3::make(0.0, 0.0, 0.0),
+///     Vector3::make(3.0, 0.0, 0.0),
+///     Vector3::make(3.0, 4.0, 0.0),
+/// ];
+/// assert_eq!(path_distance(&path, &Vector3::make(1.0, -1.0, 0.0)), Some(1.0));
+/// assert_eq!(path_distance(&path, &Vector3::make(3.0, 9.0, 0.0)), Some(7.0));
+/// ```
+#[inline]
+pub fn path_distance(path: &[Vector3], point: &Vector3) -> Option<f32> {
+    closest_point_on_path(path, point).map(|p| p.distance)
+}
+
+/// Find the position at a distance along a waypoint path
+///
+/// The distance is clamped to the path, so negative distances return the first waypoint and
+/// distances past the end return the last. An empty path returns `None`.
+///
+/// # Examples
+/// ```
+/// use vex::Vector3;
+/// use vex::point_at_path_distance;
+///
+/// let path = [
+///     Vector3::make(0.0, 0.0, 0.0),
+///     Vector3::make(3.0, 0.0, 0.0),
+///     Vector3::make(3.0, 4.0, 0.0),
+/// ];
+/// assert_eq!(point_at_path_distance(&path, 5.0), Some(Vector3::make(3.0, 2.0, 0.0)));
+/// assert_eq!(point_at_path_distance(&path, -1.0), Some(Vector3::make(0.0, 0.0, 0.0)));
+/// assert_eq!(point_at_path_distance(&path, 10.0), Some(Vector3::make(3.0, 4.0, 0.0)));
+/// ```
+pub fn point_at_path_distance(path: &[Vector3], distance: f32) -> Option<Vector3> {
+    let first = *path.first()?;
+    if distance <= 0.0 {
+        return Some(first);
+    }
+
+    let mut remaining = distance;
+    for w in path.windows(2) {
+        let d = w[1] - w[0];
+        let length = d.mag();
+        if remaining < length {
+            return Some(w[0] + d * (remaining / length));
+        }
+
+        remaining -= length;
+    }
+
+    path.last().copied()
+}
+
+/// Find the shortest path from `start` to `end` through a corridor of portals
+///
+/// Each portal is a `(left, right)` pair of edge endpoints as seen when walking from `start`
+/// towards `end`. This is the simple stupid funnel algorithm, which works on the XZ plane of a
+/// y-up world, so the y coordinates of the returned corners come from the portal endpoints they
+/// lie on. The result starts with `start`, ends with `end`, and in between holds the portal
+/// endpoints that the path turns around.
+///
+/// # Examples
+/// ```
+/// use vex::Vector3;
+/// use vex::string_pull;
+///
+/// // the straight line is blocked, so the path hugs the right side of the opening
+/// let portals = [(Vector3::make(5.0, 0.0, -3.0), Vector3::make(5.0, 0.0, -1.0))];
+/// let start = Vector3::make(0.0, 0.0, 0.0);
+/// let end = Vector3::make(10.0, 0.0, 0.0);
+/// let actual = string_pull(&portals, &start, &end);
+/// let expected = vec![start, Vector3::make(5.0, 0.0, -1.0), end];
+/// assert_eq!(actual, expected);
+///
+/// // a wide opening doesn't bend the path
+/// let portals = [(Vector3::make(5.0, 0.0, -3.0), Vector3::make(5.0, 0.0, 3.0))];
+/// assert_eq!(string_pull(&portals, &start, &end), vec![start, end]);
+/// ```
+pub fn string_pull(portals: &[(Vector3, Vector3)], start: &Vector3, end: &Vector3) -> Vec<Vector3> {
+    let mut edges = Vec::with_capacity(portals.len() + 2);
+    edges.push((*start, *start));
+    edges.extend_from_slice(portals);
+    edges.push((*end, *end));
+
+    let mut path = vec![*start];
+    let mut apex = *start;
+    let mut left = *start;
+    let mut right = *start;
+    let (mut left_index, mut right_index) = (0, 0);
+
+    let mut i = 1;
+    while i < edges.len() {
+        let (next_left, next_right) = edges[i];
+
+        // tighten the right side of the funnel
+        if signed_area_xz(&apex, &right, &next_right) <= 0.0 {
+            if same_xz(&apex, &right) || signed_area_xz(&apex, &left, &next_right) > 0.0 {
+                right = next_right;
+                right_index = i;
+            } else {
+                // the right side crossed over the left, so the left becomes a corner
+                path.push(left);
+                apex = left;
+                right = apex;
+                right_index = left_index;
+                i = left_index + 1;
+                continue;
+            }
+        }
+
+        // tighten the left side of the funnel
+        if signed_area_xz(&apex, &left, &next_left) >= 0.0 {
+            if same_xz(&apex, &left) || signed_area_xz(&apex, &right, &next_left) < 0.0 {
+                left = next_left;
+                left_index = i;
+            } else {
+                // the left side crossed over the right, so the right becomes a corner
+                path.push(right);
+                apex = right;
+                left = apex;
+                left_index = right_index;
+                i = right_index + 1;
+                continue;
+            }
+        }
+
+        i += 1;
+    }
+
+    if !same_xz(path.last().unwrap(), end) {
+        path.push(*end);
+    }
+
+    path
+}
+
+/// Find rotation-minimizing frames along a path, for extruding a cross-section along it
+///
+/// Each frame is a model matrix placed at its point, with its z-axis along the path, its y-axis
+/// starting as close to `up_hint` as possible, and its x-axis completing a right-handed frame.
+/// A cross-section drawn on the XY plane is swept along the path by transforming it with each
+/// frame. The frames are carried along by parallel transport (using double reflection), so they
+/// don't twist around the path the way frames built from a fixed up vector do at steep sections.
+///
+/// Returns no frames for paths with fewer than 2 points.
+///
+/// # Examples
+/// ```
+/// use vex::Matrix;
+/// use vex::Vector3;
+/// use vex::sweep_frames;
+///
+/// // a quarter circle turning from +x towards +z
+/// let path: Vec<Vector3> = (0..=8)
+///     .map(|i| {
+///         let angle = i as f32 / 8.0 * std::f32::consts::FRAC_PI_2;
+///         Vector3::make(angle.sin(), 0.0, 1.0 - angle.cos())
+///     })
+///     .collect();
+/// let frames = sweep_frames(&path, &Vector3::up());
+/// assert_eq!(frames.len(), path.len());
+///
+/// // frames sit on the path, face along it, and keep up as up on a flat curve
+/// for (frame, point) in frames.iter().zip(path.iter()) {
+///     assert!((frame.transform_point(&Vector3::new()) - *point).mag() < 0.000001);
+///     assert!((frame.transform_point(&Vector3::up()) - *point - Vector3::up()).mag() < 0.000001);
+/// }
+///
+/// // the end tangents only see one neighbor, so they lag the curve a little
+/// let forward = frames[8].transform_point(&Vector3::make(0.0, 0.0, 1.0)) - path[8];
+/// assert!((forward - Vector3::make(0.0, 0.0, 1.0)).mag() < 0.1);
+///
+/// // a helix keeps its frames perpendicular to the path without flipping
+/// let helix: Vec<Vector3> = (0..64)
+///     .map(|i| {
+///         let angle = i as f32 * 0.2;
+///         Vector3::make(angle.cos(), i as f32 * 0.05, angle.sin())
+///     })
+///     .collect();
+/// let frames = sweep_frames(&helix, &Vector3::up());
+/// for pair in frames.windows(2) {
+///     let a = pair[0].transform_point(&Vector3::up()) - pair[0].transform_point(&Vector3::new());
+///     let b = pair[1].transform_point(&Vector3::up()) - pair[1].transform_point(&Vector3::new());
+///     assert!(Vector3::dot(&a, &b) > 0.9);
+/// }
+/// assert!(sweep_frames(&path[..1], &Vector3::up()).is_empty());
+/// ```
+pub fn sweep_frames(path: &[Vector3], up_hint: &Vector3) -> Vec<Matrix4> {
+    if path.len() < 2 {
+        return Vec::new();
+    }
+
+    let tangents = path_tangents(path);
+    let mut up = *up_hint - tangents[0] * Vector3::dot(up_hint, &tangents[0]);
+    if up.norm() == 0.0 {
+        up = Vector3::perpendicular(&tangents[0]);
+    }
+
+    let mut frames = Vec::with_capacity(path.len());
+    frames.push(frame(&path[0], &tangents[0], &up));
+    for i in 1..path.len() {
+        up = transport(&up, &path[i - 1], &path[i], &tangents[i - 1], &tangents[i]);
+        frames.push(frame(&path[i], &tangents[i], &up));
+    }
+
+    frames
+}
+
+/// Unit tangents at each point, from the neighboring points, reusing the nearest earlier tangent
+/// where points repeat
+fn path_tangents(path: &[Vector3]) -> Vec<Vector3> {
+    let last = path.len() - 1;
+    let mut previous = None;
+    let tangents: Vec<Option<Vector3>> = (0..path.len())
+        .map(|i| {
+            let tangent = (path[(i + 1).min(last)] - path[i.saturating_sub(1)]).normalized();
+            if tangent != Vector3::new() {
+                previous = Some(tangent);
+            }
+
+            previous
+        })
+        .collect();
+
+    // points repeated at the start take the first tangent found
+    let first = tangents.iter().flatten().next().copied();
+    let first = first.unwrap_or_else(|| Vector3::make(0.0, 0.0, 1.0));
+    tangents.into_iter().map(|t| t.unwrap_or(first)).collect()
+}
+
+/// Carries an up vector from one point to the next with the double reflection method
+#[inline]
+fn transport(up: &Vector3, p0: &Vector3, p1: &Vector3, t0: &Vector3, t1: &Vector3) -> Vector3 {
+    // reflect across the plane between the points, then across the plane that lines up the
+    // reflected tangent with the next one
+    let (up, tangent) = match reflect(p1, p0, &[*up, *t0]) {
+        Some([up, tangent]) => (up, tangent),
+        None => (*up, *t0),
+    };
+
+    let up = match reflect(t1, &tangent, &[up]) {
+        Some([up]) => up,
+        None => up,
+    };
+
+    // keep rounding error from building up along long paths
+    let up = (up - *t1 * Vector3::dot(&up, t1)).normalized();
+    if up == Vector3::new() {
+        Vector3::perpendicular(t1)
+    } else {
+        up
+    }
+}
+
+/// Reflects vectors across the plane through the origin perpendicular to `a - b`, or `None` if
+/// `a` and `b` are the same
+#[inline]
+fn reflect<const N: usize>(
+    a: &Vector3,
+    b: &Vector3,
+    vectors: &[Vector3; N],
+) -> Option<[Vector3; N]> {
+    let normal = *a - *b;
+    let length_sq = normal.mag_sq();
+    if length_sq == 0.0 {
+        return None;
+    }
+
+    let mut result = *vectors;
+    for v in result.iter_mut() {
+        *v -= normal * (2.0 * Vector3::dot(&normal, v) / length_sq);
+    }
+
+    Some(result)
+}
+
+#[inline]
+fn frame(position: &Vector3, tangent: &Vector3, up: &Vector3) -> Matrix4 {
+    let side = Vector3::cross(up, tangent);
+    Matrix4::make(
+        side.x, side.y, side.z, 0.0,
+        up.x, up.y, up.z, 0.0,
+        tangent.x, tangent.y, tangent.z, 0.0,
+        position.x, position.y, position.z, 1.0,
+    )
+}
+
+/// Twice the signed area of the triangle on the XZ plane, positive when `c` is to the right of
+/// the line from `a` to `b`
+#[inline]
+fn signed_area_xz(a: &Vector3, b: &Vector3, c: &Vector3) -> f32 {
+    (b.x - a.x) * (c.z - a.z) - (c.x - a.x) * (b.z - a.z)
+}
+
+#[inline]
+fn same_xz(a: &Vector3, b: &Vector3) -> bool {
+    let dx = a.x - b.x;
+    let dz = a.z - b.z;
+    dx * dx + dz * dz < 1e-12
+}
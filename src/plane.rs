@@ -0,0 +1,175 @@
+use crate::common::Matrix;
+use crate::matrix4::Matrix4;
+use crate::ray::Ray;
+use crate::vector3::Vector3;
+use crate::vector4::Vector4;
+
+/// A plane holding every point `p` where `dot(normal, p) + d == 0`
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Plane {
+    pub normal: Vector3,
+    pub d: f32,
+}
+
+impl Plane {
+    /// Creates a plane from the provided normal and offset
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Plane;
+    /// use vex::Vector3;
+    ///
+    /// let actual = Plane::make(Vector3::up(), -2.0);
+    /// assert_eq!(actual.normal, Vector3::up());
+    /// assert_eq!(actual.d, -2.0);
+    /// ```
+    #[inline]
+    pub const fn make(normal: Vector3, d: f32) -> Plane {
+        Plane { normal, d }
+    }
+
+    /// Creates a plane through a point, facing along the provided normal
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Plane;
+    /// use vex::Vector3;
+    ///
+    /// let actual = Plane::from_point_normal(&Vector3::make(0.0, 2.0, 0.0), &Vector3::up());
+    /// assert_eq!(actual, Plane::make(Vector3::up(), -2.0));
+    /// ```
+    #[inline]
+    pub fn from_point_normal(point: &Vector3, normal: &Vector3) -> Plane {
+        Plane::make(*normal, -Vector3::dot(normal, point))
+    }
+
+    /// Creates a plane through three points, facing the side they wind counter-clockwise from
+    ///
+    /// Returns `None` if the points are collinear.
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Plane;
+    /// use vex::Vector3;
+    ///
+    /// let a = Vector3::make(0.0, 2.0, 0.0);
+    /// let b = Vector3::make(0.0, 2.0, 1.0);
+    /// let c = Vector3::make(1.0, 2.0, 0.0);
+    /// let actual = Plane::from_points(&a, &b, &c).unwrap();
+    /// assert_eq!(actual, Plane::make(Vector3::up(), -2.0));
+    ///
+    /// let d = Vector3::make(0.0, 2.0, 2.0);
+    /// assert_eq!(Plane::from_points(&a, &b, &d), None);
+    /// ```
+    #[inline]
+    pub fn from_points(a: &Vector3, b: &Vector3, c: &Vector3) -> Option<Plane> {
+        let normal = Vector3::cross(&(*b - *a), &(*c - *a)).normalized();
+        if normal == Vector3::new() {
+            return None;
+        }
+
+        Some(Plane::from_point_normal(a, &normal))
+    }
+
+    /// Find the plane scaled so its normal has unit length
+    ///
+    /// A plane with a zero normal is returned unchanged.
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Plane;
+    /// use vex::Vector3;
+    ///
+    /// let actual = Plane::make(Vector3::make(0.0, 0.0, 4.0), 8.0).normalized();
+    /// assert_eq!(actual, Plane::make(Vector3::make(0.0, 0.0, 1.0), 2.0));
+    /// ```
+    #[inline]
+    pub fn normalized(&self) -> Plane {
+        let mag = self.normal.mag();
+        if mag <= f32::EPSILON {
+            return *self;
+        }
+
+        Plane::make(self.normal * (1.0 / mag), self.d / mag)
+    }
+
+    /// Gets the distance from the plane to a point, which is negative behind the plane
+    ///
+    /// The distance is only in world units when the normal has unit length.
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Plane;
+    /// use vex::Vector3;
+    ///
+    /// let plane = Plane::from_point_normal(&Vector3::make(0.0, 2.0, 0.0), &Vector3::up());
+    /// assert_eq!(plane.signed_distance(&Vector3::make(5.0, 3.0, 1.0)), 1.0);
+    /// assert_eq!(plane.signed_distance(&Vector3::new()), -2.0);
+    /// ```
+    #[inline]
+    pub fn signed_distance(&self, point: &Vector3) -> f32 {
+        Vector3::dot(&self.normal, point) + self.d
+    }
+
+    /// Gets how far along a ray it crosses the plane, in multiples of the ray's direction
+    ///
+    /// Returns `None` if the ray runs parallel to the plane or points away from it.
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Plane;
+    /// use vex::Ray;
+    /// use vex::Vector3;
+    ///
+    /// let plane = Plane::from_point_normal(&Vector3::new(), &Vector3::up());
+    /// let ray = Ray::make(Vector3::make(1.0, 4.0, 0.0), Vector3::make(0.0, -2.0, 0.0));
+    /// assert_eq!(plane.intersect_ray(&ray), Some(2.0));
+    ///
+    /// let away = Ray::make(Vector3::make(1.0, 4.0, 0.0), Vector3::up());
+    /// assert_eq!(plane.intersect_ray(&away), None);
+    /// ```
+    #[inline]
+    pub fn intersect_ray(&self, ray: &Ray) -> Option<f32> {
+        let denom = Vector3::dot(&self.normal, &ray.direction);
+        if denom.abs() <= f32::EPSILON {
+            return None;
+        }
+
+        let t = -self.signed_distance(&ray.origin) / denom;
+        if t < 0.0 {
+            return None;
+        }
+
+        Some(t)
+    }
+
+    /// Find the plane moved by a transform, with its normal kept at unit length
+    ///
+    /// Planes transform by the inverse transpose of the matrix, so `None` is returned if the matrix
+    /// has no inverse.
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Matrix4;
+    /// use vex::Plane;
+    /// use vex::Vector3;
+    ///
+    /// let plane = Plane::from_point_normal(&Vector3::new(), &Vector3::up());
+    /// let m = Matrix4::translate(0.0, 3.0, 0.0) * Matrix4::rotate_z(std::f32::consts::FRAC_PI_2);
+    /// let actual = plane.transform(&m).unwrap();
+    /// assert!((actual.normal - Vector3::make(-1.0, 0.0, 0.0)).mag() < 0.0001);
+    /// assert!(actual.d.abs() < 0.0001);
+    ///
+    /// let actual = plane.transform(&Matrix4::scale(1.0, 4.0, 1.0)).unwrap();
+    /// assert_eq!(actual.normal, Vector3::up());
+    ///
+    /// assert_eq!(plane.transform(&Matrix4::scale(1.0, 0.0, 1.0)), None);
+    /// ```
+    #[inline]
+    pub fn transform(&self, m: &Matrix4) -> Option<Plane> {
+        let inverse_transpose = m.try_inverse()?.transposed();
+        let n = self.normal;
+        let v = inverse_transpose.transform_point(&Vector4::make(n.x, n.y, n.z, self.d));
+        Some(Plane::make(Vector3::make(v.x, v.y, v.z), v.w).normalized())
+    }
+}
@@ -0,0 +1,426 @@
+use crate::aabb::Aabb;
+use crate::common::Matrix;
+#[cfg(not(feature = "std"))]
+use crate::math::Float;
+use crate::matrix3::Matrix3;
+use crate::matrix4::Matrix4;
+use crate::quaternion::Quaternion;
+use crate::rect::Rect;
+use crate::vector2::Vector2;
+use crate::vector3::Vector3;
+
+use alloc::vec::Vec;
+use core::iter::FromIterator;
+use core::ops::{Deref, DerefMut};
+use core::slice;
+use alloc::vec;
+
+/// An owned set of 2D points with bulk operations
+///
+/// Derefs to `[Vector2]`, so slice methods and indexing work directly on the set.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Points2(pub Vec<Vector2>);
+
+impl Points2 {
+    /// Creates an empty point set
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Points2;
+    ///
+    /// let actual = Points2::new();
+    /// assert!(actual.is_empty());
+    /// ```
+    #[inline]
+    pub fn new() -> Points2 {
+        Points2(Vec::new())
+    }
+
+    /// Moves every point by an offset
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Points2;
+    /// use vex::Vector2;
+    ///
+    /// let mut actual = Points2(vec![Vector2::make(1.0, 2.0), Vector2::make(3.0, 4.0)]);
+    /// actual.translate(&Vector2::make(1.0, -1.0));
+    /// assert_eq!(actual.0, vec![Vector2::make(2.0, 1.0), Vector2::make(4.0, 3.0)]);
+    /// ```
+    #[inline]
+    pub fn translate(&mut self, offset: &Vector2) {
+        for p in self.0.iter_mut() {
+            *p += *offset;
+        }
+    }
+
+    /// Rotates every point counter-clockwise about the origin (in radians)
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Points2;
+    /// use vex::Vector2;
+    ///
+    /// let mut actual = Points2(vec![Vector2::make(1.0, 0.0)]);
+    /// actual.rotate(std::f32::consts::PI / 2.0);
+    /// assert!((actual[0] - Vector2::make(0.0, 1.0)).mag() < 0.0001);
+    /// ```
+    #[inline]
+    pub fn rotate(&mut self, angle: f32) {
+        let (sine, cosine) = angle.sin_cos();
+        for p in self.0.iter_mut() {
+            *p = Vector2::make(cosine * p.x - sine * p.y, sine * p.x + cosine * p.y);
+        }
+    }
+
+    /// Scales every point component-wise about the origin
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Points2;
+    /// use vex::Vector2;
+    ///
+    /// let mut actual = Points2(vec![Vector2::make(1.0, 2.0)]);
+    /// actual.scale(&Vector2::make(2.0, 3.0));
+    /// assert_eq!(actual.0, vec![Vector2::make(2.0, 6.0)]);
+    /// ```
+    #[inline]
+    pub fn scale(&mut self, factor: &Vector2) {
+        for p in self.0.iter_mut() {
+            *p *= *factor;
+        }
+    }
+
+    /// Transforms every point by a matrix
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Matrix3;
+    /// use vex::Points2;
+    /// use vex::Vector2;
+    ///
+    /// let mut actual = Points2(vec![Vector2::make(1.0, 2.0)]);
+    /// actual.transform(&Matrix3::make(2.0, 0.0, 0.0, 0.0, 2.0, 0.0, 1.0, 1.0, 1.0));
+    /// assert_eq!(actual.0, vec![Vector2::make(3.0, 5.0)]);
+    /// ```
+    #[inline]
+    pub fn transform(&mut self, m: &Matrix3) {
+        for p in self.0.iter_mut() {
+            *p = m.transform_point(p);
+        }
+    }
+
+    /// Find the smallest rectangle containing every point, or `None` if the set is empty
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Points2;
+    /// use vex::Rect;
+    /// use vex::Vector2;
+    ///
+    /// let points = Points2(vec![Vector2::make(1.0, -2.0), Vector2::make(-1.0, 4.0)]);
+    /// let expected = Rect::make(Vector2::make(-1.0, -2.0), Vector2::make(1.0, 4.0));
+    /// assert_eq!(points.bounds(), Some(expected));
+    /// ```
+    #[inline]
+    pub fn bounds(&self) -> Option<Rect> {
+        Rect::from_points(&self.0)
+    }
+
+    /// Find the average of every point, or `None` if the set is empty
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Points2;
+    /// use vex::Vector2;
+    ///
+    /// let points = Points2(vec![Vector2::make(1.0, 2.0), Vector2::make(3.0, 6.0)]);
+    /// assert_eq!(points.centroid(), Some(Vector2::make(2.0, 4.0)));
+    /// assert_eq!(Points2::new().centroid(), None);
+    /// ```
+    #[inline]
+    pub fn centroid(&self) -> Option<Vector2> {
+        if self.0.is_empty() {
+            return None;
+        }
+
+        let sum = self.0.iter().fold(Vector2::new(), |acc, p| acc + *p);
+        Some(sum / self.0.len() as f32)
+    }
+}
+
+impl Deref for Points2 {
+    type Target = [Vector2];
+
+    #[inline]
+    fn deref(&self) -> &[Vector2] {
+        &self.0
+    }
+}
+
+impl DerefMut for Points2 {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut [Vector2] {
+        &mut self.0
+    }
+}
+
+impl From<Vec<Vector2>> for Points2 {
+    #[inline]
+    fn from(points: Vec<Vector2>) -> Points2 {
+        Points2(points)
+    }
+}
+
+impl FromIterator<Vector2> for Points2 {
+    /// Collects points into a set
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Points2;
+    /// use vex::Vector2;
+    ///
+    /// let actual: Points2 = (0..3).map(|i| Vector2::make(i as f32, 0.0)).collect();
+    /// assert_eq!(actual.len(), 3);
+    /// ```
+    #[inline]
+    fn from_iter<I: IntoIterator<Item = Vector2>>(iter: I) -> Points2 {
+        Points2(iter.into_iter().collect())
+    }
+}
+
+impl IntoIterator for Points2 {
+    type Item = Vector2;
+    type IntoIter = vec::IntoIter<Vector2>;
+
+    #[inline]
+    fn into_iter(self) -> vec::IntoIter<Vector2> {
+        self.0.into_iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a Points2 {
+    type Item = &'a Vector2;
+    type IntoIter = slice::Iter<'a, Vector2>;
+
+    #[inline]
+    fn into_iter(self) -> slice::Iter<'a, Vector2> {
+        self.0.iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a mut Points2 {
+    type Item = &'a mut Vector2;
+    type IntoIter = slice::IterMut<'a, Vector2>;
+
+    #[inline]
+    fn into_iter(self) -> slice::IterMut<'a, Vector2> {
+        self.0.iter_mut()
+    }
+}
+
+/// An owned set of 3D points with bulk operations
+///
+/// Derefs to `[Vector3]`, so slice methods and indexing work directly on the set.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Points3(pub Vec<Vector3>);
+
+impl Points3 {
+    /// Creates an empty point set
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Points3;
+    ///
+    /// let actual = Points3::new();
+    /// assert!(actual.is_empty());
+    /// ```
+    #[inline]
+    pub fn new() -> Points3 {
+        Points3(Vec::new())
+    }
+
+    /// Moves every point by an offset
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Points3;
+    /// use vex::Vector3;
+    ///
+    /// let mut actual = Points3(vec![Vector3::make(1.0, 2.0, 3.0)]);
+    /// actual.translate(&Vector3::make(1.0, -1.0, 2.0));
+    /// assert_eq!(actual.0, vec![Vector3::make(2.0, 1.0, 5.0)]);
+    /// ```
+    #[inline]
+    pub fn translate(&mut self, offset: &Vector3) {
+        for p in self.0.iter_mut() {
+            *p += *offset;
+        }
+    }
+
+    /// Rotates every point about the origin
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Points3;
+    /// use vex::Quaternion;
+    /// use vex::Vector3;
+    ///
+    /// let mut actual = Points3(vec![Vector3::right()]);
+    /// let q = Quaternion::from_axis_angle(&Vector3::make(0.0, 0.0, 1.0), std::f32::consts::PI / 2.0);
+    /// actual.rotate(&q);
+    /// assert!((actual[0] - Vector3::up()).mag() < 0.0001);
+    /// ```
+    #[inline]
+    pub fn rotate(&mut self, rotation: &Quaternion) {
+        let m = Matrix3::from(*rotation);
+        for p in self.0.iter_mut() {
+            *p = m.transform_point(p);
+        }
+    }
+
+    /// Scales every point component-wise about the origin
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Points3;
+    /// use vex::Vector3;
+    ///
+    /// let mut actual = Points3(vec![Vector3::make(1.0, 2.0, 3.0)]);
+    /// actual.scale(&Vector3::make(2.0, 3.0, 4.0));
+    /// assert_eq!(actual.0, vec![Vector3::make(2.0, 6.0, 12.0)]);
+    /// ```
+    #[inline]
+    pub fn scale(&mut self, factor: &Vector3) {
+        for p in self.0.iter_mut() {
+            *p *= *factor;
+        }
+    }
+
+    /// Transforms every point by a matrix
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Matrix4;
+    /// use vex::Points3;
+    /// use vex::Vector3;
+    ///
+    /// let mut actual = Points3(vec![Vector3::make(1.0, 2.0, 3.0)]);
+    /// actual.transform(&Matrix4::translate(1.0, 1.0, 1.0));
+    /// assert_eq!(actual.0, vec![Vector3::make(2.0, 3.0, 4.0)]);
+    /// ```
+    #[inline]
+    pub fn transform(&mut self, m: &Matrix4) {
+        for p in self.0.iter_mut() {
+            *p = m.transform_point(p);
+        }
+    }
+
+    /// Find the smallest box containing every point, or `None` if the set is empty
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Aabb;
+    /// use vex::Points3;
+    /// use vex::Vector3;
+    ///
+    /// let points = Points3(vec![Vector3::make(1.0, -2.0, 0.0), Vector3::make(-1.0, 4.0, 2.0)]);
+    /// let expected = Aabb::make(Vector3::make(-1.0, -2.0, 0.0), Vector3::make(1.0, 4.0, 2.0));
+    /// assert_eq!(points.bounds(), Some(expected));
+    /// ```
+    #[inline]
+    pub fn bounds(&self) -> Option<Aabb> {
+        Aabb::from_points(&self.0)
+    }
+
+    /// Find the average of every point, or `None` if the set is empty
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Points3;
+    /// use vex::Vector3;
+    ///
+    /// let points = Points3(vec![Vector3::make(1.0, 2.0, 3.0), Vector3::make(3.0, 6.0, 9.0)]);
+    /// assert_eq!(points.centroid(), Some(Vector3::make(2.0, 4.0, 6.0)));
+    /// assert_eq!(Points3::new().centroid(), None);
+    /// ```
+    #[inline]
+    pub fn centroid(&self) -> Option<Vector3> {
+        if self.0.is_empty() {
+            return None;
+        }
+
+        let sum = self.0.iter().fold(Vector3::new(), |acc, p| acc + *p);
+        Some(sum / self.0.len() as f32)
+    }
+}
+
+impl Deref for Points3 {
+    type Target = [Vector3];
+
+    #[inline]
+    fn deref(&self) -> &[Vector3] {
+        &self.0
+    }
+}
+
+impl DerefMut for Points3 {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut [Vector3] {
+        &mut self.0
+    }
+}
+
+impl From<Vec<Vector3>> for Points3 {
+    #[inline]
+    fn from(points: Vec<Vector3>) -> Points3 {
+        Points3(points)
+    }
+}
+
+impl FromIterator<Vector3> for Points3 {
+    /// Collects points into a set
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Points3;
+    /// use vex::Vector3;
+    ///
+    /// let actual: Points3 = (0..3).map(|i| Vector3::make(i as f32, 0.0, 0.0)).collect();
+    /// assert_eq!(actual.len(), 3);
+    /// ```
+    #[inline]
+    fn from_iter<I: IntoIterator<Item = Vector3>>(iter: I) -> Points3 {
+        Points3(iter.into_iter().collect())
+    }
+}
+
+impl IntoIterator for Points3 {
+    type Item = Vector3;
+    type IntoIter = vec::IntoIter<Vector3>;
+
+    #[inline]
+    fn into_iter(self) -> vec::IntoIter<Vector3> {
+        self.0.into_iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a Points3 {
+    type Item = &'a Vector3;
+    type IntoIter = slice::Iter<'a, Vector3>;
+
+    #[inline]
+    fn into_iter(self) -> slice::Iter<'a, Vector3> {
+        self.0.iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a mut Points3 {
+    type Item = &'a mut Vector3;
+    type IntoIter = slice::IterMut<'a, Vector3>;
+
+    #[inline]
+    fn into_iter(self) -> slice::IterMut<'a, Vector3> {
+        self.0.iter_mut()
+    }
+}
@@ -0,0 +1,198 @@
+use crate::vector2::Vector2;
+
+use alloc::vec::Vec;
+
+// the relative rounding error of an f64 operation, 2^-53
+const EPSILON: f64 = 1.0 / 9007199254740992.0;
+const ORIENT2D_BOUND: f64 = (3.0 + 16.0 * EPSILON) * EPSILON;
+const INCIRCLE_BOUND: f64 = (10.0 + 96.0 * EPSILON) * EPSILON;
+
+// 2^27 + 1, for splitting an f64 into two halves whose products are exact
+const SPLITTER: f64 = 134217729.0;
+
+/// Finds which side of the line through `a` and `b` the point `c` is on
+///
+/// The result is positive if `a`, `b`, `c` wind counter-clockwise, negative if they wind clockwise,
+/// and exactly zero if they are collinear. Its magnitude is roughly twice the area of the triangle
+/// they form. The sign is always correct: the determinant is evaluated in `f64` with a bound on its
+/// rounding error, and only when the result lies within that bound is it recomputed exactly.
+///
+/// # Examples
+/// ```
+/// use vex::Vector2;
+/// use vex::orient2d;
+///
+/// let a = Vector2::make(0.0, 0.0);
+/// let b = Vector2::make(1.0, 0.0);
+/// assert!(orient2d(&a, &b, &Vector2::make(0.0, 1.0)) > 0.0);
+/// assert!(orient2d(&a, &b, &Vector2::make(0.0, -1.0)) < 0.0);
+/// assert_eq!(orient2d(&a, &Vector2::make(1.0, 3.0), &Vector2::make(2.0, 6.0)), 0.0);
+///
+/// // nearly collinear points that single precision can't tell apart
+/// let a = Vector2::make(0.5, 0.5);
+/// let b = Vector2::make(12.000001, 12.000002);
+/// let c = Vector2::make(24.0, 24.0);
+/// assert_eq!(Vector2::cross(&(b - a), &(c - a)), 0.0);
+/// assert!(orient2d(&a, &b, &c) < 0.0);
+/// ```
+pub fn orient2d(a: &Vector2, b: &Vector2, c: &Vector2) -> f64 {
+    let (ax, ay) = (a.x as f64, a.y as f64);
+    let (bx, by) = (b.x as f64, b.y as f64);
+    let (cx, cy) = (c.x as f64, c.y as f64);
+
+    let left = (ax - cx) * (by - cy);
+    let right = (ay - cy) * (bx - cx);
+    let det = left - right;
+    if det.abs() > ORIENT2D_BOUND * (left.abs() + right.abs()) {
+        return det;
+    }
+
+    // every product of two single precision values is exact in double precision
+    let terms = [ax * by, -(ax * cy), -(ay * bx), ay * cx, bx * cy, -(by * cx)];
+    estimate(&terms.iter().fold(Vec::new(), |sum, term| grow(&sum, *term)))
+}
+
+/// Finds whether the point `d` is inside the circle through `a`, `b`, and `c`
+///
+/// With `a`, `b`, `c` wound counter-clockwise, the result is positive if `d` is inside the circle,
+/// negative if it is outside, and exactly zero if all four points lie on it. Winding them clockwise
+/// flips the sign. Like [`orient2d`](fn.orient2d.html), the sign is always correct, with exact
+/// arithmetic used only when the `f64` result is too close to zero to trust.
+///
+/// # Examples
+/// ```
+/// use vex::Vector2;
+/// use vex::incircle;
+///
+/// let a = Vector2::make(1.0, 0.0);
+/// let b = Vector2::make(0.0, 1.0);
+/// let c = Vector2::make(-1.0, 0.0);
+/// assert!(incircle(&a, &b, &c, &Vector2::make(0.0, 0.0)) > 0.0);
+/// assert!(incircle(&a, &b, &c, &Vector2::make(2.0, 0.0)) < 0.0);
+/// assert_eq!(incircle(&a, &b, &c, &Vector2::make(0.0, -1.0)), 0.0);
+/// assert!(incircle(&a, &b, &c, &Vector2::make(0.0, -0.99999994)) > 0.0);
+/// assert!(incircle(&c, &b, &a, &Vector2::make(0.0, 0.0)) < 0.0);
+/// ```
+pub fn incircle(a: &Vector2, b: &Vector2, c: &Vector2, d: &Vector2) -> f64 {
+    let (dx, dy) = (d.x as f64, d.y as f64);
+    let (adx, ady) = (a.x as f64 - dx, a.y as f64 - dy);
+    let (bdx, bdy) = (b.x as f64 - dx, b.y as f64 - dy);
+    let (cdx, cdy) = (c.x as f64 - dx, c.y as f64 - dy);
+
+    let alift = adx * adx + ady * ady;
+    let blift = bdx * bdx + bdy * bdy;
+    let clift = cdx * cdx + cdy * cdy;
+
+    let (bc, cb) = (bdx * cdy, cdx * bdy);
+    let (ca, ac) = (cdx * ady, adx * cdy);
+    let (ab, ba) = (adx * bdy, bdx * ady);
+    let det = alift * (bc - cb) + blift * (ca - ac) + clift * (ab - ba);
+    let permanent = (bc.abs() + cb.abs()) * alift
+        + (ca.abs() + ac.abs()) * blift
+        + (ab.abs() + ba.abs()) * clift;
+    if det.abs() > INCIRCLE_BOUND * permanent {
+        return det;
+    }
+
+    // the differences aren't always exact, so they're kept as two-term expansions
+    let adx = difference(a.x as f64, dx);
+    let ady = difference(a.y as f64, dy);
+    let bdx = difference(b.x as f64, dx);
+    let bdy = difference(b.y as f64, dy);
+    let cdx = difference(c.x as f64, dx);
+    let cdy = difference(c.y as f64, dy);
+
+    let lift = |x: &[f64], y: &[f64]| sum(&product(x, x), &product(y, y));
+    let cross = |x1: &[f64], y1: &[f64], x2: &[f64], y2: &[f64]| {
+        sum(&product(x1, y2), &negated(&product(x2, y1)))
+    };
+
+    let a_term = product(&lift(&adx, &ady), &cross(&bdx, &bdy, &cdx, &cdy));
+    let b_term = product(&lift(&bdx, &bdy), &cross(&cdx, &cdy, &adx, &ady));
+    let c_term = product(&lift(&cdx, &cdy), &cross(&adx, &ady, &bdx, &bdy));
+    estimate(&sum(&sum(&a_term, &b_term), &c_term))
+}
+
+// Exact arithmetic on expansions, following Shewchuk's "Adaptive Precision Floating-Point
+// Arithmetic and Fast Robust Geometric Predicates". An expansion is a sum of non-overlapping f64
+// components in increasing order of magnitude, which together represent a value exactly.
+
+/// The rounded sum of two values and its rounding error
+#[inline]
+fn two_sum(a: f64, b: f64) -> (f64, f64) {
+    let x = a + b;
+    let b_virtual = x - a;
+    let a_virtual = x - b_virtual;
+    (x, (a - a_virtual) + (b - b_virtual))
+}
+
+/// Splits a value into high and low halves of 26 bits each
+#[inline]
+fn split(a: f64) -> (f64, f64) {
+    let c = SPLITTER * a;
+    let high = c - (c - a);
+    (high, a - high)
+}
+
+/// The rounded product of two values and its rounding error
+#[inline]
+fn two_product(a: f64, b: f64) -> (f64, f64) {
+    let x = a * b;
+    let (a_high, a_low) = split(a);
+    let (b_high, b_low) = split(b);
+    let error = x - a_high * b_high - a_low * b_high - a_high * b_low;
+    (x, a_low * b_low - error)
+}
+
+/// The exact difference of two values
+#[inline]
+fn difference(a: f64, b: f64) -> [f64; 2] {
+    let (x, y) = two_sum(a, -b);
+    [y, x]
+}
+
+/// Adds a value to an expansion
+fn grow(e: &[f64], b: f64) -> Vec<f64> {
+    let mut result = Vec::with_capacity(e.len() + 1);
+    let mut q = b;
+    for component in e.iter() {
+        let (sum, error) = two_sum(q, *component);
+        result.push(error);
+        q = sum;
+    }
+
+    result.push(q);
+    result
+}
+
+/// Adds two expansions
+fn sum(e: &[f64], f: &[f64]) -> Vec<f64> {
+    f.iter().fold(e.to_vec(), |result, component| grow(&result, *component))
+}
+
+/// Multiplies two expansions
+fn product(e: &[f64], f: &[f64]) -> Vec<f64> {
+    let mut result = Vec::new();
+    for a in e.iter() {
+        for b in f.iter() {
+            let (x, y) = two_product(*a, *b);
+            result = grow(&grow(&result, y), x);
+        }
+    }
+
+    result
+}
+
+#[inline]
+fn negated(e: &[f64]) -> Vec<f64> {
+    e.iter().map(|component| -component).collect()
+}
+
+/// The largest non-zero component of an expansion, which has the sign of the whole expansion
+///
+/// Summing the components instead could round to zero when the smaller ones nearly cancel the
+/// largest.
+#[inline]
+fn estimate(e: &[f64]) -> f64 {
+    e.iter().rev().find(|component| **component != 0.0).map_or(0.0, |component| *component)
+}
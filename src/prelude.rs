@@ -0,0 +1,23 @@
+//! The types and traits most code needs, for a single glob import
+//!
+//! Importing the traits brings `transform_point` and the generic vector and matrix operations into
+//! scope, and the types carry their constants, like `Vector3::ZERO` and `Matrix4::IDENTITY`.
+//!
+//! # Examples
+//! ```
+//! use vex::prelude::*;
+//!
+//! let m = Matrix4::translate(1.0, 2.0, 3.0);
+//! let actual = m.transform_point(&Vector3::ZERO);
+//! assert_eq!(actual, Vector3::make(1.0, 2.0, 3.0));
+//! ```
+
+pub use crate::common::{Matrix, SquareMatrix, Vector};
+pub use crate::euler::EulerOrder;
+pub use crate::matrix2::Matrix2;
+pub use crate::matrix3::Matrix3;
+pub use crate::matrix4::Matrix4;
+pub use crate::quaternion::Quaternion;
+pub use crate::vector2::Vector2;
+pub use crate::vector3::Vector3;
+pub use crate::vector4::Vector4;
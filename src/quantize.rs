@@ -0,0 +1,131 @@
+#[cfg(not(feature = "std"))]
+use crate::math::Float;
+
+/// How finely to quantize the elements of a value
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum Precision {
+    /// Store each element with this many bits, from 1 to 32
+    Bits(u32),
+    /// Use the fewest bits that keep every element within this distance of its original value
+    Tolerance(f32),
+}
+
+/// The elements of a vector or matrix stored as fixed-point integers over a shared range
+///
+/// Element `i` decodes to `min + values[i] * step`. Every element shares the range, so values
+/// whose elements differ greatly in magnitude, like a transform with a large translation, lose
+/// precision in their smaller elements.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Quantized<const N: usize> {
+    pub values: [u32; N],
+    pub min: f32,
+    pub step: f32,
+    pub bits: u32,
+}
+
+/// Quantizes the elements of a vector, quaternion, or matrix, returning the largest error of any
+/// element
+///
+/// Elements are snapped to `2^bits` evenly spaced levels between the smallest and largest element.
+/// A tolerance that can't be met even with 32 bits uses 32 bits, and the reported error reflects
+/// what was actually achieved.
+///
+/// # Examples
+/// ```
+/// use vex::Matrix4;
+/// use vex::Precision;
+/// use vex::Vector3;
+/// use vex::dequantize;
+/// use vex::quantize;
+///
+/// let m = Matrix4::from_axis_angle(Vector3::make(1.0, 2.0, 3.0), 0.7);
+/// let (q, error) = quantize(m, Precision::Bits(12));
+/// assert_eq!(q.bits, 12);
+/// assert!(error <= q.step / 2.0 + 0.000001);
+///
+/// let actual: Matrix4 = dequantize(&q);
+/// assert!(actual.approx_eq(&m, error + 0.000001));
+///
+/// let (q, error) = quantize(m, Precision::Tolerance(0.001));
+/// assert!(error <= 0.001);
+/// assert_eq!(q.bits, 10);
+/// ```
+pub fn quantize<T: Into<[f32; N]>, const N: usize>(
+    value: T,
+    precision: Precision,
+) -> (Quantized<N>, f32) {
+    let elements: [f32; N] = value.into();
+    let min = elements.iter().fold(f32::INFINITY, |a, &b| a.min(b));
+    let max = elements.iter().fold(f32::NEG_INFINITY, |a, &b| a.max(b));
+    let range = if N == 0 { 0.0 } else { (max - min) as f64 };
+
+    let bits = match precision {
+        Precision::Bits(bits) => bits.clamp(1, 32),
+        // half a step is the most any element can be off by
+        Precision::Tolerance(tolerance) => (1..=32)
+            .find(|&bits| range / levels(bits) <= 2.0 * tolerance as f64)
+            .unwrap_or(32),
+    };
+
+    let step = range / levels(bits);
+    let mut quantized = Quantized {
+        values: [0; N],
+        min: if N == 0 { 0.0 } else { min },
+        step: step as f32,
+        bits,
+    };
+
+    if step > 0.0 {
+        for (value, element) in quantized.values.iter_mut().zip(elements.iter()) {
+            let level = ((*element - min) as f64 / step).round();
+            *value = level.clamp(0.0, levels(bits)) as u32;
+        }
+    }
+
+    let decoded = decode(&quantized);
+    let error = elements
+        .iter()
+        .zip(decoded.iter())
+        .fold(0.0f32, |error, (a, b)| error.max((a - b).abs()));
+    (quantized, error)
+}
+
+/// Restores a value from its quantized elements
+///
+/// # Examples
+/// ```
+/// use vex::Precision;
+/// use vex::Vector3;
+/// use vex::dequantize;
+/// use vex::quantize;
+///
+/// let (q, error) = quantize(Vector3::make(-1.0, 0.0, 1.0), Precision::Bits(1));
+/// assert_eq!(q.values, [0, 1, 1]);
+/// assert_eq!(error, 1.0);
+/// assert_eq!(dequantize::<Vector3, 3>(&q), Vector3::make(-1.0, 1.0, 1.0));
+///
+/// let (q, error) = quantize(Vector3::make(-1.0, 0.0, 2.0), Precision::Bits(2));
+/// assert_eq!(q.values, [0, 1, 3]);
+/// assert_eq!(error, 0.0);
+/// assert_eq!(dequantize::<Vector3, 3>(&q), Vector3::make(-1.0, 0.0, 2.0));
+/// ```
+#[inline]
+pub fn dequantize<T: From<[f32; N]>, const N: usize>(quantized: &Quantized<N>) -> T {
+    T::from(decode(quantized))
+}
+
+/// The largest integer that can be stored with `bits` bits
+#[inline]
+fn levels(bits: u32) -> f64 {
+    ((1u64 << bits) - 1) as f64
+}
+
+#[inline]
+fn decode<const N: usize>(quantized: &Quantized<N>) -> [f32; N] {
+    let mut result = [0.0; N];
+    for (element, value) in result.iter_mut().zip(quantized.values.iter()) {
+        *element = quantized.min + *value as f32 * quantized.step;
+    }
+
+    result
+}
@@ -0,0 +1,1002 @@
+use crate::common;
+use crate::epsilon::Epsilon;
+use crate::format;
+#[cfg(not(feature = "std"))]
+use crate::math::Float;
+use crate::matrix3::Matrix3;
+use crate::matrix4::Matrix4;
+use crate::unit_vector3::UnitVector3;
+use crate::vector3::Vector3;
+
+use core::cmp;
+use core::convert::From;
+use core::fmt;
+use core::slice;
+use core::fmt::{Display, Formatter};
+
+use core::ops::{
+    Neg,
+    Add,
+    AddAssign,
+    Sub,
+    SubAssign,
+    Mul,
+    MulAssign,
+    Div,
+    DivAssign,
+};
+
+#[repr(C)]
+#[derive(Copy, Clone, Debug)]
+#[cfg_attr(feature = "rkyv", derive(rkyv::Archive, rkyv::Deserialize, rkyv::Serialize))]
+#[cfg_attr(feature = "rkyv", rkyv(derive(Debug), compare(PartialEq)))]
+pub struct Quaternion {
+    pub x: f32,
+    pub y: f32,
+    pub z: f32,
+    pub w: f32,
+}
+
+impl Quaternion {
+    /// The identity quaternion
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Quaternion;
+    ///
+    /// assert_eq!(Quaternion::IDENTITY, Quaternion::new());
+    /// ```
+    pub const IDENTITY: Quaternion = Quaternion {
+        x: 0.0,
+        y: 0.0,
+        z: 0.0,
+        w: 1.0,
+    };
+
+    /// Creates an identity quaternion
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Quaternion;
+    ///
+    /// let actual = Quaternion::new();
+    /// let expected = Quaternion { x: 0.0, y: 0.0, z: 0.0, w: 1.0 };
+    /// assert_eq!(actual, expected);
+    /// ```
+    #[inline]
+    pub const fn new() -> Quaternion {
+        Quaternion {
+            x: 0.0,
+            y: 0.0,
+            z: 0.0,
+            w: 1.0,
+        }
+    }
+
+    /// Creates a quaternion from the provided values
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Quaternion;
+    ///
+    /// let actual = Quaternion::make(1.0, 2.0, 3.0, 4.0);
+    /// let expected = Quaternion { x: 1.0, y: 2.0, z: 3.0, w: 4.0 };
+    /// assert_eq!(actual, expected);
+    /// ```
+    #[inline]
+    pub const fn make(x: f32, y: f32, z: f32, w: f32) -> Quaternion {
+        Quaternion { x, y, z, w }
+    }
+
+    /// Creates a rotation of `angle` radians about an axis
+    ///
+    /// The axis doesn't need to be normalized. A zero-length axis produces the identity.
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Quaternion;
+    /// use vex::Vector3;
+    ///
+    /// let q = Quaternion::from_axis_angle(&Vector3::make(0.0, 0.0, 2.0), std::f32::consts::PI / 2.0);
+    /// let actual = q.rotate(&Vector3::right());
+    /// assert!((actual - Vector3::up()).mag() < 0.0001);
+    /// ```
+    #[inline]
+    pub fn from_axis_angle(axis: &Vector3, angle: f32) -> Quaternion {
+        let mut axis = *axis;
+        if axis.norm() == 0.0 {
+            return Quaternion::new();
+        }
+
+        let half = angle * 0.5;
+        let sine = half.sin();
+        Quaternion::make(axis.x * sine, axis.y * sine, axis.z * sine, half.cos())
+    }
+
+    /// Creates a rotation of `angle` radians about a unit axis
+    ///
+    /// The axis is already normalized, so this skips the normalization and zero-length check of
+    /// [`from_axis_angle`](#method.from_axis_angle).
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Quaternion;
+    /// use vex::UnitVector3;
+    /// use vex::Vector3;
+    ///
+    /// let q = Quaternion::from_unit_axis_angle(&UnitVector3::Z, std::f32::consts::PI / 2.0);
+    /// let actual = q.rotate(&Vector3::right());
+    /// assert!((actual - Vector3::up()).mag() < 0.0001);
+    /// ```
+    #[inline]
+    pub fn from_unit_axis_angle(axis: &UnitVector3, angle: f32) -> Quaternion {
+        let half = angle * 0.5;
+        let sine = half.sin();
+        Quaternion::make(axis.x * sine, axis.y * sine, axis.z * sine, half.cos())
+    }
+
+    /// Creates a rotation from Euler angles (in radians)
+    ///
+    /// The rotations are applied about the x-axis first, then the y-axis, then the z-axis.
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Quaternion;
+    /// use vex::Vector3;
+    ///
+    /// let half_pi = std::f32::consts::PI / 2.0;
+    /// let q = Quaternion::from_euler(half_pi, 0.0, half_pi);
+    /// let actual = q.rotate(&Vector3::up());
+    /// assert!((actual - Vector3::make(0.0, 0.0, 1.0)).mag() < 0.0001);
+    /// let actual = q.rotate(&Vector3::right());
+    /// assert!((actual - Vector3::up()).mag() < 0.0001);
+    /// ```
+    #[inline]
+    pub fn from_euler(x: f32, y: f32, z: f32) -> Quaternion {
+        let qx = Quaternion::from_axis_angle(&Vector3::right(), x);
+        let qy = Quaternion::from_axis_angle(&Vector3::up(), y);
+        let qz = Quaternion::from_axis_angle(&Vector3::make(0.0, 0.0, 1.0), z);
+        qz * qy * qx
+    }
+
+    /// Find the dot product between two quaternions
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Quaternion;
+    ///
+    /// let a = Quaternion::make(1.0, 2.0, 3.0, 4.0);
+    /// let b = Quaternion::make(5.0, 6.0, 7.0, 8.0);
+    /// let actual = Quaternion::dot(&a, &b);
+    /// assert_eq!(actual, 70.0);
+    /// ```
+    #[inline]
+    pub fn dot(a: &Quaternion, b: &Quaternion) -> f32 {
+        a.x * b.x + a.y * b.y + a.z * b.z + a.w * b.w
+    }
+
+    /// Linearly interpolate between two rotations along the shortest path, then normalize
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Quaternion;
+    /// use vex::Vector3;
+    ///
+    /// let a = Quaternion::new();
+    /// let b = Quaternion::from_axis_angle(&Vector3::up(), std::f32::consts::PI / 2.0);
+    /// let actual = Quaternion::nlerp(&a, &b, 0.5);
+    /// let expected = Quaternion::from_axis_angle(&Vector3::up(), std::f32::consts::PI / 4.0);
+    /// assert!((actual - expected).mag() < 0.0001);
+    /// ```
+    #[inline]
+    pub fn nlerp(a: &Quaternion, b: &Quaternion, t: f32) -> Quaternion {
+        let b = if Quaternion::dot(a, b) < 0.0 { -*b } else { *b };
+        let mut result = *a + (b - *a) * t;
+        result.norm();
+        result
+    }
+
+    /// Spherically interpolate between two rotations along the shortest path
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Quaternion;
+    /// use vex::Vector3;
+    ///
+    /// let a = Quaternion::new();
+    /// let b = Quaternion::from_axis_angle(&Vector3::up(), std::f32::consts::PI / 2.0);
+    /// let actual = Quaternion::slerp(&a, &b, 1.0 / 3.0);
+    /// let expected = Quaternion::from_axis_angle(&Vector3::up(), std::f32::consts::PI / 6.0);
+    /// assert!((actual - expected).mag() < 0.0001);
+    /// ```
+    #[inline]
+    pub fn slerp(a: &Quaternion, b: &Quaternion, t: f32) -> Quaternion {
+        let mut cosine = Quaternion::dot(a, b);
+        let mut b = *b;
+        if cosine < 0.0 {
+            b = -b;
+            cosine = -cosine;
+        }
+
+        // fall back to nlerp when the rotations are nearly parallel
+        if cosine > 0.9995 {
+            return Quaternion::nlerp(a, &b, t);
+        }
+
+        let theta = cosine.acos();
+        let sine = theta.sin();
+        let wa = ((1.0 - t) * theta).sin() / sine;
+        let wb = (t * theta).sin() / sine;
+        *a * wa + b * wb
+    }
+
+    /// Set the components of a quaternion
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Quaternion;
+    ///
+    /// let mut actual = Quaternion::new();
+    /// actual.set(1.0, 2.0, 3.0, 4.0);
+    /// let expected = Quaternion::make(1.0, 2.0, 3.0, 4.0);
+    /// assert_eq!(actual, expected);
+    /// ```
+    #[inline]
+    pub fn set(&mut self, x: f32, y: f32, z: f32, w: f32) {
+        self.x = x;
+        self.y = y;
+        self.z = z;
+        self.w = w;
+    }
+
+    /// Get the magnitude of the quaternion
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Quaternion;
+    ///
+    /// let actual = Quaternion::make(1.0, 2.0, 2.0, 4.0).mag();
+    /// assert_eq!(actual, 5.0);
+    /// ```
+    #[inline]
+    pub fn mag(&self) -> f32 {
+        self.mag_sq().sqrt()
+    }
+
+    /// Get the squared magnitude of the quaternion
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Quaternion;
+    ///
+    /// let actual = Quaternion::make(1.0, 2.0, 2.0, 4.0).mag_sq();
+    /// assert_eq!(actual, 25.0);
+    /// ```
+    #[inline]
+    pub fn mag_sq(&self) -> f32 {
+        Quaternion::dot(self, self)
+    }
+
+    /// Normalize the quaternion
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Quaternion;
+    ///
+    /// let mut actual = Quaternion::make(1.0, 2.0, 2.0, 4.0);
+    /// actual.norm();
+    /// let expected = Quaternion::make(0.2, 0.4, 0.4, 0.8);
+    /// assert_eq!(actual, expected);
+    /// ```
+    #[inline]
+    pub fn norm(&mut self) -> f32 {
+        let length = self.mag();
+        if length > f32::EPSILON {
+            self.x /= length;
+            self.y /= length;
+            self.z /= length;
+            self.w /= length;
+            length
+        } else {
+            0.0
+        }
+    }
+
+    /// Conjugates the quaternion
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Quaternion;
+    ///
+    /// let mut actual = Quaternion::make(1.0, 2.0, 3.0, 4.0);
+    /// actual.conjugate();
+    /// let expected = Quaternion::make(-1.0, -2.0, -3.0, 4.0);
+    /// assert_eq!(actual, expected);
+    /// ```
+    #[inline]
+    pub fn conjugate(&mut self) {
+        self.x = -self.x;
+        self.y = -self.y;
+        self.z = -self.z;
+    }
+
+    /// Find the inverse of the quaternion, or `None` if its length is zero
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Quaternion;
+    ///
+    /// let actual = Quaternion::make(0.0, 0.0, 2.0, 0.0).try_inverse();
+    /// assert_eq!(actual, Some(Quaternion::make(0.0, 0.0, -0.5, 0.0)));
+    /// assert_eq!(Quaternion::make(0.0, 0.0, 0.0, 0.0).try_inverse(), None);
+    /// ```
+    #[inline]
+    pub fn try_inverse(&self) -> Option<Quaternion> {
+        let length_sq = self.mag_sq();
+        if length_sq == 0.0 {
+            return None;
+        }
+
+        let mut result = *self;
+        result.conjugate();
+        Some(result / length_sq)
+    }
+
+    /// Rotates a vector by the quaternion
+    ///
+    /// The quaternion is assumed to be normalized.
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Quaternion;
+    /// use vex::Vector3;
+    ///
+    /// let q = Quaternion::from_axis_angle(&Vector3::up(), std::f32::consts::PI);
+    /// let actual = q.rotate(&Vector3::make(1.0, 2.0, 3.0));
+    /// assert!((actual - Vector3::make(-1.0, 2.0, -3.0)).mag() < 0.0001);
+    /// ```
+    #[inline]
+    pub fn rotate(&self, v: &Vector3) -> Vector3 {
+        let u = Vector3::make(self.x, self.y, self.z);
+        let t = Vector3::cross(&u, v) * 2.0;
+        *v + t * self.w + Vector3::cross(&u, &t)
+    }
+
+    /// Find the quaternion with its components negated
+    ///
+    /// The result represents the same rotation.
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Quaternion;
+    ///
+    /// let actual = Quaternion::make(1.0, -2.0, 3.0, -4.0).negated();
+    /// let expected = Quaternion::make(-1.0, 2.0, -3.0, 4.0);
+    /// assert_eq!(actual, expected);
+    /// ```
+    #[inline]
+    pub fn negated(&self) -> Quaternion {
+        -*self
+    }
+
+    /// Find the normalized quaternion
+    ///
+    /// Quaternions too short to normalize are returned unchanged, matching `norm`.
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Quaternion;
+    ///
+    /// let actual = Quaternion::make(0.0, 0.0, 0.0, 2.0).normalized();
+    /// assert_eq!(actual, Quaternion::new());
+    /// ```
+    #[inline]
+    pub fn normalized(&self) -> Quaternion {
+        let mut result = *self;
+        result.norm();
+        result
+    }
+
+    /// Find the conjugate of the quaternion
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Quaternion;
+    ///
+    /// let actual = Quaternion::make(1.0, 2.0, 3.0, 4.0).conjugated();
+    /// let expected = Quaternion::make(-1.0, -2.0, -3.0, 4.0);
+    /// assert_eq!(actual, expected);
+    /// ```
+    #[inline]
+    pub fn conjugated(&self) -> Quaternion {
+        let mut result = *self;
+        result.conjugate();
+        result
+    }
+
+    /// Determine whether or not all components of the quaternions are within `epsilon` of each other
+    ///
+    /// This compares components directly, so `q` and `-q` are not considered equal even though they
+    /// represent the same rotation.
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Quaternion;
+    /// 
+    /// let a = Quaternion::make(1.0, 2.0, 3.0, 4.0);
+    /// assert!(a.approx_eq(&Quaternion::make(1.0000001, 2.0, 3.0, 4.0), 0.000001));
+    /// assert!(!a.approx_eq(&Quaternion::make(1.1, 2.0, 3.0, 4.0), 0.000001));
+    /// ```
+    #[inline]
+    pub fn approx_eq(&self, other: &Quaternion, epsilon: f32) -> bool {
+        (self.x - other.x).abs() <= epsilon
+            && (self.y - other.y).abs() <= epsilon
+            && (self.z - other.z).abs() <= epsilon
+            && (self.w - other.w).abs() <= epsilon
+    }
+
+    /// Determine whether or not all components of the quaternions are equal under an epsilon policy
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Epsilon;
+    /// use vex::Quaternion;
+    /// 
+    /// let a = Quaternion::make(1000000.0, 2.0, 3.0, 4.0);
+    /// assert!(a.approx_eq_with(&Quaternion::make(1000000.06, 2.0, 3.0, 4.0), &Epsilon::DEFAULT));
+    /// assert!(!a.approx_eq_with(&Quaternion::make(1000000.0, 2.1, 3.0, 4.0), &Epsilon::DEFAULT));
+    /// ```
+    #[inline]
+    pub fn approx_eq_with(&self, other: &Quaternion, epsilon: &Epsilon) -> bool {
+        let a: [f32; 4] = (*self).into();
+        let b: [f32; 4] = (*other).into();
+        epsilon.all_approx_eq(&a, &b)
+    }
+
+    /// Determine whether or not the quaternion has unit length under an epsilon policy
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Epsilon;
+    /// use vex::Quaternion;
+    /// 
+    /// assert!(Quaternion::make(0.0, 0.6, 0.8, 0.0).is_normalized(&Epsilon::DEFAULT));
+    /// assert!(!Quaternion::make(0.0, 0.6, 0.9, 0.0).is_normalized(&Epsilon::DEFAULT));
+    /// assert!(Quaternion::make(0.0, 0.6, 0.9, 0.0).is_normalized(&Epsilon::DEFAULT.with_absolute(0.1)));
+    /// ```
+    #[inline]
+    pub fn is_normalized(&self, epsilon: &Epsilon) -> bool {
+        epsilon.approx_eq(self.mag(), 1.0)
+    }
+
+    /// Determine whether or not all components of the quaternion are valid
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Quaternion;
+    ///
+    /// let actual = Quaternion::make(1.0, 2.0, 3.0, 4.0);
+    /// assert!(actual.is_valid());
+    /// ```
+    #[inline]
+    pub fn is_valid(&self) -> bool {
+        common::is_valid(self.x)
+            && common::is_valid(self.y)
+            && common::is_valid(self.z)
+            && common::is_valid(self.w)
+    }
+}
+
+impl Default for Quaternion {
+    /// Creates an identity quaternion
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Quaternion;
+    ///
+    /// assert_eq!(Quaternion::default(), Quaternion::new());
+    /// ```
+    #[inline]
+    fn default() -> Quaternion {
+        Quaternion::new()
+    }
+}
+
+impl From<[f32; 4]> for Quaternion {
+    /// Creates a Quaternion from an array of components in `x, y, z, w` order
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Quaternion;
+    /// 
+    /// let actual = Quaternion::from([1.0, 2.0, 3.0, 4.0]);
+    /// let expected = Quaternion::make(1.0, 2.0, 3.0, 4.0);
+    /// assert_eq!(actual, expected);
+    /// ```
+    #[inline]
+    fn from(item: [f32; 4]) -> Quaternion {
+        Quaternion::make(item[0], item[1], item[2], item[3])
+    }
+}
+
+impl From<Quaternion> for [f32; 4] {
+    /// Creates an array from the components of a Quaternion in `x, y, z, w` order
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Quaternion;
+    /// 
+    /// let actual: [f32; 4] = Quaternion::make(1.0, 2.0, 3.0, 4.0).into();
+    /// let expected = [1.0, 2.0, 3.0, 4.0];
+    /// assert_eq!(actual, expected);
+    /// ```
+    #[inline]
+    fn from(item: Quaternion) -> [f32; 4] {
+        [item.x, item.y, item.z, item.w]
+    }
+}
+
+impl AsRef<[f32]> for Quaternion {
+    /// Gets the components as a slice in `x, y, z, w` order
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Quaternion;
+    /// 
+    /// let v = Quaternion::make(1.0, 2.0, 3.0, 4.0);
+    /// let actual: &[f32] = v.as_ref();
+    /// assert_eq!(actual, &[1.0, 2.0, 3.0, 4.0][..]);
+    /// ```
+    #[inline]
+    fn as_ref(&self) -> &[f32] {
+        // SAFETY: the type is `repr(C)` with only f32 fields, so they're laid out contiguously
+        unsafe { slice::from_raw_parts(self as *const Quaternion as *const f32, 4) }
+    }
+}
+
+impl AsMut<[f32]> for Quaternion {
+    /// Gets the components as a mutable slice in `x, y, z, w` order
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Quaternion;
+    /// 
+    /// let mut v = Quaternion::make(1.0, 2.0, 3.0, 4.0);
+    /// v.as_mut()[0] = 5.0;
+    /// assert_eq!(v.as_ref()[0], 5.0);
+    /// ```
+    #[inline]
+    fn as_mut(&mut self) -> &mut [f32] {
+        // SAFETY: the type is `repr(C)` with only f32 fields, so they're laid out contiguously
+        unsafe { slice::from_raw_parts_mut(self as *mut Quaternion as *mut f32, 4) }
+    }
+}
+
+impl From<Matrix3> for Quaternion {
+    /// Creates a quaternion from a rotation matrix
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Matrix3;
+    /// use vex::Quaternion;
+    /// use vex::Vector3;
+    ///
+    /// let expected = Quaternion::from_axis_angle(&Vector3::make(1.0, 2.0, 3.0), 2.5);
+    /// let actual = Quaternion::from(Matrix3::from(expected));
+    /// assert!((actual - expected).mag() < 0.0001);
+    /// ```
+    #[inline]
+    fn from(m: Matrix3) -> Quaternion {
+        let trace = m.m11() + m.m22() + m.m33();
+        if trace > 0.0 {
+            let s = (trace + 1.0).sqrt() * 2.0;
+            Quaternion::make(
+                (m.m32() - m.m23()) / s,
+                (m.m13() - m.m31()) / s,
+                (m.m21() - m.m12()) / s,
+                0.25 * s,
+            )
+        } else if m.m11() > m.m22() && m.m11() > m.m33() {
+            let s = (1.0 + m.m11() - m.m22() - m.m33()).sqrt() * 2.0;
+            Quaternion::make(
+                0.25 * s,
+                (m.m12() + m.m21()) / s,
+                (m.m13() + m.m31()) / s,
+                (m.m32() - m.m23()) / s,
+            )
+        } else if m.m22() > m.m33() {
+            let s = (1.0 + m.m22() - m.m11() - m.m33()).sqrt() * 2.0;
+            Quaternion::make(
+                (m.m12() + m.m21()) / s,
+                0.25 * s,
+                (m.m23() + m.m32()) / s,
+                (m.m13() - m.m31()) / s,
+            )
+        } else {
+            let s = (1.0 + m.m33() - m.m11() - m.m22()).sqrt() * 2.0;
+            Quaternion::make(
+                (m.m13() + m.m31()) / s,
+                (m.m23() + m.m32()) / s,
+                0.25 * s,
+                (m.m21() - m.m12()) / s,
+            )
+        }
+    }
+}
+
+impl From<Matrix4> for Quaternion {
+    /// Creates a quaternion from the rotation part (upper 3x3) of a matrix
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Matrix4;
+    /// use vex::Quaternion;
+    /// use vex::Vector3;
+    ///
+    /// let expected = Quaternion::from_axis_angle(&Vector3::make(-1.0, 0.5, 2.0), 1.0);
+    /// let actual = Quaternion::from(Matrix4::from(expected));
+    /// assert!((actual - expected).mag() < 0.0001);
+    /// ```
+    #[inline]
+    fn from(m: Matrix4) -> Quaternion {
+        Quaternion::from(Matrix3::make(
+            m.m11(),
+            m.m21(),
+            m.m31(),
+            m.m12(),
+            m.m22(),
+            m.m32(),
+            m.m13(),
+            m.m23(),
+            m.m33(),
+        ))
+    }
+}
+
+impl From<Quaternion> for Matrix3 {
+    /// Creates a rotation matrix from a normalized quaternion
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Matrix;
+    /// use vex::Matrix3;
+    /// use vex::Quaternion;
+    /// use vex::Vector3;
+    ///
+    /// let q = Quaternion::from_axis_angle(&Vector3::make(1.0, 1.0, 0.0), 1.0);
+    /// let v = Vector3::make(1.0, 2.0, 3.0);
+    /// let actual = Matrix3::from(q).transform_point(&v);
+    /// assert!((actual - q.rotate(&v)).mag() < 0.0001);
+    /// ```
+    #[inline]
+    fn from(q: Quaternion) -> Matrix3 {
+        let (x, y, z, w) = (q.x, q.y, q.z, q.w);
+        Matrix3::make(
+            1.0 - 2.0 * (y * y + z * z),
+            2.0 * (x * y + w * z),
+            2.0 * (x * z - w * y),
+            2.0 * (x * y - w * z),
+            1.0 - 2.0 * (x * x + z * z),
+            2.0 * (y * z + w * x),
+            2.0 * (x * z + w * y),
+            2.0 * (y * z - w * x),
+            1.0 - 2.0 * (x * x + y * y),
+        )
+    }
+}
+
+impl From<Quaternion> for Matrix4 {
+    /// Creates a rotation matrix from a normalized quaternion
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Matrix;
+    /// use vex::Matrix4;
+    /// use vex::Quaternion;
+    /// use vex::Vector3;
+    ///
+    /// let q = Quaternion::from_axis_angle(&Vector3::make(0.0, 1.0, 1.0), -2.0);
+    /// let v = Vector3::make(1.0, 2.0, 3.0);
+    /// let actual = Matrix4::from(q).transform_point(&v);
+    /// assert!((actual - q.rotate(&v)).mag() < 0.0001);
+    /// ```
+    #[inline]
+    fn from(q: Quaternion) -> Matrix4 {
+        let m = Matrix3::from(q);
+        Matrix4::make(
+            m.m11(), m.m21(), m.m31(), 0.0,
+            m.m12(), m.m22(), m.m32(), 0.0,
+            m.m13(), m.m23(), m.m33(), 0.0,
+            0.0, 0.0, 0.0, 1.0,
+        )
+    }
+}
+
+impl Neg for Quaternion {
+    type Output = Quaternion;
+
+    /// Negates all components in a quaternion
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Quaternion;
+    ///
+    /// let actual = -Quaternion::make(1.0, 2.0, 3.0, 4.0);
+    /// let expected = Quaternion::make(-1.0, -2.0, -3.0, -4.0);
+    /// assert_eq!(actual, expected);
+    /// ```
+    #[inline]
+    fn neg(self) -> Quaternion {
+        Quaternion::make(-self.x, -self.y, -self.z, -self.w)
+    }
+}
+
+impl Add<Quaternion> for Quaternion {
+    type Output = Quaternion;
+
+    /// Add two quaternions
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Quaternion;
+    ///
+    /// let a = Quaternion::make(1.0, 2.0, 3.0, 4.0);
+    /// let b = Quaternion::make(5.0, 6.0, 7.0, 8.0);
+    /// let actual = a + b;
+    /// let expected = Quaternion::make(6.0, 8.0, 10.0, 12.0);
+    /// assert_eq!(actual, expected);
+    /// ```
+    #[inline]
+    fn add(self, _rhs: Quaternion) -> Quaternion {
+        Quaternion::make(
+            self.x + _rhs.x,
+            self.y + _rhs.y,
+            self.z + _rhs.z,
+            self.w + _rhs.w,
+        )
+    }
+}
+
+impl AddAssign<Quaternion> for Quaternion {
+    /// Increment a quaternion by another quaternion
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Quaternion;
+    ///
+    /// let mut actual = Quaternion::make(1.0, 2.0, 3.0, 4.0);
+    /// actual += Quaternion::make(5.0, 6.0, 7.0, 8.0);
+    /// let expected = Quaternion::make(6.0, 8.0, 10.0, 12.0);
+    /// assert_eq!(actual, expected);
+    /// ```
+    #[inline]
+    fn add_assign(&mut self, _rhs: Quaternion) {
+        self.x += _rhs.x;
+        self.y += _rhs.y;
+        self.z += _rhs.z;
+        self.w += _rhs.w;
+    }
+}
+
+impl Sub<Quaternion> for Quaternion {
+    type Output = Quaternion;
+
+    /// Subtract two quaternions
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Quaternion;
+    ///
+    /// let a = Quaternion::make(1.0, 2.0, 3.0, 4.0);
+    /// let b = Quaternion::make(5.0, 6.0, 7.0, 8.0);
+    /// let actual = a - b;
+    /// let expected = Quaternion::make(-4.0, -4.0, -4.0, -4.0);
+    /// assert_eq!(actual, expected);
+    /// ```
+    #[inline]
+    fn sub(self, _rhs: Quaternion) -> Quaternion {
+        Quaternion::make(
+            self.x - _rhs.x,
+            self.y - _rhs.y,
+            self.z - _rhs.z,
+            self.w - _rhs.w,
+        )
+    }
+}
+
+impl SubAssign<Quaternion> for Quaternion {
+    /// Decrement a quaternion by another quaternion
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Quaternion;
+    ///
+    /// let mut actual = Quaternion::make(1.0, 2.0, 3.0, 4.0);
+    /// actual -= Quaternion::make(5.0, 6.0, 7.0, 8.0);
+    /// let expected = Quaternion::make(-4.0, -4.0, -4.0, -4.0);
+    /// assert_eq!(actual, expected);
+    /// ```
+    #[inline]
+    fn sub_assign(&mut self, _rhs: Quaternion) {
+        self.x -= _rhs.x;
+        self.y -= _rhs.y;
+        self.z -= _rhs.z;
+        self.w -= _rhs.w;
+    }
+}
+
+impl Mul<f32> for Quaternion {
+    type Output = Quaternion;
+
+    /// Find the resulting quaternion by multiplying a scalar to a quaternion's components
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Quaternion;
+    ///
+    /// let actual = Quaternion::make(1.0, 2.0, 3.0, 4.0) * 2.0;
+    /// let expected = Quaternion::make(2.0, 4.0, 6.0, 8.0);
+    /// assert_eq!(actual, expected);
+    /// ```
+    #[inline]
+    fn mul(self, _rhs: f32) -> Quaternion {
+        Quaternion::make(self.x * _rhs, self.y * _rhs, self.z * _rhs, self.w * _rhs)
+    }
+}
+
+impl Mul<Quaternion> for Quaternion {
+    type Output = Quaternion;
+
+    /// Multiply two quaternions, producing the rotation of `_rhs` followed by `self`
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Quaternion;
+    ///
+    /// let a = Quaternion::make(1.0, 2.0, 3.0, 4.0);
+    /// let b = Quaternion::make(5.0, 6.0, 7.0, 8.0);
+    /// let actual = a * b;
+    /// let expected = Quaternion::make(24.0, 48.0, 48.0, -6.0);
+    /// assert_eq!(actual, expected);
+    /// ```
+    #[inline]
+    fn mul(self, _rhs: Quaternion) -> Quaternion {
+        Quaternion::make(
+            self.w * _rhs.x + self.x * _rhs.w + self.y * _rhs.z - self.z * _rhs.y,
+            self.w * _rhs.y - self.x * _rhs.z + self.y * _rhs.w + self.z * _rhs.x,
+            self.w * _rhs.z + self.x * _rhs.y - self.y * _rhs.x + self.z * _rhs.w,
+            self.w * _rhs.w - self.x * _rhs.x - self.y * _rhs.y - self.z * _rhs.z,
+        )
+    }
+}
+
+impl Mul<Vector3> for Quaternion {
+    type Output = Vector3;
+
+    /// Rotates a vector by the quaternion
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Quaternion;
+    /// use vex::Vector3;
+    ///
+    /// let q = Quaternion::from_axis_angle(&Vector3::right(), std::f32::consts::PI / 2.0);
+    /// let actual = q * Vector3::up();
+    /// assert!((actual - Vector3::make(0.0, 0.0, 1.0)).mag() < 0.0001);
+    /// ```
+    #[inline]
+    fn mul(self, _rhs: Vector3) -> Vector3 {
+        self.rotate(&_rhs)
+    }
+}
+
+impl MulAssign<f32> for Quaternion {
+    /// Multiply a quaternion by a scalar
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Quaternion;
+    ///
+    /// let mut actual = Quaternion::make(1.0, 2.0, 3.0, 4.0);
+    /// actual *= 2.0;
+    /// let expected = Quaternion::make(2.0, 4.0, 6.0, 8.0);
+    /// assert_eq!(actual, expected);
+    /// ```
+    #[inline]
+    fn mul_assign(&mut self, _rhs: f32) {
+        self.x *= _rhs;
+        self.y *= _rhs;
+        self.z *= _rhs;
+        self.w *= _rhs;
+    }
+}
+
+impl MulAssign<Quaternion> for Quaternion {
+    /// Multiply a quaternion by another quaternion
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Quaternion;
+    ///
+    /// let mut actual = Quaternion::make(1.0, 2.0, 3.0, 4.0);
+    /// actual *= Quaternion::make(5.0, 6.0, 7.0, 8.0);
+    /// let expected = Quaternion::make(24.0, 48.0, 48.0, -6.0);
+    /// assert_eq!(actual, expected);
+    /// ```
+    #[inline]
+    fn mul_assign(&mut self, _rhs: Quaternion) {
+        *self = *self * _rhs;
+    }
+}
+
+impl Div<f32> for Quaternion {
+    type Output = Quaternion;
+
+    /// Find the resulting quaternion by dividing a scalar to a quaternion's components
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Quaternion;
+    ///
+    /// let actual = Quaternion::make(1.0, 2.0, 3.0, 4.0) / 2.0;
+    /// let expected = Quaternion::make(0.5, 1.0, 1.5, 2.0);
+    /// assert_eq!(actual, expected);
+    /// ```
+    #[inline]
+    fn div(self, _rhs: f32) -> Quaternion {
+        Quaternion::make(self.x / _rhs, self.y / _rhs, self.z / _rhs, self.w / _rhs)
+    }
+}
+
+impl DivAssign<f32> for Quaternion {
+    /// Divide a quaternion by a scalar
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Quaternion;
+    ///
+    /// let mut actual = Quaternion::make(1.0, 2.0, 3.0, 4.0);
+    /// actual /= 2.0;
+    /// let expected = Quaternion::make(0.5, 1.0, 1.5, 2.0);
+    /// assert_eq!(actual, expected);
+    /// ```
+    #[inline]
+    fn div_assign(&mut self, _rhs: f32) {
+        self.x /= _rhs;
+        self.y /= _rhs;
+        self.z /= _rhs;
+        self.w /= _rhs;
+    }
+}
+
+impl cmp::PartialEq for Quaternion {
+    /// Determines if two quaternions' components are equivalent
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Quaternion;
+    ///
+    /// assert!(Quaternion::new() == Quaternion::new());
+    /// ```
+    #[inline]
+    fn eq(&self, _rhs: &Quaternion) -> bool {
+        self.x == _rhs.x && self.y == _rhs.y && self.z == _rhs.z && self.w == _rhs.w
+    }
+}
+
+impl Display for Quaternion {
+    /// Formats as `<x, y, z, w>`, with the formatter's flags applied to each component
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Quaternion;
+    ///
+    /// let v = Quaternion::make(0.0, 0.5, 0.0, 1.0);
+    /// assert_eq!(format!("{}", v), "<0, 0.5, 0, 1>");
+    /// assert_eq!(format!("{:.2}", v), "<0.00, 0.50, 0.00, 1.00>");
+    /// ```
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        format::write_vector(f, &[self.x, self.y, self.z, self.w])
+    }
+}
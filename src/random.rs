@@ -0,0 +1,201 @@
+//! Random vectors for the `rand` feature
+//!
+//! Every sampler here maps uniform random numbers straight onto its shape rather than rejecting
+//! samples, so each one draws a fixed number of values from the generator.
+
+#[cfg(not(feature = "std"))]
+use crate::math::Float;
+use crate::aabb::Aabb;
+use crate::vector2::Vector2;
+use crate::vector3::Vector3;
+use crate::vector4::Vector4;
+
+use core::f32::consts::PI;
+use rand::distributions::{Distribution, Standard};
+use rand::Rng;
+
+/// Samples unit `Vector3`s uniformly over the surface of the unit sphere
+///
+/// # Examples
+/// ```
+/// extern crate rand;
+///
+/// use rand::rngs::SmallRng;
+/// use rand::{Rng, SeedableRng};
+/// use vex::UnitSphere;
+/// use vex::Vector3;
+///
+/// let mut rng = SmallRng::seed_from_u64(1);
+/// let actual: Vector3 = rng.sample(UnitSphere);
+/// assert!((actual.mag() - 1.0).abs() < 0.00001);
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct UnitSphere;
+
+impl Distribution<Vector3> for UnitSphere {
+    #[inline]
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Vector3 {
+        Vector3::random_unit(rng)
+    }
+}
+
+impl Distribution<Vector2> for Standard {
+    /// Samples a vector with each component uniform in `[0, 1)`
+    ///
+    /// # Examples
+    /// ```
+    /// extern crate rand;
+    ///
+    /// use rand::rngs::SmallRng;
+    /// use rand::{Rng, SeedableRng};
+    /// use vex::Vector2;
+    ///
+    /// let mut rng = SmallRng::seed_from_u64(1);
+    /// let actual: Vector2 = rng.gen();
+    /// assert!(actual.x >= 0.0 && actual.x < 1.0);
+    /// assert!(actual.y >= 0.0 && actual.y < 1.0);
+    /// ```
+    #[inline]
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Vector2 {
+        Vector2::make(rng.gen(), rng.gen())
+    }
+}
+
+impl Distribution<Vector3> for Standard {
+    /// Samples a vector with each component uniform in `[0, 1)`
+    ///
+    /// # Examples
+    /// ```
+    /// extern crate rand;
+    ///
+    /// use rand::rngs::SmallRng;
+    /// use rand::{Rng, SeedableRng};
+    /// use vex::Vector3;
+    ///
+    /// let mut rng = SmallRng::seed_from_u64(1);
+    /// let actual: Vector3 = rng.gen();
+    /// assert!(actual.min_element() >= 0.0 && actual.max_element() < 1.0);
+    /// ```
+    #[inline]
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Vector3 {
+        Vector3::make(rng.gen(), rng.gen(), rng.gen())
+    }
+}
+
+impl Distribution<Vector4> for Standard {
+    /// Samples a vector with each component uniform in `[0, 1)`
+    ///
+    /// # Examples
+    /// ```
+    /// extern crate rand;
+    ///
+    /// use rand::rngs::SmallRng;
+    /// use rand::{Rng, SeedableRng};
+    /// use vex::Vector4;
+    ///
+    /// let mut rng = SmallRng::seed_from_u64(1);
+    /// let actual: Vector4 = rng.gen();
+    /// assert!(actual.min_element() >= 0.0 && actual.max_element() < 1.0);
+    /// ```
+    #[inline]
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Vector4 {
+        Vector4::make(rng.gen(), rng.gen(), rng.gen(), rng.gen())
+    }
+}
+
+impl Vector2 {
+    /// Find a random point uniformly distributed inside the unit disk
+    ///
+    /// # Examples
+    /// ```
+    /// extern crate rand;
+    ///
+    /// use rand::rngs::SmallRng;
+    /// use rand::SeedableRng;
+    /// use vex::Vector2;
+    ///
+    /// let mut rng = SmallRng::seed_from_u64(1);
+    /// for _ in 0..100 {
+    ///     assert!(Vector2::random_in_unit_disk(&mut rng).mag() <= 1.0);
+    /// }
+    /// ```
+    #[inline]
+    pub fn random_in_unit_disk<R: Rng + ?Sized>(rng: &mut R) -> Vector2 {
+        // the square root spreads the radius so the density is even over the area
+        let radius = rng.gen::<f32>().sqrt();
+        let (sin, cos) = (2.0 * PI * rng.gen::<f32>()).sin_cos();
+        Vector2::make(radius * cos, radius * sin)
+    }
+}
+
+impl Vector3 {
+    /// Find a random unit vector, uniformly distributed over the sphere
+    ///
+    /// # Examples
+    /// ```
+    /// extern crate rand;
+    ///
+    /// use rand::rngs::SmallRng;
+    /// use rand::SeedableRng;
+    /// use vex::Vector3;
+    ///
+    /// let mut rng = SmallRng::seed_from_u64(1);
+    /// for _ in 0..100 {
+    ///     assert!((Vector3::random_unit(&mut rng).mag() - 1.0).abs() < 0.00001);
+    /// }
+    /// ```
+    #[inline]
+    pub fn random_unit<R: Rng + ?Sized>(rng: &mut R) -> Vector3 {
+        // a uniform height on the sphere gives a uniform area, since every slice has equal area
+        let z = 1.0 - 2.0 * rng.gen::<f32>();
+        let radius = (1.0 - z * z).max(0.0).sqrt();
+        let (sin, cos) = (2.0 * PI * rng.gen::<f32>()).sin_cos();
+        Vector3::make(radius * cos, radius * sin, z)
+    }
+
+    /// Find a random point uniformly distributed inside the unit sphere
+    ///
+    /// # Examples
+    /// ```
+    /// extern crate rand;
+    ///
+    /// use rand::rngs::SmallRng;
+    /// use rand::SeedableRng;
+    /// use vex::Vector3;
+    ///
+    /// let mut rng = SmallRng::seed_from_u64(1);
+    /// for _ in 0..100 {
+    ///     assert!(Vector3::random_in_unit_sphere(&mut rng).mag() <= 1.00001);
+    /// }
+    /// ```
+    #[inline]
+    pub fn random_in_unit_sphere<R: Rng + ?Sized>(rng: &mut R) -> Vector3 {
+        let radius = rng.gen::<f32>().powf(1.0 / 3.0);
+        Vector3::random_unit(rng) * radius
+    }
+}
+
+impl Aabb {
+    /// Find a random point uniformly distributed inside the box
+    ///
+    /// # Examples
+    /// ```
+    /// extern crate rand;
+    ///
+    /// use rand::rngs::SmallRng;
+    /// use rand::SeedableRng;
+    /// use vex::Aabb;
+    /// use vex::Vector3;
+    ///
+    /// let aabb = Aabb::make(Vector3::make(-1.0, 2.0, 0.0), Vector3::make(1.0, 3.0, 5.0));
+    /// let mut rng = SmallRng::seed_from_u64(1);
+    /// for _ in 0..100 {
+    ///     assert!(aabb.contains_point(&aabb.random_point(&mut rng)));
+    /// }
+    /// ```
+    #[inline]
+    pub fn random_point<R: Rng + ?Sized>(&self, rng: &mut R) -> Vector3 {
+        let t: Vector3 = rng.gen();
+        self.min + (self.max - self.min) * t
+    }
+}
@@ -0,0 +1,112 @@
+use crate::ivector2::IVector2;
+#[cfg(not(feature = "std"))]
+use crate::math::Float;
+use crate::vector2::Vector2;
+
+/// Iterates over every grid cell touched by a triangle
+///
+/// Created by [`triangle_cells`](fn.triangle_cells.html).
+#[derive(Clone, Debug)]
+pub struct TriangleCells {
+    points: [Vector2; 3],
+    winding: f32,
+    cell_size: f32,
+    min: IVector2,
+    max: IVector2,
+    current: IVector2,
+}
+
+/// Conservatively rasterizes a triangle, yielding every cell that overlaps it
+///
+/// Cell `<x, y>` covers `[x * cell_size, (x + 1) * cell_size]` along x and likewise along y, and
+/// cells are visited row by row. Cells that only touch the triangle along an edge or at a corner
+/// are included. Degenerate triangles yield the cells touched by their line segment.
+///
+/// # Examples
+/// ```
+/// use vex::IVector2;
+/// use vex::Vector2;
+/// use vex::triangle_cells;
+///
+/// let a = Vector2::make(0.5, 0.5);
+/// let b = Vector2::make(2.4, 0.5);
+/// let c = Vector2::make(0.5, 2.4);
+/// let actual: Vec<IVector2> = triangle_cells(&a, &b, &c, 1.0).collect();
+/// let expected = vec![
+///     IVector2::make(0, 0),
+///     IVector2::make(1, 0),
+///     IVector2::make(2, 0),
+///     IVector2::make(0, 1),
+///     IVector2::make(1, 1),
+///     IVector2::make(0, 2),
+/// ];
+/// assert_eq!(actual, expected);
+/// ```
+pub fn triangle_cells(a: &Vector2, b: &Vector2, c: &Vector2, cell_size: f32) -> TriangleCells {
+    let low = Vector2::min(&Vector2::min(a, b), c) / cell_size;
+    let high = Vector2::max(&Vector2::max(a, b), c) / cell_size;
+    let min = IVector2::make(low.x.floor() as i32, low.y.floor() as i32);
+    let max = IVector2::make(high.x.floor() as i32, high.y.floor() as i32);
+
+    TriangleCells {
+        points: [*a, *b, *c],
+        winding: Vector2::cross(&(*b - *a), &(*c - *a)),
+        cell_size,
+        min,
+        max,
+        current: min,
+    }
+}
+
+impl TriangleCells {
+    fn overlaps(&self, cell: &IVector2) -> bool {
+        let low = Vector2::make(cell.x as f32, cell.y as f32) * self.cell_size;
+        let high = low + self.cell_size;
+
+        for i in 0..3 {
+            let p0 = self.points[i];
+            let p1 = self.points[(i + 1) % 3];
+            let d = p1 - p0;
+            let normal = Vector2::make(d.y, -d.x);
+
+            // project the box onto the edge normal, relative to the edge
+            let near = Vector2::make(
+                if normal.x > 0.0 { low.x } else { high.x },
+                if normal.y > 0.0 { low.y } else { high.y },
+            );
+            let far = Vector2::make(
+                if normal.x > 0.0 { high.x } else { low.x },
+                if normal.y > 0.0 { high.y } else { low.y },
+            );
+
+            let min = Vector2::dot(&normal, &(near - p0));
+            let max = Vector2::dot(&normal, &(far - p0));
+            if (self.winding >= 0.0 && min > 0.0) || (self.winding <= 0.0 && max < 0.0) {
+                return false;
+            }
+        }
+
+        true
+    }
+}
+
+impl Iterator for TriangleCells {
+    type Item = IVector2;
+
+    fn next(&mut self) -> Option<IVector2> {
+        while self.current.y <= self.max.y {
+            let cell = self.current;
+            self.current.x += 1;
+            if self.current.x > self.max.x {
+                self.current.x = self.min.x;
+                self.current.y += 1;
+            }
+
+            if self.overlaps(&cell) {
+                return Some(cell);
+            }
+        }
+
+        None
+    }
+}
@@ -0,0 +1,180 @@
+#[cfg(not(feature = "std"))]
+use crate::math::Float;
+use crate::aabb::Aabb;
+use crate::common::Matrix;
+use crate::matrix4::Matrix4;
+use crate::plane::Plane;
+use crate::vector3::Vector3;
+
+/// A half-line starting at `origin` and extending along `direction`
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Ray {
+    pub origin: Vector3,
+    pub direction: Vector3,
+}
+
+impl Ray {
+    /// Creates a ray from the provided origin and direction
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Ray;
+    /// use vex::Vector3;
+    ///
+    /// let actual = Ray::make(Vector3::one(), Vector3::forward());
+    /// assert_eq!(actual.origin, Vector3::one());
+    /// assert_eq!(actual.direction, Vector3::forward());
+    /// ```
+    #[inline]
+    pub const fn make(origin: Vector3, direction: Vector3) -> Ray {
+        Ray { origin, direction }
+    }
+
+    /// Gets the point a distance along the ray, in multiples of its direction
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Ray;
+    /// use vex::Vector3;
+    ///
+    /// let ray = Ray::make(Vector3::make(1.0, 0.0, 0.0), Vector3::make(0.0, 2.0, 0.0));
+    /// assert_eq!(ray.point_at(1.5), Vector3::make(1.0, 3.0, 0.0));
+    /// ```
+    #[inline]
+    pub fn point_at(&self, t: f32) -> Vector3 {
+        self.origin + self.direction * t
+    }
+
+    /// Find the ray moved by a transform
+    ///
+    /// The direction isn't renormalized, so a point a distance `t` along the ray transforms to the
+    /// point the same distance along the result.
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Matrix4;
+    /// use vex::Ray;
+    /// use vex::Vector3;
+    ///
+    /// let ray = Ray::make(Vector3::make(1.0, 0.0, 0.0), Vector3::make(0.0, 0.0, 1.0));
+    /// let m = Matrix4::translate(0.0, 5.0, 0.0) * Matrix4::scale(2.0, 2.0, 2.0);
+    /// let actual = ray.transform(&m);
+    /// assert_eq!(actual.origin, Vector3::make(2.0, 5.0, 0.0));
+    /// assert_eq!(actual.direction, Vector3::make(0.0, 0.0, 2.0));
+    /// ```
+    #[inline]
+    pub fn transform(&self, m: &Matrix4) -> Ray {
+        Ray::make(m.transform_point(&self.origin), m.transform_direction(&self.direction))
+    }
+
+    /// Gets how far along the ray it crosses a plane, in multiples of its direction
+    ///
+    /// Returns `None` if the ray runs parallel to the plane or points away from it.
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Plane;
+    /// use vex::Ray;
+    /// use vex::Vector3;
+    ///
+    /// let ground = Plane::from_point_normal(&Vector3::new(), &Vector3::up());
+    /// let ray = Ray::make(Vector3::make(0.0, 3.0, 0.0), Vector3::make(1.0, -1.0, 0.0));
+    /// let t = ray.intersect_plane(&ground).unwrap();
+    /// assert_eq!(ray.point_at(t), Vector3::make(3.0, 0.0, 0.0));
+    /// ```
+    #[inline]
+    pub fn intersect_plane(&self, plane: &Plane) -> Option<f32> {
+        plane.intersect_ray(self)
+    }
+
+    /// Gets how far along the ray it first enters a sphere, in multiples of its direction
+    ///
+    /// A ray starting inside the sphere hits it at `0`. Returns `None` if the ray misses the sphere
+    /// or points away from it.
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Ray;
+    /// use vex::Vector3;
+    ///
+    /// let ray = Ray::make(Vector3::make(0.0, 0.0, -5.0), Vector3::make(0.0, 0.0, 1.0));
+    /// assert_eq!(ray.intersect_sphere(&Vector3::new(), 2.0), Some(3.0));
+    /// assert_eq!(ray.intersect_sphere(&Vector3::make(0.0, 3.0, 0.0), 2.0), None);
+    /// assert_eq!(ray.intersect_sphere(&Vector3::make(0.0, 0.0, -6.0), 2.0), Some(0.0));
+    /// assert_eq!(ray.intersect_sphere(&Vector3::make(0.0, 0.0, -9.0), 2.0), None);
+    /// ```
+    #[inline]
+    pub fn intersect_sphere(&self, center: &Vector3, radius: f32) -> Option<f32> {
+        let offset = self.origin - *center;
+        let a = self.direction.mag_sq();
+        let b = Vector3::dot(&offset, &self.direction);
+        let c = offset.mag_sq() - radius * radius;
+        if c <= 0.0 {
+            return Some(0.0);
+        }
+
+        let discriminant = b * b - a * c;
+        if a <= f32::EPSILON || discriminant < 0.0 {
+            return None;
+        }
+
+        let t = (-b - discriminant.sqrt()) / a;
+        if t < 0.0 {
+            return None;
+        }
+
+        Some(t)
+    }
+
+    /// Gets how far along the ray it first enters a box, in multiples of its direction
+    ///
+    /// A ray starting inside the box hits it at `0`. Returns `None` if the ray misses the box or
+    /// points away from it.
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Aabb;
+    /// use vex::Ray;
+    /// use vex::Vector3;
+    ///
+    /// let aabb = Aabb::make(Vector3::make(-1.0, -1.0, -1.0), Vector3::one());
+    /// let ray = Ray::make(Vector3::make(-3.0, 0.5, 0.0), Vector3::make(2.0, 0.0, 0.0));
+    /// assert_eq!(ray.intersect_aabb(&aabb), Some(1.0));
+    ///
+    /// let ray = Ray::make(Vector3::make(-3.0, 2.0, 0.0), Vector3::make(2.0, 0.0, 0.0));
+    /// assert_eq!(ray.intersect_aabb(&aabb), None);
+    ///
+    /// let ray = Ray::make(Vector3::new(), Vector3::make(1.0, 1.0, 0.0));
+    /// assert_eq!(ray.intersect_aabb(&aabb), Some(0.0));
+    /// ```
+    pub fn intersect_aabb(&self, aabb: &Aabb) -> Option<f32> {
+        let origin: [f32; 3] = self.origin.into();
+        let direction: [f32; 3] = self.direction.into();
+        let min: [f32; 3] = aabb.min.into();
+        let max: [f32; 3] = aabb.max.into();
+
+        let mut near = 0.0f32;
+        let mut far = f32::INFINITY;
+        for i in 0..3 {
+            if direction[i] == 0.0 {
+                // parallel to this pair of faces, so the ray must already be between them
+                if origin[i] < min[i] || origin[i] > max[i] {
+                    return None;
+                }
+
+                continue;
+            }
+
+            let inverse = 1.0 / direction[i];
+            let t1 = (min[i] - origin[i]) * inverse;
+            let t2 = (max[i] - origin[i]) * inverse;
+            near = near.max(t1.min(t2));
+            far = far.min(t1.max(t2));
+            if near > far {
+                return None;
+            }
+        }
+
+        Some(near)
+    }
+}
@@ -0,0 +1,140 @@
+use crate::vector2::Vector2;
+
+/// An axis-aligned rectangle
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Rect {
+    pub min: Vector2,
+    pub max: Vector2,
+}
+
+impl Rect {
+    /// Creates a rectangle from the provided corners
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Rect;
+    /// use vex::Vector2;
+    ///
+    /// let actual = Rect::make(Vector2::new(), Vector2::make(2.0, 1.0));
+    /// assert_eq!(actual.min, Vector2::new());
+    /// assert_eq!(actual.max, Vector2::make(2.0, 1.0));
+    /// ```
+    #[inline]
+    pub const fn make(min: Vector2, max: Vector2) -> Rect {
+        Rect { min, max }
+    }
+
+    /// Find the smallest rectangle containing all of the provided points
+    ///
+    /// Returns `None` if no points are provided.
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Rect;
+    /// use vex::Vector2;
+    ///
+    /// let points = [Vector2::make(1.0, -2.0), Vector2::make(-1.0, 4.0), Vector2::make(0.0, 0.0)];
+    /// let actual = Rect::from_points(&points).unwrap();
+    /// assert_eq!(actual, Rect::make(Vector2::make(-1.0, -2.0), Vector2::make(1.0, 4.0)));
+    /// assert_eq!(Rect::from_points(&[]), None);
+    /// ```
+    #[inline]
+    pub fn from_points(points: &[Vector2]) -> Option<Rect> {
+        let first = points.first()?;
+        let mut rect = Rect::make(*first, *first);
+        for p in &points[1..] {
+            rect.min = Vector2::min(&rect.min, p);
+            rect.max = Vector2::max(&rect.max, p);
+        }
+
+        Some(rect)
+    }
+
+    /// Gets the width of the rectangle
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Rect;
+    /// use vex::Vector2;
+    ///
+    /// let actual = Rect::make(Vector2::make(1.0, 2.0), Vector2::make(4.0, 6.0)).width();
+    /// assert_eq!(actual, 3.0);
+    /// ```
+    #[inline]
+    pub fn width(&self) -> f32 {
+        self.max.x - self.min.x
+    }
+
+    /// Gets the height of the rectangle
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Rect;
+    /// use vex::Vector2;
+    ///
+    /// let actual = Rect::make(Vector2::make(1.0, 2.0), Vector2::make(4.0, 6.0)).height();
+    /// assert_eq!(actual, 4.0);
+    /// ```
+    #[inline]
+    pub fn height(&self) -> f32 {
+        self.max.y - self.min.y
+    }
+
+    /// Gets the center of the rectangle
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Rect;
+    /// use vex::Vector2;
+    ///
+    /// let actual = Rect::make(Vector2::make(1.0, 2.0), Vector2::make(3.0, 6.0)).center();
+    /// assert_eq!(actual, Vector2::make(2.0, 4.0));
+    /// ```
+    #[inline]
+    pub fn center(&self) -> Vector2 {
+        (self.min + self.max) * 0.5
+    }
+
+    /// Determines whether or not a point lies inside the rectangle (inclusive)
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Rect;
+    /// use vex::Vector2;
+    ///
+    /// let rect = Rect::make(Vector2::new(), Vector2::one());
+    /// assert!(rect.contains_point(&Vector2::make(1.0, 0.5)));
+    /// assert!(!rect.contains_point(&Vector2::make(1.5, 0.5)));
+    /// ```
+    #[inline]
+    pub fn contains_point(&self, point: &Vector2) -> bool {
+        point.x >= self.min.x && point.x <= self.max.x && point.y >= self.min.y && point.y <= self.max.y
+    }
+
+    /// Find the overlapping region of two rectangles
+    ///
+    /// Returns `None` if the rectangles don't overlap.
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Rect;
+    /// use vex::Vector2;
+    ///
+    /// let a = Rect::make(Vector2::new(), Vector2::make(2.0, 2.0));
+    /// let b = Rect::make(Vector2::one(), Vector2::make(3.0, 3.0));
+    /// let actual = Rect::intersection(&a, &b);
+    /// assert_eq!(actual, Some(Rect::make(Vector2::one(), Vector2::make(2.0, 2.0))));
+    ///
+    /// let c = Rect::make(Vector2::make(5.0, 5.0), Vector2::make(6.0, 6.0));
+    /// assert_eq!(Rect::intersection(&a, &c), None);
+    /// ```
+    #[inline]
+    pub fn intersection(a: &Rect, b: &Rect) -> Option<Rect> {
+        let rect = Rect::make(Vector2::max(&a.min, &b.min), Vector2::min(&a.max, &b.max));
+        if rect.min.x <= rect.max.x && rect.min.y <= rect.max.y {
+            Some(rect)
+        } else {
+            None
+        }
+    }
+}
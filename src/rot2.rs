@@ -0,0 +1,236 @@
+#[cfg(not(feature = "std"))]
+use crate::math::Float;
+use crate::matrix2::Matrix2;
+use crate::vector2::Vector2;
+
+use core::ops::{Mul, MulAssign};
+
+/// A counter-clockwise 2D rotation stored as the cosine and sine of its angle
+///
+/// This is a unit complex number: composing rotations is a complex multiply, and rotating a vector
+/// costs four multiplies, without the redundant elements of a `Matrix2`.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Rot2 {
+    pub cos: f32,
+    pub sin: f32,
+}
+
+impl Rot2 {
+    /// The rotation by zero radians
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Rot2;
+    ///
+    /// assert_eq!(Rot2::IDENTITY, Rot2::new());
+    /// ```
+    pub const IDENTITY: Rot2 = Rot2 { cos: 1.0, sin: 0.0 };
+
+    /// Creates a rotation by zero radians
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Rot2;
+    ///
+    /// let actual = Rot2::new();
+    /// assert_eq!(actual, Rot2::make(1.0, 0.0));
+    /// ```
+    #[inline]
+    pub const fn new() -> Rot2 {
+        Rot2::IDENTITY
+    }
+
+    /// Creates a rotation from the provided cosine and sine
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Rot2;
+    ///
+    /// let actual = Rot2::make(0.0, 1.0);
+    /// assert_eq!(actual.cos, 0.0);
+    /// assert_eq!(actual.sin, 1.0);
+    /// ```
+    #[inline]
+    pub const fn make(cos: f32, sin: f32) -> Rot2 {
+        Rot2 { cos, sin }
+    }
+
+    /// Creates a rotation of `angle` radians counter-clockwise
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Rot2;
+    ///
+    /// let actual = Rot2::from_angle(std::f32::consts::FRAC_PI_2);
+    /// assert!(actual.cos.abs() < 0.000001);
+    /// assert_eq!(actual.sin, 1.0);
+    /// ```
+    #[inline]
+    pub fn from_angle(angle: f32) -> Rot2 {
+        let (sin, cos) = angle.sin_cos();
+        Rot2 { cos, sin }
+    }
+
+    /// Gets the angle of the rotation in radians, within `[-pi, pi]`
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Rot2;
+    ///
+    /// let actual = Rot2::from_angle(2.5).angle();
+    /// assert!((actual - 2.5).abs() < 0.000001);
+    ///
+    /// let actual = Rot2::from_angle(4.0).angle();
+    /// assert!((actual - (4.0 - 2.0 * std::f32::consts::PI)).abs() < 0.000001);
+    /// ```
+    #[inline]
+    pub fn angle(&self) -> f32 {
+        self.sin.atan2(self.cos)
+    }
+
+    /// Find the rotation in the opposite direction
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Rot2;
+    ///
+    /// let r = Rot2::from_angle(0.7);
+    /// assert!((r * r.inverse()).angle().abs() < 0.000001);
+    /// assert_eq!(r.inverse(), Rot2::from_angle(-0.7));
+    /// ```
+    #[inline]
+    pub fn inverse(&self) -> Rot2 {
+        Rot2::make(self.cos, -self.sin)
+    }
+
+    /// Find the rotation rescaled to unit length, undoing drift from many compositions
+    ///
+    /// A zero rotation is returned as the identity.
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Rot2;
+    ///
+    /// let actual = Rot2::make(3.0, 4.0).normalized();
+    /// assert_eq!(actual, Rot2::make(0.6, 0.8));
+    /// assert_eq!(Rot2::make(0.0, 0.0).normalized(), Rot2::new());
+    /// ```
+    #[inline]
+    pub fn normalized(&self) -> Rot2 {
+        let length = (self.cos * self.cos + self.sin * self.sin).sqrt();
+        if length <= f32::EPSILON {
+            return Rot2::new();
+        }
+
+        Rot2::make(self.cos / length, self.sin / length)
+    }
+
+    /// Find the resulting vector after applying the rotation
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Rot2;
+    /// use vex::Vector2;
+    ///
+    /// let r = Rot2::from_angle(std::f32::consts::FRAC_PI_2);
+    /// let actual = r.rotate(&Vector2::make(1.0, 2.0));
+    /// assert!((actual - Vector2::make(-2.0, 1.0)).mag() < 0.000001);
+    /// ```
+    #[inline]
+    pub fn rotate(&self, v: &Vector2) -> Vector2 {
+        Vector2::make(
+            self.cos * v.x - self.sin * v.y,
+            self.sin * v.x + self.cos * v.y,
+        )
+    }
+}
+
+impl Default for Rot2 {
+    /// Creates a rotation by zero radians
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Rot2;
+    ///
+    /// assert_eq!(Rot2::default(), Rot2::new());
+    /// ```
+    #[inline]
+    fn default() -> Rot2 {
+        Rot2::new()
+    }
+}
+
+impl From<Rot2> for Matrix2 {
+    /// Creates a rotation matrix from a rotation
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Matrix2;
+    /// use vex::Rot2;
+    ///
+    /// let actual = Matrix2::from(Rot2::from_angle(0.3));
+    /// assert!(actual.approx_eq(&Matrix2::rotate(0.3), 0.000001));
+    /// ```
+    #[inline]
+    fn from(r: Rot2) -> Matrix2 {
+        Matrix2::make(r.cos, r.sin, -r.sin, r.cos)
+    }
+}
+
+impl Mul<Rot2> for Rot2 {
+    type Output = Rot2;
+
+    /// Combine two rotations, whose angles add
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Rot2;
+    ///
+    /// let actual = Rot2::from_angle(0.5) * Rot2::from_angle(1.0);
+    /// assert!((actual.angle() - 1.5).abs() < 0.000001);
+    /// ```
+    #[inline]
+    fn mul(self, _rhs: Rot2) -> Rot2 {
+        Rot2::make(
+            self.cos * _rhs.cos - self.sin * _rhs.sin,
+            self.sin * _rhs.cos + self.cos * _rhs.sin,
+        )
+    }
+}
+
+impl Mul<Vector2> for Rot2 {
+    type Output = Vector2;
+
+    /// Find the resulting vector after applying the rotation
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Rot2;
+    /// use vex::Vector2;
+    ///
+    /// let r = Rot2::from_angle(0.4);
+    /// let v = Vector2::make(3.0, -1.0);
+    /// assert_eq!(r * v, r.rotate(&v));
+    /// ```
+    #[inline]
+    fn mul(self, _rhs: Vector2) -> Vector2 {
+        self.rotate(&_rhs)
+    }
+}
+
+impl MulAssign<Rot2> for Rot2 {
+    /// Combine a rotation into this one
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Rot2;
+    ///
+    /// let mut actual = Rot2::from_angle(0.5);
+    /// actual *= Rot2::from_angle(-0.25);
+    /// assert!((actual.angle() - 0.25).abs() < 0.000001);
+    /// ```
+    #[inline]
+    fn mul_assign(&mut self, _rhs: Rot2) {
+        *self = *self * _rhs;
+    }
+}
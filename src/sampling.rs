@@ -0,0 +1,335 @@
+#[cfg(not(feature = "std"))]
+use crate::math::Float;
+use crate::vector3::Vector3;
+
+use alloc::vec::Vec;
+use core::f32::consts::PI;
+
+/// Picks indices with probability proportional to a set of weights
+///
+/// The cumulative distribution is built once, and each sample is a binary search over it.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct WeightedSampler {
+    cdf: Vec<f32>,
+}
+
+impl WeightedSampler {
+    /// Creates a sampler from a set of non-negative weights
+    ///
+    /// Panics if any weight is negative or not finite.
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::WeightedSampler;
+    ///
+    /// let sampler = WeightedSampler::make(&[1.0, 3.0]);
+    /// assert_eq!(sampler.len(), 2);
+    /// assert_eq!(sampler.total(), 4.0);
+    /// ```
+    pub fn make(weights: &[f32]) -> WeightedSampler {
+        let mut total = 0.0f64;
+        let cdf = weights
+            .iter()
+            .enumerate()
+            .map(|(i, &w)| {
+                assert!(
+                    w.is_finite() && w >= 0.0,
+                    "weight {} must be finite and non-negative, not {}",
+                    i,
+                    w
+                );
+                total += w as f64;
+                total as f32
+            })
+            .collect();
+
+        WeightedSampler { cdf }
+    }
+
+    /// Creates a sampler that picks the triangles of a mesh proportional to their area
+    ///
+    /// Every three consecutive entries of `indices` form a triangle, and any trailing indices
+    /// that don't form a full triangle are ignored. Panics if a triangle's area isn't finite, as
+    /// happens when one of its positions isn't.
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Vector3;
+    /// use vex::WeightedSampler;
+    ///
+    /// let positions = [
+    ///     Vector3::make(0.0, 0.0, 0.0),
+    ///     Vector3::make(1.0, 0.0, 0.0),
+    ///     Vector3::make(0.0, 1.0, 0.0),
+    ///     Vector3::make(0.0, 3.0, 0.0),
+    /// ];
+    ///
+    /// let sampler = WeightedSampler::from_triangles(&positions, &[0, 1, 2, 0, 1, 3]);
+    /// assert_eq!(sampler.total(), 2.0);
+    /// assert_eq!(sampler.probability(0), 0.25);
+    /// ```
+    pub fn from_triangles(positions: &[Vector3], indices: &[u32]) -> WeightedSampler {
+        let areas: Vec<f32> = indices
+            .chunks_exact(3)
+            .map(|t| {
+                let a = positions[t[0] as usize];
+                let b = positions[t[1] as usize];
+                let c = positions[t[2] as usize];
+                Vector3::cross(&(b - a), &(c - a)).mag() * 0.5
+            })
+            .collect();
+
+        WeightedSampler::make(&areas)
+    }
+
+    /// Gets the number of weights in the sampler
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::WeightedSampler;
+    ///
+    /// assert_eq!(WeightedSampler::make(&[1.0, 0.0, 2.0]).len(), 3);
+    /// ```
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.cdf.len()
+    }
+
+    /// Determines whether the sampler has no weights
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::WeightedSampler;
+    ///
+    /// assert!(WeightedSampler::make(&[]).is_empty());
+    /// ```
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.cdf.is_empty()
+    }
+
+    /// Gets the sum of all weights
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::WeightedSampler;
+    ///
+    /// assert_eq!(WeightedSampler::make(&[1.0, 2.0, 3.0]).total(), 6.0);
+    /// ```
+    #[inline]
+    pub fn total(&self) -> f32 {
+        self.cdf.last().cloned().unwrap_or(0.0)
+    }
+
+    /// Find the probability of an index being sampled
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::WeightedSampler;
+    ///
+    /// let sampler = WeightedSampler::make(&[1.0, 3.0]);
+    /// assert_eq!(sampler.probability(0), 0.25);
+    /// assert_eq!(sampler.probability(1), 0.75);
+    /// ```
+    #[inline]
+    pub fn probability(&self, index: usize) -> f32 {
+        let total = self.total();
+        if total <= 0.0 {
+            return 0.0;
+        }
+
+        let start = if index == 0 { 0.0 } else { self.cdf[index - 1] };
+        (self.cdf[index] - start) / total
+    }
+
+    /// Maps a uniform value in `[0, 1)` to an index, proportional to its weight
+    ///
+    /// Entries with zero weight are never returned. Panics if the total weight is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::WeightedSampler;
+    ///
+    /// let sampler = WeightedSampler::make(&[1.0, 0.0, 3.0]);
+    /// assert_eq!(sampler.sample(0.0), 0);
+    /// assert_eq!(sampler.sample(0.2), 0);
+    /// assert_eq!(sampler.sample(0.25), 2);
+    /// assert_eq!(sampler.sample(0.99), 2);
+    /// ```
+    pub fn sample(&self, u: f32) -> usize {
+        let total = self.total();
+        assert!(total > 0.0, "cannot sample from a zero total weight");
+
+        let target = u.clamp(0.0, 1.0) * total;
+        let index = self.cdf.partition_point(|&c| c <= target);
+        if index < self.cdf.len() {
+            index
+        } else {
+            // u rounded up to the total, so use the last entry with any weight
+            self.cdf.partition_point(|&c| c < total)
+        }
+    }
+
+    /// Maps a uniform value in `[0, 1)` to an index, and rescales it to a fresh uniform value
+    ///
+    /// The returned value is the position of `u` within the chosen entry's slice of the
+    /// distribution, which lets a single random number drive further sampling.
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::WeightedSampler;
+    ///
+    /// let sampler = WeightedSampler::make(&[1.0, 3.0]);
+    /// assert_eq!(sampler.sample_remapped(0.125), (0, 0.5));
+    /// assert_eq!(sampler.sample_remapped(0.625), (1, 0.5));
+    /// ```
+    pub fn sample_remapped(&self, u: f32) -> (usize, f32) {
+        let index = self.sample(u);
+        let start = if index == 0 { 0.0 } else { self.cdf[index - 1] };
+        let width = self.cdf[index] - start;
+        let remapped = (u.clamp(0.0, 1.0) * self.total() - start) / width;
+        (index, remapped.clamp(0.0, 1.0 - f32::EPSILON / 2.0))
+    }
+
+    /// Picks a triangle proportional to its area and a uniformly distributed point on it
+    ///
+    /// The sampler must come from [`from_triangles`](#method.from_triangles). `u.x` picks the
+    /// triangle and `u.y` and `u.z` pick the point, which is returned as barycentric weights for
+    /// the triangle's three vertices.
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Vector3;
+    /// use vex::WeightedSampler;
+    ///
+    /// let positions = [
+    ///     Vector3::make(0.0, 0.0, 0.0),
+    ///     Vector3::make(1.0, 0.0, 0.0),
+    ///     Vector3::make(0.0, 1.0, 0.0),
+    ///     Vector3::make(0.0, 3.0, 0.0),
+    /// ];
+    ///
+    /// let sampler = WeightedSampler::from_triangles(&positions, &[0, 1, 2, 0, 1, 3]);
+    /// let (triangle, barycentric) = sampler.sample_triangle(&Vector3::make(0.5, 0.25, 0.5));
+    /// assert_eq!(triangle, 1);
+    /// assert_eq!(barycentric, Vector3::make(0.5, 0.25, 0.25));
+    /// ```
+    #[inline]
+    pub fn sample_triangle(&self, u: &Vector3) -> (usize, Vector3) {
+        (self.sample(u.x), sample_barycentric(u.y, u.z))
+    }
+}
+
+/// Maps two uniform values in `[0, 1)` to uniformly distributed barycentric weights
+///
+/// # Examples
+/// ```
+/// use vex::Vector3;
+/// use vex::sample_barycentric;
+///
+/// assert_eq!(sample_barycentric(0.0, 0.5), Vector3::make(1.0, 0.0, 0.0));
+/// assert_eq!(sample_barycentric(0.25, 0.5), Vector3::make(0.5, 0.25, 0.25));
+/// ```
+#[inline]
+pub fn sample_barycentric(u: f32, v: f32) -> Vector3 {
+    let r = u.max(0.0).sqrt();
+    let a = 1.0 - r;
+    let b = r * (1.0 - v);
+    Vector3::make(a, b, 1.0 - a - b)
+}
+
+/// Maps two uniform values in `[0, 1)` to a direction uniformly distributed over the hemisphere
+/// around a unit normal, along with its probability density per steradian
+///
+/// # Examples
+/// ```
+/// use vex::Vector3;
+/// use vex::sample_hemisphere_uniform;
+///
+/// let normal = Vector3::make(0.0, 0.0, -1.0);
+/// let (direction, pdf) = sample_hemisphere_uniform(&normal, 0.3, 0.8);
+/// assert!((direction.mag() - 1.0).abs() < 0.0001);
+/// assert!((Vector3::dot(&direction, &normal) - 0.3).abs() < 0.0001);
+/// assert_eq!(pdf, 0.5 / std::f32::consts::PI);
+/// ```
+#[inline]
+pub fn sample_hemisphere_uniform(normal: &Vector3, u1: f32, u2: f32) -> (Vector3, f32) {
+    let cosine = u1;
+    let local = spherical(cosine, u2);
+    (to_tangent_frame(normal, &local), 0.5 / PI)
+}
+
+/// Maps two uniform values in `[0, 1)` to a direction over the hemisphere around a unit normal,
+/// distributed by the cosine to the normal, along with its probability density per steradian
+///
+/// # Examples
+/// ```
+/// use vex::Vector3;
+/// use vex::sample_hemisphere_cosine;
+///
+/// let normal = Vector3::up();
+/// let (direction, pdf) = sample_hemisphere_cosine(&normal, 0.0, 0.4);
+/// assert!((direction - normal).mag() < 0.0001);
+/// assert!((pdf - 1.0 / std::f32::consts::PI).abs() < 0.0001);
+///
+/// let (direction, pdf) = sample_hemisphere_cosine(&normal, 0.75, 0.4);
+/// assert!((direction.y - 0.5).abs() < 0.0001);
+/// assert!((pdf - 0.5 / std::f32::consts::PI).abs() < 0.0001);
+/// ```
+#[inline]
+pub fn sample_hemisphere_cosine(normal: &Vector3, u1: f32, u2: f32) -> (Vector3, f32) {
+    let cosine = (1.0 - u1).max(0.0).sqrt();
+    let local = spherical(cosine, u2);
+    (to_tangent_frame(normal, &local), cosine / PI)
+}
+
+/// Maps two uniform values in `[0, 1)` to a half-vector around a unit normal, distributed by the
+/// GGX microfacet distribution with the provided roughness, along with its probability density
+/// per steradian
+///
+/// `alpha` is the GGX width, commonly the square of a material's perceptual roughness. The
+/// density is that of the half-vector, `D(h) * dot(n, h)`, so converting it to a density over
+/// reflected directions divides it by `4 * dot(v, h)`.
+///
+/// # Examples
+/// ```
+/// use vex::Vector3;
+/// use vex::sample_ggx;
+///
+/// let normal = Vector3::forward();
+/// let (half, pdf) = sample_ggx(&normal, 0.5, 0.0, 0.2);
+/// assert!((half - normal).mag() < 0.0001);
+/// assert!((pdf - 4.0 / std::f32::consts::PI).abs() < 0.0001);
+///
+/// // rougher surfaces spread the same sample further from the normal
+/// let (smooth, _) = sample_ggx(&normal, 0.1, 0.5, 0.2);
+/// let (rough, _) = sample_ggx(&normal, 0.8, 0.5, 0.2);
+/// assert!(Vector3::dot(&rough, &normal) < Vector3::dot(&smooth, &normal));
+/// ```
+#[inline]
+pub fn sample_ggx(normal: &Vector3, alpha: f32, u1: f32, u2: f32) -> (Vector3, f32) {
+    let a2 = alpha * alpha;
+    let cosine_sq = ((1.0 - u1) / (1.0 + (a2 - 1.0) * u1)).clamp(0.0, 1.0);
+    let cosine = cosine_sq.sqrt();
+    let local = spherical(cosine, u2);
+
+    let denom = (a2 - 1.0) * cosine_sq + 1.0;
+    let pdf = if a2 > 0.0 { a2 * cosine / (PI * denom * denom) } else { 0.0 };
+    (to_tangent_frame(normal, &local), pdf)
+}
+
+/// The unit direction with the given cosine to +z, turned around it by `u` of a full turn
+#[inline]
+fn spherical(cosine: f32, u: f32) -> Vector3 {
+    let sine = (1.0 - cosine * cosine).max(0.0).sqrt();
+    let (s, c) = (2.0 * PI * u).sin_cos();
+    Vector3::make(sine * c, sine * s, cosine)
+}
+
+/// Moves a direction around +z into the space around a unit normal
+#[inline]
+fn to_tangent_frame(normal: &Vector3, local: &Vector3) -> Vector3 {
+    let tangent = Vector3::perpendicular(normal);
+    let bitangent = Vector3::cross(normal, &tangent);
+    tangent * local.x + bitangent * local.y + *normal * local.z
+}
@@ -0,0 +1,263 @@
+use crate::aabb::Aabb;
+use crate::clip_space::{ClipSpace, DepthRange};
+use crate::common::Matrix;
+use crate::matrix4::Matrix4;
+use crate::rect::Rect;
+use crate::vector2::Vector2;
+use crate::vector3::Vector3;
+use crate::vector4::Vector4;
+
+/// Find the conservative screen-space bounds of a box after projection
+///
+/// The box is clipped against the near plane (`z >= -w` in clip space) before the perspective
+/// divide, so boxes that straddle the camera still produce correct bounds. NDC is mapped so that
+/// -1 lands on `viewport.min` and 1 on `viewport.max` along both axes, and the result is clipped
+/// to the viewport. Returns `None` if the box is entirely behind the near plane or off screen.
+///
+/// The matrix is expected to map into OpenGL's clip volume. Use
+/// [`project_aabb_to_screen_clip`](fn.project_aabb_to_screen_clip.html) for other clip spaces.
+///
+/// # Examples
+/// ```
+/// use vex::Aabb;
+/// use vex::Matrix4;
+/// use vex::Rect;
+/// use vex::Vector2;
+/// use vex::Vector3;
+/// use vex::project_aabb_to_screen;
+///
+/// let viewport = Rect::make(Vector2::new(), Vector2::make(100.0, 100.0));
+/// let aabb = Aabb::make(Vector3::make(-0.5, -0.5, -0.5), Vector3::make(0.5, 0.5, 0.5));
+/// let actual = project_aabb_to_screen(&aabb, &Matrix4::new(), &viewport);
+/// let expected = Rect::make(Vector2::make(25.0, 25.0), Vector2::make(75.0, 75.0));
+/// assert_eq!(actual, Some(expected));
+///
+/// let aabb = Aabb::make(Vector3::make(2.0, 0.0, 0.0), Vector3::make(3.0, 1.0, 0.0));
+/// assert_eq!(project_aabb_to_screen(&aabb, &Matrix4::new(), &viewport), None);
+/// ```
+#[inline]
+pub fn project_aabb_to_screen(
+    aabb: &Aabb,
+    view_projection: &Matrix4,
+    viewport: &Rect,
+) -> Option<Rect> {
+    project_aabb_to_screen_clip(aabb, view_projection, viewport, &ClipSpace::OPENGL)
+}
+
+/// Find the conservative screen-space bounds of a box after a projection built for a clip space
+///
+/// The box is clipped against the near plane of the clip space's depth range. NDC is mapped the
+/// same way as [`project_aabb_to_screen`](fn.project_aabb_to_screen.html) whether or not the
+/// clip space flips y, so with a flipped y `viewport.min` is the top of the screen.
+///
+/// # Examples
+/// ```
+/// use vex::Aabb;
+/// use vex::ClipSpace;
+/// use vex::DepthRange;
+/// use vex::Matrix4;
+/// use vex::Rect;
+/// use vex::Vector2;
+/// use vex::Vector3;
+/// use vex::project_aabb_to_screen_clip;
+///
+/// let viewport = Rect::make(Vector2::new(), Vector2::make(100.0, 100.0));
+///
+/// // a box straddling the near plane at z = -1
+/// let aabb = Aabb::make(Vector3::make(-1.0, -1.0, -4.0), Vector3::make(1.0, 1.0, -0.5));
+/// let projection = Matrix4::perspective_clip(90.0, 1.0, 1.0, 100.0, &ClipSpace::DIRECT3D);
+/// let actual = project_aabb_to_screen_clip(&aabb, &projection, &viewport, &ClipSpace::DIRECT3D);
+/// assert_eq!(actual, Some(viewport));
+///
+/// let reverse_z = ClipSpace::make(DepthRange::OneToZero, false);
+/// let projection = Matrix4::perspective_reverse_z(90.0, 1.0, 1.0);
+/// let aabb = Aabb::make(Vector3::make(-1.0, -1.0, -0.9), Vector3::make(1.0, 1.0, -0.5));
+/// assert_eq!(project_aabb_to_screen_clip(&aabb, &projection, &viewport, &reverse_z), None);
+/// ```
+pub fn project_aabb_to_screen_clip(
+    aabb: &Aabb,
+    view_projection: &Matrix4,
+    viewport: &Rect,
+    clip_space: &ClipSpace,
+) -> Option<Rect> {
+    // how far in front of the near plane a point is, in clip space
+    let depth = |p: &Vector4| match clip_space.depth {
+        DepthRange::NegativeOneToOne => p.z + p.w,
+        DepthRange::ZeroToOne => p.z,
+        DepthRange::OneToZero => p.w - p.z,
+    };
+
+    let mut clip = [Vector4::new(); 8];
+    for (i, corner) in aabb.corners().iter().enumerate() {
+        clip[i] = view_projection.transform_point(&Vector4::make(corner.x, corner.y, corner.z, 1.0));
+    }
+
+    let mut min = Vector2::make(f32::MAX, f32::MAX);
+    let mut max = Vector2::make(f32::MIN, f32::MIN);
+    let mut any = false;
+    let mut include = |p: &Vector4| {
+        if p.w > 0.0 {
+            let ndc = Vector2::make(p.x / p.w, p.y / p.w);
+            min = Vector2::min(&min, &ndc);
+            max = Vector2::max(&max, &ndc);
+            any = true;
+        }
+    };
+
+    for p in clip.iter() {
+        if depth(p) >= 0.0 {
+            include(p);
+        }
+    }
+
+    // add the points where the box's edges cross the near plane
+    for i in 0..8 {
+        for axis in 0..3 {
+            let j = i | (1 << axis);
+            if j == i {
+                continue;
+            }
+
+            let a = clip[i];
+            let b = clip[j];
+            let da = depth(&a);
+            let db = depth(&b);
+            if (da >= 0.0) != (db >= 0.0) {
+                let t = da / (da - db);
+                include(&(a + (b - a) * t));
+            }
+        }
+    }
+
+    if !any {
+        return None;
+    }
+
+    let size = viewport.max - viewport.min;
+    let bounds = Rect::make(
+        viewport.min + (min * 0.5 + 0.5) * size,
+        viewport.min + (max * 0.5 + 0.5) * size,
+    );
+
+    Rect::intersection(&bounds, viewport)
+}
+
+/// Convert normalized device coordinates to texture coordinates
+///
+/// NDC -1 maps to 0 and 1 maps to 1 along both axes, matching
+/// [`project_aabb_to_screen`](fn.project_aabb_to_screen.html), so v increases upwards.
+///
+/// # Examples
+/// ```
+/// use vex::Vector2;
+/// use vex::ndc_to_uv;
+///
+/// let actual = ndc_to_uv(&Vector2::make(-1.0, 0.5));
+/// let expected = Vector2::make(0.0, 0.75);
+/// assert_eq!(actual, expected);
+/// ```
+#[inline]
+pub fn ndc_to_uv(ndc: &Vector2) -> Vector2 {
+    *ndc * 0.5 + 0.5
+}
+
+/// Convert texture coordinates to normalized device coordinates
+///
+/// This is the inverse of [`ndc_to_uv`](fn.ndc_to_uv.html).
+///
+/// # Examples
+/// ```
+/// use vex::Vector2;
+/// use vex::uv_to_ndc;
+///
+/// let actual = uv_to_ndc(&Vector2::make(0.0, 0.75));
+/// let expected = Vector2::make(-1.0, 0.5);
+/// assert_eq!(actual, expected);
+/// ```
+#[inline]
+pub fn uv_to_ndc(uv: &Vector2) -> Vector2 {
+    *uv * 2.0 - 1.0
+}
+
+/// Find where a pixel from the current frame was on screen in the previous frame
+///
+/// `current_ndc` holds the pixel's NDC position and depth, which is unprojected into world space
+/// with the current frame's inverse view-projection matrix and then projected with the previous
+/// frame's view-projection matrix. This only accounts for camera motion, so use
+/// [`motion_vector`](fn.motion_vector.html) for moving objects.
+///
+/// # Examples
+/// ```
+/// use vex::Matrix;
+/// use vex::Matrix4;
+/// use vex::Vector2;
+/// use vex::Vector3;
+/// use vex::Vector4;
+/// use vex::ndc_to_uv;
+/// use vex::reproject_uv;
+///
+/// let projection = Matrix4::perspective(60.0, 1.5, 0.1, 100.0);
+/// let current_vp = projection * Matrix4::look_at_rh(
+///     Vector3::make(0.0, 1.0, 5.0),
+///     Vector3::new(),
+///     Vector3::up(),
+/// );
+/// let previous_vp = projection * Matrix4::look_at_rh(
+///     Vector3::make(0.5, 1.0, 5.0),
+///     Vector3::new(),
+///     Vector3::up(),
+/// );
+///
+/// let point = Vector4::make(0.5, 0.25, -1.0, 1.0);
+/// let current = current_vp.transform_point(&point);
+/// let current_ndc = Vector3::make(current.x, current.y, current.z) / current.w;
+/// let previous = previous_vp.transform_point(&point);
+/// let expected = ndc_to_uv(&(Vector2::make(previous.x, previous.y) / previous.w));
+///
+/// let actual = reproject_uv(&current_ndc, &current_vp.try_inverse().unwrap(), &previous_vp);
+/// assert!((actual - expected).mag() < 0.0001);
+/// ```
+pub fn reproject_uv(
+    current_ndc: &Vector3,
+    current_inv_vp: &Matrix4,
+    previous_vp: &Matrix4,
+) -> Vector2 {
+    let ndc = Vector4::make(current_ndc.x, current_ndc.y, current_ndc.z, 1.0);
+    let world = current_inv_vp.transform_point(&ndc);
+    let world = world / world.w;
+    ndc_to_uv(&project(previous_vp, &world))
+}
+
+/// Find the screen-space motion of a point on an object between two frames
+///
+/// `position` is in the object's local space, and each model-view-projection matrix is the
+/// object's model matrix combined with that frame's view-projection matrix. The result is the
+/// current UV minus the previous UV, so adding it to the previous UV gives the current UV.
+///
+/// # Examples
+/// ```
+/// use vex::Matrix4;
+/// use vex::Vector2;
+/// use vex::Vector3;
+/// use vex::motion_vector;
+///
+/// let vp = Matrix4::perspective(90.0, 1.0, 0.1, 100.0);
+/// let previous_mvp = vp * Matrix4::translate(0.0, 0.0, -2.0);
+/// let current_mvp = vp * Matrix4::translate(1.0, 0.0, -2.0);
+///
+/// let actual = motion_vector(&Vector3::new(), &current_mvp, &previous_mvp);
+/// let expected = Vector2::make(0.25, 0.0);
+/// assert!((actual - expected).mag() < 0.000001);
+/// ```
+pub fn motion_vector(position: &Vector3, current_mvp: &Matrix4, previous_mvp: &Matrix4) -> Vector2 {
+    let local = Vector4::make(position.x, position.y, position.z, 1.0);
+    let current = project(current_mvp, &local);
+    let previous = project(previous_mvp, &local);
+    ndc_to_uv(&current) - ndc_to_uv(&previous)
+}
+
+#[inline]
+fn project(m: &Matrix4, p: &Vector4) -> Vector2 {
+    let clip = m.transform_point(p);
+    Vector2::make(clip.x / clip.w, clip.y / clip.w)
+}
@@ -0,0 +1,220 @@
+use crate::vector2::Vector2;
+use crate::vector3::Vector3;
+
+const SOBOL_DIRECTIONS: [[u32; 32]; 2] = [
+    [
+        0x80000000, 0xc0000000, 0xa0000000, 0xf0000000, 0x88000000, 0xcc000000, 0xaa000000, 0xff000000,
+        0x80800000, 0xc0c00000, 0xa0a00000, 0xf0f00000, 0x88880000, 0xcccc0000, 0xaaaa0000, 0xffff0000,
+        0x80008000, 0xc000c000, 0xa000a000, 0xf000f000, 0x88008800, 0xcc00cc00, 0xaa00aa00, 0xff00ff00,
+        0x80808080, 0xc0c0c0c0, 0xa0a0a0a0, 0xf0f0f0f0, 0x88888888, 0xcccccccc, 0xaaaaaaaa, 0xffffffff,
+    ],
+    [
+        0x80000000, 0xc0000000, 0x60000000, 0x90000000, 0xe8000000, 0x5c000000, 0x8e000000, 0xc5000000,
+        0x68800000, 0x9cc00000, 0xee600000, 0x55900000, 0x80680000, 0xc09c0000, 0x60ee0000, 0x90550000,
+        0xe8808000, 0x5cc0c000, 0x8e606000, 0xc5909000, 0x6868e800, 0x9c9c5c00, 0xeeee8e00, 0x5555c500,
+        0x8000e880, 0xc0005cc0, 0x60008e60, 0x9000c590, 0xe8006868, 0x5c009c9c, 0x8e00eeee, 0xc5005555,
+    ],
+];
+
+/// Find the radical inverse of an index in the provided base
+///
+/// Panics if `base` is less than 2.
+///
+/// # Examples
+/// ```
+/// use vex::halton;
+///
+/// assert_eq!(halton(1, 2), 0.5);
+/// assert_eq!(halton(3, 2), 0.75);
+/// assert_eq!(halton(5, 3), 7.0 / 9.0);
+/// ```
+#[inline]
+pub fn halton(index: u32, base: u32) -> f32 {
+    halton_scrambled(index, base, 0)
+}
+
+/// Find the radical inverse of an index with each digit scrambled by a seed
+///
+/// Every digit position gets its own seed-dependent permutation of the digits, which decorrelates
+/// sequences that share a base. A seed of 0 leaves the sequence unscrambled.
+///
+/// Panics if `base` is less than 2.
+///
+/// # Examples
+/// ```
+/// use vex::halton;
+/// use vex::halton_scrambled;
+///
+/// assert_eq!(halton_scrambled(7, 3, 0), halton(7, 3));
+/// let actual = halton_scrambled(7, 3, 1234);
+/// assert!(actual >= 0.0 && actual < 1.0);
+/// ```
+pub fn halton_scrambled(index: u32, base: u32, seed: u32) -> f32 {
+    assert!(base >= 2, "halton sequences need a base of at least 2, not {}", base);
+
+    let inv_base = 1.0 / base as f64;
+    let mut factor = inv_base;
+    let mut result = 0.0f64;
+    let mut index = index;
+    let mut level = 0;
+
+    while factor > 1e-8 && (index > 0 || seed != 0) {
+        let mut digit = index % base;
+        if seed != 0 {
+            digit = (digit + hash(seed ^ hash(level)) % base) % base;
+        }
+
+        result += digit as f64 * factor;
+        index /= base;
+        factor *= inv_base;
+        level += 1;
+    }
+
+    (result as f32).min(1.0 - f32::EPSILON / 2.0)
+}
+
+/// Find the point at an index in the 2D Halton sequence (bases 2 and 3)
+///
+/// # Examples
+/// ```
+/// use vex::Vector2;
+/// use vex::halton2;
+///
+/// assert_eq!(halton2(1), Vector2::make(0.5, 1.0 / 3.0));
+/// assert_eq!(halton2(2), Vector2::make(0.25, 2.0 / 3.0));
+/// ```
+#[inline]
+pub fn halton2(index: u32) -> Vector2 {
+    Vector2::make(halton(index, 2), halton(index, 3))
+}
+
+/// Find the point at an index in the 3D Halton sequence (bases 2, 3, and 5)
+///
+/// # Examples
+/// ```
+/// use vex::Vector3;
+/// use vex::halton3;
+///
+/// assert_eq!(halton3(1), Vector3::make(0.5, 1.0 / 3.0, 0.2));
+/// ```
+#[inline]
+pub fn halton3(index: u32) -> Vector3 {
+    Vector3::make(halton(index, 2), halton(index, 3), halton(index, 5))
+}
+
+/// Find the point at an index in the 2D Sobol sequence
+///
+/// # Examples
+/// ```
+/// use vex::Vector2;
+/// use vex::sobol2;
+///
+/// assert_eq!(sobol2(0), Vector2::make(0.0, 0.0));
+/// assert_eq!(sobol2(1), Vector2::make(0.5, 0.5));
+/// assert_eq!(sobol2(2), Vector2::make(0.25, 0.75));
+/// assert_eq!(sobol2(3), Vector2::make(0.75, 0.25));
+/// ```
+#[inline]
+pub fn sobol2(index: u32) -> Vector2 {
+    sobol2_scrambled(index, 0)
+}
+
+/// Find the point at an index in the 2D Sobol sequence, scrambled by a seed
+///
+/// Scrambling XORs each dimension with a seed-dependent random bit pattern, which preserves the
+/// stratification of the sequence. A seed of 0 leaves the sequence unscrambled.
+///
+/// # Examples
+/// ```
+/// use vex::sobol2;
+/// use vex::sobol2_scrambled;
+///
+/// assert_eq!(sobol2_scrambled(5, 0), sobol2(5));
+/// let actual = sobol2_scrambled(5, 42);
+/// assert!(actual.x >= 0.0 && actual.x < 1.0 && actual.y >= 0.0 && actual.y < 1.0);
+/// ```
+#[inline]
+pub fn sobol2_scrambled(index: u32, seed: u32) -> Vector2 {
+    Vector2::make(
+        to_unit(index.reverse_bits() ^ scramble(seed, 0)),
+        to_unit(sobol_bits(index, 0) ^ scramble(seed, 1)),
+    )
+}
+
+/// Find the point at an index in the 3D Sobol sequence
+///
+/// # Examples
+/// ```
+/// use vex::Vector3;
+/// use vex::sobol3;
+///
+/// assert_eq!(sobol3(1), Vector3::make(0.5, 0.5, 0.5));
+/// assert_eq!(sobol3(2), Vector3::make(0.25, 0.75, 0.75));
+/// ```
+#[inline]
+pub fn sobol3(index: u32) -> Vector3 {
+    sobol3_scrambled(index, 0)
+}
+
+/// Find the point at an index in the 3D Sobol sequence, scrambled by a seed
+///
+/// A seed of 0 leaves the sequence unscrambled.
+///
+/// # Examples
+/// ```
+/// use vex::sobol3;
+/// use vex::sobol3_scrambled;
+///
+/// assert_eq!(sobol3_scrambled(9, 0), sobol3(9));
+/// assert_ne!(sobol3_scrambled(9, 7), sobol3(9));
+/// ```
+#[inline]
+pub fn sobol3_scrambled(index: u32, seed: u32) -> Vector3 {
+    Vector3::make(
+        to_unit(index.reverse_bits() ^ scramble(seed, 0)),
+        to_unit(sobol_bits(index, 0) ^ scramble(seed, 1)),
+        to_unit(sobol_bits(index, 1) ^ scramble(seed, 2)),
+    )
+}
+
+#[inline]
+fn sobol_bits(index: u32, dimension: usize) -> u32 {
+    let mut result = 0;
+    let mut index = index;
+    let mut bit = 0;
+    while index != 0 {
+        if index & 1 != 0 {
+            result ^= SOBOL_DIRECTIONS[dimension][bit];
+        }
+
+        index >>= 1;
+        bit += 1;
+    }
+
+    result
+}
+
+#[inline]
+fn scramble(seed: u32, dimension: u32) -> u32 {
+    if seed == 0 {
+        0
+    } else {
+        hash(seed.wrapping_add(hash(dimension)))
+    }
+}
+
+#[inline]
+pub(crate) fn to_unit(bits: u32) -> f32 {
+    (bits >> 8) as f32 / (1u32 << 24) as f32
+}
+
+#[inline]
+pub(crate) fn hash(x: u32) -> u32 {
+    let mut x = x;
+    x ^= x >> 16;
+    x = x.wrapping_mul(0x7feb352d);
+    x ^= x >> 15;
+    x = x.wrapping_mul(0x846ca68b);
+    x ^= x >> 16;
+    x
+}
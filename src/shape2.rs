@@ -0,0 +1,606 @@
+#[cfg(not(feature = "std"))]
+use crate::math::Float;
+use crate::rect::Rect;
+use crate::vector2::Vector2;
+
+use alloc::vec::Vec;
+
+/// How two overlapping 2D shapes penetrate each other
+///
+/// Moving the second shape by `normal * depth` (or the first by the opposite) separates them.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Contact2 {
+    /// The unit direction from the first shape towards the second
+    pub normal: Vector2,
+    /// How far the shapes overlap along the normal
+    pub depth: f32,
+}
+
+impl Contact2 {
+    /// Gets the same contact as seen from the second shape
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Contact2;
+    /// use vex::Vector2;
+    ///
+    /// let contact = Contact2 { normal: Vector2::make(1.0, 0.0), depth: 0.5 };
+    /// let actual = contact.flipped();
+    /// assert_eq!(actual.normal, Vector2::make(-1.0, 0.0));
+    /// assert_eq!(actual.depth, 0.5);
+    /// ```
+    #[inline]
+    pub fn flipped(&self) -> Contact2 {
+        Contact2 {
+            normal: -self.normal,
+            depth: self.depth,
+        }
+    }
+}
+
+/// A 2D capsule (stadium): every point within `radius` of the segment from `a` to `b`
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Capsule2 {
+    pub a: Vector2,
+    pub b: Vector2,
+    pub radius: f32,
+}
+
+impl Capsule2 {
+    /// Creates a capsule from the provided segment and radius
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Capsule2;
+    /// use vex::Vector2;
+    ///
+    /// let actual = Capsule2::make(Vector2::new(), Vector2::make(2.0, 0.0), 0.5);
+    /// assert_eq!(actual.b, Vector2::make(2.0, 0.0));
+    /// assert_eq!(actual.radius, 0.5);
+    /// ```
+    #[inline]
+    pub const fn make(a: Vector2, b: Vector2, radius: f32) -> Capsule2 {
+        Capsule2 { a, b, radius }
+    }
+
+    /// Determine whether or not a point is inside the capsule
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Capsule2;
+    /// use vex::Vector2;
+    ///
+    /// let capsule = Capsule2::make(Vector2::new(), Vector2::make(2.0, 0.0), 0.5);
+    /// assert!(capsule.contains_point(&Vector2::make(1.0, 0.5)));
+    /// assert!(capsule.contains_point(&Vector2::make(2.4, 0.0)));
+    /// assert!(!capsule.contains_point(&Vector2::make(2.4, 0.4)));
+    /// ```
+    #[inline]
+    pub fn contains_point(&self, point: &Vector2) -> bool {
+        let closest = closest_on_segment(&self.a, &self.b, point);
+        (*point - closest).mag_sq() <= self.radius * self.radius
+    }
+
+    /// Find how the capsule overlaps a circle
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Capsule2;
+    /// use vex::Vector2;
+    ///
+    /// let capsule = Capsule2::make(Vector2::new(), Vector2::make(2.0, 0.0), 0.5);
+    /// let actual = capsule.overlap_circle(&Vector2::make(1.0, 1.0), 1.0).unwrap();
+    /// assert_eq!(actual.normal, Vector2::make(0.0, 1.0));
+    /// assert_eq!(actual.depth, 0.5);
+    ///
+    /// assert_eq!(capsule.overlap_circle(&Vector2::make(1.0, 2.0), 1.0), None);
+    /// ```
+    pub fn overlap_circle(&self, center: &Vector2, radius: f32) -> Option<Contact2> {
+        let closest = closest_on_segment(&self.a, &self.b, center);
+        let fallback = perpendicular(&(self.b - self.a)).unwrap_or_else(|| Vector2::make(1.0, 0.0));
+        round_contact(&closest, center, self.radius + radius, &fallback)
+    }
+
+    /// Find how the capsule overlaps another capsule
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Capsule2;
+    /// use vex::Vector2;
+    ///
+    /// let a = Capsule2::make(Vector2::new(), Vector2::make(2.0, 0.0), 0.5);
+    /// let b = Capsule2::make(Vector2::make(1.0, 0.75), Vector2::make(1.0, 3.0), 0.5);
+    /// let actual = a.overlap_capsule(&b).unwrap();
+    /// assert_eq!(actual.normal, Vector2::make(0.0, 1.0));
+    /// assert_eq!(actual.depth, 0.25);
+    ///
+    /// // crossing segments push apart along the shallowest axis
+    /// let b = Capsule2::make(Vector2::make(1.5, -1.0), Vector2::make(1.5, 1.0), 0.25);
+    /// let actual = a.overlap_capsule(&b).unwrap();
+    /// assert_eq!(actual.normal, Vector2::make(1.0, 0.0));
+    /// assert_eq!(actual.depth, 1.25);
+    ///
+    /// let b = Capsule2::make(Vector2::make(0.0, 2.0), Vector2::make(2.0, 2.0), 0.5);
+    /// assert_eq!(a.overlap_capsule(&b), None);
+    /// ```
+    pub fn overlap_capsule(&self, other: &Capsule2) -> Option<Contact2> {
+        let radius = self.radius + other.radius;
+        if segments_intersect(&self.a, &self.b, &other.a, &other.b) {
+            let self_dir = self.b - self.a;
+            let other_dir = other.b - other.a;
+            let axes = [
+                perpendicular(&self_dir),
+                perpendicular(&other_dir),
+                unit(&self_dir),
+                unit(&other_dir),
+            ];
+            let axes: Vec<Vector2> = axes.iter().filter_map(|axis| *axis).collect();
+            return separating_axes(&axes, &[self.a, self.b], &[other.a, other.b], radius);
+        }
+
+        let (p, q) = closest_between_segments(&self.a, &self.b, &other.a, &other.b);
+        round_contact(&p, &q, radius, &Vector2::make(1.0, 0.0))
+    }
+
+    /// Find how the capsule overlaps a rotated rectangle
+    ///
+    /// # Examples
+    /// ```
+    /// use std::f32::consts::PI;
+    /// use vex::Capsule2;
+    /// use vex::RotatedRect;
+    /// use vex::Vector2;
+    ///
+    /// let capsule = Capsule2::make(Vector2::make(-3.0, 0.0), Vector2::make(-1.5, 0.0), 0.5);
+    /// let rect = RotatedRect::make(Vector2::new(), Vector2::make(1.0, 1.0), PI / 4.0);
+    /// let actual = capsule.overlap_rotated_rect(&rect).unwrap();
+    /// assert!((actual.normal - Vector2::make(1.0, 0.0)).mag() < 0.000001);
+    /// assert!((actual.depth - (2.0f32.sqrt() - 1.0)).abs() < 0.000001);
+    ///
+    /// let capsule = Capsule2::make(Vector2::make(-3.0, 0.0), Vector2::make(-2.0, 0.0), 0.5);
+    /// assert_eq!(capsule.overlap_rotated_rect(&rect), None);
+    /// ```
+    pub fn overlap_rotated_rect(&self, other: &RotatedRect) -> Option<Contact2> {
+        let corners = other.corners();
+        let (x_axis, y_axis) = other.axes();
+        let mut axes = vec![x_axis, y_axis];
+        if let Some(axis) = perpendicular(&(self.b - self.a)) {
+            axes.push(axis);
+        }
+
+        let segment = [self.a, self.b];
+        if separating_axes(&axes, &segment, &corners, 0.0).is_some() {
+            return separating_axes(&axes, &segment, &corners, self.radius);
+        }
+
+        // the closest features are an endpoint of one shape and an edge of the other
+        let mut best = (self.a, other.closest_point(&self.a));
+        let mut best_dist_sq = (best.1 - best.0).mag_sq();
+        let candidate = (self.b, other.closest_point(&self.b));
+        if (candidate.1 - candidate.0).mag_sq() < best_dist_sq {
+            best = candidate;
+            best_dist_sq = (candidate.1 - candidate.0).mag_sq();
+        }
+
+        for corner in corners.iter() {
+            let p = closest_on_segment(&self.a, &self.b, corner);
+            let dist_sq = (*corner - p).mag_sq();
+            if dist_sq < best_dist_sq {
+                best = (p, *corner);
+                best_dist_sq = dist_sq;
+            }
+        }
+
+        round_contact(&best.0, &best.1, self.radius, &Vector2::make(1.0, 0.0))
+    }
+
+    /// Find how the capsule overlaps an axis-aligned rectangle
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Capsule2;
+    /// use vex::Rect;
+    /// use vex::Vector2;
+    ///
+    /// let capsule = Capsule2::make(Vector2::make(0.0, 2.5), Vector2::make(3.0, 2.5), 1.0);
+    /// let rect = Rect::make(Vector2::new(), Vector2::make(2.0, 2.0));
+    /// let actual = capsule.overlap_rect(&rect).unwrap();
+    /// assert!((actual.normal - Vector2::make(0.0, -1.0)).mag() < 0.000001);
+    /// assert!((actual.depth - 0.5).abs() < 0.000001);
+    /// ```
+    #[inline]
+    pub fn overlap_rect(&self, other: &Rect) -> Option<Contact2> {
+        self.overlap_rotated_rect(&RotatedRect::from_rect(other))
+    }
+}
+
+/// A 2D oriented bounding box
+///
+/// The box spans `half_extents` on either side of `center` along its local axes, which are
+/// rotated counter-clockwise by `angle` radians.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct RotatedRect {
+    pub center: Vector2,
+    pub half_extents: Vector2,
+    pub angle: f32,
+}
+
+impl RotatedRect {
+    /// Creates a rotated rectangle from the provided center, half extents, and angle
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::RotatedRect;
+    /// use vex::Vector2;
+    ///
+    /// let actual = RotatedRect::make(Vector2::make(1.0, 2.0), Vector2::make(3.0, 4.0), 0.5);
+    /// assert_eq!(actual.center, Vector2::make(1.0, 2.0));
+    /// assert_eq!(actual.half_extents, Vector2::make(3.0, 4.0));
+    /// assert_eq!(actual.angle, 0.5);
+    /// ```
+    #[inline]
+    pub const fn make(center: Vector2, half_extents: Vector2, angle: f32) -> RotatedRect {
+        RotatedRect {
+            center,
+            half_extents,
+            angle,
+        }
+    }
+
+    /// Creates an unrotated rectangle covering an axis-aligned rectangle
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Rect;
+    /// use vex::RotatedRect;
+    /// use vex::Vector2;
+    ///
+    /// let rect = Rect::make(Vector2::make(1.0, 2.0), Vector2::make(5.0, 4.0));
+    /// let actual = RotatedRect::from_rect(&rect);
+    /// assert_eq!(actual, RotatedRect::make(Vector2::make(3.0, 3.0), Vector2::make(2.0, 1.0), 0.0));
+    /// ```
+    #[inline]
+    pub fn from_rect(rect: &Rect) -> RotatedRect {
+        RotatedRect::make(rect.center(), (rect.max - rect.min) * 0.5, 0.0)
+    }
+
+    /// Gets the rectangle's local x and y axes
+    ///
+    /// # Examples
+    /// ```
+    /// use std::f32::consts::PI;
+    /// use vex::RotatedRect;
+    /// use vex::Vector2;
+    ///
+    /// let rect = RotatedRect::make(Vector2::new(), Vector2::make(1.0, 1.0), PI / 2.0);
+    /// let (x, y) = rect.axes();
+    /// assert!((x - Vector2::make(0.0, 1.0)).mag() < 0.000001);
+    /// assert!((y - Vector2::make(-1.0, 0.0)).mag() < 0.000001);
+    /// ```
+    #[inline]
+    pub fn axes(&self) -> (Vector2, Vector2) {
+        let (sine, cosine) = self.angle.sin_cos();
+        (Vector2::make(cosine, sine), Vector2::make(-sine, cosine))
+    }
+
+    /// Gets the rectangle's corners in counter-clockwise order
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::RotatedRect;
+    /// use vex::Vector2;
+    ///
+    /// let rect = RotatedRect::make(Vector2::make(1.0, 1.0), Vector2::make(2.0, 1.0), 0.0);
+    /// let expected = [
+    ///     Vector2::make(-1.0, 0.0),
+    ///     Vector2::make(3.0, 0.0),
+    ///     Vector2::make(3.0, 2.0),
+    ///     Vector2::make(-1.0, 2.0),
+    /// ];
+    /// assert_eq!(rect.corners(), expected);
+    /// ```
+    pub fn corners(&self) -> [Vector2; 4] {
+        let (x_axis, y_axis) = self.axes();
+        let x = x_axis * self.half_extents.x;
+        let y = y_axis * self.half_extents.y;
+        [
+            self.center - x - y,
+            self.center + x - y,
+            self.center + x + y,
+            self.center - x + y,
+        ]
+    }
+
+    /// Find the point inside the rectangle closest to the provided point
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::RotatedRect;
+    /// use vex::Vector2;
+    ///
+    /// let rect = RotatedRect::make(Vector2::new(), Vector2::make(2.0, 1.0), 0.0);
+    /// assert_eq!(rect.closest_point(&Vector2::make(3.0, 3.0)), Vector2::make(2.0, 1.0));
+    /// assert_eq!(rect.closest_point(&Vector2::make(0.5, 0.5)), Vector2::make(0.5, 0.5));
+    /// ```
+    pub fn closest_point(&self, point: &Vector2) -> Vector2 {
+        let (x_axis, y_axis) = self.axes();
+        let offset = *point - self.center;
+        let x = Vector2::dot(&offset, &x_axis).clamp(-self.half_extents.x, self.half_extents.x);
+        let y = Vector2::dot(&offset, &y_axis).clamp(-self.half_extents.y, self.half_extents.y);
+        self.center + x_axis * x + y_axis * y
+    }
+
+    /// Determine whether or not a point is inside the rectangle
+    ///
+    /// # Examples
+    /// ```
+    /// use std::f32::consts::PI;
+    /// use vex::RotatedRect;
+    /// use vex::Vector2;
+    ///
+    /// let rect = RotatedRect::make(Vector2::new(), Vector2::make(1.0, 1.0), PI / 4.0);
+    /// assert!(rect.contains_point(&Vector2::make(1.4, 0.0)));
+    /// assert!(!rect.contains_point(&Vector2::make(1.0, 1.0)));
+    /// ```
+    #[inline]
+    pub fn contains_point(&self, point: &Vector2) -> bool {
+        let (x_axis, y_axis) = self.axes();
+        let offset = *point - self.center;
+        Vector2::dot(&offset, &x_axis).abs() <= self.half_extents.x
+            && Vector2::dot(&offset, &y_axis).abs() <= self.half_extents.y
+    }
+
+    /// Find how the rectangle overlaps a circle
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::RotatedRect;
+    /// use vex::Vector2;
+    ///
+    /// let rect = RotatedRect::make(Vector2::new(), Vector2::make(2.0, 1.0), 0.0);
+    /// let actual = rect.overlap_circle(&Vector2::make(0.0, 1.5), 1.0).unwrap();
+    /// assert_eq!(actual.normal, Vector2::make(0.0, 1.0));
+    /// assert_eq!(actual.depth, 0.5);
+    ///
+    /// // a center inside the rectangle is pushed out through the nearest side
+    /// let actual = rect.overlap_circle(&Vector2::make(1.5, 0.0), 0.25).unwrap();
+    /// assert_eq!(actual.normal, Vector2::make(1.0, 0.0));
+    /// assert_eq!(actual.depth, 0.75);
+    ///
+    /// assert_eq!(rect.overlap_circle(&Vector2::make(3.0, 2.0), 1.0), None);
+    /// ```
+    pub fn overlap_circle(&self, center: &Vector2, radius: f32) -> Option<Contact2> {
+        if !self.contains_point(center) {
+            let closest = self.closest_point(center);
+            return round_contact(&closest, center, radius, &Vector2::make(1.0, 0.0));
+        }
+
+        let (x_axis, y_axis) = self.axes();
+        let offset = *center - self.center;
+        let x = Vector2::dot(&offset, &x_axis);
+        let y = Vector2::dot(&offset, &y_axis);
+        let to_x_side = self.half_extents.x - x.abs();
+        let to_y_side = self.half_extents.y - y.abs();
+        if to_x_side <= to_y_side {
+            Some(Contact2 {
+                normal: x_axis * sign(x),
+                depth: to_x_side + radius,
+            })
+        } else {
+            Some(Contact2 {
+                normal: y_axis * sign(y),
+                depth: to_y_side + radius,
+            })
+        }
+    }
+
+    /// Find how the rectangle overlaps another rotated rectangle
+    ///
+    /// # Examples
+    /// ```
+    /// use std::f32::consts::PI;
+    /// use vex::RotatedRect;
+    /// use vex::Vector2;
+    ///
+    /// let a = RotatedRect::make(Vector2::new(), Vector2::make(1.0, 1.0), 0.0);
+    /// let b = RotatedRect::make(Vector2::make(2.0, 0.0), Vector2::make(1.0, 1.0), PI / 4.0);
+    /// let actual = a.overlap_rotated_rect(&b).unwrap();
+    /// assert!((actual.normal - Vector2::make(1.0, 0.0)).mag() < 0.000001);
+    /// assert!((actual.depth - (2.0f32.sqrt() - 1.0)).abs() < 0.000001);
+    ///
+    /// let b = RotatedRect::make(Vector2::make(3.0, 0.0), Vector2::make(1.0, 1.0), PI / 4.0);
+    /// assert_eq!(a.overlap_rotated_rect(&b), None);
+    /// ```
+    pub fn overlap_rotated_rect(&self, other: &RotatedRect) -> Option<Contact2> {
+        let (ax, ay) = self.axes();
+        let (bx, by) = other.axes();
+        separating_axes(&[ax, ay, bx, by], &self.corners(), &other.corners(), 0.0)
+    }
+
+    /// Find how the rectangle overlaps an axis-aligned rectangle
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Rect;
+    /// use vex::RotatedRect;
+    /// use vex::Vector2;
+    ///
+    /// let a = RotatedRect::make(Vector2::new(), Vector2::make(1.0, 1.0), 0.0);
+    /// let b = Rect::make(Vector2::make(-3.0, -0.5), Vector2::make(-0.5, 0.5));
+    /// let actual = a.overlap_rect(&b).unwrap();
+    /// assert_eq!(actual.normal, Vector2::make(-1.0, 0.0));
+    /// assert_eq!(actual.depth, 0.5);
+    /// ```
+    #[inline]
+    pub fn overlap_rect(&self, other: &Rect) -> Option<Contact2> {
+        self.overlap_rotated_rect(&RotatedRect::from_rect(other))
+    }
+
+    /// Find how the rectangle overlaps a capsule
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Capsule2;
+    /// use vex::RotatedRect;
+    /// use vex::Vector2;
+    ///
+    /// let rect = RotatedRect::make(Vector2::new(), Vector2::make(1.0, 1.0), 0.0);
+    /// let capsule = Capsule2::make(Vector2::make(1.25, -2.0), Vector2::make(1.25, 2.0), 0.5);
+    /// let actual = rect.overlap_capsule(&capsule).unwrap();
+    /// assert_eq!(actual.normal, Vector2::make(1.0, 0.0));
+    /// assert_eq!(actual.depth, 0.25);
+    /// ```
+    #[inline]
+    pub fn overlap_capsule(&self, other: &Capsule2) -> Option<Contact2> {
+        other.overlap_rotated_rect(self).map(|contact| contact.flipped())
+    }
+}
+
+#[inline]
+fn unit(v: &Vector2) -> Option<Vector2> {
+    let length = v.mag();
+    if length > 0.0 {
+        Some(*v / length)
+    } else {
+        None
+    }
+}
+
+#[inline]
+fn sign(x: f32) -> f32 {
+    if x < 0.0 {
+        -1.0
+    } else {
+        1.0
+    }
+}
+
+#[inline]
+fn perpendicular(v: &Vector2) -> Option<Vector2> {
+    unit(&Vector2::make(-v.y, v.x))
+}
+
+#[inline]
+fn closest_on_segment(a: &Vector2, b: &Vector2, point: &Vector2) -> Vector2 {
+    let d = *b - *a;
+    let length_sq = d.mag_sq();
+    if length_sq == 0.0 {
+        return *a;
+    }
+
+    let t = (Vector2::dot(&(*point - *a), &d) / length_sq).clamp(0.0, 1.0);
+    *a + d * t
+}
+
+fn closest_between_segments(a: &Vector2, b: &Vector2, c: &Vector2, d: &Vector2) -> (Vector2, Vector2) {
+    // for segments that don't cross, one of the closest points is always an endpoint
+    let candidates = [
+        (*a, closest_on_segment(c, d, a)),
+        (*b, closest_on_segment(c, d, b)),
+        (closest_on_segment(a, b, c), *c),
+        (closest_on_segment(a, b, d), *d),
+    ];
+
+    let mut best = candidates[0];
+    for candidate in candidates[1..].iter() {
+        if (candidate.1 - candidate.0).mag_sq() < (best.1 - best.0).mag_sq() {
+            best = *candidate;
+        }
+    }
+
+    best
+}
+
+fn segments_intersect(a: &Vector2, b: &Vector2, c: &Vector2, d: &Vector2) -> bool {
+    let d1 = Vector2::cross(&(*b - *a), &(*c - *a));
+    let d2 = Vector2::cross(&(*b - *a), &(*d - *a));
+    let d3 = Vector2::cross(&(*d - *c), &(*a - *c));
+    let d4 = Vector2::cross(&(*d - *c), &(*b - *c));
+    if ((d1 > 0.0 && d2 < 0.0) || (d1 < 0.0 && d2 > 0.0))
+        && ((d3 > 0.0 && d4 < 0.0) || (d3 < 0.0 && d4 > 0.0))
+    {
+        return true;
+    }
+
+    // collinear and touching cases
+    (d1 == 0.0 && on_segment(a, b, c))
+        || (d2 == 0.0 && on_segment(a, b, d))
+        || (d3 == 0.0 && on_segment(c, d, a))
+        || (d4 == 0.0 && on_segment(c, d, b))
+}
+
+#[inline]
+fn on_segment(a: &Vector2, b: &Vector2, p: &Vector2) -> bool {
+    p.x >= a.x.min(b.x) && p.x <= a.x.max(b.x) && p.y >= a.y.min(b.y) && p.y <= a.y.max(b.y)
+}
+
+/// Contact between the closest points of two shapes' cores, where the shapes extend `radius`
+/// beyond their cores in total
+fn round_contact(p: &Vector2, q: &Vector2, radius: f32, fallback: &Vector2) -> Option<Contact2> {
+    let offset = *q - *p;
+    let distance = offset.mag();
+    if distance > radius {
+        return None;
+    }
+
+    let normal = if distance > 0.0 { offset / distance } else { *fallback };
+    Some(Contact2 {
+        normal,
+        depth: radius - distance,
+    })
+}
+
+/// Separating axis test between two convex point sets, where the shapes extend `radius` beyond
+/// their points in total, returning the smallest push along the provided unit axes
+fn separating_axes(
+    axes: &[Vector2],
+    a: &[Vector2],
+    b: &[Vector2],
+    radius: f32,
+) -> Option<Contact2> {
+    let mut best: Option<Contact2> = None;
+    for axis in axes.iter() {
+        let (a_min, a_max) = project(a, axis);
+        let (b_min, b_max) = project(b, axis);
+
+        // push b along the axis, or against it, whichever is shorter
+        let forward = a_max - b_min + radius;
+        let backward = b_max - a_min + radius;
+        if forward < 0.0 || backward < 0.0 {
+            return None;
+        }
+
+        let contact = if forward <= backward {
+            Contact2 { normal: *axis, depth: forward }
+        } else {
+            Contact2 { normal: -*axis, depth: backward }
+        };
+
+        let shallower = match best {
+            Some(b) => contact.depth < b.depth,
+            None => true,
+        };
+
+        if shallower {
+            best = Some(contact);
+        }
+    }
+
+    best.or(Some(Contact2 {
+        normal: Vector2::make(1.0, 0.0),
+        depth: radius,
+    }))
+}
+
+#[inline]
+fn project(points: &[Vector2], axis: &Vector2) -> (f32, f32) {
+    let mut min = f32::MAX;
+    let mut max = f32::MIN;
+    for p in points.iter() {
+        let d = Vector2::dot(p, axis);
+        min = min.min(d);
+        max = max.max(d);
+    }
+
+    (min, max)
+}
@@ -0,0 +1,111 @@
+#[cfg(not(feature = "std"))]
+use crate::math::Float;
+use crate::vector3::Vector3;
+
+use core::f32::consts::PI;
+
+/// Find the solid angle, in steradians, that a sphere covers when seen from a point
+///
+/// A point on or inside the sphere is surrounded by it, so it sees the whole sphere of directions.
+///
+/// # Examples
+/// ```
+/// use vex::solid_angle_of_sphere;
+///
+/// use std::f32::consts::PI;
+///
+/// // seen from twice its radius, a sphere fills a cap with a half angle of 30 degrees
+/// let actual = solid_angle_of_sphere(1.0, 2.0);
+/// assert!((actual - 2.0 * PI * (1.0 - 0.75f32.sqrt())).abs() < 0.0001);
+/// assert_eq!(solid_angle_of_sphere(1.0, 0.5), 4.0 * PI);
+/// ```
+#[inline]
+pub fn solid_angle_of_sphere(radius: f32, distance: f32) -> f32 {
+    if distance <= radius {
+        return 4.0 * PI;
+    }
+
+    let sin = radius / distance;
+    2.0 * PI * (1.0 - (1.0 - sin * sin).sqrt())
+}
+
+/// Find the solid angle, in steradians, that a triangle covers when seen from the origin
+///
+/// Uses the Van Oosterom-Strackee formula. The result doesn't depend on the winding, so subtract
+/// the viewer's position from each corner to measure from anywhere else.
+///
+/// # Examples
+/// ```
+/// use vex::Vector3;
+/// use vex::solid_angle_of_triangle;
+///
+/// use std::f32::consts::PI;
+///
+/// // one of the eight octants
+/// let a = Vector3::make(1.0, 0.0, 0.0);
+/// let b = Vector3::make(0.0, 1.0, 0.0);
+/// let c = Vector3::make(0.0, 0.0, 1.0);
+/// assert!((solid_angle_of_triangle(&a, &b, &c) - PI / 2.0).abs() < 0.0001);
+/// assert!((solid_angle_of_triangle(&a, &c, &b) - PI / 2.0).abs() < 0.0001);
+///
+/// // a triangle seen edge-on covers nothing
+/// let d = Vector3::make(1.0, 1.0, 0.0);
+/// assert_eq!(solid_angle_of_triangle(&a, &b, &d), 0.0);
+/// ```
+pub fn solid_angle_of_triangle(a: &Vector3, b: &Vector3, c: &Vector3) -> f32 {
+    let (la, lb, lc) = (a.mag(), b.mag(), c.mag());
+    let triple = Vector3::dot(a, &Vector3::cross(b, c)).abs();
+    let denominator = la * lb * lc
+        + Vector3::dot(a, b) * lc
+        + Vector3::dot(a, c) * lb
+        + Vector3::dot(b, c) * la;
+
+    2.0 * triple.atan2(denominator)
+}
+
+/// Find the solid angle, in steradians, of a spherical cap with the given half angle
+///
+/// # Examples
+/// ```
+/// use vex::spherical_cap_solid_angle;
+///
+/// use std::f32::consts::PI;
+///
+/// assert!((spherical_cap_solid_angle(PI / 2.0) - 2.0 * PI).abs() < 0.0001);
+/// assert!((spherical_cap_solid_angle(PI) - 4.0 * PI).abs() < 0.0001);
+/// ```
+#[inline]
+pub fn spherical_cap_solid_angle(half_angle: f32) -> f32 {
+    2.0 * PI * (1.0 - half_angle.cos())
+}
+
+/// Find the surface area of a spherical cap from the sphere's radius and the cap's height
+///
+/// # Examples
+/// ```
+/// use vex::spherical_cap_area;
+///
+/// use std::f32::consts::PI;
+///
+/// // a hemisphere
+/// assert_eq!(spherical_cap_area(2.0, 2.0), 8.0 * PI);
+/// ```
+#[inline]
+pub fn spherical_cap_area(radius: f32, height: f32) -> f32 {
+    2.0 * PI * radius * height
+}
+
+/// Find the height of a spherical cap from the sphere's radius and the cap's half angle
+///
+/// # Examples
+/// ```
+/// use vex::spherical_cap_height;
+///
+/// use std::f32::consts::PI;
+///
+/// assert!((spherical_cap_height(2.0, PI / 3.0) - 1.0).abs() < 0.0001);
+/// ```
+#[inline]
+pub fn spherical_cap_height(radius: f32, half_angle: f32) -> f32 {
+    radius * (1.0 - half_angle.cos())
+}
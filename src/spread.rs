@@ -0,0 +1,186 @@
+use alloc::vec::Vec;
+use core::f32::consts::PI;
+
+#[cfg(not(feature = "std"))]
+use crate::math::Float;
+use crate::sequence;
+use crate::vector2::Vector2;
+use crate::vector3::Vector3;
+
+/// How directions are distributed inside a spread cone
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum SpreadDistribution {
+    /// Every direction inside the cone is equally likely
+    Uniform,
+    /// Directions cluster around the center with a standard deviation of a third of the cone's
+    /// angle, and are clamped to the cone
+    Gaussian,
+}
+
+/// Find a direction inside a cone around `forward` from two uniform random numbers in `[0, 1)`
+///
+/// `angle` is the largest deviation from `forward` in radians, so the cone is `2 * angle` wide.
+/// `forward` doesn't need to be normalized, but the result always is.
+///
+/// # Examples
+/// ```
+/// use vex::SpreadDistribution;
+/// use vex::Vector3;
+/// use vex::spread_direction;
+///
+/// let forward = Vector3::make(0.0, 0.0, -2.0);
+/// let actual = spread_direction(&forward, 0.1, SpreadDistribution::Uniform, 0.0, 0.25);
+/// assert!((actual - Vector3::make(0.0, 0.0, -1.0)).mag() < 0.000001);
+///
+/// let actual = spread_direction(&forward, 0.1, SpreadDistribution::Uniform, 0.999, 0.25);
+/// let angle = Vector3::dot(&actual, &Vector3::make(0.0, 0.0, -1.0)).acos();
+/// assert!(angle > 0.099 && angle <= 0.1);
+/// ```
+pub fn spread_direction(
+    forward: &Vector3,
+    angle: f32,
+    distribution: SpreadDistribution,
+    u: f32,
+    v: f32,
+) -> Vector3 {
+    let forward = forward.normalized();
+    let theta = match distribution {
+        SpreadDistribution::Uniform => {
+            // uniform over the spherical cap rather than over the angle
+            let cos_theta = 1.0 - u * (1.0 - angle.cos());
+            cos_theta.clamp(-1.0, 1.0).acos()
+        }
+        SpreadDistribution::Gaussian => {
+            let radius = (-2.0 * (1.0 - u).ln()).sqrt();
+            (radius * angle / 3.0).min(angle)
+        }
+    };
+
+    let phi = 2.0 * PI * v;
+    let tangent = Vector3::perpendicular(&forward);
+    let bitangent = Vector3::cross(&forward, &tangent);
+    let (sin_theta, cos_theta) = theta.sin_cos();
+    let (sin_phi, cos_phi) = phi.sin_cos();
+    (forward * cos_theta + (tangent * cos_phi + bitangent * sin_phi) * sin_theta).normalized()
+}
+
+/// Generates `count` directions inside a cone around `forward`
+///
+/// The directions are deterministic for a given seed, so replays and networked clients can
+/// reproduce the same spread. See [`spread_direction`](fn.spread_direction.html) for how the cone
+/// is defined.
+///
+/// # Examples
+/// ```
+/// use vex::SpreadDistribution;
+/// use vex::Vector3;
+/// use vex::spread_directions;
+///
+/// let forward = Vector3::make(0.0, 0.0, -1.0);
+/// let actual = spread_directions(&forward, 0.05, 8, SpreadDistribution::Gaussian, 42);
+/// assert_eq!(actual.len(), 8);
+/// assert_eq!(actual, spread_directions(&forward, 0.05, 8, SpreadDistribution::Gaussian, 42));
+/// assert_ne!(actual, spread_directions(&forward, 0.05, 8, SpreadDistribution::Gaussian, 7));
+///
+/// for direction in actual.iter() {
+///     assert!(Vector3::dot(direction, &forward) >= (0.05f32).cos() - 0.000001);
+/// }
+/// ```
+pub fn spread_directions(
+    forward: &Vector3,
+    angle: f32,
+    count: usize,
+    distribution: SpreadDistribution,
+    seed: u32,
+) -> Vec<Vector3> {
+    (0..count as u32)
+        .map(|i| {
+            let u = random(seed, 2 * i);
+            let v = random(seed, 2 * i + 1);
+            spread_direction(forward, angle, distribution, u, v)
+        })
+        .collect()
+}
+
+/// A sequence of per-shot recoil kicks with optional random jitter
+///
+/// Offsets are in whatever units the caller applies them in, such as pitch and yaw in degrees.
+/// Shots past the end of the pattern repeat its last offset.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct RecoilPattern {
+    pub offsets: Vec<Vector2>,
+    pub jitter: f32,
+}
+
+impl RecoilPattern {
+    /// Creates a pattern from per-shot offsets and the radius of the jitter added to each shot
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::RecoilPattern;
+    /// use vex::Vector2;
+    ///
+    /// let pattern = RecoilPattern::make(vec![Vector2::make(0.0, 1.0)], 0.5);
+    /// assert_eq!(pattern.offsets.len(), 1);
+    /// assert_eq!(pattern.jitter, 0.5);
+    /// ```
+    #[inline]
+    pub fn make(offsets: Vec<Vector2>, jitter: f32) -> RecoilPattern {
+        RecoilPattern { offsets, jitter }
+    }
+
+    /// Find the kick for a shot, including its jitter
+    ///
+    /// The jitter is uniform over a disk of radius `jitter`, and is deterministic for a given seed
+    /// and shot.
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::RecoilPattern;
+    /// use vex::Vector2;
+    ///
+    /// let pattern = RecoilPattern::make(vec![Vector2::make(0.0, 1.0), Vector2::make(0.5, 1.5)], 0.0);
+    /// assert_eq!(pattern.sample(0, 1), Vector2::make(0.0, 1.0));
+    /// assert_eq!(pattern.sample(5, 1), Vector2::make(0.5, 1.5));
+    ///
+    /// let pattern = RecoilPattern::make(vec![Vector2::make(0.0, 1.0)], 0.25);
+    /// let kick = pattern.sample(3, 9);
+    /// assert!((kick - Vector2::make(0.0, 1.0)).mag() <= 0.25);
+    /// assert_eq!(kick, pattern.sample(3, 9));
+    /// ```
+    pub fn sample(&self, shot: usize, seed: u32) -> Vector2 {
+        let base = match self.offsets.last() {
+            Some(last) => *self.offsets.get(shot).unwrap_or(last),
+            None => Vector2::new(),
+        };
+
+        if self.jitter <= 0.0 {
+            return base;
+        }
+
+        let i = shot as u32;
+        let radius = self.jitter * random(seed, 2 * i).sqrt();
+        let (sine, cosine) = (2.0 * PI * random(seed, 2 * i + 1)).sin_cos();
+        base + Vector2::make(cosine, sine) * radius
+    }
+
+    /// Find the total kick after firing shots `0` through `shot`
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::RecoilPattern;
+    /// use vex::Vector2;
+    ///
+    /// let pattern = RecoilPattern::make(vec![Vector2::make(0.0, 1.0), Vector2::make(0.5, 1.5)], 0.0);
+    /// assert_eq!(pattern.accumulated(0, 1), Vector2::make(0.0, 1.0));
+    /// assert_eq!(pattern.accumulated(2, 1), Vector2::make(1.0, 4.0));
+    /// ```
+    pub fn accumulated(&self, shot: usize, seed: u32) -> Vector2 {
+        (0..=shot).fold(Vector2::new(), |total, i| total + self.sample(i, seed))
+    }
+}
+
+#[inline]
+fn random(seed: u32, index: u32) -> f32 {
+    sequence::to_unit(sequence::hash(seed.wrapping_add(sequence::hash(index))))
+}
@@ -0,0 +1,241 @@
+#[cfg(not(feature = "std"))]
+use crate::math::Float;
+use crate::vector2::Vector2;
+use crate::vector3::Vector3;
+
+/// Clamps a value to a range, easing into the limits instead of stopping abruptly
+///
+/// Values further than `softness` from both limits pass through unchanged, and values within
+/// `softness` of a limit approach it exponentially without reaching it, so the curve stays smooth.
+/// A `softness` of 0 (or less) is a hard clamp, and softness is capped at half the range.
+///
+/// # Examples
+/// ```
+/// use vex::soft_clamp;
+///
+/// assert_eq!(soft_clamp(5.0, 0.0, 10.0, 2.0), 5.0);
+/// assert_eq!(soft_clamp(8.0, 0.0, 10.0, 2.0), 8.0);
+///
+/// let actual = soft_clamp(9.0, 0.0, 10.0, 2.0);
+/// assert!(actual > 8.0 && actual < 9.0);
+///
+/// let actual = soft_clamp(100.0, 0.0, 10.0, 2.0);
+/// assert!(actual > 9.99 && actual <= 10.0);
+/// assert_eq!(soft_clamp(-3.0, 0.0, 10.0, 0.0), 0.0);
+/// ```
+pub fn soft_clamp(value: f32, min: f32, max: f32, softness: f32) -> f32 {
+    let softness = softness.min((max - min) / 2.0);
+    if softness <= 0.0 {
+        return value.clamp(min, max);
+    }
+
+    let upper = max - softness;
+    let lower = min + softness;
+    if value > upper {
+        upper + softness * (1.0 - (-(value - upper) / softness).exp())
+    } else if value < lower {
+        lower - softness * (1.0 - (-(lower - value) / softness).exp())
+    } else {
+        value
+    }
+}
+
+/// Moves a value towards a target with a critically damped spring
+///
+/// `velocity` carries the spring's state between calls, and `smooth_time` is roughly the time it
+/// takes to reach the target. The spring is solved exactly, so it's stable for any time step.
+///
+/// # Examples
+/// ```
+/// use vex::smooth_damp;
+///
+/// let mut value = 0.0;
+/// let mut velocity = 0.0;
+/// for _ in 0..120 {
+///     value = smooth_damp(value, 10.0, &mut velocity, 0.25, 1.0 / 60.0);
+///     assert!(value <= 10.0);
+/// }
+///
+/// assert!((value - 10.0).abs() < 0.01);
+/// ```
+#[inline]
+pub fn smooth_damp(current: f32, target: f32, velocity: &mut f32, smooth_time: f32, dt: f32) -> f32 {
+    let (offset, next_velocity) = critically_damped(current - target, *velocity, smooth_time, dt);
+    *velocity = next_velocity;
+    target + offset
+}
+
+/// A scalar critically damped spring
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct Spring {
+    pub position: f32,
+    pub velocity: f32,
+}
+
+impl Spring {
+    /// Creates a spring at rest at the provided position
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Spring;
+    ///
+    /// let actual = Spring::new(2.0);
+    /// assert_eq!(actual.position, 2.0);
+    /// assert_eq!(actual.velocity, 0.0);
+    /// ```
+    #[inline]
+    pub const fn new(position: f32) -> Spring {
+        Spring {
+            position,
+            velocity: 0.0,
+        }
+    }
+
+    /// Advances the spring towards a target, returning the new position
+    ///
+    /// See [`smooth_damp`](fn.smooth_damp.html) for how the spring behaves.
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Spring;
+    ///
+    /// let mut spring = Spring::new(0.0);
+    /// spring.update(10.0, 0.25, 0.1);
+    /// assert!(spring.position > 0.0 && spring.velocity > 0.0);
+    ///
+    /// for _ in 0..100 {
+    ///     spring.update(10.0, 0.25, 0.1);
+    /// }
+    ///
+    /// assert!((spring.position - 10.0).abs() < 0.0001);
+    /// ```
+    #[inline]
+    pub fn update(&mut self, target: f32, smooth_time: f32, dt: f32) -> f32 {
+        self.position = smooth_damp(self.position, target, &mut self.velocity, smooth_time, dt);
+        self.position
+    }
+}
+
+/// A 2D critically damped spring
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct Spring2 {
+    pub position: Vector2,
+    pub velocity: Vector2,
+}
+
+impl Spring2 {
+    /// Creates a spring at rest at the provided position
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Spring2;
+    /// use vex::Vector2;
+    ///
+    /// let actual = Spring2::new(Vector2::make(1.0, 2.0));
+    /// assert_eq!(actual.position, Vector2::make(1.0, 2.0));
+    /// assert_eq!(actual.velocity, Vector2::new());
+    /// ```
+    #[inline]
+    pub const fn new(position: Vector2) -> Spring2 {
+        Spring2 {
+            position,
+            velocity: Vector2::new(),
+        }
+    }
+
+    /// Advances the spring towards a target, returning the new position
+    ///
+    /// Each component behaves like [`smooth_damp`](fn.smooth_damp.html).
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Spring2;
+    /// use vex::Vector2;
+    ///
+    /// let target = Vector2::make(4.0, -2.0);
+    /// let mut spring = Spring2::new(Vector2::new());
+    /// for _ in 0..100 {
+    ///     spring.update(&target, 0.25, 0.1);
+    /// }
+    ///
+    /// assert!((spring.position - target).mag() < 0.0001);
+    /// ```
+    #[inline]
+    pub fn update(&mut self, target: &Vector2, smooth_time: f32, dt: f32) -> Vector2 {
+        for i in 0..2 {
+            let (offset, velocity) =
+                critically_damped(self.position[i] - target[i], self.velocity[i], smooth_time, dt);
+            self.position[i] = target[i] + offset;
+            self.velocity[i] = velocity;
+        }
+
+        self.position
+    }
+}
+
+/// A 3D critically damped spring
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct Spring3 {
+    pub position: Vector3,
+    pub velocity: Vector3,
+}
+
+impl Spring3 {
+    /// Creates a spring at rest at the provided position
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Spring3;
+    /// use vex::Vector3;
+    ///
+    /// let actual = Spring3::new(Vector3::make(1.0, 2.0, 3.0));
+    /// assert_eq!(actual.position, Vector3::make(1.0, 2.0, 3.0));
+    /// assert_eq!(actual.velocity, Vector3::new());
+    /// ```
+    #[inline]
+    pub const fn new(position: Vector3) -> Spring3 {
+        Spring3 {
+            position,
+            velocity: Vector3::new(),
+        }
+    }
+
+    /// Advances the spring towards a target, returning the new position
+    ///
+    /// Each component behaves like [`smooth_damp`](fn.smooth_damp.html).
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Spring3;
+    /// use vex::Vector3;
+    ///
+    /// let target = Vector3::make(4.0, -2.0, 1.0);
+    /// let mut spring = Spring3::new(Vector3::new());
+    /// for _ in 0..100 {
+    ///     spring.update(&target, 0.25, 0.1);
+    /// }
+    ///
+    /// assert!((spring.position - target).mag() < 0.0001);
+    /// ```
+    #[inline]
+    pub fn update(&mut self, target: &Vector3, smooth_time: f32, dt: f32) -> Vector3 {
+        for i in 0..3 {
+            let (offset, velocity) =
+                critically_damped(self.position[i] - target[i], self.velocity[i], smooth_time, dt);
+            self.position[i] = target[i] + offset;
+            self.velocity[i] = velocity;
+        }
+
+        self.position
+    }
+}
+
+/// Exact solution of a critically damped spring, returning the new offset from the target and
+/// the new velocity
+#[inline]
+fn critically_damped(offset: f32, velocity: f32, smooth_time: f32, dt: f32) -> (f32, f32) {
+    let omega = 2.0 / smooth_time.max(f32::EPSILON);
+    let decay = (-omega * dt).exp();
+    let temp = (velocity + omega * offset) * dt;
+    ((offset + temp) * decay, (velocity - omega * temp) * decay)
+}
@@ -0,0 +1,59 @@
+/// Matrix elements laid out one column after another
+///
+/// This is the layout `Matrix4` uses internally, and the one OpenGL and Vulkan expect.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct ColumnMajor(pub [f32; 16]);
+
+/// Matrix elements laid out one row after another
+///
+/// This is the layout used by DirectX math libraries and by C arrays written out as rows.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct RowMajor(pub [f32; 16]);
+
+impl From<RowMajor> for ColumnMajor {
+    /// Transposes row-major elements into column-major order
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::ColumnMajor;
+    /// use vex::RowMajor;
+    ///
+    /// let rows = RowMajor([1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0, 10.0, 11.0, 12.0, 13.0, 14.0, 15.0, 16.0]);
+    /// let actual = ColumnMajor::from(rows);
+    /// let expected = ColumnMajor([1.0, 5.0, 9.0, 13.0, 2.0, 6.0, 10.0, 14.0, 3.0, 7.0, 11.0, 15.0, 4.0, 8.0, 12.0, 16.0]);
+    /// assert_eq!(actual, expected);
+    /// ```
+    #[inline]
+    fn from(rows: RowMajor) -> ColumnMajor {
+        ColumnMajor(transpose(&rows.0))
+    }
+}
+
+impl From<ColumnMajor> for RowMajor {
+    /// Transposes column-major elements into row-major order
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::ColumnMajor;
+    /// use vex::RowMajor;
+    ///
+    /// let columns = ColumnMajor([1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0, 10.0, 11.0, 12.0, 13.0, 14.0, 15.0, 16.0]);
+    /// let actual = RowMajor::from(columns);
+    /// let expected = RowMajor([1.0, 5.0, 9.0, 13.0, 2.0, 6.0, 10.0, 14.0, 3.0, 7.0, 11.0, 15.0, 4.0, 8.0, 12.0, 16.0]);
+    /// assert_eq!(actual, expected);
+    /// ```
+    #[inline]
+    fn from(columns: ColumnMajor) -> RowMajor {
+        RowMajor(transpose(&columns.0))
+    }
+}
+
+#[inline]
+fn transpose(m: &[f32; 16]) -> [f32; 16] {
+    let mut result = [0.0; 16];
+    for (i, value) in m.iter().enumerate() {
+        result[(i % 4) * 4 + i / 4] = *value;
+    }
+
+    result
+}
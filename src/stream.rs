@@ -0,0 +1,129 @@
+use crate::common::Matrix;
+use crate::matrix4::Matrix4;
+use crate::vector3::Vector3;
+
+use std::io::{self, ErrorKind, Read, Write};
+
+// the number of points transformed at a time, which bounds the memory used by a stream
+const CHUNK_POINTS: usize = 4096;
+const POINT_BYTES: usize = 12;
+
+/// Transforms a stream of positions by a matrix, returning the number of positions transformed
+///
+/// Positions are read from `reader` as tightly packed little-endian `f32` triples, the layout of a
+/// `[Vector3]` on little-endian targets, and written to `writer` in the same layout. They are
+/// processed a fixed-size chunk at a time, so a stream of any length is transformed without
+/// holding more than one chunk in memory. Short reads are handled, and reads interrupted by a
+/// signal are retried.
+///
+/// Returns an error of kind `InvalidData` if the stream ends partway through a position, after
+/// every complete position before it has been written.
+///
+/// # Examples
+/// ```
+/// use vex::Matrix4;
+/// use vex::transform_point_stream;
+///
+/// let mut input = Vec::new();
+/// for value in [1.0f32, 2.0, 3.0, -1.0, 0.0, 0.5].iter() {
+///     input.extend_from_slice(&value.to_le_bytes());
+/// }
+///
+/// let mut output = Vec::new();
+/// let m = Matrix4::translate(10.0, 0.0, 0.0);
+/// let count = transform_point_stream(&input[..], &mut output, &m).unwrap();
+/// assert_eq!(count, 2);
+///
+/// let values: Vec<f32> = output
+///     .chunks(4)
+///     .map(|bytes| f32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
+///     .collect();
+/// assert_eq!(values, [11.0, 2.0, 3.0, 9.0, 0.0, 0.5]);
+///
+/// let truncated = transform_point_stream(&input[..10], &mut Vec::new(), &m);
+/// assert_eq!(truncated.unwrap_err().kind(), std::io::ErrorKind::InvalidData);
+/// ```
+///
+/// ```
+/// use std::io::Read;
+/// use vex::Matrix4;
+/// use vex::transform_point_stream;
+///
+/// // a reader that hands out a few bytes at a time, like a slow socket
+/// struct Trickle<'a>(&'a [u8]);
+///
+/// impl<'a> Read for Trickle<'a> {
+///     fn read(&mut self, buffer: &mut [u8]) -> std::io::Result<usize> {
+///         let count = self.0.len().min(buffer.len()).min(7);
+///         buffer[..count].copy_from_slice(&self.0[..count]);
+///         self.0 = &self.0[count..];
+///         Ok(count)
+///     }
+/// }
+///
+/// let input: Vec<u8> = (0..9999).flat_map(|i| (i as f32).to_le_bytes().to_vec()).collect();
+/// let mut output = Vec::new();
+/// let m = Matrix4::scale(2.0, 2.0, 2.0);
+/// let count = transform_point_stream(Trickle(&input), &mut output, &m).unwrap();
+/// assert_eq!(count, 3333);
+/// assert_eq!(output.len(), 3333 * 12);
+/// assert_eq!(output[(3332 * 12)..(3332 * 12 + 4)], (2.0f32 * 9996.0).to_le_bytes());
+/// ```
+pub fn transform_point_stream<R: Read, W: Write>(
+    mut reader: R,
+    mut writer: W,
+    m: &Matrix4,
+) -> io::Result<u64> {
+    let mut buffer = vec![0u8; CHUNK_POINTS * POINT_BYTES];
+    let mut count = 0u64;
+    let mut filled = 0;
+
+    loop {
+        let read = match reader.read(&mut buffer[filled..]) {
+            Ok(read) => read,
+            Err(ref e) if e.kind() == ErrorKind::Interrupted => continue,
+            Err(e) => return Err(e),
+        };
+
+        filled += read;
+        if filled < buffer.len() && read != 0 {
+            continue;
+        }
+
+        let whole = filled - filled % POINT_BYTES;
+        for bytes in buffer[..whole].chunks_exact_mut(POINT_BYTES) {
+            let point = m.transform_point(&read_point(bytes));
+            write_point(bytes, &point);
+        }
+
+        writer.write_all(&buffer[..whole])?;
+        count += (whole / POINT_BYTES) as u64;
+
+        if read == 0 {
+            if filled != whole {
+                let message = "stream ended partway through a position";
+                return Err(io::Error::new(ErrorKind::InvalidData, message));
+            }
+
+            return Ok(count);
+        }
+
+        filled = 0;
+    }
+}
+
+#[inline]
+fn read_point(bytes: &[u8]) -> Vector3 {
+    let element = |i: usize| {
+        f32::from_le_bytes([bytes[i], bytes[i + 1], bytes[i + 2], bytes[i + 3]])
+    };
+
+    Vector3::make(element(0), element(4), element(8))
+}
+
+#[inline]
+fn write_point(bytes: &mut [u8], point: &Vector3) {
+    bytes[0..4].copy_from_slice(&point.x.to_le_bytes());
+    bytes[4..8].copy_from_slice(&point.y.to_le_bytes());
+    bytes[8..12].copy_from_slice(&point.z.to_le_bytes());
+}
@@ -0,0 +1,159 @@
+//! Shader-style swizzles, reading or writing vector components in any order
+//!
+//! Every ordering of two or more distinct components is covered, like `Vector3::zx` and
+//! `Vector4::xyz`, along with a `set_` counterpart that writes a vector's components back in the
+//! same order.
+//!
+//! # Examples
+//! ```
+//! use vex::Vector2;
+//! use vex::Vector3;
+//!
+//! let mut v = Vector3::make(1.0, 2.0, 3.0);
+//! assert_eq!(v.xy(), Vector2::make(1.0, 2.0));
+//! assert_eq!(v.zyx(), Vector3::make(3.0, 2.0, 1.0));
+//!
+//! v.set_zx(&Vector2::make(5.0, 6.0));
+//! assert_eq!(v, Vector3::make(6.0, 2.0, 5.0));
+//! ```
+
+use crate::vector2::Vector2;
+use crate::vector3::Vector3;
+use crate::vector4::Vector4;
+
+// generates a getter and setter for each listed ordering of components
+macro_rules! swizzle {
+    ($t:ident, $sample:expr, { $($get:ident, $set:ident => $out:ident($($c:ident),+);)+ }) => {
+        impl $t {
+            $(
+                #[doc = concat!("Gets the `", stringify!($get), "` components as a `", stringify!($out), "`")]
+                #[doc = ""]
+                #[doc = "# Examples"]
+                #[doc = "```"]
+                #[doc = "use vex::{Vector2, Vector3, Vector4};"]
+                #[doc = ""]
+                #[doc = concat!("let v = ", $sample, ";")]
+                #[doc = concat!("assert_eq!(v.", stringify!($get), "(), ", stringify!($out), "::make(", stringify!($(v.$c),+), "));")]
+                #[doc = "```"]
+                #[inline]
+                pub fn $get(&self) -> $out {
+                    $out::make($(self.$c),+)
+                }
+
+                #[doc = concat!("Set the `", stringify!($get), "` components from a `", stringify!($out), "`")]
+                #[doc = ""]
+                #[doc = "# Examples"]
+                #[doc = "```"]
+                #[doc = "use vex::{Vector2, Vector3, Vector4};"]
+                #[doc = ""]
+                #[doc = concat!("let mut v = ", stringify!($t), "::new();")]
+                #[doc = concat!("let value = ", swizzle!(@value $out), ";")]
+                #[doc = concat!("v.", stringify!($set), "(&value);")]
+                #[doc = concat!("assert_eq!(v.", stringify!($get), "(), value);")]
+                #[doc = "```"]
+                #[inline]
+                pub fn $set(&mut self, v: &$out) {
+                    swizzle!(@set self, v, $($c),+);
+                }
+            )+
+        }
+    };
+    (@value Vector2) => { "Vector2::make(5.0, 6.0)" };
+    (@value Vector3) => { "Vector3::make(5.0, 6.0, 7.0)" };
+    (@value Vector4) => { "Vector4::make(5.0, 6.0, 7.0, 8.0)" };
+    (@set $target:expr, $v:ident, $a:ident, $b:ident) => {
+        $target.$a = $v.x;
+        $target.$b = $v.y;
+    };
+    (@set $target:expr, $v:ident, $a:ident, $b:ident, $c:ident) => {
+        $target.$a = $v.x;
+        $target.$b = $v.y;
+        $target.$c = $v.z;
+    };
+    (@set $target:expr, $v:ident, $a:ident, $b:ident, $c:ident, $d:ident) => {
+        $target.$a = $v.x;
+        $target.$b = $v.y;
+        $target.$c = $v.z;
+        $target.$d = $v.w;
+    };
+}
+
+swizzle!(Vector2, "Vector2::make(1.0, 2.0)", {
+    yx, set_yx => Vector2(y, x);
+});
+
+swizzle!(Vector3, "Vector3::make(1.0, 2.0, 3.0)", {
+    xy, set_xy => Vector2(x, y);
+    xz, set_xz => Vector2(x, z);
+    yx, set_yx => Vector2(y, x);
+    yz, set_yz => Vector2(y, z);
+    zx, set_zx => Vector2(z, x);
+    zy, set_zy => Vector2(z, y);
+    xzy, set_xzy => Vector3(x, z, y);
+    yxz, set_yxz => Vector3(y, x, z);
+    yzx, set_yzx => Vector3(y, z, x);
+    zxy, set_zxy => Vector3(z, x, y);
+    zyx, set_zyx => Vector3(z, y, x);
+});
+
+swizzle!(Vector4, "Vector4::make(1.0, 2.0, 3.0, 4.0)", {
+    xy, set_xy => Vector2(x, y);
+    xz, set_xz => Vector2(x, z);
+    xw, set_xw => Vector2(x, w);
+    yx, set_yx => Vector2(y, x);
+    yz, set_yz => Vector2(y, z);
+    yw, set_yw => Vector2(y, w);
+    zx, set_zx => Vector2(z, x);
+    zy, set_zy => Vector2(z, y);
+    zw, set_zw => Vector2(z, w);
+    wx, set_wx => Vector2(w, x);
+    wy, set_wy => Vector2(w, y);
+    wz, set_wz => Vector2(w, z);
+    xyz, set_xyz => Vector3(x, y, z);
+    xyw, set_xyw => Vector3(x, y, w);
+    xzy, set_xzy => Vector3(x, z, y);
+    xzw, set_xzw => Vector3(x, z, w);
+    xwy, set_xwy => Vector3(x, w, y);
+    xwz, set_xwz => Vector3(x, w, z);
+    yxz, set_yxz => Vector3(y, x, z);
+    yxw, set_yxw => Vector3(y, x, w);
+    yzx, set_yzx => Vector3(y, z, x);
+    yzw, set_yzw => Vector3(y, z, w);
+    ywx, set_ywx => Vector3(y, w, x);
+    ywz, set_ywz => Vector3(y, w, z);
+    zxy, set_zxy => Vector3(z, x, y);
+    zxw, set_zxw => Vector3(z, x, w);
+    zyx, set_zyx => Vector3(z, y, x);
+    zyw, set_zyw => Vector3(z, y, w);
+    zwx, set_zwx => Vector3(z, w, x);
+    zwy, set_zwy => Vector3(z, w, y);
+    wxy, set_wxy => Vector3(w, x, y);
+    wxz, set_wxz => Vector3(w, x, z);
+    wyx, set_wyx => Vector3(w, y, x);
+    wyz, set_wyz => Vector3(w, y, z);
+    wzx, set_wzx => Vector3(w, z, x);
+    wzy, set_wzy => Vector3(w, z, y);
+    xywz, set_xywz => Vector4(x, y, w, z);
+    xzyw, set_xzyw => Vector4(x, z, y, w);
+    xzwy, set_xzwy => Vector4(x, z, w, y);
+    xwyz, set_xwyz => Vector4(x, w, y, z);
+    xwzy, set_xwzy => Vector4(x, w, z, y);
+    yxzw, set_yxzw => Vector4(y, x, z, w);
+    yxwz, set_yxwz => Vector4(y, x, w, z);
+    yzxw, set_yzxw => Vector4(y, z, x, w);
+    yzwx, set_yzwx => Vector4(y, z, w, x);
+    ywxz, set_ywxz => Vector4(y, w, x, z);
+    ywzx, set_ywzx => Vector4(y, w, z, x);
+    zxyw, set_zxyw => Vector4(z, x, y, w);
+    zxwy, set_zxwy => Vector4(z, x, w, y);
+    zyxw, set_zyxw => Vector4(z, y, x, w);
+    zywx, set_zywx => Vector4(z, y, w, x);
+    zwxy, set_zwxy => Vector4(z, w, x, y);
+    zwyx, set_zwyx => Vector4(z, w, y, x);
+    wxyz, set_wxyz => Vector4(w, x, y, z);
+    wxzy, set_wxzy => Vector4(w, x, z, y);
+    wyxz, set_wyxz => Vector4(w, y, x, z);
+    wyzx, set_wyzx => Vector4(w, y, z, x);
+    wzxy, set_wzxy => Vector4(w, z, x, y);
+    wzyx, set_wzyx => Vector4(w, z, y, x);
+});
@@ -0,0 +1,157 @@
+//! Checks that a type's operators agree with each other
+//!
+//! Each check panics with the failing inputs when an identity doesn't hold, so they can be called
+//! straight from a test. They work for any type implementing [`Vector`](../trait.Vector.html) or
+//! [`SquareMatrix`](../trait.SquareMatrix.html), including wrapper types built on this crate.
+//! Floating point error grows with the magnitude of the samples, so `epsilon` should be chosen to
+//! match them.
+
+use crate::common::{Matrix, SquareMatrix, Vector};
+
+use core::fmt::Debug;
+
+// panics with the identity and its inputs when two sides of an identity aren't within epsilon
+macro_rules! assert_close {
+    ($actual:expr, $expected:expr, $epsilon:expr, $identity:expr, $($input:expr),+) => {{
+        let actual = $actual;
+        let expected = $expected;
+        assert!(
+            actual.approx_eq(&expected, $epsilon),
+            "{} failed for {:?}: {:?} != {:?}",
+            $identity,
+            ($($input,)+),
+            actual,
+            expected,
+        );
+    }};
+}
+
+/// Checks the vector space identities for every combination of the samples
+///
+/// These are associativity and commutativity of addition, the zero vector being the additive
+/// identity, negation being the additive inverse, scaling by 1 being the identity, and the dot
+/// product being symmetric and non-negative for a vector with itself.
+///
+/// # Examples
+/// ```
+/// use vex::Vector3;
+/// use vex::test_utils::check_vector_identities;
+///
+/// let samples = [
+///     Vector3::make(1.0, 2.0, 3.0),
+///     Vector3::make(-0.5, 4.0, 0.25),
+///     Vector3::make(0.0, -3.0, 8.0),
+/// ];
+/// check_vector_identities(&samples, 0.0001);
+/// ```
+pub fn check_vector_identities<V: Vector + Debug>(samples: &[V], epsilon: f32) {
+    for a in samples.iter() {
+        assert_close!(*a + V::zero(), *a, epsilon, "a + 0 == a", a);
+        assert_close!(V::zero() + *a, *a, epsilon, "0 + a == a", a);
+        assert_close!(*a + -*a, V::zero(), epsilon, "a + -a == 0", a);
+        #[allow(clippy::eq_op)]
+        let difference = *a - *a;
+        assert_close!(difference, V::zero(), epsilon, "a - a == 0", a);
+        assert_close!(*a * 1.0, *a, epsilon, "a * 1 == a", a);
+        assert!(V::dot(a, a) >= 0.0, "a . a >= 0 failed for {:?}", (a,));
+
+        for b in samples.iter() {
+            assert_close!(*a + *b, *b + *a, epsilon, "a + b == b + a", a, b);
+            assert_close!(*a - *b, *a + -*b, epsilon, "a - b == a + -b", a, b);
+            let (lhs, rhs) = (V::dot(a, b), V::dot(b, a));
+            assert!((lhs - rhs).abs() <= epsilon, "a . b == b . a failed for {:?}", (a, b));
+
+            for c in samples.iter() {
+                let lhs = (*a + *b) + *c;
+                let rhs = *a + (*b + *c);
+                assert_close!(lhs, rhs, epsilon, "(a + b) + c == a + (b + c)", a, b, c);
+            }
+        }
+    }
+}
+
+/// Checks the matrix identities for every combination of the samples
+///
+/// These are associativity of multiplication, the identity matrix being the multiplicative
+/// identity, transposing twice being the identity, inverses multiplying to the identity for the
+/// samples that are invertible, and the determinant of a product being the product of the
+/// determinants.
+///
+/// # Examples
+/// ```
+/// use vex::Matrix3;
+/// use vex::test_utils::check_matrix_identities;
+///
+/// let samples = [
+///     Matrix3::make(1.0, 2.0, 0.0, 0.0, 1.0, 3.0, 2.0, 0.0, 1.0),
+///     Matrix3::rotate_2d(0.5),
+///     Matrix3::make(1.0, 2.0, 3.0, 2.0, 4.0, 6.0, 0.0, 1.0, 0.0),
+/// ];
+/// check_matrix_identities(&samples, 0.0001);
+/// ```
+pub fn check_matrix_identities<M: SquareMatrix + Debug>(samples: &[M], epsilon: f32) {
+    let identity = M::identity();
+    for a in samples.iter() {
+        assert_close!(*a * identity, *a, epsilon, "a * I == a", a);
+        assert_close!(identity * *a, *a, epsilon, "I * a == a", a);
+        assert_close!(a.transposed().transposed(), *a, epsilon, "(a^T)^T == a", a);
+
+        if let Some(inverse) = a.try_inverse() {
+            assert_close!(*a * inverse, identity, epsilon, "a * a^-1 == I", a);
+            assert_close!(inverse * *a, identity, epsilon, "a^-1 * a == I", a);
+        }
+
+        for b in samples.iter() {
+            let lhs = (*a * *b).determinant();
+            let rhs = a.determinant() * b.determinant();
+            assert!(
+                (lhs - rhs).abs() <= epsilon * rhs.abs().max(1.0),
+                "det(a * b) == det(a) * det(b) failed for {:?}: {:?} != {:?}",
+                (a, b),
+                lhs,
+                rhs,
+            );
+
+            for c in samples.iter() {
+                let lhs = (*a * *b) * *c;
+                let rhs = *a * (*b * *c);
+                assert_close!(lhs, rhs, epsilon, "(a * b) * c == a * (b * c)", a, b, c);
+            }
+        }
+    }
+}
+
+/// Checks that transforming points agrees with matrix multiplication
+///
+/// Transforming by the identity must leave points unchanged, and transforming by a product must
+/// match transforming by each matrix in turn. Matrices that transform points affinely, like
+/// `Matrix4` with `Vector3`, only compose when the samples are affine themselves.
+///
+/// # Examples
+/// ```
+/// use vex::Matrix3;
+/// use vex::Vector2;
+/// use vex::test_utils::check_transform_consistency;
+///
+/// let matrices = [Matrix3::translate_2d(1.0, 2.0), Matrix3::rotate_2d(0.75), Matrix3::scale_2d(2.0, 3.0)];
+/// let points = [Vector2::make(1.0, 0.0), Vector2::make(-2.0, 5.0)];
+/// check_transform_consistency(&matrices, &points, 0.0001);
+/// ```
+pub fn check_transform_consistency<M, V>(matrices: &[M], points: &[V], epsilon: f32)
+where
+    M: SquareMatrix + Matrix<V> + Debug,
+    V: Vector + Debug,
+{
+    let identity = M::identity();
+    for p in points.iter() {
+        assert_close!(identity.transform_point(p), *p, epsilon, "I * p == p", p);
+
+        for a in matrices.iter() {
+            for b in matrices.iter() {
+                let lhs = (*a * *b).transform_point(p);
+                let rhs = a.transform_point(&b.transform_point(p));
+                assert_close!(lhs, rhs, epsilon, "(a * b) * p == a * (b * p)", a, b, p);
+            }
+        }
+    }
+}
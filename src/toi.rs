@@ -0,0 +1,165 @@
+#[cfg(not(feature = "std"))]
+use crate::math::Float;
+use crate::vector2::Vector2;
+use crate::vector3::Vector3;
+
+/// Find when two moving circles first touch during a time step
+///
+/// Velocities are the distance each circle moves over the whole step, and the result is the
+/// fraction of the step in `[0, 1]` at which the circles first touch. Circles that already overlap
+/// return `Some(0.0)`, and circles that don't touch during the step return `None`.
+///
+/// # Examples
+/// ```
+/// use vex::Vector2;
+/// use vex::toi_circle_circle;
+///
+/// // a fast bullet that would skip past the target in a single discrete step
+/// let actual = toi_circle_circle(
+///     &Vector2::make(0.0, 0.0), &Vector2::make(10.0, 0.0), 0.1,
+///     &Vector2::make(5.0, 0.0), &Vector2::new(), 0.4,
+/// );
+/// assert_eq!(actual, Some(0.45));
+///
+/// let actual = toi_circle_circle(
+///     &Vector2::make(0.0, 0.0), &Vector2::make(10.0, 0.0), 0.1,
+///     &Vector2::make(5.0, 1.0), &Vector2::new(), 0.4,
+/// );
+/// assert_eq!(actual, None);
+/// ```
+pub fn toi_circle_circle(
+    p0: &Vector2,
+    v0: &Vector2,
+    r0: f32,
+    p1: &Vector2,
+    v1: &Vector2,
+    r1: f32,
+) -> Option<f32> {
+    let d = *p1 - *p0;
+    let w = *v1 - *v0;
+    first_contact(Vector2::dot(&w, &w), Vector2::dot(&d, &w), Vector2::dot(&d, &d), r0 + r1)
+}
+
+/// Find when two moving spheres first touch during a time step
+///
+/// This is the 3D version of [`toi_circle_circle`](fn.toi_circle_circle.html).
+///
+/// # Examples
+/// ```
+/// use vex::Vector3;
+/// use vex::toi_sphere_sphere;
+///
+/// let actual = toi_sphere_sphere(
+///     &Vector3::make(0.0, 0.0, 0.0), &Vector3::make(0.0, 0.0, -4.0), 0.5,
+///     &Vector3::make(0.0, 0.0, -4.0), &Vector3::make(0.0, 0.0, 4.0), 0.5,
+/// );
+/// assert_eq!(actual, Some(0.375));
+///
+/// // already overlapping
+/// let actual = toi_sphere_sphere(
+///     &Vector3::new(), &Vector3::new(), 1.0,
+///     &Vector3::make(1.0, 0.0, 0.0), &Vector3::new(), 0.5,
+/// );
+/// assert_eq!(actual, Some(0.0));
+/// ```
+pub fn toi_sphere_sphere(
+    p0: &Vector3,
+    v0: &Vector3,
+    r0: f32,
+    p1: &Vector3,
+    v1: &Vector3,
+    r1: f32,
+) -> Option<f32> {
+    let d = *p1 - *p0;
+    let w = *v1 - *v0;
+    first_contact(Vector3::dot(&w, &w), Vector3::dot(&d, &w), Vector3::dot(&d, &d), r0 + r1)
+}
+
+/// Find when a moving point first crosses a static segment during a time step
+///
+/// `velocity` is the distance the point moves over the whole step, and the result is the fraction
+/// of the step in `[0, 1]` at which the point first touches the segment.
+///
+/// # Examples
+/// ```
+/// use vex::Vector2;
+/// use vex::toi_point_segment;
+///
+/// let a = Vector2::make(2.0, -1.0);
+/// let b = Vector2::make(2.0, 1.0);
+/// let actual = toi_point_segment(&Vector2::new(), &Vector2::make(8.0, 0.0), &a, &b);
+/// assert_eq!(actual, Some(0.25));
+///
+/// let actual = toi_point_segment(&Vector2::new(), &Vector2::make(8.0, 8.0), &a, &b);
+/// assert_eq!(actual, None);
+///
+/// // moving along the segment's line
+/// let actual = toi_point_segment(&Vector2::make(2.0, -3.0), &Vector2::make(0.0, 4.0), &a, &b);
+/// assert_eq!(actual, Some(0.5));
+/// ```
+pub fn toi_point_segment(
+    point: &Vector2,
+    velocity: &Vector2,
+    a: &Vector2,
+    b: &Vector2,
+) -> Option<f32> {
+    let edge = *b - *a;
+    let offset = *a - *point;
+    let denom = Vector2::cross(velocity, &edge);
+    if denom != 0.0 {
+        let t = Vector2::cross(&offset, &edge) / denom;
+        let s = Vector2::cross(&offset, velocity) / denom;
+        return if (0.0..=1.0).contains(&t) && (0.0..=1.0).contains(&s) {
+            Some(t)
+        } else {
+            None
+        };
+    }
+
+    // parallel, so only a point already on the segment's line can touch it
+    if Vector2::cross(&offset, &edge) != 0.0 || Vector2::cross(&offset, velocity) != 0.0 {
+        return None;
+    }
+
+    let speed_sq = velocity.mag_sq();
+    if speed_sq == 0.0 {
+        let along = Vector2::dot(&-offset, &edge);
+        return if (0.0..=edge.mag_sq()).contains(&along) { Some(0.0) } else { None };
+    }
+
+    let ta = Vector2::dot(&offset, velocity) / speed_sq;
+    let tb = Vector2::dot(&(*b - *point), velocity) / speed_sq;
+    let (enter, exit) = (ta.min(tb), ta.max(tb));
+    if exit < 0.0 || enter > 1.0 {
+        None
+    } else {
+        Some(enter.max(0.0))
+    }
+}
+
+/// Solves `|d + w * t| = radius` for the first `t` in `[0, 1]`, given `w . w`, `d . w`, and
+/// `d . d`
+#[inline]
+fn first_contact(ww: f32, dw: f32, dd: f32, radius: f32) -> Option<f32> {
+    let c = dd - radius * radius;
+    if c <= 0.0 {
+        return Some(0.0);
+    }
+
+    // not moving relative to each other, or moving apart
+    if ww == 0.0 || dw >= 0.0 {
+        return None;
+    }
+
+    let discriminant = dw * dw - ww * c;
+    if discriminant < 0.0 {
+        return None;
+    }
+
+    let t = (-dw - discriminant.sqrt()) / ww;
+    if t <= 1.0 {
+        Some(t)
+    } else {
+        None
+    }
+}
@@ -0,0 +1,356 @@
+#[cfg(not(feature = "std"))]
+use crate::math::Float;
+use crate::matrix3::Matrix3;
+use crate::matrix4::Matrix4;
+use crate::quaternion::Quaternion;
+use crate::vector3::Vector3;
+
+use core::ops::{Mul, MulAssign};
+
+/// How `Transform::blend` interpolates scale
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ScaleBlend {
+    /// Interpolate each scale component linearly
+    Linear,
+    /// Interpolate each scale component in log space, like
+    /// [`blend_scale_log`](fn.blend_scale_log.html)
+    Log,
+}
+
+/// Interpolate between two scales in log space
+///
+/// Each component moves by a constant ratio rather than a constant amount, so blending from 1 to
+/// 100 passes through 10 halfway instead of 50.5, which keeps animated growth looking even. A
+/// component that is zero in either scale, or that changes sign, can't be interpolated in log space
+/// and is interpolated linearly instead.
+///
+/// # Examples
+/// ```
+/// use vex::Vector3;
+/// use vex::blend_scale_log;
+///
+/// let a = Vector3::make(1.0, -2.0, 0.0);
+/// let b = Vector3::make(100.0, -8.0, 4.0);
+/// let actual = blend_scale_log(&a, &b, 0.5);
+/// assert!((actual.x - 10.0).abs() < 0.0001);
+/// assert!((actual.y + 4.0).abs() < 0.0001);
+/// assert_eq!(actual.z, 2.0);
+///
+/// assert_eq!(blend_scale_log(&a, &b, 0.0), a);
+/// ```
+pub fn blend_scale_log(a: &Vector3, b: &Vector3, t: f32) -> Vector3 {
+    let blend = |a: f32, b: f32| {
+        if a == 0.0 || b == 0.0 || (a < 0.0) != (b < 0.0) {
+            return a + (b - a) * t;
+        }
+
+        let (la, lb) = (a.abs().ln(), b.abs().ln());
+        (la + (lb - la) * t).exp().copysign(a)
+    };
+
+    Vector3::make(blend(a.x, b.x), blend(a.y, b.y), blend(a.z, b.z))
+}
+
+/// A translation, rotation, and scale, applied to points in reverse order
+///
+/// Points are scaled first, then rotated, then translated, which matches `T * R * S` as a matrix.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Transform {
+    pub translation: Vector3,
+    pub rotation: Quaternion,
+    pub scale: Vector3,
+}
+
+impl Transform {
+    /// The identity transform
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Transform;
+    ///
+    /// assert_eq!(Transform::IDENTITY, Transform::new());
+    /// ```
+    pub const IDENTITY: Transform = Transform::new();
+
+    /// Creates an identity transform
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Matrix4;
+    /// use vex::Transform;
+    ///
+    /// let actual = Transform::new().to_matrix4();
+    /// assert_eq!(actual, Matrix4::new());
+    /// ```
+    #[inline]
+    pub const fn new() -> Transform {
+        Transform {
+            translation: Vector3::new(),
+            rotation: Quaternion::new(),
+            scale: Vector3::one(),
+        }
+    }
+
+    /// Creates a transform from the provided parts
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Quaternion;
+    /// use vex::Transform;
+    /// use vex::Vector3;
+    ///
+    /// let actual = Transform::make(Vector3::make(1.0, 2.0, 3.0), Quaternion::new(), Vector3::one());
+    /// assert_eq!(actual.translation, Vector3::make(1.0, 2.0, 3.0));
+    /// ```
+    #[inline]
+    pub const fn make(translation: Vector3, rotation: Quaternion, scale: Vector3) -> Transform {
+        Transform {
+            translation,
+            rotation,
+            scale,
+        }
+    }
+
+    /// Find the matrix that applies the transform
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Matrix;
+    /// use vex::Quaternion;
+    /// use vex::Transform;
+    /// use vex::Vector3;
+    ///
+    /// let rotation = Quaternion::from_axis_angle(&Vector3::up(), 1.0);
+    /// let t = Transform::make(Vector3::make(1.0, 2.0, 3.0), rotation, Vector3::make(2.0, 1.0, 3.0));
+    /// let p = Vector3::make(-1.0, 0.5, 2.0);
+    /// let actual = t.to_matrix4().transform_point(&p);
+    /// assert!((actual - t.transform_point(&p)).mag() < 0.0001);
+    /// ```
+    #[inline]
+    pub fn to_matrix4(&self) -> Matrix4 {
+        let r = Matrix3::from(self.rotation);
+        let s = self.scale;
+        let t = self.translation;
+        Matrix4::make(
+            r.m11() * s.x, r.m21() * s.x, r.m31() * s.x, 0.0,
+            r.m12() * s.y, r.m22() * s.y, r.m32() * s.y, 0.0,
+            r.m13() * s.z, r.m23() * s.z, r.m33() * s.z, 0.0,
+            t.x, t.y, t.z, 1.0,
+        )
+    }
+
+    /// Transforms a point by scaling, rotating, then translating it
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Quaternion;
+    /// use vex::Transform;
+    /// use vex::Vector3;
+    ///
+    /// let rotation = Quaternion::from_axis_angle(&Vector3::make(0.0, 0.0, 1.0), std::f32::consts::PI / 2.0);
+    /// let t = Transform::make(Vector3::make(1.0, 0.0, 0.0), rotation, Vector3::make(2.0, 2.0, 2.0));
+    /// let actual = t.transform_point(&Vector3::right());
+    /// assert!((actual - Vector3::make(1.0, 2.0, 0.0)).mag() < 0.0001);
+    /// ```
+    #[inline]
+    pub fn transform_point(&self, point: &Vector3) -> Vector3 {
+        self.rotation.rotate(&(*point * self.scale)) + self.translation
+    }
+
+    /// Transforms a vector by scaling and rotating it, ignoring translation
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Quaternion;
+    /// use vex::Transform;
+    /// use vex::Vector3;
+    ///
+    /// let t = Transform::make(Vector3::make(1.0, 0.0, 0.0), Quaternion::new(), Vector3::make(2.0, 2.0, 2.0));
+    /// let actual = t.transform_vector(&Vector3::up());
+    /// assert_eq!(actual, Vector3::make(0.0, 2.0, 0.0));
+    /// ```
+    #[inline]
+    pub fn transform_vector(&self, vector: &Vector3) -> Vector3 {
+        self.rotation.rotate(&(*vector * self.scale))
+    }
+
+    /// Transforms a direction by rotating it, ignoring scale and translation
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Quaternion;
+    /// use vex::Transform;
+    /// use vex::Vector3;
+    ///
+    /// let rotation = Quaternion::from_axis_angle(&Vector3::make(0.0, 0.0, 1.0), std::f32::consts::PI / 2.0);
+    /// let t = Transform::make(Vector3::make(1.0, 0.0, 0.0), rotation, Vector3::make(2.0, 2.0, 2.0));
+    /// let actual = t.transform_direction(&Vector3::right());
+    /// assert!((actual - Vector3::up()).mag() < 0.0001);
+    /// ```
+    #[inline]
+    pub fn transform_direction(&self, direction: &Vector3) -> Vector3 {
+        self.rotation.rotate(direction)
+    }
+
+    /// Interpolate between two transforms part by part
+    ///
+    /// Translation is interpolated linearly and rotation spherically, and `scale` picks how scale
+    /// is interpolated. Blending the parts rather than the matrices keeps non-uniform scale from
+    /// shearing, and log space keeps large scale ratios from visibly speeding up or slowing down.
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Quaternion;
+    /// use vex::ScaleBlend;
+    /// use vex::Transform;
+    /// use vex::Vector3;
+    ///
+    /// let a = Transform::make(Vector3::new(), Quaternion::new(), Vector3::make(1.0, 1.0, 1.0));
+    /// let b = Transform::make(
+    ///     Vector3::make(2.0, 0.0, 0.0),
+    ///     Quaternion::from_axis_angle(&Vector3::up(), 1.0),
+    ///     Vector3::make(100.0, 4.0, 1.0),
+    /// );
+    ///
+    /// let actual = Transform::blend(&a, &b, 0.5, ScaleBlend::Log);
+    /// assert_eq!(actual.translation, Vector3::make(1.0, 0.0, 0.0));
+    /// let expected = Quaternion::from_axis_angle(&Vector3::up(), 0.5);
+    /// assert!(actual.rotation.approx_eq(&expected, 0.0001));
+    /// assert!((actual.scale - Vector3::make(10.0, 2.0, 1.0)).mag() < 0.0001);
+    ///
+    /// let actual = Transform::blend(&a, &b, 0.5, ScaleBlend::Linear);
+    /// assert_eq!(actual.scale, Vector3::make(50.5, 2.5, 1.0));
+    /// ```
+    pub fn blend(a: &Transform, b: &Transform, t: f32, scale: ScaleBlend) -> Transform {
+        Transform {
+            translation: Vector3::lerp(&a.translation, &b.translation, t),
+            rotation: Quaternion::slerp(&a.rotation, &b.rotation, t),
+            scale: match scale {
+                ScaleBlend::Linear => Vector3::lerp(&a.scale, &b.scale, t),
+                ScaleBlend::Log => blend_scale_log(&a.scale, &b.scale, t),
+            },
+        }
+    }
+
+    /// Find the inverse of the transform, or `None` if any scale component is zero
+    ///
+    /// The result is exact when the scale is uniform. With non-uniform scale and a rotation the
+    /// true inverse can't be expressed as a translation, rotation, and scale, so this is the
+    /// closest one that undoes each part in reverse.
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Quaternion;
+    /// use vex::Transform;
+    /// use vex::Vector3;
+    ///
+    /// let t = Transform::make(Vector3::make(1.0, 2.0, 3.0), Quaternion::new(), Vector3::make(2.0, 4.0, 1.0));
+    /// let actual = t.try_inverse().unwrap();
+    /// assert_eq!(actual.translation, Vector3::make(-0.5, -0.5, -3.0));
+    /// assert_eq!(actual.scale, Vector3::make(0.5, 0.25, 1.0));
+    ///
+    /// let rotation = Quaternion::from_axis_angle(&Vector3::up(), 1.0);
+    /// let t = Transform::make(Vector3::make(1.0, 2.0, 3.0), rotation, Vector3::make(2.0, 2.0, 2.0));
+    /// let inverse = t.try_inverse().unwrap();
+    /// let p = Vector3::make(-1.0, 0.5, 2.0);
+    /// let actual = inverse.transform_point(&t.transform_point(&p));
+    /// assert!((actual - p).mag() < 0.0001);
+    ///
+    /// let t = Transform::make(Vector3::new(), Quaternion::new(), Vector3::make(1.0, 0.0, 1.0));
+    /// assert_eq!(t.try_inverse(), None);
+    /// ```
+    pub fn try_inverse(&self) -> Option<Transform> {
+        if self.scale.x == 0.0 || self.scale.y == 0.0 || self.scale.z == 0.0 {
+            return None;
+        }
+
+        let rotation = self.rotation.try_inverse()?;
+        let scale = Vector3::make(1.0 / self.scale.x, 1.0 / self.scale.y, 1.0 / self.scale.z);
+        Some(Transform::make(rotation.rotate(&-self.translation) * scale, rotation, scale))
+    }
+}
+
+impl Default for Transform {
+    #[inline]
+    fn default() -> Transform {
+        Transform::new()
+    }
+}
+
+impl From<Transform> for Matrix4 {
+    /// Creates a matrix that applies a transform
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Matrix4;
+    /// use vex::Quaternion;
+    /// use vex::Transform;
+    /// use vex::Vector3;
+    ///
+    /// let t = Transform::make(Vector3::make(1.0, 2.0, 3.0), Quaternion::new(), Vector3::one());
+    /// assert_eq!(Matrix4::from(t), Matrix4::translate(1.0, 2.0, 3.0));
+    /// ```
+    #[inline]
+    fn from(t: Transform) -> Matrix4 {
+        t.to_matrix4()
+    }
+}
+
+impl Mul<Transform> for Transform {
+    type Output = Transform;
+
+    /// Composes two transforms, so that `_rhs` is applied first
+    ///
+    /// Like [`inverse`](#method.inverse), this is exact unless `self` has non-uniform scale and
+    /// `_rhs` is rotated.
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Quaternion;
+    /// use vex::Transform;
+    /// use vex::Vector3;
+    ///
+    /// let parent = Transform::make(
+    ///     Vector3::make(1.0, 2.0, 3.0),
+    ///     Quaternion::from_axis_angle(&Vector3::up(), 1.0),
+    ///     Vector3::make(2.0, 2.0, 2.0),
+    /// );
+    /// let child = Transform::make(
+    ///     Vector3::make(-1.0, 0.0, 4.0),
+    ///     Quaternion::from_axis_angle(&Vector3::right(), 0.5),
+    ///     Vector3::make(1.0, 3.0, 1.0),
+    /// );
+    ///
+    /// let p = Vector3::make(0.5, -1.0, 2.0);
+    /// let actual = (parent * child).transform_point(&p);
+    /// let expected = parent.transform_point(&child.transform_point(&p));
+    /// assert!((actual - expected).mag() < 0.0001);
+    /// ```
+    #[inline]
+    fn mul(self, _rhs: Transform) -> Transform {
+        Transform {
+            translation: self.transform_point(&_rhs.translation),
+            rotation: self.rotation * _rhs.rotation,
+            scale: self.scale * _rhs.scale,
+        }
+    }
+}
+
+impl MulAssign<Transform> for Transform {
+    /// Composes a transform with another, so that `_rhs` is applied first
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Quaternion;
+    /// use vex::Transform;
+    /// use vex::Vector3;
+    ///
+    /// let mut actual = Transform::make(Vector3::make(1.0, 0.0, 0.0), Quaternion::new(), Vector3::one());
+    /// actual *= Transform::make(Vector3::make(0.0, 2.0, 0.0), Quaternion::new(), Vector3::one());
+    /// assert_eq!(actual.translation, Vector3::make(1.0, 2.0, 0.0));
+    /// ```
+    #[inline]
+    fn mul_assign(&mut self, _rhs: Transform) {
+        *self = *self * _rhs;
+    }
+}
@@ -0,0 +1,190 @@
+use crate::epsilon::Epsilon;
+use crate::matrix4::Matrix4;
+
+use alloc::vec::Vec;
+
+/// Keeps each object's transform for this frame and the last, addressed by index
+///
+/// Renderers need last frame's transforms alongside this frame's for motion vectors. Transforms
+/// are set throughout a frame, and `commit` ends it, making the current transforms the previous
+/// ones. A transform that isn't set again stays current, so it reads as not having moved.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct TransformPool {
+    current: Vec<Matrix4>,
+    previous: Vec<Matrix4>,
+}
+
+impl TransformPool {
+    /// Creates a pool with no transforms
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::TransformPool;
+    ///
+    /// assert!(TransformPool::new().is_empty());
+    /// ```
+    #[inline]
+    pub fn new() -> TransformPool {
+        TransformPool::default()
+    }
+
+    /// Adds a transform, returning its index
+    ///
+    /// Its previous transform starts out the same, so it doesn't count as changed.
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Epsilon;
+    /// use vex::Matrix4;
+    /// use vex::TransformPool;
+    ///
+    /// let mut pool = TransformPool::new();
+    /// let index = pool.push(Matrix4::translate(1.0, 2.0, 3.0));
+    /// assert_eq!(index, 0);
+    /// assert_eq!(pool.previous(index), pool.current(index));
+    /// assert_eq!(pool.changed(&Epsilon::DEFAULT).count(), 0);
+    /// ```
+    #[inline]
+    pub fn push(&mut self, transform: Matrix4) -> usize {
+        self.current.push(transform);
+        self.previous.push(transform);
+        self.current.len() - 1
+    }
+
+    /// Gets the number of transforms
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Matrix4;
+    /// use vex::TransformPool;
+    ///
+    /// let mut pool = TransformPool::new();
+    /// pool.push(Matrix4::IDENTITY);
+    /// pool.push(Matrix4::IDENTITY);
+    /// assert_eq!(pool.len(), 2);
+    /// ```
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.current.len()
+    }
+
+    /// Determines whether or not there are no transforms
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Matrix4;
+    /// use vex::TransformPool;
+    ///
+    /// let mut pool = TransformPool::new();
+    /// assert!(pool.is_empty());
+    ///
+    /// pool.push(Matrix4::IDENTITY);
+    /// assert!(!pool.is_empty());
+    /// ```
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.current.is_empty()
+    }
+
+    /// Gets this frame's transform at an index
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Matrix4;
+    /// use vex::TransformPool;
+    ///
+    /// let mut pool = TransformPool::new();
+    /// let index = pool.push(Matrix4::IDENTITY);
+    /// pool.set(index, Matrix4::translate(1.0, 0.0, 0.0));
+    /// assert_eq!(*pool.current(index), Matrix4::translate(1.0, 0.0, 0.0));
+    /// ```
+    #[inline]
+    pub fn current(&self, index: usize) -> &Matrix4 {
+        &self.current[index]
+    }
+
+    /// Gets last frame's transform at an index
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Matrix4;
+    /// use vex::TransformPool;
+    ///
+    /// let mut pool = TransformPool::new();
+    /// let index = pool.push(Matrix4::IDENTITY);
+    /// pool.set(index, Matrix4::translate(1.0, 0.0, 0.0));
+    /// assert_eq!(*pool.previous(index), Matrix4::IDENTITY);
+    /// ```
+    #[inline]
+    pub fn previous(&self, index: usize) -> &Matrix4 {
+        &self.previous[index]
+    }
+
+    /// Sets this frame's transform at an index
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Matrix4;
+    /// use vex::TransformPool;
+    ///
+    /// let mut pool = TransformPool::new();
+    /// let index = pool.push(Matrix4::IDENTITY);
+    /// pool.set(index, Matrix4::scale(2.0, 2.0, 2.0));
+    /// assert_eq!(*pool.current(index), Matrix4::scale(2.0, 2.0, 2.0));
+    /// ```
+    #[inline]
+    pub fn set(&mut self, index: usize, transform: Matrix4) {
+        self.current[index] = transform;
+    }
+
+    /// Ends the frame, so this frame's transforms become the previous ones
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Epsilon;
+    /// use vex::Matrix4;
+    /// use vex::TransformPool;
+    ///
+    /// let mut pool = TransformPool::new();
+    /// let index = pool.push(Matrix4::IDENTITY);
+    /// pool.set(index, Matrix4::translate(1.0, 0.0, 0.0));
+    /// pool.commit();
+    /// assert_eq!(*pool.previous(index), Matrix4::translate(1.0, 0.0, 0.0));
+    /// assert_eq!(pool.changed(&Epsilon::DEFAULT).count(), 0);
+    /// ```
+    #[inline]
+    pub fn commit(&mut self) {
+        self.previous.copy_from_slice(&self.current);
+    }
+
+    /// Find the indices of the transforms that moved since the last frame
+    ///
+    /// Transforms whose elements all match their previous ones within `epsilon` are skipped, so
+    /// jitter from recomputing the same transform doesn't count as motion.
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Epsilon;
+    /// use vex::Matrix4;
+    /// use vex::TransformPool;
+    ///
+    /// let mut pool = TransformPool::new();
+    /// for _ in 0..3 {
+    ///     pool.push(Matrix4::IDENTITY);
+    /// }
+    ///
+    /// pool.set(0, Matrix4::translate(0.0, 0.0000001, 0.0));
+    /// pool.set(2, Matrix4::translate(0.0, 1.0, 0.0));
+    /// let actual: Vec<usize> = pool.changed(&Epsilon::DEFAULT).collect();
+    /// assert_eq!(actual, vec![2]);
+    /// ```
+    #[inline]
+    pub fn changed<'a>(&'a self, epsilon: &'a Epsilon) -> impl Iterator<Item = usize> + 'a {
+        self.current
+            .iter()
+            .zip(self.previous.iter())
+            .enumerate()
+            .filter(move |(_, (current, previous))| !current.approx_eq_with(previous, epsilon))
+            .map(|(i, _)| i)
+    }
+}
@@ -0,0 +1,133 @@
+use crate::vector3::Vector3;
+
+use core::ops::{Deref, Neg};
+
+/// A `Vector3` that is guaranteed to have unit length
+///
+/// It can only be made by normalizing a vector that has a direction, so APIs that take one never
+/// have to guard against zero-length or non-finite axes. Derefs to `Vector3`, so its components
+/// and methods can be read directly.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct UnitVector3(Vector3);
+
+impl UnitVector3 {
+    /// The unit vector pointing along +x
+    pub const X: UnitVector3 = UnitVector3(Vector3::X);
+
+    /// The unit vector pointing along +y
+    pub const Y: UnitVector3 = UnitVector3(Vector3::Y);
+
+    /// The unit vector pointing along +z
+    pub const Z: UnitVector3 = UnitVector3(Vector3::Z);
+
+    /// Creates a unit vector by normalizing a vector, if it has a direction
+    ///
+    /// Vectors with non-finite components, or too short to normalize, give `None`.
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::UnitVector3;
+    /// use vex::Vector3;
+    ///
+    /// let actual = UnitVector3::try_new(Vector3::make(0.0, 3.0, 0.0)).unwrap();
+    /// assert_eq!(actual, UnitVector3::Y);
+    ///
+    /// assert!(UnitVector3::try_new(Vector3::new()).is_none());
+    /// assert!(UnitVector3::try_new(Vector3::make(f32::NAN, 1.0, 0.0)).is_none());
+    /// assert!(UnitVector3::try_new(Vector3::make(f32::INFINITY, 1.0, 0.0)).is_none());
+    /// ```
+    #[inline]
+    pub fn try_new(v: Vector3) -> Option<UnitVector3> {
+        if !v.is_valid() {
+            return None;
+        }
+
+        let mut v = v;
+        if v.norm() == 0.0 {
+            return None;
+        }
+
+        Some(UnitVector3(v))
+    }
+
+    /// Creates a unit vector by normalizing a vector
+    ///
+    /// # Panics
+    ///
+    /// Panics if the vector has non-finite components or is too short to normalize. Use
+    /// [`try_new`](#method.try_new) for vectors that may not have a direction.
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::UnitVector3;
+    /// use vex::Vector3;
+    ///
+    /// let actual = UnitVector3::new_normalize(Vector3::make(3.0, 0.0, -4.0));
+    /// assert_eq!(actual.get(), Vector3::make(0.6, 0.0, -0.8));
+    /// ```
+    #[inline]
+    pub fn new_normalize(v: Vector3) -> UnitVector3 {
+        match UnitVector3::try_new(v) {
+            Some(unit) => unit,
+            None => panic!("cannot normalize {:?} into a unit vector", v),
+        }
+    }
+
+    /// Gets the unit vector as a plain `Vector3`
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::UnitVector3;
+    /// use vex::Vector3;
+    ///
+    /// assert_eq!(UnitVector3::X.get(), Vector3::right());
+    /// ```
+    #[inline]
+    pub fn get(&self) -> Vector3 {
+        self.0
+    }
+}
+
+impl Deref for UnitVector3 {
+    type Target = Vector3;
+
+    #[inline]
+    fn deref(&self) -> &Vector3 {
+        &self.0
+    }
+}
+
+impl From<UnitVector3> for Vector3 {
+    /// Converts a unit vector into a plain `Vector3`
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::UnitVector3;
+    /// use vex::Vector3;
+    ///
+    /// let actual: Vector3 = UnitVector3::Z.into();
+    /// assert_eq!(actual, Vector3::make(0.0, 0.0, 1.0));
+    /// ```
+    #[inline]
+    fn from(unit: UnitVector3) -> Vector3 {
+        unit.0
+    }
+}
+
+impl Neg for UnitVector3 {
+    type Output = UnitVector3;
+
+    /// Find the unit vector pointing the opposite way
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::UnitVector3;
+    /// use vex::Vector3;
+    ///
+    /// assert_eq!((-UnitVector3::Y).get(), Vector3::make(0.0, -1.0, 0.0));
+    /// ```
+    #[inline]
+    fn neg(self) -> UnitVector3 {
+        UnitVector3(-self.0)
+    }
+}
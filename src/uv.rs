@@ -0,0 +1,225 @@
+use crate::common::Matrix;
+#[cfg(not(feature = "std"))]
+use crate::math::Float;
+use crate::matrix4::Matrix4;
+use crate::vector2::Vector2;
+use crate::vector3::Vector3;
+
+use alloc::vec::Vec;
+use core::f32::consts::PI;
+
+/// Projects positions onto a plane spanned by the provided axes
+///
+/// # Examples
+/// ```
+/// use vex::Vector2;
+/// use vex::Vector3;
+/// use vex::planar_uvs;
+///
+/// let positions = [Vector3::make(1.0, 2.0, 3.0), Vector3::make(2.0, 4.0, 6.0)];
+/// let origin = Vector3::make(1.0, 0.0, 0.0);
+/// let actual = planar_uvs(&positions, &origin, &Vector3::right(), &Vector3::up());
+/// let expected = vec![Vector2::make(0.0, 2.0), Vector2::make(1.0, 4.0)];
+/// assert_eq!(actual, expected);
+/// ```
+#[inline]
+pub fn planar_uvs(
+    positions: &[Vector3],
+    origin: &Vector3,
+    u_axis: &Vector3,
+    v_axis: &Vector3,
+) -> Vec<Vector2> {
+    positions
+        .iter()
+        .map(|p| {
+            let offset = *p - *origin;
+            Vector2::make(Vector3::dot(&offset, u_axis), Vector3::dot(&offset, v_axis))
+        })
+        .collect()
+}
+
+/// Find the blend weights for triplanar mapping given a surface normal
+///
+/// Higher sharpness values tighten the transition between the three projections. The weights
+/// always sum to 1.
+///
+/// # Examples
+/// ```
+/// use vex::Vector3;
+/// use vex::triplanar_weights;
+///
+/// let actual = triplanar_weights(&Vector3::make(0.0, -2.0, 0.0), 4.0);
+/// let expected = Vector3::make(0.0, 1.0, 0.0);
+/// assert_eq!(actual, expected);
+///
+/// let actual = triplanar_weights(&Vector3::make(1.0, 1.0, 0.0), 1.0);
+/// let expected = Vector3::make(0.5, 0.5, 0.0);
+/// assert_eq!(actual, expected);
+/// ```
+#[inline]
+pub fn triplanar_weights(normal: &Vector3, sharpness: f32) -> Vector3 {
+    let weights = Vector3::make(
+        normal.x.abs().powf(sharpness),
+        normal.y.abs().powf(sharpness),
+        normal.z.abs().powf(sharpness),
+    );
+
+    let sum = weights.x + weights.y + weights.z;
+    if sum > 0.0 {
+        weights / sum
+    } else {
+        Vector3::one() / 3.0
+    }
+}
+
+/// Find box-mapped UVs by projecting each position along the dominant axis of its normal
+///
+/// The faces are oriented as if looking at each side of the box from the outside:
+/// +X uses <-z, y>, -X <z, y>, +Y <x, -z>, -Y <x, z>, +Z <x, y>, and -Z <-x, y>.
+///
+/// # Panics
+///
+/// Panics if `positions` and `normals` have different lengths, since they're matched by index.
+///
+/// # Examples
+/// ```
+/// use vex::Vector2;
+/// use vex::Vector3;
+/// use vex::box_uvs;
+///
+/// let positions = [Vector3::make(1.0, 2.0, 3.0), Vector3::make(1.0, 2.0, 3.0)];
+/// let normals = [Vector3::right(), Vector3::up()];
+/// let actual = box_uvs(&positions, &normals);
+/// let expected = vec![Vector2::make(-3.0, 2.0), Vector2::make(1.0, -3.0)];
+/// assert_eq!(actual, expected);
+/// ```
+pub fn box_uvs(positions: &[Vector3], normals: &[Vector3]) -> Vec<Vector2> {
+    assert_eq!(positions.len(), normals.len(), "positions and normals must have the same length");
+    positions
+        .iter()
+        .zip(normals.iter())
+        .map(|(p, n)| {
+            let a = Vector3::make(n.x.abs(), n.y.abs(), n.z.abs());
+            if a.x >= a.y && a.x >= a.z {
+                if n.x >= 0.0 {
+                    Vector2::make(-p.z, p.y)
+                } else {
+                    Vector2::make(p.z, p.y)
+                }
+            } else if a.y >= a.z {
+                if n.y >= 0.0 {
+                    Vector2::make(p.x, -p.z)
+                } else {
+                    Vector2::make(p.x, p.z)
+                }
+            } else if n.z >= 0.0 {
+                Vector2::make(p.x, p.y)
+            } else {
+                Vector2::make(-p.x, p.y)
+            }
+        })
+        .collect()
+}
+
+/// Find cylindrical UVs around the y-axis of the provided mapping transform
+///
+/// Positions are transformed into mapping space first. U wraps around the axis in [0, 1) starting
+/// at -z, and V is the height along the axis.
+///
+/// # Examples
+/// ```
+/// use vex::Matrix4;
+/// use vex::Vector2;
+/// use vex::Vector3;
+/// use vex::cylindrical_uvs;
+///
+/// let positions = [Vector3::make(0.0, 1.0, -1.0), Vector3::make(0.0, 3.0, 1.0)];
+/// let actual = cylindrical_uvs(&positions, &Matrix4::new());
+/// let expected = vec![Vector2::make(0.0, 1.0), Vector2::make(0.5, 3.0)];
+/// assert_eq!(actual, expected);
+/// ```
+pub fn cylindrical_uvs(positions: &[Vector3], transform: &Matrix4) -> Vec<Vector2> {
+    positions
+        .iter()
+        .map(|p| {
+            let local = transform.transform_point(p);
+            Vector2::make(wrap_angle(local.x, local.z), local.y)
+        })
+        .collect()
+}
+
+/// Find spherical UVs around the origin of the provided mapping transform
+///
+/// Positions are transformed into mapping space first. U wraps around the y-axis in [0, 1)
+/// starting at -z, and V runs from 0 at the bottom pole to 1 at the top pole.
+///
+/// # Examples
+/// ```
+/// use vex::Matrix4;
+/// use vex::Vector2;
+/// use vex::Vector3;
+/// use vex::spherical_uvs;
+///
+/// let positions = [Vector3::make(0.0, 0.0, -2.0), Vector3::make(2.0, 0.0, 0.0)];
+/// let actual = spherical_uvs(&positions, &Matrix4::new());
+/// let expected = vec![Vector2::make(0.0, 0.5), Vector2::make(0.25, 0.5)];
+/// assert_eq!(actual, expected);
+/// ```
+pub fn spherical_uvs(positions: &[Vector3], transform: &Matrix4) -> Vec<Vector2> {
+    positions
+        .iter()
+        .map(|p| {
+            let local = transform.transform_point(p);
+            let radius = local.mag();
+            let v = if radius > 0.0 {
+                0.5 + (local.y / radius).clamp(-1.0, 1.0).asin() / PI
+            } else {
+                0.5
+            };
+
+            Vector2::make(wrap_angle(local.x, local.z), v)
+        })
+        .collect()
+}
+
+/// Fixes the seam of a wrapped projection for a single face
+///
+/// Faces straddling the seam of a cylindrical or spherical mapping have U values on both sides of
+/// the wrap. This shifts the low side up by one so the face interpolates across the seam instead
+/// of across the whole texture.
+///
+/// # Examples
+/// ```
+/// use vex::Vector2;
+/// use vex::wrap_seam;
+///
+/// let mut actual = [Vector2::make(0.95, 0.0), Vector2::make(0.05, 0.0), Vector2::make(0.9, 1.0)];
+/// wrap_seam(&mut actual);
+/// assert!((actual[1].x - 1.05).abs() < 0.0001);
+/// ```
+pub fn wrap_seam(uvs: &mut [Vector2]) {
+    let mut low = f32::MAX;
+    let mut high = f32::MIN;
+    for uv in uvs.iter() {
+        low = low.min(uv.x);
+        high = high.max(uv.x);
+    }
+
+    if high - low > 0.5 {
+        for uv in uvs.iter_mut() {
+            if uv.x < 0.5 {
+                uv.x += 1.0;
+            }
+        }
+    }
+}
+
+#[inline]
+fn wrap_angle(x: f32, z: f32) -> f32 {
+    let u = x.atan2(-z) / (2.0 * PI);
+    if u < 0.0 {
+        u + 1.0
+    } else {
+        u
+    }
+}
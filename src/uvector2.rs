@@ -0,0 +1,673 @@
+use crate::format;
+#[cfg(not(feature = "std"))]
+use crate::math::Float;
+use crate::vector2::Vector2;
+
+use core::fmt;
+use core::fmt::{Display, Formatter};
+
+use core::ops::{
+    Add,
+    AddAssign,
+    Div,
+    DivAssign,
+    Index,
+    IndexMut,
+    Mul,
+    MulAssign,
+    Sub,
+    SubAssign,
+};
+
+#[repr(C)]
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct UVector2 {
+    pub x: u32,
+    pub y: u32,
+}
+
+impl UVector2 {
+    /// The vector <0, 0>
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::UVector2;
+    ///
+    /// assert_eq!(UVector2::ZERO, UVector2::new());
+    /// ```
+    pub const ZERO: UVector2 = UVector2 { x: 0, y: 0 };
+
+    /// The vector <1, 1>
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::UVector2;
+    ///
+    /// assert_eq!(UVector2::ONE, UVector2::make(1, 1));
+    /// ```
+    pub const ONE: UVector2 = UVector2 { x: 1, y: 1 };
+
+    /// A unit vector <1, 0> along the x-axis
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::UVector2;
+    ///
+    /// assert_eq!(UVector2::X, UVector2::make(1, 0));
+    /// ```
+    pub const X: UVector2 = UVector2 { x: 1, y: 0 };
+
+    /// A unit vector <0, 1> along the y-axis
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::UVector2;
+    ///
+    /// assert_eq!(UVector2::Y, UVector2::make(0, 1));
+    /// ```
+    pub const Y: UVector2 = UVector2 { x: 0, y: 1 };
+
+    /// Creates a vector <0, 0>
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::UVector2;
+    ///
+    /// let actual = UVector2::new();
+    /// let expected = UVector2 { x: 0, y: 0 };
+    /// assert_eq!(actual, expected);
+    /// ```
+    #[inline]
+    pub const fn new() -> UVector2 {
+        UVector2 { x: 0, y: 0 }
+    }
+
+    /// Creates a vector from the provided values
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::UVector2;
+    ///
+    /// let actual = UVector2::make(1, 2);
+    /// let expected = UVector2 { x: 1, y: 2 };
+    /// assert_eq!(actual, expected);
+    /// ```
+    #[inline]
+    pub const fn make(x: u32, y: u32) -> UVector2 {
+        UVector2 { x, y }
+    }
+
+    /// Find the dot product between two vectors
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::UVector2;
+    ///
+    /// let a = UVector2::make(1, 2);
+    /// let b = UVector2::make(3, 5);
+    /// assert_eq!(UVector2::dot(&a, &b), 13);
+    /// ```
+    #[inline]
+    pub fn dot(a: &UVector2, b: &UVector2) -> u32 {
+        a.x * b.x + a.y * b.y
+    }
+
+    /// Find the minimum (smallest) set of values
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::UVector2;
+    ///
+    /// let a = UVector2::make(1, 2);
+    /// let b = UVector2::make(4, 0);
+    /// assert_eq!(UVector2::min(&a, &b), UVector2::make(1, 0));
+    /// ```
+    #[inline]
+    pub fn min(a: &UVector2, b: &UVector2) -> UVector2 {
+        UVector2::make(a.x.min(b.x), a.y.min(b.y))
+    }
+
+    /// Find the maximum (largest) set of values
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::UVector2;
+    ///
+    /// let a = UVector2::make(1, 2);
+    /// let b = UVector2::make(4, 0);
+    /// assert_eq!(UVector2::max(&a, &b), UVector2::make(4, 2));
+    /// ```
+    #[inline]
+    pub fn max(a: &UVector2, b: &UVector2) -> UVector2 {
+        UVector2::make(a.x.max(b.x), a.y.max(b.y))
+    }
+
+    /// Find the vector with each component clamped between two vectors
+    ///
+    /// Panics if any component of `a` is greater than the same component of `b`.
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::UVector2;
+    ///
+    /// let a = UVector2::make(0, 0);
+    /// let b = UVector2::make(2, 2);
+    /// let actual = UVector2::make(5, 1).clamped(&a, &b);
+    /// assert_eq!(actual, UVector2::make(2, 1));
+    /// ```
+    #[inline]
+    pub fn clamped(&self, a: &UVector2, b: &UVector2) -> UVector2 {
+        UVector2::make(
+            self.x.clamp(a.x, b.x),
+            self.y.clamp(a.y, b.y),
+        )
+    }
+
+    /// Gets the smallest component
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::UVector2;
+    ///
+    /// assert_eq!(UVector2::make(3, 5).min_element(), 3);
+    /// ```
+    #[inline]
+    pub fn min_element(&self) -> u32 {
+        self.x.min(self.y)
+    }
+
+    /// Gets the largest component
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::UVector2;
+    ///
+    /// assert_eq!(UVector2::make(3, 5).max_element(), 5);
+    /// ```
+    #[inline]
+    pub fn max_element(&self) -> u32 {
+        self.x.max(self.y)
+    }
+
+    /// Gets the sum of the components
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::UVector2;
+    ///
+    /// assert_eq!(UVector2::make(3, 5).sum(), 8);
+    /// ```
+    #[inline]
+    pub fn sum(&self) -> u32 {
+        self.x + self.y
+    }
+
+    /// Gets the product of the components
+    ///
+    /// Useful for counting the cells in a grid or the invocations in a dispatch of this size.
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::UVector2;
+    ///
+    /// assert_eq!(UVector2::make(3, 5).product(), 15);
+    /// ```
+    #[inline]
+    pub fn product(&self) -> u32 {
+        self.x * self.y
+    }
+
+    /// Creates a vector from a floating point vector with each component rounded down
+    ///
+    /// Components outside the range of `u32` saturate to its limits, and NaN becomes 0.
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::UVector2;
+    /// use vex::Vector2;
+    ///
+    /// let actual = UVector2::floor(&Vector2::make(1.5, 0.5));
+    /// assert_eq!(actual, UVector2::make(1, 0));
+    /// ```
+    #[inline]
+    pub fn floor(v: &Vector2) -> UVector2 {
+        UVector2::make(v.x.floor() as u32, v.y.floor() as u32)
+    }
+
+    /// Creates a vector from a floating point vector with each component rounded up
+    ///
+    /// Components outside the range of `u32` saturate to its limits, and NaN becomes 0.
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::UVector2;
+    /// use vex::Vector2;
+    ///
+    /// let actual = UVector2::ceil(&Vector2::make(1.5, 0.5));
+    /// assert_eq!(actual, UVector2::make(2, 1));
+    /// ```
+    #[inline]
+    pub fn ceil(v: &Vector2) -> UVector2 {
+        UVector2::make(v.x.ceil() as u32, v.y.ceil() as u32)
+    }
+
+    /// Creates a vector from a floating point vector with each component rounded to the nearest integer, with halves rounded away from zero
+    ///
+    /// Components outside the range of `u32` saturate to its limits, and NaN becomes 0.
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::UVector2;
+    /// use vex::Vector2;
+    ///
+    /// let actual = UVector2::round(&Vector2::make(1.5, 0.5));
+    /// assert_eq!(actual, UVector2::make(2, 1));
+    /// ```
+    #[inline]
+    pub fn round(v: &Vector2) -> UVector2 {
+        UVector2::make(v.x.round() as u32, v.y.round() as u32)
+    }
+
+    /// Converts the vector to a floating point vector
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::UVector2;
+    /// use vex::Vector2;
+    ///
+    /// let actual = UVector2::make(1, 2).as_f32();
+    /// assert_eq!(actual, Vector2::make(1.0, 2.0));
+    /// ```
+    #[inline]
+    pub fn as_f32(&self) -> Vector2 {
+        Vector2::make(self.x as f32, self.y as f32)
+    }
+}
+
+impl From<[u32; 2]> for UVector2 {
+    /// Creates a vector from an array of its components
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::UVector2;
+    ///
+    /// let actual = UVector2::from([1, 2]);
+    /// assert_eq!(actual, UVector2::make(1, 2));
+    /// ```
+    #[inline]
+    fn from(values: [u32; 2]) -> UVector2 {
+        UVector2::make(values[0], values[1])
+    }
+}
+
+impl From<UVector2> for [u32; 2] {
+    /// Creates an array from the components of a vector
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::UVector2;
+    ///
+    /// let actual: [u32; 2] = UVector2::make(1, 2).into();
+    /// assert_eq!(actual, [1, 2]);
+    /// ```
+    #[inline]
+    fn from(v: UVector2) -> [u32; 2] {
+        [v.x, v.y]
+    }
+}
+
+impl Index<u32> for UVector2 {
+    type Output = u32;
+
+    /// Looks up a component by index
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::UVector2;
+    ///
+    /// let v = UVector2::make(1, 2);
+    /// assert_eq!(v[1], 2);
+    /// ```
+    #[inline]
+    fn index(&self, index: u32) -> &u32 {
+        match index {
+            0 => &self.x,
+            1 => &self.y,
+            _ => panic!("Invalid index for UVector2: {}", index),
+        }
+    }
+}
+
+impl IndexMut<u32> for UVector2 {
+    /// Mutate a component by index
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::UVector2;
+    ///
+    /// let mut v = UVector2::make(1, 2);
+    /// v[1] = 8;
+    /// assert_eq!(v[1], 8);
+    /// ```
+    #[inline]
+    fn index_mut(&mut self, index: u32) -> &mut u32 {
+        match index {
+            0 => &mut self.x,
+            1 => &mut self.y,
+            _ => panic!("Invalid index for UVector2: {}", index),
+        }
+    }
+}
+
+impl Add<u32> for UVector2 {
+    type Output = UVector2;
+
+    /// Add a scalar to a vector
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::UVector2;
+    ///
+    /// let actual = UVector2::make(1, 2) + 2;
+    /// assert_eq!(actual, UVector2::make(3, 4));
+    /// ```
+    #[inline]
+    fn add(self, _rhs: u32) -> UVector2 {
+        UVector2::make(self.x + _rhs, self.y + _rhs)
+    }
+}
+
+impl AddAssign<u32> for UVector2 {
+    /// Increment a vector by a scalar
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::UVector2;
+    ///
+    /// let mut actual = UVector2::make(1, 2);
+    /// actual += 2;
+    /// assert_eq!(actual, UVector2::make(3, 4));
+    /// ```
+    #[inline]
+    fn add_assign(&mut self, _rhs: u32) {
+        self.x += _rhs;
+        self.y += _rhs;
+    }
+}
+
+impl Add<UVector2> for UVector2 {
+    type Output = UVector2;
+
+    /// Add two vectors
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::UVector2;
+    ///
+    /// let actual = UVector2::make(1, 2) + UVector2::make(3, 5);
+    /// assert_eq!(actual, UVector2::make(4, 7));
+    /// ```
+    #[inline]
+    fn add(self, _rhs: UVector2) -> UVector2 {
+        UVector2::make(self.x + _rhs.x, self.y + _rhs.y)
+    }
+}
+
+impl AddAssign<UVector2> for UVector2 {
+    /// Increment a vector by another vector
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::UVector2;
+    ///
+    /// let mut actual = UVector2::make(1, 2);
+    /// actual += UVector2::make(3, 5);
+    /// assert_eq!(actual, UVector2::make(4, 7));
+    /// ```
+    #[inline]
+    fn add_assign(&mut self, _rhs: UVector2) {
+        self.x += _rhs.x;
+        self.y += _rhs.y;
+    }
+}
+
+impl Sub<u32> for UVector2 {
+    type Output = UVector2;
+
+    /// Subtract a scalar from a vector
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::UVector2;
+    ///
+    /// let actual = UVector2::make(3, 5) - 1;
+    /// assert_eq!(actual, UVector2::make(2, 4));
+    /// ```
+    #[inline]
+    fn sub(self, _rhs: u32) -> UVector2 {
+        UVector2::make(self.x - _rhs, self.y - _rhs)
+    }
+}
+
+impl SubAssign<u32> for UVector2 {
+    /// Decrement a vector by a scalar
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::UVector2;
+    ///
+    /// let mut actual = UVector2::make(3, 5);
+    /// actual -= 1;
+    /// assert_eq!(actual, UVector2::make(2, 4));
+    /// ```
+    #[inline]
+    fn sub_assign(&mut self, _rhs: u32) {
+        self.x -= _rhs;
+        self.y -= _rhs;
+    }
+}
+
+impl Sub<UVector2> for UVector2 {
+    type Output = UVector2;
+
+    /// Subtract two vectors
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::UVector2;
+    ///
+    /// let actual = UVector2::make(3, 5) - UVector2::make(1, 2);
+    /// assert_eq!(actual, UVector2::make(2, 3));
+    /// ```
+    #[inline]
+    fn sub(self, _rhs: UVector2) -> UVector2 {
+        UVector2::make(self.x - _rhs.x, self.y - _rhs.y)
+    }
+}
+
+impl SubAssign<UVector2> for UVector2 {
+    /// Decrement a vector by another vector
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::UVector2;
+    ///
+    /// let mut actual = UVector2::make(3, 5);
+    /// actual -= UVector2::make(1, 2);
+    /// assert_eq!(actual, UVector2::make(2, 3));
+    /// ```
+    #[inline]
+    fn sub_assign(&mut self, _rhs: UVector2) {
+        self.x -= _rhs.x;
+        self.y -= _rhs.y;
+    }
+}
+
+impl Mul<u32> for UVector2 {
+    type Output = UVector2;
+
+    /// Multiply a vector by a scalar
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::UVector2;
+    ///
+    /// let actual = UVector2::make(1, 2) * 2;
+    /// assert_eq!(actual, UVector2::make(2, 4));
+    /// ```
+    #[inline]
+    fn mul(self, _rhs: u32) -> UVector2 {
+        UVector2::make(self.x * _rhs, self.y * _rhs)
+    }
+}
+
+impl MulAssign<u32> for UVector2 {
+    /// Multiply a vector by a scalar
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::UVector2;
+    ///
+    /// let mut actual = UVector2::make(1, 2);
+    /// actual *= 2;
+    /// assert_eq!(actual, UVector2::make(2, 4));
+    /// ```
+    #[inline]
+    fn mul_assign(&mut self, _rhs: u32) {
+        self.x *= _rhs;
+        self.y *= _rhs;
+    }
+}
+
+impl Mul<UVector2> for UVector2 {
+    type Output = UVector2;
+
+    /// Multiply two vectors component-wise
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::UVector2;
+    ///
+    /// let actual = UVector2::make(1, 2) * UVector2::make(3, 5);
+    /// assert_eq!(actual, UVector2::make(3, 10));
+    /// ```
+    #[inline]
+    fn mul(self, _rhs: UVector2) -> UVector2 {
+        UVector2::make(self.x * _rhs.x, self.y * _rhs.y)
+    }
+}
+
+impl MulAssign<UVector2> for UVector2 {
+    /// Multiply a vector by another vector component-wise
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::UVector2;
+    ///
+    /// let mut actual = UVector2::make(1, 2);
+    /// actual *= UVector2::make(3, 5);
+    /// assert_eq!(actual, UVector2::make(3, 10));
+    /// ```
+    #[inline]
+    fn mul_assign(&mut self, _rhs: UVector2) {
+        self.x *= _rhs.x;
+        self.y *= _rhs.y;
+    }
+}
+
+impl Div<u32> for UVector2 {
+    type Output = UVector2;
+
+    /// Divide a vector by a scalar
+    ///
+    /// Division by zero panics, and division rounds towards zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::UVector2;
+    ///
+    /// let actual = UVector2::make(6, 10) / 2;
+    /// assert_eq!(actual, UVector2::make(3, 5));
+    /// ```
+    #[inline]
+    fn div(self, _rhs: u32) -> UVector2 {
+        UVector2::make(self.x / _rhs, self.y / _rhs)
+    }
+}
+
+impl DivAssign<u32> for UVector2 {
+    /// Divide a vector by a scalar
+    ///
+    /// Division by zero panics, and division rounds towards zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::UVector2;
+    ///
+    /// let mut actual = UVector2::make(6, 10);
+    /// actual /= 2;
+    /// assert_eq!(actual, UVector2::make(3, 5));
+    /// ```
+    #[inline]
+    fn div_assign(&mut self, _rhs: u32) {
+        self.x /= _rhs;
+        self.y /= _rhs;
+    }
+}
+
+impl Div<UVector2> for UVector2 {
+    type Output = UVector2;
+
+    /// Divide two vectors component-wise
+    ///
+    /// Division by zero panics, and division rounds towards zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::UVector2;
+    ///
+    /// let actual = UVector2::make(6, 10) / UVector2::make(3, 5);
+    /// assert_eq!(actual, UVector2::make(2, 2));
+    /// ```
+    #[inline]
+    fn div(self, _rhs: UVector2) -> UVector2 {
+        UVector2::make(self.x / _rhs.x, self.y / _rhs.y)
+    }
+}
+
+impl DivAssign<UVector2> for UVector2 {
+    /// Divide a vector by another vector component-wise
+    ///
+    /// Division by zero panics, and division rounds towards zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::UVector2;
+    ///
+    /// let mut actual = UVector2::make(6, 10);
+    /// actual /= UVector2::make(3, 5);
+    /// assert_eq!(actual, UVector2::make(2, 2));
+    /// ```
+    #[inline]
+    fn div_assign(&mut self, _rhs: UVector2) {
+        self.x /= _rhs.x;
+        self.y /= _rhs.y;
+    }
+}
+
+impl Display for UVector2 {
+    /// Formats as `<x, y>`, with the formatter's flags applied to each component
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::UVector2;
+    ///
+    /// let v = UVector2::make(1, 20);
+    /// assert_eq!(format!("{}", v), "<1, 20>");
+    /// assert_eq!(format!("{:02}", v), "<01, 20>");
+    /// ```
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        format::write_vector(f, &[self.x, self.y])
+    }
+}
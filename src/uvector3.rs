@@ -0,0 +1,695 @@
+use crate::format;
+#[cfg(not(feature = "std"))]
+use crate::math::Float;
+use crate::vector3::Vector3;
+
+use core::fmt;
+use core::fmt::{Display, Formatter};
+
+use core::ops::{
+    Add,
+    AddAssign,
+    Div,
+    DivAssign,
+    Index,
+    IndexMut,
+    Mul,
+    MulAssign,
+    Sub,
+    SubAssign,
+};
+
+#[repr(C)]
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct UVector3 {
+    pub x: u32,
+    pub y: u32,
+    pub z: u32,
+}
+
+impl UVector3 {
+    /// The vector <0, 0, 0>
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::UVector3;
+    ///
+    /// assert_eq!(UVector3::ZERO, UVector3::new());
+    /// ```
+    pub const ZERO: UVector3 = UVector3 { x: 0, y: 0, z: 0 };
+
+    /// The vector <1, 1, 1>
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::UVector3;
+    ///
+    /// assert_eq!(UVector3::ONE, UVector3::make(1, 1, 1));
+    /// ```
+    pub const ONE: UVector3 = UVector3 { x: 1, y: 1, z: 1 };
+
+    /// A unit vector <1, 0, 0> along the x-axis
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::UVector3;
+    ///
+    /// assert_eq!(UVector3::X, UVector3::make(1, 0, 0));
+    /// ```
+    pub const X: UVector3 = UVector3 { x: 1, y: 0, z: 0 };
+
+    /// A unit vector <0, 1, 0> along the y-axis
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::UVector3;
+    ///
+    /// assert_eq!(UVector3::Y, UVector3::make(0, 1, 0));
+    /// ```
+    pub const Y: UVector3 = UVector3 { x: 0, y: 1, z: 0 };
+
+    /// A unit vector <0, 0, 1> along the z-axis
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::UVector3;
+    ///
+    /// assert_eq!(UVector3::Z, UVector3::make(0, 0, 1));
+    /// ```
+    pub const Z: UVector3 = UVector3 { x: 0, y: 0, z: 1 };
+
+    /// Creates a vector <0, 0, 0>
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::UVector3;
+    ///
+    /// let actual = UVector3::new();
+    /// let expected = UVector3 { x: 0, y: 0, z: 0 };
+    /// assert_eq!(actual, expected);
+    /// ```
+    #[inline]
+    pub const fn new() -> UVector3 {
+        UVector3 { x: 0, y: 0, z: 0 }
+    }
+
+    /// Creates a vector from the provided values
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::UVector3;
+    ///
+    /// let actual = UVector3::make(1, 2, 3);
+    /// let expected = UVector3 { x: 1, y: 2, z: 3 };
+    /// assert_eq!(actual, expected);
+    /// ```
+    #[inline]
+    pub const fn make(x: u32, y: u32, z: u32) -> UVector3 {
+        UVector3 { x, y, z }
+    }
+
+    /// Find the dot product between two vectors
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::UVector3;
+    ///
+    /// let a = UVector3::make(1, 2, 3);
+    /// let b = UVector3::make(3, 5, 4);
+    /// assert_eq!(UVector3::dot(&a, &b), 25);
+    /// ```
+    #[inline]
+    pub fn dot(a: &UVector3, b: &UVector3) -> u32 {
+        a.x * b.x + a.y * b.y + a.z * b.z
+    }
+
+    /// Find the minimum (smallest) set of values
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::UVector3;
+    ///
+    /// let a = UVector3::make(1, 2, 3);
+    /// let b = UVector3::make(4, 0, 6);
+    /// assert_eq!(UVector3::min(&a, &b), UVector3::make(1, 0, 3));
+    /// ```
+    #[inline]
+    pub fn min(a: &UVector3, b: &UVector3) -> UVector3 {
+        UVector3::make(a.x.min(b.x), a.y.min(b.y), a.z.min(b.z))
+    }
+
+    /// Find the maximum (largest) set of values
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::UVector3;
+    ///
+    /// let a = UVector3::make(1, 2, 3);
+    /// let b = UVector3::make(4, 0, 6);
+    /// assert_eq!(UVector3::max(&a, &b), UVector3::make(4, 2, 6));
+    /// ```
+    #[inline]
+    pub fn max(a: &UVector3, b: &UVector3) -> UVector3 {
+        UVector3::make(a.x.max(b.x), a.y.max(b.y), a.z.max(b.z))
+    }
+
+    /// Find the vector with each component clamped between two vectors
+    ///
+    /// Panics if any component of `a` is greater than the same component of `b`.
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::UVector3;
+    ///
+    /// let a = UVector3::make(0, 0, 0);
+    /// let b = UVector3::make(2, 2, 2);
+    /// let actual = UVector3::make(5, 1, 3).clamped(&a, &b);
+    /// assert_eq!(actual, UVector3::make(2, 1, 2));
+    /// ```
+    #[inline]
+    pub fn clamped(&self, a: &UVector3, b: &UVector3) -> UVector3 {
+        UVector3::make(
+            self.x.clamp(a.x, b.x),
+            self.y.clamp(a.y, b.y),
+            self.z.clamp(a.z, b.z),
+        )
+    }
+
+    /// Gets the smallest component
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::UVector3;
+    ///
+    /// assert_eq!(UVector3::make(3, 5, 4).min_element(), 3);
+    /// ```
+    #[inline]
+    pub fn min_element(&self) -> u32 {
+        self.x.min(self.y).min(self.z)
+    }
+
+    /// Gets the largest component
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::UVector3;
+    ///
+    /// assert_eq!(UVector3::make(3, 5, 4).max_element(), 5);
+    /// ```
+    #[inline]
+    pub fn max_element(&self) -> u32 {
+        self.x.max(self.y).max(self.z)
+    }
+
+    /// Gets the sum of the components
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::UVector3;
+    ///
+    /// assert_eq!(UVector3::make(3, 5, 4).sum(), 12);
+    /// ```
+    #[inline]
+    pub fn sum(&self) -> u32 {
+        self.x + self.y + self.z
+    }
+
+    /// Gets the product of the components
+    ///
+    /// Useful for counting the cells in a grid or the invocations in a dispatch of this size.
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::UVector3;
+    ///
+    /// assert_eq!(UVector3::make(3, 5, 4).product(), 60);
+    /// ```
+    #[inline]
+    pub fn product(&self) -> u32 {
+        self.x * self.y * self.z
+    }
+
+    /// Creates a vector from a floating point vector with each component rounded down
+    ///
+    /// Components outside the range of `u32` saturate to its limits, and NaN becomes 0.
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::UVector3;
+    /// use vex::Vector3;
+    ///
+    /// let actual = UVector3::floor(&Vector3::make(1.5, 0.5, 2.7));
+    /// assert_eq!(actual, UVector3::make(1, 0, 2));
+    /// ```
+    #[inline]
+    pub fn floor(v: &Vector3) -> UVector3 {
+        UVector3::make(v.x.floor() as u32, v.y.floor() as u32, v.z.floor() as u32)
+    }
+
+    /// Creates a vector from a floating point vector with each component rounded up
+    ///
+    /// Components outside the range of `u32` saturate to its limits, and NaN becomes 0.
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::UVector3;
+    /// use vex::Vector3;
+    ///
+    /// let actual = UVector3::ceil(&Vector3::make(1.5, 0.5, 2.7));
+    /// assert_eq!(actual, UVector3::make(2, 1, 3));
+    /// ```
+    #[inline]
+    pub fn ceil(v: &Vector3) -> UVector3 {
+        UVector3::make(v.x.ceil() as u32, v.y.ceil() as u32, v.z.ceil() as u32)
+    }
+
+    /// Creates a vector from a floating point vector with each component rounded to the nearest integer, with halves rounded away from zero
+    ///
+    /// Components outside the range of `u32` saturate to its limits, and NaN becomes 0.
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::UVector3;
+    /// use vex::Vector3;
+    ///
+    /// let actual = UVector3::round(&Vector3::make(1.5, 0.5, 2.7));
+    /// assert_eq!(actual, UVector3::make(2, 1, 3));
+    /// ```
+    #[inline]
+    pub fn round(v: &Vector3) -> UVector3 {
+        UVector3::make(v.x.round() as u32, v.y.round() as u32, v.z.round() as u32)
+    }
+
+    /// Converts the vector to a floating point vector
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::UVector3;
+    /// use vex::Vector3;
+    ///
+    /// let actual = UVector3::make(1, 2, 3).as_f32();
+    /// assert_eq!(actual, Vector3::make(1.0, 2.0, 3.0));
+    /// ```
+    #[inline]
+    pub fn as_f32(&self) -> Vector3 {
+        Vector3::make(self.x as f32, self.y as f32, self.z as f32)
+    }
+}
+
+impl From<[u32; 3]> for UVector3 {
+    /// Creates a vector from an array of its components
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::UVector3;
+    ///
+    /// let actual = UVector3::from([1, 2, 3]);
+    /// assert_eq!(actual, UVector3::make(1, 2, 3));
+    /// ```
+    #[inline]
+    fn from(values: [u32; 3]) -> UVector3 {
+        UVector3::make(values[0], values[1], values[2])
+    }
+}
+
+impl From<UVector3> for [u32; 3] {
+    /// Creates an array from the components of a vector
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::UVector3;
+    ///
+    /// let actual: [u32; 3] = UVector3::make(1, 2, 3).into();
+    /// assert_eq!(actual, [1, 2, 3]);
+    /// ```
+    #[inline]
+    fn from(v: UVector3) -> [u32; 3] {
+        [v.x, v.y, v.z]
+    }
+}
+
+impl Index<u32> for UVector3 {
+    type Output = u32;
+
+    /// Looks up a component by index
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::UVector3;
+    ///
+    /// let v = UVector3::make(1, 2, 3);
+    /// assert_eq!(v[2], 3);
+    /// ```
+    #[inline]
+    fn index(&self, index: u32) -> &u32 {
+        match index {
+            0 => &self.x,
+            1 => &self.y,
+            2 => &self.z,
+            _ => panic!("Invalid index for UVector3: {}", index),
+        }
+    }
+}
+
+impl IndexMut<u32> for UVector3 {
+    /// Mutate a component by index
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::UVector3;
+    ///
+    /// let mut v = UVector3::make(1, 2, 3);
+    /// v[2] = 8;
+    /// assert_eq!(v[2], 8);
+    /// ```
+    #[inline]
+    fn index_mut(&mut self, index: u32) -> &mut u32 {
+        match index {
+            0 => &mut self.x,
+            1 => &mut self.y,
+            2 => &mut self.z,
+            _ => panic!("Invalid index for UVector3: {}", index),
+        }
+    }
+}
+
+impl Add<u32> for UVector3 {
+    type Output = UVector3;
+
+    /// Add a scalar to a vector
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::UVector3;
+    ///
+    /// let actual = UVector3::make(1, 2, 3) + 2;
+    /// assert_eq!(actual, UVector3::make(3, 4, 5));
+    /// ```
+    #[inline]
+    fn add(self, _rhs: u32) -> UVector3 {
+        UVector3::make(self.x + _rhs, self.y + _rhs, self.z + _rhs)
+    }
+}
+
+impl AddAssign<u32> for UVector3 {
+    /// Increment a vector by a scalar
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::UVector3;
+    ///
+    /// let mut actual = UVector3::make(1, 2, 3);
+    /// actual += 2;
+    /// assert_eq!(actual, UVector3::make(3, 4, 5));
+    /// ```
+    #[inline]
+    fn add_assign(&mut self, _rhs: u32) {
+        self.x += _rhs;
+        self.y += _rhs;
+        self.z += _rhs;
+    }
+}
+
+impl Add<UVector3> for UVector3 {
+    type Output = UVector3;
+
+    /// Add two vectors
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::UVector3;
+    ///
+    /// let actual = UVector3::make(1, 2, 3) + UVector3::make(3, 5, 4);
+    /// assert_eq!(actual, UVector3::make(4, 7, 7));
+    /// ```
+    #[inline]
+    fn add(self, _rhs: UVector3) -> UVector3 {
+        UVector3::make(self.x + _rhs.x, self.y + _rhs.y, self.z + _rhs.z)
+    }
+}
+
+impl AddAssign<UVector3> for UVector3 {
+    /// Increment a vector by another vector
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::UVector3;
+    ///
+    /// let mut actual = UVector3::make(1, 2, 3);
+    /// actual += UVector3::make(3, 5, 4);
+    /// assert_eq!(actual, UVector3::make(4, 7, 7));
+    /// ```
+    #[inline]
+    fn add_assign(&mut self, _rhs: UVector3) {
+        self.x += _rhs.x;
+        self.y += _rhs.y;
+        self.z += _rhs.z;
+    }
+}
+
+impl Sub<u32> for UVector3 {
+    type Output = UVector3;
+
+    /// Subtract a scalar from a vector
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::UVector3;
+    ///
+    /// let actual = UVector3::make(3, 5, 4) - 1;
+    /// assert_eq!(actual, UVector3::make(2, 4, 3));
+    /// ```
+    #[inline]
+    fn sub(self, _rhs: u32) -> UVector3 {
+        UVector3::make(self.x - _rhs, self.y - _rhs, self.z - _rhs)
+    }
+}
+
+impl SubAssign<u32> for UVector3 {
+    /// Decrement a vector by a scalar
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::UVector3;
+    ///
+    /// let mut actual = UVector3::make(3, 5, 4);
+    /// actual -= 1;
+    /// assert_eq!(actual, UVector3::make(2, 4, 3));
+    /// ```
+    #[inline]
+    fn sub_assign(&mut self, _rhs: u32) {
+        self.x -= _rhs;
+        self.y -= _rhs;
+        self.z -= _rhs;
+    }
+}
+
+impl Sub<UVector3> for UVector3 {
+    type Output = UVector3;
+
+    /// Subtract two vectors
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::UVector3;
+    ///
+    /// let actual = UVector3::make(3, 5, 4) - UVector3::make(1, 2, 3);
+    /// assert_eq!(actual, UVector3::make(2, 3, 1));
+    /// ```
+    #[inline]
+    fn sub(self, _rhs: UVector3) -> UVector3 {
+        UVector3::make(self.x - _rhs.x, self.y - _rhs.y, self.z - _rhs.z)
+    }
+}
+
+impl SubAssign<UVector3> for UVector3 {
+    /// Decrement a vector by another vector
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::UVector3;
+    ///
+    /// let mut actual = UVector3::make(3, 5, 4);
+    /// actual -= UVector3::make(1, 2, 3);
+    /// assert_eq!(actual, UVector3::make(2, 3, 1));
+    /// ```
+    #[inline]
+    fn sub_assign(&mut self, _rhs: UVector3) {
+        self.x -= _rhs.x;
+        self.y -= _rhs.y;
+        self.z -= _rhs.z;
+    }
+}
+
+impl Mul<u32> for UVector3 {
+    type Output = UVector3;
+
+    /// Multiply a vector by a scalar
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::UVector3;
+    ///
+    /// let actual = UVector3::make(1, 2, 3) * 2;
+    /// assert_eq!(actual, UVector3::make(2, 4, 6));
+    /// ```
+    #[inline]
+    fn mul(self, _rhs: u32) -> UVector3 {
+        UVector3::make(self.x * _rhs, self.y * _rhs, self.z * _rhs)
+    }
+}
+
+impl MulAssign<u32> for UVector3 {
+    /// Multiply a vector by a scalar
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::UVector3;
+    ///
+    /// let mut actual = UVector3::make(1, 2, 3);
+    /// actual *= 2;
+    /// assert_eq!(actual, UVector3::make(2, 4, 6));
+    /// ```
+    #[inline]
+    fn mul_assign(&mut self, _rhs: u32) {
+        self.x *= _rhs;
+        self.y *= _rhs;
+        self.z *= _rhs;
+    }
+}
+
+impl Mul<UVector3> for UVector3 {
+    type Output = UVector3;
+
+    /// Multiply two vectors component-wise
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::UVector3;
+    ///
+    /// let actual = UVector3::make(1, 2, 3) * UVector3::make(3, 5, 4);
+    /// assert_eq!(actual, UVector3::make(3, 10, 12));
+    /// ```
+    #[inline]
+    fn mul(self, _rhs: UVector3) -> UVector3 {
+        UVector3::make(self.x * _rhs.x, self.y * _rhs.y, self.z * _rhs.z)
+    }
+}
+
+impl MulAssign<UVector3> for UVector3 {
+    /// Multiply a vector by another vector component-wise
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::UVector3;
+    ///
+    /// let mut actual = UVector3::make(1, 2, 3);
+    /// actual *= UVector3::make(3, 5, 4);
+    /// assert_eq!(actual, UVector3::make(3, 10, 12));
+    /// ```
+    #[inline]
+    fn mul_assign(&mut self, _rhs: UVector3) {
+        self.x *= _rhs.x;
+        self.y *= _rhs.y;
+        self.z *= _rhs.z;
+    }
+}
+
+impl Div<u32> for UVector3 {
+    type Output = UVector3;
+
+    /// Divide a vector by a scalar
+    ///
+    /// Division by zero panics, and division rounds towards zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::UVector3;
+    ///
+    /// let actual = UVector3::make(6, 10, 8) / 2;
+    /// assert_eq!(actual, UVector3::make(3, 5, 4));
+    /// ```
+    #[inline]
+    fn div(self, _rhs: u32) -> UVector3 {
+        UVector3::make(self.x / _rhs, self.y / _rhs, self.z / _rhs)
+    }
+}
+
+impl DivAssign<u32> for UVector3 {
+    /// Divide a vector by a scalar
+    ///
+    /// Division by zero panics, and division rounds towards zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::UVector3;
+    ///
+    /// let mut actual = UVector3::make(6, 10, 8);
+    /// actual /= 2;
+    /// assert_eq!(actual, UVector3::make(3, 5, 4));
+    /// ```
+    #[inline]
+    fn div_assign(&mut self, _rhs: u32) {
+        self.x /= _rhs;
+        self.y /= _rhs;
+        self.z /= _rhs;
+    }
+}
+
+impl Div<UVector3> for UVector3 {
+    type Output = UVector3;
+
+    /// Divide two vectors component-wise
+    ///
+    /// Division by zero panics, and division rounds towards zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::UVector3;
+    ///
+    /// let actual = UVector3::make(6, 10, 9) / UVector3::make(3, 5, 3);
+    /// assert_eq!(actual, UVector3::make(2, 2, 3));
+    /// ```
+    #[inline]
+    fn div(self, _rhs: UVector3) -> UVector3 {
+        UVector3::make(self.x / _rhs.x, self.y / _rhs.y, self.z / _rhs.z)
+    }
+}
+
+impl DivAssign<UVector3> for UVector3 {
+    /// Divide a vector by another vector component-wise
+    ///
+    /// Division by zero panics, and division rounds towards zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::UVector3;
+    ///
+    /// let mut actual = UVector3::make(6, 10, 9);
+    /// actual /= UVector3::make(3, 5, 3);
+    /// assert_eq!(actual, UVector3::make(2, 2, 3));
+    /// ```
+    #[inline]
+    fn div_assign(&mut self, _rhs: UVector3) {
+        self.x /= _rhs.x;
+        self.y /= _rhs.y;
+        self.z /= _rhs.z;
+    }
+}
+
+impl Display for UVector3 {
+    /// Formats as `<x, y, z>`, with the formatter's flags applied to each component
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::UVector3;
+    ///
+    /// let v = UVector3::make(1, 20, 3);
+    /// assert_eq!(format!("{}", v), "<1, 20, 3>");
+    /// assert_eq!(format!("{:<2}", v), "<1 , 20, 3 >");
+    /// ```
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        format::write_vector(f, &[self.x, self.y, self.z])
+    }
+}
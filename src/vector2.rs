@@ -1,13 +1,20 @@
 use crate::common;
+use crate::epsilon::Epsilon;
+use crate::format;
+#[cfg(not(feature = "std"))]
+use crate::math::Float;
+use crate::normalized;
+use crate::parse::{self, ParseError};
 use crate::vector3::Vector3;
 
-use std::cmp;
-use std::convert::From;
-use std::f32::EPSILON;
-use std::fmt;
-use std::fmt::{Display, Formatter};
+use core::cmp;
+use core::convert::From;
+use core::fmt;
+use core::slice;
+use core::fmt::{Display, Formatter};
+use core::str::FromStr;
 
-use std::ops::{
+use core::ops::{
     Index,
     IndexMut,
     Neg,
@@ -21,305 +28,1296 @@ use std::ops::{
     DivAssign,
 };
 
-#[repr(C, packed)]
+#[repr(C)]
 #[derive(Copy, Clone, Debug)]
+#[cfg_attr(feature = "rkyv", derive(rkyv::Archive, rkyv::Deserialize, rkyv::Serialize))]
+#[cfg_attr(feature = "rkyv", rkyv(derive(Debug), compare(PartialEq)))]
 pub struct Vector2 {
     pub x: f32,
     pub y: f32,
 }
 
 impl Vector2 {
+    /// A vector <0.0, 0.0>
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Vector2;
+    /// 
+    /// assert_eq!(Vector2::ZERO, Vector2::new());
+    /// ```
+    pub const ZERO: Vector2 = Vector2 { x: 0.0, y: 0.0 };
+
+    /// A vector <1.0, 1.0>
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Vector2;
+    /// 
+    /// assert_eq!(Vector2::ONE, Vector2::one());
+    /// ```
+    pub const ONE: Vector2 = Vector2 { x: 1.0, y: 1.0 };
+
+    /// A unit vector <1.0, 0.0> along the x-axis
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Vector2;
+    ///
+    /// assert_eq!(Vector2::X, Vector2::make(1.0, 0.0));
+    /// ```
+    pub const X: Vector2 = Vector2 { x: 1.0, y: 0.0 };
+
+    /// A unit vector <0.0, 1.0> along the y-axis
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Vector2;
+    ///
+    /// assert_eq!(Vector2::Y, Vector2::make(0.0, 1.0));
+    /// ```
+    pub const Y: Vector2 = Vector2 { x: 0.0, y: 1.0 };
+
     /// Creates a vector <0.0, 0.0>
     ///
     /// # Examples
     /// ```
     /// use vex::Vector2;
     /// 
-    /// let actual = Vector2::new();
-    /// let expected = Vector2 { x: 0.0, y: 0.0 };
+    /// let actual = Vector2::new();
+    /// let expected = Vector2 { x: 0.0, y: 0.0 };
+    /// assert_eq!(actual, expected);
+    /// ```
+    #[inline]
+    pub const fn new() -> Vector2 {
+        Vector2 { x: 0.0, y: 0.0 }
+    }
+
+    /// Creates a vector <1.0, 1.0>
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Vector2;
+    /// 
+    /// let actual = Vector2::one();
+    /// let expected = Vector2 { x: 1.0, y: 1.0 };
+    /// assert_eq!(actual, expected);
+    /// ```
+    #[inline]
+    pub const fn one() -> Vector2 {
+        Vector2 { x: 1.0, y: 1.0 }
+    }
+
+    /// Creates a vector from the provided values
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Vector2;
+    /// 
+    /// let actual = Vector2::make(1.0, 2.0);
+    /// let expected = Vector2 { x: 1.0, y: 2.0 };
+    /// assert_eq!(actual, expected);
+    /// ```
+    #[inline]
+    pub const fn make(x: f32, y: f32) -> Vector2 {
+        Vector2 { x, y }
+    }
+
+    /// Find the dot product between two vectors
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Vector2;
+    /// 
+    /// let a = Vector2::make(1.0, 0.0);
+    /// let b = Vector2::make(0.0, 1.0);
+    /// let actual = Vector2::dot(&a, &b);
+    /// let expected = 0.0;
+    /// assert_eq!(actual, expected);
+    /// ```
+    #[inline]
+    pub fn dot(a: &Vector2, b: &Vector2) -> f32 {
+        a.x * b.x + a.y * b.y
+    }
+
+    /// Find the cross product between two vectors
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Vector2;
+    /// 
+    /// let a = Vector2::make(1.0, 0.0);
+    /// let b = Vector2::make(0.0, 1.0);
+    /// let actual = Vector2::cross(&a, &b);
+    /// let expected = 1.0;
+    /// assert_eq!(actual, expected);
+    /// ```
+    #[inline]
+    pub fn cross(a: &Vector2, b: &Vector2) -> f32 {
+        a.x * b.y - a.y * b.x
+    }
+
+    /// Find the unsigned angle between two vectors (in radians)
+    /// 
+    /// The result is in `[0, PI]`, and is 0 if either vector has zero length.
+    /// 
+    /// # Examples
+    /// ```
+    /// use std::f32::consts::PI;
+    /// use vex::Vector2;
+    /// 
+    /// let a = Vector2::make(1.0, 0.0);
+    /// let b = Vector2::make(0.0, -2.0);
+    /// assert_eq!(Vector2::angle_between(&a, &b), PI / 2.0);
+    /// assert_eq!(Vector2::angle_between(&a, &Vector2::new()), 0.0);
+    /// ```
+    #[inline]
+    pub fn angle_between(a: &Vector2, b: &Vector2) -> f32 {
+        Vector2::signed_angle(a, b).abs()
+    }
+
+    /// Find the signed angle (in radians) to rotate `a` onto `b`
+    /// 
+    /// Counter-clockwise rotations are positive, so the result is in `[-PI, PI]`. The result is 0
+    /// if either vector has zero length.
+    /// 
+    /// # Examples
+    /// ```
+    /// use std::f32::consts::PI;
+    /// use vex::Vector2;
+    /// 
+    /// let a = Vector2::make(1.0, 0.0);
+    /// assert_eq!(Vector2::signed_angle(&a, &Vector2::make(0.0, 2.0)), PI / 2.0);
+    /// assert_eq!(Vector2::signed_angle(&a, &Vector2::make(0.0, -2.0)), -PI / 2.0);
+    /// assert_eq!(Vector2::signed_angle(&a, &Vector2::new()), 0.0);
+    /// ```
+    #[inline]
+    pub fn signed_angle(a: &Vector2, b: &Vector2) -> f32 {
+        Vector2::cross(a, b).atan2(Vector2::dot(a, b))
+    }
+
+    /// Find the cross product between a scalar (left) and vector (right)
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Vector2;
+    /// 
+    /// let s = 1.0;
+    /// let v = Vector2::make(1.0, 0.0);
+    /// let actual = Vector2::cross_scalar_vec(s, &v);
+    /// let expected = Vector2::make(0.0, 1.0);
+    /// assert_eq!(actual, expected);
+    /// ```
+    #[inline]
+    pub fn cross_scalar_vec(s: f32, v: &Vector2) -> Vector2 {
+        Vector2::make(-s * v.y, s * v.x)
+    }
+
+    /// Find the cross product between a vector (left) and scalar (right)
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Vector2;
+    /// 
+    /// let s = 1.0;
+    /// let v = Vector2::make(1.0, 0.0);
+    /// let actual = Vector2::cross_vec_scalar(&v, s);
+    /// let expected = Vector2::make(0.0, -1.0);
+    /// assert_eq!(actual, expected);
+    /// ```
+    #[inline]
+    pub fn cross_vec_scalar(v: &Vector2, s: f32) -> Vector2 {
+        Vector2::make(s * v.y, -s * v.x)
+    }
+
+    /// Find the minimum (component-wise) vector between two vectors
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Vector2;
+    /// 
+    /// let a = Vector2::make(1.0, 4.0);
+    /// let b = Vector2::make(2.0, 3.0);
+    /// let actual = Vector2::min(&a, &b);
+    /// let expected = Vector2::make(1.0, 3.0);
+    /// assert_eq!(actual, expected);
+    /// ```
+    #[inline]
+    pub fn min(a: &Vector2, b: &Vector2) -> Vector2 {
+        Vector2::make(a.x.min(b.x), a.y.min(b.y))
+    }
+
+    /// Find the maximum (component-wise) vector between two vectors
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Vector2;
+    /// 
+    /// let a = Vector2::make(1.0, 4.0);
+    /// let b = Vector2::make(2.0, 3.0);
+    /// let actual = Vector2::max(&a, &b);
+    /// let expected = Vector2::make(2.0, 4.0);
+    /// assert_eq!(actual, expected);
+    /// ```
+    #[inline]
+    pub fn max(a: &Vector2, b: &Vector2) -> Vector2 {
+        Vector2::make(a.x.max(b.x), a.y.max(b.y))
+    }
+
+    /// Find the smooth minimum (component-wise) of two vectors, blending where components are
+    /// within `k` of each other
+    ///
+    /// Each component is the quadratic polynomial [`smooth_min`](fn.smooth_min.html) of the
+    /// components.
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Vector2;
+    /// 
+    /// let a = Vector2::make(1.0, 2.0);
+    /// let b = Vector2::make(3.0, 2.0);
+    /// let actual = Vector2::smooth_min(&a, &b, 1.0);
+    /// assert_eq!(actual, Vector2::make(1.0, 1.75));
+    /// ```
+    #[inline]
+    pub fn smooth_min(a: &Vector2, b: &Vector2, k: f32) -> Vector2 {
+        Vector2::make(
+            common::smooth_min(a.x, b.x, k),
+            common::smooth_min(a.y, b.y, k),
+        )
+    }
+
+    /// Find the smooth maximum (component-wise) of two vectors, blending where components are
+    /// within `k` of each other
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Vector2;
+    /// 
+    /// let a = Vector2::make(1.0, 2.0);
+    /// let b = Vector2::make(3.0, 2.0);
+    /// let actual = Vector2::smooth_max(&a, &b, 1.0);
+    /// assert_eq!(actual, Vector2::make(3.0, 2.25));
+    /// ```
+    #[inline]
+    pub fn smooth_max(a: &Vector2, b: &Vector2, k: f32) -> Vector2 {
+        Vector2::make(
+            common::smooth_max(a.x, b.x, k),
+            common::smooth_max(a.y, b.y, k),
+        )
+    }
+
+    /// Find the soft absolute value (component-wise) of a vector, `sqrt(x^2 + k^2)`
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Vector2;
+    /// 
+    /// let actual = Vector2::make(-3.0, 0.0).soft_abs(4.0);
+    /// assert_eq!(actual, Vector2::make(5.0, 4.0));
+    /// ```
+    #[inline]
+    pub fn soft_abs(&self, k: f32) -> Vector2 {
+        Vector2::make(
+            common::soft_abs(self.x, k),
+            common::soft_abs(self.y, k),
+        )
+    }
+
+    /// Find the vector with a function applied to each component
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Vector2;
+    /// 
+    /// let actual = Vector2::make(1.0, -2.0).map(|c| c * c);
+    /// assert_eq!(actual, Vector2::make(1.0, 4.0));
+    /// ```
+    #[inline]
+    pub fn map<F: Fn(f32) -> f32>(&self, f: F) -> Vector2 {
+        Vector2::make(
+            f(self.x),
+            f(self.y),
+        )
+    }
+
+    /// Find the vector combining each pair of components from two vectors with a function
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Vector2;
+    /// 
+    /// let a = Vector2::make(1.0, -2.0);
+    /// let b = Vector2::make(3.0, 4.0);
+    /// let actual = a.zip_with(&b, f32::max);
+    /// assert_eq!(actual, Vector2::make(3.0, 4.0));
+    /// ```
+    #[inline]
+    pub fn zip_with<F: Fn(f32, f32) -> f32>(&self, other: &Vector2, f: F) -> Vector2 {
+        Vector2::make(
+            f(self.x, other.x),
+            f(self.y, other.y),
+        )
+    }
+
+    /// Gets the smallest component
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Vector2;
+    /// 
+    /// assert_eq!(Vector2::make(1.0, -2.0).min_element(), -2.0);
+    /// ```
+    #[inline]
+    pub fn min_element(&self) -> f32 {
+        self.x.min(self.y)
+    }
+
+    /// Gets the largest component
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Vector2;
+    /// 
+    /// assert_eq!(Vector2::make(1.0, -2.0).max_element(), 1.0);
+    /// ```
+    #[inline]
+    pub fn max_element(&self) -> f32 {
+        self.x.max(self.y)
+    }
+
+    /// Gets the sum of the components
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Vector2;
+    /// 
+    /// assert_eq!(Vector2::make(1.0, -2.0).sum(), -1.0);
+    /// ```
+    #[inline]
+    pub fn sum(&self) -> f32 {
+        self.x + self.y
+    }
+
+    /// Find the vector with the reciprocal of each component
+    ///
+    /// Zero components become infinite, so use `recip_or` when they can occur.
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Vector2;
+    /// 
+    /// let actual = Vector2::make(2.0, -4.0).recip();
+    /// assert_eq!(actual, Vector2::make(0.5, -0.25));
+    /// ```
+    #[inline]
+    pub fn recip(&self) -> Vector2 {
+        Vector2::make(
+            1.0 / self.x,
+            1.0 / self.y,
+        )
+    }
+
+    /// Find the vector with the reciprocal of each component, using `fallback` for zero components
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Vector2;
+    /// 
+    /// let actual = Vector2::make(2.0, 0.0).recip_or(0.0);
+    /// assert_eq!(actual, Vector2::make(0.5, 0.0));
+    /// ```
+    #[inline]
+    pub fn recip_or(&self, fallback: f32) -> Vector2 {
+        self.map(|c| if c == 0.0 { fallback } else { 1.0 / c })
+    }
+
+    /// Find the vector with the reciprocal square root of each component
+    ///
+    /// Zero components become infinite and negative components become NaN.
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Vector2;
+    /// 
+    /// let actual = Vector2::make(4.0, 16.0).rsqrt();
+    /// assert_eq!(actual, Vector2::make(0.5, 0.25));
+    /// ```
+    #[inline]
+    pub fn rsqrt(&self) -> Vector2 {
+        Vector2::make(
+            1.0 / self.x.sqrt(),
+            1.0 / self.y.sqrt(),
+        )
+    }
+
+    /// Linearly interpolate between two vectors
+    ///
+    /// Values of `t` outside `[0, 1]` extrapolate along the line through both vectors.
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Vector2;
+    /// 
+    /// let a = Vector2::make(2.0, 0.0);
+    /// let b = Vector2::make(4.0, 8.0);
+    /// assert_eq!(Vector2::lerp(&a, &b, 0.5), Vector2::make(3.0, 4.0));
+    /// assert_eq!(Vector2::lerp(&a, &b, 2.0), Vector2::make(6.0, 16.0));
+    /// ```
+    #[inline]
+    pub fn lerp(a: &Vector2, b: &Vector2, t: f32) -> Vector2 {
+        *a + (*b - *a) * t
+    }
+
+    /// Linearly interpolate between two vectors with either a scalar factor or one per component
+    ///
+    /// Matches GLSL's `mix`, giving `a * (1 - t) + b * t` for each component, so a factor of 1
+    /// returns exactly `b`.
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Vector2;
+    /// 
+    /// let a = Vector2::make(0.0, 10.0);
+    /// let b = Vector2::make(4.0, 20.0);
+    /// assert_eq!(Vector2::mix(&a, &b, 0.5), Vector2::make(2.0, 15.0));
+    ///
+    /// let t = Vector2::make(0.0, 1.0);
+    /// assert_eq!(Vector2::mix(&a, &b, t), Vector2::make(0.0, 20.0));
+    /// ```
+    #[inline]
+    pub fn mix<T: common::MixFactor<Vector2>>(a: &Vector2, b: &Vector2, t: T) -> Vector2 {
+        let t = t.spread();
+        Vector2::make(
+            a.x * (1.0 - t.x) + b.x * t.x,
+            a.y * (1.0 - t.y) + b.y * t.y,
+        )
+    }
+
+    /// Spherically interpolate between two unit vectors at a constant angular rate
+    ///
+    /// Values of `t` outside `[0, 1]` keep rotating along the same arc. Nearly parallel vectors
+    /// fall back to a normalized lerp, and opposite vectors rotate through an arbitrary
+    /// perpendicular direction.
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Vector2;
+    /// 
+    /// let a = Vector2::make(1.0, 0.0);
+    /// let b = Vector2::make(0.0, 1.0);
+    /// let actual = Vector2::slerp(&a, &b, 1.0 / 3.0);
+    /// let angle = std::f32::consts::PI / 6.0;
+    /// let mut expected = Vector2::new();
+    /// expected.x = angle.cos();
+    /// expected.y = angle.sin();
+    /// assert!((actual - expected).mag() < 0.0001);
+    ///
+    /// let actual = Vector2::slerp(&a, &-a, 0.5);
+    /// assert!((actual.mag() - 1.0).abs() < 0.0001);
+    /// assert!(Vector2::dot(&actual, &a).abs() < 0.0001);
+    /// ```
+    #[inline]
+    pub fn slerp(a: &Vector2, b: &Vector2, t: f32) -> Vector2 {
+        let cosine = Vector2::dot(a, b).clamp(-1.0, 1.0);
+
+        // fall back to nlerp when the vectors are nearly parallel
+        if cosine > 0.9995 {
+            return Vector2::lerp(a, b, t).normalized();
+        }
+
+        // the arc is ambiguous for opposite vectors, so pick any perpendicular one
+        if cosine < -0.9995 {
+            let perpendicular = Vector2::perpendicular(a);
+            let angle = t * core::f32::consts::PI;
+            return *a * angle.cos() + perpendicular * angle.sin();
+        }
+
+        let theta = cosine.acos();
+        let sine = theta.sin();
+        let wa = ((1.0 - t) * theta).sin() / sine;
+        let wb = (t * theta).sin() / sine;
+        *a * wa + *b * wb
+    }
+
+    /// Interpolate between two vectors, easing in and out with a smoothstep curve
+    ///
+    /// `t` is clamped to `[0, 1]`, so the result never overshoots either vector.
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Vector2;
+    /// 
+    /// let a = Vector2::make(2.0, 0.0);
+    /// let b = Vector2::make(4.0, 8.0);
+    /// assert_eq!(Vector2::smooth_step(&a, &b, 0.5), Vector2::make(3.0, 4.0));
+    /// assert_eq!(Vector2::smooth_step(&a, &b, 0.25), Vector2::make(2.3125, 1.25));
+    /// assert_eq!(Vector2::smooth_step(&a, &b, -1.0), a);
+    /// assert_eq!(Vector2::smooth_step(&a, &b, 2.0), b);
+    /// ```
+    #[inline]
+    pub fn smooth_step(a: &Vector2, b: &Vector2, t: f32) -> Vector2 {
+        let t = t.clamp(0.0, 1.0);
+        Vector2::lerp(a, b, t * t * (3.0 - 2.0 * t))
+    }
+
+    /// Find the angle and distance to a target if it's inside an observer's field of view
+    /// 
+    /// The field of view is a sector around `observer_dir` spanning `fov` radians in total, so a
+    /// target is visible when it's within `fov / 2` of the direction and no farther than
+    /// `max_distance`. `observer_dir` doesn't need to be normalized. A target at the observer's
+    /// position is always visible, and a zero-length direction sees nothing else.
+    /// 
+    /// # Examples
+    /// ```
+    /// use std::f32::consts::PI;
+    /// use vex::Vector2;
+    /// 
+    /// let observer = Vector2::new();
+    /// let dir = Vector2::make(1.0, 0.0);
+    /// let (angle, distance) = Vector2::fov_check(&observer, &dir, &Vector2::make(3.0, 3.0), PI / 2.0, 5.0).unwrap();
+    /// assert!((angle - PI / 4.0).abs() < 0.000001);
+    /// assert!((distance - (18.0f32).sqrt()).abs() < 0.000001);
+    /// 
+    /// assert_eq!(Vector2::fov_check(&observer, &dir, &Vector2::make(10.0, 0.0), PI / 2.0, 5.0), None);
+    /// assert_eq!(Vector2::fov_check(&observer, &dir, &Vector2::make(0.0, 3.0), PI / 2.0, 5.0), None);
+    /// ```
+    #[inline]
+    pub fn fov_check(
+        observer_pos: &Vector2,
+        observer_dir: &Vector2,
+        target_pos: &Vector2,
+        fov: f32,
+        max_distance: f32,
+    ) -> Option<(f32, f32)> {
+        let offset = *target_pos - *observer_pos;
+        let distance = offset.mag();
+        if distance > max_distance {
+            return None;
+        }
+
+        if distance == 0.0 {
+            return Some((0.0, 0.0));
+        }
+
+        let length = observer_dir.mag();
+        if length == 0.0 {
+            return None;
+        }
+
+        let cosine = Vector2::dot(observer_dir, &offset) / (length * distance);
+        let angle = cosine.clamp(-1.0, 1.0).acos();
+        if angle <= fov / 2.0 {
+            Some((angle, distance))
+        } else {
+            None
+        }
+    }
+
+    /// Determine whether or not a target is inside an observer's field of view
+    /// 
+    /// See [`fov_check`](#method.fov_check) for how the field of view is defined.
+    /// 
+    /// # Examples
+    /// ```
+    /// use std::f32::consts::PI;
+    /// use vex::Vector2;
+    /// 
+    /// let observer = Vector2::new();
+    /// let dir = Vector2::make(1.0, 0.0);
+    /// assert!(Vector2::in_fov(&observer, &dir, &Vector2::make(3.0, 3.0), PI / 2.0, 5.0));
+    /// assert!(!Vector2::in_fov(&observer, &dir, &Vector2::make(3.0, 3.0), PI / 3.0, 5.0));
+    /// assert!(!Vector2::in_fov(&observer, &dir, &Vector2::make(10.0, 0.0), PI / 2.0, 5.0));
+    /// ```
+    #[inline]
+    pub fn in_fov(
+        observer_pos: &Vector2,
+        observer_dir: &Vector2,
+        target_pos: &Vector2,
+        fov: f32,
+        max_distance: f32,
+    ) -> bool {
+        Vector2::fov_check(observer_pos, observer_dir, target_pos, fov, max_distance).is_some()
+    }
+
+    /// Find the projection of the vector onto another vector
+    /// 
+    /// Projecting onto a zero vector produces a zero vector.
+    /// 
+    /// # Examples
+    /// ```
+    /// use vex::Vector2;
+    /// 
+    /// let actual = Vector2::make(2.0, 3.0).project_onto(&Vector2::make(4.0, 0.0));
+    /// let expected = Vector2::make(2.0, 0.0);
+    /// assert_eq!(actual, expected);
+    /// assert_eq!(Vector2::make(2.0, 3.0).project_onto(&Vector2::new()), Vector2::new());
+    /// ```
+    #[inline]
+    pub fn project_onto(&self, other: &Vector2) -> Vector2 {
+        let length_sq = other.mag_sq();
+        if length_sq == 0.0 {
+            return Vector2::new();
+        }
+
+        *other * (Vector2::dot(self, other) / length_sq)
+    }
+
+    /// Find the part of the vector perpendicular to another vector
+    /// 
+    /// This is the vector minus its [`project_onto`](#method.project_onto) projection.
+    /// 
+    /// # Examples
+    /// ```
+    /// use vex::Vector2;
+    /// 
+    /// let actual = Vector2::make(2.0, 3.0).reject_from(&Vector2::make(4.0, 0.0));
+    /// let expected = Vector2::make(0.0, 3.0);
+    /// assert_eq!(actual, expected);
+    /// ```
+    #[inline]
+    pub fn reject_from(&self, other: &Vector2) -> Vector2 {
+        *self - self.project_onto(other)
+    }
+
+    /// Find the reflection of the vector about a surface with the provided normal
+    /// 
+    /// `normal` should be normalized.
+    /// 
+    /// # Examples
+    /// ```
+    /// use vex::Vector2;
+    /// 
+    /// let actual = Vector2::make(1.0, -1.0).reflect(&Vector2::make(0.0, 1.0));
+    /// let expected = Vector2::make(1.0, 1.0);
+    /// assert_eq!(actual, expected);
+    /// ```
+    #[inline]
+    pub fn reflect(&self, normal: &Vector2) -> Vector2 {
+        *self - *normal * (2.0 * Vector2::dot(self, normal))
+    }
+
+    /// Find the refraction of the vector through a surface with the provided normal
+    /// 
+    /// `eta` is the ratio of the refractive indices (incident over transmitted), and both the
+    /// vector and `normal` should be normalized with `normal` facing against the vector. Returns
+    /// `None` on total internal reflection.
+    /// 
+    /// # Examples
+    /// ```
+    /// use vex::Vector2;
+    /// 
+    /// let incident = Vector2::make(1.0, -1.0).normalized();
+    /// let normal = Vector2::make(0.0, 1.0);
+    /// assert_eq!(incident.refract(&normal, 1.0), Some(incident));
+    /// 
+    /// let actual = incident.refract(&normal, 1.0 / 1.5).unwrap();
+    /// let sine = actual.x / actual.mag();
+    /// assert!((sine - incident.x / 1.5).abs() < 0.000001);
+    /// 
+    /// assert_eq!(incident.refract(&normal, 1.5), None);
+    /// ```
+    #[inline]
+    pub fn refract(&self, normal: &Vector2, eta: f32) -> Option<Vector2> {
+        let cosine = Vector2::dot(normal, self);
+        let k = 1.0 - eta * eta * (1.0 - cosine * cosine);
+        if k < 0.0 {
+            return None;
+        }
+
+        Some(*self * eta - *normal * (eta * cosine + k.sqrt()))
+    }
+
+    #[inline]
+    fn perpendicular(a: &Vector2) -> Vector2 {
+        a.skewed()
+    }
+
+    /// Find the clamped (component-wise) vector between two vectors
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Vector2;
+    /// 
+    /// let a = Vector2::make(1.0, 3.0);
+    /// let b = Vector2::make(2.0, 4.0);
+    /// let mut actual = Vector2::make(0.0, 5.0);
+    /// actual.clamp(&a, &b);
+    /// let expected = Vector2::make(1.0, 4.0);
+    /// assert_eq!(actual, expected);
+    /// ```
+    #[inline]
+    pub fn clamp(&mut self, a: &Vector2, b: &Vector2) {
+        let low = Self::min(a, b);
+        let high = Self::max(a, b);
+        let result = Self::max(&low, &Self::min(self, &high));
+        self.set(result.x, result.y);
+    }
+
+    /// Set the components of a vector
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Vector2;
+    /// 
+    /// let mut actual = Vector2::new();
+    /// actual.set(1.0, 2.0);
+    /// let expected = Vector2::make(1.0, 2.0);
+    /// assert_eq!(actual, expected);
+    /// ```
+    #[inline]
+    pub fn set(&mut self, x: f32, y: f32) {
+        self.x = x;
+        self.y = y;
+    }
+
+    /// Get the magnitude of the vector
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Vector2;
+    /// 
+    /// let actual = Vector2::make(1.0, 2.0).mag();
+    /// let expected = 2.2360679775;
+    /// assert_eq!(actual, expected);
+    /// ```
+    #[inline]
+    pub fn mag(&self) -> f32 {
+        self.mag_sq().sqrt()
+    }
+
+    /// Get the squared magnitude of the vector
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Vector2;
+    /// 
+    /// let actual = Vector2::make(1.0, 2.0).mag_sq();
+    /// let expected = 5.0;
+    /// assert_eq!(actual, expected);
+    /// ```
+    #[inline]
+    pub fn mag_sq(&self) -> f32 {
+        self.x * self.x + self.y * self.y
+    }
+
+    /// Find the distance between two vectors
+    /// 
+    /// # Examples
+    /// ```
+    /// use vex::Vector2;
+    /// 
+    /// let a = Vector2::make(1.0, 2.0);
+    /// let b = Vector2::make(4.0, 6.0);
+    /// let actual = Vector2::distance(&a, &b);
+    /// let expected = 5.0;
+    /// assert_eq!(actual, expected);
+    /// ```
+    #[inline]
+    pub fn distance(a: &Vector2, b: &Vector2) -> f32 {
+        Vector2::distance_sq(a, b).sqrt()
+    }
+
+    /// Find the squared distance between two vectors
+    /// 
+    /// # Examples
+    /// ```
+    /// use vex::Vector2;
+    /// 
+    /// let a = Vector2::make(1.0, 2.0);
+    /// let b = Vector2::make(4.0, 6.0);
+    /// let actual = Vector2::distance_sq(&a, &b);
+    /// let expected = 25.0;
+    /// assert_eq!(actual, expected);
+    /// ```
+    #[inline]
+    pub fn distance_sq(a: &Vector2, b: &Vector2) -> f32 {
+        (*b - *a).mag_sq()
+    }
+
+    /// Find the distance from the vector to another vector
+    /// 
+    /// # Examples
+    /// ```
+    /// use vex::Vector2;
+    /// 
+    /// let actual = Vector2::make(1.0, 2.0).distance_to(&Vector2::make(4.0, 6.0));
+    /// let expected = 5.0;
+    /// assert_eq!(actual, expected);
+    /// ```
+    #[inline]
+    pub fn distance_to(&self, other: &Vector2) -> f32 {
+        Vector2::distance(self, other)
+    }
+
+    /// Find the squared distance from the vector to another vector
+    /// 
+    /// # Examples
+    /// ```
+    /// use vex::Vector2;
+    /// 
+    /// let actual = Vector2::make(1.0, 2.0).distance_sq_to(&Vector2::make(4.0, 6.0));
+    /// let expected = 25.0;
+    /// assert_eq!(actual, expected);
+    /// ```
+    #[inline]
+    pub fn distance_sq_to(&self, other: &Vector2) -> f32 {
+        Vector2::distance_sq(self, other)
+    }
+
+    /// Normalize the vector
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Vector2;
+    /// 
+    /// let mut actual = Vector2::make(1.0, 2.0);
+    /// actual.norm();
+    /// let expected = Vector2::make(0.4472135955, 0.894427191);
+    /// assert_eq!(actual, expected);
+    /// ```
+    #[inline]
+    pub fn norm(&mut self) -> f32 {
+        let length = self.mag();
+        if length > f32::EPSILON {
+            self.x /= length;
+            self.y /= length;
+            length
+        } else {
+            0.0
+        }
+    }
+
+    /// Set the components of a vector to their absolute values
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Vector2;
+    /// 
+    /// let mut actual = Vector2::make(-1.0, -2.0);
+    /// actual.abs();
+    /// let expected = Vector2::make(1.0, 2.0);
+    /// assert_eq!(actual, expected);
+    /// ```
+    #[inline]
+    pub fn abs(&mut self) {
+        self.x = self.x.abs();
+        self.y = self.y.abs();
+    }
+
+    /// Skew the vector
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Vector2;
+    /// 
+    /// let mut actual = Vector2::make(1.0, 2.0);
+    /// actual.skew();
+    /// let expected = Vector2::make(-2.0, 1.0);
+    /// assert_eq!(actual, expected);
+    /// ```
+    #[inline]
+    pub fn skew(&mut self) {
+        let x = self.x;
+        self.x = -self.y;
+        self.y = x;
+    }
+
+    /// Negate the components of the vector
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Vector2;
+    /// 
+    /// let mut actual = Vector2::make(1.0, -2.0);
+    /// actual.negate();
+    /// let expected = Vector2::make(-1.0, 2.0);
+    /// assert_eq!(actual, expected);
+    /// ```
+    #[inline]
+    pub fn negate(&mut self) {
+        self.x = -self.x;
+        self.y = -self.y;
+    }
+
+    /// Find the vector with its components negated
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Vector2;
+    /// 
+    /// let actual = Vector2::make(1.0, -2.0).negated();
+    /// let expected = Vector2::make(-1.0, 2.0);
+    /// assert_eq!(actual, expected);
+    /// ```
+    #[inline]
+    pub fn negated(&self) -> Vector2 {
+        -*self
+    }
+
+    /// Find the vector with its components set to their absolute values
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Vector2;
+    /// 
+    /// let actual = Vector2::make(1.0, -2.0).abs_value();
+    /// let expected = Vector2::make(1.0, 2.0);
+    /// assert_eq!(actual, expected);
+    /// ```
+    #[inline]
+    pub fn abs_value(&self) -> Vector2 {
+        let mut result = *self;
+        result.abs();
+        result
+    }
+
+    /// Find the vector with its components clamped between the provided vectors
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Vector2;
+    /// 
+    /// let actual = Vector2::make(1.0, -2.0).clamped(&Vector2::make(0.0, 0.0), &Vector2::make(2.0, 2.0));
+    /// let expected = Vector2::make(1.0, 0.0);
+    /// assert_eq!(actual, expected);
+    /// ```
+    #[inline]
+    pub fn clamped(&self, a: &Vector2, b: &Vector2) -> Vector2 {
+        let mut result = *self;
+        result.clamp(a, b);
+        result
+    }
+
+    /// Find the normalized vector
+    ///
+    /// Vectors too short to normalize are returned as zero, matching `norm`.
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Vector2;
+    /// 
+    /// let actual = Vector2::make(0.0, 3.0).normalized();
+    /// let expected = Vector2::make(0.0, 1.0);
+    /// assert_eq!(actual, expected);
+    /// ```
+    #[inline]
+    pub fn normalized(&self) -> Vector2 {
+        let mut result = *self;
+        if result.norm() == 0.0 {
+            result = Vector2::new();
+        }
+
+        result
+    }
+
+    /// Find the vector skewed a quarter turn counter-clockwise
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Vector2;
+    /// 
+    /// let actual = Vector2::make(1.0, 2.0).skewed();
+    /// let expected = Vector2::make(-2.0, 1.0);
     /// assert_eq!(actual, expected);
     /// ```
     #[inline]
-    pub fn new() -> Vector2 {
-        Vector2 { x: 0.0, y: 0.0 }
+    pub fn skewed(&self) -> Vector2 {
+        let mut result = *self;
+        result.skew();
+        result
     }
 
-    /// Creates a vector <1.0, 1.0>
+    /// Encodes the vector's components as normalized i16 values
     ///
     /// # Examples
     /// ```
     /// use vex::Vector2;
     /// 
-    /// let actual = Vector2::one();
-    /// let expected = Vector2 { x: 1.0, y: 1.0 };
-    /// assert_eq!(actual, expected);
+    /// let actual = Vector2::make(1.0, -1.0).to_snorm16();
+    /// assert_eq!(actual, [32767, -32767]);
     /// ```
     #[inline]
-    pub fn one() -> Vector2 {
-        Vector2 { x: 1.0, y: 1.0 }
+    pub fn to_snorm16(&self) -> [i16; 2] {
+        [normalized::encode_snorm16(self.x), normalized::encode_snorm16(self.y)]
     }
 
-    /// Creates a vector from the provided values
+    /// Creates a vector from normalized i16 values
     ///
     /// # Examples
     /// ```
     /// use vex::Vector2;
     /// 
-    /// let actual = Vector2::make(1.0, 2.0);
-    /// let expected = Vector2 { x: 1.0, y: 2.0 };
+    /// let actual = Vector2::from_snorm16([32767, -32767]);
+    /// let expected = Vector2::make(1.0, -1.0);
     /// assert_eq!(actual, expected);
     /// ```
     #[inline]
-    pub fn make(x: f32, y: f32) -> Vector2 {
-        Vector2 { x, y }
+    pub fn from_snorm16(v: [i16; 2]) -> Vector2 {
+        Vector2::make(normalized::decode_snorm16(v[0]), normalized::decode_snorm16(v[1]))
     }
 
-    /// Find the dot product between two vectors
+    /// Encodes the vector's components as normalized i8 values
     ///
     /// # Examples
     /// ```
     /// use vex::Vector2;
     /// 
-    /// let a = Vector2::make(1.0, 0.0);
-    /// let b = Vector2::make(0.0, 1.0);
-    /// let actual = Vector2::dot(&a, &b);
-    /// let expected = 0.0;
-    /// assert_eq!(actual, expected);
+    /// let actual = Vector2::make(1.0, -1.0).to_snorm8();
+    /// assert_eq!(actual, [127, -127]);
     /// ```
     #[inline]
-    pub fn dot(a: &Vector2, b: &Vector2) -> f32 {
-        a.x * b.x + a.y * b.y
+    pub fn to_snorm8(&self) -> [i8; 2] {
+        [normalized::encode_snorm8(self.x), normalized::encode_snorm8(self.y)]
     }
 
-    /// Find the cross product between two vectors
+    /// Creates a vector from normalized i8 values
     ///
     /// # Examples
     /// ```
     /// use vex::Vector2;
     /// 
-    /// let a = Vector2::make(1.0, 0.0);
-    /// let b = Vector2::make(0.0, 1.0);
-    /// let actual = Vector2::cross(&a, &b);
-    /// let expected = 1.0;
+    /// let actual = Vector2::from_snorm8([127, -127]);
+    /// let expected = Vector2::make(1.0, -1.0);
     /// assert_eq!(actual, expected);
     /// ```
     #[inline]
-    pub fn cross(a: &Vector2, b: &Vector2) -> f32 {
-        a.x * b.y - a.y * b.x
+    pub fn from_snorm8(v: [i8; 2]) -> Vector2 {
+        Vector2::make(normalized::decode_snorm8(v[0]), normalized::decode_snorm8(v[1]))
     }
 
-    /// Find the cross product between a scalar (left) and vector (right)
+    /// Encodes the vector's components as normalized u16 values
     ///
     /// # Examples
     /// ```
     /// use vex::Vector2;
     /// 
-    /// let s = 1.0;
-    /// let v = Vector2::make(1.0, 0.0);
-    /// let actual = Vector2::cross_scalar_vec(s, &v);
-    /// let expected = Vector2::make(0.0, 1.0);
-    /// assert_eq!(actual, expected);
+    /// let actual = Vector2::make(1.0, 0.0).to_unorm16();
+    /// assert_eq!(actual, [65535, 0]);
     /// ```
     #[inline]
-    pub fn cross_scalar_vec(s: f32, v: &Vector2) -> Vector2 {
-        Vector2::make(-s * v.y, s * v.x)
+    pub fn to_unorm16(&self) -> [u16; 2] {
+        [normalized::encode_unorm16(self.x), normalized::encode_unorm16(self.y)]
     }
 
-    /// Find the cross product between a vector (left) and scalar (right)
+    /// Creates a vector from normalized u16 values
     ///
     /// # Examples
     /// ```
     /// use vex::Vector2;
     /// 
-    /// let s = 1.0;
-    /// let v = Vector2::make(1.0, 0.0);
-    /// let actual = Vector2::cross_vec_scalar(&v, s);
-    /// let expected = Vector2::make(0.0, -1.0);
+    /// let actual = Vector2::from_unorm16([65535, 0]);
+    /// let expected = Vector2::make(1.0, 0.0);
     /// assert_eq!(actual, expected);
     /// ```
     #[inline]
-    pub fn cross_vec_scalar(v: &Vector2, s: f32) -> Vector2 {
-        Vector2::make(s * v.y, -s * v.x)
+    pub fn from_unorm16(v: [u16; 2]) -> Vector2 {
+        Vector2::make(normalized::decode_unorm16(v[0]), normalized::decode_unorm16(v[1]))
     }
 
-    /// Find the minimum (component-wise) vector between two vectors
+    /// Encodes the vector's components as normalized u8 values
     ///
     /// # Examples
     /// ```
     /// use vex::Vector2;
     /// 
-    /// let a = Vector2::make(1.0, 4.0);
-    /// let b = Vector2::make(2.0, 3.0);
-    /// let actual = Vector2::min(&a, &b);
-    /// let expected = Vector2::make(1.0, 3.0);
-    /// assert_eq!(actual, expected);
+    /// let actual = Vector2::make(1.0, 0.0).to_unorm8();
+    /// assert_eq!(actual, [255, 0]);
     /// ```
     #[inline]
-    pub fn min(a: &Vector2, b: &Vector2) -> Vector2 {
-        Vector2::make(a.x.min(b.x), a.y.min(b.y))
+    pub fn to_unorm8(&self) -> [u8; 2] {
+        [normalized::encode_unorm8(self.x), normalized::encode_unorm8(self.y)]
     }
 
-    /// Find the maximum (component-wise) vector between two vectors
+    /// Creates a vector from normalized u8 values
     ///
     /// # Examples
     /// ```
     /// use vex::Vector2;
     /// 
-    /// let a = Vector2::make(1.0, 4.0);
-    /// let b = Vector2::make(2.0, 3.0);
-    /// let actual = Vector2::max(&a, &b);
-    /// let expected = Vector2::make(2.0, 4.0);
+    /// let actual = Vector2::from_unorm8([255, 0]);
+    /// let expected = Vector2::make(1.0, 0.0);
     /// assert_eq!(actual, expected);
     /// ```
     #[inline]
-    pub fn max(a: &Vector2, b: &Vector2) -> Vector2 {
-        Vector2::make(a.x.max(b.x), a.y.max(b.y))
+    pub fn from_unorm8(v: [u8; 2]) -> Vector2 {
+        Vector2::make(normalized::decode_unorm8(v[0]), normalized::decode_unorm8(v[1]))
     }
 
-    /// Find the clamped (component-wise) vector between two vectors
+    /// Determine whether or not all components of the vectors are within `epsilon` of each other
     ///
     /// # Examples
     /// ```
     /// use vex::Vector2;
     /// 
-    /// let a = Vector2::make(1.0, 3.0);
-    /// let b = Vector2::make(2.0, 4.0);
-    /// let mut actual = Vector2::make(0.0, 5.0);
-    /// actual.clamp(&a, &b);
-    /// let expected = Vector2::make(1.0, 4.0);
-    /// assert_eq!(actual, expected);
+    /// let a = Vector2::make(1.0, 2.0);
+    /// assert!(a.approx_eq(&Vector2::make(1.0000001, 2.0), 0.000001));
+    /// assert!(!a.approx_eq(&Vector2::make(1.1, 2.0), 0.000001));
     /// ```
     #[inline]
-    pub fn clamp(&mut self, a: &Vector2, b: &Vector2) {
-        let low = Self::min(a, b);
-        let high = Self::max(a, b);
-        let result = Self::max(&low, &Self::min(self, &high));
-        self.set(result.x, result.y);
+    pub fn approx_eq(&self, other: &Vector2, epsilon: f32) -> bool {
+        for i in 0..2 {
+            if (self[i] - other[i]).abs() > epsilon {
+                return false;
+            }
+        }
+
+        true
     }
 
-    /// Set the components of a vector
+    /// Determine whether or not all components of the vectors are equal under an epsilon policy
     ///
     /// # Examples
     /// ```
+    /// use vex::Epsilon;
     /// use vex::Vector2;
     /// 
-    /// let mut actual = Vector2::new();
-    /// actual.set(1.0, 2.0);
-    /// let expected = Vector2::make(1.0, 2.0);
-    /// assert_eq!(actual, expected);
+    /// let a = Vector2::make(1000000.0, 2.0);
+    /// assert!(a.approx_eq_with(&Vector2::make(1000000.06, 2.0), &Epsilon::DEFAULT));
+    /// assert!(!a.approx_eq_with(&Vector2::make(1000000.0, 2.1), &Epsilon::DEFAULT));
     /// ```
     #[inline]
-    pub fn set(&mut self, x: f32, y: f32) {
-        self.x = x;
-        self.y = y;
+    pub fn approx_eq_with(&self, other: &Vector2, epsilon: &Epsilon) -> bool {
+        let a: [f32; 2] = (*self).into();
+        let b: [f32; 2] = (*other).into();
+        epsilon.all_approx_eq(&a, &b)
     }
 
-    /// Get the magnitude of the vector
+    /// Determine whether or not the vector has unit length under an epsilon policy
     ///
     /// # Examples
     /// ```
+    /// use vex::Epsilon;
     /// use vex::Vector2;
     /// 
-    /// let actual = Vector2::make(1.0, 2.0).mag();
-    /// let expected = 2.2360679775;
-    /// assert_eq!(actual, expected);
+    /// assert!(Vector2::make(0.6, 0.8).is_normalized(&Epsilon::DEFAULT));
+    /// assert!(!Vector2::make(0.6, 0.9).is_normalized(&Epsilon::DEFAULT));
+    /// assert!(Vector2::make(0.6, 0.9).is_normalized(&Epsilon::DEFAULT.with_absolute(0.1)));
     /// ```
     #[inline]
-    pub fn mag(&self) -> f32 {
-        self.mag_sq().sqrt()
+    pub fn is_normalized(&self, epsilon: &Epsilon) -> bool {
+        epsilon.approx_eq(self.mag(), 1.0)
     }
 
-    /// Get the squared magnitude of the vector
+    /// Determine whether or not the vector is too short to have a direction under an epsilon policy
     ///
     /// # Examples
     /// ```
+    /// use vex::Epsilon;
     /// use vex::Vector2;
     /// 
-    /// let actual = Vector2::make(1.0, 2.0).mag_sq();
-    /// let expected = 5.0;
-    /// assert_eq!(actual, expected);
+    /// let v = Vector2::make(0.6, 0.9) * 0.0000001;
+    /// assert!(v.is_zero(&Epsilon::DEFAULT));
+    /// assert!(!v.is_zero(&Epsilon::DEFAULT.with_absolute(0.0)));
     /// ```
     #[inline]
-    pub fn mag_sq(&self) -> f32 {
-        self.x * self.x + self.y * self.y
+    pub fn is_zero(&self, epsilon: &Epsilon) -> bool {
+        epsilon.is_zero(self.mag())
     }
 
-    /// Normalize the vector
+    /// Determine whether or not all components of the vector are valid
     ///
     /// # Examples
     /// ```
     /// use vex::Vector2;
     /// 
-    /// let mut actual = Vector2::make(1.0, 2.0);
-    /// actual.norm();
-    /// let expected = Vector2::make(0.4472135955, 0.894427191);
-    /// assert_eq!(actual, expected);
+    /// let actual = Vector2::make(1.0, 2.0);
+    /// assert!(actual.is_valid());
     /// ```
     #[inline]
-    pub fn norm(&mut self) -> f32 {
-        let length = self.mag();
-        if length > EPSILON {
-            self.x /= length;
-            self.y /= length;
-            length
-        } else {
-            0.0
+    pub fn is_valid(&self) -> bool {
+        for i in 0..2 {
+            if !common::is_valid(self[i]) {
+                return false;
+            }
         }
+
+        true
     }
+}
 
-    /// Set the components of a vector to their absolute values
+impl Default for Vector2 {
+    /// Creates a zero vector
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Vector2;
+    ///
+    /// assert_eq!(Vector2::default(), Vector2::new());
+    /// ```
+    #[inline]
+    fn default() -> Vector2 {
+        Vector2::new()
+    }
+}
+
+impl From<[f32; 2]> for Vector2 {
+    /// Creates a Vector2 from an array of components
     ///
     /// # Examples
     /// ```
     /// use vex::Vector2;
     /// 
-    /// let mut actual = Vector2::make(-1.0, -2.0);
-    /// actual.abs();
+    /// let actual = Vector2::from([1.0, 2.0]);
     /// let expected = Vector2::make(1.0, 2.0);
     /// assert_eq!(actual, expected);
     /// ```
     #[inline]
-    pub fn abs(&mut self) {
-        self.x = self.x.abs();
-        self.y = self.y.abs();
+    fn from(item: [f32; 2]) -> Vector2 {
+        Vector2::make(item[0], item[1])
     }
+}
 
-    /// Skew the vector
+impl From<Vector2> for [f32; 2] {
+    /// Creates an array from the components of a Vector2
     ///
     /// # Examples
     /// ```
     /// use vex::Vector2;
     /// 
-    /// let mut actual = Vector2::make(1.0, 2.0);
-    /// actual.skew();
-    /// let expected = Vector2::make(-2.0, 1.0);
+    /// let actual: [f32; 2] = Vector2::make(1.0, 2.0).into();
+    /// let expected = [1.0, 2.0];
     /// assert_eq!(actual, expected);
     /// ```
     #[inline]
-    pub fn skew(&mut self) {
-        let x = self.x;
-        self.x = -self.y;
-        self.y = x;
+    fn from(item: Vector2) -> [f32; 2] {
+        [item.x, item.y]
     }
+}
 
-    /// Determine whether or not all components of the vector are valid
+impl AsRef<[f32]> for Vector2 {
+    /// Gets the components as a slice
     ///
     /// # Examples
     /// ```
     /// use vex::Vector2;
     /// 
-    /// let actual = Vector2::make(1.0, 2.0);
-    /// assert!(actual.is_valid());
+    /// let v = Vector2::make(1.0, 2.0);
+    /// let actual: &[f32] = v.as_ref();
+    /// assert_eq!(actual, &[1.0, 2.0][..]);
     /// ```
     #[inline]
-    pub fn is_valid(&self) -> bool {
-        for i in 0..2 {
-            if !common::is_valid(self[i]) {
-                return false;
-            }
-        }
+    fn as_ref(&self) -> &[f32] {
+        // SAFETY: the type is `repr(C)` with only f32 fields, so they're laid out contiguously
+        unsafe { slice::from_raw_parts(self as *const Vector2 as *const f32, 2) }
+    }
+}
 
-        true
+impl AsMut<[f32]> for Vector2 {
+    /// Gets the components as a mutable slice
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Vector2;
+    /// 
+    /// let mut v = Vector2::make(1.0, 2.0);
+    /// v.as_mut()[0] = 5.0;
+    /// assert_eq!(v.as_ref()[0], 5.0);
+    /// ```
+    #[inline]
+    fn as_mut(&mut self) -> &mut [f32] {
+        // SAFETY: the type is `repr(C)` with only f32 fields, so they're laid out contiguously
+        unsafe { slice::from_raw_parts_mut(self as *mut Vector2 as *mut f32, 2) }
     }
 }
 
@@ -360,12 +1358,10 @@ impl Index<u32> for Vector2 {
     /// ```
     #[inline]
     fn index(&self, index: u32) -> &f32 {
-        unsafe {
-            match index {
-                0 => &self.x,
-                1 => &self.y,
-                _ => panic!("Invalid index for Vector2: {}", index),
-            }
+        match index {
+            0 => &self.x,
+            1 => &self.y,
+            _ => panic!("Invalid index for Vector2: {}", index),
         }
     }
 }
@@ -384,13 +1380,11 @@ impl IndexMut<u32> for Vector2 {
     /// assert_eq!(v[1], 4.0);
     /// ```
     #[inline]
-    fn index_mut<'a>(&'a mut self, index: u32) -> &'a mut f32 {
-        unsafe {
-            match index {
-                0 => &mut self.x,
-                1 => &mut self.y,
-                _ => panic!("Invalid index for Vector2: {}", index),
-            }
+    fn index_mut(&mut self, index: u32) -> &mut f32 {
+        match index {
+            0 => &mut self.x,
+            1 => &mut self.y,
+            _ => panic!("Invalid index for Vector2: {}", index),
         }
     }
 }
@@ -433,6 +1427,25 @@ impl Add<f32> for Vector2 {
     }
 }
 
+impl Add<Vector2> for f32 {
+    type Output = Vector2;
+
+    /// Find the resulting vector by adding a vector's components to a scalar
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Vector2;
+    /// 
+    /// let actual = 1.0 + Vector2::make(1.0, 2.0);
+    /// let expected = Vector2::make(2.0, 3.0);
+    /// assert_eq!(actual, expected);
+    /// ```
+    #[inline]
+    fn add(self, _rhs: Vector2) -> Vector2 {
+        Vector2::make(self + _rhs.x, self + _rhs.y)
+    }
+}
+
 impl Add<Vector2> for Vector2 {
     type Output = Vector2;
 
@@ -511,6 +1524,25 @@ impl Sub<f32> for Vector2 {
     }
 }
 
+impl Sub<Vector2> for f32 {
+    type Output = Vector2;
+
+    /// Find the resulting vector by subtracting a vector's components from a scalar
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Vector2;
+    /// 
+    /// let actual = 10.0 - Vector2::make(1.0, 2.0);
+    /// let expected = Vector2::make(9.0, 8.0);
+    /// assert_eq!(actual, expected);
+    /// ```
+    #[inline]
+    fn sub(self, _rhs: Vector2) -> Vector2 {
+        Vector2::make(self - _rhs.x, self - _rhs.y)
+    }
+}
+
 impl Sub<Vector2> for Vector2 {
     type Output = Vector2;
 
@@ -588,6 +1620,25 @@ impl Mul<f32> for Vector2 {
     }
 }
 
+impl Mul<Vector2> for f32 {
+    type Output = Vector2;
+
+    /// Find the resulting vector by multiplying a scalar by a vector's components
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Vector2;
+    /// 
+    /// let actual = 2.0 * Vector2::make(1.0, 2.0);
+    /// let expected = Vector2::make(2.0, 4.0);
+    /// assert_eq!(actual, expected);
+    /// ```
+    #[inline]
+    fn mul(self, _rhs: Vector2) -> Vector2 {
+        Vector2::make(self * _rhs.x, self * _rhs.y)
+    }
+}
+
 impl Mul<Vector2> for Vector2 {
     type Output = Vector2;
 
@@ -747,8 +1798,76 @@ impl cmp::PartialEq for Vector2 {
 }
 
 impl Display for Vector2 {
+    /// Formats as `<x, y>`, with the formatter's flags applied to each component
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Vector2;
+    ///
+    /// let v = Vector2::make(1.0, -2.5);
+    /// assert_eq!(format!("{}", v), "<1, -2.5>");
+    /// assert_eq!(format!("{:.2}", v), "<1.00, -2.50>");
+    /// ```
     #[inline]
     fn fmt(&self, f: &mut Formatter) -> fmt::Result {
-        unsafe { write!(f, "<{}  {}>", self.x, self.y) }
+        format::write_vector(f, &[self.x, self.y])
+    }
+}
+
+impl FromStr for Vector2 {
+    type Err = ParseError;
+
+    /// Parses a vector written as `<x, y>`, as `Display` writes it
+    ///
+    /// Whitespace around components is ignored, so padded output parses too.
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::ParseError;
+    /// use vex::Vector2;
+    ///
+    /// let v = Vector2::make(1.0, -2.5);
+    /// assert_eq!("<1, -2.5>".parse::<Vector2>(), Ok(v));
+    /// assert_eq!(format!("{:8.3}", v).parse::<Vector2>(), Ok(v));
+    ///
+    /// let actual = "<1, 2, 3, 4, 5>".parse::<Vector2>();
+    /// assert_eq!(actual, Err(ParseError::Count { expected: 2, found: 5 }));
+    /// assert_eq!("(1, 2)".parse::<Vector2>(), Err(ParseError::Expected('<')));
+    /// ```
+    #[inline]
+    fn from_str(s: &str) -> Result<Vector2, ParseError> {
+        let [x, y] = parse::parse_vector(s)?;
+        Ok(Vector2::make(x, y))
+    }
+}
+
+impl common::Vector for Vector2 {
+    #[inline]
+    fn zero() -> Vector2 {
+        Vector2::new()
+    }
+
+    #[inline]
+    fn dot(a: &Vector2, b: &Vector2) -> f32 {
+        Vector2::dot(a, b)
+    }
+
+    #[inline]
+    fn approx_eq(&self, other: &Vector2, epsilon: f32) -> bool {
+        Vector2::approx_eq(self, other, epsilon)
+    }
+}
+
+impl common::MixFactor<Vector2> for f32 {
+    #[inline]
+    fn spread(self) -> Vector2 {
+        Vector2::make(self, self)
+    }
+}
+
+impl common::MixFactor<Vector2> for Vector2 {
+    #[inline]
+    fn spread(self) -> Vector2 {
+        self
     }
 }
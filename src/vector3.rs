@@ -1,14 +1,21 @@
 use crate::common;
+use crate::epsilon::Epsilon;
+use crate::format;
+#[cfg(not(feature = "std"))]
+use crate::math::Float;
+use crate::normalized;
+use crate::parse::{self, ParseError};
 use crate::vector2::Vector2;
 use crate::vector4::Vector4;
 
-use std::cmp;
-use std::convert::From;
-use std::f32::EPSILON;
-use std::fmt;
-use std::fmt::{Display, Formatter};
+use core::cmp;
+use core::convert::From;
+use core::fmt;
+use core::slice;
+use core::fmt::{Display, Formatter};
+use core::str::FromStr;
 
-use std::ops::{
+use core::ops::{
     Index,
     IndexMut,
     Neg,
@@ -22,8 +29,10 @@ use std::ops::{
     DivAssign,
 };
 
-#[repr(C, packed)]
+#[repr(C)]
 #[derive(Copy, Clone, Debug)]
+#[cfg_attr(feature = "rkyv", derive(rkyv::Archive, rkyv::Deserialize, rkyv::Serialize))]
+#[cfg_attr(feature = "rkyv", rkyv(derive(Debug), compare(PartialEq)))]
 pub struct Vector3 {
     pub x: f32,
     pub y: f32,
@@ -31,297 +40,1344 @@ pub struct Vector3 {
 }
 
 impl Vector3 {
+    /// A vector <0.0, 0.0, 0.0>
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Vector3;
+    /// 
+    /// assert_eq!(Vector3::ZERO, Vector3::new());
+    /// ```
+    pub const ZERO: Vector3 = Vector3 { x: 0.0, y: 0.0, z: 0.0 };
+
+    /// A vector <1.0, 1.0, 1.0>
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Vector3;
+    /// 
+    /// assert_eq!(Vector3::ONE, Vector3::one());
+    /// ```
+    pub const ONE: Vector3 = Vector3 { x: 1.0, y: 1.0, z: 1.0 };
+
+    /// A unit vector <1.0, 0.0, 0.0> along the x-axis
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Vector3;
+    ///
+    /// assert_eq!(Vector3::X, Vector3::make(1.0, 0.0, 0.0));
+    /// ```
+    pub const X: Vector3 = Vector3 { x: 1.0, y: 0.0, z: 0.0 };
+
+    /// A unit vector <0.0, 1.0, 0.0> along the y-axis
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Vector3;
+    ///
+    /// assert_eq!(Vector3::Y, Vector3::make(0.0, 1.0, 0.0));
+    /// ```
+    pub const Y: Vector3 = Vector3 { x: 0.0, y: 1.0, z: 0.0 };
+
+    /// A unit vector <0.0, 0.0, 1.0> along the z-axis
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Vector3;
+    ///
+    /// assert_eq!(Vector3::Z, Vector3::make(0.0, 0.0, 1.0));
+    /// ```
+    pub const Z: Vector3 = Vector3 { x: 0.0, y: 0.0, z: 1.0 };
+
     /// Creates a vector <0.0, 0.0, 0.0>
     ///
     /// # Examples
     /// ```
     /// use vex::Vector3;
     /// 
-    /// let actual = Vector3::new();
-    /// let expected = Vector3 { x: 0.0, y: 0.0, z: 0.0 };
-    /// assert_eq!(actual, expected);
+    /// let actual = Vector3::new();
+    /// let expected = Vector3 { x: 0.0, y: 0.0, z: 0.0 };
+    /// assert_eq!(actual, expected);
+    /// ```
+    #[inline]
+    pub const fn new() -> Vector3 {
+        Vector3 { x: 0.0, y: 0.0, z: 0.0 }
+    }
+
+    /// Creates a vector <1.0, 1.0, 1.0>
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Vector3;
+    /// 
+    /// let actual = Vector3::one();
+    /// let expected = Vector3 { x: 1.0, y: 1.0, z: 1.0 };
+    /// assert_eq!(actual, expected);
+    /// ```
+    #[inline]
+    pub const fn one() -> Vector3 {
+        Vector3 { x: 1.0, y: 1.0, z: 1.0 }
+    }
+
+    /// Creates a right vector
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Vector3;
+    /// 
+    /// let actual = Vector3::right();
+    /// let expected = Vector3 { x: 1.0, y: 0.0, z: 0.0 };
+    /// assert_eq!(actual, expected);
+    /// ```
+    #[inline]
+    pub const fn right() -> Vector3 {
+        Vector3 { x: 1.0, y: 0.0, z: 0.0 }
+    }
+
+    /// Creates an up vector
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Vector3;
+    /// 
+    /// let actual = Vector3::up();
+    /// let expected = Vector3 { x: 0.0, y: 1.0, z: 0.0 };
+    /// assert_eq!(actual, expected);
+    /// ```
+    #[inline]
+    pub const fn up() -> Vector3 {
+        Vector3 { x: 0.0, y: 1.0, z: 0.0 }
+    }
+
+    /// Creates a forward vector
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Vector3;
+    /// 
+    /// let actual = Vector3::forward();
+    /// let expected = Vector3 { x: 0.0, y: 0.0, z: -1.0 };
+    /// assert_eq!(actual, expected);
+    /// ```
+    #[inline]
+    pub const fn forward() -> Vector3 {
+        Vector3 { x: 0.0, y: 0.0, z: -1.0 }
+    }
+
+    /// Creates a vector from the provided values
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Vector3;
+    /// 
+    /// let actual = Vector3::make(1.0, 2.0, 3.0);
+    /// let expected = Vector3 { x: 1.0, y: 2.0, z: 3.0 };
+    /// assert_eq!(actual, expected);
+    ///
+    /// // vectors can be made at compile time, such as for lookup tables
+    /// static NEIGHBORS: [Vector3; 3] = [Vector3::X, Vector3::make(0.0, -1.0, 0.0), Vector3::Z];
+    /// assert_eq!(NEIGHBORS[1], -Vector3::Y);
+    /// ```
+    #[inline]
+    pub const fn make(x: f32, y: f32, z: f32) -> Vector3 {
+        Vector3 { x, y, z }
+    }
+
+    /// Find the dot product between two vectors
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Vector3;
+    /// 
+    /// let a = Vector3::make(1.0, 0.0, 0.0);
+    /// let b = Vector3::make(0.0, 0.0, 1.0);
+    /// let actual = Vector3::dot(&a, &b);
+    /// let expected = 0.0;
+    /// assert_eq!(actual, expected);
+    /// ```
+    #[inline]
+    pub fn dot(a: &Vector3, b: &Vector3) -> f32 {
+        a.x * b.x + a.y * b.y + a.z * b.z
+    }
+
+    /// Find the cross product between two vectors
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Vector3;
+    /// 
+    /// let a = Vector3::make(0.0, 0.0, 1.0);
+    /// let b = Vector3::make(1.0, 0.0, 0.0);
+    /// let actual = Vector3::cross(&a, &b);
+    /// let expected = Vector3::make(0.0, 1.0, 0.0);
+    /// assert_eq!(actual, expected);
+    /// ```
+    #[inline]
+    pub fn cross(a: &Vector3, b: &Vector3) -> Vector3 {
+        Vector3::make(
+            a.y * b.z - a.z * b.y,
+            a.z * b.x - a.x * b.z,
+            a.x * b.y - a.y * b.x,
+        )
+    }
+
+    /// Find the angle between two vectors (in radians)
+    /// 
+    /// The result is in `[0, PI]`, and is 0 if either vector has zero length. This stays accurate
+    /// for nearly parallel vectors, where `acos` of the normalized dot product doesn't.
+    /// 
+    /// # Examples
+    /// ```
+    /// use std::f32::consts::PI;
+    /// use vex::Vector3;
+    /// 
+    /// let a = Vector3::make(1.0, 0.0, 0.0);
+    /// let b = Vector3::make(0.0, 0.0, -2.0);
+    /// assert_eq!(Vector3::angle_between(&a, &b), PI / 2.0);
+    /// assert_eq!(Vector3::angle_between(&a, &-a), PI);
+    /// assert_eq!(Vector3::angle_between(&a, &Vector3::new()), 0.0);
+    /// ```
+    #[inline]
+    pub fn angle_between(a: &Vector3, b: &Vector3) -> f32 {
+        Vector3::cross(a, b).mag().atan2(Vector3::dot(a, b))
+    }
+
+    /// Find the minimum (component-wise) vector between two vectors
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Vector3;
+    /// 
+    /// let a = Vector3::make(1.0, 4.0, 5.0);
+    /// let b = Vector3::make(2.0, 3.0, 6.0);
+    /// let actual = Vector3::min(&a, &b);
+    /// let expected = Vector3::make(1.0, 3.0, 5.0);
+    /// assert_eq!(actual, expected);
+    /// ```
+    #[inline]
+    pub fn min(a: &Vector3, b: &Vector3) -> Vector3 {
+        Vector3::make(a.x.min(b.x), a.y.min(b.y), a.z.min(b.z))
+    }
+
+    /// Find the maximum (component-wise) vector between two vectors
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Vector3;
+    /// 
+    /// let a = Vector3::make(1.0, 4.0, 5.0);
+    /// let b = Vector3::make(2.0, 3.0, 6.0);
+    /// let actual = Vector3::max(&a, &b);
+    /// let expected = Vector3::make(2.0, 4.0, 6.0);
+    /// assert_eq!(actual, expected);
+    /// ```
+    #[inline]
+    pub fn max(a: &Vector3, b: &Vector3) -> Vector3 {
+        Vector3::make(a.x.max(b.x), a.y.max(b.y), a.z.max(b.z))
+    }
+
+    /// Find the smooth minimum (component-wise) of two vectors, blending where components are
+    /// within `k` of each other
+    ///
+    /// Each component is the quadratic polynomial [`smooth_min`](fn.smooth_min.html) of the
+    /// components.
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Vector3;
+    /// 
+    /// let a = Vector3::make(1.0, 2.0, 5.0);
+    /// let b = Vector3::make(3.0, 2.0, 5.0);
+    /// let actual = Vector3::smooth_min(&a, &b, 1.0);
+    /// assert_eq!(actual, Vector3::make(1.0, 1.75, 4.75));
+    /// ```
+    #[inline]
+    pub fn smooth_min(a: &Vector3, b: &Vector3, k: f32) -> Vector3 {
+        Vector3::make(
+            common::smooth_min(a.x, b.x, k),
+            common::smooth_min(a.y, b.y, k),
+            common::smooth_min(a.z, b.z, k),
+        )
+    }
+
+    /// Find the smooth maximum (component-wise) of two vectors, blending where components are
+    /// within `k` of each other
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Vector3;
+    /// 
+    /// let a = Vector3::make(1.0, 2.0, 5.0);
+    /// let b = Vector3::make(3.0, 2.0, 5.0);
+    /// let actual = Vector3::smooth_max(&a, &b, 1.0);
+    /// assert_eq!(actual, Vector3::make(3.0, 2.25, 5.25));
+    /// ```
+    #[inline]
+    pub fn smooth_max(a: &Vector3, b: &Vector3, k: f32) -> Vector3 {
+        Vector3::make(
+            common::smooth_max(a.x, b.x, k),
+            common::smooth_max(a.y, b.y, k),
+            common::smooth_max(a.z, b.z, k),
+        )
+    }
+
+    /// Find the soft absolute value (component-wise) of a vector, `sqrt(x^2 + k^2)`
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Vector3;
+    /// 
+    /// let actual = Vector3::make(-3.0, 0.0, 3.0).soft_abs(4.0);
+    /// assert_eq!(actual, Vector3::make(5.0, 4.0, 5.0));
+    /// ```
+    #[inline]
+    pub fn soft_abs(&self, k: f32) -> Vector3 {
+        Vector3::make(
+            common::soft_abs(self.x, k),
+            common::soft_abs(self.y, k),
+            common::soft_abs(self.z, k),
+        )
+    }
+
+    /// Find the vector with a function applied to each component
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Vector3;
+    /// 
+    /// let actual = Vector3::make(1.0, -2.0, 3.0).map(|c| c * c);
+    /// assert_eq!(actual, Vector3::make(1.0, 4.0, 9.0));
+    /// ```
+    #[inline]
+    pub fn map<F: Fn(f32) -> f32>(&self, f: F) -> Vector3 {
+        Vector3::make(
+            f(self.x),
+            f(self.y),
+            f(self.z),
+        )
+    }
+
+    /// Find the vector combining each pair of components from two vectors with a function
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Vector3;
+    /// 
+    /// let a = Vector3::make(1.0, -2.0, 3.0);
+    /// let b = Vector3::make(3.0, 4.0, -1.0);
+    /// let actual = a.zip_with(&b, f32::max);
+    /// assert_eq!(actual, Vector3::make(3.0, 4.0, 3.0));
+    /// ```
+    #[inline]
+    pub fn zip_with<F: Fn(f32, f32) -> f32>(&self, other: &Vector3, f: F) -> Vector3 {
+        Vector3::make(
+            f(self.x, other.x),
+            f(self.y, other.y),
+            f(self.z, other.z),
+        )
+    }
+
+    /// Gets the smallest component
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Vector3;
+    /// 
+    /// assert_eq!(Vector3::make(1.0, -2.0, 3.0).min_element(), -2.0);
+    /// ```
+    #[inline]
+    pub fn min_element(&self) -> f32 {
+        self.x.min(self.y).min(self.z)
+    }
+
+    /// Gets the largest component
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Vector3;
+    /// 
+    /// assert_eq!(Vector3::make(1.0, -2.0, 3.0).max_element(), 3.0);
+    /// ```
+    #[inline]
+    pub fn max_element(&self) -> f32 {
+        self.x.max(self.y).max(self.z)
+    }
+
+    /// Gets the sum of the components
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Vector3;
+    /// 
+    /// assert_eq!(Vector3::make(1.0, -2.0, 3.0).sum(), 2.0);
+    /// ```
+    #[inline]
+    pub fn sum(&self) -> f32 {
+        self.x + self.y + self.z
+    }
+
+    /// Find the vector with the reciprocal of each component
+    ///
+    /// Zero components become infinite, so use `recip_or` when they can occur.
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Vector3;
+    /// 
+    /// let actual = Vector3::make(2.0, -4.0, 0.5).recip();
+    /// assert_eq!(actual, Vector3::make(0.5, -0.25, 2.0));
+    /// ```
+    #[inline]
+    pub fn recip(&self) -> Vector3 {
+        Vector3::make(
+            1.0 / self.x,
+            1.0 / self.y,
+            1.0 / self.z,
+        )
+    }
+
+    /// Find the vector with the reciprocal of each component, using `fallback` for zero components
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Vector3;
+    /// 
+    /// let actual = Vector3::make(2.0, 0.0, -0.5).recip_or(0.0);
+    /// assert_eq!(actual, Vector3::make(0.5, 0.0, -2.0));
+    /// ```
+    #[inline]
+    pub fn recip_or(&self, fallback: f32) -> Vector3 {
+        self.map(|c| if c == 0.0 { fallback } else { 1.0 / c })
+    }
+
+    /// Find the vector with the reciprocal square root of each component
+    ///
+    /// Zero components become infinite and negative components become NaN.
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Vector3;
+    /// 
+    /// let actual = Vector3::make(4.0, 16.0, 0.25).rsqrt();
+    /// assert_eq!(actual, Vector3::make(0.5, 0.25, 2.0));
+    /// ```
+    #[inline]
+    pub fn rsqrt(&self) -> Vector3 {
+        Vector3::make(
+            1.0 / self.x.sqrt(),
+            1.0 / self.y.sqrt(),
+            1.0 / self.z.sqrt(),
+        )
+    }
+
+    /// Linearly interpolate between two vectors
+    ///
+    /// Values of `t` outside `[0, 1]` extrapolate along the line through both vectors.
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Vector3;
+    /// 
+    /// let a = Vector3::make(2.0, 0.0, 0.0);
+    /// let b = Vector3::make(4.0, 8.0, 0.0);
+    /// assert_eq!(Vector3::lerp(&a, &b, 0.5), Vector3::make(3.0, 4.0, 0.0));
+    /// assert_eq!(Vector3::lerp(&a, &b, 2.0), Vector3::make(6.0, 16.0, 0.0));
+    /// ```
+    #[inline]
+    pub fn lerp(a: &Vector3, b: &Vector3, t: f32) -> Vector3 {
+        *a + (*b - *a) * t
+    }
+
+    /// Linearly interpolate between two vectors with either a scalar factor or one per component
+    ///
+    /// Matches GLSL's `mix`, giving `a * (1 - t) + b * t` for each component, so a factor of 1
+    /// returns exactly `b`.
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Vector3;
+    /// 
+    /// let a = Vector3::make(0.0, 10.0, -4.0);
+    /// let b = Vector3::make(4.0, 20.0, 4.0);
+    /// assert_eq!(Vector3::mix(&a, &b, 0.5), Vector3::make(2.0, 15.0, 0.0));
+    ///
+    /// let t = Vector3::make(0.0, 1.0, 0.25);
+    /// assert_eq!(Vector3::mix(&a, &b, t), Vector3::make(0.0, 20.0, -2.0));
+    /// ```
+    #[inline]
+    pub fn mix<T: common::MixFactor<Vector3>>(a: &Vector3, b: &Vector3, t: T) -> Vector3 {
+        let t = t.spread();
+        Vector3::make(
+            a.x * (1.0 - t.x) + b.x * t.x,
+            a.y * (1.0 - t.y) + b.y * t.y,
+            a.z * (1.0 - t.z) + b.z * t.z,
+        )
+    }
+
+    /// Spherically interpolate between two unit vectors at a constant angular rate
+    ///
+    /// Values of `t` outside `[0, 1]` keep rotating along the same arc. Nearly parallel vectors
+    /// fall back to a normalized lerp, and opposite vectors rotate through an arbitrary
+    /// perpendicular direction.
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Vector3;
+    /// 
+    /// let a = Vector3::make(1.0, 0.0, 0.0);
+    /// let b = Vector3::make(0.0, 1.0, 0.0);
+    /// let actual = Vector3::slerp(&a, &b, 1.0 / 3.0);
+    /// let angle = std::f32::consts::PI / 6.0;
+    /// let mut expected = Vector3::new();
+    /// expected.x = angle.cos();
+    /// expected.y = angle.sin();
+    /// assert!((actual - expected).mag() < 0.0001);
+    ///
+    /// let actual = Vector3::slerp(&a, &-a, 0.5);
+    /// assert!((actual.mag() - 1.0).abs() < 0.0001);
+    /// assert!(Vector3::dot(&actual, &a).abs() < 0.0001);
+    /// ```
+    #[inline]
+    pub fn slerp(a: &Vector3, b: &Vector3, t: f32) -> Vector3 {
+        let cosine = Vector3::dot(a, b).clamp(-1.0, 1.0);
+
+        // fall back to nlerp when the vectors are nearly parallel
+        if cosine > 0.9995 {
+            return Vector3::lerp(a, b, t).normalized();
+        }
+
+        // the arc is ambiguous for opposite vectors, so pick any perpendicular one
+        if cosine < -0.9995 {
+            let perpendicular = Vector3::perpendicular(a);
+            let angle = t * core::f32::consts::PI;
+            return *a * angle.cos() + perpendicular * angle.sin();
+        }
+
+        let theta = cosine.acos();
+        let sine = theta.sin();
+        let wa = ((1.0 - t) * theta).sin() / sine;
+        let wb = (t * theta).sin() / sine;
+        *a * wa + *b * wb
+    }
+
+    /// Interpolate between two vectors, easing in and out with a smoothstep curve
+    ///
+    /// `t` is clamped to `[0, 1]`, so the result never overshoots either vector.
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Vector3;
+    /// 
+    /// let a = Vector3::make(2.0, 0.0, 0.0);
+    /// let b = Vector3::make(4.0, 8.0, 0.0);
+    /// assert_eq!(Vector3::smooth_step(&a, &b, 0.5), Vector3::make(3.0, 4.0, 0.0));
+    /// assert_eq!(Vector3::smooth_step(&a, &b, 0.25), Vector3::make(2.3125, 1.25, 0.0));
+    /// assert_eq!(Vector3::smooth_step(&a, &b, -1.0), a);
+    /// assert_eq!(Vector3::smooth_step(&a, &b, 2.0), b);
+    /// ```
+    #[inline]
+    pub fn smooth_step(a: &Vector3, b: &Vector3, t: f32) -> Vector3 {
+        let t = t.clamp(0.0, 1.0);
+        Vector3::lerp(a, b, t * t * (3.0 - 2.0 * t))
+    }
+
+    /// Find the angle and distance to a target if it's inside an observer's field of view
+    /// 
+    /// The field of view is a cone around `observer_dir` spanning `fov` radians in total, so a
+    /// target is visible when it's within `fov / 2` of the direction and no farther than
+    /// `max_distance`. `observer_dir` doesn't need to be normalized. A target at the observer's
+    /// position is always visible, and a zero-length direction sees nothing else.
+    /// 
+    /// # Examples
+    /// ```
+    /// use std::f32::consts::PI;
+    /// use vex::Vector3;
+    /// 
+    /// let observer = Vector3::new();
+    /// let dir = Vector3::make(0.0, 0.0, -1.0);
+    /// let (angle, distance) = Vector3::fov_check(&observer, &dir, &Vector3::make(0.0, 3.0, -3.0), PI / 2.0, 5.0).unwrap();
+    /// assert!((angle - PI / 4.0).abs() < 0.000001);
+    /// assert!((distance - (18.0f32).sqrt()).abs() < 0.000001);
+    /// 
+    /// assert_eq!(Vector3::fov_check(&observer, &dir, &Vector3::make(0.0, 0.0, -10.0), PI / 2.0, 5.0), None);
+    /// assert_eq!(Vector3::fov_check(&observer, &dir, &Vector3::make(3.0, 0.0, 0.0), PI / 2.0, 5.0), None);
+    /// ```
+    #[inline]
+    pub fn fov_check(
+        observer_pos: &Vector3,
+        observer_dir: &Vector3,
+        target_pos: &Vector3,
+        fov: f32,
+        max_distance: f32,
+    ) -> Option<(f32, f32)> {
+        let offset = *target_pos - *observer_pos;
+        let distance = offset.mag();
+        if distance > max_distance {
+            return None;
+        }
+
+        if distance == 0.0 {
+            return Some((0.0, 0.0));
+        }
+
+        let length = observer_dir.mag();
+        if length == 0.0 {
+            return None;
+        }
+
+        let cosine = Vector3::dot(observer_dir, &offset) / (length * distance);
+        let angle = cosine.clamp(-1.0, 1.0).acos();
+        if angle <= fov / 2.0 {
+            Some((angle, distance))
+        } else {
+            None
+        }
+    }
+
+    /// Determine whether or not a target is inside an observer's field of view
+    /// 
+    /// See [`fov_check`](#method.fov_check) for how the field of view is defined.
+    /// 
+    /// # Examples
+    /// ```
+    /// use std::f32::consts::PI;
+    /// use vex::Vector3;
+    /// 
+    /// let observer = Vector3::new();
+    /// let dir = Vector3::make(0.0, 0.0, -1.0);
+    /// assert!(Vector3::in_fov(&observer, &dir, &Vector3::make(0.0, 3.0, -3.0), PI / 2.0, 5.0));
+    /// assert!(!Vector3::in_fov(&observer, &dir, &Vector3::make(0.0, 3.0, -3.0), PI / 3.0, 5.0));
+    /// assert!(!Vector3::in_fov(&observer, &dir, &Vector3::make(0.0, 0.0, -10.0), PI / 2.0, 5.0));
+    /// ```
+    #[inline]
+    pub fn in_fov(
+        observer_pos: &Vector3,
+        observer_dir: &Vector3,
+        target_pos: &Vector3,
+        fov: f32,
+        max_distance: f32,
+    ) -> bool {
+        Vector3::fov_check(observer_pos, observer_dir, target_pos, fov, max_distance).is_some()
+    }
+
+    /// Find the projection of the vector onto another vector
+    /// 
+    /// Projecting onto a zero vector produces a zero vector.
+    /// 
+    /// # Examples
+    /// ```
+    /// use vex::Vector3;
+    /// 
+    /// let actual = Vector3::make(2.0, 3.0, 4.0).project_onto(&Vector3::make(4.0, 0.0, 0.0));
+    /// let expected = Vector3::make(2.0, 0.0, 0.0);
+    /// assert_eq!(actual, expected);
+    /// assert_eq!(Vector3::make(2.0, 3.0, 4.0).project_onto(&Vector3::new()), Vector3::new());
+    /// ```
+    #[inline]
+    pub fn project_onto(&self, other: &Vector3) -> Vector3 {
+        let length_sq = other.mag_sq();
+        if length_sq == 0.0 {
+            return Vector3::new();
+        }
+
+        *other * (Vector3::dot(self, other) / length_sq)
+    }
+
+    /// Find the part of the vector perpendicular to another vector
+    /// 
+    /// This is the vector minus its [`project_onto`](#method.project_onto) projection.
+    /// 
+    /// # Examples
+    /// ```
+    /// use vex::Vector3;
+    /// 
+    /// let actual = Vector3::make(2.0, 3.0, 4.0).reject_from(&Vector3::make(4.0, 0.0, 0.0));
+    /// let expected = Vector3::make(0.0, 3.0, 4.0);
+    /// assert_eq!(actual, expected);
+    /// ```
+    #[inline]
+    pub fn reject_from(&self, other: &Vector3) -> Vector3 {
+        *self - self.project_onto(other)
+    }
+
+    /// Find the reflection of the vector about a surface with the provided normal
+    /// 
+    /// `normal` should be normalized.
+    /// 
+    /// # Examples
+    /// ```
+    /// use vex::Vector3;
+    /// 
+    /// let actual = Vector3::make(1.0, -1.0, 0.0).reflect(&Vector3::make(0.0, 1.0, 0.0));
+    /// let expected = Vector3::make(1.0, 1.0, 0.0);
+    /// assert_eq!(actual, expected);
+    /// ```
+    #[inline]
+    pub fn reflect(&self, normal: &Vector3) -> Vector3 {
+        *self - *normal * (2.0 * Vector3::dot(self, normal))
+    }
+
+    /// Find the refraction of the vector through a surface with the provided normal
+    /// 
+    /// `eta` is the ratio of the refractive indices (incident over transmitted), and both the
+    /// vector and `normal` should be normalized with `normal` facing against the vector. Returns
+    /// `None` on total internal reflection.
+    /// 
+    /// # Examples
+    /// ```
+    /// use vex::Vector3;
+    /// 
+    /// let incident = Vector3::make(1.0, -1.0, 0.0).normalized();
+    /// let normal = Vector3::make(0.0, 1.0, 0.0);
+    /// assert_eq!(incident.refract(&normal, 1.0), Some(incident));
+    /// 
+    /// let actual = incident.refract(&normal, 1.0 / 1.5).unwrap();
+    /// let sine = actual.x / actual.mag();
+    /// assert!((sine - incident.x / 1.5).abs() < 0.000001);
+    /// 
+    /// assert_eq!(incident.refract(&normal, 1.5), None);
+    /// ```
+    #[inline]
+    pub fn refract(&self, normal: &Vector3, eta: f32) -> Option<Vector3> {
+        let cosine = Vector3::dot(normal, self);
+        let k = 1.0 - eta * eta * (1.0 - cosine * cosine);
+        if k < 0.0 {
+            return None;
+        }
+
+        Some(*self * eta - *normal * (eta * cosine + k.sqrt()))
+    }
+
+    /// Find the nearest of the six unit axes, ±X, ±Y, and ±Z
+    /// 
+    /// Ties go to the earlier axis, and a zero vector snaps to zero.
+    /// 
+    /// # Examples
+    /// ```
+    /// use vex::Vector3;
+    /// 
+    /// assert_eq!(Vector3::make(0.2, -0.9, 0.4).snap_to_axis(), Vector3::make(0.0, -1.0, 0.0));
+    /// assert_eq!(Vector3::make(3.0, 1.0, -3.0).snap_to_axis(), Vector3::make(1.0, 0.0, 0.0));
+    /// assert_eq!(Vector3::new().snap_to_axis(), Vector3::new());
+    /// ```
+    #[inline]
+    pub fn snap_to_axis(&self) -> Vector3 {
+        let mut axis = 0;
+        for i in 1..3 {
+            if self[i].abs() > self[axis].abs() {
+                axis = i;
+            }
+        }
+
+        let mut result = Vector3::new();
+        if self[axis] != 0.0 {
+            result[axis] = self[axis].signum();
+        }
+
+        result
+    }
+
+    #[inline]
+    pub(crate) fn perpendicular(a: &Vector3) -> Vector3 {
+        // cross with the axis least aligned with the vector
+        let axis = if a.x.abs() < a.y.abs() && a.x.abs() < a.z.abs() {
+            Vector3::right()
+        } else if a.y.abs() < a.z.abs() {
+            Vector3::up()
+        } else {
+            Vector3::forward()
+        };
+
+        Vector3::cross(a, &axis).normalized()
+    }
+
+    /// Find the clamped (component-wise) vector between two vectors
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Vector3;
+    /// 
+    /// let a = Vector3::make(1.0, 3.0, 5.0);
+    /// let b = Vector3::make(2.0, 4.0, 6.0);
+    /// let mut actual = Vector3::make(0.0, 5.0, 10.0);
+    /// actual.clamp(&a, &b);
+    /// let expected = Vector3::make(1.0, 4.0, 6.0);
+    /// assert_eq!(actual, expected);
+    /// ```
+    #[inline]
+    pub fn clamp(&mut self, a: &Vector3, b: &Vector3) {
+        let low = Self::min(a, b);
+        let high = Self::max(a, b);
+        let result = Self::max(&low, &Self::min(self, &high));
+        self.set(result.x, result.y, result.z);
+    }
+
+    /// Set the components of a vector
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Vector3;
+    /// 
+    /// let mut actual = Vector3::new();
+    /// actual.set(1.0, 2.0, 3.0);
+    /// let expected = Vector3::make(1.0, 2.0, 3.0);
+    /// assert_eq!(actual, expected);
+    /// ```
+    #[inline]
+    pub fn set(&mut self, x: f32, y: f32, z: f32) {
+        self.x = x;
+        self.y = y;
+        self.z = z;
+    }
+
+    /// Get the magnitude of the vector
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Vector3;
+    /// 
+    /// let actual = Vector3::make(1.0, 2.0, 3.0).mag();
+    /// let expected = 3.74165738677;
+    /// assert_eq!(actual, expected);
+    /// ```
+    #[inline]
+    pub fn mag(&self) -> f32 {
+        self.mag_sq().sqrt()
+    }
+
+    /// Get the squared magnitude of the vector
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Vector3;
+    /// 
+    /// let actual = Vector3::make(1.0, 2.0, 3.0).mag_sq();
+    /// let expected = 14.0;
+    /// assert_eq!(actual, expected);
+    /// ```
+    #[inline]
+    pub fn mag_sq(&self) -> f32 {
+        self.x * self.x + self.y * self.y + self.z * self.z
+    }
+
+    /// Find the distance between two vectors
+    /// 
+    /// # Examples
+    /// ```
+    /// use vex::Vector3;
+    /// 
+    /// let a = Vector3::make(1.0, 2.0, 3.0);
+    /// let b = Vector3::make(3.0, 5.0, 9.0);
+    /// let actual = Vector3::distance(&a, &b);
+    /// let expected = 7.0;
+    /// assert_eq!(actual, expected);
+    /// ```
+    #[inline]
+    pub fn distance(a: &Vector3, b: &Vector3) -> f32 {
+        Vector3::distance_sq(a, b).sqrt()
+    }
+
+    /// Find the squared distance between two vectors
+    /// 
+    /// # Examples
+    /// ```
+    /// use vex::Vector3;
+    /// 
+    /// let a = Vector3::make(1.0, 2.0, 3.0);
+    /// let b = Vector3::make(3.0, 5.0, 9.0);
+    /// let actual = Vector3::distance_sq(&a, &b);
+    /// let expected = 49.0;
+    /// assert_eq!(actual, expected);
+    /// ```
+    #[inline]
+    pub fn distance_sq(a: &Vector3, b: &Vector3) -> f32 {
+        (*b - *a).mag_sq()
+    }
+
+    /// Find the distance from the vector to another vector
+    /// 
+    /// # Examples
+    /// ```
+    /// use vex::Vector3;
+    /// 
+    /// let actual = Vector3::make(1.0, 2.0, 3.0).distance_to(&Vector3::make(3.0, 5.0, 9.0));
+    /// let expected = 7.0;
+    /// assert_eq!(actual, expected);
+    /// ```
+    #[inline]
+    pub fn distance_to(&self, other: &Vector3) -> f32 {
+        Vector3::distance(self, other)
+    }
+
+    /// Find the squared distance from the vector to another vector
+    /// 
+    /// # Examples
+    /// ```
+    /// use vex::Vector3;
+    /// 
+    /// let actual = Vector3::make(1.0, 2.0, 3.0).distance_sq_to(&Vector3::make(3.0, 5.0, 9.0));
+    /// let expected = 49.0;
+    /// assert_eq!(actual, expected);
+    /// ```
+    #[inline]
+    pub fn distance_sq_to(&self, other: &Vector3) -> f32 {
+        Vector3::distance_sq(self, other)
+    }
+
+    /// Normalize the vector
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Vector3;
+    /// 
+    /// let mut actual = Vector3::make(1.0, 2.0, 3.0);
+    /// actual.norm();
+    /// let expected = Vector3::make(0.26726124191, 0.53452248382, 0.8017837);
+    /// assert_eq!(actual, expected);
+    /// ```
+    #[inline]
+    pub fn norm(&mut self) -> f32 {
+        let length = self.mag();
+        if length > f32::EPSILON {
+            self.x /= length;
+            self.y /= length;
+            self.z /= length;
+            length
+        } else {
+            0.0
+        }
+    }
+
+    /// Set the components of a vector to their absolute values
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Vector3;
+    /// 
+    /// let mut actual = Vector3::make(-1.0, -2.0, -3.0);
+    /// actual.abs();
+    /// let expected = Vector3::make(1.0, 2.0, 3.0);
+    /// assert_eq!(actual, expected);
+    /// ```
+    #[inline]
+    pub fn abs(&mut self) {
+        self.x = self.x.abs();
+        self.y = self.y.abs();
+        self.z = self.z.abs();
+    }
+
+    /// Negate the components of the vector
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Vector3;
+    /// 
+    /// let mut actual = Vector3::make(1.0, -2.0, 3.0);
+    /// actual.negate();
+    /// let expected = Vector3::make(-1.0, 2.0, -3.0);
+    /// assert_eq!(actual, expected);
+    /// ```
+    #[inline]
+    pub fn negate(&mut self) {
+        self.x = -self.x;
+        self.y = -self.y;
+        self.z = -self.z;
+    }
+
+    /// Find the vector with its components negated
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Vector3;
+    /// 
+    /// let actual = Vector3::make(1.0, -2.0, 3.0).negated();
+    /// let expected = Vector3::make(-1.0, 2.0, -3.0);
+    /// assert_eq!(actual, expected);
+    /// ```
+    #[inline]
+    pub fn negated(&self) -> Vector3 {
+        -*self
+    }
+
+    /// Find the vector with its components set to their absolute values
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Vector3;
+    /// 
+    /// let actual = Vector3::make(1.0, -2.0, 3.0).abs_value();
+    /// let expected = Vector3::make(1.0, 2.0, 3.0);
+    /// assert_eq!(actual, expected);
+    /// ```
+    #[inline]
+    pub fn abs_value(&self) -> Vector3 {
+        let mut result = *self;
+        result.abs();
+        result
+    }
+
+    /// Find the vector with its components clamped between the provided vectors
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Vector3;
+    /// 
+    /// let actual = Vector3::make(1.0, -2.0, 3.0).clamped(&Vector3::make(0.0, 0.0, 0.0), &Vector3::make(2.0, 2.0, 2.0));
+    /// let expected = Vector3::make(1.0, 0.0, 2.0);
+    /// assert_eq!(actual, expected);
+    /// ```
+    #[inline]
+    pub fn clamped(&self, a: &Vector3, b: &Vector3) -> Vector3 {
+        let mut result = *self;
+        result.clamp(a, b);
+        result
+    }
+
+    /// Find the normalized vector
+    ///
+    /// Vectors too short to normalize are returned as zero, matching `norm`.
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Vector3;
+    /// 
+    /// let actual = Vector3::make(0.0, 3.0, 0.0).normalized();
+    /// let expected = Vector3::make(0.0, 1.0, 0.0);
+    /// assert_eq!(actual, expected);
+    /// ```
+    #[inline]
+    pub fn normalized(&self) -> Vector3 {
+        let mut result = *self;
+        if result.norm() == 0.0 {
+            result = Vector3::new();
+        }
+
+        result
+    }
+
+    /// Encodes the vector's components as normalized i16 values
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Vector3;
+    /// 
+    /// let actual = Vector3::make(1.0, -1.0, 0.0).to_snorm16();
+    /// assert_eq!(actual, [32767, -32767, 0]);
     /// ```
     #[inline]
-    pub fn new() -> Vector3 {
-        Vector3 { x: 0.0, y: 0.0, z: 0.0 }
+    pub fn to_snorm16(&self) -> [i16; 3] {
+        [
+            normalized::encode_snorm16(self.x),
+            normalized::encode_snorm16(self.y),
+            normalized::encode_snorm16(self.z),
+        ]
     }
 
-    /// Creates a vector <1.0, 1.0, 1.0>
+    /// Creates a vector from normalized i16 values
     ///
     /// # Examples
     /// ```
     /// use vex::Vector3;
     /// 
-    /// let actual = Vector3::one();
-    /// let expected = Vector3 { x: 1.0, y: 1.0, z: 1.0 };
+    /// let actual = Vector3::from_snorm16([32767, -32767, 0]);
+    /// let expected = Vector3::make(1.0, -1.0, 0.0);
     /// assert_eq!(actual, expected);
     /// ```
     #[inline]
-    pub fn one() -> Vector3 {
-        Vector3 { x: 1.0, y: 1.0, z: 1.0 }
+    pub fn from_snorm16(v: [i16; 3]) -> Vector3 {
+        Vector3::make(
+            normalized::decode_snorm16(v[0]),
+            normalized::decode_snorm16(v[1]),
+            normalized::decode_snorm16(v[2]),
+        )
     }
 
-    /// Creates a right vector
+    /// Encodes the vector's components as normalized i8 values
     ///
     /// # Examples
     /// ```
     /// use vex::Vector3;
     /// 
-    /// let actual = Vector3::right();
-    /// let expected = Vector3 { x: 1.0, y: 0.0, z: 0.0 };
-    /// assert_eq!(actual, expected);
+    /// let actual = Vector3::make(1.0, -1.0, 0.0).to_snorm8();
+    /// assert_eq!(actual, [127, -127, 0]);
     /// ```
     #[inline]
-    pub fn right() -> Vector3 {
-        Vector3 { x: 1.0, y: 0.0, z: 0.0 }
+    pub fn to_snorm8(&self) -> [i8; 3] {
+        [
+            normalized::encode_snorm8(self.x),
+            normalized::encode_snorm8(self.y),
+            normalized::encode_snorm8(self.z),
+        ]
     }
 
-    /// Creates an up vector
+    /// Creates a vector from normalized i8 values
     ///
     /// # Examples
     /// ```
     /// use vex::Vector3;
-
-    /// let actual = Vector3::up();
-    /// let expected = Vector3 { x: 0.0, y: 1.0, z: 0.0 };
+    /// 
+    /// let actual = Vector3::from_snorm8([127, -127, 0]);
+    /// let expected = Vector3::make(1.0, -1.0, 0.0);
     /// assert_eq!(actual, expected);
     /// ```
     #[inline]
-    pub fn up() -> Vector3 {
-        Vector3 { x: 0.0, y: 1.0, z: 0.0 }
+    pub fn from_snorm8(v: [i8; 3]) -> Vector3 {
+        Vector3::make(
+            normalized::decode_snorm8(v[0]),
+            normalized::decode_snorm8(v[1]),
+            normalized::decode_snorm8(v[2]),
+        )
     }
 
-    /// Creates a forward vector
+    /// Encodes the vector's components as normalized u16 values
     ///
     /// # Examples
     /// ```
     /// use vex::Vector3;
     /// 
-    /// let actual = Vector3::forward();
-    /// let expected = Vector3 { x: 0.0, y: 0.0, z: -1.0 };
-    /// assert_eq!(actual, expected);
+    /// let actual = Vector3::make(1.0, 0.0, 0.5).to_unorm16();
+    /// assert_eq!(actual, [65535, 0, 32768]);
     /// ```
     #[inline]
-    pub fn forward() -> Vector3 {
-        Vector3 { x: 0.0, y: 0.0, z: -1.0 }
+    pub fn to_unorm16(&self) -> [u16; 3] {
+        [
+            normalized::encode_unorm16(self.x),
+            normalized::encode_unorm16(self.y),
+            normalized::encode_unorm16(self.z),
+        ]
     }
 
-    /// Creates a vector from the provided values
+    /// Creates a vector from normalized u16 values
     ///
     /// # Examples
     /// ```
     /// use vex::Vector3;
     /// 
-    /// let actual = Vector3::make(1.0, 2.0, 3.0);
-    /// let expected = Vector3 { x: 1.0, y: 2.0, z: 3.0 };
+    /// let actual = Vector3::from_unorm16([65535, 0, 0]);
+    /// let expected = Vector3::make(1.0, 0.0, 0.0);
     /// assert_eq!(actual, expected);
     /// ```
     #[inline]
-    pub fn make(x: f32, y: f32, z: f32) -> Vector3 {
-        Vector3 { x, y, z }
+    pub fn from_unorm16(v: [u16; 3]) -> Vector3 {
+        Vector3::make(
+            normalized::decode_unorm16(v[0]),
+            normalized::decode_unorm16(v[1]),
+            normalized::decode_unorm16(v[2]),
+        )
     }
 
-    /// Find the dot product between two vectors
+    /// Encodes the vector's components as normalized u8 values
     ///
     /// # Examples
     /// ```
     /// use vex::Vector3;
     /// 
-    /// let a = Vector3::make(1.0, 0.0, 0.0);
-    /// let b = Vector3::make(0.0, 0.0, 1.0);
-    /// let actual = Vector3::dot(&a, &b);
-    /// let expected = 0.0;
-    /// assert_eq!(actual, expected);
+    /// let actual = Vector3::make(1.0, 0.0, 0.5).to_unorm8();
+    /// assert_eq!(actual, [255, 0, 128]);
     /// ```
     #[inline]
-    pub fn dot(a: &Vector3, b: &Vector3) -> f32 {
-        a.x * b.x + a.y * b.y + a.z * b.z
+    pub fn to_unorm8(&self) -> [u8; 3] {
+        [
+            normalized::encode_unorm8(self.x),
+            normalized::encode_unorm8(self.y),
+            normalized::encode_unorm8(self.z),
+        ]
     }
 
-    /// Find the cross product between two vectors
+    /// Creates a vector from normalized u8 values
     ///
     /// # Examples
     /// ```
     /// use vex::Vector3;
     /// 
-    /// let a = Vector3::make(0.0, 0.0, 1.0);
-    /// let b = Vector3::make(1.0, 0.0, 0.0);
-    /// let actual = Vector3::cross(&a, &b);
-    /// let expected = Vector3::make(0.0, 1.0, 0.0);
+    /// let actual = Vector3::from_unorm8([255, 0, 0]);
+    /// let expected = Vector3::make(1.0, 0.0, 0.0);
     /// assert_eq!(actual, expected);
     /// ```
     #[inline]
-    pub fn cross(a: &Vector3, b: &Vector3) -> Vector3 {
+    pub fn from_unorm8(v: [u8; 3]) -> Vector3 {
         Vector3::make(
-            a.y * b.z - a.z * b.y,
-            a.z * b.x - a.x * b.z,
-            a.x * b.y - a.y * b.x,
+            normalized::decode_unorm8(v[0]),
+            normalized::decode_unorm8(v[1]),
+            normalized::decode_unorm8(v[2]),
         )
     }
 
-    /// Find the minimum (component-wise) vector between two vectors
+    /// Determine whether or not all components of the vectors are within `epsilon` of each other
     ///
     /// # Examples
     /// ```
     /// use vex::Vector3;
     /// 
-    /// let a = Vector3::make(1.0, 4.0, 5.0);
-    /// let b = Vector3::make(2.0, 3.0, 6.0);
-    /// let actual = Vector3::min(&a, &b);
-    /// let expected = Vector3::make(1.0, 3.0, 5.0);
-    /// assert_eq!(actual, expected);
+    /// let a = Vector3::make(1.0, 2.0, 3.0);
+    /// assert!(a.approx_eq(&Vector3::make(1.0000001, 2.0, 3.0), 0.000001));
+    /// assert!(!a.approx_eq(&Vector3::make(1.1, 2.0, 3.0), 0.000001));
     /// ```
     #[inline]
-    pub fn min(a: &Vector3, b: &Vector3) -> Vector3 {
-        Vector3::make(a.x.min(b.x), a.y.min(b.y), a.z.min(b.z))
+    pub fn approx_eq(&self, other: &Vector3, epsilon: f32) -> bool {
+        for i in 0..3 {
+            if (self[i] - other[i]).abs() > epsilon {
+                return false;
+            }
+        }
+
+        true
     }
 
-    /// Find the maximum (component-wise) vector between two vectors
+    /// Determine whether or not all components of the vectors are equal under an epsilon policy
     ///
     /// # Examples
     /// ```
+    /// use vex::Epsilon;
     /// use vex::Vector3;
     /// 
-    /// let a = Vector3::make(1.0, 4.0, 5.0);
-    /// let b = Vector3::make(2.0, 3.0, 6.0);
-    /// let actual = Vector3::max(&a, &b);
-    /// let expected = Vector3::make(2.0, 4.0, 6.0);
-    /// assert_eq!(actual, expected);
+    /// let a = Vector3::make(1000000.0, 2.0, 3.0);
+    /// assert!(a.approx_eq_with(&Vector3::make(1000000.06, 2.0, 3.0), &Epsilon::DEFAULT));
+    /// assert!(!a.approx_eq_with(&Vector3::make(1000000.0, 2.1, 3.0), &Epsilon::DEFAULT));
     /// ```
     #[inline]
-    pub fn max(a: &Vector3, b: &Vector3) -> Vector3 {
-        Vector3::make(a.x.max(b.x), a.y.max(b.y), a.z.max(b.z))
+    pub fn approx_eq_with(&self, other: &Vector3, epsilon: &Epsilon) -> bool {
+        let a: [f32; 3] = (*self).into();
+        let b: [f32; 3] = (*other).into();
+        epsilon.all_approx_eq(&a, &b)
     }
 
-    /// Find the clamped (component-wise) vector between two vectors
+    /// Determine whether or not the vector has unit length under an epsilon policy
     ///
     /// # Examples
     /// ```
+    /// use vex::Epsilon;
     /// use vex::Vector3;
     /// 
-    /// let a = Vector3::make(1.0, 3.0, 5.0);
-    /// let b = Vector3::make(2.0, 4.0, 6.0);
-    /// let mut actual = Vector3::make(0.0, 5.0, 10.0);
-    /// actual.clamp(&a, &b);
-    /// let expected = Vector3::make(1.0, 4.0, 6.0);
-    /// assert_eq!(actual, expected);
+    /// assert!(Vector3::make(0.0, 0.6, 0.8).is_normalized(&Epsilon::DEFAULT));
+    /// assert!(!Vector3::make(0.0, 0.6, 0.9).is_normalized(&Epsilon::DEFAULT));
+    /// assert!(Vector3::make(0.0, 0.6, 0.9).is_normalized(&Epsilon::DEFAULT.with_absolute(0.1)));
     /// ```
     #[inline]
-    pub fn clamp(&mut self, a: &Vector3, b: &Vector3) {
-        let low = Self::min(a, b);
-        let high = Self::max(a, b);
-        let result = Self::max(&low, &Self::min(self, &high));
-        self.set(result.x, result.y, result.z);
+    pub fn is_normalized(&self, epsilon: &Epsilon) -> bool {
+        epsilon.approx_eq(self.mag(), 1.0)
     }
 
-    /// Set the components of a vector
+    /// Determine whether or not the vector is too short to have a direction under an epsilon policy
     ///
     /// # Examples
     /// ```
+    /// use vex::Epsilon;
     /// use vex::Vector3;
     /// 
-    /// let mut actual = Vector3::new();
-    /// actual.set(1.0, 2.0, 3.0);
-    /// let expected = Vector3::make(1.0, 2.0, 3.0);
-    /// assert_eq!(actual, expected);
+    /// let v = Vector3::make(0.0, 0.6, 0.9) * 0.0000001;
+    /// assert!(v.is_zero(&Epsilon::DEFAULT));
+    /// assert!(!v.is_zero(&Epsilon::DEFAULT.with_absolute(0.0)));
     /// ```
     #[inline]
-    pub fn set(&mut self, x: f32, y: f32, z: f32) {
-        self.x = x;
-        self.y = y;
-        self.z = z;
+    pub fn is_zero(&self, epsilon: &Epsilon) -> bool {
+        epsilon.is_zero(self.mag())
     }
 
-    /// Get the magnitude of the vector
+    /// Determine whether or not all components of the vector are valid
     ///
     /// # Examples
     /// ```
     /// use vex::Vector3;
     /// 
-    /// let actual = Vector3::make(1.0, 2.0, 3.0).mag();
-    /// let expected = 3.74165738677;
-    /// assert_eq!(actual, expected);
+    /// let actual = Vector3::make(1.0, 2.0, 3.0);
+    /// assert!(actual.is_valid());
     /// ```
     #[inline]
-    pub fn mag(&self) -> f32 {
-        self.mag_sq().sqrt()
+    pub fn is_valid(&self) -> bool {
+        for i in 0..3 {
+            if !common::is_valid(self[i]) {
+                return false;
+            }
+        }
+
+        true
     }
+}
 
-    /// Get the squared magnitude of the vector
+impl Default for Vector3 {
+    /// Creates a zero vector
     ///
     /// # Examples
     /// ```
     /// use vex::Vector3;
-    /// 
-    /// let actual = Vector3::make(1.0, 2.0, 3.0).mag_sq();
-    /// let expected = 14.0;
-    /// assert_eq!(actual, expected);
+    ///
+    /// assert_eq!(Vector3::default(), Vector3::new());
     /// ```
     #[inline]
-    pub fn mag_sq(&self) -> f32 {
-        self.x * self.x + self.y * self.y + self.z * self.z
+    fn default() -> Vector3 {
+        Vector3::new()
     }
+}
 
-    /// Normalize the vector
+impl From<[f32; 3]> for Vector3 {
+    /// Creates a Vector3 from an array of components
     ///
     /// # Examples
     /// ```
     /// use vex::Vector3;
     /// 
-    /// let mut actual = Vector3::make(1.0, 2.0, 3.0);
-    /// actual.norm();
-    /// let expected = Vector3::make(0.26726124191, 0.53452248382, 0.8017837);
+    /// let actual = Vector3::from([1.0, 2.0, 3.0]);
+    /// let expected = Vector3::make(1.0, 2.0, 3.0);
     /// assert_eq!(actual, expected);
     /// ```
     #[inline]
-    pub fn norm(&mut self) -> f32 {
-        let length = self.mag();
-        if length > EPSILON {
-            self.x /= length;
-            self.y /= length;
-            self.z /= length;
-            length
-        } else {
-            0.0
-        }
+    fn from(item: [f32; 3]) -> Vector3 {
+        Vector3::make(item[0], item[1], item[2])
     }
+}
 
-    /// Set the components of a vector to their absolute values
+impl From<Vector3> for [f32; 3] {
+    /// Creates an array from the components of a Vector3
     ///
     /// # Examples
     /// ```
     /// use vex::Vector3;
     /// 
-    /// let mut actual = Vector3::make(-1.0, -2.0, -3.0);
-    /// actual.abs();
-    /// let expected = Vector3::make(1.0, 2.0, 3.0);
+    /// let actual: [f32; 3] = Vector3::make(1.0, 2.0, 3.0).into();
+    /// let expected = [1.0, 2.0, 3.0];
     /// assert_eq!(actual, expected);
     /// ```
     #[inline]
-    pub fn abs(&mut self) {
-        self.x = self.x.abs();
-        self.y = self.y.abs();
-        self.z = self.z.abs();
+    fn from(item: Vector3) -> [f32; 3] {
+        [item.x, item.y, item.z]
     }
+}
 
-    /// Determine whether or not all components of the vector are valid
+impl AsRef<[f32]> for Vector3 {
+    /// Gets the components as a slice
     ///
     /// # Examples
     /// ```
     /// use vex::Vector3;
     /// 
-    /// let actual = Vector3::make(1.0, 2.0, 3.0);
-    /// assert!(actual.is_valid());
+    /// let v = Vector3::make(1.0, 2.0, 3.0);
+    /// let actual: &[f32] = v.as_ref();
+    /// assert_eq!(actual, &[1.0, 2.0, 3.0][..]);
     /// ```
     #[inline]
-    pub fn is_valid(&self) -> bool {
-        for i in 0..3 {
-            if !common::is_valid(self[i]) {
-                return false;
-            }
-        }
+    fn as_ref(&self) -> &[f32] {
+        // SAFETY: the type is `repr(C)` with only f32 fields, so they're laid out contiguously
+        unsafe { slice::from_raw_parts(self as *const Vector3 as *const f32, 3) }
+    }
+}
 
-        true
+impl AsMut<[f32]> for Vector3 {
+    /// Gets the components as a mutable slice
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Vector3;
+    /// 
+    /// let mut v = Vector3::make(1.0, 2.0, 3.0);
+    /// v.as_mut()[0] = 5.0;
+    /// assert_eq!(v.as_ref()[0], 5.0);
+    /// ```
+    #[inline]
+    fn as_mut(&mut self) -> &mut [f32] {
+        // SAFETY: the type is `repr(C)` with only f32 fields, so they're laid out contiguously
+        unsafe { slice::from_raw_parts_mut(self as *mut Vector3 as *mut f32, 3) }
     }
 }
 
@@ -387,13 +1443,11 @@ impl Index<u32> for Vector3 {
     /// ```
     #[inline]
     fn index(&self, index: u32) -> &f32 {
-        unsafe {
-            match index {
-                0 => &self.x,
-                1 => &self.y,
-                2 => &self.z,
-                _ => panic!("Invalid index for Vector3: {}", index),
-            }
+        match index {
+            0 => &self.x,
+            1 => &self.y,
+            2 => &self.z,
+            _ => panic!("Invalid index for Vector3: {}", index),
         }
     }
 }
@@ -414,14 +1468,12 @@ impl IndexMut<u32> for Vector3 {
     /// assert_eq!(v[2], 6.0);
     /// ```
     #[inline]
-    fn index_mut<'a>(&'a mut self, index: u32) -> &'a mut f32 {
-        unsafe {
-            match index {
-                0 => &mut self.x,
-                1 => &mut self.y,
-                2 => &mut self.z,
-                _ => panic!("Invalid index for Vector3: {}", index),
-            }
+    fn index_mut(&mut self, index: u32) -> &mut f32 {
+        match index {
+            0 => &mut self.x,
+            1 => &mut self.y,
+            2 => &mut self.z,
+            _ => panic!("Invalid index for Vector3: {}", index),
         }
     }
 }
@@ -464,6 +1516,25 @@ impl Add<f32> for Vector3 {
     }
 }
 
+impl Add<Vector3> for f32 {
+    type Output = Vector3;
+
+    /// Find the resulting vector by adding a vector's components to a scalar
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Vector3;
+    /// 
+    /// let actual = 1.0 + Vector3::make(1.0, 2.0, 3.0);
+    /// let expected = Vector3::make(2.0, 3.0, 4.0);
+    /// assert_eq!(actual, expected);
+    /// ```
+    #[inline]
+    fn add(self, _rhs: Vector3) -> Vector3 {
+        Vector3::make(self + _rhs.x, self + _rhs.y, self + _rhs.z)
+    }
+}
+
 impl Add<Vector3> for Vector3 {
     type Output = Vector3;
 
@@ -544,6 +1615,25 @@ impl Sub<f32> for Vector3 {
     }
 }
 
+impl Sub<Vector3> for f32 {
+    type Output = Vector3;
+
+    /// Find the resulting vector by subtracting a vector's components from a scalar
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Vector3;
+    /// 
+    /// let actual = 10.0 - Vector3::make(1.0, 2.0, 3.0);
+    /// let expected = Vector3::make(9.0, 8.0, 7.0);
+    /// assert_eq!(actual, expected);
+    /// ```
+    #[inline]
+    fn sub(self, _rhs: Vector3) -> Vector3 {
+        Vector3::make(self - _rhs.x, self - _rhs.y, self - _rhs.z)
+    }
+}
+
 impl Sub<Vector3> for Vector3 {
     type Output = Vector3;
 
@@ -623,6 +1713,25 @@ impl Mul<f32> for Vector3 {
     }
 }
 
+impl Mul<Vector3> for f32 {
+    type Output = Vector3;
+
+    /// Find the resulting vector by multiplying a scalar by a vector's components
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Vector3;
+    /// 
+    /// let actual = 2.0 * Vector3::make(1.0, 2.0, 3.0);
+    /// let expected = Vector3::make(2.0, 4.0, 6.0);
+    /// assert_eq!(actual, expected);
+    /// ```
+    #[inline]
+    fn mul(self, _rhs: Vector3) -> Vector3 {
+        Vector3::make(self * _rhs.x, self * _rhs.y, self * _rhs.z)
+    }
+}
+
 impl Mul<Vector3> for Vector3 {
     type Output = Vector3;
 
@@ -786,8 +1895,82 @@ impl cmp::PartialEq for Vector3 {
 }
 
 impl Display for Vector3 {
+    /// Formats as `<x, y, z>`, with the formatter's flags applied to each component
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Vector3;
+    ///
+    /// let v = Vector3::make(1.0, -2.5, 0.125);
+    /// assert_eq!(format!("{}", v), "<1, -2.5, 0.125>");
+    /// assert_eq!(format!("{:6.2}", v), "<  1.00,  -2.50,   0.12>");
+    /// ```
     #[inline]
     fn fmt(&self, f: &mut Formatter) -> fmt::Result {
-        unsafe { write!(f, "<{}  {}  {}>", self.x, self.y, self.z) }
+        format::write_vector(f, &[self.x, self.y, self.z])
+    }
+}
+
+impl FromStr for Vector3 {
+    type Err = ParseError;
+
+    /// Parses a vector written as `<x, y, z>`, as `Display` writes it
+    ///
+    /// Whitespace around components is ignored, so padded output parses too.
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::ParseError;
+    /// use vex::Vector3;
+    ///
+    /// let v = Vector3::make(1.0, -2.5, 0.125);
+    /// assert_eq!("<1, -2.5, 0.125>".parse::<Vector3>(), Ok(v));
+    /// assert_eq!(format!("{:8.3}", v).parse::<Vector3>(), Ok(v));
+    ///
+    /// let actual = "<1, 2, 3, 4, 5>".parse::<Vector3>();
+    /// assert_eq!(actual, Err(ParseError::Count { expected: 3, found: 5 }));
+    /// assert_eq!("1, 2, 3".parse::<Vector3>(), Err(ParseError::Expected('<')));
+    ///
+    /// // older versions separated components with whitespace alone
+    /// assert_eq!("<1  -2.5  0.125>".parse::<Vector3>(), Ok(v));
+    ///
+    /// let actual = "<1, two, 3>".parse::<Vector3>();
+    /// assert!(matches!(actual, Err(ParseError::Number { index: 1, .. })));
+    /// ```
+    #[inline]
+    fn from_str(s: &str) -> Result<Vector3, ParseError> {
+        let [x, y, z] = parse::parse_vector(s)?;
+        Ok(Vector3::make(x, y, z))
+    }
+}
+
+impl common::Vector for Vector3 {
+    #[inline]
+    fn zero() -> Vector3 {
+        Vector3::new()
+    }
+
+    #[inline]
+    fn dot(a: &Vector3, b: &Vector3) -> f32 {
+        Vector3::dot(a, b)
+    }
+
+    #[inline]
+    fn approx_eq(&self, other: &Vector3, epsilon: f32) -> bool {
+        Vector3::approx_eq(self, other, epsilon)
+    }
+}
+
+impl common::MixFactor<Vector3> for f32 {
+    #[inline]
+    fn spread(self) -> Vector3 {
+        Vector3::make(self, self, self)
+    }
+}
+
+impl common::MixFactor<Vector3> for Vector3 {
+    #[inline]
+    fn spread(self) -> Vector3 {
+        self
     }
 }
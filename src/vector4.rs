@@ -1,13 +1,20 @@
 use crate::common;
+use crate::epsilon::Epsilon;
+use crate::format;
+#[cfg(not(feature = "std"))]
+use crate::math::Float;
+use crate::normalized;
+use crate::parse::{self, ParseError};
 use crate::vector3::Vector3;
 
-use std::cmp;
-use std::convert::From;
-use std::f32::EPSILON;
-use std::fmt;
-use std::fmt::{Display, Formatter};
+use core::cmp;
+use core::convert::From;
+use core::fmt;
+use core::slice;
+use core::fmt::{Display, Formatter};
+use core::str::FromStr;
 
-use std::ops::{
+use core::ops::{
     Index,
     IndexMut,
     Neg,
@@ -21,8 +28,20 @@ use std::ops::{
     DivAssign,
 };
 
-#[repr(C, packed)]
+/// Aligned to 16 bytes so it can be loaded directly into SIMD registers
+///
+/// # Examples
+/// ```
+/// use std::mem;
+/// use vex::Vector4;
+///
+/// assert_eq!(mem::align_of::<Vector4>(), 16);
+/// assert_eq!(mem::size_of::<Vector4>(), 16);
+/// ```
+#[repr(C, align(16))]
 #[derive(Copy, Clone, Debug)]
+#[cfg_attr(feature = "rkyv", derive(rkyv::Archive, rkyv::Deserialize, rkyv::Serialize))]
+#[cfg_attr(feature = "rkyv", rkyv(derive(Debug), compare(PartialEq)))]
 pub struct Vector4 {
     pub x: f32,
     pub y: f32,
@@ -31,224 +50,993 @@ pub struct Vector4 {
 }
 
 impl Vector4 {
+    /// A vector <0.0, 0.0, 0.0, 0.0>
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Vector4;
+    /// 
+    /// assert_eq!(Vector4::ZERO, Vector4::new());
+    /// ```
+    pub const ZERO: Vector4 = Vector4 { x: 0.0, y: 0.0, z: 0.0, w: 0.0 };
+
+    /// A vector <1.0, 1.0, 1.0, 1.0>
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Vector4;
+    /// 
+    /// assert_eq!(Vector4::ONE, Vector4::one());
+    /// ```
+    pub const ONE: Vector4 = Vector4 { x: 1.0, y: 1.0, z: 1.0, w: 1.0 };
+
+    /// A unit vector <1.0, 0.0, 0.0, 0.0> along the x-axis
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Vector4;
+    ///
+    /// assert_eq!(Vector4::X, Vector4::make(1.0, 0.0, 0.0, 0.0));
+    /// ```
+    pub const X: Vector4 = Vector4 { x: 1.0, y: 0.0, z: 0.0, w: 0.0 };
+
+    /// A unit vector <0.0, 1.0, 0.0, 0.0> along the y-axis
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Vector4;
+    ///
+    /// assert_eq!(Vector4::Y, Vector4::make(0.0, 1.0, 0.0, 0.0));
+    /// ```
+    pub const Y: Vector4 = Vector4 { x: 0.0, y: 1.0, z: 0.0, w: 0.0 };
+
+    /// A unit vector <0.0, 0.0, 1.0, 0.0> along the z-axis
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Vector4;
+    ///
+    /// assert_eq!(Vector4::Z, Vector4::make(0.0, 0.0, 1.0, 0.0));
+    /// ```
+    pub const Z: Vector4 = Vector4 { x: 0.0, y: 0.0, z: 1.0, w: 0.0 };
+
+    /// A unit vector <0.0, 0.0, 0.0, 1.0> along the w-axis
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Vector4;
+    ///
+    /// assert_eq!(Vector4::W, Vector4::make(0.0, 0.0, 0.0, 1.0));
+    /// ```
+    pub const W: Vector4 = Vector4 { x: 0.0, y: 0.0, z: 0.0, w: 1.0 };
+
+    /// Creates a vector <0.0, 0.0, 0.0, 0.0>
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Vector4;
+    /// 
+    /// let actual = Vector4::new();
+    /// let expected = Vector4 { x: 0.0, y: 0.0, z: 0.0, w: 0.0 };
+    /// assert_eq!(actual, expected);
+    /// ```
+    #[inline]
+    pub const fn new() -> Vector4 {
+        Vector4 {
+            x: 0.0,
+            y: 0.0,
+            z: 0.0,
+            w: 0.0,
+        }
+    }
+
     /// Creates a vector <0.0, 0.0, 0.0, 0.0>
     ///
     /// # Examples
     /// ```
     /// use vex::Vector4;
     /// 
-    /// let actual = Vector4::new();
-    /// let expected = Vector4 { x: 0.0, y: 0.0, z: 0.0, w: 0.0 };
-    /// assert_eq!(actual, expected);
+    /// let actual = Vector4::one();
+    /// let expected = Vector4 { x: 1.0, y: 1.0, z: 1.0, w: 1.0 };
+    /// assert_eq!(actual, expected);
+    /// ```
+    #[inline]
+    pub const fn one() -> Vector4 {
+        Vector4 {
+            x: 1.0,
+            y: 1.0,
+            z: 1.0,
+            w: 1.0,
+        }
+    }
+
+    /// Creates a vector from the provided values
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Vector4;
+    /// 
+    /// let actual = Vector4::make(1.0, 2.0, 3.0, 4.0);
+    /// let expected = Vector4 { x: 1.0, y: 2.0, z: 3.0, w: 4.0 };
+    /// assert_eq!(actual, expected);
+    /// ```
+    #[inline]
+    pub const fn make(x: f32, y: f32, z: f32, w: f32) -> Vector4 {
+        Vector4 { x, y, z, w }
+    }
+
+    /// Find the dot product between two vectors
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Vector4;
+    /// 
+    /// let a = Vector4::make(1.0, 0.0, 0.0, 0.0);
+    /// let b = Vector4::make(0.0, 0.0, 1.0, 0.0);
+    /// let actual = Vector4::dot(&a, &b);
+    /// let expected = 0.0;
+    /// assert_eq!(actual, expected);
+    /// ```
+    #[inline]
+    pub fn dot(a: &Vector4, b: &Vector4) -> f32 {
+        a.x * b.x + a.y * b.y + a.z * b.z + a.w * b.w
+    }
+
+    /// Find the minimum (component-wise) vector between two vectors
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Vector4;
+    /// 
+    /// let a = Vector4::make(1.0, 4.0, 5.0, 7.0);
+    /// let b = Vector4::make(2.0, 3.0, 6.0, 8.0);
+    /// let actual = Vector4::min(&a, &b);
+    /// let expected = Vector4::make(1.0, 3.0, 5.0, 7.0);
+    /// assert_eq!(actual, expected);
+    /// ```
+    #[inline]
+    pub fn min(a: &Vector4, b: &Vector4) -> Vector4 {
+        Vector4::make(a.x.min(b.x), a.y.min(b.y), a.z.min(b.z), a.w.min(b.w))
+    }
+
+    /// Find the maximum (component-wise) vector between two vectors
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Vector4;
+    /// 
+    /// let a = Vector4::make(1.0, 4.0, 5.0, 7.0);
+    /// let b = Vector4::make(2.0, 3.0, 6.0, 8.0);
+    /// let actual = Vector4::max(&a, &b);
+    /// let expected = Vector4::make(2.0, 4.0, 6.0, 8.0);
+    /// assert_eq!(actual, expected);
+    /// ```
+    #[inline]
+    pub fn max(a: &Vector4, b: &Vector4) -> Vector4 {
+        Vector4::make(a.x.max(b.x), a.y.max(b.y), a.z.max(b.z), a.w.max(b.w))
+    }
+
+    /// Find the smooth minimum (component-wise) of two vectors, blending where components are
+    /// within `k` of each other
+    ///
+    /// Each component is the quadratic polynomial [`smooth_min`](fn.smooth_min.html) of the
+    /// components.
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Vector4;
+    /// 
+    /// let a = Vector4::make(1.0, 2.0, 5.0, 0.0);
+    /// let b = Vector4::make(3.0, 2.0, 5.0, 9.0);
+    /// let actual = Vector4::smooth_min(&a, &b, 1.0);
+    /// assert_eq!(actual, Vector4::make(1.0, 1.75, 4.75, 0.0));
+    /// ```
+    #[inline]
+    pub fn smooth_min(a: &Vector4, b: &Vector4, k: f32) -> Vector4 {
+        Vector4::make(
+            common::smooth_min(a.x, b.x, k),
+            common::smooth_min(a.y, b.y, k),
+            common::smooth_min(a.z, b.z, k),
+            common::smooth_min(a.w, b.w, k),
+        )
+    }
+
+    /// Find the smooth maximum (component-wise) of two vectors, blending where components are
+    /// within `k` of each other
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Vector4;
+    /// 
+    /// let a = Vector4::make(1.0, 2.0, 5.0, 0.0);
+    /// let b = Vector4::make(3.0, 2.0, 5.0, 9.0);
+    /// let actual = Vector4::smooth_max(&a, &b, 1.0);
+    /// assert_eq!(actual, Vector4::make(3.0, 2.25, 5.25, 9.0));
+    /// ```
+    #[inline]
+    pub fn smooth_max(a: &Vector4, b: &Vector4, k: f32) -> Vector4 {
+        Vector4::make(
+            common::smooth_max(a.x, b.x, k),
+            common::smooth_max(a.y, b.y, k),
+            common::smooth_max(a.z, b.z, k),
+            common::smooth_max(a.w, b.w, k),
+        )
+    }
+
+    /// Find the soft absolute value (component-wise) of a vector, `sqrt(x^2 + k^2)`
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Vector4;
+    /// 
+    /// let actual = Vector4::make(-3.0, 0.0, 3.0, 4.0).soft_abs(4.0);
+    /// assert!((actual - Vector4::make(5.0, 4.0, 5.0, 32.0f32.sqrt())).mag() < 0.000001);
+    /// ```
+    #[inline]
+    pub fn soft_abs(&self, k: f32) -> Vector4 {
+        Vector4::make(
+            common::soft_abs(self.x, k),
+            common::soft_abs(self.y, k),
+            common::soft_abs(self.z, k),
+            common::soft_abs(self.w, k),
+        )
+    }
+
+    /// Find the vector with a function applied to each component
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Vector4;
+    /// 
+    /// let actual = Vector4::make(1.0, -2.0, 3.0, 0.5).map(|c| c * c);
+    /// assert_eq!(actual, Vector4::make(1.0, 4.0, 9.0, 0.25));
+    /// ```
+    #[inline]
+    pub fn map<F: Fn(f32) -> f32>(&self, f: F) -> Vector4 {
+        Vector4::make(
+            f(self.x),
+            f(self.y),
+            f(self.z),
+            f(self.w),
+        )
+    }
+
+    /// Find the vector combining each pair of components from two vectors with a function
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Vector4;
+    /// 
+    /// let a = Vector4::make(1.0, -2.0, 3.0, 0.5);
+    /// let b = Vector4::make(3.0, 4.0, -1.0, 2.0);
+    /// let actual = a.zip_with(&b, f32::max);
+    /// assert_eq!(actual, Vector4::make(3.0, 4.0, 3.0, 2.0));
+    /// ```
+    #[inline]
+    pub fn zip_with<F: Fn(f32, f32) -> f32>(&self, other: &Vector4, f: F) -> Vector4 {
+        Vector4::make(
+            f(self.x, other.x),
+            f(self.y, other.y),
+            f(self.z, other.z),
+            f(self.w, other.w),
+        )
+    }
+
+    /// Gets the smallest component
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Vector4;
+    /// 
+    /// assert_eq!(Vector4::make(1.0, -2.0, 3.0, 0.5).min_element(), -2.0);
+    /// ```
+    #[inline]
+    pub fn min_element(&self) -> f32 {
+        self.x.min(self.y).min(self.z).min(self.w)
+    }
+
+    /// Gets the largest component
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Vector4;
+    /// 
+    /// assert_eq!(Vector4::make(1.0, -2.0, 3.0, 0.5).max_element(), 3.0);
+    /// ```
+    #[inline]
+    pub fn max_element(&self) -> f32 {
+        self.x.max(self.y).max(self.z).max(self.w)
+    }
+
+    /// Gets the sum of the components
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Vector4;
+    /// 
+    /// assert_eq!(Vector4::make(1.0, -2.0, 3.0, 0.5).sum(), 2.5);
+    /// ```
+    #[inline]
+    pub fn sum(&self) -> f32 {
+        self.x + self.y + self.z + self.w
+    }
+
+    /// Find the vector with the reciprocal of each component
+    ///
+    /// Zero components become infinite, so use `recip_or` when they can occur.
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Vector4;
+    /// 
+    /// let actual = Vector4::make(2.0, -4.0, 0.5, 1.0).recip();
+    /// assert_eq!(actual, Vector4::make(0.5, -0.25, 2.0, 1.0));
+    /// ```
+    #[inline]
+    pub fn recip(&self) -> Vector4 {
+        Vector4::make(
+            1.0 / self.x,
+            1.0 / self.y,
+            1.0 / self.z,
+            1.0 / self.w,
+        )
+    }
+
+    /// Find the vector with the reciprocal of each component, using `fallback` for zero components
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Vector4;
+    /// 
+    /// let actual = Vector4::make(2.0, 0.0, -0.5, 4.0).recip_or(0.0);
+    /// assert_eq!(actual, Vector4::make(0.5, 0.0, -2.0, 0.25));
+    /// ```
+    #[inline]
+    pub fn recip_or(&self, fallback: f32) -> Vector4 {
+        self.map(|c| if c == 0.0 { fallback } else { 1.0 / c })
+    }
+
+    /// Find the vector with the reciprocal square root of each component
+    ///
+    /// Zero components become infinite and negative components become NaN.
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Vector4;
+    /// 
+    /// let actual = Vector4::make(4.0, 16.0, 0.25, 1.0).rsqrt();
+    /// assert_eq!(actual, Vector4::make(0.5, 0.25, 2.0, 1.0));
+    /// ```
+    #[inline]
+    pub fn rsqrt(&self) -> Vector4 {
+        Vector4::make(
+            1.0 / self.x.sqrt(),
+            1.0 / self.y.sqrt(),
+            1.0 / self.z.sqrt(),
+            1.0 / self.w.sqrt(),
+        )
+    }
+
+    /// Linearly interpolate between two vectors
+    ///
+    /// Values of `t` outside `[0, 1]` extrapolate along the line through both vectors.
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Vector4;
+    /// 
+    /// let a = Vector4::make(2.0, 0.0, 0.0, 0.0);
+    /// let b = Vector4::make(4.0, 8.0, 0.0, 0.0);
+    /// assert_eq!(Vector4::lerp(&a, &b, 0.5), Vector4::make(3.0, 4.0, 0.0, 0.0));
+    /// assert_eq!(Vector4::lerp(&a, &b, 2.0), Vector4::make(6.0, 16.0, 0.0, 0.0));
+    /// ```
+    #[inline]
+    pub fn lerp(a: &Vector4, b: &Vector4, t: f32) -> Vector4 {
+        *a + (*b - *a) * t
+    }
+
+    /// Linearly interpolate between two vectors with either a scalar factor or one per component
+    ///
+    /// Matches GLSL's `mix`, giving `a * (1 - t) + b * t` for each component, so a factor of 1
+    /// returns exactly `b`.
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Vector4;
+    /// 
+    /// let a = Vector4::make(0.0, 10.0, -4.0, 1.0);
+    /// let b = Vector4::make(4.0, 20.0, 4.0, 3.0);
+    /// assert_eq!(Vector4::mix(&a, &b, 0.5), Vector4::make(2.0, 15.0, 0.0, 2.0));
+    ///
+    /// let t = Vector4::make(0.0, 1.0, 0.25, 0.5);
+    /// assert_eq!(Vector4::mix(&a, &b, t), Vector4::make(0.0, 20.0, -2.0, 2.0));
+    /// ```
+    #[inline]
+    pub fn mix<T: common::MixFactor<Vector4>>(a: &Vector4, b: &Vector4, t: T) -> Vector4 {
+        let t = t.spread();
+        Vector4::make(
+            a.x * (1.0 - t.x) + b.x * t.x,
+            a.y * (1.0 - t.y) + b.y * t.y,
+            a.z * (1.0 - t.z) + b.z * t.z,
+            a.w * (1.0 - t.w) + b.w * t.w,
+        )
+    }
+
+    /// Spherically interpolate between two unit vectors at a constant angular rate
+    ///
+    /// Values of `t` outside `[0, 1]` keep rotating along the same arc. Nearly parallel vectors
+    /// fall back to a normalized lerp, and opposite vectors rotate through an arbitrary
+    /// perpendicular direction.
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Vector4;
+    /// 
+    /// let a = Vector4::make(1.0, 0.0, 0.0, 0.0);
+    /// let b = Vector4::make(0.0, 1.0, 0.0, 0.0);
+    /// let actual = Vector4::slerp(&a, &b, 1.0 / 3.0);
+    /// let angle = std::f32::consts::PI / 6.0;
+    /// let mut expected = Vector4::new();
+    /// expected.x = angle.cos();
+    /// expected.y = angle.sin();
+    /// assert!((actual - expected).mag() < 0.0001);
+    ///
+    /// let actual = Vector4::slerp(&a, &-a, 0.5);
+    /// assert!((actual.mag() - 1.0).abs() < 0.0001);
+    /// assert!(Vector4::dot(&actual, &a).abs() < 0.0001);
+    /// ```
+    #[inline]
+    pub fn slerp(a: &Vector4, b: &Vector4, t: f32) -> Vector4 {
+        let cosine = Vector4::dot(a, b).clamp(-1.0, 1.0);
+
+        // fall back to nlerp when the vectors are nearly parallel
+        if cosine > 0.9995 {
+            return Vector4::lerp(a, b, t).normalized();
+        }
+
+        // the arc is ambiguous for opposite vectors, so pick any perpendicular one
+        if cosine < -0.9995 {
+            let perpendicular = Vector4::perpendicular(a);
+            let angle = t * core::f32::consts::PI;
+            return *a * angle.cos() + perpendicular * angle.sin();
+        }
+
+        let theta = cosine.acos();
+        let sine = theta.sin();
+        let wa = ((1.0 - t) * theta).sin() / sine;
+        let wb = (t * theta).sin() / sine;
+        *a * wa + *b * wb
+    }
+
+    /// Interpolate between two vectors, easing in and out with a smoothstep curve
+    ///
+    /// `t` is clamped to `[0, 1]`, so the result never overshoots either vector.
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Vector4;
+    /// 
+    /// let a = Vector4::make(2.0, 0.0, 0.0, 0.0);
+    /// let b = Vector4::make(4.0, 8.0, 0.0, 0.0);
+    /// assert_eq!(Vector4::smooth_step(&a, &b, 0.5), Vector4::make(3.0, 4.0, 0.0, 0.0));
+    /// assert_eq!(Vector4::smooth_step(&a, &b, 0.25), Vector4::make(2.3125, 1.25, 0.0, 0.0));
+    /// assert_eq!(Vector4::smooth_step(&a, &b, -1.0), a);
+    /// assert_eq!(Vector4::smooth_step(&a, &b, 2.0), b);
+    /// ```
+    #[inline]
+    pub fn smooth_step(a: &Vector4, b: &Vector4, t: f32) -> Vector4 {
+        let t = t.clamp(0.0, 1.0);
+        Vector4::lerp(a, b, t * t * (3.0 - 2.0 * t))
+    }
+
+    #[inline]
+    fn perpendicular(a: &Vector4) -> Vector4 {
+        // remove the vector's part from the axis least aligned with it
+        let mut axis = Vector4::new();
+        let mut smallest = 0;
+        for i in 1..4 {
+            if a[i].abs() < a[smallest].abs() {
+                smallest = i;
+            }
+        }
+
+        axis[smallest] = 1.0;
+        (axis - *a * Vector4::dot(a, &axis)).normalized()
+    }
+
+    /// Find the clamped (component-wise) vector between two vectors
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Vector4;
+    /// 
+    /// let a = Vector4::make(1.0, 3.0, 5.0, 7.0);
+    /// let b = Vector4::make(2.0, 4.0, 6.0, 8.0);
+    /// let mut actual = Vector4::make(0.0, 5.0, 10.0, 20.0);
+    /// actual.clamp(&a, &b);
+    /// let expected = Vector4::make(1.0, 4.0, 6.0, 8.0);
+    /// assert_eq!(actual, expected);
+    /// ```
+    #[inline]
+    pub fn clamp(&mut self, a: &Vector4, b: &Vector4) {
+        let low = Self::min(a, b);
+        let high = Self::max(a, b);
+        let result = Self::max(&low, &Self::min(self, &high));
+        self.set(result.x, result.y, result.z, result.w);
+    }
+
+    /// Set the components of a vector
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Vector4;
+    /// 
+    /// let mut actual = Vector4::new();
+    /// actual.set(1.0, 2.0, 3.0, 4.0);
+    /// let expected = Vector4::make(1.0, 2.0, 3.0, 4.0);
+    /// assert_eq!(actual, expected);
+    /// ```
+    #[inline]
+    pub fn set(&mut self, x: f32, y: f32, z: f32, w: f32) {
+        self.x = x;
+        self.y = y;
+        self.z = z;
+        self.w = w;
+    }
+
+    /// Get the magnitude of the vector
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Vector4;
+    /// 
+    /// let actual = Vector4::make(1.0, 2.0, 3.0, 4.0).mag();
+    /// let expected = 5.47722557505;
+    /// assert_eq!(actual, expected);
+    /// ```
+    #[inline]
+    pub fn mag(&self) -> f32 {
+        self.mag_sq().sqrt()
+    }
+
+    /// Get the squared magnitude of the vector
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Vector4;
+    /// 
+    /// let actual = Vector4::make(1.0, 2.0, 3.0, 4.0).mag_sq();
+    /// let expected = 30.0;
+    /// assert_eq!(actual, expected);
+    /// ```
+    #[inline]
+    pub fn mag_sq(&self) -> f32 {
+        self.x * self.x + self.y * self.y + self.z * self.z + self.w * self.w
+    }
+
+    /// Find the distance between two vectors
+    /// 
+    /// # Examples
+    /// ```
+    /// use vex::Vector4;
+    /// 
+    /// let a = Vector4::make(1.0, 2.0, 3.0, 4.0);
+    /// let b = Vector4::make(2.0, 3.0, 4.0, 5.0);
+    /// let actual = Vector4::distance(&a, &b);
+    /// let expected = 2.0;
+    /// assert_eq!(actual, expected);
+    /// ```
+    #[inline]
+    pub fn distance(a: &Vector4, b: &Vector4) -> f32 {
+        Vector4::distance_sq(a, b).sqrt()
+    }
+
+    /// Find the squared distance between two vectors
+    /// 
+    /// # Examples
+    /// ```
+    /// use vex::Vector4;
+    /// 
+    /// let a = Vector4::make(1.0, 2.0, 3.0, 4.0);
+    /// let b = Vector4::make(2.0, 3.0, 4.0, 5.0);
+    /// let actual = Vector4::distance_sq(&a, &b);
+    /// let expected = 4.0;
+    /// assert_eq!(actual, expected);
+    /// ```
+    #[inline]
+    pub fn distance_sq(a: &Vector4, b: &Vector4) -> f32 {
+        (*b - *a).mag_sq()
+    }
+
+    /// Find the distance from the vector to another vector
+    /// 
+    /// # Examples
+    /// ```
+    /// use vex::Vector4;
+    /// 
+    /// let actual = Vector4::make(1.0, 2.0, 3.0, 4.0).distance_to(&Vector4::make(2.0, 3.0, 4.0, 5.0));
+    /// let expected = 2.0;
+    /// assert_eq!(actual, expected);
+    /// ```
+    #[inline]
+    pub fn distance_to(&self, other: &Vector4) -> f32 {
+        Vector4::distance(self, other)
+    }
+
+    /// Find the squared distance from the vector to another vector
+    /// 
+    /// # Examples
+    /// ```
+    /// use vex::Vector4;
+    /// 
+    /// let actual = Vector4::make(1.0, 2.0, 3.0, 4.0).distance_sq_to(&Vector4::make(2.0, 3.0, 4.0, 5.0));
+    /// let expected = 4.0;
+    /// assert_eq!(actual, expected);
+    /// ```
+    #[inline]
+    pub fn distance_sq_to(&self, other: &Vector4) -> f32 {
+        Vector4::distance_sq(self, other)
+    }
+
+    /// Normalize the vector
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Vector4;
+    /// 
+    /// let mut actual = Vector4::make(1.0, 2.0, 3.0, 4.0);
+    /// actual.norm();
+    /// let expected = Vector4::make(0.18257418, 0.36514837, 0.5477225, 0.73029673);
+    /// assert_eq!(actual, expected);
+    /// ```
+    #[inline]
+    pub fn norm(&mut self) -> f32 {
+        let length = self.mag();
+        if length > f32::EPSILON {
+            self.x /= length;
+            self.y /= length;
+            self.z /= length;
+            self.w /= length;
+            length
+        } else {
+            0.0
+        }
+    }
+
+    /// Set the components of a vector to their absolute values
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Vector4;
+    /// 
+    /// let mut actual = Vector4::make(-1.0, -2.0, -3.0, -4.0);
+    /// actual.abs();
+    /// let expected = Vector4::make(1.0, 2.0, 3.0, 4.0);
+    /// assert_eq!(actual, expected);
+    /// ```
+    #[inline]
+    pub fn abs(&mut self) {
+        self.x = self.x.abs();
+        self.y = self.y.abs();
+        self.z = self.z.abs();
+        self.w = self.w.abs();
+    }
+
+    /// Negate the components of the vector
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Vector4;
+    /// 
+    /// let mut actual = Vector4::make(1.0, -2.0, 3.0, -4.0);
+    /// actual.negate();
+    /// let expected = Vector4::make(-1.0, 2.0, -3.0, 4.0);
+    /// assert_eq!(actual, expected);
+    /// ```
+    #[inline]
+    pub fn negate(&mut self) {
+        self.x = -self.x;
+        self.y = -self.y;
+        self.z = -self.z;
+        self.w = -self.w;
+    }
+
+    /// Find the vector with its components negated
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Vector4;
+    /// 
+    /// let actual = Vector4::make(1.0, -2.0, 3.0, -4.0).negated();
+    /// let expected = Vector4::make(-1.0, 2.0, -3.0, 4.0);
+    /// assert_eq!(actual, expected);
+    /// ```
+    #[inline]
+    pub fn negated(&self) -> Vector4 {
+        -*self
+    }
+
+    /// Find the vector with its components set to their absolute values
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Vector4;
+    /// 
+    /// let actual = Vector4::make(1.0, -2.0, 3.0, -4.0).abs_value();
+    /// let expected = Vector4::make(1.0, 2.0, 3.0, 4.0);
+    /// assert_eq!(actual, expected);
+    /// ```
+    #[inline]
+    pub fn abs_value(&self) -> Vector4 {
+        let mut result = *self;
+        result.abs();
+        result
+    }
+
+    /// Find the vector with its components clamped between the provided vectors
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Vector4;
+    /// 
+    /// let actual = Vector4::make(1.0, -2.0, 3.0, -4.0).clamped(&Vector4::make(0.0, 0.0, 0.0, 0.0), &Vector4::make(2.0, 2.0, 2.0, 2.0));
+    /// let expected = Vector4::make(1.0, 0.0, 2.0, 0.0);
+    /// assert_eq!(actual, expected);
+    /// ```
+    #[inline]
+    pub fn clamped(&self, a: &Vector4, b: &Vector4) -> Vector4 {
+        let mut result = *self;
+        result.clamp(a, b);
+        result
+    }
+
+    /// Find the normalized vector
+    ///
+    /// Vectors too short to normalize are returned as zero, matching `norm`.
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Vector4;
+    /// 
+    /// let actual = Vector4::make(0.0, 3.0, 0.0, 0.0).normalized();
+    /// let expected = Vector4::make(0.0, 1.0, 0.0, 0.0);
+    /// assert_eq!(actual, expected);
+    /// ```
+    #[inline]
+    pub fn normalized(&self) -> Vector4 {
+        let mut result = *self;
+        if result.norm() == 0.0 {
+            result = Vector4::new();
+        }
+
+        result
+    }
+
+    /// Encodes the vector's components as normalized i16 values
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Vector4;
+    /// 
+    /// let actual = Vector4::make(1.0, -1.0, 0.0, 0.5).to_snorm16();
+    /// assert_eq!(actual, [32767, -32767, 0, 16384]);
     /// ```
     #[inline]
-    pub fn new() -> Vector4 {
-        Vector4 {
-            x: 0.0,
-            y: 0.0,
-            z: 0.0,
-            w: 0.0,
-        }
+    pub fn to_snorm16(&self) -> [i16; 4] {
+        [
+            normalized::encode_snorm16(self.x),
+            normalized::encode_snorm16(self.y),
+            normalized::encode_snorm16(self.z),
+            normalized::encode_snorm16(self.w),
+        ]
     }
 
-    /// Creates a vector <0.0, 0.0, 0.0, 0.0>
+    /// Creates a vector from normalized i16 values
     ///
     /// # Examples
     /// ```
     /// use vex::Vector4;
     /// 
-    /// let actual = Vector4::one();
-    /// let expected = Vector4 { x: 1.0, y: 1.0, z: 1.0, w: 1.0 };
+    /// let actual = Vector4::from_snorm16([32767, -32767, 0, 0]);
+    /// let expected = Vector4::make(1.0, -1.0, 0.0, 0.0);
     /// assert_eq!(actual, expected);
     /// ```
     #[inline]
-    pub fn one() -> Vector4 {
-        Vector4 {
-            x: 1.0,
-            y: 1.0,
-            z: 1.0,
-            w: 1.0,
-        }
+    pub fn from_snorm16(v: [i16; 4]) -> Vector4 {
+        Vector4::make(
+            normalized::decode_snorm16(v[0]),
+            normalized::decode_snorm16(v[1]),
+            normalized::decode_snorm16(v[2]),
+            normalized::decode_snorm16(v[3]),
+        )
     }
 
-    /// Creates a vector from the provided values
+    /// Encodes the vector's components as normalized i8 values
     ///
     /// # Examples
     /// ```
     /// use vex::Vector4;
     /// 
-    /// let actual = Vector4::make(1.0, 2.0, 3.0, 4.0);
-    /// let expected = Vector4 { x: 1.0, y: 2.0, z: 3.0, w: 4.0 };
-    /// assert_eq!(actual, expected);
+    /// let actual = Vector4::make(1.0, -1.0, 0.0, 0.5).to_snorm8();
+    /// assert_eq!(actual, [127, -127, 0, 64]);
     /// ```
     #[inline]
-    pub fn make(x: f32, y: f32, z: f32, w: f32) -> Vector4 {
-        Vector4 { x, y, z, w }
+    pub fn to_snorm8(&self) -> [i8; 4] {
+        [
+            normalized::encode_snorm8(self.x),
+            normalized::encode_snorm8(self.y),
+            normalized::encode_snorm8(self.z),
+            normalized::encode_snorm8(self.w),
+        ]
     }
 
-    /// Find the dot product between two vectors
+    /// Creates a vector from normalized i8 values
     ///
     /// # Examples
     /// ```
     /// use vex::Vector4;
     /// 
-    /// let a = Vector4::make(1.0, 0.0, 0.0, 0.0);
-    /// let b = Vector4::make(0.0, 0.0, 1.0, 0.0);
-    /// let actual = Vector4::dot(&a, &b);
-    /// let expected = 0.0;
+    /// let actual = Vector4::from_snorm8([127, -127, 0, 0]);
+    /// let expected = Vector4::make(1.0, -1.0, 0.0, 0.0);
     /// assert_eq!(actual, expected);
     /// ```
     #[inline]
-    pub fn dot(a: &Vector4, b: &Vector4) -> f32 {
-        a.x * b.x + a.y * b.y + a.z * b.z + a.w * b.w
+    pub fn from_snorm8(v: [i8; 4]) -> Vector4 {
+        Vector4::make(
+            normalized::decode_snorm8(v[0]),
+            normalized::decode_snorm8(v[1]),
+            normalized::decode_snorm8(v[2]),
+            normalized::decode_snorm8(v[3]),
+        )
     }
 
-    /// Find the minimum (component-wise) vector between two vectors
+    /// Encodes the vector's components as normalized u16 values
     ///
     /// # Examples
     /// ```
     /// use vex::Vector4;
     /// 
-    /// let a = Vector4::make(1.0, 4.0, 5.0, 7.0);
-    /// let b = Vector4::make(2.0, 3.0, 6.0, 8.0);
-    /// let actual = Vector4::min(&a, &b);
-    /// let expected = Vector4::make(1.0, 3.0, 5.0, 7.0);
-    /// assert_eq!(actual, expected);
+    /// let actual = Vector4::make(1.0, 0.0, 0.5, 2.0).to_unorm16();
+    /// assert_eq!(actual, [65535, 0, 32768, 65535]);
     /// ```
     #[inline]
-    pub fn min(a: &Vector4, b: &Vector4) -> Vector4 {
-        Vector4::make(a.x.min(b.x), a.y.min(b.y), a.z.min(b.z), a.w.min(b.w))
+    pub fn to_unorm16(&self) -> [u16; 4] {
+        [
+            normalized::encode_unorm16(self.x),
+            normalized::encode_unorm16(self.y),
+            normalized::encode_unorm16(self.z),
+            normalized::encode_unorm16(self.w),
+        ]
     }
 
-    /// Find the maximum (component-wise) vector between two vectors
+    /// Creates a vector from normalized u16 values
     ///
     /// # Examples
     /// ```
     /// use vex::Vector4;
     /// 
-    /// let a = Vector4::make(1.0, 4.0, 5.0, 7.0);
-    /// let b = Vector4::make(2.0, 3.0, 6.0, 8.0);
-    /// let actual = Vector4::max(&a, &b);
-    /// let expected = Vector4::make(2.0, 4.0, 6.0, 8.0);
+    /// let actual = Vector4::from_unorm16([65535, 0, 0, 0]);
+    /// let expected = Vector4::make(1.0, 0.0, 0.0, 0.0);
     /// assert_eq!(actual, expected);
     /// ```
     #[inline]
-    pub fn max(a: &Vector4, b: &Vector4) -> Vector4 {
-        Vector4::make(a.x.max(b.x), a.y.max(b.y), a.z.max(b.z), a.w.max(b.w))
+    pub fn from_unorm16(v: [u16; 4]) -> Vector4 {
+        Vector4::make(
+            normalized::decode_unorm16(v[0]),
+            normalized::decode_unorm16(v[1]),
+            normalized::decode_unorm16(v[2]),
+            normalized::decode_unorm16(v[3]),
+        )
     }
 
-    /// Find the clamped (component-wise) vector between two vectors
+    /// Encodes the vector's components as normalized u8 values
     ///
     /// # Examples
     /// ```
     /// use vex::Vector4;
     /// 
-    /// let a = Vector4::make(1.0, 3.0, 5.0, 7.0);
-    /// let b = Vector4::make(2.0, 4.0, 6.0, 8.0);
-    /// let mut actual = Vector4::make(0.0, 5.0, 10.0, 20.0);
-    /// actual.clamp(&a, &b);
-    /// let expected = Vector4::make(1.0, 4.0, 6.0, 8.0);
-    /// assert_eq!(actual, expected);
+    /// let actual = Vector4::make(1.0, 0.0, 0.5, 2.0).to_unorm8();
+    /// assert_eq!(actual, [255, 0, 128, 255]);
     /// ```
     #[inline]
-    pub fn clamp(&mut self, a: &Vector4, b: &Vector4) {
-        let low = Self::min(a, b);
-        let high = Self::max(a, b);
-        let result = Self::max(&low, &Self::min(self, &high));
-        self.set(result.x, result.y, result.z, result.w);
+    pub fn to_unorm8(&self) -> [u8; 4] {
+        [
+            normalized::encode_unorm8(self.x),
+            normalized::encode_unorm8(self.y),
+            normalized::encode_unorm8(self.z),
+            normalized::encode_unorm8(self.w),
+        ]
     }
 
-    /// Set the components of a vector
+    /// Creates a vector from normalized u8 values
     ///
     /// # Examples
     /// ```
     /// use vex::Vector4;
     /// 
-    /// let mut actual = Vector4::new();
-    /// actual.set(1.0, 2.0, 3.0, 4.0);
-    /// let expected = Vector4::make(1.0, 2.0, 3.0, 4.0);
+    /// let actual = Vector4::from_unorm8([255, 0, 0, 0]);
+    /// let expected = Vector4::make(1.0, 0.0, 0.0, 0.0);
     /// assert_eq!(actual, expected);
     /// ```
     #[inline]
-    pub fn set(&mut self, x: f32, y: f32, z: f32, w: f32) {
-        self.x = x;
-        self.y = y;
-        self.z = z;
-        self.w = w;
+    pub fn from_unorm8(v: [u8; 4]) -> Vector4 {
+        Vector4::make(
+            normalized::decode_unorm8(v[0]),
+            normalized::decode_unorm8(v[1]),
+            normalized::decode_unorm8(v[2]),
+            normalized::decode_unorm8(v[3]),
+        )
     }
 
-    /// Get the magnitude of the vector
+    /// Determine whether or not all components of the vectors are within `epsilon` of each other
     ///
     /// # Examples
     /// ```
     /// use vex::Vector4;
     /// 
-    /// let actual = Vector4::make(1.0, 2.0, 3.0, 4.0).mag();
-    /// let expected = 5.47722557505;
-    /// assert_eq!(actual, expected);
+    /// let a = Vector4::make(1.0, 2.0, 3.0, 4.0);
+    /// assert!(a.approx_eq(&Vector4::make(1.0000001, 2.0, 3.0, 4.0), 0.000001));
+    /// assert!(!a.approx_eq(&Vector4::make(1.1, 2.0, 3.0, 4.0), 0.000001));
     /// ```
     #[inline]
-    pub fn mag(&self) -> f32 {
-        self.mag_sq().sqrt()
+    pub fn approx_eq(&self, other: &Vector4, epsilon: f32) -> bool {
+        for i in 0..4 {
+            if (self[i] - other[i]).abs() > epsilon {
+                return false;
+            }
+        }
+
+        true
     }
 
-    /// Get the squared magnitude of the vector
+    /// Determine whether or not all components of the vectors are equal under an epsilon policy
     ///
     /// # Examples
     /// ```
+    /// use vex::Epsilon;
     /// use vex::Vector4;
     /// 
-    /// let actual = Vector4::make(1.0, 2.0, 3.0, 4.0).mag_sq();
-    /// let expected = 30.0;
-    /// assert_eq!(actual, expected);
+    /// let a = Vector4::make(1000000.0, 2.0, 3.0, 4.0);
+    /// assert!(a.approx_eq_with(&Vector4::make(1000000.06, 2.0, 3.0, 4.0), &Epsilon::DEFAULT));
+    /// assert!(!a.approx_eq_with(&Vector4::make(1000000.0, 2.1, 3.0, 4.0), &Epsilon::DEFAULT));
     /// ```
     #[inline]
-    pub fn mag_sq(&self) -> f32 {
-        self.x * self.x + self.y * self.y + self.z * self.z + self.w * self.w
+    pub fn approx_eq_with(&self, other: &Vector4, epsilon: &Epsilon) -> bool {
+        let a: [f32; 4] = (*self).into();
+        let b: [f32; 4] = (*other).into();
+        epsilon.all_approx_eq(&a, &b)
     }
 
-    /// Normalize the vector
+    /// Determine whether or not the vector has unit length under an epsilon policy
     ///
     /// # Examples
     /// ```
+    /// use vex::Epsilon;
     /// use vex::Vector4;
     /// 
-    /// let mut actual = Vector4::make(1.0, 2.0, 3.0, 4.0);
-    /// actual.norm();
-    /// let expected = Vector4::make(0.18257418, 0.36514837, 0.5477225, 0.73029673);
-    /// assert_eq!(actual, expected);
+    /// assert!(Vector4::make(0.0, 0.6, 0.8, 0.0).is_normalized(&Epsilon::DEFAULT));
+    /// assert!(!Vector4::make(0.0, 0.6, 0.9, 0.0).is_normalized(&Epsilon::DEFAULT));
+    /// assert!(Vector4::make(0.0, 0.6, 0.9, 0.0).is_normalized(&Epsilon::DEFAULT.with_absolute(0.1)));
     /// ```
     #[inline]
-    pub fn norm(&mut self) -> f32 {
-        let length = self.mag();
-        if length > EPSILON {
-            self.x /= length;
-            self.y /= length;
-            self.z /= length;
-            self.w /= length;
-            length
-        } else {
-            0.0
-        }
+    pub fn is_normalized(&self, epsilon: &Epsilon) -> bool {
+        epsilon.approx_eq(self.mag(), 1.0)
     }
 
-    /// Set the components of a vector to their absolute values
+    /// Determine whether or not the vector is too short to have a direction under an epsilon policy
     ///
     /// # Examples
     /// ```
+    /// use vex::Epsilon;
     /// use vex::Vector4;
     /// 
-    /// let mut actual = Vector4::make(-1.0, -2.0, -3.0, -4.0);
-    /// actual.abs();
-    /// let expected = Vector4::make(1.0, 2.0, 3.0, 4.0);
-    /// assert_eq!(actual, expected);
+    /// let v = Vector4::make(0.0, 0.6, 0.9, 0.0) * 0.0000001;
+    /// assert!(v.is_zero(&Epsilon::DEFAULT));
+    /// assert!(!v.is_zero(&Epsilon::DEFAULT.with_absolute(0.0)));
     /// ```
     #[inline]
-    pub fn abs(&mut self) {
-        self.x = self.x.abs();
-        self.y = self.y.abs();
-        self.z = self.z.abs();
-        self.w = self.w.abs();
+    pub fn is_zero(&self, epsilon: &Epsilon) -> bool {
+        epsilon.is_zero(self.mag())
     }
 
     /// Determine whether or not all components of the vector are valid
@@ -272,6 +1060,91 @@ impl Vector4 {
     }
 }
 
+impl Default for Vector4 {
+    /// Creates a zero vector
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Vector4;
+    ///
+    /// assert_eq!(Vector4::default(), Vector4::new());
+    /// ```
+    #[inline]
+    fn default() -> Vector4 {
+        Vector4::new()
+    }
+}
+
+impl From<[f32; 4]> for Vector4 {
+    /// Creates a Vector4 from an array of components
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Vector4;
+    /// 
+    /// let actual = Vector4::from([1.0, 2.0, 3.0, 4.0]);
+    /// let expected = Vector4::make(1.0, 2.0, 3.0, 4.0);
+    /// assert_eq!(actual, expected);
+    /// ```
+    #[inline]
+    fn from(item: [f32; 4]) -> Vector4 {
+        Vector4::make(item[0], item[1], item[2], item[3])
+    }
+}
+
+impl From<Vector4> for [f32; 4] {
+    /// Creates an array from the components of a Vector4
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Vector4;
+    /// 
+    /// let actual: [f32; 4] = Vector4::make(1.0, 2.0, 3.0, 4.0).into();
+    /// let expected = [1.0, 2.0, 3.0, 4.0];
+    /// assert_eq!(actual, expected);
+    /// ```
+    #[inline]
+    fn from(item: Vector4) -> [f32; 4] {
+        [item.x, item.y, item.z, item.w]
+    }
+}
+
+impl AsRef<[f32]> for Vector4 {
+    /// Gets the components as a slice
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Vector4;
+    /// 
+    /// let v = Vector4::make(1.0, 2.0, 3.0, 4.0);
+    /// let actual: &[f32] = v.as_ref();
+    /// assert_eq!(actual, &[1.0, 2.0, 3.0, 4.0][..]);
+    /// ```
+    #[inline]
+    fn as_ref(&self) -> &[f32] {
+        // SAFETY: the type is `repr(C)` with only f32 fields, so they're laid out contiguously
+        unsafe { slice::from_raw_parts(self as *const Vector4 as *const f32, 4) }
+    }
+}
+
+impl AsMut<[f32]> for Vector4 {
+    /// Gets the components as a mutable slice
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Vector4;
+    /// 
+    /// let mut v = Vector4::make(1.0, 2.0, 3.0, 4.0);
+    /// v.as_mut()[0] = 5.0;
+    /// assert_eq!(v.as_ref()[0], 5.0);
+    /// ```
+    #[inline]
+    fn as_mut(&mut self) -> &mut [f32] {
+        // SAFETY: the type is `repr(C)` with only f32 fields, so they're laid out contiguously
+        unsafe { slice::from_raw_parts_mut(self as *mut Vector4 as *mut f32, 4) }
+    }
+}
+
 impl From<Vector3> for Vector4 {
     /// Creates a Vector4 from the components of a Vector3
     ///
@@ -313,14 +1186,12 @@ impl Index<u32> for Vector4 {
     /// ```
     #[inline]
     fn index(&self, index: u32) -> &f32 {
-        unsafe {
-            match index {
-                0 => &self.x,
-                1 => &self.y,
-                2 => &self.z,
-                3 => &self.w,
-                _ => panic!("Invalid index for Vector4: {}", index),
-            }
+        match index {
+            0 => &self.x,
+            1 => &self.y,
+            2 => &self.z,
+            3 => &self.w,
+            _ => panic!("Invalid index for Vector4: {}", index),
         }
     }
 }
@@ -343,15 +1214,13 @@ impl IndexMut<u32> for Vector4 {
     /// assert_eq!(v[3], 7.0);
     /// ```
     #[inline]
-    fn index_mut<'a>(&'a mut self, index: u32) -> &'a mut f32 {
-        unsafe {
-            match index {
-                0 => &mut self.x,
-                1 => &mut self.y,
-                2 => &mut self.z,
-                3 => &mut self.w,
-                _ => panic!("Invalid index for Vector4: {}", index),
-            }
+    fn index_mut(&mut self, index: u32) -> &mut f32 {
+        match index {
+            0 => &mut self.x,
+            1 => &mut self.y,
+            2 => &mut self.z,
+            3 => &mut self.w,
+            _ => panic!("Invalid index for Vector4: {}", index),
         }
     }
 }
@@ -394,6 +1263,25 @@ impl Add<f32> for Vector4 {
     }
 }
 
+impl Add<Vector4> for f32 {
+    type Output = Vector4;
+
+    /// Find the resulting vector by adding a vector's components to a scalar
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Vector4;
+    /// 
+    /// let actual = 1.0 + Vector4::make(1.0, 2.0, 3.0, 4.0);
+    /// let expected = Vector4::make(2.0, 3.0, 4.0, 5.0);
+    /// assert_eq!(actual, expected);
+    /// ```
+    #[inline]
+    fn add(self, _rhs: Vector4) -> Vector4 {
+        Vector4::make(self + _rhs.x, self + _rhs.y, self + _rhs.z, self + _rhs.w)
+    }
+}
+
 impl Add<Vector4> for Vector4 {
     type Output = Vector4;
 
@@ -481,6 +1369,25 @@ impl Sub<f32> for Vector4 {
     }
 }
 
+impl Sub<Vector4> for f32 {
+    type Output = Vector4;
+
+    /// Find the resulting vector by subtracting a vector's components from a scalar
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Vector4;
+    /// 
+    /// let actual = 10.0 - Vector4::make(1.0, 2.0, 3.0, 4.0);
+    /// let expected = Vector4::make(9.0, 8.0, 7.0, 6.0);
+    /// assert_eq!(actual, expected);
+    /// ```
+    #[inline]
+    fn sub(self, _rhs: Vector4) -> Vector4 {
+        Vector4::make(self - _rhs.x, self - _rhs.y, self - _rhs.z, self - _rhs.w)
+    }
+}
+
 impl Sub<Vector4> for Vector4 {
     type Output = Vector4;
 
@@ -567,6 +1474,25 @@ impl Mul<f32> for Vector4 {
     }
 }
 
+impl Mul<Vector4> for f32 {
+    type Output = Vector4;
+
+    /// Find the resulting vector by multiplying a scalar by a vector's components
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Vector4;
+    /// 
+    /// let actual = 2.0 * Vector4::make(1.0, 2.0, 3.0, 4.0);
+    /// let expected = Vector4::make(2.0, 4.0, 6.0, 8.0);
+    /// assert_eq!(actual, expected);
+    /// ```
+    #[inline]
+    fn mul(self, _rhs: Vector4) -> Vector4 {
+        Vector4::make(self * _rhs.x, self * _rhs.y, self * _rhs.z, self * _rhs.w)
+    }
+}
+
 impl Mul<Vector4> for Vector4 {
     type Output = Vector4;
 
@@ -744,8 +1670,76 @@ impl cmp::PartialEq for Vector4 {
 }
 
 impl Display for Vector4 {
+    /// Formats as `<x, y, z, w>`, with the formatter's flags applied to each component
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::Vector4;
+    ///
+    /// let v = Vector4::make(1.0, -2.5, 0.0, 4.0);
+    /// assert_eq!(format!("{}", v), "<1, -2.5, 0, 4>");
+    /// assert_eq!(format!("{:.1}", v), "<1.0, -2.5, 0.0, 4.0>");
+    /// ```
     #[inline]
     fn fmt(&self, f: &mut Formatter) -> fmt::Result {
-        unsafe { write!(f, "<{}  {}  {}  {}>", self.x, self.y, self.z, self.w) }
+        format::write_vector(f, &[self.x, self.y, self.z, self.w])
+    }
+}
+
+impl FromStr for Vector4 {
+    type Err = ParseError;
+
+    /// Parses a vector written as `<x, y, z, w>`, as `Display` writes it
+    ///
+    /// Whitespace around components is ignored, so padded output parses too.
+    ///
+    /// # Examples
+    /// ```
+    /// use vex::ParseError;
+    /// use vex::Vector4;
+    ///
+    /// let v = Vector4::make(1.0, -2.5, 0.0, 4.0);
+    /// assert_eq!("<1, -2.5, 0, 4>".parse::<Vector4>(), Ok(v));
+    /// assert_eq!(format!("{:8.3}", v).parse::<Vector4>(), Ok(v));
+    ///
+    /// let actual = "<1, 2, 3, 4, 5>".parse::<Vector4>();
+    /// assert_eq!(actual, Err(ParseError::Count { expected: 4, found: 5 }));
+    /// assert_eq!("1, 2, 3".parse::<Vector4>(), Err(ParseError::Expected('<')));
+    /// ```
+    #[inline]
+    fn from_str(s: &str) -> Result<Vector4, ParseError> {
+        let [x, y, z, w] = parse::parse_vector(s)?;
+        Ok(Vector4::make(x, y, z, w))
+    }
+}
+
+impl common::Vector for Vector4 {
+    #[inline]
+    fn zero() -> Vector4 {
+        Vector4::new()
+    }
+
+    #[inline]
+    fn dot(a: &Vector4, b: &Vector4) -> f32 {
+        Vector4::dot(a, b)
+    }
+
+    #[inline]
+    fn approx_eq(&self, other: &Vector4, epsilon: f32) -> bool {
+        Vector4::approx_eq(self, other, epsilon)
+    }
+}
+
+impl common::MixFactor<Vector4> for f32 {
+    #[inline]
+    fn spread(self) -> Vector4 {
+        Vector4::make(self, self, self, self)
+    }
+}
+
+impl common::MixFactor<Vector4> for Vector4 {
+    #[inline]
+    fn spread(self) -> Vector4 {
+        self
     }
 }
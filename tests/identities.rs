@@ -0,0 +1,95 @@
+extern crate vex;
+
+use vex::test_utils::{check_matrix_identities, check_transform_consistency, check_vector_identities};
+use vex::{EulerOrder, Matrix2, Matrix3, Matrix4, Vector2, Vector3, Vector4};
+
+const EPSILON: f32 = 0.0001;
+
+fn vector2_samples() -> Vec<Vector2> {
+    vec![
+        Vector2::new(),
+        Vector2::make(1.0, 2.0),
+        Vector2::make(-0.5, 4.0),
+        Vector2::make(3.25, -1.5),
+    ]
+}
+
+fn vector3_samples() -> Vec<Vector3> {
+    vec![
+        Vector3::new(),
+        Vector3::make(1.0, 2.0, 3.0),
+        Vector3::make(-0.5, 4.0, 0.25),
+        Vector3::make(0.0, -3.0, 8.0),
+    ]
+}
+
+fn vector4_samples() -> Vec<Vector4> {
+    vec![
+        Vector4::new(),
+        Vector4::make(1.0, 2.0, 3.0, 1.0),
+        Vector4::make(-0.5, 4.0, 0.25, 0.0),
+        Vector4::make(0.0, -3.0, 8.0, 2.0),
+    ]
+}
+
+fn matrix2_samples() -> Vec<Matrix2> {
+    vec![
+        Matrix2::new(),
+        Matrix2::rotate(0.5),
+        Matrix2::scale(2.0, -0.5),
+        Matrix2::make(1.0, 2.0, 2.0, 4.0),
+    ]
+}
+
+fn matrix3_samples() -> Vec<Matrix3> {
+    vec![
+        Matrix3::new(),
+        Matrix3::from_transform_2d(&Vector2::make(1.0, -2.0), 0.75, &Vector2::make(2.0, 0.5)),
+        Matrix3::from_euler(0.25, -0.5, 1.0, EulerOrder::XYZ),
+        Matrix3::make(1.0, 2.0, 3.0, 2.0, 4.0, 6.0, 0.0, 1.0, 0.0),
+    ]
+}
+
+fn affine_2d_samples() -> Vec<Matrix3> {
+    vec![
+        Matrix3::new(),
+        Matrix3::translate_2d(1.0, -2.0),
+        Matrix3::rotate_2d(0.75),
+        Matrix3::scale_2d(2.0, 0.5),
+    ]
+}
+
+fn matrix4_samples() -> Vec<Matrix4> {
+    vec![
+        Matrix4::new(),
+        Matrix4::translate(1.0, -2.0, 3.0),
+        Matrix4::rotate_x(0.5),
+        Matrix4::rotate(0.2, -0.4, 0.9),
+        Matrix4::scale(2.0, 0.5, -1.0),
+        Matrix4::from_euler(0.25, -0.5, 1.0, EulerOrder::ZYX),
+        Matrix4::from_axis_angle(Vector3::make(1.0, 2.0, -1.0), 0.8),
+    ]
+}
+
+#[test]
+fn vector_identities() {
+    check_vector_identities(&vector2_samples(), EPSILON);
+    check_vector_identities(&vector3_samples(), EPSILON);
+    check_vector_identities(&vector4_samples(), EPSILON);
+}
+
+#[test]
+fn matrix_identities() {
+    check_matrix_identities(&matrix2_samples(), EPSILON);
+    check_matrix_identities(&matrix3_samples(), EPSILON);
+    check_matrix_identities(&matrix4_samples(), EPSILON);
+}
+
+#[test]
+fn transform_consistency() {
+    check_transform_consistency(&matrix2_samples(), &vector2_samples(), EPSILON);
+    check_transform_consistency(&affine_2d_samples(), &vector2_samples(), EPSILON);
+    check_transform_consistency(&matrix3_samples(), &vector3_samples(), EPSILON);
+    check_transform_consistency(&matrix4_samples(), &vector3_samples(), EPSILON);
+    check_transform_consistency(&matrix4_samples(), &vector4_samples(), EPSILON);
+}